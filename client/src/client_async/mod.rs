@@ -0,0 +1,454 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Async JSON-RPC clients for testing against specific versions of Bitcoin Core.
+//!
+//! Mirrors [`client_sync`](crate::client_sync): each version specific module defines a `Client`
+//! with the same methods, as `async fn`s, that sends requests using `reqwest`. The argument types
+//! and the [`Error`] type are shared with the sync clients, transport errors are returned as
+//! `Error::JsonRpc(jsonrpc::error::Error::Transport(_))` and RPC errors as
+//! `Error::JsonRpc(jsonrpc::error::Error::Rpc(_))`, just as the sync clients do.
+//!
+//! The clients do not start a runtime, they must be used from within a `tokio` runtime.
+
+pub mod v17;
+pub mod v18;
+pub mod v19;
+pub mod v20;
+pub mod v21;
+pub mod v22;
+pub mod v23;
+pub mod v24;
+pub mod v25;
+pub mod v26;
+#[cfg(feature = "unstable-v28")]
+pub mod v28;
+
+#[doc(inline)]
+pub use crate::client_sync::{Auth, Error, Result};
+
+/// Defines an async JSON-RPC `Client` using `reqwest`.
+#[macro_export]
+macro_rules! define_jsonrpc_reqwest_client {
+    ($version:literal) => {
+        use std::fmt;
+
+        use $crate::client_sync::error::Error;
+        use $crate::client_sync::{log_response, Auth, Result};
+
+        /// Client implements an async JSON-RPC client for the Bitcoin Core daemon or compatible
+        /// APIs.
+        ///
+        /// The client is `Send + Sync` and cheap to clone, clones share the underlying connection
+        /// pool.
+        #[derive(Clone)]
+        pub struct Client {
+            inner: reqwest::Client,
+            url: String,
+            credentials: Option<$crate::client_sync::Credentials>,
+            timeout: Option<std::time::Duration>,
+            /// Used to create request ids, shared between clones.
+            nonce: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+            safety_mode: bool,
+            /// Cached result of checking the node's chain, shared between clones.
+            chain_check: std::sync::Arc<std::sync::atomic::AtomicU8>,
+            /// The methods this client may call, `None` if all methods are allowed.
+            allowed_methods: Option<std::sync::Arc<std::collections::BTreeSet<String>>>,
+            /// Called for each request and response, shared between clones.
+            hook: Option<$crate::client_sync::RpcHook>,
+        }
+
+        // Enforce the thread-safety guarantees documented on `Client`.
+        const _: () = {
+            fn assert_send_sync<T: core::marker::Send + Sync + Clone>() {}
+            #[allow(dead_code)]
+            fn assert_client() { assert_send_sync::<Client>(); }
+        };
+
+        impl fmt::Debug for Client {
+            fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
+                write!(f, "bitcoind-json-rpc::client_async::{}::Client({})", $version, self.url)
+            }
+        }
+
+        impl Client {
+            /// Creates a client to a bitcoind JSON-RPC server without authentication.
+            pub fn new(url: &str) -> Self { Self::with_parts(url.to_owned(), None) }
+
+            /// Creates a client to a bitcoind JSON-RPC server with authentication.
+            ///
+            /// A cookie file is read when the client is created and re-read whenever it changes,
+            /// e.g. when `bitcoind` restarts and writes a new cookie.
+            pub fn new_with_auth(url: &str, auth: Auth) -> Result<Self> {
+                if matches!(auth, Auth::None) {
+                    return Err(Error::MissingUserPassword);
+                }
+                let credentials = $crate::client_sync::Credentials::from_auth(auth)?;
+                Ok(Self::with_parts(url.to_owned(), credentials))
+            }
+
+            /// Creates a client to the node (or wallet) at `url`.
+            ///
+            /// Unlike [`Client::new_with_auth`], `auth` may be [`Auth::None`].
+            pub fn from_node_url(url: &$crate::node_url::NodeUrl, auth: Auth) -> Result<Self> {
+                let credentials = $crate::client_sync::Credentials::from_auth(auth)?;
+                Ok(Self::with_parts(url.to_string(), credentials))
+            }
+
+            /// Creates a client from a connection profile.
+            ///
+            /// See [`profile`](crate::client_sync::profile) for loading profiles from a file.
+            #[cfg(feature = "profiles")]
+            pub fn from_profile(
+                profile: &$crate::client_sync::profile::ClientProfile,
+            ) -> Result<Self> {
+                let credentials = $crate::client_sync::Credentials::from_auth(profile.auth.clone())?;
+                let mut client = Self::with_parts(profile.endpoint(), credentials);
+                client.timeout = profile.timeout;
+                Ok(client)
+            }
+
+            /// Returns a client to the same server that uses `timeout` for all its requests.
+            ///
+            /// `self` is not modified, this allows slow RPCs (e.g. `verifychain`, `gettxoutsetinfo`)
+            /// to be called with a generous timeout while other calls continue to fail fast.
+            pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+                let mut client = self.clone();
+                client.timeout = Some(timeout);
+                client
+            }
+
+            /// Enables or disables safety mode (enabled by default).
+            ///
+            /// With safety mode on, calls to regtest-only methods (see
+            /// [`REGTEST_ONLY_METHODS`](crate::client_sync::REGTEST_ONLY_METHODS)) return
+            /// [`Error::RegtestOnly`] instead of being sent if the node reports it is on mainnet.
+            pub fn set_safety_mode(&mut self, enabled: bool) { self.safety_mode = enabled; }
+
+            /// Returns true if safety mode is enabled.
+            pub fn safety_mode(&self) -> bool { self.safety_mode }
+
+            /// Restricts the client to calling only `methods`, e.g. to match a node's `rpcwhitelist`.
+            ///
+            /// Calls to any other method return [`Error::MethodNotAllowed`] without being sent,
+            /// instead of the node failing the request with HTTP 403. The allowed methods are
+            /// shared with clients cloned after this call. With safety mode on, the first call to a
+            /// regtest-only method also calls `getblockchaininfo`.
+            pub fn set_allowed_methods<I, S>(&mut self, methods: I)
+            where
+                I: IntoIterator<Item = S>,
+                S: Into<String>,
+            {
+                let methods = methods.into_iter().map(Into::into).collect();
+                self.allowed_methods = Some(std::sync::Arc::new(methods));
+            }
+
+            /// Removes any restriction set with [`Client::set_allowed_methods`].
+            pub fn clear_allowed_methods(&mut self) { self.allowed_methods = None; }
+
+            /// Returns the methods this client may call, `None` if the client is not restricted.
+            pub fn allowed_methods(&self) -> Option<&std::collections::BTreeSet<String>> {
+                self.allowed_methods.as_deref()
+            }
+
+            /// Sets a hook called for each request sent and each response received.
+            ///
+            /// Useful to debug what is sent over the wire, or to collect metrics. The hook is shared
+            /// with clients cloned after this call and is called from the task making the call. The
+            /// `log` crate is used irrespective of the hook (target `bitcoind-json-rpc`, requests
+            /// logged at debug level and results at trace level).
+            pub fn set_rpc_hook<F>(&mut self, hook: F)
+            where
+                F: Fn(&$crate::client_sync::RpcEvent<'_>) + core::marker::Send + Sync + 'static,
+            {
+                self.hook = Some(std::sync::Arc::new(hook));
+            }
+
+            /// Removes any hook set with [`Client::set_rpc_hook`].
+            pub fn clear_rpc_hook(&mut self) { self.hook = None; }
+
+            /// Returns the documentation of the typed methods implemented by this client.
+            ///
+            /// See [`method_docs`](crate::method_docs) for listing the methods available on the
+            /// connected node instead.
+            pub fn method_docs() -> impl Iterator<Item = &'static $crate::method_docs::MethodDoc> {
+                let version = $version[1..].parse().expect("client version is of the form vXX");
+                $crate::method_docs::available(version)
+            }
+
+            /// Calls the hook, if one is set.
+            fn run_hook(&self, event: $crate::client_sync::RpcEvent<'_>) {
+                if let Some(ref hook) = self.hook {
+                    hook(&event)
+                }
+            }
+
+            /// Returns an error if the client is restricted and `method` is not allowed.
+            fn check_allowed(&self, method: &str) -> Result<()> {
+                match self.allowed_methods {
+                    Some(ref allowed) if !allowed.contains(method) =>
+                        Err(Error::MethodNotAllowed(method.to_owned())),
+                    _ => Ok(()),
+                }
+            }
+
+            /// Returns an error if `method` is regtest-only and the node is on mainnet.
+            async fn check_regtest_only(&self, method: &str) -> Result<()> {
+                if !self.safety_mode || !$crate::client_sync::is_regtest_only(method) {
+                    return Ok(());
+                }
+
+                use std::sync::atomic::Ordering;
+
+                // 0: not yet checked, 1: not mainnet, 2: mainnet.
+                let is_mainnet = match self.chain_check.load(Ordering::Relaxed) {
+                    0 => {
+                        let info: serde_json::Value =
+                            self.send_unchecked("getblockchaininfo", &[]).await?;
+                        let main = info.get("chain").and_then(|c| c.as_str()) == Some("main");
+                        self.chain_check.store(if main { 2 } else { 1 }, Ordering::Relaxed);
+                        main
+                    }
+                    cached => cached == 2,
+                };
+
+                if is_mainnet {
+                    return Err(Error::RegtestOnly(method.to_owned()));
+                }
+                Ok(())
+            }
+
+            fn with_parts(
+                url: String,
+                credentials: Option<$crate::client_sync::Credentials>,
+            ) -> Self {
+                Self {
+                    inner: reqwest::Client::new(),
+                    url,
+                    credentials,
+                    timeout: None,
+                    nonce: std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                    safety_mode: true,
+                    chain_check: std::sync::Arc::new(std::sync::atomic::AtomicU8::new(0)),
+                    allowed_methods: None,
+                    hook: None,
+                }
+            }
+
+            /// Call an RPC `method` with given `args` list.
+            pub async fn call<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<T> {
+                self.check_regtest_only(method).await?;
+                self.send_unchecked(method, args).await
+            }
+
+            /// Sends a request without checking for regtest-only methods.
+            async fn send_unchecked<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<T> {
+                self.check_allowed(method)?;
+
+                let raw = serde_json::value::to_raw_value(args)?;
+                let nonce = self.nonce.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let req = $crate::client_sync::transport::Request {
+                    method,
+                    params: Some(&*raw),
+                    id: serde_json::Value::from(nonce),
+                    jsonrpc: Some("2.0"),
+                };
+                if log::log_enabled!(log::Level::Debug) {
+                    log::debug!(target: "bitcoind-json-rpc", "request: {} {}", method, serde_json::Value::from(args));
+                }
+                self.run_hook($crate::client_sync::RpcEvent::Request { method, args });
+
+                let start = std::time::Instant::now();
+                let resp = self.post(&req).await;
+                log_response(method, &resp);
+                self.run_hook($crate::client_sync::RpcEvent::Response {
+                    method,
+                    elapsed: start.elapsed(),
+                    result: resp.as_ref(),
+                });
+                Ok(resp?.result()?)
+            }
+
+            /// Calls `method` with `args`, returning the result as untyped JSON.
+            ///
+            /// An escape hatch for methods this crate does not (yet) support. Use [`Client::call`]
+            /// to deserialize the result directly into a type of your own.
+            pub async fn call_raw(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<serde_json::Value> {
+                self.call(method, args).await
+            }
+
+            /// Calls `method` once for each entry of `args`, sending all the requests as a single
+            /// JSON-RPC batch.
+            ///
+            /// The results are returned in the same order as `args`, an error response to any of
+            /// the requests fails the whole batch. Use [`Client::call_batch_results`] to handle
+            /// the error responses individually.
+            pub async fn call_batch<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[Vec<serde_json::Value>],
+            ) -> Result<Vec<T>> {
+                self.call_batch_results(method, args).await?.into_iter().collect()
+            }
+
+            /// Calls `method` once for each entry of `args`, sending all the requests as a single
+            /// JSON-RPC batch, returning the result of each request.
+            ///
+            /// The results are returned in the same order as `args`. The outer error is returned
+            /// only if the batch as a whole fails, an error response to one of the requests is
+            /// returned in its place without affecting the others.
+            pub async fn call_batch_results<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[Vec<serde_json::Value>],
+            ) -> Result<Vec<Result<T>>> {
+                if args.is_empty() {
+                    return Ok(vec![]);
+                }
+                self.check_allowed(method)?;
+                self.check_regtest_only(method).await?;
+
+                let raws = args
+                    .iter()
+                    .map(|args| serde_json::value::to_raw_value(args))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                let requests = raws
+                    .iter()
+                    .map(|raw| {
+                        let nonce = self.nonce.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        $crate::client_sync::transport::Request {
+                            method,
+                            params: Some(&**raw),
+                            id: serde_json::Value::from(nonce),
+                            jsonrpc: Some("2.0"),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                if log::log_enabled!(log::Level::Debug) {
+                    log::debug!(target: "bitcoind-json-rpc", "batch request: {} x{}", method, args.len());
+                }
+                for args in args {
+                    self.run_hook($crate::client_sync::RpcEvent::Request { method, args });
+                }
+
+                let start = std::time::Instant::now();
+                let batch: Result<Vec<$crate::client_sync::transport::Response>> =
+                    self.post(&requests).await;
+                let elapsed = start.elapsed();
+                let mut responses = match batch {
+                    Ok(responses) => responses,
+                    Err(e) => {
+                        self.run_hook($crate::client_sync::RpcEvent::Response {
+                            method,
+                            elapsed,
+                            result: Err(&e),
+                        });
+                        return Err(e);
+                    }
+                };
+                requests
+                    .iter()
+                    .map(|req| {
+                        let i = responses
+                            .iter()
+                            .position(|resp| resp.id == req.id)
+                            .ok_or(Error::UnexpectedStructure)?;
+                        let resp = Ok(responses.swap_remove(i));
+                        log_response(method, &resp);
+                        self.run_hook($crate::client_sync::RpcEvent::Response {
+                            method,
+                            elapsed,
+                            result: resp.as_ref(),
+                        });
+                        Ok(resp.and_then(|resp| Ok(resp.result()?)))
+                    })
+                    .collect()
+            }
+
+            /// Posts `body` to the node and parses the response.
+            async fn post<B, R>(&self, body: &B) -> Result<R>
+            where
+                B: serde::Serialize + ?Sized,
+                R: for<'a> serde::de::Deserialize<'a>,
+            {
+                fn transport_err(e: reqwest::Error) -> Error {
+                    Error::JsonRpc(jsonrpc::error::Error::Transport(Box::new(e)))
+                }
+
+                let mut builder = self.inner.post(&self.url).json(body);
+                if let Some(ref credentials) = self.credentials {
+                    let (user, pass) = credentials.user_pass()?;
+                    builder = builder.basic_auth(user, pass);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                let resp = builder.send().await.map_err(transport_err)?;
+                let status = resp.error_for_status_ref().map(|_| ());
+                let body = resp.bytes().await.map_err(transport_err)?;
+
+                // Core returns RPC errors with a non-success status code and the error in the body.
+                match serde_json::from_slice(&body) {
+                    Ok(resp) => Ok(resp),
+                    Err(e) => {
+                        status.map_err(transport_err)?;
+                        Err(Error::Json(e))
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Implements the `check_expected_server_version()` on an async `Client`.
+///
+/// Requires `Client` to be in scope and implement `server_version()`.
+/// See and/or use `impl_async_client_v17__getnetworkinfo`.
+///
+/// # Parameters
+///
+/// - `$expected_versions`: The supported server versions, all point releases of the major
+///   version e.g., `[230000, 230100, 230200]`.
+#[macro_export]
+macro_rules! impl_async_client_check_expected_server_version {
+    ($expected_versions:expr) => {
+        impl Client {
+            /// Returns the versions of Core (all the point releases) supported by this client.
+            pub fn supported_versions() -> Vec<$crate::version::CoreVersion> {
+                $expected_versions
+                    .iter()
+                    .map(|v| $crate::version::CoreVersion::from_server_version(*v))
+                    .collect()
+            }
+
+            /// Checks that the JSON-RPC endpoint is for a `bitcoind` instance with a supported
+            /// version.
+            ///
+            /// # Errors
+            ///
+            /// [`Error::ServerVersion`] if the version reported by `bitcoind` is not one of
+            /// [`Client::supported_versions`].
+            pub async fn check_expected_server_version(&self) -> Result<()> {
+                let server_version = self.server_version().await?;
+                if !$expected_versions.contains(&server_version) {
+                    return Err($crate::client_sync::error::UnsupportedVersionError {
+                        got: $crate::version::CoreVersion::from_server_version(server_version),
+                        expected: Self::supported_versions(),
+                    })?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
@@ -0,0 +1,590 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getblockchaininfo`
+#[macro_export]
+macro_rules! impl_async_client_v17__getblockchaininfo {
+    () => {
+        impl Client {
+            pub async fn get_blockchain_info(&self) -> Result<GetBlockchainInfo> {
+                self.call("getblockchaininfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getbestblockhash`
+#[macro_export]
+macro_rules! impl_async_client_v17__getbestblockhash {
+    () => {
+        impl Client {
+            /// Gets the blockhash of the current chain tip.
+            pub async fn best_block_hash(&self) -> Result<bitcoin::BlockHash> {
+                let json = self.get_best_block_hash().await?;
+                Ok(json.block_hash()?)
+            }
+
+            pub async fn get_best_block_hash(&self) -> Result<GetBestBlockHash> {
+                self.call("getbestblockhash", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getblock`
+#[macro_export]
+macro_rules! impl_async_client_v17__getblock {
+    () => {
+        impl Client {
+            /// Gets a block by blockhash.
+            pub async fn get_block(&self, hash: &BlockHash) -> Result<Block> {
+                let json = self.get_block_verbosity_zero(hash).await?;
+                Ok(json.block()?)
+            }
+
+            /// Gets a block by blockhash, including the block's metadata and decoded transactions.
+            pub async fn get_block_verbose(
+                &self,
+                hash: &BlockHash,
+            ) -> Result<$crate::json::model::GetBlockVerbosityTwo> {
+                let json = self.get_block_verbosity_two(hash).await?;
+                json.into_model().map_err(|e| Error::IntoModel(Box::new(e)))
+            }
+
+            // FIXME(getblock): This handling of optional args is ugly as hell but because the returned json
+            // is different for each verbosity these are functionally different methods. Is there a better way?
+
+            pub async fn get_block_verbosity_zero(
+                &self,
+                hash: &BlockHash,
+            ) -> Result<GetBlockVerbosityZero> {
+                self.call("getblock", &[into_json(hash)?, 0.into()]).await
+            }
+
+            pub async fn get_block_verbosity_one(
+                &self,
+                hash: &BlockHash,
+            ) -> Result<GetBlockVerbosityOne> {
+                self.call("getblock", &[into_json(hash)?, 1.into()]).await
+            }
+
+            pub async fn get_block_verbosity_two(
+                &self,
+                hash: &BlockHash,
+            ) -> Result<GetBlockVerbosityTwo> {
+                self.call("getblock", &[into_json(hash)?, 2.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getblockheader`
+#[macro_export]
+macro_rules! impl_async_client_v17__getblockheader {
+    () => {
+        impl Client {
+            pub async fn get_block_header(&self, hash: &BlockHash) -> Result<GetBlockHeader> {
+                self.call("getblockheader", &[into_json(hash)?, into_json(false)?]).await
+            }
+
+            pub async fn get_block_header_verbose(
+                &self,
+                hash: &BlockHash,
+            ) -> Result<GetBlockHeaderVerbose> {
+                self.call("getblockheader", &[into_json(hash)?, into_json(true)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getblockhash`
+#[macro_export]
+macro_rules! impl_async_client_v17__getblockhash {
+    () => {
+        impl Client {
+            pub async fn get_block_hash(&self, height: u64) -> Result<GetBlockHash> {
+                self.call("getblockhash", &[into_json(height)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getchaintips`
+///
+/// Also implements `detect_reorg`, requires `get_block_hash` to be implemented.
+#[macro_export]
+macro_rules! impl_async_client_v17__getchaintips {
+    () => {
+        impl Client {
+            pub async fn get_chain_tips(&self) -> Result<GetChainTips> {
+                self.call("getchaintips", &[]).await
+            }
+
+            /// Returns the deepest fork that replaced any of the last `depth` blocks of the active
+            /// chain, `None` if the last `depth` blocks have not changed.
+            ///
+            /// Blocks disconnected by a reorg remain known to the node as a fully validated
+            /// (`valid-fork`) branch, the fork point is where that branch meets the active chain.
+            pub async fn detect_reorg(
+                &self,
+                depth: u64,
+            ) -> Result<Option<$crate::client_sync::v17::ChainFork>> {
+                use $crate::json::model::ChainTipsStatus;
+
+                let tips = self
+                    .get_chain_tips()
+                    .await?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))?;
+                let active = tips.active().ok_or(Error::UnexpectedStructure)?;
+                let min_height = active.height.saturating_sub(depth);
+
+                let stale = tips
+                    .0
+                    .iter()
+                    .filter(|tip| tip.status == ChainTipsStatus::ValidFork)
+                    .filter(|tip| tip.fork_height() >= min_height)
+                    .min_by_key(|tip| tip.fork_height());
+                let stale = match stale {
+                    Some(stale) => stale,
+                    None => return Ok(None),
+                };
+
+                let fork_height = stale.fork_height();
+                let fork_point = self.get_block_hash(fork_height).await?.block_hash()?;
+                Ok(Some($crate::client_sync::v17::ChainFork {
+                    fork_point,
+                    fork_height,
+                    stale_tip: stale.hash,
+                    stale_blocks: stale.branch_length,
+                }))
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `preciousblock`
+#[macro_export]
+macro_rules! impl_async_client_v17__preciousblock {
+    () => {
+        impl Client {
+            /// Treats the block `hash` as if it were received before others with the same work.
+            pub async fn precious_block(&self, hash: &BlockHash) -> Result<()> {
+                self.call("preciousblock", &[into_json(hash)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `invalidateblock`
+///
+/// This is a hidden, regtest-only, RPC (see `REGTEST_ONLY_METHODS`).
+#[macro_export]
+macro_rules! impl_async_client_v17__invalidateblock {
+    () => {
+        impl Client {
+            /// Permanently marks the block `hash` as invalid, as if it violated a consensus rule.
+            pub async fn invalidate_block(&self, hash: &BlockHash) -> Result<()> {
+                self.call("invalidateblock", &[into_json(hash)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `reconsiderblock`
+///
+/// This is a hidden, regtest-only, RPC (see `REGTEST_ONLY_METHODS`).
+#[macro_export]
+macro_rules! impl_async_client_v17__reconsiderblock {
+    () => {
+        impl Client {
+            /// Removes invalidity status of the block `hash` and its descendants, reconsidering
+            /// them for activation.
+            pub async fn reconsider_block(&self, hash: &BlockHash) -> Result<()> {
+                self.call("reconsiderblock", &[into_json(hash)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gettxout`
+#[macro_export]
+macro_rules! impl_async_client_v17__gettxout {
+    () => {
+        impl Client {
+            pub async fn get_tx_out(&self, txid: Txid, vout: u64) -> Result<GetTxOut> {
+                self.call("gettxout", &[into_json(txid)?, into_json(vout)?]).await
+            }
+
+            /// Returns details about an unspent transaction output, `None` if the output is spent
+            /// or does not exist.
+            ///
+            /// Outputs spent by a transaction in the mempool are treated as spent if
+            /// `include_mempool` is true (this is the default used by `get_tx_out`).
+            pub async fn try_get_tx_out(
+                &self,
+                txid: Txid,
+                vout: u64,
+                include_mempool: bool,
+            ) -> Result<Option<GetTxOut>> {
+                self.call(
+                    "gettxout",
+                    &[into_json(txid)?, into_json(vout)?, into_json(include_mempool)?],
+                )
+                .await
+            }
+
+            /// Returns the fee paid by `tx`, the value of its previous outputs less its outputs.
+            ///
+            /// The previous outputs are looked up with a single batch of `gettxout` calls, those
+            /// not in the UTXO set (spent, or created by a mempool transaction) are then looked up
+            /// with a batch of `getrawtransaction` calls. Previous outputs spent in a block can
+            /// only be found if the node runs with `-txindex`.
+            ///
+            /// # Errors
+            ///
+            /// [`Error::TxFee`] if `tx` is a coinbase transaction, if any previous output can not
+            /// be found, or if the outputs are worth more than the inputs.
+            ///
+            /// [`Error::TxFee`]: crate::client_sync::Error::TxFee
+            pub async fn tx_fee(&self, tx: &$crate::bitcoin::Transaction) -> Result<Amount> {
+                use $crate::bitcoin::consensus::encode;
+                use $crate::bitcoin::Transaction;
+                use $crate::client_sync::TxFeeError;
+
+                /// Core's `RPC_INVALID_ADDRESS_OR_KEY`, returned for an unknown transaction.
+                const RPC_INVALID_ADDRESS_OR_KEY: i32 = -5;
+
+                if tx.is_coinbase() {
+                    return Err(TxFeeError::Coinbase.into());
+                }
+
+                let args = tx
+                    .input
+                    .iter()
+                    .map(|txin| {
+                        let outpoint = txin.previous_output;
+                        Ok(vec![into_json(outpoint.txid)?, into_json(outpoint.vout)?, false.into()])
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let utxos: Vec<Option<GetTxOut>> = self.call_batch("gettxout", &args).await?;
+                let mut values = Vec::with_capacity(utxos.len());
+                for utxo in utxos {
+                    values.push(utxo.map(|utxo| Amount::from_btc(utxo.value)).transpose()?);
+                }
+
+                let mut txids = tx
+                    .input
+                    .iter()
+                    .zip(&values)
+                    .filter(|(_, value)| value.is_none())
+                    .map(|(txin, _)| txin.previous_output.txid)
+                    .collect::<Vec<_>>();
+                txids.sort();
+                txids.dedup();
+
+                let args = txids
+                    .iter()
+                    .map(|txid| Ok(vec![into_json(txid)?]))
+                    .collect::<Result<Vec<_>>>()?;
+                let hexes: Vec<Result<String>> =
+                    self.call_batch_results("getrawtransaction", &args).await?;
+                let mut prev_txs = std::collections::BTreeMap::new();
+                for (txid, hex) in txids.into_iter().zip(hexes) {
+                    let hex = match hex {
+                        Ok(hex) => hex,
+                        // Unknown transaction, its outpoints are reported below.
+                        Err(e) if e.rpc_code() == Some(RPC_INVALID_ADDRESS_OR_KEY) => continue,
+                        Err(e) => return Err(e),
+                    };
+                    prev_txs.insert(txid, encode::deserialize_hex::<Transaction>(&hex)?);
+                }
+
+                let mut input = Amount::ZERO;
+                let mut unknown = vec![];
+                for (txin, value) in tx.input.iter().zip(values) {
+                    let outpoint = txin.previous_output;
+                    let value = value.or_else(|| {
+                        let prev_tx = prev_txs.get(&outpoint.txid)?;
+                        prev_tx.output.get(outpoint.vout as usize).map(|txout| txout.value)
+                    });
+                    match value {
+                        Some(value) =>
+                            input = input.checked_add(value).ok_or(Error::UnexpectedStructure)?,
+                        None => unknown.push(outpoint),
+                    }
+                }
+                if !unknown.is_empty() {
+                    return Err(TxFeeError::UnknownPrevOuts(unknown).into());
+                }
+
+                let output = tx
+                    .output
+                    .iter()
+                    .try_fold(Amount::ZERO, |sum, txout| sum.checked_add(txout.value))
+                    .ok_or(Error::UnexpectedStructure)?;
+                input
+                    .checked_sub(output)
+                    .ok_or_else(|| TxFeeError::OutputsExceedInputs { input, output }.into())
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gettxoutproof`
+#[macro_export]
+macro_rules! impl_async_client_v17__gettxoutproof {
+    () => {
+        impl Client {
+            /// Returns a proof that `txids` were included in a block.
+            ///
+            /// Without `-txindex` the proof can only be found if one of the transactions has an
+            /// unspent output, use `get_tx_out_proof_in_block` if the block is known.
+            pub async fn get_tx_out_proof(&self, txids: &[Txid]) -> Result<GetTxOutProof> {
+                self.call("gettxoutproof", &[into_json(txids)?]).await
+            }
+
+            /// Returns a proof that `txids` were included in the block with hash `block_hash`.
+            pub async fn get_tx_out_proof_in_block(
+                &self,
+                txids: &[Txid],
+                block_hash: &BlockHash,
+            ) -> Result<GetTxOutProof> {
+                self.call("gettxoutproof", &[into_json(txids)?, into_json(block_hash)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `verifytxoutproof`
+#[macro_export]
+macro_rules! impl_async_client_v17__verifytxoutproof {
+    () => {
+        impl Client {
+            /// Verifies `proof`, returning the transactions it commits to.
+            ///
+            /// Returns an RPC error if the block is not in the node's best chain.
+            pub async fn verify_tx_out_proof(
+                &self,
+                proof: &$crate::bitcoin::MerkleBlock,
+            ) -> Result<VerifyTxOutProof> {
+                let proof = $crate::bitcoin::consensus::encode::serialize_hex(proof);
+                self.call("verifytxoutproof", &[proof.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `verifychain`
+#[macro_export]
+macro_rules! impl_async_client_v17__verifychain {
+    () => {
+        impl Client {
+            /// Verifies the blockchain database using the default check level and number of blocks.
+            pub async fn verify_chain(&self) -> Result<bool> { self.call("verifychain", &[]).await }
+
+            /// Verifies the blockchain database, calling `on_progress` every `poll_interval`.
+            ///
+            /// `verifychain` is called using a client with `timeout`. Core does not expose the
+            /// progress of `verifychain`, see [`VerifyChainHeartbeat`].
+            ///
+            /// # Parameters
+            ///
+            /// - `check_level`: How thorough the verification is (0-4).
+            /// - `nblocks`: The number of blocks to check, `0` for all.
+            ///
+            /// [`VerifyChainHeartbeat`]: crate::client_sync::VerifyChainHeartbeat
+            pub async fn verify_chain_with_heartbeat<F>(
+                &self,
+                check_level: u32,
+                nblocks: u32,
+                timeout: std::time::Duration,
+                interval: std::time::Duration,
+                mut on_heartbeat: F,
+            ) -> Result<bool>
+            where
+                F: FnMut(&$crate::client_sync::VerifyChainHeartbeat),
+            {
+                use std::time::Instant;
+
+                let client = self.with_timeout(timeout);
+                let args = [check_level.into(), nblocks.into()];
+                let mut verify = Box::pin(client.call::<bool>("verifychain", &args));
+
+                let start = Instant::now();
+                loop {
+                    match tokio::time::timeout(interval, &mut verify).await {
+                        Ok(result) => return result,
+                        Err(_) => {
+                            let heartbeat = $crate::client_sync::VerifyChainHeartbeat {
+                                elapsed: start.elapsed(),
+                            };
+                            on_heartbeat(&heartbeat);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gettxoutsetinfo`
+#[macro_export]
+macro_rules! impl_async_client_v17__gettxoutsetinfo {
+    () => {
+        impl Client {
+            pub async fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `scantxoutset`
+#[macro_export]
+macro_rules! impl_async_client_v17__scantxoutset {
+    () => {
+        impl Client {
+            /// Scans the UTXO set for outputs matching `scan_objects`.
+            ///
+            /// Blocks until the scan is done, there can only be one scan in progress at a time.
+            pub async fn scan_tx_out_set(
+                &self,
+                scan_objects: &[$crate::client_sync::v17::ScanObject],
+            ) -> Result<ScanTxOutSetStart> {
+                let action = $crate::client_sync::v17::ScanAction::Start;
+                self.call("scantxoutset", &[into_json(action)?, into_json(scan_objects)?]).await
+            }
+
+            /// Returns the progress of the scan in progress, `None` if there is none.
+            pub async fn scan_tx_out_set_status(&self) -> Result<Option<ScanTxOutSetStatus>> {
+                let action = $crate::client_sync::v17::ScanAction::Status;
+                self.call("scantxoutset", &[into_json(action)?]).await
+            }
+
+            /// Aborts the scan in progress, returns true if a scan was aborted.
+            pub async fn scan_tx_out_set_abort(&self) -> Result<bool> {
+                let action = $crate::client_sync::v17::ScanAction::Abort;
+                self.call("scantxoutset", &[into_json(action)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getrawmempool`
+#[macro_export]
+macro_rules! impl_async_client_v17__getrawmempool {
+    () => {
+        impl Client {
+            pub async fn get_raw_mempool(&self) -> Result<GetRawMempool> {
+                self.call("getrawmempool", &[]).await
+            }
+
+            pub async fn get_raw_mempool_verbose(&self) -> Result<GetRawMempoolVerbose> {
+                self.call("getrawmempool", &[into_json(true)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getmempoolentry`
+#[macro_export]
+macro_rules! impl_async_client_v17__getmempoolentry {
+    () => {
+        impl Client {
+            pub async fn get_mempool_entry(&self, txid: Txid) -> Result<GetMempoolEntry> {
+                self.call("getmempoolentry", &[into_json(&txid)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getmempoolinfo`
+///
+/// Also implements `mempool_candidates` and `mempool_candidate`, requires `get_raw_mempool_verbose`
+/// and `get_mempool_entry`.
+#[macro_export]
+macro_rules! impl_async_client_v17__getmempoolinfo {
+    () => {
+        impl Client {
+            pub async fn get_mempool_info(&self) -> Result<GetMempoolInfo> {
+                self.call("getmempoolinfo", &[]).await
+            }
+
+            /// Returns the mempool transactions near eviction or expiry, lowest descendant score
+            /// first.
+            ///
+            /// Uses `getmempoolinfo` and `getrawmempool` (verbose), the whole mempool is fetched.
+            pub async fn mempool_candidates(
+                &self,
+                options: &$crate::client_sync::v17::MempoolCandidateOptions,
+            ) -> Result<Vec<$crate::client_sync::v17::MempoolCandidate>> {
+                use $crate::client_sync::v17::MempoolCandidate;
+
+                let info = self
+                    .get_mempool_info()
+                    .await?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))?;
+                let mempool = self
+                    .get_raw_mempool_verbose()
+                    .await?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))?;
+
+                let mut entries = mempool.0.into_iter().collect::<Vec<_>>();
+                entries.sort_by_key(|(_, entry)| entry.descendant_score());
+                let now = $crate::client_sync::unix_time_now();
+
+                let candidates = entries
+                    .iter()
+                    .enumerate()
+                    .map(|(rank, (txid, entry))| {
+                        let min_fee = info.mempool_min_fee;
+                        MempoolCandidate::assess(*txid, entry, min_fee, Some(rank), options, now)
+                    })
+                    .filter(|candidate| candidate.is_at_risk())
+                    .collect();
+                Ok(candidates)
+            }
+
+            /// Assesses the mempool transaction `txid` for eviction and expiry risk.
+            ///
+            /// Uses `getmempoolinfo` and `getmempoolentry`, the eviction rank is not computed.
+            pub async fn mempool_candidate(
+                &self,
+                txid: Txid,
+                options: &$crate::client_sync::v17::MempoolCandidateOptions,
+            ) -> Result<$crate::client_sync::v17::MempoolCandidate> {
+                let info = self
+                    .get_mempool_info()
+                    .await?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))?;
+                let entry = self
+                    .get_mempool_entry(txid)
+                    .await?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))?;
+
+                let now = $crate::client_sync::unix_time_now();
+                Ok($crate::client_sync::v17::MempoolCandidate::assess(
+                    txid,
+                    &entry.0,
+                    info.mempool_min_fee,
+                    None,
+                    options,
+                    now,
+                ))
+            }
+        }
+    };
+}
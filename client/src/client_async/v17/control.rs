@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Control ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getmemoryinfo`
+#[macro_export]
+macro_rules! impl_async_client_v17__getmemoryinfo {
+    () => {
+        impl Client {
+            /// Gets general statistics about memory usage in the daemon.
+            pub async fn get_memory_info(&self) -> Result<GetMemoryInfoStats> {
+                let mode = $crate::client_sync::v17::MemoryInfoMode::Stats;
+                self.call("getmemoryinfo", &[into_json(mode)?]).await
+            }
+
+            /// Gets the XML document describing low-level heap state.
+            ///
+            /// Only available if `bitcoind` was compiled with glibc, Core returns an RPC error
+            /// otherwise.
+            pub async fn get_memory_info_malloc_info(&self) -> Result<GetMemoryInfoMallocInfo> {
+                let mode = $crate::client_sync::v17::MemoryInfoMode::MallocInfo;
+                self.call("getmemoryinfo", &[into_json(mode)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `stop`
+#[macro_export]
+macro_rules! impl_async_client_v17__stop {
+    () => {
+        impl Client {
+            pub async fn stop(&self) -> Result<String> { self.call("stop", &[]).await }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `setmocktime`
+///
+/// This is a hidden, regtest-only, RPC (see `REGTEST_ONLY_METHODS`).
+#[macro_export]
+macro_rules! impl_async_client_v17__setmocktime {
+    () => {
+        impl Client {
+            /// Sets the node's local time to `timestamp` (seconds since epoch), `0` disables.
+            pub async fn set_mock_time(&self, timestamp: u64) -> Result<()> {
+                self.call("setmocktime", &[timestamp.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `logging`
+#[macro_export]
+macro_rules! impl_async_client_v17__logging {
+    () => {
+        impl Client {
+            /// Gets the debug logging status of each category.
+            pub async fn get_logging(&self) -> Result<Logging> { self.call("logging", &[]).await }
+
+            /// Adds `include` to and then removes `exclude` from the debug logging categories.
+            ///
+            /// Returns the resulting status of each category, a category in both lists ends up
+            /// excluded.
+            pub async fn logging(
+                &self,
+                include: &[$crate::json::model::LogCategory],
+                exclude: &[$crate::json::model::LogCategory],
+            ) -> Result<Logging> {
+                self.call("logging", &[into_json(include)?, into_json(exclude)?]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! An async JSON-RPC client for testing against Bitcoin Core `v0.17.1`.
+//!
+//! We ignore option arguments unless they effect the shape of the returned JSON data.
+
+pub mod blockchain;
+pub mod control;
+pub mod generating;
+pub mod mining;
+pub mod network;
+pub mod raw_transactions;
+pub mod util;
+pub mod wallet;
+
+use bitcoin::address::{Address, NetworkChecked};
+use bitcoin::{Amount, Block, BlockHash, Txid};
+
+use crate::client_sync::{handle_defaults, into_json};
+use crate::json::v17::*;
+
+crate::define_jsonrpc_reqwest_client!("v17");
+
+// == Blockchain ==
+crate::impl_async_client_v17__getblockchaininfo!();
+crate::impl_async_client_v17__getbestblockhash!();
+crate::impl_async_client_v17__getblock!();
+crate::impl_async_client_v17__getblockheader!();
+crate::impl_async_client_v17__getblockhash!();
+crate::impl_async_client_v17__getchaintips!();
+crate::impl_async_client_v17__preciousblock!();
+crate::impl_async_client_v17__invalidateblock!();
+crate::impl_async_client_v17__reconsiderblock!();
+crate::impl_async_client_v17__gettxout!();
+crate::impl_async_client_v17__gettxoutproof!();
+crate::impl_async_client_v17__verifytxoutproof!();
+crate::impl_async_client_v17__verifychain!();
+crate::impl_async_client_v17__gettxoutsetinfo!();
+crate::impl_async_client_v17__scantxoutset!();
+crate::impl_async_client_v17__getrawmempool!();
+crate::impl_async_client_v17__getmempoolentry!();
+crate::impl_async_client_v17__getmempoolinfo!();
+
+// == Control ==
+crate::impl_async_client_v17__getmemoryinfo!();
+crate::impl_async_client_v17__logging!();
+crate::impl_async_client_v17__stop!();
+crate::impl_async_client_v17__setmocktime!();
+
+// == Generating ==
+crate::impl_async_client_v17__generatetoaddress!();
+
+// == Mining ==
+crate::impl_async_client_v17__getmininginfo!();
+crate::impl_async_client_v17__getblocktemplate!();
+
+// == Network ==
+crate::impl_async_client_v17__getnetworkinfo!();
+crate::impl_async_client_v17__getpeerinfo!();
+crate::impl_async_client_check_expected_server_version!({ [170000, 170001, 170100, 170200] });
+
+// == Rawtransactions ==
+crate::impl_async_client_v17__combinepsbt!();
+crate::impl_async_client_v17__createrawtransaction!();
+crate::impl_async_client_v17__fundrawtransaction!();
+crate::impl_async_client_v17__getrawtransaction!();
+crate::impl_async_client_v17__decodepsbt!();
+crate::impl_async_client_v17__decoderawtransaction!();
+crate::impl_async_client_v17__finalizepsbt!();
+crate::impl_async_client_v17__sendrawtransaction!();
+crate::impl_async_client_v17__testmempoolaccept!();
+
+// == Util ==
+crate::impl_async_client_v17__estimatesmartfee!();
+crate::impl_async_client_v17__verifymessage!();
+
+// == Wallet ==
+crate::impl_async_client_v17__addmultisigaddress!();
+crate::impl_async_client_v17__createwallet!();
+crate::impl_async_client_v17__unloadwallet!();
+crate::impl_async_client_v17__loadwallet!();
+crate::impl_async_client_v17__getnewaddress!();
+crate::impl_async_client_v17__getrawchangeaddress!();
+crate::impl_async_client_v17__getaddressinfo!();
+crate::impl_async_client_v17__getwalletinfo!();
+crate::impl_async_client_v17__getbalance!();
+crate::impl_async_client_v17__sendtoaddress!();
+crate::impl_async_client_v17__gettransaction!();
+crate::impl_async_client_v17__listtransactions!();
+crate::impl_async_client_v17__listsinceblock!();
+crate::impl_async_client_v17__importmulti!();
+crate::impl_async_client_v17__importaddress!();
+crate::impl_async_client_v17__rescanblockchain!();
+crate::impl_async_client_v17__listreceivedbyaddress!();
+crate::impl_async_client_v17__listunspent!();
+crate::impl_async_client_v17__lockunspent!();
+crate::impl_async_client_v17__getaddressesbylabel!();
+crate::impl_async_client_v17__getreceivedbyaddress!();
+crate::impl_async_client_v17__bumpfee!();
+crate::impl_async_client_v17__walletcreatefundedpsbt!();
+crate::impl_async_client_v17__walletprocesspsbt!();
+crate::impl_async_client_v17__listwallets!();
+crate::impl_async_client_v17__encryptwallet!();
+crate::impl_async_client_v17__walletpassphrase!();
+crate::impl_async_client_v17__walletlock!();
+crate::impl_async_client_v17__walletpassphrasechange!();
+crate::impl_async_client_v17__signrawtransactionwithwallet!();
+crate::impl_async_client_v17__signmessage!();
+crate::impl_async_client_v17__accounts!();
+
+#[doc(inline)]
+pub use crate::client_sync::v17::{
+    AddressType, BroadcastOutcome, ChainFork, CreateRawTransactionInput,
+    CreateRawTransactionOutput, CreateWalletOptions, EstimateMode, FundRawTransactionOptions,
+    ImportMultiOptions, ImportMultiRequest, ImportMultiScriptPubkey, ImportTimestamp,
+    MemoryInfoMode, MempoolCandidate, MempoolCandidateOptions, ScanAction, ScanObject,
+    ScanRangeError, SendToAddressRequest, SighashType, TemplateRequest,
+    WalletCreateFundedPsbtOptions,
+};
@@ -0,0 +1,830 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `addmultisigaddress`
+#[macro_export]
+macro_rules! impl_async_client_v17__addmultisigaddress {
+    () => {
+        impl Client {
+            pub async fn add_multisig_address(
+                &self,
+                nrequired: u32,
+                keys: &[bitcoin::PublicKey],
+            ) -> Result<AddMultisigAddress> {
+                self.add_multisig_address_with_options(nrequired, keys, None, None).await
+            }
+
+            /// Adds a multisig address, assigned to `label`, using the given address type.
+            ///
+            /// Options left as `None` use the node's defaults (no label and `-addresstype`).
+            pub async fn add_multisig_address_with_options(
+                &self,
+                nrequired: u32,
+                keys: &[bitcoin::PublicKey],
+                label: Option<&str>,
+                address_type: Option<$crate::client_sync::v17::AddressType>,
+            ) -> Result<AddMultisigAddress> {
+                let keys = keys.iter().map(|key| key.to_string()).collect::<Vec<_>>();
+                let mut args = [
+                    nrequired.into(),
+                    into_json(keys)?,
+                    into_json(label)?,
+                    into_json(address_type)?,
+                ];
+                self.call(
+                    "addmultisigaddress",
+                    handle_defaults(&mut args, &["".into(), serde_json::Value::Null]),
+                )
+                .await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `createwallet`
+#[macro_export]
+macro_rules! impl_async_client_v17__createwallet {
+    () => {
+        impl Client {
+            pub async fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
+                self.call("createwallet", &[wallet.into()]).await
+            }
+
+            /// Creates a wallet using the options set in `options`.
+            pub async fn create_wallet_with_options(
+                &self,
+                wallet: &str,
+                options: &$crate::client_sync::v17::CreateWalletOptions,
+            ) -> Result<CreateWallet> {
+                let args = [wallet.into(), into_json(options.disable_private_keys)?];
+                self.call("createwallet", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `unloadwallet`
+#[macro_export]
+macro_rules! impl_async_client_v17__unloadwallet {
+    () => {
+        impl Client {
+            pub async fn unload_wallet(&self, wallet: &str) -> Result<()> {
+                self.call("unloadwallet", &[wallet.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `loadwallet`
+#[macro_export]
+macro_rules! impl_async_client_v17__loadwallet {
+    () => {
+        impl Client {
+            pub async fn load_wallet(&self, wallet: &str) -> Result<LoadWallet> {
+                self.call("loadwallet", &[wallet.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getbalance`
+#[macro_export]
+macro_rules! impl_async_client_v17__getbalance {
+    () => {
+        impl Client {
+            pub async fn get_balance(&self) -> Result<GetBalance> {
+                self.call("getbalance", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getaddressesbylabel`
+///
+/// Also implements `addresses_with_balances_for_label`, this requires `Client` to implement
+/// `get_received_by_address` and `list_unspent`.
+#[macro_export]
+macro_rules! impl_async_client_v17__getaddressesbylabel {
+    () => {
+        impl Client {
+            pub async fn get_addresses_by_label(&self, label: &str) -> Result<GetAddressesByLabel> {
+                self.call("getaddressesbylabel", &[label.into()]).await
+            }
+
+            /// Gets the received and unspent balances of all the addresses assigned `label`.
+            ///
+            /// Combines `getaddressesbylabel`, `getreceivedbyaddress` and `listunspent`, the
+            /// returned summaries are ordered by address.
+            pub async fn addresses_with_balances_for_label(
+                &self,
+                label: &str,
+            ) -> Result<Vec<$crate::json::model::LabelAddressBalance>> {
+                use $crate::client_sync::Error;
+
+                let addresses = self
+                    .get_addresses_by_label(label)
+                    .await?
+                    .into_model()
+                    .map_err(|e| Error::IntoModel(Box::new(e)))?;
+                let unspent = self
+                    .list_unspent()
+                    .await?
+                    .into_model()
+                    .map_err(|e| Error::IntoModel(Box::new(e)))?;
+
+                let mut summaries = Vec::with_capacity(addresses.0.len());
+                for (address, info) in addresses.0 {
+                    // Addresses returned by the node are valid for the node's network.
+                    let checked = address.clone().assume_checked();
+                    let received = self
+                        .get_received_by_address(&checked)
+                        .await?
+                        .into_model()
+                        .map_err(|e| Error::IntoModel(Box::new(e)))?
+                        .0;
+                    let (unspent, unspent_count) = unspent
+                        .0
+                        .iter()
+                        .filter(|utxo| utxo.address.as_ref() == Some(&address))
+                        .fold((bitcoin::Amount::ZERO, 0), |(sum, count), utxo| {
+                            (sum + utxo.amount, count + 1)
+                        });
+                    summaries.push($crate::json::model::LabelAddressBalance {
+                        address,
+                        purpose: info.purpose,
+                        received,
+                        unspent,
+                        unspent_count,
+                    });
+                }
+                Ok(summaries)
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getreceivedbyaddress`
+#[macro_export]
+macro_rules! impl_async_client_v17__getreceivedbyaddress {
+    () => {
+        impl Client {
+            pub async fn get_received_by_address(
+                &self,
+                address: &Address<NetworkChecked>,
+            ) -> Result<GetReceivedByAddress> {
+                self.call("getreceivedbyaddress", &[address.to_string().into()]).await
+            }
+
+            /// Only includes transactions with at least `minconf` confirmations, pass zero to
+            /// include transactions in the mempool.
+            pub async fn get_received_by_address_with_minconf(
+                &self,
+                address: &Address<NetworkChecked>,
+                minconf: u32,
+            ) -> Result<GetReceivedByAddress> {
+                let args = [address.to_string().into(), minconf.into()];
+                self.call("getreceivedbyaddress", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getnewaddress`
+#[macro_export]
+macro_rules! impl_async_client_v17__getnewaddress {
+    () => {
+        impl Client {
+            /// Gets a new address from `bitcoind` and parses it assuming its correct.
+            pub async fn new_address(&self) -> Result<bitcoin::Address> {
+                use core::str::FromStr;
+
+                let json = self.get_new_address().await?;
+                let address = bitcoin::Address::from_str(&json.0)
+                    .expect("assume the address is valid")
+                    .assume_checked(); // Assume bitcoind will return an invalid address for the network its on.
+                Ok(address)
+            }
+
+            /// Gets a new address from `bitcoind` and parses it assuming its correct.
+            pub async fn new_address_with_type(&self, ty: AddressType) -> Result<bitcoin::Address> {
+                use core::str::FromStr;
+
+                let json = self.get_new_address_with_type(ty).await?;
+                let address = bitcoin::Address::from_str(&json.0)
+                    .expect("assume the address is valid")
+                    .assume_checked(); // Assume bitcoind will return an invalid address for the network its on.
+                Ok(address)
+            }
+
+            pub async fn get_new_address(&self) -> Result<GetNewAddress> {
+                self.call("getnewaddress", &[]).await
+            }
+
+            pub async fn get_new_address_with_type(
+                &self,
+                ty: AddressType,
+            ) -> Result<GetNewAddress> {
+                self.call("getnewaddress", &["".into(), into_json(ty)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `sendtoaddress`
+#[macro_export]
+macro_rules! impl_async_client_v17__sendtoaddress {
+    () => {
+        impl Client {
+            pub async fn send_to_address(
+                &self,
+                address: &Address<NetworkChecked>,
+                amount: Amount,
+            ) -> Result<SendToAddress> {
+                let mut args = [address.to_string().into(), into_json(amount.to_btc())?];
+                self.call("sendtoaddress", handle_defaults(&mut args, &["".into(), "".into()]))
+                    .await
+            }
+
+            /// Sends to an address using the options set in `request`.
+            ///
+            /// Options left as `None` are sent as `null`, which Core treats as not given.
+            pub async fn send_to_address_with(
+                &self,
+                request: &$crate::client_sync::v17::SendToAddressRequest,
+            ) -> Result<SendToAddress> {
+                let args = [
+                    request.address.to_string().into(),
+                    into_json(request.amount.to_btc())?,
+                    into_json(&request.comment)?,
+                    into_json(&request.comment_to)?,
+                    into_json(request.subtract_fee)?,
+                    into_json(request.replaceable)?,
+                    into_json(request.conf_target)?,
+                    into_json(request.estimate_mode)?,
+                ];
+                self.call("sendtoaddress", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gettransaction`
+#[macro_export]
+macro_rules! impl_async_client_v17__gettransaction {
+    () => {
+        impl Client {
+            pub async fn get_transaction(&self, txid: Txid) -> Result<GetTransaction> {
+                self.call("gettransaction", &[into_json(txid)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listtransactions`
+#[macro_export]
+macro_rules! impl_async_client_v17__listtransactions {
+    () => {
+        impl Client {
+            pub async fn list_transactions(&self) -> Result<ListTransactions> {
+                self.call("listtransactions", &[]).await
+            }
+
+            /// Lists at most `count` of the wallet's most recent transactions, skipping the `skip`
+            /// most recent.
+            pub async fn list_transactions_page(
+                &self,
+                count: usize,
+                skip: usize,
+            ) -> Result<ListTransactions> {
+                self.call("listtransactions", &["*".into(), count.into(), skip.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listsinceblock`
+#[macro_export]
+macro_rules! impl_async_client_v17__listsinceblock {
+    () => {
+        impl Client {
+            pub async fn list_since_block(&self) -> Result<ListSinceBlock> {
+                self.call("listsinceblock", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listunspent`
+#[macro_export]
+macro_rules! impl_async_client_v17__listunspent {
+    () => {
+        impl Client {
+            pub async fn list_unspent(&self) -> Result<ListUnspent> {
+                self.call("listunspent", &[]).await
+            }
+
+            /// Converts `utxos` (e.g. selected from `list_unspent`) into PSBT inputs, in order.
+            ///
+            /// The previous transactions of non-segwit outputs are fetched with a single batch of
+            /// `getrawtransaction` calls, which requires the node to run with `-txindex` unless the
+            /// transactions are still in the mempool.
+            pub async fn psbt_inputs_for_unspent(
+                &self,
+                utxos: &[$crate::json::model::ListUnspentItem],
+            ) -> Result<Vec<$crate::bitcoin::psbt::Input>> {
+                use $crate::bitcoin::consensus::encode;
+                use $crate::bitcoin::Transaction;
+                use $crate::client_sync::Error;
+
+                let mut txids = utxos
+                    .iter()
+                    .filter(|utxo| utxo.needs_prev_tx())
+                    .map(|utxo| utxo.txid)
+                    .collect::<Vec<_>>();
+                txids.sort();
+                txids.dedup();
+
+                let args = txids
+                    .iter()
+                    .map(|txid| Ok(vec![into_json(txid)?]))
+                    .collect::<Result<Vec<_>>>()?;
+                let hexes: Vec<String> = self.call_batch("getrawtransaction", &args).await?;
+                let mut prev_txs = std::collections::BTreeMap::new();
+                for (txid, hex) in txids.into_iter().zip(hexes) {
+                    prev_txs.insert(txid, encode::deserialize_hex::<Transaction>(&hex)?);
+                }
+
+                utxos
+                    .iter()
+                    .map(|utxo| {
+                        let prev_tx = prev_txs.get(&utxo.txid).cloned();
+                        utxo.to_psbt_input(prev_tx).map_err(|e| Error::IntoModel(Box::new(e)))
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API methods `lockunspent` and `listlockunspent`
+#[macro_export]
+macro_rules! impl_async_client_v17__lockunspent {
+    () => {
+        impl Client {
+            /// Locks (or unlocks if `unlock` is true) `outpoints`, locked outputs are not selected
+            /// when funding transactions.
+            ///
+            /// Locks are held in memory only and are released when the node restarts.
+            pub async fn lock_unspent(
+                &self,
+                unlock: bool,
+                outpoints: &[bitcoin::OutPoint],
+            ) -> Result<bool> {
+                let outpoints = $crate::json::serde_outpoint::vec::serialize(
+                    outpoints,
+                    serde_json::value::Serializer,
+                )?;
+                self.call("lockunspent", &[unlock.into(), outpoints]).await
+            }
+
+            pub async fn list_lock_unspent(&self) -> Result<ListLockUnspent> {
+                self.call("listlockunspent", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `bumpfee`
+#[macro_export]
+macro_rules! impl_async_client_v17__bumpfee {
+    () => {
+        impl Client {
+            pub async fn bump_fee(&self, txid: Txid) -> Result<BumpFee> {
+                self.call("bumpfee", &[into_json(txid)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `walletcreatefundedpsbt`
+#[macro_export]
+macro_rules! impl_async_client_v17__walletcreatefundedpsbt {
+    () => {
+        impl Client {
+            pub async fn wallet_create_funded_psbt(
+                &self,
+                inputs: &[$crate::client_sync::v17::CreateRawTransactionInput],
+                outputs: &[$crate::client_sync::v17::CreateRawTransactionOutput],
+            ) -> Result<WalletCreateFundedPsbt> {
+                self.call("walletcreatefundedpsbt", &[into_json(inputs)?, into_json(outputs)?])
+                    .await
+            }
+
+            /// Creates and funds a PSBT using `options`, the locktime is zero.
+            pub async fn wallet_create_funded_psbt_with_options(
+                &self,
+                inputs: &[$crate::client_sync::v17::CreateRawTransactionInput],
+                outputs: &[$crate::client_sync::v17::CreateRawTransactionOutput],
+                options: &WalletCreateFundedPsbtOptions,
+            ) -> Result<WalletCreateFundedPsbt> {
+                let args = [into_json(inputs)?, into_json(outputs)?, 0.into(), into_json(options)?];
+                self.call("walletcreatefundedpsbt", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `walletprocesspsbt`
+#[macro_export]
+macro_rules! impl_async_client_v17__walletprocesspsbt {
+    () => {
+        impl Client {
+            pub async fn wallet_process_psbt(
+                &self,
+                psbt: &bitcoin::Psbt,
+            ) -> Result<WalletProcessPsbt> {
+                self.call("walletprocesspsbt", &[into_json(psbt.to_string())?]).await
+            }
+
+            pub async fn wallet_process_psbt_with_options(
+                &self,
+                psbt: &bitcoin::Psbt,
+                sign: bool,
+                sighash_type: $crate::client_sync::v17::SighashType,
+                bip32derivs: bool,
+            ) -> Result<WalletProcessPsbt> {
+                let args = [
+                    into_json(psbt.to_string())?,
+                    sign.into(),
+                    into_json(sighash_type)?,
+                    bip32derivs.into(),
+                ];
+                self.call("walletprocesspsbt", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `signrawtransactionwithwallet`
+#[macro_export]
+macro_rules! impl_async_client_v17__signrawtransactionwithwallet {
+    () => {
+        impl Client {
+            pub async fn sign_raw_transaction_with_wallet(
+                &self,
+                tx: &bitcoin::Transaction,
+            ) -> Result<SignRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("signrawtransactionwithwallet", &[hex.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listwallets`
+#[macro_export]
+macro_rules! impl_async_client_v17__listwallets {
+    () => {
+        impl Client {
+            pub async fn list_wallets(&self) -> Result<ListWallets> {
+                self.call("listwallets", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `encryptwallet`
+#[macro_export]
+macro_rules! impl_async_client_v17__encryptwallet {
+    () => {
+        impl Client {
+            pub async fn encrypt_wallet(&self, passphrase: &str) -> Result<EncryptWallet> {
+                self.call("encryptwallet", &[passphrase.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `walletpassphrase`
+#[macro_export]
+macro_rules! impl_async_client_v17__walletpassphrase {
+    () => {
+        impl Client {
+            /// Unlocks the wallet for `timeout`, rounded down to whole seconds.
+            pub async fn wallet_passphrase(
+                &self,
+                passphrase: &str,
+                timeout: std::time::Duration,
+            ) -> Result<()> {
+                self.call("walletpassphrase", &[passphrase.into(), timeout.as_secs().into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `walletlock`
+#[macro_export]
+macro_rules! impl_async_client_v17__walletlock {
+    () => {
+        impl Client {
+            pub async fn wallet_lock(&self) -> Result<()> { self.call("walletlock", &[]).await }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `walletpassphrasechange`
+#[macro_export]
+macro_rules! impl_async_client_v17__walletpassphrasechange {
+    () => {
+        impl Client {
+            pub async fn wallet_passphrase_change(
+                &self,
+                old_passphrase: &str,
+                new_passphrase: &str,
+            ) -> Result<()> {
+                self.call("walletpassphrasechange", &[old_passphrase.into(), new_passphrase.into()])
+                    .await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `importaddress`
+#[macro_export]
+macro_rules! impl_async_client_v17__importaddress {
+    () => {
+        impl Client {
+            /// Imports `address` as watch-only, rescanning the chain for its transactions.
+            ///
+            /// Not available for descriptor wallets.
+            pub async fn import_address(&self, address: &Address<NetworkChecked>) -> Result<()> {
+                self.call("importaddress", &[address.to_string().into()]).await
+            }
+
+            /// Imports an address, or a script, as watch-only.
+            ///
+            /// Not available for descriptor wallets.
+            pub async fn import_address_with_options(
+                &self,
+                target: &$crate::client_sync::v17::ImportAddressTarget,
+                options: &$crate::client_sync::v17::ImportAddressOptions,
+            ) -> Result<()> {
+                let args = [
+                    into_json(target)?,
+                    options.label.clone().into(),
+                    options.rescan.into(),
+                    options.p2sh.into(),
+                ];
+                self.call("importaddress", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `rescanblockchain`
+#[macro_export]
+macro_rules! impl_async_client_v17__rescanblockchain {
+    () => {
+        impl Client {
+            /// Rescans the whole chain for wallet transactions.
+            pub async fn rescan_blockchain(&self) -> Result<RescanBlockchain> {
+                self.call("rescanblockchain", &[]).await
+            }
+
+            /// Rescans the chain for wallet transactions from `start_height` up to and including
+            /// `stop_height`, or the chain tip if `None`.
+            pub async fn rescan_blockchain_range(
+                &self,
+                start_height: u32,
+                stop_height: Option<u32>,
+            ) -> Result<RescanBlockchain> {
+                let mut args = vec![into_json(start_height)?];
+                if let Some(stop_height) = stop_height {
+                    args.push(into_json(stop_height)?);
+                }
+                self.call("rescanblockchain", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listreceivedbyaddress`
+#[macro_export]
+macro_rules! impl_async_client_v17__listreceivedbyaddress {
+    () => {
+        impl Client {
+            pub async fn list_received_by_address(
+                &self,
+                minconf: u32,
+                include_empty: bool,
+                include_watch_only: bool,
+                address_filter: Option<&Address<NetworkChecked>>,
+            ) -> Result<ListReceivedByAddress> {
+                let mut args = vec![
+                    into_json(minconf)?,
+                    into_json(include_empty)?,
+                    into_json(include_watch_only)?,
+                ];
+                if let Some(address) = address_filter {
+                    args.push(address.to_string().into());
+                }
+                self.call("listreceivedbyaddress", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `importmulti`
+#[macro_export]
+macro_rules! impl_async_client_v17__importmulti {
+    () => {
+        impl Client {
+            pub async fn import_multi(
+                &self,
+                requests: &[$crate::client_sync::v17::ImportMultiRequest],
+            ) -> Result<ImportMulti> {
+                self.call("importmulti", &[into_json(requests)?]).await
+            }
+
+            pub async fn import_multi_with_options(
+                &self,
+                requests: &[$crate::client_sync::v17::ImportMultiRequest],
+                options: &$crate::client_sync::v17::ImportMultiOptions,
+            ) -> Result<ImportMulti> {
+                self.call("importmulti", &[into_json(requests)?, into_json(options)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getrawchangeaddress`
+#[macro_export]
+macro_rules! impl_async_client_v17__getrawchangeaddress {
+    () => {
+        impl Client {
+            pub async fn get_raw_change_address(&self) -> Result<GetRawChangeAddress> {
+                self.call("getrawchangeaddress", &[]).await
+            }
+
+            pub async fn get_raw_change_address_with_type(
+                &self,
+                ty: AddressType,
+            ) -> Result<GetRawChangeAddress> {
+                self.call("getrawchangeaddress", &[into_json(ty)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getaddressinfo`
+#[macro_export]
+macro_rules! impl_async_client_v17__getaddressinfo {
+    () => {
+        impl Client {
+            pub async fn get_address_info(
+                &self,
+                address: &Address<NetworkChecked>,
+            ) -> Result<GetAddressInfo> {
+                self.call("getaddressinfo", &[address.to_string().into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getwalletinfo`
+#[macro_export]
+macro_rules! impl_async_client_v17__getwalletinfo {
+    () => {
+        impl Client {
+            pub async fn get_wallet_info(&self) -> Result<GetWalletInfo> {
+                self.call("getwalletinfo", &[]).await
+            }
+
+            /// Returns the address types the wallet generates by default (`-addresstype` and
+            /// `-changetype`).
+            ///
+            /// Core does not report these options, they are found by generating a receive and a
+            /// change address without an explicit type and calling `getaddressinfo` on them. This
+            /// uses up a key from each of the wallet's key pools.
+            pub async fn address_type_defaults(
+                &self,
+            ) -> Result<$crate::json::model::AddressTypeDefaults> {
+                use $crate::client_sync::Error;
+                use $crate::json::model;
+
+                let info = self
+                    .get_wallet_info()
+                    .await?
+                    .into_model()
+                    .map_err(|e| Error::IntoModel(Box::new(e)))?;
+                let receive = self
+                    .get_new_address()
+                    .await?
+                    .address()
+                    .map_err(|e| Error::IntoModel(Box::new(e)))?;
+                let change = self
+                    .get_raw_change_address()
+                    .await?
+                    .into_model()
+                    .map_err(|e| Error::IntoModel(Box::new(e)))?
+                    .0;
+
+                let mut types = Vec::with_capacity(2);
+                for address in [receive.assume_checked(), change.assume_checked()].iter() {
+                    let info = self
+                        .call::<GetAddressInfo>("getaddressinfo", &[address.to_string().into()])
+                        .await?
+                        .into_model()
+                        .map_err(|e| Error::IntoModel(Box::new(e)))?;
+                    types.push(info.address_type().ok_or(Error::UnexpectedStructure)?);
+                }
+
+                Ok(model::AddressTypeDefaults {
+                    wallet_name: info.wallet_name,
+                    descriptors: info.descriptors,
+                    address_type: types[0],
+                    change_type: types[1],
+                })
+            }
+        }
+    };
+}
+
+/// Implements the deprecated bitcoind JSON-RPC API account methods.
+///
+/// Requires `bitcoind` to be started with `-deprecatedrpc=accounts`, the methods were removed in
+/// v0.18 so this macro is only used by the `v17` client.
+#[macro_export]
+macro_rules! impl_async_client_v17__accounts {
+    () => {
+        impl Client {
+            #[deprecated(note = "requires `-deprecatedrpc=accounts`, use labels instead")]
+            pub async fn get_account(
+                &self,
+                address: &Address<NetworkChecked>,
+            ) -> Result<GetAccount> {
+                self.call("getaccount", &[address.to_string().into()]).await
+            }
+
+            #[deprecated(note = "requires `-deprecatedrpc=accounts`, use labels instead")]
+            pub async fn get_account_address(&self, account: &str) -> Result<GetAccountAddress> {
+                self.call("getaccountaddress", &[account.into()]).await
+            }
+
+            #[deprecated(note = "requires `-deprecatedrpc=accounts`, use labels instead")]
+            pub async fn get_addresses_by_account(
+                &self,
+                account: &str,
+            ) -> Result<GetAddressesByAccount> {
+                self.call("getaddressesbyaccount", &[account.into()]).await
+            }
+
+            #[deprecated(note = "requires `-deprecatedrpc=accounts`, use labels instead")]
+            pub async fn get_received_by_account(
+                &self,
+                account: &str,
+            ) -> Result<GetReceivedByAccount> {
+                self.call("getreceivedbyaccount", &[account.into()]).await
+            }
+
+            #[deprecated(note = "requires `-deprecatedrpc=accounts`, use labels instead")]
+            pub async fn list_accounts(&self) -> Result<ListAccounts> {
+                self.call("listaccounts", &[]).await
+            }
+
+            #[deprecated(note = "requires `-deprecatedrpc=accounts`, use labels instead")]
+            pub async fn set_account(
+                &self,
+                address: &Address<NetworkChecked>,
+                account: &str,
+            ) -> Result<()> {
+                self.call("setaccount", &[address.to_string().into(), account.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `signmessage`
+#[macro_export]
+macro_rules! impl_async_client_v17__signmessage {
+    () => {
+        impl Client {
+            /// Signs `message` with the private key of `address`, a P2PKH address of the wallet.
+            pub async fn sign_message(
+                &self,
+                address: &Address<NetworkChecked>,
+                message: &str,
+            ) -> Result<SignMessage> {
+                self.call("signmessage", &[address.to_string().into(), message.into()]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Requires `Client` to be in scope.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+//!
+//! See, or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `createwallet`
+#[macro_export]
+macro_rules! impl_async_client_v18__createwallet {
+    () => {
+        impl Client {
+            pub async fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
+                self.call("createwallet", &[wallet.into()]).await
+            }
+
+            /// Creates a wallet using the options set in `options`.
+            pub async fn create_wallet_with_options(
+                &self,
+                wallet: &str,
+                options: &$crate::client_sync::v18::CreateWalletOptions,
+            ) -> Result<CreateWallet> {
+                let args = [
+                    wallet.into(),
+                    into_json(options.disable_private_keys)?,
+                    into_json(options.blank)?,
+                ];
+                self.call("createwallet", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listwalletdir`
+///
+/// Also implements `wallet_inventory`, requires `list_wallets` to be implemented.
+#[macro_export]
+macro_rules! impl_async_client_v18__listwalletdir {
+    () => {
+        impl Client {
+            pub async fn list_wallet_dir(&self) -> Result<ListWalletDir> {
+                self.call("listwalletdir", &[]).await
+            }
+
+            /// Compares the loaded wallets with the wallets in the wallet directory.
+            ///
+            /// If `probe` is true each unloaded wallet is loaded, and unloaded again, to check that
+            /// it is readable. Wallets that fail to load are moved to
+            /// [`WalletInventory::unreadable`](crate::json::model::WalletInventory::unreadable).
+            pub async fn wallet_inventory(
+                &self,
+                probe: bool,
+            ) -> Result<$crate::json::model::WalletInventory> {
+                let loaded = self.list_wallets().await?.into_model();
+                let on_disk = self.list_wallet_dir().await?.into_model();
+                let mut inventory = $crate::json::model::WalletInventory::new(&loaded, &on_disk);
+
+                if probe {
+                    for name in inventory.unloaded.clone() {
+                        // The return type of these methods differs across versions.
+                        match self
+                            .call::<serde_json::Value>("loadwallet", &[name.clone().into()])
+                            .await
+                        {
+                            Ok(_) => {
+                                let _: serde_json::Value =
+                                    self.call("unloadwallet", &[name.into()]).await?;
+                            }
+                            Err(e) => inventory.mark_unreadable(&name, e.to_string()),
+                        }
+                    }
+                }
+                Ok(inventory)
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getreceivedbylabel`
+#[macro_export]
+macro_rules! impl_async_client_v18__getreceivedbylabel {
+    () => {
+        impl Client {
+            pub async fn get_received_by_label(&self, label: &str) -> Result<GetReceivedByLabel> {
+                self.call("getreceivedbylabel", &[label.into()]).await
+            }
+
+            /// Only includes transactions with at least `minconf` confirmations, pass zero to
+            /// include transactions in the mempool.
+            pub async fn get_received_by_label_with_minconf(
+                &self,
+                label: &str,
+                minconf: u32,
+            ) -> Result<GetReceivedByLabel> {
+                self.call("getreceivedbylabel", &[label.into(), minconf.into()]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.19.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `createwallet`
+#[macro_export]
+macro_rules! impl_async_client_v19__createwallet {
+    () => {
+        impl Client {
+            pub async fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
+                self.call("createwallet", &[wallet.into()]).await
+            }
+
+            /// Creates a wallet using the options set in `options`.
+            pub async fn create_wallet_with_options(
+                &self,
+                wallet: &str,
+                options: &$crate::client_sync::v19::CreateWalletOptions,
+            ) -> Result<CreateWallet> {
+                let args = [
+                    wallet.into(),
+                    into_json(options.disable_private_keys)?,
+                    into_json(options.blank)?,
+                    into_json(&options.passphrase)?,
+                    into_json(options.avoid_reuse)?,
+                ];
+                self.call("createwallet", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getbalances`
+#[macro_export]
+macro_rules! impl_async_client_v19__getbalances {
+    () => {
+        impl Client {
+            pub async fn get_balances(&self) -> Result<GetBalances> {
+                self.call("getbalances", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gettransaction` with `verbose` set.
+#[macro_export]
+macro_rules! impl_async_client_v19__gettransaction {
+    () => {
+        impl Client {
+            pub async fn get_transaction_verbose(&self, txid: Txid) -> Result<GetTransaction> {
+                // Pass null for `include_watchonly` so Core picks the default for the wallet.
+                self.call(
+                    "gettransaction",
+                    &[into_json(txid)?, serde_json::Value::Null, true.into()],
+                )
+                .await
+            }
+        }
+    };
+}
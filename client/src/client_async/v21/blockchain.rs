@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getindexinfo`
+#[macro_export]
+macro_rules! impl_async_client_v21__getindexinfo {
+    () => {
+        impl Client {
+            pub async fn get_index_info(&self) -> Result<GetIndexInfo> {
+                self.call("getindexinfo", &[]).await
+            }
+
+            /// Returns the status of the index `name`, the result is empty if it is not enabled.
+            pub async fn get_index_info_for(&self, name: &str) -> Result<GetIndexInfo> {
+                self.call("getindexinfo", &[name.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gettxoutsetinfo` with the `hash_type` argument
+#[macro_export]
+macro_rules! impl_async_client_v21__gettxoutsetinfo {
+    () => {
+        impl Client {
+            pub async fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[]).await
+            }
+
+            /// Returns UTXO set statistics, calculating the hash of the UTXO set using `hash_type`.
+            pub async fn get_tx_out_set_info_with_hash_type(
+                &self,
+                hash_type: $crate::client_sync::v21::TxOutSetHashType,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[into_json(hash_type)?]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `createwallet`
+#[macro_export]
+macro_rules! impl_async_client_v21__createwallet {
+    () => {
+        impl Client {
+            pub async fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
+                self.call("createwallet", &[wallet.into()]).await
+            }
+
+            /// Creates a wallet using the options set in `options`.
+            pub async fn create_wallet_with_options(
+                &self,
+                wallet: &str,
+                options: &$crate::client_sync::v21::CreateWalletOptions,
+            ) -> Result<CreateWallet> {
+                let args = [
+                    wallet.into(),
+                    into_json(options.disable_private_keys)?,
+                    into_json(options.blank)?,
+                    into_json(&options.passphrase)?,
+                    into_json(options.avoid_reuse)?,
+                    into_json(options.descriptors)?,
+                    into_json(options.load_on_startup)?,
+                ];
+                self.call("createwallet", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `bumpfee`
+#[macro_export]
+macro_rules! impl_async_client_v21__bumpfee {
+    () => {
+        impl Client {
+            pub async fn bump_fee(&self, txid: Txid) -> Result<BumpFee> {
+                self.call("bumpfee", &[into_json(txid)?]).await
+            }
+
+            pub async fn bump_fee_with_options(
+                &self,
+                txid: Txid,
+                options: &BumpFeeOptions,
+            ) -> Result<BumpFee> {
+                self.call("bumpfee", &[into_json(txid)?, into_json(options)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `psbtbumpfee`
+#[macro_export]
+macro_rules! impl_async_client_v21__psbtbumpfee {
+    () => {
+        impl Client {
+            pub async fn psbt_bump_fee(&self, txid: Txid) -> Result<PsbtBumpFee> {
+                self.call("psbtbumpfee", &[into_json(txid)?]).await
+            }
+
+            pub async fn psbt_bump_fee_with_options(
+                &self,
+                txid: Txid,
+                options: &BumpFeeOptions,
+            ) -> Result<PsbtBumpFee> {
+                self.call("psbtbumpfee", &[into_json(txid)?, into_json(options)?]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `importdescriptors`
+#[macro_export]
+macro_rules! impl_async_client_v21__importdescriptors {
+    () => {
+        impl Client {
+            pub async fn import_descriptors(
+                &self,
+                requests: &[$crate::client_sync::v21::ImportDescriptorsRequest],
+            ) -> Result<ImportDescriptors> {
+                self.call("importdescriptors", &[into_json(requests)?]).await
+            }
+
+            /// Imports `requests` in calls of at most `chunk_size` descriptors.
+            ///
+            /// Descriptors that fail to import are retried, in later calls, up to `max_retries`
+            /// times. A call to the node that fails (e.g. a timeout) counts as a failed attempt
+            /// for each descriptor in the chunk, importing a descriptor again is harmless so they
+            /// are retried in the same way. `on_progress` is called after each call to the node.
+            pub async fn import_descriptors_chunked<F>(
+                &self,
+                requests: &[$crate::client_sync::v21::ImportDescriptorsRequest],
+                chunk_size: std::num::NonZeroUsize,
+                max_retries: u32,
+                mut on_progress: F,
+            ) -> Result<$crate::client_sync::v21::ImportDescriptorsReport>
+            where
+                F: FnMut(&$crate::client_sync::v21::ImportDescriptorsProgress),
+            {
+                use $crate::client_sync::v21::{
+                    FailedImport, ImportDescriptorsProgress, ImportDescriptorsReport,
+                };
+                use $crate::json::model::{ImportDescriptorsResult, JsonRpcError};
+
+                let total = requests.len();
+                let mut report = ImportDescriptorsReport::default();
+                let mut attempts = vec![0_u32; total];
+                let mut pending = (0..total).collect::<Vec<_>>();
+
+                while !pending.is_empty() {
+                    let mut retry = vec![];
+                    for chunk in pending.chunks(chunk_size.get()) {
+                        let batch = chunk.iter().map(|&i| &requests[i]).collect::<Vec<_>>();
+                        let json: Result<ImportDescriptors> =
+                            self.call("importdescriptors", &[into_json(&batch)?]).await;
+                        let results = match json {
+                            Ok(json) => json.into_model().0,
+                            Err(e) => {
+                                let error = JsonRpcError {
+                                    code: e.rpc_code().unwrap_or(0),
+                                    message: e.to_string(),
+                                };
+                                let result = ImportDescriptorsResult {
+                                    success: false,
+                                    warnings: vec![],
+                                    error: Some(error),
+                                };
+                                vec![result; chunk.len()]
+                            }
+                        };
+                        if results.len() != chunk.len() {
+                            return Err($crate::client_sync::Error::UnexpectedStructure);
+                        }
+
+                        for (&i, result) in chunk.iter().zip(results) {
+                            attempts[i] += 1;
+                            if result.success {
+                                report.imported += 1;
+                                if !result.warnings.is_empty() {
+                                    report.warnings.push((i, result.warnings));
+                                }
+                            } else if attempts[i] > max_retries {
+                                let error = result.error.unwrap_or_else(|| JsonRpcError {
+                                    code: 0,
+                                    message: "no error returned".to_owned(),
+                                });
+                                report.failed.push(FailedImport {
+                                    index: i,
+                                    descriptor: requests[i].descriptor.clone(),
+                                    error,
+                                    attempts: attempts[i],
+                                });
+                            } else {
+                                retry.push(i);
+                            }
+                        }
+
+                        on_progress(&ImportDescriptorsProgress {
+                            total,
+                            imported: report.imported,
+                            failed: report.failed.len(),
+                            retrying: retry.len(),
+                        });
+                    }
+                    pending = retry;
+                }
+
+                report.warnings.sort_by_key(|(i, _)| *i);
+                report.failed.sort_by_key(|f| f.index);
+                Ok(report)
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `send`
+#[macro_export]
+macro_rules! impl_async_client_v21__send {
+    () => {
+        impl Client {
+            pub async fn send(
+                &self,
+                params: &$crate::client_sync::v21::SendParams,
+            ) -> Result<Send> {
+                // 1 vB is 4 wu, so sat/vB is sat/kwu divided by 250.
+                let fee_rate = params.fee_rate.map(|rate| rate.to_sat_per_kwu() as f64 / 250.0);
+                let args = [
+                    into_json(&params.outputs)?,
+                    into_json(params.conf_target)?,
+                    into_json(params.estimate_mode)?,
+                    into_json(fee_rate)?,
+                    into_json(&params.options)?,
+                ];
+                self.call("send", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `sendtoaddress`
+#[macro_export]
+macro_rules! impl_async_client_v21__sendtoaddress {
+    () => {
+        impl Client {
+            pub async fn send_to_address(
+                &self,
+                address: &Address<NetworkChecked>,
+                amount: Amount,
+            ) -> Result<SendToAddress> {
+                let mut args = [address.to_string().into(), into_json(amount.to_btc())?];
+                self.call("sendtoaddress", handle_defaults(&mut args, &["".into(), "".into()]))
+                    .await
+            }
+
+            /// Sends to an address using the options set in `request`.
+            ///
+            /// Options left as `None` are sent as `null`, which Core treats as not given.
+            pub async fn send_to_address_with(
+                &self,
+                request: &$crate::client_sync::v21::SendToAddressRequest,
+            ) -> Result<SendToAddress> {
+                // 1 vB is 4 wu, so sat/vB is sat/kwu divided by 250.
+                let fee_rate = request.fee_rate.map(|rate| rate.to_sat_per_kwu() as f64 / 250.0);
+                let args = [
+                    request.address.to_string().into(),
+                    into_json(request.amount.to_btc())?,
+                    into_json(&request.comment)?,
+                    into_json(&request.comment_to)?,
+                    into_json(request.subtract_fee)?,
+                    into_json(request.replaceable)?,
+                    into_json(request.conf_target)?,
+                    into_json(request.estimate_mode)?,
+                    into_json(request.avoid_reuse)?,
+                    into_json(fee_rate)?,
+                ];
+                self.call("sendtoaddress", &args).await
+            }
+        }
+    };
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v22.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `gettxoutsetinfo` with the `hash_or_height` argument
+///
+/// Requires `get_index_info_for` to be implemented.
+#[macro_export]
+macro_rules! impl_async_client_v22__gettxoutsetinfo {
+    () => {
+        impl Client {
+            pub async fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[]).await
+            }
+
+            /// Returns UTXO set statistics, calculating the hash of the UTXO set using `hash_type`.
+            pub async fn get_tx_out_set_info_with_hash_type(
+                &self,
+                hash_type: $crate::client_sync::v21::TxOutSetHashType,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[into_json(hash_type)?]).await
+            }
+
+            /// Returns UTXO set statistics at a historical block, using `coinstatsindex`.
+            ///
+            /// The MuHash of the UTXO set is calculated, the legacy hash is not supported when
+            /// querying the index. Returns [`Error::IndexNotAvailable`] if the node is not running
+            /// with `-coinstatsindex`.
+            ///
+            /// [`Error::IndexNotAvailable`]: crate::client_sync::Error::IndexNotAvailable
+            pub async fn get_tx_out_set_info_at(
+                &self,
+                at: $crate::client_sync::v22::HashOrHeight,
+            ) -> Result<GetTxOutSetInfo> {
+                const INDEX: &str = "coinstatsindex";
+
+                let index = self.get_index_info_for(INDEX).await?;
+                if !index.0.contains_key(INDEX) {
+                    return Err($crate::client_sync::Error::IndexNotAvailable(INDEX.to_owned()));
+                }
+                self.call("gettxoutsetinfo", &["muhash".into(), into_json(at)?, true.into()]).await
+            }
+        }
+    };
+}
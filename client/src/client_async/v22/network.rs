@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v22.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getnodeaddresses` with the `network` argument
+///
+/// Use along with `impl_async_client_v18__getnodeaddresses`.
+#[macro_export]
+macro_rules! impl_async_client_v22__getnodeaddresses {
+    () => {
+        impl Client {
+            /// Returns up to `count` known node addresses on `network`, 0 returns all of them.
+            pub async fn get_node_addresses_on_network(
+                &self,
+                count: usize,
+                network: &$crate::json::model::NetworkType,
+            ) -> Result<GetNodeAddresses> {
+                self.call("getnodeaddresses", &[count.into(), network.to_core_arg().into()]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v22.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `createwallet`
+#[macro_export]
+macro_rules! impl_async_client_v22__createwallet {
+    () => {
+        impl Client {
+            pub async fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
+                self.call("createwallet", &[wallet.into()]).await
+            }
+
+            /// Creates a wallet using the options set in `options`.
+            pub async fn create_wallet_with_options(
+                &self,
+                wallet: &str,
+                options: &$crate::client_sync::v22::CreateWalletOptions,
+            ) -> Result<CreateWallet> {
+                let args = [
+                    wallet.into(),
+                    into_json(options.disable_private_keys)?,
+                    into_json(options.blank)?,
+                    into_json(&options.passphrase)?,
+                    into_json(options.avoid_reuse)?,
+                    into_json(options.descriptors)?,
+                    into_json(options.load_on_startup)?,
+                    into_json(options.external_signer)?,
+                ];
+                self.call("createwallet", &args).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `unloadwallet`
+#[macro_export]
+macro_rules! impl_async_client_v22__unloadwallet {
+    () => {
+        impl Client {
+            pub async fn unload_wallet(&self, wallet: &str) -> Result<UnloadWallet> {
+                self.call("unloadwallet", &[wallet.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `loadwallet`
+#[macro_export]
+macro_rules! impl_async_client_v22__loadwallet {
+    () => {
+        impl Client {
+            pub async fn load_wallet(&self, wallet: &str) -> Result<LoadWallet> {
+                self.call("loadwallet", &[wallet.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `listdescriptors`
+#[macro_export]
+macro_rules! impl_async_client_v22__listdescriptors {
+    () => {
+        impl Client {
+            pub async fn list_descriptors(&self) -> Result<ListDescriptors> {
+                self.call("listdescriptors", &[]).await
+            }
+        }
+    };
+}
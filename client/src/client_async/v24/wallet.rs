@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v24.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `walletprocesspsbt` with the `finalize` argument
+#[macro_export]
+macro_rules! impl_async_client_v24__walletprocesspsbt {
+    () => {
+        impl Client {
+            pub async fn wallet_process_psbt(
+                &self,
+                psbt: &bitcoin::Psbt,
+            ) -> Result<WalletProcessPsbt> {
+                self.call("walletprocesspsbt", &[into_json(psbt.to_string())?]).await
+            }
+
+            pub async fn wallet_process_psbt_with_options(
+                &self,
+                psbt: &bitcoin::Psbt,
+                sign: bool,
+                sighash_type: $crate::client_sync::v17::SighashType,
+                bip32derivs: bool,
+                finalize: bool,
+            ) -> Result<WalletProcessPsbt> {
+                let args = [
+                    into_json(psbt.to_string())?,
+                    sign.into(),
+                    into_json(sighash_type)?,
+                    bip32derivs.into(),
+                    finalize.into(),
+                ];
+                self.call("walletprocesspsbt", &args).await
+            }
+        }
+    };
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v25`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `sendrawtransaction` with the `maxfeerate` and
+/// `maxburnamount` arguments.
+#[macro_export]
+macro_rules! impl_async_client_v25__sendrawtransaction {
+    () => {
+        impl Client {
+            /// Sends `tx`, rejecting it if its fee rate is above `max_fee_rate` or if it sends
+            /// more than `max_burn_amount` to provably unspendable outputs.
+            ///
+            /// Core's defaults are 0.10 BTC/kvB and zero respectively.
+            pub async fn send_raw_transaction_with_limits(
+                &self,
+                tx: &bitcoin::Transaction,
+                max_fee_rate: bitcoin::FeeRate,
+                max_burn_amount: Amount,
+            ) -> Result<SendRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                let max_fee_rate = $crate::client_sync::fee_rate_to_btc_per_kvb(max_fee_rate);
+                self.call(
+                    "sendrawtransaction",
+                    &[hex.into(), max_fee_rate.into(), max_burn_amount.to_btc().into()],
+                )
+                .await
+            }
+        }
+    };
+}
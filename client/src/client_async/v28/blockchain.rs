@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of the forthcoming `bitcoind v28`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_reqwest_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getdescriptoractivity`
+#[macro_export]
+macro_rules! impl_async_client_v28__getdescriptoractivity {
+    () => {
+        impl Client {
+            pub async fn get_descriptor_activity(
+                &self,
+                block_hashes: &[BlockHash],
+                descriptors: &[&str],
+            ) -> Result<$crate::json::v28::GetDescriptorActivity> {
+                let args = [into_json(block_hashes)?, into_json(descriptors)?];
+                self.call("getdescriptoractivity", &args).await
+            }
+        }
+    };
+}
@@ -2,7 +2,10 @@
 
 use std::{error, fmt, io};
 
-use bitcoin::{hex, secp256k1};
+use bitcoin::{hex, secp256k1, Amount, OutPoint, Txid};
+
+use crate::node_url::NodeUrlError;
+use crate::version::CoreVersion;
 
 /// The error type for errors produced in this library.
 #[derive(Debug)]
@@ -20,10 +23,34 @@ pub enum Error {
     UnexpectedStructure,
     /// The daemon returned an error string.
     Returned(String),
-    /// The server version did not match what was expected.
-    ServerVersion(UnexpectedServerVersionError),
+    /// The server version is not one of the versions supported by the client.
+    ServerVersion(UnsupportedVersionError),
     /// Missing user/password
     MissingUserPassword,
+    /// Refused to call a regtest-only method (safety mode is on and the node is on mainnet).
+    RegtestOnly(String),
+    /// Refused to call a method that is not in the client's allowed methods.
+    MethodNotAllowed(String),
+    /// Failed to convert a JSON result into its model type.
+    IntoModel(Box<dyn error::Error + Send + Sync + 'static>),
+    /// The node does not have a required index enabled (e.g. `coinstatsindex`).
+    IndexNotAvailable(String),
+    /// Failed to compute the fee of a transaction.
+    TxFee(TxFeeError),
+    /// The node could not find a transaction.
+    TxNotFound(TxNotFoundError),
+    /// The URL of the node is invalid.
+    InvalidUrl(NodeUrlError),
+}
+
+impl Error {
+    /// Returns the code of the error response returned by `bitcoind`, if this is an RPC error.
+    pub fn rpc_code(&self) -> Option<i32> {
+        match *self {
+            Error::JsonRpc(jsonrpc::error::Error::Rpc(ref e)) => Some(e.code),
+            _ => None,
+        }
+    }
 }
 
 impl From<jsonrpc::error::Error> for Error {
@@ -50,6 +77,10 @@ impl From<secp256k1::Error> for Error {
     fn from(e: secp256k1::Error) -> Error { Error::Secp256k1(e) }
 }
 
+impl From<NodeUrlError> for Error {
+    fn from(e: NodeUrlError) -> Error { Error::InvalidUrl(e) }
+}
+
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Error { Error::Io(e) }
 }
@@ -76,6 +107,15 @@ impl fmt::Display for Error {
             Returned(ref s) => write!(f, "the daemon returned an error string: {}", s),
             ServerVersion(ref e) => write!(f, "server version: {}", e),
             MissingUserPassword => write!(f, "missing user and/or password"),
+            RegtestOnly(ref method) =>
+                write!(f, "refusing to call regtest-only method `{}` on mainnet", method),
+            MethodNotAllowed(ref method) =>
+                write!(f, "method `{}` is not in the client's allowed methods", method),
+            IntoModel(ref e) => write!(f, "conversion into model type failed: {}", e),
+            IndexNotAvailable(ref index) => write!(f, "the node does not have `{}` enabled", index),
+            TxFee(ref e) => write!(f, "transaction fee: {}", e),
+            TxNotFound(ref e) => write!(f, "transaction not found: {}", e),
+            InvalidUrl(ref e) => write!(f, "invalid URL: {}", e),
         }
     }
 }
@@ -94,33 +134,136 @@ impl error::Error for Error {
             Io(ref e) => Some(e),
             InvalidAmount(ref e) => Some(e),
             ServerVersion(ref e) => Some(e),
-            InvalidCookieFile | UnexpectedStructure | Returned(_) | MissingUserPassword => None,
+            TxFee(ref e) => Some(e),
+            TxNotFound(ref e) => Some(e),
+            InvalidUrl(ref e) => Some(e),
+            IntoModel(ref e) => Some(&**e),
+            InvalidCookieFile | UnexpectedStructure | Returned(_) | MissingUserPassword
+            | RegtestOnly(_) | MethodNotAllowed(_) | IndexNotAvailable(_) => None,
         }
     }
 }
 
-/// Error returned when RPC client expects a different version than bitcoind reports.
+/// Error returned when the version of bitcoind is not one of the versions supported by the client.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct UnexpectedServerVersionError {
+pub struct UnsupportedVersionError {
     /// Version from server.
-    pub got: usize,
-    /// Expected server version.
-    pub expected: Vec<usize>,
+    pub got: CoreVersion,
+    /// The versions supported by the client.
+    pub expected: Vec<CoreVersion>,
+}
+
+impl fmt::Display for UnsupportedVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsupported bitcoind version {}, expected one of:", self.got)?;
+        for (i, version) in self.expected.iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            write!(f, "{}{}", sep, version)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for UnsupportedVersionError {}
+
+impl From<UnsupportedVersionError> for Error {
+    fn from(e: UnsupportedVersionError) -> Self { Self::ServerVersion(e) }
+}
+
+/// Error returned when the fee of a transaction can not be computed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxFeeError {
+    /// The transaction is a coinbase transaction, it has no previous outputs.
+    Coinbase,
+    /// The previous outputs could not be found e.g., they are spent and the node does not have
+    /// `-txindex` enabled (or is pruned).
+    UnknownPrevOuts(Vec<OutPoint>),
+    /// The outputs are worth more than the inputs.
+    OutputsExceedInputs {
+        /// The sum of the values of the previous outputs.
+        input: Amount,
+        /// The sum of the values of the outputs.
+        output: Amount,
+    },
+}
+
+impl fmt::Display for TxFeeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TxFeeError::*;
+
+        match *self {
+            Coinbase => write!(f, "a coinbase transaction does not pay a fee"),
+            UnknownPrevOuts(ref outpoints) => {
+                write!(f, "unknown previous outputs:")?;
+                for (i, outpoint) in outpoints.iter().enumerate() {
+                    let sep = if i == 0 { " " } else { ", " };
+                    write!(f, "{}{}", sep, outpoint)?;
+                }
+                Ok(())
+            }
+            OutputsExceedInputs { input, output } =>
+                write!(f, "outputs ({}) exceed inputs ({})", output, input),
+        }
+    }
+}
+
+impl error::Error for TxFeeError {}
+
+impl From<TxFeeError> for Error {
+    fn from(e: TxFeeError) -> Self { Self::TxFee(e) }
+}
+
+/// Error returned when `getrawtransaction` can not find a transaction, without a block hash.
+///
+/// Without `-txindex` the node only looks up transactions in the mempool, a transaction in a block
+/// can still be found by providing the hash of the block containing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxNotFoundError {
+    /// The transaction is not in the mempool and the node does not have `-txindex` enabled.
+    TxIndexDisabled(Txid),
+    /// The transaction is not in the mempool and the transaction index is still being built.
+    TxIndexSyncing(Txid),
+    /// The transaction is neither in the mempool nor in the transaction index.
+    ///
+    /// Also returned by nodes before v0.21, these can not report whether `-txindex` is enabled.
+    NotFound(Txid),
+}
+
+impl TxNotFoundError {
+    /// Returns the ID of the transaction that could not be found.
+    pub fn txid(&self) -> Txid {
+        use TxNotFoundError::*;
+
+        match *self {
+            TxIndexDisabled(txid) | TxIndexSyncing(txid) | NotFound(txid) => txid,
+        }
+    }
 }
 
-impl fmt::Display for UnexpectedServerVersionError {
+impl fmt::Display for TxNotFoundError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut expected = String::new();
-        for version in &self.expected {
-            let v = format!(" {} ", version);
-            expected.push_str(&v);
+        use TxNotFoundError::*;
+
+        match *self {
+            TxIndexDisabled(txid) => write!(
+                f,
+                "{} is not in the mempool and `-txindex` is disabled, provide the hash of the \
+                 block containing it or restart the node with `-txindex`",
+                txid
+            ),
+            TxIndexSyncing(txid) => write!(
+                f,
+                "{} is not in the mempool and `-txindex` is still syncing, provide the hash of \
+                 the block containing it or retry once the index is synced",
+                txid
+            ),
+            NotFound(txid) => write!(f, "{} is not in the mempool or the blockchain", txid),
         }
-        write!(f, "unexpected bitcoind version, got: {} expected one of: {}", self.got, expected)
     }
 }
 
-impl error::Error for UnexpectedServerVersionError {}
+impl error::Error for TxNotFoundError {}
 
-impl From<UnexpectedServerVersionError> for Error {
-    fn from(e: UnexpectedServerVersionError) -> Self { Self::ServerVersion(e) }
+impl From<TxNotFoundError> for Error {
+    fn from(e: TxNotFoundError) -> Self { Self::TxNotFound(e) }
 }
@@ -0,0 +1,429 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A parallel block fetcher, for indexers that process a range of blocks.
+//!
+//! [`BlockFetcher`] fetches the blocks at a range of heights using a number of threads and returns
+//! them in height order. Implement [`FetchHooks`] to plug metrics or processing logic into the
+//! fetch pipeline.
+//!
+//! Blocks are fetched using `getblock` with `verbosity` set to 0 and decoded locally. Use
+//! [`BlockFetcher::with_verbose_blocks`] to have the node decode them instead, and
+//! [`BlockFetcher::with_verbosity_downgrade`] to only do so for blocks below a size threshold in
+//! memory constrained environments. [`BlockFetcher::metrics`] shows which path was taken.
+//!
+//! ```no_run
+//! use bitcoind_json_rpc_client::client_sync::fetch::{BlockDecoded, BlockFetcher, FetchHooks};
+//! use bitcoind_json_rpc_client::client_sync::v26::Client;
+//! use bitcoind_json_rpc_client::client_sync::Auth;
+//!
+//! struct Progress;
+//!
+//! impl FetchHooks for Progress {
+//!     fn on_block_decoded(&self, ctx: &BlockDecoded<'_>) {
+//!         println!("{} {}: {} transactions", ctx.height, ctx.hash, ctx.block.txdata.len());
+//!     }
+//! }
+//!
+//! # fn main() -> bitcoind_json_rpc_client::client_sync::Result<()> {
+//! let auth = Auth::UserPass("user".to_owned(), "password".to_owned());
+//! let client = Client::new_with_auth("http://127.0.0.1:8332", auth)?;
+//!
+//! let fetcher = BlockFetcher::new(client).with_threads(4).with_hooks(Progress);
+//! let blocks = fetcher.fetch(800_000..=800_099)?;
+//! # let _ = blocks;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+use std::{fmt, thread};
+
+use bitcoin::block::{self, Block};
+use bitcoin::consensus::encode;
+use bitcoin::BlockHash;
+
+use crate::client_sync::{Error, Result};
+use crate::json::model;
+
+/// The number of threads used by default.
+const DEFAULT_THREADS: usize = 4;
+
+/// The JSON-RPC calls used by [`BlockFetcher`], implemented by all the version specific clients.
+pub trait BlockSource {
+    /// Returns the hash of the block at `height` in the active chain, using `getblockhash`.
+    fn block_hash(&self, height: u64) -> Result<BlockHash>;
+
+    /// Returns the hex encoded block `hash`, using `getblock` with `verbosity` set to 0.
+    fn block_hex(&self, hash: &BlockHash) -> Result<String>;
+
+    /// Returns the size in bytes of block `hash`, using `getblock` with `verbosity` set to 1.
+    fn block_size(&self, hash: &BlockHash) -> Result<usize>;
+
+    /// Returns block `hash` decoded by the node, using `getblock` with `verbosity` set to 2.
+    fn block_verbose(&self, hash: &BlockHash) -> Result<model::GetBlockVerbosityTwo>;
+}
+
+/// Implements [`BlockSource`] for a version specific client.
+///
+/// Requires `Client` to be in scope and to implement `get_block_verbosity_zero`,
+/// `get_block_verbosity_one` and `get_block_verbose`.
+#[macro_export]
+macro_rules! impl_client_block_source {
+    () => {
+        impl $crate::client_sync::fetch::BlockSource for Client {
+            fn block_hash(&self, height: u64) -> $crate::client_sync::Result<bitcoin::BlockHash> {
+                let hash: String =
+                    self.call("getblockhash", &[$crate::client_sync::into_json(height)?])?;
+                Ok(hash.parse()?)
+            }
+
+            fn block_hex(&self, hash: &bitcoin::BlockHash) -> $crate::client_sync::Result<String> {
+                Ok(Client::get_block_verbosity_zero(self, hash)?.0)
+            }
+
+            fn block_size(&self, hash: &bitcoin::BlockHash) -> $crate::client_sync::Result<usize> {
+                Ok(Client::get_block_verbosity_one(self, hash)?.size)
+            }
+
+            fn block_verbose(
+                &self,
+                hash: &bitcoin::BlockHash,
+            ) -> $crate::client_sync::Result<$crate::json::model::GetBlockVerbosityTwo> {
+                Client::get_block_verbose(self, hash)
+            }
+        }
+    };
+}
+
+/// A block returned by [`BlockFetcher::fetch`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FetchedBlock {
+    /// The height of the block.
+    pub height: u64,
+    /// The block hash.
+    pub hash: BlockHash,
+    /// The block.
+    pub block: Block,
+    /// How the block was fetched.
+    pub path: FetchPath,
+}
+
+/// How a block was fetched from the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FetchPath {
+    /// `getblock` with `verbosity` set to 2, decoded by the node.
+    Verbose,
+    /// `getblock` with `verbosity` set to 0, decoded locally.
+    Raw,
+}
+
+/// The number of blocks, and their total size in bytes, fetched using each [`FetchPath`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FetchMetrics {
+    /// The number of blocks fetched using [`FetchPath::Verbose`].
+    pub verbose_blocks: u64,
+    /// The total size of the blocks fetched using [`FetchPath::Verbose`].
+    pub verbose_bytes: u64,
+    /// The number of blocks fetched using [`FetchPath::Raw`].
+    pub raw_blocks: u64,
+    /// The total size of the blocks fetched using [`FetchPath::Raw`].
+    pub raw_bytes: u64,
+}
+
+/// Context passed to [`FetchHooks::on_block_fetched`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockFetched {
+    /// The height of the block.
+    pub height: u64,
+    /// The block hash.
+    pub hash: BlockHash,
+    /// The size of the block in bytes.
+    pub size: usize,
+    /// How the block was fetched.
+    pub path: FetchPath,
+    /// The time taken by the `getblockhash` and `getblock` calls.
+    pub elapsed: Duration,
+}
+
+/// Context passed to [`FetchHooks::on_block_decoded`].
+#[derive(Clone, Copy, Debug)]
+pub struct BlockDecoded<'a> {
+    /// The height of the block.
+    pub height: u64,
+    /// The block hash.
+    pub hash: BlockHash,
+    /// The decoded block.
+    pub block: &'a Block,
+}
+
+/// Context passed to [`FetchHooks::on_reorg_detected`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReorgDetected {
+    /// The height of the first block that does not connect to the block fetched before it.
+    pub height: u64,
+    /// The hash of the block fetched at `height`.
+    pub hash: BlockHash,
+    /// The hash of the block fetched at `height - 1`.
+    pub expected_prev: BlockHash,
+    /// The previous block hash in the header of the block fetched at `height`.
+    pub actual_prev: BlockHash,
+}
+
+/// Hook points in the [`BlockFetcher`] pipeline, all methods do nothing by default.
+///
+/// `on_block_fetched` and `on_block_decoded` are called on the fetching threads, so blocks are not
+/// seen in height order. `on_reorg_detected` is called on the thread calling `fetch`.
+pub trait FetchHooks: Send + Sync {
+    /// Called once the node has returned a block, before a raw block is decoded.
+    fn on_block_fetched(&self, _ctx: &BlockFetched) {}
+
+    /// Called once a block has been decoded.
+    fn on_block_decoded(&self, _ctx: &BlockDecoded<'_>) {}
+
+    /// Called if the chain changed while fetching, see [`BlockFetcher::fetch`].
+    fn on_reorg_detected(&self, _ctx: &ReorgDetected) {}
+}
+
+impl FetchHooks for () {}
+
+/// Allows keeping a handle to the hooks e.g., to read metrics they collect.
+impl<H: FetchHooks + ?Sized> FetchHooks for Arc<H> {
+    fn on_block_fetched(&self, ctx: &BlockFetched) { (**self).on_block_fetched(ctx) }
+
+    fn on_block_decoded(&self, ctx: &BlockDecoded<'_>) { (**self).on_block_decoded(ctx) }
+
+    fn on_reorg_detected(&self, ctx: &ReorgDetected) { (**self).on_reorg_detected(ctx) }
+}
+
+/// Fetches blocks in parallel using any of the version specific clients.
+pub struct BlockFetcher<C> {
+    client: Arc<C>,
+    threads: usize,
+    hooks: Arc<dyn FetchHooks>,
+    verbose: bool,
+    downgrade_threshold: Option<usize>,
+    stats: Arc<Stats>,
+}
+
+impl<C> BlockFetcher<C> {
+    /// Creates a block fetcher using `client`, with the default number of threads and no hooks.
+    pub fn new(client: C) -> Self {
+        BlockFetcher {
+            client: Arc::new(client),
+            threads: DEFAULT_THREADS,
+            hooks: Arc::new(()),
+            verbose: false,
+            downgrade_threshold: None,
+            stats: Arc::new(Stats::default()),
+        }
+    }
+
+    /// Sets the number of threads used to fetch blocks, at least one thread is always used.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Sets the hooks called while fetching blocks.
+    pub fn with_hooks<H: FetchHooks + 'static>(mut self, hooks: H) -> Self {
+        self.hooks = Arc::new(hooks);
+        self
+    }
+
+    /// Fetches blocks using `getblock` with `verbosity` set to 2, the node decodes them.
+    pub fn with_verbose_blocks(mut self) -> Self {
+        self.verbose = true;
+        self
+    }
+
+    /// Fetches blocks larger than `threshold` bytes using `getblock` with `verbosity` set to 0,
+    /// decoding them locally, and the others with `verbosity` set to 2.
+    ///
+    /// This avoids the memory overhead of the verbose JSON of large blocks. The size of each block
+    /// is looked up first using `getblock` with `verbosity` set to 1, which only lists the txids,
+    /// so the path only depends on the block itself.
+    pub fn with_verbosity_downgrade(mut self, threshold: usize) -> Self {
+        self.verbose = true;
+        self.downgrade_threshold = Some(threshold);
+        self
+    }
+
+    /// Returns the client used to fetch blocks.
+    pub fn client(&self) -> &C { &self.client }
+
+    /// Returns the number of blocks fetched using each path, across all calls to `fetch`.
+    pub fn metrics(&self) -> FetchMetrics {
+        FetchMetrics {
+            verbose_blocks: self.stats.verbose_blocks.load(Ordering::Relaxed),
+            verbose_bytes: self.stats.verbose_bytes.load(Ordering::Relaxed),
+            raw_blocks: self.stats.raw_blocks.load(Ordering::Relaxed),
+            raw_bytes: self.stats.raw_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl<C: BlockSource + Send + Sync + 'static> BlockFetcher<C> {
+    /// Fetches the blocks at `heights`, returned in height order.
+    ///
+    /// Each block is checked to connect to the block fetched before it. If the chain changed while
+    /// fetching, [`FetchHooks::on_reorg_detected`] is called and only the blocks before the first
+    /// block that does not connect are returned. Fetch again from there once the reorg has been
+    /// handled.
+    ///
+    /// Returns the first error hit by any of the threads, no more heights are fetched after that.
+    pub fn fetch(&self, heights: RangeInclusive<u64>) -> Result<Vec<FetchedBlock>> {
+        let (start, end) = (*heights.start(), *heights.end());
+        if start > end {
+            return Ok(vec![]);
+        }
+        let count = end - start + 1;
+        let threads = (self.threads as u64).min(count) as usize;
+
+        let next = Arc::new(AtomicU64::new(start));
+        let (tx, rx) = mpsc::channel();
+        let mut handles = Vec::with_capacity(threads);
+        for _ in 0..threads {
+            let worker = Worker {
+                client: Arc::clone(&self.client),
+                hooks: Arc::clone(&self.hooks),
+                verbose: self.verbose,
+                downgrade_threshold: self.downgrade_threshold,
+                stats: Arc::clone(&self.stats),
+            };
+            let next = Arc::clone(&next);
+            let tx = tx.clone();
+
+            handles.push(thread::spawn(move || loop {
+                let height = next.fetch_add(1, Ordering::SeqCst);
+                if height > end {
+                    break;
+                }
+                let res = worker.fetch_block(height);
+                let failed = res.is_err();
+                if failed {
+                    // Stop the other threads from fetching any more heights.
+                    next.store(end.saturating_add(1), Ordering::SeqCst);
+                }
+                if tx.send(res).is_err() || failed {
+                    break;
+                }
+            }));
+        }
+        drop(tx);
+
+        let results = rx.into_iter().collect::<Vec<_>>();
+        for handle in handles {
+            if let Err(panic) = handle.join() {
+                std::panic::resume_unwind(panic);
+            }
+        }
+        let mut blocks = results.into_iter().collect::<Result<Vec<_>>>()?;
+        blocks.sort_by_key(|b| b.height);
+
+        if let Some(i) =
+            (1..blocks.len()).find(|&i| blocks[i].block.header.prev_blockhash != blocks[i - 1].hash)
+        {
+            self.hooks.on_reorg_detected(&ReorgDetected {
+                height: blocks[i].height,
+                hash: blocks[i].hash,
+                expected_prev: blocks[i - 1].hash,
+                actual_prev: blocks[i].block.header.prev_blockhash,
+            });
+            blocks.truncate(i);
+        }
+        Ok(blocks)
+    }
+}
+
+impl<C: fmt::Debug> fmt::Debug for BlockFetcher<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BlockFetcher")
+            .field("client", &self.client)
+            .field("threads", &self.threads)
+            .field("verbose", &self.verbose)
+            .field("downgrade_threshold", &self.downgrade_threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Counters shared by the fetching threads.
+#[derive(Debug, Default)]
+struct Stats {
+    verbose_blocks: AtomicU64,
+    verbose_bytes: AtomicU64,
+    raw_blocks: AtomicU64,
+    raw_bytes: AtomicU64,
+}
+
+impl Stats {
+    fn record(&self, path: FetchPath, size: usize) {
+        let (blocks, bytes) = match path {
+            FetchPath::Verbose => (&self.verbose_blocks, &self.verbose_bytes),
+            FetchPath::Raw => (&self.raw_blocks, &self.raw_bytes),
+        };
+        blocks.fetch_add(1, Ordering::Relaxed);
+        bytes.fetch_add(size as u64, Ordering::Relaxed);
+    }
+}
+
+/// The state used by a single fetching thread.
+struct Worker<C> {
+    client: Arc<C>,
+    hooks: Arc<dyn FetchHooks>,
+    verbose: bool,
+    downgrade_threshold: Option<usize>,
+    stats: Arc<Stats>,
+}
+
+impl<C: BlockSource> Worker<C> {
+    /// Fetches the block at `height`.
+    fn fetch_block(&self, height: u64) -> Result<FetchedBlock> {
+        let start = Instant::now();
+        let hash = self.client.block_hash(height)?;
+        let path = match self.downgrade_threshold {
+            Some(threshold) if self.client.block_size(&hash)? > threshold => FetchPath::Raw,
+            _ if self.verbose => FetchPath::Verbose,
+            _ => FetchPath::Raw,
+        };
+
+        let block = match path {
+            FetchPath::Verbose => {
+                let verbose = self.client.block_verbose(&hash)?;
+                self.fetched(height, hash, verbose.size, path, start);
+
+                let header = block::Header::try_from(&verbose.header_verbose())
+                    .map_err(|e| Error::IntoModel(Box::new(e)))?;
+                let block = Block { header, txdata: verbose.tx };
+                // Catches a header that did not round trip through the verbose JSON.
+                if block.block_hash() != hash {
+                    return Err(Error::UnexpectedStructure);
+                }
+                block
+            }
+            FetchPath::Raw => {
+                let hex = self.client.block_hex(&hash)?;
+                self.fetched(height, hash, hex.len() / 2, path, start);
+
+                encode::deserialize_hex::<Block>(&hex)?
+            }
+        };
+        self.hooks.on_block_decoded(&BlockDecoded { height, hash, block: &block });
+
+        Ok(FetchedBlock { height, hash, block, path })
+    }
+
+    /// Records a fetched block and calls the `on_block_fetched` hook.
+    fn fetched(&self, height: u64, hash: BlockHash, size: usize, path: FetchPath, start: Instant) {
+        self.stats.record(path, size);
+        self.hooks.on_block_fetched(&BlockFetched {
+            height,
+            hash,
+            size,
+            path,
+            elapsed: start.elapsed(),
+        });
+    }
+}
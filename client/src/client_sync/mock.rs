@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A mock transport that returns canned responses, for testing code built on top of the clients.
+//!
+//! Responses are scripted per method. Queued replies (see [`MockTransport::push_result`] and
+//! friends) are returned first, in order, which allows injecting error sequences e.g., the first
+//! call times out and the second succeeds. Once a method's queue is empty its paged responses (see
+//! [`MockTransport::set_paged`]) are used, slicing the canned items using the `count` and `skip`
+//! arguments of the request. Calling a method with no scripted responses returns the same RPC
+//! error as Core returns for an unknown method.
+//!
+//! The transport is cheap to clone and clones share their state, so a clone can be kept to script
+//! further responses and inspect the calls made after the client is created.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::{fmt, io};
+
+use jsonrpc::error::RpcError;
+use serde_json::Value;
+
+use crate::client_sync::transport::{Request, Response, Transport};
+use crate::client_sync::{Error, Result};
+
+/// The RPC error code Core returns for an unknown method.
+const RPC_METHOD_NOT_FOUND: i32 = -32601;
+
+/// A [`Transport`] that returns scripted responses instead of talking to `bitcoind`.
+#[derive(Clone, Default)]
+pub struct MockTransport(Arc<Mutex<State>>);
+
+#[derive(Default)]
+struct State {
+    /// Replies returned in order before falling back to `paged`.
+    queued: HashMap<String, VecDeque<MockReply>>,
+    /// Paged canned responses, used once a method's queue is empty.
+    paged: HashMap<String, Paged>,
+    /// Every call made, in order.
+    calls: Vec<MockCall>,
+}
+
+/// A scripted reply to a single call.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MockReply {
+    /// The call succeeds and returns this JSON result.
+    Result(Value),
+    /// The server returns an RPC error.
+    RpcError {
+        /// The RPC error code e.g., `-28` for "Loading block index".
+        code: i32,
+        /// The error message.
+        message: String,
+    },
+    /// The request times out, returned as a transport error.
+    Timeout,
+    /// The connection is refused, returned as a transport error.
+    ConnectionRefused,
+}
+
+/// A call made to a [`MockTransport`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockCall {
+    /// The RPC method called.
+    pub method: String,
+    /// The positional arguments of the call.
+    pub args: Vec<Value>,
+}
+
+/// Canned items returned a page at a time, like `listtransactions` returns wallet transactions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Paged {
+    items: Vec<Value>,
+    count_arg: usize,
+    skip_arg: usize,
+    default_count: usize,
+}
+
+impl Paged {
+    /// Creates paged responses with the `listtransactions` argument layout.
+    ///
+    /// That is, `count` is the second argument (default 10) and `skip` the third (default 0).
+    pub fn new<I: IntoIterator<Item = Value>>(items: I) -> Self {
+        Paged { items: items.into_iter().collect(), count_arg: 1, skip_arg: 2, default_count: 10 }
+    }
+
+    /// Sets the positions of the `count` and `skip` arguments.
+    pub fn args(mut self, count_arg: usize, skip_arg: usize) -> Self {
+        self.count_arg = count_arg;
+        self.skip_arg = skip_arg;
+        self
+    }
+
+    /// Sets the number of items returned if the `count` argument is not given.
+    pub fn default_count(mut self, count: usize) -> Self {
+        self.default_count = count;
+        self
+    }
+
+    /// Returns the page selected by `args`.
+    fn page(&self, args: &[Value]) -> Value {
+        let arg = |index: usize| args.get(index).and_then(Value::as_u64).map(|n| n as usize);
+        let count = arg(self.count_arg).unwrap_or(self.default_count);
+        let skip = arg(self.skip_arg).unwrap_or(0);
+        Value::Array(self.items.iter().skip(skip).take(count).cloned().collect())
+    }
+}
+
+impl MockTransport {
+    /// Creates a mock transport with no scripted responses.
+    pub fn new() -> Self { Self::default() }
+
+    /// Queues a reply to the next call to `method`.
+    pub fn push(&self, method: &str, reply: MockReply) {
+        self.state().queued.entry(method.to_owned()).or_default().push_back(reply);
+    }
+
+    /// Queues a successful reply to the next call to `method`.
+    ///
+    /// # Panics
+    ///
+    /// If `result` fails to serialize to JSON.
+    pub fn push_result<T: serde::Serialize>(&self, method: &str, result: T) {
+        let value = serde_json::to_value(result).expect("failed to serialize mock result");
+        self.push(method, MockReply::Result(value));
+    }
+
+    /// Queues an RPC error reply to the next call to `method`.
+    pub fn push_rpc_error(&self, method: &str, code: i32, message: &str) {
+        self.push(method, MockReply::RpcError { code, message: message.to_owned() });
+    }
+
+    /// Queues a timeout of the next call to `method`.
+    pub fn push_timeout(&self, method: &str) { self.push(method, MockReply::Timeout); }
+
+    /// Queues a refused connection for the next call to `method`.
+    pub fn push_connection_refused(&self, method: &str) {
+        self.push(method, MockReply::ConnectionRefused);
+    }
+
+    /// Sets the paged responses of `method`, used once its queued replies are exhausted.
+    pub fn set_paged(&self, method: &str, paged: Paged) {
+        self.state().paged.insert(method.to_owned(), paged);
+    }
+
+    /// Returns all calls made so far, in order.
+    pub fn calls(&self) -> Vec<MockCall> { self.state().calls.clone() }
+
+    /// Returns the calls made to `method` so far, in order.
+    pub fn calls_to(&self, method: &str) -> Vec<MockCall> {
+        self.state().calls.iter().filter(|call| call.method == method).cloned().collect()
+    }
+
+    /// Returns the number of queued replies not yet returned, for all methods.
+    pub fn pending(&self) -> usize { self.state().queued.values().map(VecDeque::len).sum() }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        // A panic while holding the lock does not leave the state inconsistent.
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Transport for MockTransport {
+    fn send_request(&self, request: &Request) -> Result<Response> {
+        let args = match request.params {
+            Some(params) => serde_json::from_str::<Vec<Value>>(params.get())?,
+            None => vec![],
+        };
+
+        let mut state = self.state();
+        let reply = match state.queued.get_mut(request.method).and_then(VecDeque::pop_front) {
+            Some(reply) => reply,
+            None => match state.paged.get(request.method) {
+                Some(paged) => MockReply::Result(paged.page(&args)),
+                None => MockReply::RpcError {
+                    code: RPC_METHOD_NOT_FOUND,
+                    message: "Method not found".to_owned(),
+                },
+            },
+        };
+        state.calls.push(MockCall { method: request.method.to_owned(), args });
+        drop(state);
+
+        let mut response =
+            Response { result: None, error: None, id: request.id.clone(), jsonrpc: None };
+        match reply {
+            MockReply::Result(value) =>
+                response.result = Some(serde_json::value::to_raw_value(&value)?),
+            MockReply::RpcError { code, message } =>
+                response.error = Some(RpcError { code, message, data: None }),
+            MockReply::Timeout => {
+                let e = io::Error::new(io::ErrorKind::TimedOut, "mock request timed out");
+                return Err(Error::JsonRpc(jsonrpc::error::Error::Transport(Box::new(e))));
+            }
+            MockReply::ConnectionRefused => {
+                let e = io::Error::new(io::ErrorKind::ConnectionRefused, "mock connection refused");
+                return Err(Error::JsonRpc(jsonrpc::error::Error::Transport(Box::new(e))));
+            }
+        }
+        Ok(response)
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "mock") }
+}
+
+impl fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = self.state();
+        f.debug_struct("MockTransport")
+            .field("queued", &state.queued)
+            .field("paged", &state.paged)
+            .field("calls", &state.calls)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::client_sync::v17::Client;
+    use crate::json::v17::{Bip125Replaceable, GetTransactionDetailCategory, TransactionItem};
+
+    fn transaction_item(i: u64) -> TransactionItem {
+        TransactionItem {
+            involves_watch_only: None,
+            address: None,
+            category: GetTransactionDetailCategory::Generate,
+            amount: 50.0,
+            label: None,
+            vout: 0,
+            fee: None,
+            confirmations: 100,
+            generated: Some(true),
+            trusted: None,
+            block_hash: None,
+            block_index: None,
+            block_time: None,
+            txid: format!("{:064x}", i),
+            wallet_conflicts: vec![],
+            time: i,
+            time_received: i,
+            comment: None,
+            bip125_replaceable: Bip125Replaceable::No,
+            abandoned: None,
+        }
+    }
+
+    #[test]
+    fn paging_and_retry() {
+        let all = (0..25).map(transaction_item).collect::<Vec<_>>();
+
+        let mock = MockTransport::new();
+        let items = all.iter().map(|item| serde_json::to_value(item).unwrap());
+        mock.set_paged("listtransactions", Paged::new(items));
+        mock.push_timeout("listtransactions");
+        mock.push_rpc_error("listtransactions", -28, "Loading wallet...");
+        let client = Client::from_transport(mock.clone());
+
+        // Retry each page until it succeeds, and page until a short page is returned.
+        let mut paged = Vec::new();
+        let mut errors = 0;
+        loop {
+            let page = match client.list_transactions_page(10, paged.len()) {
+                Ok(page) => page.0,
+                Err(_) => {
+                    errors += 1;
+                    continue;
+                }
+            };
+            let done = page.len() < 10;
+            paged.extend(page);
+            if done {
+                break;
+            }
+        }
+        assert_eq!(errors, 2);
+        assert_eq!(paged, all);
+        assert_eq!(mock.pending(), 0);
+
+        let calls = mock.calls_to("listtransactions");
+        assert_eq!(calls.len(), 5);
+        assert_eq!(calls[4].args, vec![json!("*"), json!(10), json!(20)]);
+        assert!(client.get_blockchain_info().is_err());
+    }
+}
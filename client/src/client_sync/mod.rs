@@ -2,7 +2,15 @@
 
 //! JSON-RPC clients for testing against specific versions of Bitcoin Core.
 
-mod error;
+pub(crate) mod error;
+pub mod fetch;
+pub mod mock;
+#[cfg(unix)]
+pub mod notify;
+#[cfg(feature = "profiles")]
+pub mod profile;
+pub mod rpc;
+pub mod transport;
 pub mod v17;
 pub mod v18;
 pub mod v19;
@@ -13,12 +21,22 @@ pub mod v23;
 pub mod v24;
 pub mod v25;
 pub mod v26;
+#[cfg(feature = "unstable-v28")]
+pub mod v28;
 
 use std::fs::File;
 use std::io::{BufRead, BufReader};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
-pub use crate::client_sync::error::Error;
+pub use crate::client_sync::error::{Error, TxFeeError, TxNotFoundError, UnsupportedVersionError};
+#[doc(inline)]
+pub use crate::client_sync::mock::MockTransport;
+#[doc(inline)]
+pub use crate::client_sync::rpc::{BitcoindRpc, NodeState};
+#[doc(inline)]
+pub use crate::client_sync::transport::{MinreqTransport, RetryTransport, Transport};
 
 /// Crate-specific Result type.
 ///
@@ -40,17 +58,134 @@ impl Auth {
             Auth::None => Ok((None, None)),
             Auth::UserPass(u, p) => Ok((Some(u), Some(p))),
             Auth::CookieFile(path) => {
-                let line = BufReader::new(File::open(path)?)
-                    .lines()
-                    .next()
-                    .ok_or(Error::InvalidCookieFile)??;
-                let colon = line.find(':').ok_or(Error::InvalidCookieFile)?;
-                Ok((Some(line[..colon].into()), Some(line[colon + 1..].into())))
+                let (user, pass) = read_cookie_file(&path)?;
+                Ok((Some(user), Some(pass)))
             }
         }
     }
 }
 
+/// Reads the user and password from a `bitcoind` cookie file.
+fn read_cookie_file(path: &Path) -> Result<(String, String)> {
+    let line =
+        BufReader::new(File::open(path)?).lines().next().ok_or(Error::InvalidCookieFile)??;
+    let colon = line.find(':').ok_or(Error::InvalidCookieFile)?;
+    Ok((line[..colon].into(), line[colon + 1..].into()))
+}
+
+/// The basic auth credentials used by a client.
+///
+/// `bitcoind` writes a new cookie file each time it starts, the cookie file is re-read whenever it
+/// is modified so that a long lived client keeps working across node restarts.
+#[derive(Clone)]
+pub(crate) enum Credentials {
+    /// A fixed user and password.
+    UserPass(String, Option<String>),
+    /// The credentials in a cookie file.
+    Cookie(Arc<CookieFile>),
+}
+
+impl Credentials {
+    /// Returns the credentials for `auth`, `None` for [`Auth::None`].
+    ///
+    /// The cookie file, if any, is read so that a missing or invalid file is reported early.
+    pub(crate) fn from_auth(auth: Auth) -> Result<Option<Self>> {
+        match auth {
+            Auth::None => Ok(None),
+            Auth::UserPass(user, pass) => Ok(Some(Credentials::UserPass(user, Some(pass)))),
+            Auth::CookieFile(path) => {
+                let cookie = CookieFile { path, cached: Mutex::new(None) };
+                cookie.user_pass()?;
+                Ok(Some(Credentials::Cookie(Arc::new(cookie))))
+            }
+        }
+    }
+
+    /// Returns the user and password, re-reading the cookie file if it has been modified.
+    pub(crate) fn user_pass(&self) -> Result<(String, Option<String>)> {
+        match *self {
+            Credentials::UserPass(ref user, ref pass) => Ok((user.clone(), pass.clone())),
+            Credentials::Cookie(ref cookie) =>
+                cookie.user_pass().map(|(user, pass)| (user, Some(pass))),
+        }
+    }
+}
+
+/// A cookie file and the credentials last read from it.
+pub(crate) struct CookieFile {
+    path: PathBuf,
+    /// The modification time of the file when it was last read, and the credentials read.
+    cached: Mutex<Option<(SystemTime, String, String)>>,
+}
+
+impl CookieFile {
+    fn user_pass(&self) -> Result<(String, String)> {
+        let modified = std::fs::metadata(&self.path)?.modified()?;
+        // A panic while holding the lock does not leave the cache inconsistent.
+        let mut cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((at, ref user, ref pass)) = *cached {
+            if at == modified {
+                return Ok((user.clone(), pass.clone()));
+            }
+        }
+        let (user, pass) = read_cookie_file(&self.path)?;
+        *cached = Some((modified, user.clone(), pass.clone()));
+        Ok((user, pass))
+    }
+}
+
+/// RPC methods that are only meaningful on test chains and are dangerous on mainnet.
+///
+/// With safety mode on (the default) the client refuses to call these if the node is on mainnet.
+pub const REGTEST_ONLY_METHODS: &[&str] = &[
+    "generate",
+    "generateblock",
+    "generatetoaddress",
+    "generatetodescriptor",
+    "invalidateblock",
+    "reconsiderblock",
+    "setmocktime",
+    "mockscheduler",
+];
+
+/// Returns true if `method` is one of the [`REGTEST_ONLY_METHODS`].
+pub fn is_regtest_only(method: &str) -> bool { REGTEST_ONLY_METHODS.contains(&method) }
+
+/// A heartbeat reported while waiting for `verifychain` to complete.
+///
+/// Core does not expose the progress of `verifychain` over RPC (it is only written to the debug
+/// log), and holds the chain lock while verifying so other RPCs block. A heartbeat therefore only
+/// reports that verification is still running.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyChainHeartbeat {
+    /// Time since `verifychain` was called.
+    pub elapsed: std::time::Duration,
+}
+
+/// A hook called for each request sent and each response received, see `Client::set_rpc_hook`.
+pub type RpcHook = Arc<dyn Fn(&RpcEvent<'_>) + Send + Sync>;
+
+/// A request or response, as passed to an [`RpcHook`].
+#[derive(Debug)]
+pub enum RpcEvent<'a> {
+    /// A request is about to be sent.
+    Request {
+        /// The RPC method called.
+        method: &'a str,
+        /// The positional arguments of the call.
+        args: &'a [serde_json::Value],
+    },
+    /// A response was received, or sending the request failed.
+    Response {
+        /// The RPC method called.
+        method: &'a str,
+        /// Time taken to send the request and receive the response.
+        elapsed: std::time::Duration,
+        /// The response, which may be an error response returned by the node.
+        result: std::result::Result<&'a transport::Response, &'a Error>,
+    },
+}
+
 /// Defines a `jsonrpc::Client` using `minreq`.
 #[macro_export]
 macro_rules! define_jsonrpc_minreq_client {
@@ -61,15 +196,130 @@ macro_rules! define_jsonrpc_minreq_client {
         use $crate::client_sync::error::Error;
 
         /// Client implements a JSON-RPC client for the Bitcoin Core daemon or compatible APIs.
+        ///
+        /// The client is `Send + Sync` and cheap to clone, clones share the underlying transport.
+        /// Calls are not serialized, concurrent calls from multiple threads (e.g. using a clone
+        /// per thread or a shared `Arc<Client>`) each use their own HTTP request.
+        ///
+        /// Requests are sent using [`MinreqTransport`](crate::client_sync::MinreqTransport) unless
+        /// the client is created with [`Client::from_transport`], use [`Client::builder`] to
+        /// configure timeouts, retries and a proxy.
+        #[derive(Clone)]
         pub struct Client {
-            inner: jsonrpc::client::Client,
+            inner: std::sync::Arc<dyn $crate::client_sync::Transport>,
+            /// Used to create request ids, shared between clones.
+            nonce: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+            /// The URL, credentials and transport options, `None` if using a custom transport.
+            http: Option<(
+                String,
+                Option<$crate::client_sync::Credentials>,
+                $crate::client_sync::transport::HttpOptions,
+            )>,
+            safety_mode: bool,
+            /// Cached result of checking the node's chain, shared between clones.
+            chain_check: std::sync::Arc<std::sync::atomic::AtomicU8>,
+            /// The methods this client may call, `None` if all methods are allowed.
+            allowed_methods: Option<std::sync::Arc<std::collections::BTreeSet<String>>>,
+            /// Called for each request and response, shared between clones.
+            hook: Option<$crate::client_sync::RpcHook>,
+        }
+
+        // Enforce the thread-safety guarantees documented on `Client`.
+        const _: () = {
+            fn assert_send_sync<T: core::marker::Send + Sync + Clone>() {}
+            #[allow(dead_code)]
+            fn assert_client() { assert_send_sync::<Client>(); }
+        };
+
+        /// Builds a [`Client`] with a configured HTTP transport, see [`Client::builder`].
+        ///
+        /// Long running calls (e.g. `scantxoutset`, `gettxoutsetinfo`) need a generous read
+        /// timeout, a node reachable over Tor needs a SOCKS5 proxy:
+        ///
+        /// ```text
+        /// let client = Client::builder("http://xyz.onion:8332")
+        ///     .auth(Auth::UserPass(user, pass))
+        ///     .connect_timeout(Duration::from_secs(30))
+        ///     .read_timeout(Duration::from_secs(600))
+        ///     .retry(3, Duration::from_secs(1))
+        ///     .socks5_proxy("127.0.0.1:9050".parse()?)
+        ///     .build()?;
+        /// ```
+        #[derive(Clone, Debug)]
+        pub struct ClientBuilder {
+            url: String,
+            auth: Auth,
+            options: $crate::client_sync::transport::HttpOptions,
+        }
+
+        impl ClientBuilder {
+            /// Sets the authentication method, [`Auth::None`] by default.
+            pub fn auth(mut self, auth: Auth) -> Self {
+                self.auth = auth;
+                self
+            }
+
+            /// Sets the timeout for connecting to the node (or to the proxy).
+            pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+                self.options.connect_timeout = Some(timeout);
+                self
+            }
+
+            /// Sets the timeout for sending a request and reading its response.
+            ///
+            /// Unless a connect timeout or a proxy is also set this is the timeout of the whole
+            /// request, including connecting, and only has second granularity.
+            pub fn read_timeout(mut self, timeout: std::time::Duration) -> Self {
+                self.options.read_timeout = Some(timeout);
+                self
+            }
+
+            /// Retries requests that fail with a transport error up to `max_retries` times.
+            ///
+            /// Only errors before the request is sent (e.g. connection refused) are retried, RPC
+            /// errors and read timeouts are not since re-sending a wallet-mutating call is unsafe,
+            /// see [`RetryTransport`](crate::client_sync::RetryTransport).
+            pub fn retry(mut self, max_retries: u32, initial_backoff: std::time::Duration) -> Self {
+                self.options.retry = Some((max_retries, initial_backoff));
+                self
+            }
+
+            /// Connects to the node through the SOCKS5 proxy at `proxy` e.g., Tor's SOCKS port.
+            ///
+            /// The host name is resolved by the proxy, which allows connecting to onion services.
+            /// Proxies that require authentication are not supported.
+            pub fn socks5_proxy(mut self, proxy: std::net::SocketAddr) -> Self {
+                self.options.proxy = Some(proxy);
+                self
+            }
+
+            /// Creates the client.
+            ///
+            /// Reads the cookie file, if using cookie authentication. A connect timeout or a proxy
+            /// requires the URL to be a valid [`NodeUrl`](crate::node_url::NodeUrl).
+            pub fn build(self) -> Result<Client> {
+                if self.options.needs_tcp() {
+                    self.url.parse::<$crate::node_url::NodeUrl>()?;
+                }
+                let credentials = $crate::client_sync::Credentials::from_auth(self.auth)?;
+                Ok(Client::with_parts(self.url, credentials, self.options))
+            }
         }
 
         impl fmt::Debug for Client {
             fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
+                struct Target<'a>(&'a dyn $crate::client_sync::Transport);
+                impl fmt::Debug for Target<'_> {
+                    fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
+                        self.0.fmt_target(f)
+                    }
+                }
+
                 write!(
                     f,
-                    "bitcoind-json-rpc::client_sync::{}::Client({:?})", $version, self.inner
+                    "bitcoind-json-rpc::client_sync::{}::Client({:?})",
+                    $version,
+                    Target(&*self.inner)
                 )
             }
         }
@@ -77,30 +327,208 @@ macro_rules! define_jsonrpc_minreq_client {
         impl Client {
             /// Creates a client to a bitcoind JSON-RPC server without authentication.
             pub fn new(url: &str) -> Self {
-                let transport = jsonrpc::http::minreq_http::Builder::new()
-                    .url(url)
-                    .expect("jsonrpc v0.18, this function does not error")
-                    .build();
-                let inner = jsonrpc::client::Client::with_transport(transport);
+                Self::with_parts(url.to_owned(), None, Default::default())
+            }
 
-                Self { inner }
+            /// Returns a builder for a client to `url` with a configured HTTP transport.
+            pub fn builder(url: &str) -> ClientBuilder {
+                ClientBuilder { url: url.to_owned(), auth: Auth::None, options: Default::default() }
             }
 
-            /// Creates a client to a bitcoind JSON-RPC server without authentication.
+            /// Creates a client to a bitcoind JSON-RPC server with authentication.
+            ///
+            /// A cookie file is read when the client is created and re-read whenever it changes,
+            /// e.g. when `bitcoind` restarts and writes a new cookie.
             pub fn new_with_auth(url: &str, auth: Auth) -> Result<Self> {
                 if matches!(auth, Auth::None) {
                     return Err(Error::MissingUserPassword);
                 }
-                let (user, pass) = auth.get_user_pass()?;
+                let credentials = $crate::client_sync::Credentials::from_auth(auth)?;
+                Ok(Self::with_parts(url.to_owned(), credentials, Default::default()))
+            }
+
+            /// Creates a client to the node (or wallet) at `url`.
+            ///
+            /// Unlike [`Client::new_with_auth`], `auth` may be [`Auth::None`].
+            pub fn from_node_url(url: &$crate::node_url::NodeUrl, auth: Auth) -> Result<Self> {
+                let credentials = $crate::client_sync::Credentials::from_auth(auth)?;
+                Ok(Self::with_parts(url.to_string(), credentials, Default::default()))
+            }
 
-                let transport = jsonrpc::http::minreq_http::Builder::new()
-                    .url(url)
-                    .expect("jsonrpc v0.18, this function does not error")
-                    .basic_auth(user.unwrap(), pass)
-                    .build();
-                let inner = jsonrpc::client::Client::with_transport(transport);
+            /// Creates a client from a connection profile.
+            ///
+            /// See [`profile`](crate::client_sync::profile) for loading profiles from a file.
+            #[cfg(feature = "profiles")]
+            pub fn from_profile(
+                profile: &$crate::client_sync::profile::ClientProfile,
+            ) -> Result<Self> {
+                let credentials = $crate::client_sync::Credentials::from_auth(profile.auth.clone())?;
+                let options = $crate::client_sync::transport::HttpOptions {
+                    read_timeout: profile.timeout,
+                    ..Default::default()
+                };
+                Ok(Self::with_parts(profile.endpoint(), credentials, options))
+            }
 
-                Ok(Self { inner })
+            /// Returns a client to the same server that uses `timeout` for all its requests.
+            ///
+            /// `self` is not modified, this allows slow RPCs (e.g. `verifychain`, `gettxoutsetinfo`)
+            /// to be called with a generous timeout while other calls continue to fail fast:
+            ///
+            /// `client.with_timeout(Duration::from_secs(600)).call("verifychain", &[])`
+            ///
+            /// Note that the default transport only supports second granularity. Replaces the read
+            /// timeout of a client created with a [`ClientBuilder`], other options are kept. Has no
+            /// effect if the client uses a custom transport (configure the transport instead).
+            pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+                let (url, credentials, mut options) = match self.http {
+                    Some((ref url, ref credentials, ref options)) =>
+                        (url.clone(), credentials.clone(), options.clone()),
+                    None => return self.clone(),
+                };
+                options.read_timeout = Some(timeout);
+                let mut client = Self::with_parts(url, credentials, options);
+                client.safety_mode = self.safety_mode;
+                client.chain_check = self.chain_check.clone();
+                client.allowed_methods = self.allowed_methods.clone();
+                client.hook = self.hook.clone();
+                client
+            }
+
+            /// Creates a client that sends all requests using `transport`.
+            pub fn from_transport<T: $crate::client_sync::Transport>(transport: T) -> Self {
+                Self::with_transport(std::sync::Arc::new(transport), None)
+            }
+
+            /// Enables or disables safety mode (enabled by default).
+            ///
+            /// With safety mode on, calls to regtest-only methods (see
+            /// [`REGTEST_ONLY_METHODS`](crate::client_sync::REGTEST_ONLY_METHODS)) return
+            /// [`Error::RegtestOnly`] instead of being sent if the node reports it is on mainnet.
+            pub fn set_safety_mode(&mut self, enabled: bool) { self.safety_mode = enabled; }
+
+            /// Returns true if safety mode is enabled.
+            pub fn safety_mode(&self) -> bool { self.safety_mode }
+
+            /// Restricts the client to calling only `methods`, e.g. to match a node's `rpcwhitelist`.
+            ///
+            /// Calls to any other method return [`Error::MethodNotAllowed`] without being sent,
+            /// instead of the node failing the request with HTTP 403. The allowed methods are
+            /// shared with clients cloned after this call. With safety mode on, the first call to a
+            /// regtest-only method also calls `getblockchaininfo`.
+            pub fn set_allowed_methods<I, S>(&mut self, methods: I)
+            where
+                I: IntoIterator<Item = S>,
+                S: Into<String>,
+            {
+                let methods = methods.into_iter().map(Into::into).collect();
+                self.allowed_methods = Some(std::sync::Arc::new(methods));
+            }
+
+            /// Removes any restriction set with [`Client::set_allowed_methods`].
+            pub fn clear_allowed_methods(&mut self) { self.allowed_methods = None; }
+
+            /// Returns the methods this client may call, `None` if the client is not restricted.
+            pub fn allowed_methods(&self) -> Option<&std::collections::BTreeSet<String>> {
+                self.allowed_methods.as_deref()
+            }
+
+            /// Sets a hook called for each request sent and each response received.
+            ///
+            /// Useful to debug what is sent over the wire, or to collect metrics. The hook is shared
+            /// with clients cloned after this call and is called on the thread making the call. The
+            /// `log` crate is used irrespective of the hook (target `bitcoind-json-rpc`, requests
+            /// logged at debug level and results at trace level).
+            pub fn set_rpc_hook<F>(&mut self, hook: F)
+            where
+                F: Fn(&$crate::client_sync::RpcEvent<'_>) + core::marker::Send + Sync + 'static,
+            {
+                self.hook = Some(std::sync::Arc::new(hook));
+            }
+
+            /// Removes any hook set with [`Client::set_rpc_hook`].
+            pub fn clear_rpc_hook(&mut self) { self.hook = None; }
+
+            /// Returns the documentation of the typed methods implemented by this client.
+            ///
+            /// See [`method_docs`](crate::method_docs) for listing the methods available on the
+            /// connected node instead.
+            pub fn method_docs() -> impl Iterator<Item = &'static $crate::method_docs::MethodDoc> {
+                let version = $version[1..].parse().expect("client version is of the form vXX");
+                $crate::method_docs::available(version)
+            }
+
+            /// Calls the hook, if one is set.
+            fn run_hook(&self, event: $crate::client_sync::RpcEvent<'_>) {
+                if let Some(ref hook) = self.hook {
+                    hook(&event)
+                }
+            }
+
+            /// Returns an error if the client is restricted and `method` is not allowed.
+            fn check_allowed(&self, method: &str) -> Result<()> {
+                match self.allowed_methods {
+                    Some(ref allowed) if !allowed.contains(method) =>
+                        Err(Error::MethodNotAllowed(method.to_owned())),
+                    _ => Ok(()),
+                }
+            }
+
+            /// Returns an error if `method` is regtest-only and the node is on mainnet.
+            fn check_regtest_only(&self, method: &str) -> Result<()> {
+                if !self.safety_mode || !$crate::client_sync::is_regtest_only(method) {
+                    return Ok(());
+                }
+
+                use std::sync::atomic::Ordering;
+
+                // 0: not yet checked, 1: not mainnet, 2: mainnet.
+                let is_mainnet = match self.chain_check.load(Ordering::Relaxed) {
+                    0 => {
+                        let info: serde_json::Value = self.call("getblockchaininfo", &[])?;
+                        let main = info.get("chain").and_then(|c| c.as_str()) == Some("main");
+                        self.chain_check.store(if main { 2 } else { 1 }, Ordering::Relaxed);
+                        main
+                    }
+                    cached => cached == 2,
+                };
+
+                if is_mainnet {
+                    return Err(Error::RegtestOnly(method.to_owned()));
+                }
+                Ok(())
+            }
+
+            fn with_parts(
+                url: String,
+                credentials: Option<$crate::client_sync::Credentials>,
+                options: $crate::client_sync::transport::HttpOptions,
+            ) -> Self {
+                let transport = $crate::client_sync::transport::http_transport(
+                    &url,
+                    credentials.clone(),
+                    &options,
+                );
+                Self::with_transport(transport, Some((url, credentials, options)))
+            }
+
+            fn with_transport(
+                inner: std::sync::Arc<dyn $crate::client_sync::Transport>,
+                http: Option<(
+                    String,
+                    Option<$crate::client_sync::Credentials>,
+                    $crate::client_sync::transport::HttpOptions,
+                )>,
+            ) -> Self {
+                Self {
+                    inner,
+                    nonce: std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                    http,
+                    safety_mode: true,
+                    chain_check: std::sync::Arc::new(std::sync::atomic::AtomicU8::new(0)),
+                    allowed_methods: None,
+                    hook: None,
+                }
             }
 
             /// Call an RPC `method` with given `args` list.
@@ -109,16 +537,131 @@ macro_rules! define_jsonrpc_minreq_client {
                 method: &str,
                 args: &[serde_json::Value],
             ) -> Result<T> {
+                self.check_allowed(method)?;
+                self.check_regtest_only(method)?;
+
                 let raw = serde_json::value::to_raw_value(args)?;
-                let req = self.inner.build_request(&method, Some(&*raw));
+                let nonce = self.nonce.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let req = $crate::client_sync::transport::Request {
+                    method,
+                    params: Some(&*raw),
+                    id: serde_json::Value::from(nonce),
+                    jsonrpc: Some("2.0"),
+                };
                 if log::log_enabled!(log::Level::Debug) {
                     log::debug!(target: "bitcoind-json-rpc", "request: {} {}", method, serde_json::Value::from(args));
                 }
+                self.run_hook($crate::client_sync::RpcEvent::Request { method, args });
 
-                let resp = self.inner.send_request(req).map_err(Error::from);
+                let start = std::time::Instant::now();
+                let resp = self.inner.send_request(&req);
                 log_response(method, &resp);
+                self.run_hook($crate::client_sync::RpcEvent::Response {
+                    method,
+                    elapsed: start.elapsed(),
+                    result: resp.as_ref(),
+                });
                 Ok(resp?.result()?)
             }
+
+            /// Calls `method` with `args`, returning the result as untyped JSON.
+            ///
+            /// An escape hatch for methods this crate does not (yet) support. Use [`Client::call`]
+            /// to deserialize the result directly into a type of your own.
+            pub fn call_raw(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<serde_json::Value> {
+                self.call(method, args)
+            }
+
+            /// Calls `method` once for each entry of `args`, sending all the requests as a single
+            /// JSON-RPC batch.
+            ///
+            /// The results are returned in the same order as `args`, an error response to any of
+            /// the requests fails the whole batch. Use [`Client::call_batch_results`] to handle
+            /// the error responses individually.
+            pub fn call_batch<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[Vec<serde_json::Value>],
+            ) -> Result<Vec<T>> {
+                self.call_batch_results(method, args)?.into_iter().collect()
+            }
+
+            /// Calls `method` once for each entry of `args`, sending all the requests as a single
+            /// JSON-RPC batch, returning the result of each request.
+            ///
+            /// The results are returned in the same order as `args`. The outer error is returned
+            /// only if the batch as a whole fails, an error response to one of the requests is
+            /// returned in its place without affecting the others.
+            pub fn call_batch_results<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[Vec<serde_json::Value>],
+            ) -> Result<Vec<Result<T>>> {
+                if args.is_empty() {
+                    return Ok(vec![]);
+                }
+                self.check_allowed(method)?;
+                self.check_regtest_only(method)?;
+
+                let raws = args
+                    .iter()
+                    .map(|args| serde_json::value::to_raw_value(args))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                let requests = raws
+                    .iter()
+                    .map(|raw| {
+                        let nonce = self.nonce.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        $crate::client_sync::transport::Request {
+                            method,
+                            params: Some(&**raw),
+                            id: serde_json::Value::from(nonce),
+                            jsonrpc: Some("2.0"),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                if log::log_enabled!(log::Level::Debug) {
+                    log::debug!(target: "bitcoind-json-rpc", "batch request: {} x{}", method, args.len());
+                }
+                for args in args {
+                    self.run_hook($crate::client_sync::RpcEvent::Request { method, args });
+                }
+
+                let start = std::time::Instant::now();
+                let batch = self.inner.send_batch(&requests);
+                let elapsed = start.elapsed();
+                let mut responses = match batch {
+                    Ok(responses) => responses,
+                    Err(e) => {
+                        self.run_hook($crate::client_sync::RpcEvent::Response {
+                            method,
+                            elapsed,
+                            result: Err(&e),
+                        });
+                        return Err(e);
+                    }
+                };
+                requests
+                    .iter()
+                    .map(|req| {
+                        let i = responses
+                            .iter()
+                            .position(|resp| resp.id == req.id)
+                            .ok_or(Error::UnexpectedStructure)?;
+                        let resp = Ok(responses.swap_remove(i));
+                        log_response(method, &resp);
+                        self.run_hook($crate::client_sync::RpcEvent::Response {
+                            method,
+                            elapsed,
+                            result: resp.as_ref(),
+                        });
+                        Ok(resp.and_then(|resp| Ok(resp.result()?)))
+                    })
+                    .collect()
+            }
         }
     }
 }
@@ -130,18 +673,33 @@ macro_rules! define_jsonrpc_minreq_client {
 ///
 /// # Parameters
 ///
-/// - `$expected_versions`: An vector of expected server versions e.g., `[230100, 230200]`.
+/// - `$expected_versions`: The supported server versions, all point releases of the major
+///   version e.g., `[230000, 230100, 230200]`.
 #[macro_export]
 macro_rules! impl_client_check_expected_server_version {
     ($expected_versions:expr) => {
         impl Client {
-            /// Checks that the JSON-RPC endpoint is for a `bitcoind` instance with the expected version.
+            /// Returns the versions of Core (all the point releases) supported by this client.
+            pub fn supported_versions() -> Vec<$crate::version::CoreVersion> {
+                $expected_versions
+                    .iter()
+                    .map(|v| $crate::version::CoreVersion::from_server_version(*v))
+                    .collect()
+            }
+
+            /// Checks that the JSON-RPC endpoint is for a `bitcoind` instance with a supported
+            /// version.
+            ///
+            /// # Errors
+            ///
+            /// [`Error::ServerVersion`] if the version reported by `bitcoind` is not one of
+            /// [`Client::supported_versions`].
             pub fn check_expected_server_version(&self) -> Result<()> {
                 let server_version = self.server_version()?;
                 if !$expected_versions.contains(&server_version) {
-                    return Err($crate::client_sync::error::UnexpectedServerVersionError {
-                        got: server_version,
-                        expected: $expected_versions.to_vec(),
+                    return Err($crate::client_sync::error::UnsupportedVersionError {
+                        got: $crate::version::CoreVersion::from_server_version(server_version),
+                        expected: Self::supported_versions(),
                     })?;
                 }
                 Ok(())
@@ -151,13 +709,24 @@ macro_rules! impl_client_check_expected_server_version {
 }
 
 /// Shorthand for converting a variable into a `serde_json::Value`.
-fn into_json<T>(val: T) -> Result<serde_json::Value>
+pub(crate) fn into_json<T>(val: T) -> Result<serde_json::Value>
 where
     T: serde::ser::Serialize,
 {
     Ok(serde_json::to_value(val)?)
 }
 
+/// Returns the current time in seconds since the Unix epoch, zero if the clock is before it.
+pub(crate) fn unix_time_now() -> u64 {
+    SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// Converts a fee rate into BTC/kvB, the unit of Core's `maxfeerate` arguments.
+pub(crate) fn fee_rate_to_btc_per_kvb(fee_rate: bitcoin::FeeRate) -> f64 {
+    // 1 vB is 4 wu, so sat/kvB is sat/kwu multiplied by 4.
+    bitcoin::Amount::from_sat(fee_rate.to_sat_per_kwu() * 4).to_btc()
+}
+
 /// Shorthand for converting an `Option` into an `Option<serde_json::Value>`.
 #[allow(dead_code)] // TODO: Remove this if unused still when we are done.
 fn opt_into_json<T>(opt: Option<T>) -> Result<serde_json::Value>
@@ -197,7 +766,7 @@ fn empty_obj() -> serde_json::Value { serde_json::Value::Object(Default::default
 /// Elements of `args` without corresponding `defaults` value, won't be substituted, because they
 /// are required.
 #[allow(dead_code)] // TODO: Remove this if unused still when we are done.
-fn handle_defaults<'a>(
+pub(crate) fn handle_defaults<'a>(
     args: &'a mut [serde_json::Value],
     defaults: &[serde_json::Value],
 ) -> &'a [serde_json::Value] {
@@ -243,7 +812,7 @@ fn opt_result<T: for<'a> serde::de::Deserialize<'a>>(
 }
 
 /// Helper to log an RPC response.
-fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
+pub(crate) fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
     use log::Level::{Debug, Trace, Warn};
 
     if log::log_enabled!(Warn) || log::log_enabled!(Debug) || log::log_enabled!(Trace) {
@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Ingestion of `-walletnotify` and `-blocknotify` hook invocations.
+//!
+//! Core runs a shell command each time a wallet transaction changes or the best block changes, a
+//! hook that writes a single line to a unix socket (or a named pipe) lets a Rust application act on
+//! those notifications:
+//!
+//! ```text
+//! -walletnotify='echo "wallet %s %w" | nc -U /run/bitcoind/notify.sock'
+//! -blocknotify='echo "block %s" | nc -U /run/bitcoind/notify.sock'
+//! ```
+//!
+//! Each line is parsed into a [`Notification`], which carries only what Core substitutes into the
+//! command. Use [`Notification::resolve`] to fetch the transaction (`gettransaction`) or block
+//! header (`getblockheader`) the notification refers to.
+//!
+//! ```text
+//! let listener = NotifyListener::bind("/run/bitcoind/notify.sock")?;
+//! for notification in listener.incoming() {
+//!     match notification?.resolve(&client)? {
+//!         Event::Transaction { transaction, .. } => println!("{}", transaction.txid),
+//!         Event::Block { hash, header } => println!("{} {}", hash, header.time),
+//!     }
+//! }
+//! ```
+//!
+//! A named pipe (created with `mkfifo`) can be read using [`Notifications::new`].
+
+use std::io::{self, BufRead, BufReader};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{error, fmt, fs};
+
+use bitcoin::block::Header;
+use bitcoin::hex::HexToArrayError;
+use bitcoin::{BlockHash, Txid};
+
+use crate::client_sync::{BitcoindRpc, Result};
+use crate::json::model;
+
+/// A notification from a `-walletnotify` or `-blocknotify` hook.
+///
+/// The line format is `wallet <txid> [<wallet name>]` or `block <block hash>`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Notification {
+    /// A wallet transaction was added or changed (`-walletnotify`).
+    Wallet {
+        /// The transaction ID (`%s`).
+        txid: Txid,
+        /// The wallet name (`%w`), if included in the hook command.
+        wallet: Option<String>,
+    },
+    /// The best block changed (`-blocknotify`).
+    Block(BlockHash),
+}
+
+impl Notification {
+    /// Fetches the transaction or block header this notification refers to.
+    ///
+    /// Transactions are fetched using `client` irrespective of the notification's wallet name, on
+    /// a node with multiple wallets loaded pass a client for the wallet named in the notification.
+    pub fn resolve<C: BitcoindRpc>(&self, client: &C) -> Result<Event> {
+        match *self {
+            Notification::Wallet { txid, ref wallet } => Ok(Event::Transaction {
+                wallet: wallet.clone(),
+                transaction: client.get_transaction(txid)?,
+            }),
+            Notification::Block(hash) =>
+                Ok(Event::Block { hash, header: client.get_block_header(&hash)? }),
+        }
+    }
+}
+
+impl FromStr for Notification {
+    type Err = ParseNotificationError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        use ParseNotificationError as E;
+
+        let s = s.trim();
+        let (kind, rest) = match s.find(char::is_whitespace) {
+            Some(i) => (&s[..i], s[i..].trim_start()),
+            None => (s, ""),
+        };
+        let (value, rest) = match rest.find(char::is_whitespace) {
+            Some(i) => (&rest[..i], rest[i..].trim()),
+            None => (rest, ""),
+        };
+
+        match kind {
+            "wallet" => {
+                let txid = value.parse().map_err(E::Txid)?;
+                // Wallet names may contain whitespace, the name is the rest of the line.
+                let wallet = if rest.is_empty() { None } else { Some(rest.to_owned()) };
+                Ok(Notification::Wallet { txid, wallet })
+            }
+            "block" => {
+                if !rest.is_empty() {
+                    return Err(E::TrailingData(rest.to_owned()));
+                }
+                Ok(Notification::Block(value.parse().map_err(E::BlockHash)?))
+            }
+            _ => Err(E::UnknownKind(kind.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Notification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Notification::Wallet { txid, wallet: Some(ref wallet) } =>
+                write!(f, "wallet {} {}", txid, wallet),
+            Notification::Wallet { txid, wallet: None } => write!(f, "wallet {}", txid),
+            Notification::Block(hash) => write!(f, "block {}", hash),
+        }
+    }
+}
+
+/// A notification resolved using follow up RPC calls, see [`Notification::resolve`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    /// A wallet transaction was added or changed.
+    Transaction {
+        /// The wallet name, if included in the hook command.
+        wallet: Option<String>,
+        /// The transaction, as returned by `gettransaction`.
+        transaction: model::GetTransaction,
+    },
+    /// The best block changed.
+    Block {
+        /// The hash of the new best block.
+        hash: BlockHash,
+        /// The header of the new best block.
+        header: Header,
+    },
+}
+
+/// An iterator over the notifications read a line at a time from `R`.
+///
+/// Empty lines are skipped. Iteration ends when the reader does, for a named pipe that is once all
+/// writers have closed it.
+#[derive(Debug)]
+pub struct Notifications<R> {
+    reader: R,
+    line: String,
+}
+
+impl<R: BufRead> Notifications<R> {
+    /// Creates an iterator over the notifications read from `reader`.
+    pub fn new(reader: R) -> Self { Self { reader, line: String::new() } }
+}
+
+impl<R: BufRead> Iterator for Notifications<R> {
+    type Item = std::result::Result<Notification, NotifyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) if self.line.trim().is_empty() => continue,
+                Ok(_) => return Some(self.line.parse().map_err(NotifyError::Parse)),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(NotifyError::Io(e))),
+            }
+        }
+    }
+}
+
+/// Listens on a unix socket for notifications written by hook commands.
+///
+/// The socket file is removed when the listener is dropped.
+#[derive(Debug)]
+pub struct NotifyListener {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+impl NotifyListener {
+    /// Creates a listener bound to the socket at `path`.
+    ///
+    /// Fails if `path` already exists, a stale socket from a previous run must be removed first.
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let listener = UnixListener::bind(&path)?;
+        Ok(Self { listener, path })
+    }
+
+    /// Returns the path of the socket.
+    pub fn path(&self) -> &Path { &self.path }
+
+    /// Returns an iterator over the notifications received, blocking while waiting for hooks.
+    ///
+    /// Each connection may write any number of lines, they are returned in order before the next
+    /// connection is accepted.
+    pub fn incoming(&self) -> Incoming<'_> { Incoming { listener: &self.listener, current: None } }
+}
+
+impl Drop for NotifyListener {
+    fn drop(&mut self) { let _ = fs::remove_file(&self.path); }
+}
+
+/// An iterator over the notifications received by a [`NotifyListener`].
+///
+/// Never returns `None`.
+#[derive(Debug)]
+pub struct Incoming<'a> {
+    listener: &'a UnixListener,
+    current: Option<Notifications<BufReader<UnixStream>>>,
+}
+
+impl Iterator for Incoming<'_> {
+    type Item = std::result::Result<Notification, NotifyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut notifications) = self.current {
+                match notifications.next() {
+                    Some(item) => return Some(item),
+                    None => self.current = None,
+                }
+            }
+            match self.listener.accept() {
+                Ok((stream, _)) => self.current = Some(Notifications::new(BufReader::new(stream))),
+                Err(e) => return Some(Err(NotifyError::Io(e))),
+            }
+        }
+    }
+}
+
+/// Error reading a notification.
+#[derive(Debug)]
+pub enum NotifyError {
+    /// Failed to read from the socket or pipe.
+    Io(io::Error),
+    /// Failed to parse a notification line.
+    Parse(ParseNotificationError),
+}
+
+impl fmt::Display for NotifyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use NotifyError::*;
+
+        match *self {
+            Io(ref e) => write!(f, "failed to read notification: {}", e),
+            Parse(ref e) => write!(f, "failed to parse notification: {}", e),
+        }
+    }
+}
+
+impl error::Error for NotifyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use NotifyError::*;
+
+        match *self {
+            Io(ref e) => Some(e),
+            Parse(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error parsing a notification line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseNotificationError {
+    /// The line does not start with `wallet` or `block`.
+    UnknownKind(String),
+    /// Invalid transaction ID.
+    Txid(HexToArrayError),
+    /// Invalid block hash.
+    BlockHash(HexToArrayError),
+    /// A block notification has data after the block hash.
+    TrailingData(String),
+}
+
+impl fmt::Display for ParseNotificationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseNotificationError::*;
+
+        match *self {
+            UnknownKind(ref kind) => write!(f, "unknown notification kind `{}`", kind),
+            Txid(ref e) => write!(f, "invalid txid: {}", e),
+            BlockHash(ref e) => write!(f, "invalid block hash: {}", e),
+            TrailingData(ref data) => write!(f, "unexpected data after block hash: `{}`", data),
+        }
+    }
+}
+
+impl error::Error for ParseNotificationError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use ParseNotificationError::*;
+
+        match *self {
+            Txid(ref e) | BlockHash(ref e) => Some(e),
+            UnknownKind(_) | TrailingData(_) => None,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Named connection profiles, loaded from a TOML file.
+//!
+//! Users commonly run several nodes (e.g. mainnet, testnet, signet and regtest), a profile file
+//! describes how to connect to each of them:
+//!
+//! ```toml
+//! # The profile used by `ClientProfiles::default_profile`.
+//! default = "regtest"
+//!
+//! [profiles.mainnet]
+//! url = "http://127.0.0.1:8332"
+//! cookie_file = "/home/user/.bitcoin/.cookie"
+//! network = "main"
+//! wallet = "hot"
+//! timeout_secs = 30
+//!
+//! [profiles.regtest]
+//! url = "http://127.0.0.1:18443"
+//! user = "user"
+//! password = "password"
+//! network = "regtest"
+//! ```
+//!
+//! `network` uses Core's chain names (as passed to `-chain`). A profile can use either a cookie
+//! file or a user and password, or neither. Clients are created from a profile using
+//! `Client::from_profile`.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{error, fmt, fs, io};
+
+use bitcoin::Network;
+use serde::Deserialize;
+
+use crate::client_sync::Auth;
+use crate::node_url::NodeUrl;
+
+/// A set of named connection profiles, as loaded from a profile file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientProfiles {
+    /// The name of the default profile, if set.
+    pub default: Option<String>,
+    /// The profiles, by name.
+    pub profiles: BTreeMap<String, ClientProfile>,
+}
+
+impl ClientProfiles {
+    /// Reads all the profiles in the TOML file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ProfileError> {
+        let s = fs::read_to_string(path).map_err(ProfileError::Io)?;
+        Self::from_toml_str(&s)
+    }
+
+    /// Parses all the profiles in `s`, see the [module docs](self) for the format.
+    pub fn from_toml_str(s: &str) -> Result<Self, ProfileError> {
+        let file: ProfileFile = toml::from_str(s).map_err(ProfileError::Toml)?;
+
+        let profiles = file
+            .profiles
+            .into_iter()
+            .map(|(name, raw)| {
+                let profile = raw.into_profile(&name)?;
+                Ok((name, profile))
+            })
+            .collect::<Result<BTreeMap<_, _>, ProfileError>>()?;
+
+        if let Some(ref default) = file.default {
+            if !profiles.contains_key(default) {
+                return Err(ProfileError::UnknownProfile(default.clone()));
+            }
+        }
+        Ok(ClientProfiles { default: file.default, profiles })
+    }
+
+    /// Returns the profile called `name`.
+    pub fn get(&self, name: &str) -> Result<&ClientProfile, ProfileError> {
+        self.profiles.get(name).ok_or_else(|| ProfileError::UnknownProfile(name.to_owned()))
+    }
+
+    /// Returns the default profile.
+    pub fn default_profile(&self) -> Result<&ClientProfile, ProfileError> {
+        match self.default {
+            Some(ref name) => self.get(name),
+            None => Err(ProfileError::NoDefault),
+        }
+    }
+}
+
+/// How to connect to a single node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientProfile {
+    /// The URL of the node's RPC server e.g., `http://127.0.0.1:8332`.
+    pub url: String,
+    /// How to authenticate with the node.
+    pub auth: Auth,
+    /// The wallet to use for wallet RPCs, if any.
+    pub wallet: Option<String>,
+    /// The network the node is expected to be on, if set.
+    pub network: Option<Network>,
+    /// The timeout for requests, the transport's default if not set.
+    pub timeout: Option<Duration>,
+}
+
+impl ClientProfile {
+    /// Reads the profile called `name` from the TOML file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P, name: &str) -> Result<Self, ProfileError> {
+        let profiles = ClientProfiles::from_file(path)?;
+        let profile = profiles.get(name)?;
+        Ok(profile.clone())
+    }
+
+    /// Creates a profile for the node at `url`, with no wallet, network or timeout.
+    pub fn new<S: Into<String>>(url: S, auth: Auth) -> Self {
+        ClientProfile { url: url.into(), auth, wallet: None, network: None, timeout: None }
+    }
+
+    /// Returns the URL to use for requests, including the wallet path if a wallet is set.
+    ///
+    /// If `url` has no port the default port for `network` is used. The URL is returned unchanged
+    /// if it is not a valid [`NodeUrl`], leaving the error to be reported by the transport.
+    pub fn endpoint(&self) -> String {
+        let network = self.network.unwrap_or(Network::Bitcoin);
+        match (NodeUrl::parse(&self.url, network), self.wallet.as_ref()) {
+            (Ok(url), Some(wallet)) => url.with_wallet(wallet.clone()).to_string(),
+            (Ok(url), None) => url.to_string(),
+            (Err(_), _) => self.url.clone(),
+        }
+    }
+}
+
+/// A profile as it appears in the file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileFile {
+    default: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, RawProfile>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawProfile {
+    url: String,
+    user: Option<String>,
+    password: Option<String>,
+    cookie_file: Option<PathBuf>,
+    wallet: Option<String>,
+    network: Option<String>,
+    timeout_secs: Option<u64>,
+}
+
+impl RawProfile {
+    fn into_profile(self, name: &str) -> Result<ClientProfile, ProfileError> {
+        let invalid = |reason: &str| ProfileError::Invalid {
+            profile: name.to_owned(),
+            reason: reason.to_owned(),
+        };
+
+        let auth = match (self.user, self.password, self.cookie_file) {
+            (None, None, None) => Auth::None,
+            (Some(user), Some(password), None) => Auth::UserPass(user, password),
+            (None, None, Some(path)) => Auth::CookieFile(path),
+            (_, _, Some(_)) => return Err(invalid("both a cookie file and a user are set")),
+            _ => return Err(invalid("`user` and `password` must be set together")),
+        };
+        let network = match self.network {
+            Some(chain) => Some(
+                Network::from_core_arg(&chain)
+                    .map_err(|_| invalid(&format!("unknown network: {}", chain)))?,
+            ),
+            None => None,
+        };
+
+        NodeUrl::parse(&self.url, network.unwrap_or(Network::Bitcoin))
+            .map_err(|e| invalid(&format!("invalid url: {}", e)))?;
+
+        Ok(ClientProfile {
+            url: self.url,
+            auth,
+            wallet: self.wallet,
+            network,
+            timeout: self.timeout_secs.map(Duration::from_secs),
+        })
+    }
+}
+
+/// Error when loading connection profiles.
+#[derive(Debug)]
+pub enum ProfileError {
+    /// Failed to read the profile file.
+    Io(io::Error),
+    /// The profile file is not valid TOML or has unexpected fields.
+    Toml(toml::de::Error),
+    /// There is no profile with this name.
+    UnknownProfile(String),
+    /// No default profile is set.
+    NoDefault,
+    /// A profile is invalid.
+    Invalid {
+        /// The name of the profile.
+        profile: String,
+        /// Why the profile is invalid.
+        reason: String,
+    },
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ProfileError::*;
+
+        match *self {
+            Io(ref e) => write!(f, "failed to read profile file: {}", e),
+            Toml(ref e) => write!(f, "failed to parse profile file: {}", e),
+            UnknownProfile(ref name) => write!(f, "no profile called `{}`", name),
+            NoDefault => write!(f, "no default profile is set"),
+            Invalid { ref profile, ref reason } =>
+                write!(f, "invalid profile `{}`: {}", profile, reason),
+        }
+    }
+}
+
+impl error::Error for ProfileError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use ProfileError::*;
+
+        match *self {
+            Io(ref e) => Some(e),
+            Toml(ref e) => Some(e),
+            UnknownProfile(_) | NoDefault | Invalid { .. } => None,
+        }
+    }
+}
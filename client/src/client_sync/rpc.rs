@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A version-generic abstraction over the version specific clients.
+//!
+//! Every version specific `Client` implements [`BitcoindRpc`]. The trait methods return the
+//! version-nonspecific model types, so code written against the trait runs against any supported
+//! version of Core.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bitcoin::address::{Address, NetworkChecked};
+use bitcoin::block::Header;
+use bitcoin::{Amount, BlockHash, Txid};
+
+use crate::client_sync::Result;
+use crate::json::model;
+
+/// The RPC error code returned while the node is starting up (`RPC_IN_WARMUP`).
+const RPC_IN_WARMUP: i32 = -28;
+
+/// How long to wait between polls of the node in [`BitcoindRpc::wait_for_ready`].
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The JSON-RPC methods common to all supported versions of Core.
+///
+/// The version specific clients have inherent methods of the same name that return the version
+/// specific types, those take precedence when calling a method on a concrete `Client`. Use this
+/// trait as a bound (or as a trait object) to write code that is generic over the client.
+pub trait BitcoindRpc {
+    /// Calls `getblockchaininfo`.
+    fn get_blockchain_info(&self) -> Result<model::GetBlockchainInfo>;
+
+    /// Calls `getbestblockhash`.
+    fn get_best_block_hash(&self) -> Result<model::GetBestBlockHash>;
+
+    /// Calls `getrawmempool`.
+    fn get_raw_mempool(&self) -> Result<model::GetRawMempool>;
+
+    /// Calls `getmempoolinfo`.
+    fn get_mempool_info(&self) -> Result<model::GetMempoolInfo>;
+
+    /// Calls `getnetworkinfo`.
+    fn get_network_info(&self) -> Result<model::GetNetworkInfo>;
+
+    /// Calls `generatetoaddress`.
+    fn generate_to_address(
+        &self,
+        nblocks: usize,
+        address: &Address<NetworkChecked>,
+    ) -> Result<model::GenerateToAddress>;
+
+    /// Calls `getnewaddress`.
+    fn get_new_address(&self) -> Result<model::GetNewAddress>;
+
+    /// Calls `getbalance`.
+    fn get_balance(&self) -> Result<model::GetBalance>;
+
+    /// Calls `sendtoaddress`.
+    fn send_to_address(
+        &self,
+        address: &Address<NetworkChecked>,
+        amount: Amount,
+    ) -> Result<model::SendToAddress>;
+
+    /// Calls `gettransaction`.
+    fn get_transaction(&self, txid: Txid) -> Result<model::GetTransaction>;
+
+    /// Calls `getblockheader` with `verbose` set to false.
+    fn get_block_header(&self, hash: &BlockHash) -> Result<Header>;
+
+    /// Returns the state of the node, using `getblockchaininfo`.
+    ///
+    /// The node is considered synced once it is out of initial block download and the
+    /// verification progress is at least `sync_threshold`, see [`model::IbdState::is_synced`].
+    fn node_state(&self, sync_threshold: f64) -> Result<NodeState> {
+        match self.get_blockchain_info() {
+            Ok(info) => {
+                let state = info.ibd_state();
+                if state.is_synced(sync_threshold) {
+                    Ok(NodeState::Synced(state))
+                } else {
+                    Ok(NodeState::InitialBlockDownload(state))
+                }
+            }
+            Err(e) if e.rpc_code() == Some(RPC_IN_WARMUP) =>
+                Ok(NodeState::Warmup(warmup_message(&e))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Polls the node until it has finished starting up, or `timeout` elapses.
+    ///
+    /// Errors calling the node (e.g. connection refused while the node is starting) are retried,
+    /// the last one is returned if the node never responds. Returns [`NodeState::Warmup`] if the
+    /// node is still starting up when `timeout` elapses.
+    fn wait_for_ready(&self, sync_threshold: f64, timeout: Duration) -> Result<NodeState> {
+        let start = Instant::now();
+        loop {
+            let state = self.node_state(sync_threshold);
+            match state {
+                Ok(NodeState::Warmup(_)) | Err(_) if start.elapsed() < timeout =>
+                    thread::sleep(POLL_INTERVAL),
+                _ => return state,
+            }
+        }
+    }
+}
+
+/// The state of a node, as returned by [`BitcoindRpc::node_state`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum NodeState {
+    /// The node is starting up (e.g. loading the block index), with the status message.
+    Warmup(String),
+    /// The node is in initial block download, or has not yet verified enough of the chain.
+    InitialBlockDownload(model::IbdState),
+    /// The node is synced.
+    Synced(model::IbdState),
+}
+
+impl NodeState {
+    /// Returns the IBD state, `None` while the node is starting up.
+    pub fn ibd_state(&self) -> Option<&model::IbdState> {
+        match *self {
+            NodeState::Warmup(_) => None,
+            NodeState::InitialBlockDownload(ref state) | NodeState::Synced(ref state) =>
+                Some(state),
+        }
+    }
+
+    /// Returns true if the node is synced.
+    pub fn is_synced(&self) -> bool { matches!(*self, NodeState::Synced(_)) }
+}
+
+/// Returns the message of the warmup error `e` e.g., "Loading block index...".
+fn warmup_message(e: &crate::client_sync::Error) -> String {
+    match *e {
+        crate::client_sync::Error::JsonRpc(jsonrpc::error::Error::Rpc(ref e)) => e.message.clone(),
+        _ => e.to_string(),
+    }
+}
+
+/// Implements [`BitcoindRpc`] for a version specific client.
+///
+/// Requires `Client` to be in scope and to implement all the methods of the trait, returning the
+/// version specific types.
+#[macro_export]
+macro_rules! impl_client_bitcoind_rpc {
+    () => {
+        impl $crate::client_sync::BitcoindRpc for Client {
+            fn get_blockchain_info(&self) -> Result<$crate::json::model::GetBlockchainInfo> {
+                Client::get_blockchain_info(self)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn get_best_block_hash(&self) -> Result<$crate::json::model::GetBestBlockHash> {
+                Client::get_best_block_hash(self)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn get_raw_mempool(&self) -> Result<$crate::json::model::GetRawMempool> {
+                Client::get_raw_mempool(self)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn get_mempool_info(&self) -> Result<$crate::json::model::GetMempoolInfo> {
+                Client::get_mempool_info(self)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn get_network_info(&self) -> Result<$crate::json::model::GetNetworkInfo> {
+                Client::get_network_info(self)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn generate_to_address(
+                &self,
+                nblocks: usize,
+                address: &Address<NetworkChecked>,
+            ) -> Result<$crate::json::model::GenerateToAddress> {
+                Client::generate_to_address(self, nblocks, address)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn get_new_address(&self) -> Result<$crate::json::model::GetNewAddress> {
+                Client::get_new_address(self)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn get_balance(&self) -> Result<$crate::json::model::GetBalance> {
+                Client::get_balance(self)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn send_to_address(
+                &self,
+                address: &Address<NetworkChecked>,
+                amount: Amount,
+            ) -> Result<$crate::json::model::SendToAddress> {
+                Client::send_to_address(self, address, amount)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn get_transaction(&self, txid: Txid) -> Result<$crate::json::model::GetTransaction> {
+                Client::get_transaction(self, txid)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))
+            }
+
+            fn get_block_header(&self, hash: &BlockHash) -> Result<bitcoin::block::Header> {
+                Ok(Client::get_block_header(self, hash)?.block_header()?)
+            }
+        }
+    };
+}
@@ -0,0 +1,584 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The transport used by the clients to send JSON-RPC requests.
+//!
+//! The clients default to [`MinreqTransport`], any other [`Transport`] can be used by creating the
+//! client with `Client::from_transport` (e.g. a mock transport in tests, or a transport that
+//! records and replays requests). Wrap a transport in a [`RetryTransport`] to retry requests that
+//! fail before the node responds.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use std::{error, fmt, thread};
+
+use bitcoin::base64::engine::general_purpose::STANDARD as BASE64;
+use bitcoin::base64::Engine as _;
+#[doc(inline)]
+pub use jsonrpc::{Request, Response};
+
+use crate::client_sync::{Credentials, Error, Result};
+use crate::node_url::NodeUrl;
+
+/// A transport capable of sending JSON-RPC requests to `bitcoind`.
+pub trait Transport: Send + Sync + 'static {
+    /// Sends `request` and returns the response.
+    ///
+    /// An error response from the server (i.e., an RPC error) is not a transport error, it should
+    /// be returned as a [`Response`] with the `error` field set.
+    fn send_request(&self, request: &Request) -> Result<Response>;
+
+    /// Sends `requests` as a single batch and returns the responses, in any order.
+    ///
+    /// The default implementation sends the requests one at a time.
+    fn send_batch(&self, requests: &[Request]) -> Result<Vec<Response>> {
+        requests.iter().map(|request| self.send_request(request)).collect()
+    }
+
+    /// Formats the target of this transport e.g., the URL.
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+/// The default transport, HTTP using `minreq`.
+#[derive(Clone, Debug)]
+pub struct MinreqTransport(jsonrpc::minreq_http::MinreqHttpTransport);
+
+impl MinreqTransport {
+    /// Creates a transport to `url` using the optional basic auth credentials and timeout.
+    pub fn new(url: &str, user_pass: Option<UserPass>, timeout: Option<Duration>) -> Self {
+        let mut builder = jsonrpc::minreq_http::Builder::new()
+            .url(url)
+            .expect("jsonrpc v0.18, this function does not error");
+        if let Some((user, pass)) = user_pass {
+            builder = builder.basic_auth(user, pass);
+        }
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        MinreqTransport(builder.build())
+    }
+}
+
+impl Transport for MinreqTransport {
+    fn send_request(&self, request: &Request) -> Result<Response> {
+        Ok(jsonrpc::Transport::send_request(&self.0, request.clone())?)
+    }
+
+    fn send_batch(&self, requests: &[Request]) -> Result<Vec<Response>> {
+        Ok(jsonrpc::Transport::send_batch(&self.0, requests)?)
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        jsonrpc::Transport::fmt_target(&self.0, f)
+    }
+}
+
+/// A transport that retries requests failing with a transport error, backing off between attempts.
+///
+/// Only failures that happen before the request is sent are retried (e.g. connection refused or a
+/// missing cookie file while the node restarts), RPC errors and HTTP error responses are returned
+/// immediately. The backoff starts at `initial_backoff` and doubles after each attempt.
+///
+/// Errors after the request was sent, e.g. a read timeout, are never retried: the node may have
+/// executed the call, and sending it again is not safe for RPCs that mutate the wallet (e.g.
+/// `sendtoaddress`, `bumpfee` or `walletpassphrase`). Transports that are not known to this crate
+/// are only retried if they return an [`io::Error`] with [`io::ErrorKind::ConnectionRefused`].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bitcoind_json_rpc_client::client_sync::transport::RetryTransport;
+/// # use bitcoind_json_rpc_client::client_sync::{v26::Client, MockTransport};
+/// let mock = MockTransport::new();
+/// mock.push_connection_refused("getblockcount");
+/// mock.push_result("getblockcount", 101);
+///
+/// let client = Client::from_transport(RetryTransport::new(mock.clone(), 2, Duration::ZERO));
+/// let count: u64 = client.call("getblockcount", &[]).unwrap();
+/// assert_eq!(count, 101);
+/// assert_eq!(mock.calls_to("getblockcount").len(), 2);
+///
+/// // The node may have received the request, it is not sent again.
+/// mock.push_timeout("getblockcount");
+/// assert!(client.call::<u64>("getblockcount", &[]).is_err());
+/// assert_eq!(mock.calls_to("getblockcount").len(), 3);
+/// ```
+pub struct RetryTransport {
+    inner: Arc<dyn Transport>,
+    max_retries: u32,
+    initial_backoff: Duration,
+}
+
+impl RetryTransport {
+    /// Creates a transport that retries requests sent using `inner` up to `max_retries` times.
+    pub fn new<T: Transport>(inner: T, max_retries: u32, initial_backoff: Duration) -> Self {
+        Self::from_arc(Arc::new(inner), max_retries, initial_backoff)
+    }
+
+    fn from_arc(inner: Arc<dyn Transport>, max_retries: u32, initial_backoff: Duration) -> Self {
+        RetryTransport { inner, max_retries, initial_backoff }
+    }
+
+    /// Calls `send` until it succeeds, fails with an error that is not retried, or runs out of
+    /// retries.
+    fn retry<T, F: Fn() -> Result<T>>(&self, send: F) -> Result<T> {
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 0;
+        loop {
+            match send() {
+                Err(e) if attempt < self.max_retries && is_retryable(&e) => {
+                    attempt += 1;
+                    log::debug!(
+                        target: "bitcoind-json-rpc",
+                        "transport error, retry {} of {} in {:?}: {}",
+                        attempt, self.max_retries, backoff, e
+                    );
+                    thread::sleep(backoff);
+                    backoff = backoff.saturating_mul(2);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl fmt::Debug for RetryTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryTransport")
+            .field("max_retries", &self.max_retries)
+            .field("initial_backoff", &self.initial_backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Transport for RetryTransport {
+    fn send_request(&self, request: &Request) -> Result<Response> {
+        self.retry(|| self.inner.send_request(request))
+    }
+
+    fn send_batch(&self, requests: &[Request]) -> Result<Vec<Response>> {
+        self.retry(|| self.inner.send_batch(requests))
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { self.inner.fmt_target(f) }
+}
+
+/// Returns true if `e` is a transport error that occurred before the request was sent.
+fn is_retryable(e: &Error) -> bool {
+    use jsonrpc::minreq_http::Error as MinreqHttpError;
+
+    match *e {
+        // The cookie file is removed when the node shuts down, and written again once it restarts.
+        Error::Io(ref e) => e.kind() == io::ErrorKind::NotFound,
+        Error::JsonRpc(jsonrpc::error::Error::Transport(ref e)) => {
+            if let Some(e) = e.downcast_ref::<MinreqHttpError>() {
+                // `minreq` returns the same I/O error variant for reads, only a refused connection
+                // is known to have happened before the request was sent.
+                return match *e {
+                    MinreqHttpError::Minreq(jsonrpc::minreq::Error::AddressNotFound) => true,
+                    MinreqHttpError::Minreq(jsonrpc::minreq::Error::IoError(ref e)) =>
+                        e.kind() == io::ErrorKind::ConnectionRefused,
+                    _ => false,
+                };
+            }
+            if let Some(e) = e.downcast_ref::<TcpError>() {
+                return matches!(*e, TcpError::Connect(_) | TcpError::SocksReply(_));
+            }
+            if let Some(e) = e.downcast_ref::<io::Error>() {
+                return e.kind() == io::ErrorKind::ConnectionRefused;
+            }
+            false
+        }
+        _ => false,
+    }
+}
+
+/// The configuration of the HTTP transport of a client, see `ClientBuilder`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HttpOptions {
+    /// Timeout for connecting to the node (or proxy).
+    pub(crate) connect_timeout: Option<Duration>,
+    /// Timeout for reading the response, for [`MinreqTransport`] the timeout of the whole request.
+    pub(crate) read_timeout: Option<Duration>,
+    /// The maximum number of retries and the initial backoff.
+    pub(crate) retry: Option<(u32, Duration)>,
+    /// The SOCKS5 proxy to connect through.
+    pub(crate) proxy: Option<SocketAddr>,
+}
+
+impl HttpOptions {
+    /// Returns true if the options can not be implemented using [`MinreqTransport`].
+    pub(crate) fn needs_tcp(&self) -> bool {
+        self.connect_timeout.is_some() || self.proxy.is_some()
+    }
+}
+
+/// Returns the HTTP transport to `url` using `credentials` and `options`.
+pub(crate) fn http_transport(
+    url: &str,
+    credentials: Option<Credentials>,
+    options: &HttpOptions,
+) -> Arc<dyn Transport> {
+    let transport: Arc<dyn Transport> = match credentials {
+        Some(Credentials::UserPass(user, pass)) =>
+            basic_transport(url, Some((user, pass)), options),
+        Some(cookie @ Credentials::Cookie(_)) => Arc::new(CookieTransport {
+            url: url.to_owned(),
+            options: options.clone(),
+            credentials: cookie,
+            current: Mutex::new(None),
+        }),
+        None => basic_transport(url, None, options),
+    };
+    match options.retry {
+        Some((max_retries, backoff)) =>
+            Arc::new(RetryTransport::from_arc(transport, max_retries, backoff)),
+        None => transport,
+    }
+}
+
+/// Returns a transport to `url` authenticated using `user_pass`.
+fn basic_transport(
+    url: &str,
+    user_pass: Option<UserPass>,
+    options: &HttpOptions,
+) -> Arc<dyn Transport> {
+    if options.needs_tcp() {
+        Arc::new(TcpTransport::new(url, user_pass, options))
+    } else {
+        Arc::new(MinreqTransport::new(url, user_pass, options.read_timeout))
+    }
+}
+
+/// The basic auth user and optional password.
+type UserPass = (String, Option<String>);
+
+/// A transport authenticated using a cookie file, rebuilt whenever the cookie changes.
+struct CookieTransport {
+    url: String,
+    options: HttpOptions,
+    credentials: Credentials,
+    /// The credentials the current transport was built with, and the transport.
+    current: Mutex<Option<(UserPass, Arc<dyn Transport>)>>,
+}
+
+impl CookieTransport {
+    fn transport(&self) -> Result<Arc<dyn Transport>> {
+        let user_pass = self.credentials.user_pass()?;
+        // A panic while holding the lock does not leave the transport inconsistent.
+        let mut current = self.current.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((ref built_with, ref transport)) = *current {
+            if *built_with == user_pass {
+                return Ok(transport.clone());
+            }
+        }
+        let transport = basic_transport(&self.url, Some(user_pass.clone()), &self.options);
+        *current = Some((user_pass, transport.clone()));
+        Ok(transport)
+    }
+}
+
+impl Transport for CookieTransport {
+    fn send_request(&self, request: &Request) -> Result<Response> {
+        self.transport()?.send_request(request)
+    }
+
+    fn send_batch(&self, requests: &[Request]) -> Result<Vec<Response>> {
+        self.transport()?.send_batch(requests)
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.url) }
+}
+
+/// HTTP over a `std` TCP stream, optionally connecting through a SOCKS5 proxy.
+///
+/// Used instead of [`MinreqTransport`] if a connect timeout or a proxy is configured, `minreq` only
+/// supports a single timeout and HTTP proxies. A new connection is made for each request.
+struct TcpTransport {
+    /// The URL, parsed as a [`NodeUrl`] when sending a request.
+    url: String,
+    /// The value of the `Authorization` header, if any.
+    authorization: Option<String>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    proxy: Option<SocketAddr>,
+}
+
+impl TcpTransport {
+    fn new(url: &str, user_pass: Option<UserPass>, options: &HttpOptions) -> Self {
+        let authorization = user_pass.map(|(user, pass)| {
+            let credentials = format!("{}:{}", user, pass.unwrap_or_default());
+            format!("Basic {}", BASE64.encode(credentials))
+        });
+        TcpTransport {
+            url: url.to_owned(),
+            authorization,
+            connect_timeout: options.connect_timeout,
+            read_timeout: options.read_timeout,
+            proxy: options.proxy,
+        }
+    }
+
+    /// Connects to `addr`, using the connect timeout if one is set.
+    fn connect_to(&self, addr: &SocketAddr) -> io::Result<TcpStream> {
+        match self.connect_timeout {
+            Some(timeout) => TcpStream::connect_timeout(addr, timeout),
+            None => TcpStream::connect(addr),
+        }
+    }
+
+    /// Connects to the node at `url`, through the proxy if one is set.
+    fn connect(&self, url: &NodeUrl) -> std::result::Result<TcpStream, TcpError> {
+        let host = url.host().trim_start_matches('[').trim_end_matches(']');
+        let port = url.port();
+
+        let stream = match self.proxy {
+            Some(proxy) => {
+                let mut stream = self.connect_to(&proxy).map_err(TcpError::Connect)?;
+                stream.set_read_timeout(self.connect_timeout)?;
+                stream.set_write_timeout(self.connect_timeout)?;
+                // Nothing has been sent to the node until the proxy has connected to it.
+                socks5_connect(&mut stream, host, port).map_err(|e| match e {
+                    TcpError::Io(e) => TcpError::Connect(e),
+                    e => e,
+                })?;
+                stream
+            }
+            None => {
+                let mut last_error = None;
+                let mut stream = None;
+                for addr in (host, port).to_socket_addrs().map_err(TcpError::Connect)? {
+                    match self.connect_to(&addr) {
+                        Ok(s) => {
+                            stream = Some(s);
+                            break;
+                        }
+                        Err(e) => last_error = Some(e),
+                    }
+                }
+                match stream {
+                    Some(stream) => stream,
+                    None => {
+                        let e = last_error.unwrap_or_else(|| {
+                            io::Error::new(io::ErrorKind::NotFound, "host resolved to no addresses")
+                        });
+                        return Err(TcpError::Connect(e));
+                    }
+                }
+            }
+        };
+        stream.set_read_timeout(self.read_timeout)?;
+        stream.set_write_timeout(self.read_timeout)?;
+        Ok(stream)
+    }
+
+    /// Posts `body` to the node at `url` and returns the body of the response.
+    fn post(&self, url: &NodeUrl, body: &[u8]) -> std::result::Result<Vec<u8>, TcpError> {
+        let mut stream = self.connect(url)?;
+
+        // The path is everything after the third `/` of `http://<host>:<port>/...`.
+        let url_string = url.to_string();
+        let path = url_string.splitn(4, '/').nth(3).unwrap_or("");
+
+        let mut head = format!(
+            "POST /{} HTTP/1.1\r\nHost: {}:{}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n",
+            path,
+            url.host(),
+            url.port(),
+            body.len()
+        );
+        if let Some(ref authorization) = self.authorization {
+            head.push_str(&format!("Authorization: {}\r\n", authorization));
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(body)?;
+        stream.flush()?;
+
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let status =
+            line.split_whitespace().nth(1).and_then(|s| s.parse::<u16>().ok()).ok_or_else(
+                || TcpError::InvalidResponse(format!("status line `{}`", line.trim())),
+            )?;
+
+        let mut content_length = None;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                return Err(TcpError::InvalidResponse("truncated headers".to_owned()));
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                let value = value.trim();
+                if name.eq_ignore_ascii_case("content-length") {
+                    let len = value.parse::<usize>().map_err(|_| {
+                        TcpError::InvalidResponse(format!("content length `{}`", value))
+                    })?;
+                    content_length = Some(len);
+                } else if name.eq_ignore_ascii_case("transfer-encoding") {
+                    // Core does not chunk JSON-RPC responses.
+                    return Err(TcpError::InvalidResponse(format!(
+                        "transfer encoding `{}`",
+                        value
+                    )));
+                }
+            }
+        }
+
+        let mut body = Vec::new();
+        match content_length {
+            Some(len) => {
+                body.resize(len, 0);
+                reader.read_exact(&mut body)?;
+            }
+            None => {
+                reader.read_to_end(&mut body)?;
+            }
+        }
+
+        // Core returns RPC errors with a non-200 status and a JSON body.
+        if status != 200 && serde_json::from_slice::<serde_json::Value>(&body).is_err() {
+            let body = String::from_utf8_lossy(&body).into_owned();
+            return Err(TcpError::Http { status, body });
+        }
+        Ok(body)
+    }
+
+    /// Sends `request`, serialized as JSON, and deserializes the response.
+    fn send<Req, Resp>(&self, request: &Req) -> Result<Resp>
+    where
+        Req: serde::Serialize + ?Sized,
+        Resp: for<'a> serde::Deserialize<'a>,
+    {
+        let url = self.url.parse()?;
+        let body = serde_json::to_vec(request)?;
+        let response =
+            self.post(&url, &body).map_err(|e| jsonrpc::error::Error::Transport(Box::new(e)))?;
+        Ok(serde_json::from_slice(&response)?)
+    }
+}
+
+impl Transport for TcpTransport {
+    fn send_request(&self, request: &Request) -> Result<Response> { self.send(request) }
+
+    fn send_batch(&self, requests: &[Request]) -> Result<Vec<Response>> { self.send(requests) }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.url)?;
+        if let Some(proxy) = self.proxy {
+            write!(f, " (via SOCKS5 proxy {})", proxy)?;
+        }
+        Ok(())
+    }
+}
+
+/// Asks the SOCKS5 proxy connected to using `stream` to connect to `host:port`.
+///
+/// Only proxies that do not require authentication (e.g. Tor) are supported. `host` is sent to the
+/// proxy to resolve unless it is an IP address, this allows connecting to onion services.
+fn socks5_connect(
+    stream: &mut TcpStream,
+    host: &str,
+    port: u16,
+) -> std::result::Result<(), TcpError> {
+    // Version 5, one authentication method: no authentication.
+    stream.write_all(&[5, 1, 0])?;
+    let mut method = [0u8; 2];
+    stream.read_exact(&mut method)?;
+    if method != [5, 0] {
+        return Err(TcpError::SocksAuth);
+    }
+
+    // Version 5, connect command, reserved.
+    let mut request = vec![5, 1, 0];
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(1);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            let len = u8::try_from(host.len())
+                .map_err(|_| TcpError::InvalidResponse(format!("host too long `{}`", host)))?;
+            request.push(3);
+            request.push(len);
+            request.extend_from_slice(host.as_bytes());
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request)?;
+
+    // Version, reply, reserved, address type followed by the bound address and port.
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply)?;
+    if reply[1] != 0 {
+        return Err(TcpError::SocksReply(reply[1]));
+    }
+    let address_len = match reply[3] {
+        1 => 4,
+        4 => 16,
+        3 => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len)?;
+            usize::from(len[0])
+        }
+        atyp => return Err(TcpError::InvalidResponse(format!("SOCKS5 address type {}", atyp))),
+    };
+    let mut bound = vec![0u8; address_len + 2];
+    stream.read_exact(&mut bound)?;
+    Ok(())
+}
+
+/// Error sending a request using a [`TcpTransport`].
+#[derive(Debug)]
+enum TcpError {
+    /// Failed to connect to the node (or proxy), nothing has been sent to the node.
+    Connect(io::Error),
+    /// Failed to write the request or read the response.
+    Io(io::Error),
+    /// The SOCKS5 proxy requires authentication.
+    SocksAuth,
+    /// The SOCKS5 proxy failed to connect to the node, with this reply code.
+    SocksReply(u8),
+    /// The node returned an HTTP error without a JSON body.
+    Http { status: u16, body: String },
+    /// The response (from the node or the proxy) is malformed.
+    InvalidResponse(String),
+}
+
+impl From<io::Error> for TcpError {
+    fn from(e: io::Error) -> Self { TcpError::Io(e) }
+}
+
+impl fmt::Display for TcpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TcpError::*;
+
+        match *self {
+            Connect(ref e) => write!(f, "failed to connect: {}", e),
+            Io(ref e) => write!(f, "I/O error: {}", e),
+            SocksAuth => write!(f, "SOCKS5 proxy requires authentication"),
+            SocksReply(code) => write!(f, "SOCKS5 proxy failed to connect (reply {})", code),
+            Http { status, ref body } => write!(f, "HTTP status {}: {}", status, body),
+            InvalidResponse(ref what) => write!(f, "invalid response: {}", what),
+        }
+    }
+}
+
+impl error::Error for TcpError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            TcpError::Connect(ref e) | TcpError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
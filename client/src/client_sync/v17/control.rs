@@ -9,6 +9,52 @@
 //!
 //! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
+/// Implements bitcoind JSON-RPC API method `getmemoryinfo`
+#[macro_export]
+macro_rules! impl_client_v17__getmemoryinfo {
+    () => {
+        impl Client {
+            /// Gets general statistics about memory usage in the daemon.
+            pub fn get_memory_info(&self) -> Result<GetMemoryInfoStats> {
+                let mode = $crate::client_sync::v17::MemoryInfoMode::Stats;
+                self.call("getmemoryinfo", &[into_json(mode)?])
+            }
+
+            /// Gets the XML document describing low-level heap state.
+            ///
+            /// Only available if `bitcoind` was compiled with glibc, Core returns an RPC error
+            /// otherwise.
+            pub fn get_memory_info_malloc_info(&self) -> Result<GetMemoryInfoMallocInfo> {
+                let mode = $crate::client_sync::v17::MemoryInfoMode::MallocInfo;
+                self.call("getmemoryinfo", &[into_json(mode)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `logging`
+#[macro_export]
+macro_rules! impl_client_v17__logging {
+    () => {
+        impl Client {
+            /// Gets the debug logging status of each category.
+            pub fn get_logging(&self) -> Result<Logging> { self.call("logging", &[]) }
+
+            /// Adds `include` to and then removes `exclude` from the debug logging categories.
+            ///
+            /// Returns the resulting status of each category, a category in both lists ends up
+            /// excluded.
+            pub fn logging(
+                &self,
+                include: &[$crate::json::model::LogCategory],
+                exclude: &[$crate::json::model::LogCategory],
+            ) -> Result<Logging> {
+                self.call("logging", &[into_json(include)?, into_json(exclude)?])
+            }
+        }
+    };
+}
+
 /// Implements bitcoind JSON-RPC API method `stop`
 #[macro_export]
 macro_rules! impl_client_v17__stop {
@@ -18,3 +64,18 @@ macro_rules! impl_client_v17__stop {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `setmocktime`
+///
+/// This is a hidden, regtest-only, RPC (see `REGTEST_ONLY_METHODS`).
+#[macro_export]
+macro_rules! impl_client_v17__setmocktime {
+    () => {
+        impl Client {
+            /// Sets the node's local time to `timestamp` (seconds since epoch), `0` disables.
+            pub fn set_mock_time(&self, timestamp: u64) -> Result<()> {
+                self.call("setmocktime", &[timestamp.into()])
+            }
+        }
+    };
+}
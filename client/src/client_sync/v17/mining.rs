@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getmininginfo`
+#[macro_export]
+macro_rules! impl_client_v17__getmininginfo {
+    () => {
+        impl Client {
+            pub fn get_mining_info(&self) -> Result<GetMiningInfo> {
+                self.call("getmininginfo", &[])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getblocktemplate`
+#[macro_export]
+macro_rules! impl_client_v17__getblocktemplate {
+    () => {
+        impl Client {
+            pub fn get_block_template(
+                &self,
+                request: &$crate::client_sync::v17::TemplateRequest,
+            ) -> Result<GetBlockTemplate> {
+                self.call("getblocktemplate", &[into_json(request)?])
+            }
+        }
+    };
+}
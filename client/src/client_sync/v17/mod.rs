@@ -7,46 +7,161 @@
 pub mod blockchain;
 pub mod control;
 pub mod generating;
+pub mod mining;
 pub mod network;
 pub mod raw_transactions;
+pub mod util;
 pub mod wallet;
 
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
 use bitcoin::address::{Address, NetworkChecked};
-use bitcoin::{Amount, Block, BlockHash, Txid};
-use serde::{Deserialize, Serialize};
+use bitcoin::{
+    Amount, Block, BlockHash, FeeRate, OutPoint, PrivateKey, PublicKey, ScriptBuf, Txid,
+};
+use serde::ser::SerializeMap as _;
+use serde::{Deserialize, Serialize, Serializer};
 
-use crate::client_sync::{handle_defaults, into_json};
+use crate::client_sync::{fee_rate_to_btc_per_kvb, handle_defaults, into_json};
+use crate::json::model;
 use crate::json::v17::*;
 
 crate::define_jsonrpc_minreq_client!("v17");
+crate::impl_client_block_source!();
+crate::impl_client_bitcoind_rpc!();
 
 // == Blockchain ==
 crate::impl_client_v17__getblockchaininfo!();
 crate::impl_client_v17__getbestblockhash!();
 crate::impl_client_v17__getblock!();
+crate::impl_client_v17__getblockheader!();
+crate::impl_client_v17__getblockhash!();
+crate::impl_client_v17__getchaintips!();
+crate::impl_client_v17__preciousblock!();
+crate::impl_client_v17__invalidateblock!();
+crate::impl_client_v17__reconsiderblock!();
 crate::impl_client_v17__gettxout!();
+crate::impl_client_v17__gettxoutproof!();
+crate::impl_client_v17__verifytxoutproof!();
+crate::impl_client_v17__verifychain!();
+crate::impl_client_v17__gettxoutsetinfo!();
+crate::impl_client_v17__scantxoutset!();
+crate::impl_client_v17__getrawmempool!();
+crate::impl_client_v17__getmempoolentry!();
+crate::impl_client_v17__getmempoolinfo!();
 
 // == Control ==
+crate::impl_client_v17__getmemoryinfo!();
+crate::impl_client_v17__logging!();
 crate::impl_client_v17__stop!();
+crate::impl_client_v17__setmocktime!();
 
 // == Generating ==
 crate::impl_client_v17__generatetoaddress!();
 
+// == Mining ==
+crate::impl_client_v17__getmininginfo!();
+crate::impl_client_v17__getblocktemplate!();
+
 // == Network ==
 crate::impl_client_v17__getnetworkinfo!();
-crate::impl_client_check_expected_server_version!({ [170100] });
+crate::impl_client_v17__getpeerinfo!();
+crate::impl_client_check_expected_server_version!({ [170000, 170001, 170100, 170200] });
 
 // == Rawtransactions ==
+crate::impl_client_v17__combinepsbt!();
+crate::impl_client_v17__createrawtransaction!();
+crate::impl_client_v17__fundrawtransaction!();
+crate::impl_client_v17__getrawtransaction!();
+crate::impl_client_v17__decodepsbt!();
+crate::impl_client_v17__decoderawtransaction!();
+crate::impl_client_v17__finalizepsbt!();
 crate::impl_client_v17__sendrawtransaction!();
+crate::impl_client_v17__testmempoolaccept!();
+
+// == Util ==
+crate::impl_client_v17__estimatesmartfee!();
+crate::impl_client_v17__verifymessage!();
 
 // == Wallet ==
+crate::impl_client_v17__addmultisigaddress!();
 crate::impl_client_v17__createwallet!();
 crate::impl_client_v17__unloadwallet!();
 crate::impl_client_v17__loadwallet!();
 crate::impl_client_v17__getnewaddress!();
+crate::impl_client_v17__getrawchangeaddress!();
+crate::impl_client_v17__getaddressinfo!();
+crate::impl_client_v17__getwalletinfo!();
 crate::impl_client_v17__getbalance!();
 crate::impl_client_v17__sendtoaddress!();
 crate::impl_client_v17__gettransaction!();
+crate::impl_client_v17__listtransactions!();
+crate::impl_client_v17__listsinceblock!();
+crate::impl_client_v17__importmulti!();
+crate::impl_client_v17__importaddress!();
+crate::impl_client_v17__rescanblockchain!();
+crate::impl_client_v17__listreceivedbyaddress!();
+crate::impl_client_v17__listunspent!();
+crate::impl_client_v17__lockunspent!();
+crate::impl_client_v17__getaddressesbylabel!();
+crate::impl_client_v17__getreceivedbyaddress!();
+crate::impl_client_v17__bumpfee!();
+crate::impl_client_v17__walletcreatefundedpsbt!();
+crate::impl_client_v17__walletprocesspsbt!();
+crate::impl_client_v17__signrawtransactionwithwallet!();
+crate::impl_client_v17__signmessage!();
+crate::impl_client_v17__accounts!();
+crate::impl_client_v17__listwallets!();
+crate::impl_client_v17__encryptwallet!();
+crate::impl_client_v17__walletpassphrase!();
+crate::impl_client_v17__walletlock!();
+crate::impl_client_v17__walletpassphrasechange!();
+
+/// The `mode` argument to the `getmemoryinfo` RPC.
+///
+/// Each mode returns a different type so is used by a separate `Client` function:
+/// `get_memory_info` and `get_memory_info_malloc_info`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryInfoMode {
+    /// General statistics about memory usage in the daemon.
+    Stats,
+    /// An XML string describing low-level heap state (glibc only).
+    MallocInfo,
+}
+
+/// Argument to the `Client::get_block_template` function.
+///
+/// Core requires the `segwit` rule to be set, use `TemplateRequest::default()` for a plain
+/// template request or add the `signet` rule when talking to a signet node.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TemplateRequest {
+    /// This must be set to "template", "proposal" (see BIP 23), or omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// Client side supported features.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<String>,
+    /// Client side supported softfork deployments.
+    pub rules: Vec<String>,
+}
+
+impl Default for TemplateRequest {
+    fn default() -> Self {
+        TemplateRequest { mode: None, capabilities: vec![], rules: vec!["segwit".to_owned()] }
+    }
+}
+
+impl TemplateRequest {
+    /// Returns a template request with the `segwit` and `signet` rules set.
+    pub fn signet() -> Self {
+        TemplateRequest {
+            rules: vec!["segwit".to_owned(), "signet".to_owned()],
+            ..Default::default()
+        }
+    }
+}
 
 /// Argument to the `Client::get_new_address_with_type` function.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -69,3 +184,711 @@ impl fmt::Display for AddressType {
         fmt::Display::fmt(s, f)
     }
 }
+
+/// Argument to the `Client::wallet_process_psbt_with_options` function.
+///
+/// `Default` signs taproot inputs with `SIGHASH_DEFAULT` and other inputs with `SIGHASH_ALL`, it
+/// is only accepted by Core v22 onwards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SighashType {
+    #[serde(rename = "DEFAULT")]
+    Default,
+    #[serde(rename = "ALL")]
+    All,
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "SINGLE")]
+    Single,
+    #[serde(rename = "ALL|ANYONECANPAY")]
+    AllPlusAnyoneCanPay,
+    #[serde(rename = "NONE|ANYONECANPAY")]
+    NonePlusAnyoneCanPay,
+    #[serde(rename = "SINGLE|ANYONECANPAY")]
+    SinglePlusAnyoneCanPay,
+}
+
+impl fmt::Display for SighashType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SighashType::*;
+
+        let s = match *self {
+            Default => "DEFAULT",
+            All => "ALL",
+            None => "NONE",
+            Single => "SINGLE",
+            AllPlusAnyoneCanPay => "ALL|ANYONECANPAY",
+            NonePlusAnyoneCanPay => "NONE|ANYONECANPAY",
+            SinglePlusAnyoneCanPay => "SINGLE|ANYONECANPAY",
+        };
+        fmt::Display::fmt(s, f)
+    }
+}
+
+/// An input argument to the `Client::create_raw_transaction` function.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateRawTransactionInput {
+    /// The output to spend.
+    #[serde(flatten, with = "crate::json::serde_outpoint")]
+    pub outpoint: OutPoint,
+    /// The sequence number, Core sets it depending on the locktime if not given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u32>,
+}
+
+impl From<OutPoint> for CreateRawTransactionInput {
+    fn from(outpoint: OutPoint) -> Self { CreateRawTransactionInput { outpoint, sequence: None } }
+}
+
+/// An output argument to the `Client::create_raw_transaction` function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CreateRawTransactionOutput {
+    /// Pays the amount to the address.
+    Address(Address<NetworkChecked>, Amount),
+    /// An `OP_RETURN` output carrying the data.
+    Data(Vec<u8>),
+}
+
+impl Serialize for CreateRawTransactionOutput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use bitcoin::hex::DisplayHex as _;
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        match *self {
+            CreateRawTransactionOutput::Address(ref address, amount) =>
+                map.serialize_entry(&address.to_string(), &amount.to_btc())?,
+            CreateRawTransactionOutput::Data(ref data) =>
+                map.serialize_entry("data", &data.to_lower_hex_string())?,
+        }
+        map.end()
+    }
+}
+
+/// A single request for the `importmulti` method.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ImportMultiRequest {
+    /// The descriptor to import (v0.18 onwards), either this or `script_pubkey` must be set.
+    #[serde(rename = "desc", skip_serializing_if = "Option::is_none")]
+    pub descriptor: Option<String>,
+    /// The script or address to import, either this or `descriptor` must be set.
+    #[serde(rename = "scriptPubKey", skip_serializing_if = "Option::is_none")]
+    pub script_pubkey: Option<ImportMultiScriptPubkey>,
+    /// Time from which to rescan the chain for transactions.
+    pub timestamp: ImportTimestamp,
+    /// The redeem script, allowed only if `script_pubkey` is a P2SH (or P2SH-P2WSH) script.
+    #[serde(rename = "redeemscript", skip_serializing_if = "Option::is_none")]
+    pub redeem_script: Option<ScriptBuf>,
+    /// The witness script, allowed only if `script_pubkey` is a P2WSH (or P2SH-P2WSH) script.
+    #[serde(rename = "witnessscript", skip_serializing_if = "Option::is_none")]
+    pub witness_script: Option<ScriptBuf>,
+    /// Public keys to import.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub pubkeys: Vec<PublicKey>,
+    /// Private keys to import.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keys: Vec<PrivateKey>,
+    /// The range (start, end) to import, if a ranged descriptor is used (v0.18 onwards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<(u32, u32)>,
+    /// Whether matching outputs should be treated as not incoming payments (e.g. change).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    /// Whether matching outputs should be considered watch-only.
+    #[serde(rename = "watchonly", skip_serializing_if = "Option::is_none")]
+    pub watch_only: Option<bool>,
+    /// Label to assign to the address, only allowed with `internal` false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Whether imported public keys should be added to the keypool (v0.18 onwards).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keypool: Option<bool>,
+}
+
+impl ImportMultiRequest {
+    /// Creates a request to import `descriptor` (v0.18 onwards).
+    pub fn descriptor<S: Into<String>>(descriptor: S, timestamp: ImportTimestamp) -> Self {
+        ImportMultiRequest { descriptor: Some(descriptor.into()), ..Self::empty(timestamp) }
+    }
+
+    /// Creates a request to import `address`.
+    pub fn address(address: Address<NetworkChecked>, timestamp: ImportTimestamp) -> Self {
+        let script_pubkey = Some(ImportMultiScriptPubkey::Address(address));
+        ImportMultiRequest { script_pubkey, ..Self::empty(timestamp) }
+    }
+
+    /// Creates a request to import `script`.
+    pub fn script(script: ScriptBuf, timestamp: ImportTimestamp) -> Self {
+        let script_pubkey = Some(ImportMultiScriptPubkey::Script(script));
+        ImportMultiRequest { script_pubkey, ..Self::empty(timestamp) }
+    }
+
+    fn empty(timestamp: ImportTimestamp) -> Self {
+        ImportMultiRequest {
+            descriptor: None,
+            script_pubkey: None,
+            timestamp,
+            redeem_script: None,
+            witness_script: None,
+            pubkeys: vec![],
+            keys: vec![],
+            range: None,
+            internal: None,
+            watch_only: None,
+            label: None,
+            keypool: None,
+        }
+    }
+}
+
+/// The `scriptPubKey` of an [`ImportMultiRequest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportMultiScriptPubkey {
+    /// The script to import.
+    Script(ScriptBuf),
+    /// The address to import.
+    Address(Address<NetworkChecked>),
+}
+
+impl Serialize for ImportMultiScriptPubkey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match *self {
+            ImportMultiScriptPubkey::Script(ref script) =>
+                serializer.serialize_str(&script.to_hex_string()),
+            ImportMultiScriptPubkey::Address(ref address) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("address", &address.to_string())?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// The address or script to import as watch-only using `importaddress`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportAddressTarget {
+    /// The address to import.
+    Address(Address<NetworkChecked>),
+    /// The script to import, sent hex encoded.
+    Script(ScriptBuf),
+}
+
+impl Serialize for ImportAddressTarget {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match *self {
+            ImportAddressTarget::Address(ref address) =>
+                serializer.serialize_str(&address.to_string()),
+            ImportAddressTarget::Script(ref script) =>
+                serializer.serialize_str(&script.to_hex_string()),
+        }
+    }
+}
+
+/// Options for the `importaddress` method.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportAddressOptions {
+    /// The label to assign to the address (or script), defaults to "".
+    pub label: String,
+    /// Whether to rescan the chain for transactions, defaults to true.
+    ///
+    /// A rescan can take minutes, when importing many addresses set this to false and call
+    /// `rescan_blockchain` once all are imported.
+    pub rescan: bool,
+    /// Whether to also import the P2SH address of the script, defaults to false.
+    ///
+    /// Only valid when importing a script, the script is then the redeem script.
+    pub p2sh: bool,
+}
+
+impl Default for ImportAddressOptions {
+    fn default() -> Self {
+        ImportAddressOptions { label: String::new(), rescan: true, p2sh: false }
+    }
+}
+
+/// Options for the `fundrawtransaction` method.
+///
+/// Fields left as `None` (or empty) are not sent, the node uses its defaults.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundRawTransactionOptions {
+    /// The address to send change to, defaults to a new wallet address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<Address<NetworkChecked>>,
+    /// The index of the change output, defaults to a random position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// Whether to also select inputs which are watch-only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_watching: Option<bool>,
+    /// Whether to lock the selected unspent outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// The fee rate to pay, sent to the node in BTC/kvB.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_btc_per_kvb")]
+    pub fee_rate: Option<FeeRate>,
+    /// The indices of the outputs to deduct the fee from, split equally between them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// Whether the transaction signals BIP-125 replaceability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// Confirmation target in blocks.
+    #[serde(rename = "conf_target", skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u16>,
+    /// The fee estimate mode.
+    #[serde(rename = "estimate_mode", skip_serializing_if = "Option::is_none")]
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+/// Options for the `walletcreatefundedpsbt` method.
+///
+/// Fields left as `None` (or empty) are not sent, the node uses its defaults. From v21 the fee rate
+/// is sent as `fee_rate` in sat/vB instead, see `v21::WalletCreateFundedPsbtOptions`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletCreateFundedPsbtOptions {
+    /// The address to send change to, defaults to a new wallet address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<Address<NetworkChecked>>,
+    /// The index of the change output, defaults to a random position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// The output type to use for change, defaults to the node's `-changetype` setting.
+    #[serde(rename = "change_type", skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<AddressType>,
+    /// Whether to also select inputs which are watch-only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_watching: Option<bool>,
+    /// Whether to lock the selected unspent outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// The fee rate to pay, sent to the node in BTC/kvB.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_btc_per_kvb")]
+    pub fee_rate: Option<FeeRate>,
+    /// The indices of the outputs to deduct the fee from, split equally between them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// Whether the transaction signals BIP-125 replaceability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// Confirmation target in blocks.
+    #[serde(rename = "conf_target", skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u16>,
+    /// The fee estimate mode.
+    #[serde(rename = "estimate_mode", skip_serializing_if = "Option::is_none")]
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+/// Serializes a fee rate in BTC/kvB, the unit expected by the `feeRate` option.
+fn serialize_btc_per_kvb<S: Serializer>(
+    fee_rate: &Option<FeeRate>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match *fee_rate {
+        Some(fee_rate) => serializer.serialize_f64(fee_rate_to_btc_per_kvb(fee_rate)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// The fee estimate mode.
+///
+/// Serialized in upper case, v0.17 to v0.20 only accept upper case (later versions ignore case).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EstimateMode {
+    /// Use the node's default.
+    Unset,
+    /// Estimate with a shorter time horizon, more responsive to short-term drops in fee rates.
+    Economical,
+    /// Estimate with a longer time horizon, less likely to be outbid.
+    Conservative,
+}
+
+/// Options for the `importmulti` method.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ImportMultiOptions {
+    /// Whether to rescan the chain after all imports, defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rescan: Option<bool>,
+}
+
+/// The `timestamp` of an `importmulti` or `importdescriptors` request.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImportTimestamp {
+    /// Do not rescan, the script or descriptor is known to have no history.
+    Now,
+    /// Rescan from this UNIX epoch time.
+    Time(u64),
+}
+
+impl Serialize for ImportTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match *self {
+            ImportTimestamp::Now => serializer.serialize_str("now"),
+            ImportTimestamp::Time(time) => serializer.serialize_u64(time),
+        }
+    }
+}
+
+/// Arguments to the `Client::send_to_address_with` function.
+///
+/// Options left as `None` are sent as `null`, the node uses its defaults.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SendToAddressRequest {
+    /// The address to send to.
+    pub address: Address<NetworkChecked>,
+    /// The amount to send.
+    pub amount: Amount,
+    /// A comment used to store what the transaction is for, stored in the wallet.
+    pub comment: Option<String>,
+    /// A comment to store the name of the recipient, stored in the wallet.
+    pub comment_to: Option<String>,
+    /// Whether the fee is deducted from the amount being sent.
+    pub subtract_fee: Option<bool>,
+    /// Whether to signal BIP-125 replaceability, the node defaults to its `-walletrbf` setting.
+    pub replaceable: Option<bool>,
+    /// Confirmation target in blocks.
+    pub conf_target: Option<u16>,
+    /// The fee estimate mode.
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+impl SendToAddressRequest {
+    /// Creates a request to send `amount` to `address`, all other options use the node's defaults.
+    pub fn new(address: Address<NetworkChecked>, amount: Amount) -> Self {
+        SendToAddressRequest {
+            address,
+            amount,
+            comment: None,
+            comment_to: None,
+            subtract_fee: None,
+            replaceable: None,
+            conf_target: None,
+            estimate_mode: None,
+        }
+    }
+
+    /// Sets the comment stored in the wallet.
+    pub fn comment<S: Into<String>>(mut self, comment: S) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Sets the name of the recipient stored in the wallet.
+    pub fn comment_to<S: Into<String>>(mut self, comment_to: S) -> Self {
+        self.comment_to = Some(comment_to.into());
+        self
+    }
+
+    /// Sets whether the fee is deducted from the amount being sent.
+    pub fn subtract_fee(mut self, subtract_fee: bool) -> Self {
+        self.subtract_fee = Some(subtract_fee);
+        self
+    }
+
+    /// Sets whether the transaction signals BIP-125 replaceability.
+    pub fn replaceable(mut self, replaceable: bool) -> Self {
+        self.replaceable = Some(replaceable);
+        self
+    }
+
+    /// Sets the confirmation target in blocks.
+    pub fn conf_target(mut self, conf_target: u16) -> Self {
+        self.conf_target = Some(conf_target);
+        self
+    }
+
+    /// Sets the fee estimate mode.
+    pub fn estimate_mode(mut self, estimate_mode: EstimateMode) -> Self {
+        self.estimate_mode = Some(estimate_mode);
+        self
+    }
+}
+
+/// Options for the `Client::create_wallet_with_options` function.
+///
+/// Options left as `None` are sent as `null`, the node uses its defaults.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateWalletOptions {
+    /// Disable the possibility of private keys (only watch-only addresses are possible).
+    pub disable_private_keys: Option<bool>,
+}
+
+impl CreateWalletOptions {
+    /// Creates options that use the node's defaults.
+    pub fn new() -> Self { Self::default() }
+
+    /// Sets whether private keys are disabled.
+    pub fn disable_private_keys(mut self, disable: bool) -> Self {
+        self.disable_private_keys = Some(disable);
+        self
+    }
+}
+
+/// The outcome of `Client::broadcast_checked`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BroadcastOutcome {
+    /// The transaction passed `testmempoolaccept` and was broadcast.
+    Broadcast(Txid),
+    /// The transaction failed `testmempoolaccept` with a reason the caller chose to override,
+    /// it was broadcast anyway.
+    Overridden {
+        /// The id of the broadcast transaction.
+        txid: Txid,
+        /// The overridden reject reason.
+        reason: String,
+    },
+    /// The transaction is valid but was rejected by the node's policy, it was not broadcast.
+    PolicyRejected {
+        /// The id of the rejected transaction.
+        txid: Txid,
+        /// The reject reason reported by Core.
+        reason: String,
+    },
+    /// The transaction is invalid, it was not broadcast.
+    ConsensusRejected {
+        /// The id of the rejected transaction.
+        txid: Txid,
+        /// The reject reason reported by Core.
+        reason: String,
+    },
+}
+
+impl BroadcastOutcome {
+    /// Returns the transaction id.
+    pub fn txid(&self) -> Txid {
+        use BroadcastOutcome::*;
+
+        match *self {
+            Broadcast(txid) => txid,
+            Overridden { txid, .. }
+            | PolicyRejected { txid, .. }
+            | ConsensusRejected { txid, .. } => txid,
+        }
+    }
+
+    /// Returns true if the transaction was broadcast.
+    pub fn is_broadcast(&self) -> bool {
+        matches!(*self, BroadcastOutcome::Broadcast(_) | BroadcastOutcome::Overridden { .. })
+    }
+}
+
+/// Thresholds used by `Client::mempool_candidates` to flag transactions at risk.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MempoolCandidateOptions {
+    /// Flag transactions with a descendant score below `mempoolminfee` plus this margin.
+    pub fee_rate_margin: FeeRate,
+    /// The node's `-mempoolexpiry`.
+    pub expiry: Duration,
+    /// Flag transactions that expire within this time.
+    pub expiry_margin: Duration,
+}
+
+impl MempoolCandidateOptions {
+    /// Creates options using Core's default expiry (two weeks), a margin of 1 sat/vB and a day.
+    pub fn new() -> Self { Self::default() }
+}
+
+impl Default for MempoolCandidateOptions {
+    fn default() -> Self {
+        MempoolCandidateOptions {
+            fee_rate_margin: FeeRate::from_sat_per_vb_u32(1),
+            expiry: Duration::from_secs(336 * 60 * 60),
+            expiry_margin: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// A mempool transaction assessed for eviction and expiry risk.
+///
+/// Returned by `Client::mempool_candidates` and `Client::mempool_candidate`, useful to decide when
+/// to fee bump (or rebroadcast) an unconfirmed transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MempoolCandidate {
+    /// The transaction id.
+    pub txid: Txid,
+    /// The fee rate Core orders transactions by for eviction, see
+    /// `model::MempoolEntry::descendant_score`.
+    pub descendant_score: FeeRate,
+    /// The minimum fee rate for a transaction to be accepted to the mempool.
+    pub mempool_min_fee: FeeRate,
+    /// The position of the transaction when the mempool is ordered by descendant score, zero is
+    /// evicted first. `None` if the rest of the mempool was not fetched.
+    pub eviction_rank: Option<usize>,
+    /// True if the descendant score is within the fee rate margin of `mempool_min_fee`.
+    pub near_eviction: bool,
+    /// The time until the transaction expires, zero if it is overdue.
+    pub expires_in: Duration,
+    /// True if the transaction expires within the expiry margin.
+    pub near_expiry: bool,
+}
+
+impl MempoolCandidate {
+    /// Assesses `entry` against `options`, `now` is the current time in seconds since the epoch.
+    pub fn assess(
+        txid: Txid,
+        entry: &model::MempoolEntry,
+        mempool_min_fee: FeeRate,
+        eviction_rank: Option<usize>,
+        options: &MempoolCandidateOptions,
+        now: u64,
+    ) -> Self {
+        let descendant_score = entry.descendant_score().unwrap_or(FeeRate::ZERO);
+        let threshold = mempool_min_fee
+            .to_sat_per_kwu()
+            .saturating_add(options.fee_rate_margin.to_sat_per_kwu());
+        let near_eviction = descendant_score.to_sat_per_kwu() < threshold;
+
+        let expires_at = entry.time.saturating_add(options.expiry.as_secs());
+        let expires_in = Duration::from_secs(expires_at.saturating_sub(now));
+        let near_expiry = expires_in <= options.expiry_margin;
+
+        MempoolCandidate {
+            txid,
+            descendant_score,
+            mempool_min_fee,
+            eviction_rank,
+            near_eviction,
+            expires_in,
+            near_expiry,
+        }
+    }
+
+    /// Returns true if the transaction is near eviction or near expiry.
+    pub fn is_at_risk(&self) -> bool { self.near_eviction || self.near_expiry }
+}
+
+/// A fork of the active chain found by `Client::detect_reorg`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChainFork {
+    /// The last block the stale branch has in common with the active chain.
+    pub fork_point: BlockHash,
+    /// The height of the fork point.
+    pub fork_height: u64,
+    /// The tip of the stale branch.
+    pub stale_tip: BlockHash,
+    /// The number of blocks in the stale branch, i.e., the depth of the reorg.
+    pub stale_blocks: u64,
+}
+
+/// The action of the `scantxoutset` and `scanblocks` methods.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScanAction {
+    /// Start a scan, there can only be one scan in progress at a time.
+    Start,
+    /// Abort the scan in progress.
+    Abort,
+    /// Report the progress of the scan in progress.
+    Status,
+}
+
+/// A scan object of the `scantxoutset` and `scanblocks` methods.
+///
+/// Either a descriptor, or a ranged descriptor and the range of child indexes to scan (Core scans
+/// `0..=1000` if a ranged descriptor is given without a range).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanObject {
+    descriptor: String,
+    range: Option<RangeInclusive<u32>>,
+}
+
+impl ScanObject {
+    /// The maximum number of child indexes Core scans for a single descriptor.
+    pub const MAX_RANGE_LEN: u32 = 1_000_000;
+
+    /// Creates a scan object for `descriptor`, ranged descriptors use Core's default range.
+    pub fn new<S: Into<String>>(descriptor: S) -> Self {
+        ScanObject { descriptor: descriptor.into(), range: None }
+    }
+
+    /// Creates a scan object for the ranged `descriptor`, scanning the child indexes in `range`.
+    pub fn with_range<S: Into<String>>(
+        descriptor: S,
+        range: RangeInclusive<u32>,
+    ) -> std::result::Result<Self, ScanRangeError> {
+        let (start, end) = (*range.start(), *range.end());
+        if start > end {
+            return Err(ScanRangeError::StartAfterEnd { start, end });
+        }
+        // Core rejects indexes that would be hardened.
+        if end >= 1 << 31 {
+            return Err(ScanRangeError::EndTooHigh(end));
+        }
+        if end - start >= Self::MAX_RANGE_LEN {
+            return Err(ScanRangeError::TooLarge { start, end });
+        }
+        Ok(ScanObject { descriptor: descriptor.into(), range: Some(range) })
+    }
+
+    /// Creates a scan object for the ranged `descriptor`, scanning child indexes `0..=end`.
+    pub fn with_end<S: Into<String>>(
+        descriptor: S,
+        end: u32,
+    ) -> std::result::Result<Self, ScanRangeError> {
+        Self::with_range(descriptor, 0..=end)
+    }
+
+    /// Returns the descriptor.
+    pub fn descriptor(&self) -> &str { &self.descriptor }
+
+    /// Returns the range of child indexes to scan, `None` if Core's default is used.
+    pub fn range(&self) -> Option<&RangeInclusive<u32>> { self.range.as_ref() }
+}
+
+impl From<String> for ScanObject {
+    fn from(descriptor: String) -> Self { Self::new(descriptor) }
+}
+
+impl From<&str> for ScanObject {
+    fn from(descriptor: &str) -> Self { Self::new(descriptor) }
+}
+
+impl Serialize for ScanObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self.range {
+            None => serializer.serialize_str(&self.descriptor),
+            Some(ref range) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("desc", &self.descriptor)?;
+                map.serialize_entry("range", &[*range.start(), *range.end()])?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// Error returned when creating a [`ScanObject`] with a range Core rejects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScanRangeError {
+    /// The start of the range is after its end.
+    StartAfterEnd {
+        /// The start of the range.
+        start: u32,
+        /// The end of the range.
+        end: u32,
+    },
+    /// The end of the range is a hardened index.
+    EndTooHigh(u32),
+    /// The range contains more than [`ScanObject::MAX_RANGE_LEN`] indexes.
+    TooLarge {
+        /// The start of the range.
+        start: u32,
+        /// The end of the range.
+        end: u32,
+    },
+}
+
+impl fmt::Display for ScanRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ScanRangeError::*;
+
+        match *self {
+            StartAfterEnd { start, end } =>
+                write!(f, "range start {} is after range end {}", start, end),
+            EndTooHigh(end) => write!(f, "range end {} is too high", end),
+            TooLarge { start, end } => write!(f, "range {}..={} is too large", start, end),
+        }
+    }
+}
+
+impl std::error::Error for ScanRangeError {}
@@ -26,3 +26,13 @@ macro_rules! impl_client_v17__getnetworkinfo {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `getpeerinfo`
+#[macro_export]
+macro_rules! impl_client_v17__getpeerinfo {
+    () => {
+        impl Client {
+            pub fn get_peer_info(&self) -> Result<GetPeerInfo> { self.call("getpeerinfo", &[]) }
+        }
+    };
+}
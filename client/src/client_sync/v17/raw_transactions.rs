@@ -9,6 +9,159 @@
 //!
 //! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
+/// Implements bitcoind JSON-RPC API method `combinepsbt`
+#[macro_export]
+macro_rules! impl_client_v17__combinepsbt {
+    () => {
+        impl Client {
+            pub fn combine_psbt(&self, psbts: &[bitcoin::Psbt]) -> Result<CombinePsbt> {
+                let psbts = psbts.iter().map(|psbt| psbt.to_string()).collect::<Vec<_>>();
+                self.call("combinepsbt", &[into_json(psbts)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `createrawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__createrawtransaction {
+    () => {
+        impl Client {
+            pub fn create_raw_transaction(
+                &self,
+                inputs: &[$crate::client_sync::v17::CreateRawTransactionInput],
+                outputs: &[$crate::client_sync::v17::CreateRawTransactionOutput],
+            ) -> Result<CreateRawTransaction> {
+                self.call("createrawtransaction", &[into_json(inputs)?, into_json(outputs)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `decodepsbt`
+#[macro_export]
+macro_rules! impl_client_v17__decodepsbt {
+    () => {
+        impl Client {
+            pub fn decode_psbt(&self, psbt: &bitcoin::Psbt) -> Result<DecodePsbt> {
+                self.call("decodepsbt", &[into_json(psbt.to_string())?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `decoderawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__decoderawtransaction {
+    () => {
+        impl Client {
+            pub fn decode_raw_transaction(
+                &self,
+                tx: &bitcoin::Transaction,
+            ) -> Result<DecodeRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("decoderawtransaction", &[hex.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `finalizepsbt`
+#[macro_export]
+macro_rules! impl_client_v17__finalizepsbt {
+    () => {
+        impl Client {
+            pub fn finalize_psbt(&self, psbt: &bitcoin::Psbt) -> Result<FinalizePsbt> {
+                self.call("finalizepsbt", &[into_json(psbt.to_string())?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `fundrawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__fundrawtransaction {
+    () => {
+        impl Client {
+            pub fn fund_raw_transaction(
+                &self,
+                tx: &bitcoin::Transaction,
+            ) -> Result<FundRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("fundrawtransaction", &[hex.into()])
+            }
+
+            /// Funds `tx` using the coin selection `options`.
+            pub fn fund_raw_transaction_with_options(
+                &self,
+                tx: &bitcoin::Transaction,
+                options: &FundRawTransactionOptions,
+            ) -> Result<FundRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("fundrawtransaction", &[hex.into(), into_json(options)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getrawtransaction`
+#[macro_export]
+macro_rules! impl_client_v17__getrawtransaction {
+    () => {
+        impl Client {
+            /// Returns a transaction in the mempool, or in a block if the node has `-txindex`.
+            ///
+            /// # Errors
+            ///
+            /// [`Error::TxNotFound`] if the transaction can not be found, the error says whether
+            /// this is because `-txindex` is disabled or still syncing (requires `getindexinfo`,
+            /// added in v0.21).
+            ///
+            /// [`Error::TxNotFound`]: crate::client_sync::Error::TxNotFound
+            pub fn get_raw_transaction(&self, txid: Txid) -> Result<GetRawTransaction> {
+                use $crate::client_sync::TxNotFoundError;
+
+                /// Core's `RPC_INVALID_ADDRESS_OR_KEY`, returned for an unknown transaction.
+                const RPC_INVALID_ADDRESS_OR_KEY: i32 = -5;
+                const INDEX: &str = "txindex";
+
+                match self.call("getrawtransaction", &[into_json(txid)?]) {
+                    Err(e) if e.rpc_code() == Some(RPC_INVALID_ADDRESS_OR_KEY) => {
+                        // Any error here (e.g. method not found before v0.21) means the state of
+                        // the index is unknown.
+                        let index = self
+                            .call::<$crate::json::v21::GetIndexInfo>(
+                                "getindexinfo",
+                                &[INDEX.into()],
+                            )
+                            .ok();
+                        let e = match index.map(|index| index.0.get(INDEX).map(|info| info.synced))
+                        {
+                            Some(None) => TxNotFoundError::TxIndexDisabled(txid),
+                            Some(Some(false)) => TxNotFoundError::TxIndexSyncing(txid),
+                            Some(Some(true)) | None => TxNotFoundError::NotFound(txid),
+                        };
+                        Err(e.into())
+                    }
+                    result => result,
+                }
+            }
+
+            /// Returns a transaction in the block `block_hash`, does not require `-txindex`.
+            pub fn get_raw_transaction_in_block(
+                &self,
+                txid: Txid,
+                block_hash: &bitcoin::BlockHash,
+            ) -> Result<GetRawTransaction> {
+                self.call(
+                    "getrawtransaction",
+                    &[into_json(txid)?, false.into(), into_json(block_hash)?],
+                )
+            }
+        }
+    };
+}
+
 /// Implements bitcoind JSON-RPC API method `sendrawtransaction`
 #[macro_export]
 macro_rules! impl_client_v17__sendrawtransaction {
@@ -24,3 +177,70 @@ macro_rules! impl_client_v17__sendrawtransaction {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `testmempoolaccept`
+///
+/// Also implements `broadcast_checked`, requires `send_raw_transaction`.
+#[macro_export]
+macro_rules! impl_client_v17__testmempoolaccept {
+    () => {
+        impl Client {
+            pub fn test_mempool_accept(
+                &self,
+                tx: &bitcoin::Transaction,
+            ) -> Result<TestMempoolAccept> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("testmempoolaccept", &[into_json([hex])?])
+            }
+
+            /// Broadcasts `tx` only if `testmempoolaccept` accepts it.
+            pub fn broadcast_checked(&self, tx: &bitcoin::Transaction) -> Result<BroadcastOutcome> {
+                self.broadcast_checked_overriding(tx, &[])
+            }
+
+            /// Broadcasts `tx` if `testmempoolaccept` accepts it, or rejects it for a policy
+            /// reason listed in `overrides` (e.g. "txn-already-in-mempool").
+            ///
+            /// Consensus rejections are never overridden. An overridden transaction may still
+            /// be rejected by `sendrawtransaction`, in which case the error is returned.
+            pub fn broadcast_checked_overriding(
+                &self,
+                tx: &bitcoin::Transaction,
+                overrides: &[&str],
+            ) -> Result<BroadcastOutcome> {
+                use $crate::json::model::RejectionKind;
+
+                let model = self
+                    .test_mempool_accept(tx)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::IntoModel(Box::new(e)))?;
+                let acceptance = model
+                    .0
+                    .into_iter()
+                    .next()
+                    .ok_or($crate::client_sync::Error::UnexpectedStructure)?;
+                let txid = acceptance.txid;
+
+                let overridden = match acceptance.rejection_kind() {
+                    None => None,
+                    Some(kind) => {
+                        let reason = acceptance.reject_reason.unwrap_or_default();
+                        match kind {
+                            RejectionKind::Consensus =>
+                                return Ok(BroadcastOutcome::ConsensusRejected { txid, reason }),
+                            RejectionKind::Policy if !overrides.contains(&reason.as_str()) =>
+                                return Ok(BroadcastOutcome::PolicyRejected { txid, reason }),
+                            RejectionKind::Policy => Some(reason),
+                        }
+                    }
+                };
+
+                let sent = self.send_raw_transaction(tx)?.txid()?;
+                match overridden {
+                    Some(reason) => Ok(BroadcastOutcome::Overridden { txid: sent, reason }),
+                    None => Ok(BroadcastOutcome::Broadcast(sent)),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `estimatesmartfee`
+#[macro_export]
+macro_rules! impl_client_v17__estimatesmartfee {
+    () => {
+        impl Client {
+            /// Estimates the fee rate needed for a transaction to start confirming within
+            /// `conf_target` blocks, using the node's default estimate mode.
+            pub fn estimate_smart_fee(&self, conf_target: u16) -> Result<EstimateSmartFee> {
+                self.call("estimatesmartfee", &[into_json(conf_target)?])
+            }
+
+            /// Estimates the fee rate needed for a transaction to start confirming within
+            /// `conf_target` blocks, using `mode`.
+            pub fn estimate_smart_fee_with_mode(
+                &self,
+                conf_target: u16,
+                mode: $crate::client_sync::v17::EstimateMode,
+            ) -> Result<EstimateSmartFee> {
+                self.call("estimatesmartfee", &[into_json(conf_target)?, into_json(mode)?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `verifymessage`
+#[macro_export]
+macro_rules! impl_client_v17__verifymessage {
+    () => {
+        impl Client {
+            /// Verifies that `signature` is a signature of `message` by the key of `address`.
+            ///
+            /// Only P2PKH addresses are supported by Core.
+            pub fn verify_message(
+                &self,
+                address: &Address<NetworkChecked>,
+                signature: &$crate::bitcoin::sign_message::MessageSignature,
+                message: &str,
+            ) -> Result<bool> {
+                let args =
+                    [address.to_string().into(), signature.to_base64().into(), message.into()];
+                self.call("verifymessage", &args)
+            }
+        }
+    };
+}
@@ -1,43 +0,0 @@
-// SPDX-License-Identifier: CC0-1.0
-
-//! A JSON-RPC client for testing against Bitcoin Core `v0.18.1`.
-//!
-//! We ignore option arguments unless they effect the shape of the returned JSON data.
-
-use bitcoin::address::{Address, NetworkChecked};
-use bitcoin::{Amount, Block, BlockHash, Txid};
-
-use crate::client_sync::{handle_defaults, into_json};
-use crate::json::v18::*;
-
-crate::define_jsonrpc_minreq_client!("v18");
-
-// == Blockchain ==
-crate::impl_client_v17__getblockchaininfo!();
-crate::impl_client_v17__getbestblockhash!();
-crate::impl_client_v17__getblock!();
-crate::impl_client_v17__gettxout!();
-
-// == Control ==
-crate::impl_client_v17__stop!();
-
-// == Generating ==
-crate::impl_client_v17__generatetoaddress!();
-
-// == Network ==
-crate::impl_client_v17__getnetworkinfo!();
-crate::impl_client_check_expected_server_version!({ [180100] });
-
-// == Rawtransactions ==
-crate::impl_client_v17__sendrawtransaction!();
-
-// == Wallet ==
-crate::impl_client_v17__createwallet!();
-crate::impl_client_v17__unloadwallet!();
-crate::impl_client_v17__loadwallet!();
-crate::impl_client_v17__getnewaddress!();
-crate::impl_client_v17__getbalance!();
-crate::impl_client_v17__sendtoaddress!();
-crate::impl_client_v17__gettransaction!();
-
-pub use crate::client_sync::v17::AddressType;
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Requires `Client` to be in scope.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+//!
+//! See, or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getnodeaddresses`
+#[macro_export]
+macro_rules! impl_client_v18__getnodeaddresses {
+    () => {
+        impl Client {
+            /// Returns a single known node address (Core's default `count` is 1).
+            pub fn get_node_addresses(&self) -> Result<GetNodeAddresses> {
+                self.call("getnodeaddresses", &[])
+            }
+
+            /// Returns up to `count` known node addresses.
+            ///
+            /// Core v0.21 onwards returns all known addresses if `count` is 0.
+            pub fn get_node_addresses_with_count(&self, count: usize) -> Result<GetNodeAddresses> {
+                self.call("getnodeaddresses", &[count.into()])
+            }
+        }
+    };
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Requires `Client` to be in scope.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+//!
+//! See, or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `deriveaddresses`
+#[macro_export]
+macro_rules! impl_client_v18__deriveaddresses {
+    () => {
+        impl Client {
+            /// Derives the address of a non-ranged `descriptor`.
+            pub fn derive_addresses(&self, descriptor: &str) -> Result<DeriveAddresses> {
+                self.call("deriveaddresses", &[descriptor.into()])
+            }
+
+            /// Derives the addresses of a ranged `descriptor` from index `range.0` to `range.1`
+            /// (inclusive).
+            pub fn derive_addresses_with_range(
+                &self,
+                descriptor: &str,
+                range: (u32, u32),
+            ) -> Result<DeriveAddresses> {
+                self.call("deriveaddresses", &[descriptor.into(), into_json([range.0, range.1])?])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getdescriptorinfo`
+#[macro_export]
+macro_rules! impl_client_v18__getdescriptorinfo {
+    () => {
+        impl Client {
+            pub fn get_descriptor_info(&self, descriptor: &str) -> Result<GetDescriptorInfo> {
+                self.call("getdescriptorinfo", &[descriptor.into()])
+            }
+        }
+    };
+}
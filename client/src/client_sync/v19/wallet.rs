@@ -9,6 +9,34 @@
 //!
 //! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
+/// Implements bitcoind JSON-RPC API method `createwallet`
+#[macro_export]
+macro_rules! impl_client_v19__createwallet {
+    () => {
+        impl Client {
+            pub fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
+                self.call("createwallet", &[wallet.into()])
+            }
+
+            /// Creates a wallet using the options set in `options`.
+            pub fn create_wallet_with_options(
+                &self,
+                wallet: &str,
+                options: &$crate::client_sync::v19::CreateWalletOptions,
+            ) -> Result<CreateWallet> {
+                let args = [
+                    wallet.into(),
+                    into_json(options.disable_private_keys)?,
+                    into_json(options.blank)?,
+                    into_json(&options.passphrase)?,
+                    into_json(options.avoid_reuse)?,
+                ];
+                self.call("createwallet", &args)
+            }
+        }
+    };
+}
+
 /// Implements bitcoind JSON-RPC API method `getbalances`
 #[macro_export]
 macro_rules! impl_client_v19__getbalances {
@@ -18,3 +46,19 @@ macro_rules! impl_client_v19__getbalances {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `gettransaction` with `verbose` set.
+#[macro_export]
+macro_rules! impl_client_v19__gettransaction {
+    () => {
+        impl Client {
+            pub fn get_transaction_verbose(&self, txid: Txid) -> Result<GetTransaction> {
+                // Pass null for `include_watchonly` so Core picks the default for the wallet.
+                self.call(
+                    "gettransaction",
+                    &[into_json(txid)?, serde_json::Value::Null, true.into()],
+                )
+            }
+        }
+    };
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getindexinfo`
+#[macro_export]
+macro_rules! impl_client_v21__getindexinfo {
+    () => {
+        impl Client {
+            pub fn get_index_info(&self) -> Result<GetIndexInfo> { self.call("getindexinfo", &[]) }
+
+            /// Returns the status of the index `name`, the result is empty if it is not enabled.
+            pub fn get_index_info_for(&self, name: &str) -> Result<GetIndexInfo> {
+                self.call("getindexinfo", &[name.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `gettxoutsetinfo` with the `hash_type` argument
+#[macro_export]
+macro_rules! impl_client_v21__gettxoutsetinfo {
+    () => {
+        impl Client {
+            pub fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[])
+            }
+
+            /// Returns UTXO set statistics, calculating the hash of the UTXO set using `hash_type`.
+            pub fn get_tx_out_set_info_with_hash_type(
+                &self,
+                hash_type: $crate::client_sync::v21::TxOutSetHashType,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[into_json(hash_type)?])
+            }
+        }
+    };
+}
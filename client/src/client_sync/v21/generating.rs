@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Generating ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `generateblock`
+#[macro_export]
+macro_rules! impl_client_v21__generateblock {
+    () => {
+        impl Client {
+            /// Mines a block, containing `transactions` in the given order, paying to `address`.
+            pub fn generate_block(
+                &self,
+                address: &Address<NetworkChecked>,
+                transactions: &[$crate::client_sync::v21::GenerateBlockTransaction],
+            ) -> Result<GenerateBlock> {
+                self.call("generateblock", &[into_json(address)?, into_json(transactions)?])
+            }
+
+            /// Mines a block, containing `transactions` in the given order, paying to `descriptor`.
+            pub fn generate_block_to_descriptor(
+                &self,
+                descriptor: &str,
+                transactions: &[$crate::client_sync::v21::GenerateBlockTransaction],
+            ) -> Result<GenerateBlock> {
+                self.call("generateblock", &[descriptor.into(), into_json(transactions)?])
+            }
+        }
+    };
+}
@@ -0,0 +1,668 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A JSON-RPC client for testing against Bitcoin Core `v0.21.2`.
+//!
+//! We ignore option arguments unless they effect the shape of the returned JSON data.
+
+mod blockchain;
+mod generating;
+mod wallet;
+
+use bitcoin::address::{Address, NetworkChecked};
+use bitcoin::{Amount, Block, BlockHash, FeeRate, Transaction, Txid};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::client_sync::{handle_defaults, into_json};
+use crate::json::v21::*;
+
+crate::define_jsonrpc_minreq_client!("v21");
+crate::impl_client_block_source!();
+crate::impl_client_bitcoind_rpc!();
+
+// == Blockchain ==
+crate::impl_client_v17__getblockchaininfo!();
+crate::impl_client_v17__getbestblockhash!();
+crate::impl_client_v17__getblock!();
+crate::impl_client_v17__getblockheader!();
+crate::impl_client_v17__getblockhash!();
+crate::impl_client_v17__getchaintips!();
+crate::impl_client_v17__preciousblock!();
+crate::impl_client_v17__invalidateblock!();
+crate::impl_client_v17__reconsiderblock!();
+crate::impl_client_v17__gettxout!();
+crate::impl_client_v17__gettxoutproof!();
+crate::impl_client_v17__verifytxoutproof!();
+crate::impl_client_v17__verifychain!();
+crate::impl_client_v21__gettxoutsetinfo!();
+crate::impl_client_v17__scantxoutset!();
+crate::impl_client_v17__getrawmempool!();
+crate::impl_client_v17__getmempoolentry!();
+crate::impl_client_v17__getmempoolinfo!();
+crate::impl_client_v21__getindexinfo!();
+
+// == Control ==
+crate::impl_client_v17__getmemoryinfo!();
+crate::impl_client_v17__logging!();
+crate::impl_client_v17__stop!();
+crate::impl_client_v17__setmocktime!();
+
+// == Generating ==
+crate::impl_client_v21__generateblock!();
+crate::impl_client_v17__generatetoaddress!();
+crate::impl_client_v20__generatetodescriptor!();
+
+// == Mining ==
+crate::impl_client_v17__getmininginfo!();
+crate::impl_client_v17__getblocktemplate!();
+
+// == Network ==
+crate::impl_client_v17__getnetworkinfo!();
+crate::impl_client_v17__getpeerinfo!();
+crate::impl_client_check_expected_server_version!({ [210000, 210100, 210200] });
+crate::impl_client_v18__getnodeaddresses!();
+
+// == Rawtransactions ==
+crate::impl_client_v17__combinepsbt!();
+crate::impl_client_v17__createrawtransaction!();
+crate::impl_client_v17__fundrawtransaction!();
+crate::impl_client_v17__getrawtransaction!();
+crate::impl_client_v17__decodepsbt!();
+crate::impl_client_v17__decoderawtransaction!();
+crate::impl_client_v17__finalizepsbt!();
+crate::impl_client_v17__sendrawtransaction!();
+crate::impl_client_v19__sendrawtransaction!();
+crate::impl_client_v17__testmempoolaccept!();
+
+// == Util ==
+crate::impl_client_v17__estimatesmartfee!();
+crate::impl_client_v17__verifymessage!();
+crate::impl_client_v18__deriveaddresses!();
+crate::impl_client_v18__getdescriptorinfo!();
+
+// == Wallet ==
+crate::impl_client_v17__addmultisigaddress!();
+crate::impl_client_v21__createwallet!();
+crate::impl_client_v17__unloadwallet!();
+crate::impl_client_v17__loadwallet!();
+crate::impl_client_v17__getnewaddress!();
+crate::impl_client_v17__getrawchangeaddress!();
+crate::impl_client_v17__getaddressinfo!();
+crate::impl_client_v17__getwalletinfo!();
+crate::impl_client_v17__getbalance!();
+crate::impl_client_v19__getbalances!();
+crate::impl_client_v21__sendtoaddress!();
+crate::impl_client_v17__gettransaction!();
+crate::impl_client_v19__gettransaction!();
+crate::impl_client_v17__listtransactions!();
+crate::impl_client_v17__listsinceblock!();
+crate::impl_client_v17__importmulti!();
+crate::impl_client_v17__importaddress!();
+crate::impl_client_v17__rescanblockchain!();
+crate::impl_client_v17__listreceivedbyaddress!();
+crate::impl_client_v17__listunspent!();
+crate::impl_client_v17__lockunspent!();
+crate::impl_client_v17__getaddressesbylabel!();
+crate::impl_client_v17__getreceivedbyaddress!();
+crate::impl_client_v18__getreceivedbylabel!();
+crate::impl_client_v21__bumpfee!();
+crate::impl_client_v21__psbtbumpfee!();
+crate::impl_client_v17__walletcreatefundedpsbt!();
+crate::impl_client_v17__walletprocesspsbt!();
+crate::impl_client_v17__listwallets!();
+crate::impl_client_v17__encryptwallet!();
+crate::impl_client_v17__walletpassphrase!();
+crate::impl_client_v17__walletlock!();
+crate::impl_client_v17__walletpassphrasechange!();
+crate::impl_client_v18__listwalletdir!();
+crate::impl_client_v21__importdescriptors!();
+crate::impl_client_v21__send!();
+crate::impl_client_v17__signrawtransactionwithwallet!();
+crate::impl_client_v17__signmessage!();
+
+pub use crate::client_sync::v17::{
+    AddressType, BroadcastOutcome, ChainFork, CreateRawTransactionInput,
+    CreateRawTransactionOutput, EstimateMode, FundRawTransactionOptions, ImportAddressOptions,
+    ImportAddressTarget, ImportMultiOptions, ImportMultiRequest, ImportMultiScriptPubkey,
+    ImportTimestamp, MemoryInfoMode, MempoolCandidate, MempoolCandidateOptions, ScanAction,
+    ScanObject, ScanRangeError, SighashType, TemplateRequest,
+};
+
+/// Options for the `Client::create_wallet_with_options` function.
+///
+/// As [`v19::CreateWalletOptions`] with the `descriptors` and `load_on_startup` options added in
+/// v0.21.
+///
+/// Options left as `None` are sent as `null`, the node uses its defaults.
+///
+/// [`v19::CreateWalletOptions`]: crate::client_sync::v19::CreateWalletOptions
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateWalletOptions {
+    /// Disable the possibility of private keys (only watch-only addresses are possible).
+    pub disable_private_keys: Option<bool>,
+    /// Create a blank wallet, without keys or an HD seed.
+    pub blank: Option<bool>,
+    /// Encrypt the wallet with this passphrase.
+    pub passphrase: Option<String>,
+    /// Track address reuse, outputs to addresses already spent from are avoided when spending.
+    pub avoid_reuse: Option<bool>,
+    /// Create a native descriptor wallet, the default from v23.
+    pub descriptors: Option<bool>,
+    /// Load the wallet when the node starts, `None` leaves the startup setting unchanged.
+    pub load_on_startup: Option<bool>,
+}
+
+impl CreateWalletOptions {
+    /// Creates options that use the node's defaults.
+    pub fn new() -> Self { Self::default() }
+
+    /// Sets whether private keys are disabled.
+    pub fn disable_private_keys(mut self, disable: bool) -> Self {
+        self.disable_private_keys = Some(disable);
+        self
+    }
+
+    /// Sets whether to create a blank wallet.
+    pub fn blank(mut self, blank: bool) -> Self {
+        self.blank = Some(blank);
+        self
+    }
+
+    /// Sets the passphrase used to encrypt the wallet.
+    pub fn passphrase(mut self, passphrase: &str) -> Self {
+        self.passphrase = Some(passphrase.to_owned());
+        self
+    }
+
+    /// Sets whether to avoid address reuse.
+    pub fn avoid_reuse(mut self, avoid_reuse: bool) -> Self {
+        self.avoid_reuse = Some(avoid_reuse);
+        self
+    }
+
+    /// Sets whether to create a descriptor wallet.
+    pub fn descriptors(mut self, descriptors: bool) -> Self {
+        self.descriptors = Some(descriptors);
+        self
+    }
+
+    /// Sets whether the wallet is loaded when the node starts.
+    pub fn load_on_startup(mut self, load: bool) -> Self {
+        self.load_on_startup = Some(load);
+        self
+    }
+}
+
+/// Options for the `bumpfee` and `psbtbumpfee` methods.
+///
+/// Fields left as `None` (or empty) are not sent, the node uses its defaults.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct BumpFeeOptions {
+    /// Confirmation target in blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u16>,
+    /// The fee rate to pay, sent to the node in sat/vB.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_fee_rate")]
+    pub fee_rate: Option<FeeRate>,
+    /// Whether the new transaction should still be marked bip-125 replaceable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// Outputs to replace the outputs of the original transaction with (v24 and later).
+    ///
+    /// Earlier versions of Core reject the options if this is not empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub outputs: Vec<BumpFeeOutput>,
+}
+
+impl BumpFeeOptions {
+    /// Creates options that use the node's defaults.
+    pub fn new() -> Self { Self::default() }
+
+    /// Sets the confirmation target in blocks.
+    pub fn conf_target(mut self, conf_target: u16) -> Self {
+        self.conf_target = Some(conf_target);
+        self
+    }
+
+    /// Sets the fee rate to pay.
+    pub fn fee_rate(mut self, fee_rate: FeeRate) -> Self {
+        self.fee_rate = Some(fee_rate);
+        self
+    }
+
+    /// Sets whether the new transaction should still be marked bip-125 replaceable.
+    pub fn replaceable(mut self, replaceable: bool) -> Self {
+        self.replaceable = Some(replaceable);
+        self
+    }
+
+    /// Adds an output to replace the outputs of the original transaction with (v24 and later).
+    pub fn output(mut self, output: BumpFeeOutput) -> Self {
+        self.outputs.push(output);
+        self
+    }
+}
+
+/// Options for the `walletcreatefundedpsbt` method.
+///
+/// As [`v17::WalletCreateFundedPsbtOptions`] except that the fee rate is sent as `fee_rate` in
+/// sat/vB, the option added in v21 (`feeRate` is still accepted, in BTC/kvB).
+///
+/// [`v17::WalletCreateFundedPsbtOptions`]: crate::client_sync::v17::WalletCreateFundedPsbtOptions
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletCreateFundedPsbtOptions {
+    /// The address to send change to, defaults to a new wallet address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<Address<NetworkChecked>>,
+    /// The index of the change output, defaults to a random position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// The output type to use for change, defaults to the node's `-changetype` setting.
+    #[serde(rename = "change_type", skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<AddressType>,
+    /// Whether to also select inputs which are watch-only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_watching: Option<bool>,
+    /// Whether to lock the selected unspent outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// The fee rate to pay, sent to the node in sat/vB.
+    #[serde(
+        rename = "fee_rate",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_fee_rate"
+    )]
+    pub fee_rate: Option<FeeRate>,
+    /// The indices of the outputs to deduct the fee from, split equally between them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// Whether the transaction signals BIP-125 replaceability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    /// Confirmation target in blocks.
+    #[serde(rename = "conf_target", skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u16>,
+    /// The fee estimate mode.
+    #[serde(rename = "estimate_mode", skip_serializing_if = "Option::is_none")]
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+/// Arguments to the `Client::send_to_address_with` function.
+///
+/// Options left as `None` are sent as `null`, the node uses its defaults.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SendToAddressRequest {
+    /// The address to send to.
+    pub address: Address<NetworkChecked>,
+    /// The amount to send.
+    pub amount: Amount,
+    /// A comment used to store what the transaction is for, stored in the wallet.
+    pub comment: Option<String>,
+    /// A comment to store the name of the recipient, stored in the wallet.
+    pub comment_to: Option<String>,
+    /// Whether the fee is deducted from the amount being sent.
+    pub subtract_fee: Option<bool>,
+    /// Whether to signal BIP-125 replaceability, the node defaults to its `-walletrbf` setting.
+    pub replaceable: Option<bool>,
+    /// Confirmation target in blocks.
+    pub conf_target: Option<u16>,
+    /// The fee estimate mode.
+    pub estimate_mode: Option<EstimateMode>,
+    /// Whether to avoid spending from dirty addresses (only for wallets with `avoid_reuse` set).
+    pub avoid_reuse: Option<bool>,
+    /// The fee rate to pay, sent to the node in sat/vB.
+    ///
+    /// Core rejects the request if this is set along with `conf_target` or `estimate_mode`.
+    pub fee_rate: Option<FeeRate>,
+}
+
+impl SendToAddressRequest {
+    /// Creates a request to send `amount` to `address`, all other options use the node's defaults.
+    pub fn new(address: Address<NetworkChecked>, amount: Amount) -> Self {
+        SendToAddressRequest {
+            address,
+            amount,
+            comment: None,
+            comment_to: None,
+            subtract_fee: None,
+            replaceable: None,
+            conf_target: None,
+            estimate_mode: None,
+            avoid_reuse: None,
+            fee_rate: None,
+        }
+    }
+
+    /// Sets the comment stored in the wallet.
+    pub fn comment<S: Into<String>>(mut self, comment: S) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Sets the name of the recipient stored in the wallet.
+    pub fn comment_to<S: Into<String>>(mut self, comment_to: S) -> Self {
+        self.comment_to = Some(comment_to.into());
+        self
+    }
+
+    /// Sets whether the fee is deducted from the amount being sent.
+    pub fn subtract_fee(mut self, subtract_fee: bool) -> Self {
+        self.subtract_fee = Some(subtract_fee);
+        self
+    }
+
+    /// Sets whether the transaction signals BIP-125 replaceability.
+    pub fn replaceable(mut self, replaceable: bool) -> Self {
+        self.replaceable = Some(replaceable);
+        self
+    }
+
+    /// Sets the confirmation target in blocks.
+    pub fn conf_target(mut self, conf_target: u16) -> Self {
+        self.conf_target = Some(conf_target);
+        self
+    }
+
+    /// Sets the fee estimate mode.
+    pub fn estimate_mode(mut self, estimate_mode: EstimateMode) -> Self {
+        self.estimate_mode = Some(estimate_mode);
+        self
+    }
+
+    /// Sets whether to avoid spending from dirty addresses.
+    pub fn avoid_reuse(mut self, avoid_reuse: bool) -> Self {
+        self.avoid_reuse = Some(avoid_reuse);
+        self
+    }
+
+    /// Sets the fee rate to pay.
+    pub fn fee_rate(mut self, fee_rate: FeeRate) -> Self {
+        self.fee_rate = Some(fee_rate);
+        self
+    }
+}
+
+/// An output of the replacement transaction, see [`BumpFeeOptions::outputs`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum BumpFeeOutput {
+    /// Pay `amount` to `address`.
+    Address(Address<NetworkChecked>, Amount),
+    /// An `OP_RETURN` output with the given data.
+    Data(Vec<u8>),
+}
+
+impl Serialize for BumpFeeOutput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use bitcoin::hex::DisplayHex;
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        match *self {
+            BumpFeeOutput::Address(ref address, amount) =>
+                map.serialize_entry(&address.to_string(), &amount.to_btc())?,
+            BumpFeeOutput::Data(ref data) =>
+                map.serialize_entry("data", &data.to_lower_hex_string())?,
+        }
+        map.end()
+    }
+}
+
+/// Arguments to the `Client::send` method.
+///
+/// Built using [`SendParams::new`] and the builder methods, fields left as `None` (or empty) are
+/// not sent and the node uses its defaults.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SendParams {
+    /// The outputs to pay, in order.
+    pub outputs: Vec<SendOutput>,
+    /// Confirmation target in blocks.
+    pub conf_target: Option<u16>,
+    /// The fee estimate mode.
+    pub estimate_mode: Option<EstimateMode>,
+    /// The fee rate to pay, sent to the node in sat/vB.
+    pub fee_rate: Option<FeeRate>,
+    /// Further options.
+    pub options: SendOptions,
+}
+
+impl SendParams {
+    /// Creates parameters without any outputs.
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds an output paying `amount` to `address`.
+    pub fn add_address(mut self, address: Address<NetworkChecked>, amount: Amount) -> Self {
+        self.outputs.push(SendOutput::Address(address, amount));
+        self
+    }
+
+    /// Adds an `OP_RETURN` output carrying `data`.
+    pub fn add_data(mut self, data: Vec<u8>) -> Self {
+        self.outputs.push(SendOutput::Data(data));
+        self
+    }
+
+    /// Sets the confirmation target in blocks.
+    pub fn conf_target(mut self, blocks: u16) -> Self {
+        self.conf_target = Some(blocks);
+        self
+    }
+
+    /// Sets the fee estimate mode.
+    pub fn estimate_mode(mut self, mode: EstimateMode) -> Self {
+        self.estimate_mode = Some(mode);
+        self
+    }
+
+    /// Sets the fee rate, this can not be combined with a confirmation target.
+    pub fn fee_rate(mut self, fee_rate: FeeRate) -> Self {
+        self.fee_rate = Some(fee_rate);
+        self
+    }
+
+    /// Sets the further options.
+    pub fn options(mut self, options: SendOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+/// An output of the transaction created by `Client::send`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SendOutput {
+    /// Pay `amount` to `address`.
+    Address(Address<NetworkChecked>, Amount),
+    /// An `OP_RETURN` output with the given data.
+    Data(Vec<u8>),
+}
+
+impl Serialize for SendOutput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use bitcoin::hex::DisplayHex;
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        match *self {
+            SendOutput::Address(ref address, amount) =>
+                map.serialize_entry(&address.to_string(), &amount.to_btc())?,
+            SendOutput::Data(ref data) =>
+                map.serialize_entry("data", &data.to_lower_hex_string())?,
+        }
+        map.end()
+    }
+}
+
+/// The `options` argument of `Client::send`.
+///
+/// Fields left as `None` (or empty) are not sent, the node uses its defaults.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SendOptions {
+    /// Whether to add inputs other than `inputs` if they are not enough.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_inputs: Option<bool>,
+    /// Whether to add the transaction to the wallet and broadcast it, if false return the
+    /// transaction instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_to_wallet: Option<bool>,
+    /// The address to send change to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_address: Option<Address<NetworkChecked>>,
+    /// The index of the change output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_position: Option<u32>,
+    /// The output type of the change address, only used if `change_address` is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_type: Option<AddressType>,
+    /// Whether to also select inputs which are watch-only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_watching: Option<bool>,
+    /// Inputs to spend.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<CreateRawTransactionInput>,
+    /// Raw locktime, non-zero also locktime-activates inputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locktime: Option<u32>,
+    /// Whether to lock the selected unspent outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_unspents: Option<bool>,
+    /// Whether to always return a PSBT.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub psbt: Option<bool>,
+    /// Indexes of the outputs to deduct the fee from, equally.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// Whether the transaction signals BIP-125 replaceability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+}
+
+/// Serializes a fee rate in sat/vB, the unit expected by the `fee_rate` option since v21.
+fn serialize_fee_rate<S: Serializer>(
+    fee_rate: &Option<FeeRate>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match *fee_rate {
+        // 1 vB is 4 wu, so sat/vB is sat/kwu divided by 250.
+        Some(fee_rate) => serializer.serialize_f64(fee_rate.to_sat_per_kwu() as f64 / 250.0),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// A single request for the `importdescriptors` method.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ImportDescriptorsRequest {
+    /// The descriptor to import, including its checksum.
+    #[serde(rename = "desc")]
+    pub descriptor: String,
+    /// Set this descriptor to be the active descriptor for the corresponding output type/externality.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The range (start, end) to import, if a ranged descriptor is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<(u32, u32)>,
+    /// The next index to generate addresses from, if a ranged descriptor is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_index: Option<u32>,
+    /// Time from which to rescan the chain for transactions.
+    pub timestamp: ImportTimestamp,
+    /// Whether matching outputs should be treated as not incoming payments (e.g. change).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    /// Label to assign to the address, only allowed with `internal` false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl ImportDescriptorsRequest {
+    /// Creates a request to import `descriptor`, all other options use the node's defaults.
+    pub fn new<S: Into<String>>(descriptor: S, timestamp: ImportTimestamp) -> Self {
+        ImportDescriptorsRequest {
+            descriptor: descriptor.into(),
+            active: None,
+            range: None,
+            next_index: None,
+            timestamp,
+            internal: None,
+            label: None,
+        }
+    }
+}
+
+/// Progress of `Client::import_descriptors_chunked`, reported after each chunk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportDescriptorsProgress {
+    /// The total number of requests.
+    pub total: usize,
+    /// The number of descriptors imported so far.
+    pub imported: usize,
+    /// The number of descriptors that failed and will not be retried.
+    pub failed: usize,
+    /// The number of descriptors that failed and are queued to be retried.
+    pub retrying: usize,
+}
+
+/// The outcome of `Client::import_descriptors_chunked`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportDescriptorsReport {
+    /// The number of descriptors imported.
+    pub imported: usize,
+    /// Warnings for imported descriptors, by index into the requests.
+    pub warnings: Vec<(usize, Vec<String>)>,
+    /// The descriptors that could not be imported, sorted by index into the requests.
+    pub failed: Vec<FailedImport>,
+}
+
+impl ImportDescriptorsReport {
+    /// Returns true if all descriptors were imported.
+    pub fn is_complete(&self) -> bool { self.failed.is_empty() }
+}
+
+/// A descriptor that could not be imported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailedImport {
+    /// Index into the requests.
+    pub index: usize,
+    /// The descriptor.
+    pub descriptor: String,
+    /// The error returned by the node on the last attempt.
+    pub error: crate::json::model::JsonRpcError,
+    /// The number of times the import was attempted.
+    pub attempts: u32,
+}
+
+/// The `hash_type` argument to the `gettxoutsetinfo` RPC.
+///
+/// The legacy hash is calculated by default, use `Client::get_tx_out_set_info`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TxOutSetHashType {
+    /// The MuHash of the UTXO set.
+    MuHash,
+    /// Do not calculate a hash of the UTXO set.
+    None,
+}
+
+/// A transaction to include in a block generated using `Client::generate_block`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenerateBlockTransaction {
+    /// A transaction currently in the mempool.
+    Txid(Txid),
+    /// A raw transaction, it does not need to be in the mempool.
+    Raw(Transaction),
+}
+
+impl From<Txid> for GenerateBlockTransaction {
+    fn from(txid: Txid) -> Self { GenerateBlockTransaction::Txid(txid) }
+}
+
+impl From<Transaction> for GenerateBlockTransaction {
+    fn from(tx: Transaction) -> Self { GenerateBlockTransaction::Raw(tx) }
+}
+
+impl Serialize for GenerateBlockTransaction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match *self {
+            GenerateBlockTransaction::Txid(ref txid) => serializer.collect_str(txid),
+            GenerateBlockTransaction::Raw(ref tx) =>
+                serializer.serialize_str(&bitcoin::consensus::encode::serialize_hex(tx)),
+        }
+    }
+}
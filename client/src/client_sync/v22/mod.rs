@@ -4,43 +4,223 @@
 //!
 //! We ignore option arguments unless they effect the shape of the returned JSON data.
 
+mod blockchain;
+mod network;
 mod wallet;
 
 use bitcoin::address::{Address, NetworkChecked};
 use bitcoin::{Amount, Block, BlockHash, Txid};
+use serde::{Serialize, Serializer};
 
 use crate::client_sync::{handle_defaults, into_json};
 use crate::json::v22::*;
 
 crate::define_jsonrpc_minreq_client!("v22");
+crate::impl_client_block_source!();
+crate::impl_client_bitcoind_rpc!();
 
 // == Blockchain ==
 crate::impl_client_v17__getblockchaininfo!();
 crate::impl_client_v17__getbestblockhash!();
 crate::impl_client_v17__getblock!();
+crate::impl_client_v17__getblockheader!();
+crate::impl_client_v17__getblockhash!();
+crate::impl_client_v17__getchaintips!();
+crate::impl_client_v17__preciousblock!();
+crate::impl_client_v17__invalidateblock!();
+crate::impl_client_v17__reconsiderblock!();
 crate::impl_client_v17__gettxout!();
+crate::impl_client_v17__gettxoutproof!();
+crate::impl_client_v17__verifytxoutproof!();
+crate::impl_client_v17__verifychain!();
+crate::impl_client_v21__getindexinfo!();
+crate::impl_client_v22__gettxoutsetinfo!();
+crate::impl_client_v17__scantxoutset!();
+crate::impl_client_v17__getrawmempool!();
+crate::impl_client_v17__getmempoolentry!();
+crate::impl_client_v17__getmempoolinfo!();
 
 // == Control ==
+crate::impl_client_v17__getmemoryinfo!();
+crate::impl_client_v17__logging!();
 crate::impl_client_v17__stop!();
+crate::impl_client_v17__setmocktime!();
 
 // == Generating ==
+crate::impl_client_v21__generateblock!();
 crate::impl_client_v17__generatetoaddress!();
+crate::impl_client_v20__generatetodescriptor!();
+
+// == Mining ==
+crate::impl_client_v17__getmininginfo!();
+crate::impl_client_v17__getblocktemplate!();
 
 // == Network ==
 crate::impl_client_v17__getnetworkinfo!();
+crate::impl_client_v17__getpeerinfo!();
 crate::impl_client_check_expected_server_version!({ [220000, 220100] });
+crate::impl_client_v18__getnodeaddresses!();
+crate::impl_client_v22__getnodeaddresses!();
 
 // == Rawtransactions ==
+crate::impl_client_v17__combinepsbt!();
+crate::impl_client_v17__createrawtransaction!();
+crate::impl_client_v17__fundrawtransaction!();
+crate::impl_client_v17__getrawtransaction!();
+crate::impl_client_v17__decodepsbt!();
+crate::impl_client_v17__decoderawtransaction!();
+crate::impl_client_v17__finalizepsbt!();
 crate::impl_client_v17__sendrawtransaction!();
+crate::impl_client_v19__sendrawtransaction!();
+crate::impl_client_v17__testmempoolaccept!();
+
+// == Util ==
+crate::impl_client_v17__estimatesmartfee!();
+crate::impl_client_v17__verifymessage!();
+crate::impl_client_v18__deriveaddresses!();
+crate::impl_client_v18__getdescriptorinfo!();
 
 // == Wallet ==
-crate::impl_client_v17__createwallet!();
+crate::impl_client_v17__addmultisigaddress!();
+crate::impl_client_v22__createwallet!();
 crate::impl_client_v22__unloadwallet!();
 crate::impl_client_v22__loadwallet!();
 crate::impl_client_v17__getbalance!();
 crate::impl_client_v19__getbalances!();
 crate::impl_client_v17__getnewaddress!();
-crate::impl_client_v17__sendtoaddress!();
+crate::impl_client_v17__getrawchangeaddress!();
+crate::impl_client_v17__getaddressinfo!();
+crate::impl_client_v17__getwalletinfo!();
+crate::impl_client_v21__sendtoaddress!();
 crate::impl_client_v17__gettransaction!();
+crate::impl_client_v19__gettransaction!();
+crate::impl_client_v17__listtransactions!();
+crate::impl_client_v17__listsinceblock!();
+crate::impl_client_v17__importmulti!();
+crate::impl_client_v17__importaddress!();
+crate::impl_client_v17__rescanblockchain!();
+crate::impl_client_v17__listreceivedbyaddress!();
+crate::impl_client_v17__listunspent!();
+crate::impl_client_v17__lockunspent!();
+crate::impl_client_v17__getaddressesbylabel!();
+crate::impl_client_v17__getreceivedbyaddress!();
+crate::impl_client_v18__getreceivedbylabel!();
+crate::impl_client_v21__bumpfee!();
+crate::impl_client_v21__psbtbumpfee!();
+crate::impl_client_v17__walletcreatefundedpsbt!();
+crate::impl_client_v17__walletprocesspsbt!();
+crate::impl_client_v17__listwallets!();
+crate::impl_client_v17__encryptwallet!();
+crate::impl_client_v17__walletpassphrase!();
+crate::impl_client_v17__walletlock!();
+crate::impl_client_v17__walletpassphrasechange!();
+crate::impl_client_v18__listwalletdir!();
+crate::impl_client_v21__importdescriptors!();
+crate::impl_client_v21__send!();
+crate::impl_client_v22__listdescriptors!();
+crate::impl_client_v17__signrawtransactionwithwallet!();
+crate::impl_client_v17__signmessage!();
+
+pub use crate::client_sync::v17::{
+    AddressType, BroadcastOutcome, ChainFork, CreateRawTransactionInput,
+    CreateRawTransactionOutput, FundRawTransactionOptions, ImportAddressOptions,
+    ImportAddressTarget, ImportMultiOptions, ImportMultiRequest, ImportMultiScriptPubkey,
+    MemoryInfoMode, MempoolCandidate, MempoolCandidateOptions, ScanAction, ScanObject,
+    ScanRangeError, SighashType, TemplateRequest,
+};
+pub use crate::client_sync::v21::{
+    BumpFeeOptions, BumpFeeOutput, EstimateMode, FailedImport, GenerateBlockTransaction,
+    ImportDescriptorsProgress, ImportDescriptorsReport, ImportDescriptorsRequest, ImportTimestamp,
+    SendOptions, SendOutput, SendParams, SendToAddressRequest, TxOutSetHashType,
+    WalletCreateFundedPsbtOptions,
+};
+
+/// Options for the `Client::create_wallet_with_options` function.
+///
+/// As [`v21::CreateWalletOptions`] with the `external_signer` option added in v22.
+///
+/// Options left as `None` are sent as `null`, the node uses its defaults.
+///
+/// [`v21::CreateWalletOptions`]: crate::client_sync::v21::CreateWalletOptions
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateWalletOptions {
+    /// Disable the possibility of private keys (only watch-only addresses are possible).
+    pub disable_private_keys: Option<bool>,
+    /// Create a blank wallet, without keys or an HD seed.
+    pub blank: Option<bool>,
+    /// Encrypt the wallet with this passphrase.
+    pub passphrase: Option<String>,
+    /// Track address reuse, outputs to addresses already spent from are avoided when spending.
+    pub avoid_reuse: Option<bool>,
+    /// Create a native descriptor wallet, the default from v23.
+    pub descriptors: Option<bool>,
+    /// Load the wallet when the node starts, `None` leaves the startup setting unchanged.
+    pub load_on_startup: Option<bool>,
+    /// Use an external signer such as a hardware wallet, requires a descriptor wallet.
+    pub external_signer: Option<bool>,
+}
+
+impl CreateWalletOptions {
+    /// Creates options that use the node's defaults.
+    pub fn new() -> Self { Self::default() }
+
+    /// Sets whether private keys are disabled.
+    pub fn disable_private_keys(mut self, disable: bool) -> Self {
+        self.disable_private_keys = Some(disable);
+        self
+    }
+
+    /// Sets whether to create a blank wallet.
+    pub fn blank(mut self, blank: bool) -> Self {
+        self.blank = Some(blank);
+        self
+    }
+
+    /// Sets the passphrase used to encrypt the wallet.
+    pub fn passphrase(mut self, passphrase: &str) -> Self {
+        self.passphrase = Some(passphrase.to_owned());
+        self
+    }
+
+    /// Sets whether to avoid address reuse.
+    pub fn avoid_reuse(mut self, avoid_reuse: bool) -> Self {
+        self.avoid_reuse = Some(avoid_reuse);
+        self
+    }
+
+    /// Sets whether to create a descriptor wallet.
+    pub fn descriptors(mut self, descriptors: bool) -> Self {
+        self.descriptors = Some(descriptors);
+        self
+    }
+
+    /// Sets whether the wallet is loaded when the node starts.
+    pub fn load_on_startup(mut self, load: bool) -> Self {
+        self.load_on_startup = Some(load);
+        self
+    }
+
+    /// Sets whether to use an external signer (requires `-signer`).
+    pub fn external_signer(mut self, external_signer: bool) -> Self {
+        self.external_signer = Some(external_signer);
+        self
+    }
+}
+
+/// Argument to the `Client::get_tx_out_set_info_at` function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HashOrHeight {
+    /// The block with this hash.
+    Hash(BlockHash),
+    /// The block at this height in the active chain.
+    Height(u64),
+}
 
-pub use crate::client_sync::v17::AddressType;
+impl Serialize for HashOrHeight {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match *self {
+            HashOrHeight::Hash(ref hash) => hash.serialize(serializer),
+            HashOrHeight::Height(height) => serializer.serialize_u64(height),
+        }
+    }
+}
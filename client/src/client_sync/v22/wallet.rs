@@ -9,6 +9,37 @@
 //!
 //! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
 
+/// Implements bitcoind JSON-RPC API method `createwallet`
+#[macro_export]
+macro_rules! impl_client_v22__createwallet {
+    () => {
+        impl Client {
+            pub fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
+                self.call("createwallet", &[wallet.into()])
+            }
+
+            /// Creates a wallet using the options set in `options`.
+            pub fn create_wallet_with_options(
+                &self,
+                wallet: &str,
+                options: &$crate::client_sync::v22::CreateWalletOptions,
+            ) -> Result<CreateWallet> {
+                let args = [
+                    wallet.into(),
+                    into_json(options.disable_private_keys)?,
+                    into_json(options.blank)?,
+                    into_json(&options.passphrase)?,
+                    into_json(options.avoid_reuse)?,
+                    into_json(options.descriptors)?,
+                    into_json(options.load_on_startup)?,
+                    into_json(options.external_signer)?,
+                ];
+                self.call("createwallet", &args)
+            }
+        }
+    };
+}
+
 /// Implements bitcoind JSON-RPC API method `unloadwallet`
 #[macro_export]
 macro_rules! impl_client_v22__unloadwallet {
@@ -32,3 +63,15 @@ macro_rules! impl_client_v22__loadwallet {
         }
     };
 }
+
+/// Implements bitcoind JSON-RPC API method `listdescriptors`
+#[macro_export]
+macro_rules! impl_client_v22__listdescriptors {
+    () => {
+        impl Client {
+            pub fn list_descriptors(&self) -> Result<ListDescriptors> {
+                self.call("listdescriptors", &[])
+            }
+        }
+    };
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A JSON-RPC client for testing against Bitcoin Core `v23`.
+//!
+//! We ignore option arguments unless they effect the shape of the returned JSON data.
+
+mod wallet;
+
+use bitcoin::address::{Address, NetworkChecked};
+use bitcoin::{Amount, Block, BlockHash, Txid};
+use serde::{Deserialize, Serialize};
+
+use crate::client_sync::{handle_defaults, into_json};
+use crate::json::v23::*;
+
+crate::define_jsonrpc_minreq_client!("v23");
+crate::impl_client_block_source!();
+crate::impl_client_bitcoind_rpc!();
+
+// == Blockchain ==
+crate::impl_client_v17__getblockchaininfo!();
+crate::impl_client_v17__getbestblockhash!();
+crate::impl_client_v17__getblock!();
+crate::impl_client_v17__getblockheader!();
+crate::impl_client_v17__getblockhash!();
+crate::impl_client_v17__getchaintips!();
+crate::impl_client_v17__preciousblock!();
+crate::impl_client_v17__invalidateblock!();
+crate::impl_client_v17__reconsiderblock!();
+crate::impl_client_v17__gettxout!();
+crate::impl_client_v17__gettxoutproof!();
+crate::impl_client_v17__verifytxoutproof!();
+crate::impl_client_v17__verifychain!();
+crate::impl_client_v21__getindexinfo!();
+crate::impl_client_v22__gettxoutsetinfo!();
+crate::impl_client_v17__scantxoutset!();
+crate::impl_client_v17__getrawmempool!();
+crate::impl_client_v17__getmempoolentry!();
+crate::impl_client_v17__getmempoolinfo!();
+
+// == Control ==
+crate::impl_client_v17__getmemoryinfo!();
+crate::impl_client_v17__logging!();
+crate::impl_client_v17__stop!();
+crate::impl_client_v17__setmocktime!();
+
+// == Generating ==
+crate::impl_client_v21__generateblock!();
+crate::impl_client_v17__generatetoaddress!();
+crate::impl_client_v20__generatetodescriptor!();
+
+// == Mining ==
+crate::impl_client_v17__getmininginfo!();
+crate::impl_client_v17__getblocktemplate!();
+
+// == Network ==
+crate::impl_client_v17__getnetworkinfo!();
+crate::impl_client_v17__getpeerinfo!();
+crate::impl_client_check_expected_server_version!({ [230000, 230100, 230200] });
+crate::impl_client_v18__getnodeaddresses!();
+crate::impl_client_v22__getnodeaddresses!();
+
+// == Rawtransactions ==
+crate::impl_client_v17__combinepsbt!();
+crate::impl_client_v17__createrawtransaction!();
+crate::impl_client_v17__fundrawtransaction!();
+crate::impl_client_v17__getrawtransaction!();
+crate::impl_client_v17__decodepsbt!();
+crate::impl_client_v17__decoderawtransaction!();
+crate::impl_client_v17__finalizepsbt!();
+crate::impl_client_v17__sendrawtransaction!();
+crate::impl_client_v19__sendrawtransaction!();
+crate::impl_client_v17__testmempoolaccept!();
+
+// == Util ==
+crate::impl_client_v17__estimatesmartfee!();
+crate::impl_client_v17__verifymessage!();
+crate::impl_client_v18__deriveaddresses!();
+crate::impl_client_v18__getdescriptorinfo!();
+
+// == Wallet ==
+crate::impl_client_v17__addmultisigaddress!();
+crate::impl_client_v22__createwallet!();
+crate::impl_client_v22__unloadwallet!();
+crate::impl_client_v22__loadwallet!();
+crate::impl_client_v17__getbalance!();
+crate::impl_client_v19__getbalances!();
+crate::impl_client_v17__getnewaddress!();
+crate::impl_client_v17__getrawchangeaddress!();
+crate::impl_client_v17__getaddressinfo!();
+crate::impl_client_v17__getwalletinfo!();
+crate::impl_client_v21__sendtoaddress!();
+crate::impl_client_v17__gettransaction!();
+crate::impl_client_v19__gettransaction!();
+crate::impl_client_v17__listtransactions!();
+crate::impl_client_v17__listsinceblock!();
+crate::impl_client_v17__importmulti!();
+crate::impl_client_v17__importaddress!();
+crate::impl_client_v17__rescanblockchain!();
+crate::impl_client_v17__listreceivedbyaddress!();
+crate::impl_client_v17__listunspent!();
+crate::impl_client_v17__lockunspent!();
+crate::impl_client_v17__getaddressesbylabel!();
+crate::impl_client_v23__getreceivedbyaddress!();
+crate::impl_client_v23__getreceivedbylabel!();
+crate::impl_client_v21__bumpfee!();
+crate::impl_client_v21__psbtbumpfee!();
+crate::impl_client_v17__walletcreatefundedpsbt!();
+crate::impl_client_v17__walletprocesspsbt!();
+crate::impl_client_v17__listwallets!();
+crate::impl_client_v17__encryptwallet!();
+crate::impl_client_v17__walletpassphrase!();
+crate::impl_client_v17__walletlock!();
+crate::impl_client_v17__walletpassphrasechange!();
+crate::impl_client_v18__listwalletdir!();
+crate::impl_client_v21__importdescriptors!();
+crate::impl_client_v21__send!();
+crate::impl_client_v23__listdescriptors!();
+crate::impl_client_v17__signrawtransactionwithwallet!();
+crate::impl_client_v17__signmessage!();
+
+pub use crate::client_sync::v17::{
+    BroadcastOutcome, ChainFork, CreateRawTransactionInput, CreateRawTransactionOutput,
+    FundRawTransactionOptions, ImportAddressOptions, ImportAddressTarget, ImportMultiOptions,
+    ImportMultiRequest, ImportMultiScriptPubkey, MemoryInfoMode, MempoolCandidate,
+    MempoolCandidateOptions, ScanAction, ScanObject, ScanRangeError, SighashType, TemplateRequest,
+};
+pub use crate::client_sync::v21::{
+    BumpFeeOptions, BumpFeeOutput, EstimateMode, FailedImport, GenerateBlockTransaction,
+    ImportDescriptorsProgress, ImportDescriptorsReport, ImportDescriptorsRequest, ImportTimestamp,
+    SendOptions, SendOutput, SendParams, SendToAddressRequest, TxOutSetHashType,
+    WalletCreateFundedPsbtOptions,
+};
+pub use crate::client_sync::v22::{CreateWalletOptions, HashOrHeight};
+
+/// Argument to the `Client::get_new_address_with_type` function.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AddressType {
+    Legacy,
+    P2shSegwit,
+    Bech32,
+    Bech32m,
+}
+
+impl fmt::Display for AddressType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use AddressType::*;
+
+        let s = match *self {
+            Legacy => "legacy",
+            P2shSegwit => "p2sh-segwit",
+            Bech32 => "bech32",
+            Bech32m => "bech32m",
+        };
+        fmt::Display::fmt(s, f)
+    }
+}
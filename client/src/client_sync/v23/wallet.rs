@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v23.2`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `listdescriptors`
+#[macro_export]
+macro_rules! impl_client_v23__listdescriptors {
+    () => {
+        impl Client {
+            pub fn list_descriptors(&self) -> Result<ListDescriptors> {
+                self.call("listdescriptors", &[false.into()])
+            }
+
+            /// Lists the wallet's descriptors including private keys (`private` set to true).
+            ///
+            /// The descriptors are redacted from `Debug` output and zeroed on drop, see
+            /// `json::model::PrivateDescriptor`.
+            pub fn list_descriptors_private(&self) -> Result<ListDescriptorsPrivate> {
+                self.call("listdescriptors", &[true.into()])
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getreceivedbyaddress`
+///
+/// From v23 mature coinbase outputs are included in the amount received, immature ones are only
+/// included if `include_immature_coinbase` is set.
+#[macro_export]
+macro_rules! impl_client_v23__getreceivedbyaddress {
+    () => {
+        impl Client {
+            pub fn get_received_by_address(
+                &self,
+                address: &Address<NetworkChecked>,
+            ) -> Result<GetReceivedByAddress> {
+                self.call("getreceivedbyaddress", &[address.to_string().into()])
+            }
+
+            /// Only includes transactions with at least `minconf` confirmations, pass zero to
+            /// include transactions in the mempool.
+            pub fn get_received_by_address_with_minconf(
+                &self,
+                address: &Address<NetworkChecked>,
+                minconf: u32,
+            ) -> Result<GetReceivedByAddress> {
+                self.call("getreceivedbyaddress", &[address.to_string().into(), minconf.into()])
+            }
+
+            pub fn get_received_by_address_with_options(
+                &self,
+                address: &Address<NetworkChecked>,
+                minconf: u32,
+                include_immature_coinbase: bool,
+            ) -> Result<GetReceivedByAddress> {
+                let args =
+                    [address.to_string().into(), minconf.into(), include_immature_coinbase.into()];
+                self.call("getreceivedbyaddress", &args)
+            }
+        }
+    };
+}
+
+/// Implements bitcoind JSON-RPC API method `getreceivedbylabel`
+///
+/// From v23 mature coinbase outputs are included in the amount received, immature ones are only
+/// included if `include_immature_coinbase` is set.
+#[macro_export]
+macro_rules! impl_client_v23__getreceivedbylabel {
+    () => {
+        impl Client {
+            pub fn get_received_by_label(&self, label: &str) -> Result<GetReceivedByLabel> {
+                self.call("getreceivedbylabel", &[label.into()])
+            }
+
+            /// Only includes transactions with at least `minconf` confirmations, pass zero to
+            /// include transactions in the mempool.
+            pub fn get_received_by_label_with_minconf(
+                &self,
+                label: &str,
+                minconf: u32,
+            ) -> Result<GetReceivedByLabel> {
+                self.call("getreceivedbylabel", &[label.into(), minconf.into()])
+            }
+
+            pub fn get_received_by_label_with_options(
+                &self,
+                label: &str,
+                minconf: u32,
+                include_immature_coinbase: bool,
+            ) -> Result<GetReceivedByLabel> {
+                let args = [label.into(), minconf.into(), include_immature_coinbase.into()];
+                self.call("getreceivedbylabel", &args)
+            }
+        }
+    };
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v25`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `scanblocks`
+///
+/// Requires `bitcoind` to be started with `-blockfilterindex`.
+#[macro_export]
+macro_rules! impl_client_v25__scanblocks {
+    () => {
+        impl Client {
+            /// Scans the blocks from `start_height` (default 0) to `stop_height` (default the chain
+            /// tip) for blocks that may be relevant to `scan_objects`.
+            pub fn scan_blocks(
+                &self,
+                scan_objects: &[$crate::client_sync::v17::ScanObject],
+                start_height: Option<u32>,
+                stop_height: Option<u32>,
+            ) -> Result<ScanBlocksStart> {
+                let mut args = vec![
+                    "start".into(),
+                    into_json(scan_objects)?,
+                    into_json(start_height.unwrap_or(0))?,
+                ];
+                if let Some(stop_height) = stop_height {
+                    args.push(into_json(stop_height)?);
+                }
+                self.call("scanblocks", &args)
+            }
+
+            /// Aborts the current scan, returns true if a scan was aborted.
+            pub fn scan_blocks_abort(&self) -> Result<bool> {
+                self.call("scanblocks", &["abort".into()])
+            }
+        }
+    };
+}
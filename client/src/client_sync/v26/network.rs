@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v26`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_minreq_client!` macro to define a `Client`.
+
+/// Implements bitcoind JSON-RPC API method `getrawaddrman`
+///
+/// This is a hidden RPC, the result is only available with the `unstable-rpc` feature.
+#[macro_export]
+macro_rules! impl_client_v26__getrawaddrman {
+    () => {
+        impl Client {
+            /// Returns all the entries of the address manager's new and tried tables.
+            pub fn get_raw_addr_man(&self) -> Result<GetRawAddrMan> {
+                self.call("getrawaddrman", &[])
+            }
+        }
+    };
+}
@@ -11,3 +11,15 @@ pub extern crate json;
 #[cfg(feature = "client-sync")]
 #[macro_use]
 pub mod client_sync;
+
+#[cfg(feature = "client-async")]
+#[macro_use]
+pub mod client_async;
+
+pub mod method_docs;
+pub mod node_url;
+pub mod prelude;
+
+#[cfg(feature = "tokio-bridge")]
+pub mod tokio_bridge;
+pub mod version;
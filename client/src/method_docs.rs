@@ -0,0 +1,1164 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Documentation of the typed client methods, for discovering the methods available at runtime.
+//!
+//! Each entry of the registry records the JSON-RPC method a client method calls, a summary of
+//! Core's help for it, and the versions of Core that support it. Downstream tools can use this to
+//! implement a `help` like listing of the typed calls available for the connected node:
+//!
+//! ```
+//! use bitcoind_json_rpc_client::method_docs;
+//!
+//! // The `version` field returned by `getnetworkinfo` e.g., 260000 for v26.0.
+//! let version = method_docs::major_version(260000);
+//! for doc in method_docs::available(version) {
+//!     println!("{:<40} {}", doc.method, doc.help);
+//! }
+//!
+//! let doc = method_docs::find("get_received_by_label").unwrap();
+//! assert_eq!(doc.rpc, "getreceivedbylabel");
+//! assert!(!doc.is_available(17));
+//! ```
+//!
+//! Every method implemented by the `impl_client_vNN__*` macros has an entry. Constructors, the
+//! untyped `call` methods and the server version check are not included.
+//!
+//! The registry is maintained by hand alongside the `impl_client_*` macros, it does not rely on
+//! any compiler or `rustdoc` support.
+
+/// Documentation of a single typed client method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MethodDoc {
+    /// The name of the JSON-RPC method e.g., `getreceivedbyaddress`.
+    pub rpc: &'static str,
+    /// The name of the client method e.g., `get_received_by_address_with_minconf`.
+    pub method: &'static str,
+    /// The first Core major version whose client implements the method (`17` for v0.17).
+    pub since: u32,
+    /// The first Core major version whose client no longer implements the method, if any.
+    pub removed: Option<u32>,
+    /// A summary, usually the first line, of Core's help text for the JSON-RPC method.
+    pub help: &'static str,
+}
+
+impl MethodDoc {
+    /// Returns true if the client for Core major version `version` implements this method.
+    pub fn is_available(&self, version: u32) -> bool {
+        version >= self.since && self.removed.map_or(true, |removed| version < removed)
+    }
+}
+
+/// Converts the `version` field returned by `getnetworkinfo` to a Core major version.
+///
+/// Both pre and post v22 version numbers are supported e.g., 170100 (v0.17.1) returns 17 and
+/// 260000 (v26.0) returns 26.
+pub fn major_version(node_version: usize) -> u32 { (node_version / 10_000) as u32 }
+
+/// Returns the documentation of all the client methods, sorted by JSON-RPC method.
+pub fn all() -> &'static [MethodDoc] { METHODS }
+
+/// Returns the documentation of the client methods available for Core major version `version`.
+pub fn available(version: u32) -> impl Iterator<Item = &'static MethodDoc> {
+    METHODS.iter().filter(move |doc| doc.is_available(version))
+}
+
+/// Returns the documentation of the client method called `method`.
+pub fn find(method: &str) -> Option<&'static MethodDoc> {
+    METHODS.iter().find(|doc| doc.method == method)
+}
+
+/// Returns the documentation of the client methods that call the JSON-RPC method `rpc`.
+pub fn for_rpc<'a>(rpc: &'a str) -> impl Iterator<Item = &'static MethodDoc> + 'a {
+    METHODS.iter().filter(move |doc| doc.rpc == rpc)
+}
+
+/// The registry, keep sorted by JSON-RPC method then client method.
+const METHODS: &[MethodDoc] = &[
+    MethodDoc {
+        rpc: "addmultisigaddress",
+        method: "add_multisig_address",
+        since: 17,
+        removed: None,
+        help: "Add a nrequired-to-sign multisignature address to the wallet.",
+    },
+    MethodDoc {
+        rpc: "addmultisigaddress",
+        method: "add_multisig_address_with_options",
+        since: 17,
+        removed: None,
+        help: "Add a nrequired-to-sign multisignature address to the wallet.",
+    },
+    MethodDoc {
+        rpc: "bumpfee",
+        method: "bump_fee",
+        since: 17,
+        removed: None,
+        help: "Bumps the fee of an opt-in-RBF transaction, replacing it with a new transaction.",
+    },
+    MethodDoc {
+        rpc: "bumpfee",
+        method: "bump_fee_with_options",
+        since: 21,
+        removed: None,
+        help: "Bumps the fee of an opt-in-RBF transaction, replacing it with a new transaction.",
+    },
+    MethodDoc {
+        rpc: "combinepsbt",
+        method: "combine_psbt",
+        since: 17,
+        removed: None,
+        help: "Combine multiple partially signed Bitcoin transactions into one transaction.",
+    },
+    MethodDoc {
+        rpc: "createrawtransaction",
+        method: "create_raw_transaction",
+        since: 17,
+        removed: None,
+        help: "Create a transaction spending the given inputs and creating new outputs.",
+    },
+    MethodDoc {
+        rpc: "createwallet",
+        method: "create_wallet",
+        since: 17,
+        removed: None,
+        help: "Creates and loads a new wallet.",
+    },
+    MethodDoc {
+        rpc: "createwallet",
+        method: "create_wallet_with_options",
+        since: 17,
+        removed: None,
+        help: "Creates and loads a new wallet.",
+    },
+    MethodDoc {
+        rpc: "decodepsbt",
+        method: "decode_psbt",
+        since: 17,
+        removed: None,
+        help: "Return a JSON object representing the serialized, base64-encoded PSBT.",
+    },
+    MethodDoc {
+        rpc: "decoderawtransaction",
+        method: "decode_raw_transaction",
+        since: 17,
+        removed: None,
+        help: "Return a JSON object representing the serialized, hex-encoded transaction.",
+    },
+    MethodDoc {
+        rpc: "deriveaddresses",
+        method: "derive_addresses",
+        since: 18,
+        removed: None,
+        help: "Derives one or more addresses corresponding to an output descriptor.",
+    },
+    MethodDoc {
+        rpc: "deriveaddresses",
+        method: "derive_addresses_with_range",
+        since: 18,
+        removed: None,
+        help: "Derives one or more addresses corresponding to an output descriptor.",
+    },
+    MethodDoc {
+        rpc: "dumptxoutset",
+        method: "dump_tx_out_set",
+        since: 26,
+        removed: None,
+        help: "Write the serialized UTXO set to disk.",
+    },
+    MethodDoc {
+        rpc: "encryptwallet",
+        method: "encrypt_wallet",
+        since: 17,
+        removed: None,
+        help: "Encrypts the wallet with 'passphrase'.",
+    },
+    MethodDoc {
+        rpc: "estimatesmartfee",
+        method: "estimate_smart_fee",
+        since: 17,
+        removed: None,
+        help: "Estimates the fee per kilobyte needed to confirm within conf_target blocks.",
+    },
+    MethodDoc {
+        rpc: "estimatesmartfee",
+        method: "estimate_smart_fee_with_mode",
+        since: 17,
+        removed: None,
+        help: "Estimates the fee per kilobyte needed to confirm within conf_target blocks.",
+    },
+    MethodDoc {
+        rpc: "finalizepsbt",
+        method: "finalize_psbt",
+        since: 17,
+        removed: None,
+        help: "Finalize the inputs of a PSBT.",
+    },
+    MethodDoc {
+        rpc: "fundrawtransaction",
+        method: "fund_raw_transaction",
+        since: 17,
+        removed: None,
+        help: "Add inputs to a transaction until it has enough in value to meet its out value.",
+    },
+    MethodDoc {
+        rpc: "fundrawtransaction",
+        method: "fund_raw_transaction_with_options",
+        since: 17,
+        removed: None,
+        help: "Add inputs to a transaction until it has enough in value to meet its out value.",
+    },
+    MethodDoc {
+        rpc: "generateblock",
+        method: "generate_block",
+        since: 21,
+        removed: None,
+        help: "Mine a block with a set of ordered transactions to an address or descriptor.",
+    },
+    MethodDoc {
+        rpc: "generateblock",
+        method: "generate_block_to_descriptor",
+        since: 21,
+        removed: None,
+        help: "Mine a block with a set of ordered transactions to an address or descriptor.",
+    },
+    MethodDoc {
+        rpc: "generatetoaddress",
+        method: "generate_to_address",
+        since: 17,
+        removed: None,
+        help: "Mine blocks immediately to a specified address.",
+    },
+    MethodDoc {
+        rpc: "generatetodescriptor",
+        method: "generate_to_descriptor",
+        since: 20,
+        removed: None,
+        help: "Mine blocks immediately to a specified descriptor.",
+    },
+    MethodDoc {
+        rpc: "getaccount",
+        method: "get_account",
+        since: 17,
+        removed: Some(18),
+        help: "DEPRECATED. Returns the account associated with the given address.",
+    },
+    MethodDoc {
+        rpc: "getaccountaddress",
+        method: "get_account_address",
+        since: 17,
+        removed: Some(18),
+        help: "DEPRECATED. Returns the current address for receiving payments to this account.",
+    },
+    MethodDoc {
+        rpc: "getaddressesbyaccount",
+        method: "get_addresses_by_account",
+        since: 17,
+        removed: Some(18),
+        help: "DEPRECATED. Returns the list of addresses for the given account.",
+    },
+    MethodDoc {
+        rpc: "getaddressesbylabel",
+        method: "addresses_with_balances_for_label",
+        since: 17,
+        removed: None,
+        help: "Returns the received and unspent balances of all addresses with a label.",
+    },
+    MethodDoc {
+        rpc: "getaddressesbylabel",
+        method: "get_addresses_by_label",
+        since: 17,
+        removed: None,
+        help: "Returns the list of addresses assigned the specified label.",
+    },
+    MethodDoc {
+        rpc: "getaddressinfo",
+        method: "get_address_info",
+        since: 17,
+        removed: None,
+        help: "Return information about the given bitcoin address.",
+    },
+    MethodDoc {
+        rpc: "getbalance",
+        method: "get_balance",
+        since: 17,
+        removed: None,
+        help: "Returns the total available balance.",
+    },
+    MethodDoc {
+        rpc: "getbalances",
+        method: "get_balances",
+        since: 19,
+        removed: None,
+        help: "Returns an object with all balances in BTC.",
+    },
+    MethodDoc {
+        rpc: "getbestblockhash",
+        method: "best_block_hash",
+        since: 17,
+        removed: None,
+        help: "Returns the hash of the best (tip) block in the most-work fully-validated chain.",
+    },
+    MethodDoc {
+        rpc: "getbestblockhash",
+        method: "get_best_block_hash",
+        since: 17,
+        removed: None,
+        help: "Returns the hash of the best (tip) block in the most-work fully-validated chain.",
+    },
+    MethodDoc {
+        rpc: "getblock",
+        method: "get_block",
+        since: 17,
+        removed: None,
+        help: "Returns the block, parsed from the serialized hex-encoded data.",
+    },
+    MethodDoc {
+        rpc: "getblock",
+        method: "get_block_verbose",
+        since: 17,
+        removed: None,
+        help: "Returns an Object with information about the block and its transactions.",
+    },
+    MethodDoc {
+        rpc: "getblock",
+        method: "get_block_verbosity_one",
+        since: 17,
+        removed: None,
+        help: "Returns the block 'hash', serialized or as a JSON object depending on verbosity.",
+    },
+    MethodDoc {
+        rpc: "getblock",
+        method: "get_block_verbosity_two",
+        since: 17,
+        removed: None,
+        help: "Returns the block 'hash', serialized or as a JSON object depending on verbosity.",
+    },
+    MethodDoc {
+        rpc: "getblock",
+        method: "get_block_verbosity_zero",
+        since: 17,
+        removed: None,
+        help: "Returns the block 'hash', serialized or as a JSON object depending on verbosity.",
+    },
+    MethodDoc {
+        rpc: "getblockchaininfo",
+        method: "get_blockchain_info",
+        since: 17,
+        removed: None,
+        help: "Returns an object containing various state info regarding blockchain processing.",
+    },
+    MethodDoc {
+        rpc: "getblockhash",
+        method: "get_block_hash",
+        since: 17,
+        removed: None,
+        help: "Returns the hash of the block at the given height in the active chain.",
+    },
+    MethodDoc {
+        rpc: "getblockheader",
+        method: "get_block_header",
+        since: 17,
+        removed: None,
+        help: "Returns the serialized, hex-encoded block header.",
+    },
+    MethodDoc {
+        rpc: "getblockheader",
+        method: "get_block_header_verbose",
+        since: 17,
+        removed: None,
+        help: "Returns an Object with information about blockheader <hash>.",
+    },
+    MethodDoc {
+        rpc: "getblocktemplate",
+        method: "get_block_template",
+        since: 17,
+        removed: None,
+        help: "Returns data needed to construct a block to work on.",
+    },
+    MethodDoc {
+        rpc: "getchainstates",
+        method: "get_chain_states",
+        since: 26,
+        removed: None,
+        help: "Return information about chainstates.",
+    },
+    MethodDoc {
+        rpc: "getchaintips",
+        method: "detect_reorg",
+        since: 17,
+        removed: None,
+        help: "Returns the fork point if any of the last N blocks were replaced by a reorg.",
+    },
+    MethodDoc {
+        rpc: "getchaintips",
+        method: "get_chain_tips",
+        since: 17,
+        removed: None,
+        help: "Returns all known tips in the block tree, including stale branches.",
+    },
+    #[cfg(feature = "unstable-v28")]
+    MethodDoc {
+        rpc: "getdescriptoractivity",
+        method: "get_descriptor_activity",
+        since: 28,
+        removed: None,
+        help: "Get spend and receive activity associated with a set of descriptors.",
+    },
+    MethodDoc {
+        rpc: "getdescriptorinfo",
+        method: "get_descriptor_info",
+        since: 18,
+        removed: None,
+        help: "Analyses a descriptor.",
+    },
+    MethodDoc {
+        rpc: "getindexinfo",
+        method: "get_index_info",
+        since: 21,
+        removed: None,
+        help: "Returns the status of one or all available indices currently running in the node.",
+    },
+    MethodDoc {
+        rpc: "getindexinfo",
+        method: "get_index_info_for",
+        since: 21,
+        removed: None,
+        help: "Returns the status of one or all available indices currently running in the node.",
+    },
+    MethodDoc {
+        rpc: "getmemoryinfo",
+        method: "get_memory_info",
+        since: 17,
+        removed: None,
+        help: "Returns an object containing information about memory usage.",
+    },
+    MethodDoc {
+        rpc: "getmemoryinfo",
+        method: "get_memory_info_malloc_info",
+        since: 17,
+        removed: None,
+        help: "Returns an object containing information about memory usage.",
+    },
+    MethodDoc {
+        rpc: "getmempoolentry",
+        method: "get_mempool_entry",
+        since: 17,
+        removed: None,
+        help: "Returns mempool data for given transaction.",
+    },
+    MethodDoc {
+        rpc: "getmempoolentry",
+        method: "mempool_candidate",
+        since: 17,
+        removed: None,
+        help: "Assesses a mempool transaction for eviction and expiry risk.",
+    },
+    MethodDoc {
+        rpc: "getmempoolinfo",
+        method: "get_mempool_info",
+        since: 17,
+        removed: None,
+        help: "Returns details on the active state of the TX memory pool.",
+    },
+    MethodDoc {
+        rpc: "getmininginfo",
+        method: "get_mining_info",
+        since: 17,
+        removed: None,
+        help: "Returns a json object containing mining-related information.",
+    },
+    MethodDoc {
+        rpc: "getnetworkinfo",
+        method: "get_network_info",
+        since: 17,
+        removed: None,
+        help: "Returns an object containing various state info regarding P2P networking.",
+    },
+    MethodDoc {
+        rpc: "getnetworkinfo",
+        method: "server_version",
+        since: 17,
+        removed: None,
+        help: "Returns the server version, the version field of getnetworkinfo.",
+    },
+    MethodDoc {
+        rpc: "getnewaddress",
+        method: "get_new_address",
+        since: 17,
+        removed: None,
+        help: "Returns a new Bitcoin address for receiving payments.",
+    },
+    MethodDoc {
+        rpc: "getnewaddress",
+        method: "get_new_address_with_type",
+        since: 17,
+        removed: None,
+        help: "Returns a new Bitcoin address for receiving payments.",
+    },
+    MethodDoc {
+        rpc: "getnewaddress",
+        method: "new_address",
+        since: 17,
+        removed: None,
+        help: "Returns a new Bitcoin address for receiving payments.",
+    },
+    MethodDoc {
+        rpc: "getnewaddress",
+        method: "new_address_with_type",
+        since: 17,
+        removed: None,
+        help: "Returns a new Bitcoin address of the given type for receiving payments.",
+    },
+    MethodDoc {
+        rpc: "getnodeaddresses",
+        method: "get_node_addresses",
+        since: 18,
+        removed: None,
+        help: "Return known addresses which can be used to find new nodes in the network.",
+    },
+    MethodDoc {
+        rpc: "getnodeaddresses",
+        method: "get_node_addresses_on_network",
+        since: 22,
+        removed: None,
+        help: "Return known addresses which can be used to find new nodes in the network.",
+    },
+    MethodDoc {
+        rpc: "getnodeaddresses",
+        method: "get_node_addresses_with_count",
+        since: 18,
+        removed: None,
+        help: "Return known addresses which can be used to find new nodes in the network.",
+    },
+    MethodDoc {
+        rpc: "getpeerinfo",
+        method: "get_peer_info",
+        since: 17,
+        removed: None,
+        help: "Returns data about each connected network node as a json array of objects.",
+    },
+    #[cfg(feature = "unstable-rpc")]
+    MethodDoc {
+        rpc: "getrawaddrman",
+        method: "get_raw_addr_man",
+        since: 26,
+        removed: None,
+        help: "Returns information on all address manager entries for the new and tried tables.",
+    },
+    MethodDoc {
+        rpc: "getrawchangeaddress",
+        method: "get_raw_change_address",
+        since: 17,
+        removed: None,
+        help: "Returns a new Bitcoin address, for receiving change.",
+    },
+    MethodDoc {
+        rpc: "getrawchangeaddress",
+        method: "get_raw_change_address_with_type",
+        since: 17,
+        removed: None,
+        help: "Returns a new Bitcoin address, for receiving change.",
+    },
+    MethodDoc {
+        rpc: "getrawmempool",
+        method: "get_raw_mempool",
+        since: 17,
+        removed: None,
+        help: "Returns all transaction ids in memory pool.",
+    },
+    MethodDoc {
+        rpc: "getrawmempool",
+        method: "get_raw_mempool_verbose",
+        since: 17,
+        removed: None,
+        help: "Returns all transaction ids in memory pool.",
+    },
+    MethodDoc {
+        rpc: "getrawmempool",
+        method: "mempool_candidates",
+        since: 17,
+        removed: None,
+        help: "Returns the mempool transactions near eviction or expiry.",
+    },
+    MethodDoc {
+        rpc: "getrawtransaction",
+        method: "get_raw_transaction",
+        since: 17,
+        removed: None,
+        help: "Returns a mempool transaction, or one in a block if the node has -txindex.",
+    },
+    MethodDoc {
+        rpc: "getrawtransaction",
+        method: "get_raw_transaction_in_block",
+        since: 17,
+        removed: None,
+        help: "Returns a transaction in the given block, does not require -txindex.",
+    },
+    MethodDoc {
+        rpc: "getreceivedbyaccount",
+        method: "get_received_by_account",
+        since: 17,
+        removed: Some(18),
+        help: "DEPRECATED. Returns the total amount received by addresses with <account>.",
+    },
+    MethodDoc {
+        rpc: "getreceivedbyaddress",
+        method: "get_received_by_address",
+        since: 17,
+        removed: None,
+        help: "Returns the total amount received by the given address.",
+    },
+    MethodDoc {
+        rpc: "getreceivedbyaddress",
+        method: "get_received_by_address_with_minconf",
+        since: 17,
+        removed: None,
+        help: "Returns the total amount received by the given address.",
+    },
+    MethodDoc {
+        rpc: "getreceivedbyaddress",
+        method: "get_received_by_address_with_options",
+        since: 23,
+        removed: None,
+        help: "Returns the total amount received by the given address.",
+    },
+    MethodDoc {
+        rpc: "getreceivedbylabel",
+        method: "get_received_by_label",
+        since: 18,
+        removed: None,
+        help: "Returns the total amount received by addresses with <label>.",
+    },
+    MethodDoc {
+        rpc: "getreceivedbylabel",
+        method: "get_received_by_label_with_minconf",
+        since: 18,
+        removed: None,
+        help: "Returns the total amount received by addresses with <label>.",
+    },
+    MethodDoc {
+        rpc: "getreceivedbylabel",
+        method: "get_received_by_label_with_options",
+        since: 23,
+        removed: None,
+        help: "Returns the total amount received by addresses with <label>.",
+    },
+    MethodDoc {
+        rpc: "gettransaction",
+        method: "get_transaction",
+        since: 17,
+        removed: None,
+        help: "Get detailed information about in-wallet transaction <txid>.",
+    },
+    MethodDoc {
+        rpc: "gettransaction",
+        method: "get_transaction_verbose",
+        since: 19,
+        removed: None,
+        help: "Get detailed information about in-wallet transaction <txid>.",
+    },
+    MethodDoc {
+        rpc: "gettxout",
+        method: "get_tx_out",
+        since: 17,
+        removed: None,
+        help: "Returns details about an unspent transaction output.",
+    },
+    MethodDoc {
+        rpc: "gettxout",
+        method: "try_get_tx_out",
+        since: 17,
+        removed: None,
+        help: "Returns details about an unspent transaction output.",
+    },
+    MethodDoc {
+        rpc: "gettxout",
+        method: "tx_fee",
+        since: 17,
+        removed: None,
+        help: "Returns the fee paid by a transaction, using the values of its previous outputs.",
+    },
+    MethodDoc {
+        rpc: "gettxoutproof",
+        method: "get_tx_out_proof",
+        since: 17,
+        removed: None,
+        help: "Returns a hex-encoded proof that \"txid\" was included in a block.",
+    },
+    MethodDoc {
+        rpc: "gettxoutproof",
+        method: "get_tx_out_proof_in_block",
+        since: 17,
+        removed: None,
+        help: "Returns a hex-encoded proof that \"txid\" was included in a block.",
+    },
+    MethodDoc {
+        rpc: "gettxoutsetinfo",
+        method: "get_tx_out_set_info",
+        since: 17,
+        removed: None,
+        help: "Returns statistics about the unspent transaction output set.",
+    },
+    MethodDoc {
+        rpc: "gettxoutsetinfo",
+        method: "get_tx_out_set_info_at",
+        since: 22,
+        removed: None,
+        help: "Returns statistics about the unspent transaction output set.",
+    },
+    MethodDoc {
+        rpc: "gettxoutsetinfo",
+        method: "get_tx_out_set_info_with_hash_type",
+        since: 21,
+        removed: None,
+        help: "Returns statistics about the unspent transaction output set.",
+    },
+    MethodDoc {
+        rpc: "getwalletinfo",
+        method: "address_type_defaults",
+        since: 17,
+        removed: None,
+        help: "Returns the address types the wallet generates by default.",
+    },
+    MethodDoc {
+        rpc: "getwalletinfo",
+        method: "get_wallet_info",
+        since: 17,
+        removed: None,
+        help: "Returns an object containing various wallet state info.",
+    },
+    MethodDoc {
+        rpc: "importaddress",
+        method: "import_address",
+        since: 17,
+        removed: None,
+        help: "Adds an address or script (in hex) to watch as if it were in your wallet.",
+    },
+    MethodDoc {
+        rpc: "importaddress",
+        method: "import_address_with_options",
+        since: 17,
+        removed: None,
+        help: "Adds an address or script (in hex) to watch as if it were in your wallet.",
+    },
+    MethodDoc {
+        rpc: "importdescriptors",
+        method: "import_descriptors",
+        since: 21,
+        removed: None,
+        help: "Import descriptors.",
+    },
+    MethodDoc {
+        rpc: "importdescriptors",
+        method: "import_descriptors_chunked",
+        since: 21,
+        removed: None,
+        help: "Import descriptors.",
+    },
+    MethodDoc {
+        rpc: "importmulti",
+        method: "import_multi",
+        since: 17,
+        removed: None,
+        help: "Import addresses/scripts, rescanning all addresses in one-shot-only.",
+    },
+    MethodDoc {
+        rpc: "importmulti",
+        method: "import_multi_with_options",
+        since: 17,
+        removed: None,
+        help: "Import addresses/scripts, rescanning all addresses in one-shot-only.",
+    },
+    MethodDoc {
+        rpc: "invalidateblock",
+        method: "invalidate_block",
+        since: 17,
+        removed: None,
+        help: "Permanently marks a block as invalid, as if it violated a consensus rule.",
+    },
+    MethodDoc {
+        rpc: "listaccounts",
+        method: "list_accounts",
+        since: 17,
+        removed: Some(18),
+        help: "DEPRECATED. Returns the account names and their balances.",
+    },
+    MethodDoc {
+        rpc: "listdescriptors",
+        method: "list_descriptors",
+        since: 22,
+        removed: None,
+        help: "List descriptors imported into a descriptor-enabled wallet.",
+    },
+    MethodDoc {
+        rpc: "listdescriptors",
+        method: "list_descriptors_private",
+        since: 23,
+        removed: None,
+        help: "List descriptors imported into a descriptor-enabled wallet.",
+    },
+    MethodDoc {
+        rpc: "listlockunspent",
+        method: "list_lock_unspent",
+        since: 17,
+        removed: None,
+        help: "Returns the list of temporarily unspendable outputs.",
+    },
+    MethodDoc {
+        rpc: "listreceivedbyaddress",
+        method: "list_received_by_address",
+        since: 17,
+        removed: None,
+        help: "List balances by receiving address.",
+    },
+    MethodDoc {
+        rpc: "listsinceblock",
+        method: "list_since_block",
+        since: 17,
+        removed: None,
+        help: "Get all transactions in blocks since block [blockhash].",
+    },
+    MethodDoc {
+        rpc: "listtransactions",
+        method: "list_transactions",
+        since: 17,
+        removed: None,
+        help: "Returns up to 'count' most recent transactions.",
+    },
+    MethodDoc {
+        rpc: "listtransactions",
+        method: "list_transactions_page",
+        since: 17,
+        removed: None,
+        help: "Returns up to 'count' most recent transactions.",
+    },
+    MethodDoc {
+        rpc: "listunspent",
+        method: "list_unspent",
+        since: 17,
+        removed: None,
+        help: "Returns array of unspent transaction outputs.",
+    },
+    MethodDoc {
+        rpc: "listunspent",
+        method: "psbt_inputs_for_unspent",
+        since: 17,
+        removed: None,
+        help: "Converts unspent transaction outputs into PSBT inputs.",
+    },
+    MethodDoc {
+        rpc: "listwalletdir",
+        method: "list_wallet_dir",
+        since: 18,
+        removed: None,
+        help: "Returns a list of wallets in the wallet directory.",
+    },
+    MethodDoc {
+        rpc: "listwalletdir",
+        method: "wallet_inventory",
+        since: 18,
+        removed: None,
+        help: "Compares the loaded wallets with the wallets in the wallet directory.",
+    },
+    MethodDoc {
+        rpc: "listwallets",
+        method: "list_wallets",
+        since: 17,
+        removed: None,
+        help: "Returns a list of currently loaded wallets.",
+    },
+    MethodDoc {
+        rpc: "loadtxoutset",
+        method: "load_tx_out_set",
+        since: 26,
+        removed: None,
+        help: "Load the serialized UTXO set from disk.",
+    },
+    MethodDoc {
+        rpc: "loadwallet",
+        method: "load_wallet",
+        since: 17,
+        removed: None,
+        help: "Loads a wallet from a wallet file or directory.",
+    },
+    MethodDoc {
+        rpc: "lockunspent",
+        method: "lock_unspent",
+        since: 17,
+        removed: None,
+        help: "Temporarily locks or unlocks outputs, locked outputs are not used for funding.",
+    },
+    MethodDoc {
+        rpc: "logging",
+        method: "get_logging",
+        since: 17,
+        removed: None,
+        help: "Gets and sets the logging configuration.",
+    },
+    MethodDoc {
+        rpc: "logging",
+        method: "logging",
+        since: 17,
+        removed: None,
+        help: "Gets and sets the logging configuration.",
+    },
+    MethodDoc {
+        rpc: "preciousblock",
+        method: "precious_block",
+        since: 17,
+        removed: None,
+        help: "Treats a block as if it were received before others with the same work.",
+    },
+    MethodDoc {
+        rpc: "psbtbumpfee",
+        method: "psbt_bump_fee",
+        since: 21,
+        removed: None,
+        help: "Bumps the fee of an opt-in-RBF transaction, replacing it with a new transaction.",
+    },
+    MethodDoc {
+        rpc: "psbtbumpfee",
+        method: "psbt_bump_fee_with_options",
+        since: 21,
+        removed: None,
+        help: "Bumps the fee of an opt-in-RBF transaction, replacing it with a new transaction.",
+    },
+    MethodDoc {
+        rpc: "reconsiderblock",
+        method: "reconsider_block",
+        since: 17,
+        removed: None,
+        help: "Removes invalidity status of a block, its ancestors and its descendants.",
+    },
+    MethodDoc {
+        rpc: "rescanblockchain",
+        method: "rescan_blockchain",
+        since: 17,
+        removed: None,
+        help: "Rescan the local blockchain for wallet related transactions.",
+    },
+    MethodDoc {
+        rpc: "rescanblockchain",
+        method: "rescan_blockchain_range",
+        since: 17,
+        removed: None,
+        help: "Rescan the local blockchain for wallet related transactions.",
+    },
+    MethodDoc {
+        rpc: "scanblocks",
+        method: "scan_blocks",
+        since: 25,
+        removed: None,
+        help: "Return relevant blockhashes for given descriptors (requires blockfilterindex).",
+    },
+    MethodDoc {
+        rpc: "scanblocks",
+        method: "scan_blocks_abort",
+        since: 25,
+        removed: None,
+        help: "Return relevant blockhashes for given descriptors (requires blockfilterindex).",
+    },
+    MethodDoc {
+        rpc: "scantxoutset",
+        method: "scan_tx_out_set",
+        since: 17,
+        removed: None,
+        help: "Scans the unspent transaction output set for entries that match descriptors.",
+    },
+    MethodDoc {
+        rpc: "scantxoutset",
+        method: "scan_tx_out_set_abort",
+        since: 17,
+        removed: None,
+        help: "Scans the unspent transaction output set for entries that match descriptors.",
+    },
+    MethodDoc {
+        rpc: "scantxoutset",
+        method: "scan_tx_out_set_status",
+        since: 17,
+        removed: None,
+        help: "Scans the unspent transaction output set for entries that match descriptors.",
+    },
+    MethodDoc {
+        rpc: "send",
+        method: "send",
+        since: 21,
+        removed: None,
+        help: "Send a transaction.",
+    },
+    MethodDoc {
+        rpc: "sendrawtransaction",
+        method: "send_raw_transaction",
+        since: 17,
+        removed: None,
+        help: "Submits raw transaction (serialized, hex-encoded) to local node and network.",
+    },
+    MethodDoc {
+        rpc: "sendrawtransaction",
+        method: "send_raw_transaction_with_limits",
+        since: 25,
+        removed: None,
+        help: "Submits raw transaction (serialized, hex-encoded) to local node and network.",
+    },
+    MethodDoc {
+        rpc: "sendrawtransaction",
+        method: "send_raw_transaction_with_max_fee_rate",
+        since: 19,
+        removed: None,
+        help: "Submits raw transaction (serialized, hex-encoded) to local node and network.",
+    },
+    MethodDoc {
+        rpc: "sendtoaddress",
+        method: "send_to_address",
+        since: 17,
+        removed: None,
+        help: "Send an amount to a given address.",
+    },
+    MethodDoc {
+        rpc: "sendtoaddress",
+        method: "send_to_address_with",
+        since: 17,
+        removed: None,
+        help: "Send an amount to a given address.",
+    },
+    MethodDoc {
+        rpc: "setaccount",
+        method: "set_account",
+        since: 17,
+        removed: Some(18),
+        help: "DEPRECATED. Sets the account associated with the given address.",
+    },
+    MethodDoc {
+        rpc: "setmocktime",
+        method: "set_mock_time",
+        since: 17,
+        removed: None,
+        help: "Set the local time to given timestamp (-regtest only).",
+    },
+    MethodDoc {
+        rpc: "signmessage",
+        method: "sign_message",
+        since: 17,
+        removed: None,
+        help: "Sign a message with the private key of an address.",
+    },
+    MethodDoc {
+        rpc: "signrawtransactionwithwallet",
+        method: "sign_raw_transaction_with_wallet",
+        since: 17,
+        removed: None,
+        help: "Sign inputs for raw transaction (serialized, hex-encoded).",
+    },
+    MethodDoc {
+        rpc: "stop",
+        method: "stop",
+        since: 17,
+        removed: None,
+        help: "Request a graceful shutdown of Bitcoin Core.",
+    },
+    MethodDoc {
+        rpc: "testmempoolaccept",
+        method: "broadcast_checked",
+        since: 17,
+        removed: None,
+        help: "Broadcasts a transaction only if testmempoolaccept accepts it.",
+    },
+    MethodDoc {
+        rpc: "testmempoolaccept",
+        method: "broadcast_checked_overriding",
+        since: 17,
+        removed: None,
+        help: "Broadcasts a transaction unless rejected for a reason not in the overrides.",
+    },
+    MethodDoc {
+        rpc: "testmempoolaccept",
+        method: "test_mempool_accept",
+        since: 17,
+        removed: None,
+        help: "Returns if raw transaction (serialized, hex-encoded) would be accepted by mempool.",
+    },
+    MethodDoc {
+        rpc: "unloadwallet",
+        method: "unload_wallet",
+        since: 17,
+        removed: None,
+        help: "Unloads the wallet referenced by the request endpoint or the argument.",
+    },
+    MethodDoc {
+        rpc: "verifychain",
+        method: "verify_chain",
+        since: 17,
+        removed: None,
+        help: "Verifies blockchain database.",
+    },
+    MethodDoc {
+        rpc: "verifychain",
+        method: "verify_chain_with_heartbeat",
+        since: 17,
+        removed: None,
+        help: "Verifies the blockchain database, calling a heartbeat while it runs.",
+    },
+    MethodDoc {
+        rpc: "verifymessage",
+        method: "verify_message",
+        since: 17,
+        removed: None,
+        help: "Verify a signed message.",
+    },
+    MethodDoc {
+        rpc: "verifytxoutproof",
+        method: "verify_tx_out_proof",
+        since: 17,
+        removed: None,
+        help: "Verifies that a proof points to a transaction in a block.",
+    },
+    MethodDoc {
+        rpc: "walletcreatefundedpsbt",
+        method: "wallet_create_funded_psbt",
+        since: 17,
+        removed: None,
+        help: "Creates and funds a transaction in the Partially Signed Transaction format.",
+    },
+    MethodDoc {
+        rpc: "walletcreatefundedpsbt",
+        method: "wallet_create_funded_psbt_with_options",
+        since: 17,
+        removed: None,
+        help: "Creates and funds a transaction in the Partially Signed Transaction format.",
+    },
+    MethodDoc {
+        rpc: "walletlock",
+        method: "wallet_lock",
+        since: 17,
+        removed: None,
+        help: "Removes the wallet encryption key from memory, locking the wallet.",
+    },
+    MethodDoc {
+        rpc: "walletpassphrase",
+        method: "wallet_passphrase",
+        since: 17,
+        removed: None,
+        help: "Stores the wallet decryption key in memory for 'timeout' seconds.",
+    },
+    MethodDoc {
+        rpc: "walletpassphrasechange",
+        method: "wallet_passphrase_change",
+        since: 17,
+        removed: None,
+        help: "Changes the wallet passphrase from 'oldpassphrase' to 'newpassphrase'.",
+    },
+    MethodDoc {
+        rpc: "walletprocesspsbt",
+        method: "wallet_process_psbt",
+        since: 17,
+        removed: None,
+        help: "Update a PSBT with input information from our wallet and then sign inputs.",
+    },
+    MethodDoc {
+        rpc: "walletprocesspsbt",
+        method: "wallet_process_psbt_with_options",
+        since: 17,
+        removed: None,
+        help: "Update a PSBT with input information from our wallet and then sign inputs.",
+    },
+];
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The URL of a node's JSON-RPC server.
+//!
+//! Building URLs by concatenating strings is error-prone e.g., using the mainnet port for a regtest
+//! node or forgetting to escape a wallet name. A [`NodeUrl`] defaults the port from the network
+//! (as Core does for `-rpcport`) and percent-encodes the wallet name of the wallet endpoint:
+//!
+//! ```
+//! use bitcoind_json_rpc_client::bitcoin::Network;
+//! use bitcoind_json_rpc_client::node_url::NodeUrl;
+//!
+//! let url = NodeUrl::new(Network::Regtest).with_wallet("my wallet");
+//! assert_eq!(url.to_string(), "http://127.0.0.1:18443/wallet/my%20wallet");
+//!
+//! let url: NodeUrl = "http://node.local:8332".parse().unwrap();
+//! assert_eq!(url.port(), 8332);
+//! ```
+
+use std::fmt;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use bitcoin::Network;
+
+/// The path prefix of Core's per-wallet endpoint.
+const WALLET_PATH: &str = "/wallet/";
+
+/// Returns the default RPC port Core uses for `network` (see `-rpcport`).
+pub fn default_rpc_port(network: Network) -> u16 {
+    match network {
+        Network::Bitcoin => 8332,
+        Network::Testnet => 18332,
+        Network::Testnet4 => 48332,
+        Network::Signet => 38332,
+        Network::Regtest => 18443,
+    }
+}
+
+/// The URL of a node's JSON-RPC server, optionally including a wallet path.
+///
+/// Only `http` is supported, as is the case for Core's RPC server.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NodeUrl {
+    host: String,
+    port: u16,
+    wallet: Option<String>,
+}
+
+impl NodeUrl {
+    /// Creates a URL for a node on `network` listening on localhost, using the default port.
+    pub fn new(network: Network) -> Self {
+        NodeUrl { host: "127.0.0.1".to_owned(), port: default_rpc_port(network), wallet: None }
+    }
+
+    /// Creates a URL for a node listening on `addr`.
+    pub fn from_socket_addr(addr: SocketAddr) -> Self {
+        let host = match addr {
+            SocketAddr::V4(addr) => addr.ip().to_string(),
+            SocketAddr::V6(addr) => format!("[{}]", addr.ip()),
+        };
+        NodeUrl { host, port: addr.port(), wallet: None }
+    }
+
+    /// Parses `s`, using the default port for `network` if `s` does not include a port.
+    ///
+    /// The scheme is optional, a wallet path (`/wallet/<name>`) may be included.
+    pub fn parse(s: &str, network: Network) -> Result<Self, NodeUrlError> {
+        let rest = match s.find("://") {
+            Some(i) if &s[..i] == "http" => &s[i + 3..],
+            Some(i) => return Err(NodeUrlError::Scheme(s[..i].to_owned())),
+            None => s,
+        };
+        let (authority, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, ""),
+        };
+
+        // Split off the port, taking care not to split an IPv6 address.
+        let (host, port) = match authority.rfind(':') {
+            Some(i) if !authority[i..].contains(']') => {
+                let port = &authority[i + 1..];
+                let port = port.parse().map_err(|_| NodeUrlError::Port(port.to_owned()))?;
+                (&authority[..i], port)
+            }
+            _ => (authority, default_rpc_port(network)),
+        };
+        validate_host(host)?;
+
+        let wallet = match path {
+            "" | "/" => None,
+            _ => match path.strip_prefix(WALLET_PATH) {
+                Some(wallet) if !wallet.is_empty() => Some(
+                    percent_decode(wallet).ok_or_else(|| NodeUrlError::Path(path.to_owned()))?,
+                ),
+                _ => return Err(NodeUrlError::Path(path.to_owned())),
+            },
+        };
+
+        Ok(NodeUrl { host: host.to_owned(), port, wallet })
+    }
+
+    /// Sets the host, an IPv6 address must be enclosed in brackets.
+    pub fn with_host<S: Into<String>>(mut self, host: S) -> Result<Self, NodeUrlError> {
+        let host = host.into();
+        validate_host(&host)?;
+        self.host = host;
+        Ok(self)
+    }
+
+    /// Sets the port.
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Sets the wallet, requests are sent to the wallet endpoint (`/wallet/<name>`).
+    pub fn with_wallet<S: Into<String>>(mut self, wallet: S) -> Self {
+        self.wallet = Some(wallet.into());
+        self
+    }
+
+    /// Returns the URL of the node itself, without the wallet path.
+    pub fn without_wallet(&self) -> Self { NodeUrl { wallet: None, ..self.clone() } }
+
+    /// Returns the host.
+    pub fn host(&self) -> &str { &self.host }
+
+    /// Returns the port.
+    pub fn port(&self) -> u16 { self.port }
+
+    /// Returns the wallet name, if set.
+    pub fn wallet(&self) -> Option<&str> { self.wallet.as_deref() }
+}
+
+impl fmt::Display for NodeUrl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "http://{}:{}", self.host, self.port)?;
+        if let Some(ref wallet) = self.wallet {
+            f.write_str(WALLET_PATH)?;
+            for byte in wallet.bytes() {
+                if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+                    write!(f, "{}", byte as char)?;
+                } else {
+                    write!(f, "%{:02X}", byte)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for NodeUrl {
+    type Err = NodeUrlError;
+
+    /// Parses a URL, the port defaults to the mainnet port if not given.
+    fn from_str(s: &str) -> Result<Self, Self::Err> { NodeUrl::parse(s, Network::Bitcoin) }
+}
+
+/// Returns an error if `host` is not a valid host name or IP address.
+fn validate_host(host: &str) -> Result<(), NodeUrlError> {
+    let valid = match host.strip_prefix('[') {
+        Some(ipv6) =>
+            ipv6.strip_suffix(']').map_or(false, |ip| ip.parse::<std::net::Ipv6Addr>().is_ok()),
+        None =>
+            !host.is_empty()
+                && host.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.'),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(NodeUrlError::Host(host.to_owned()))
+    }
+}
+
+/// Decodes the percent-encoded `s`, returns `None` if invalid.
+fn percent_decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            let hex = std::str::from_utf8(&hex).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Error parsing or building a [`NodeUrl`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NodeUrlError {
+    /// The scheme is not `http`.
+    Scheme(String),
+    /// The host is empty or invalid.
+    Host(String),
+    /// The port is not a valid port number.
+    Port(String),
+    /// The path is not empty or a wallet path (`/wallet/<name>`).
+    Path(String),
+}
+
+impl fmt::Display for NodeUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use NodeUrlError::*;
+
+        match *self {
+            Scheme(ref scheme) =>
+                write!(f, "unsupported scheme `{}`, only http is supported", scheme),
+            Host(ref host) => write!(f, "invalid host `{}`", host),
+            Port(ref port) => write!(f, "invalid port `{}`", port),
+            Path(ref path) => write!(f, "invalid path `{}`, expected `/wallet/<name>`", path),
+        }
+    }
+}
+
+impl std::error::Error for NodeUrlError {}
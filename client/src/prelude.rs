@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Re-exports of the commonly used types from this crate, the `json` crate and `rust-bitcoin`.
+//!
+//! Typical applications only need a single import:
+//!
+//! ```text
+//! use bitcoind_json_rpc_client::prelude::*;
+//! ```
+//!
+//! The client and its argument types are those of the latest supported version of Core, use the
+//! versioned modules directly to talk to an older node.
+
+#[doc(no_inline)]
+pub use bitcoin::{Address, Amount, BlockHash, FeeRate, Network, Transaction, Txid};
+
+#[cfg(feature = "client-sync")]
+#[doc(no_inline)]
+pub use crate::client_sync::v26::{
+    BumpFeeOptions, Client, ClientBuilder, CreateRawTransactionInput, CreateRawTransactionOutput,
+    CreateWalletOptions, EstimateMode, FundRawTransactionOptions, ImportDescriptorsRequest,
+    ImportTimestamp, SendOptions, SendOutput, SendParams, SendToAddressRequest,
+};
+#[cfg(feature = "client-sync")]
+#[doc(no_inline)]
+pub use crate::client_sync::{Auth, BitcoindRpc, MockTransport, NodeState};
+#[doc(no_inline)]
+pub use crate::json::model::{
+    self, AddressType, Chain, FundRawTransaction, GetAddressInfo, GetBalances, GetBlockchainInfo,
+    GetDescriptorInfo, GetNetworkInfo, GetTransaction, GetWalletInfo, IbdState, ListTransactions,
+    ListUnspent, ListUnspentItem, MempoolAcceptance, PrivateDescriptor, SendToAddress,
+    TestMempoolAccept, TransactionItem,
+};
+#[doc(no_inline)]
+pub use crate::node_url::NodeUrl;
+#[cfg(feature = "tokio-bridge")]
+#[doc(no_inline)]
+pub use crate::tokio_bridge::AsyncClientHandle;
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Async wrappers around the sync clients, for use from within a `tokio` runtime.
+//!
+//! Calling a method of a sync client from an async task blocks the executor thread until `bitcoind`
+//! responds. An [`AsyncClientHandle`] instead runs each call on `tokio`'s blocking thread pool
+//! (using `spawn_blocking`), which allows code bases that already use a sync client to be called
+//! from async code without switching to the `client-async` clients.
+//!
+//! ```no_run
+//! use bitcoind_json_rpc_client::client_sync::v26::Client;
+//! use bitcoind_json_rpc_client::client_sync::{Auth, Result};
+//! use bitcoind_json_rpc_client::tokio_bridge::AsyncClientHandle;
+//!
+//! # async fn example() -> Result<()> {
+//! let auth = Auth::UserPass("user".to_owned(), "password".to_owned());
+//! let handle = AsyncClientHandle::new(Client::new_with_auth("http://127.0.0.1:18443", auth)?);
+//!
+//! // The version-generic methods are available directly.
+//! let info = handle.get_blockchain_info().await?;
+//! // Any other method of the version specific client can be called using `run`.
+//! let hash = info.best_block_hash;
+//! let block = handle.run(move |client| client.get_block(&hash)).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bitcoin::address::{Address, NetworkChecked};
+use bitcoin::block::Header;
+use bitcoin::{Amount, BlockHash, Txid};
+
+use crate::client_sync::{BitcoindRpc, Error, NodeState, Result};
+use crate::json::model;
+
+/// A handle to a sync client that runs calls on `tokio`'s blocking thread pool.
+///
+/// The handle is cheap to clone, clones share the wrapped client.
+#[derive(Debug)]
+pub struct AsyncClientHandle<C> {
+    client: Arc<C>,
+}
+
+impl<C> AsyncClientHandle<C> {
+    /// Creates a handle that wraps `client`.
+    pub fn new(client: C) -> Self { Self { client: Arc::new(client) } }
+
+    /// Returns the wrapped client, for making blocking calls outside of an async context.
+    pub fn client(&self) -> &C { &self.client }
+}
+
+impl<C: Send + Sync + 'static> AsyncClientHandle<C> {
+    /// Runs `f` with the wrapped client on `tokio`'s blocking thread pool.
+    ///
+    /// # Panics
+    ///
+    /// If called outside of a `tokio` runtime or if `f` panics.
+    pub async fn run<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&C) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let client = Arc::clone(&self.client);
+        match tokio::task::spawn_blocking(move || f(&client)).await {
+            Ok(res) => res,
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            // Blocking tasks are only cancelled if the runtime is shutting down.
+            Err(_) => Err(Error::Io(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "blocking task cancelled, runtime shutting down",
+            ))),
+        }
+    }
+}
+
+impl<C: BitcoindRpc + Send + Sync + 'static> AsyncClientHandle<C> {
+    /// Calls `getblockchaininfo`.
+    pub async fn get_blockchain_info(&self) -> Result<model::GetBlockchainInfo> {
+        self.run(|client| client.get_blockchain_info()).await
+    }
+
+    /// Calls `getbestblockhash`.
+    pub async fn get_best_block_hash(&self) -> Result<model::GetBestBlockHash> {
+        self.run(|client| client.get_best_block_hash()).await
+    }
+
+    /// Calls `getrawmempool`.
+    pub async fn get_raw_mempool(&self) -> Result<model::GetRawMempool> {
+        self.run(|client| client.get_raw_mempool()).await
+    }
+
+    /// Calls `getmempoolinfo`.
+    pub async fn get_mempool_info(&self) -> Result<model::GetMempoolInfo> {
+        self.run(|client| client.get_mempool_info()).await
+    }
+
+    /// Calls `getnetworkinfo`.
+    pub async fn get_network_info(&self) -> Result<model::GetNetworkInfo> {
+        self.run(|client| client.get_network_info()).await
+    }
+
+    /// Calls `generatetoaddress`.
+    pub async fn generate_to_address(
+        &self,
+        nblocks: usize,
+        address: &Address<NetworkChecked>,
+    ) -> Result<model::GenerateToAddress> {
+        let address = address.clone();
+        self.run(move |client| client.generate_to_address(nblocks, &address)).await
+    }
+
+    /// Calls `getnewaddress`.
+    pub async fn get_new_address(&self) -> Result<model::GetNewAddress> {
+        self.run(|client| client.get_new_address()).await
+    }
+
+    /// Calls `getbalance`.
+    pub async fn get_balance(&self) -> Result<model::GetBalance> {
+        self.run(|client| client.get_balance()).await
+    }
+
+    /// Calls `sendtoaddress`.
+    pub async fn send_to_address(
+        &self,
+        address: &Address<NetworkChecked>,
+        amount: Amount,
+    ) -> Result<model::SendToAddress> {
+        let address = address.clone();
+        self.run(move |client| client.send_to_address(&address, amount)).await
+    }
+
+    /// Calls `gettransaction`.
+    pub async fn get_transaction(&self, txid: Txid) -> Result<model::GetTransaction> {
+        self.run(move |client| client.get_transaction(txid)).await
+    }
+
+    /// Calls `getblockheader` with `verbose` set to false.
+    pub async fn get_block_header(&self, hash: &BlockHash) -> Result<Header> {
+        let hash = *hash;
+        self.run(move |client| client.get_block_header(&hash)).await
+    }
+
+    /// Returns the state of the node, see [`BitcoindRpc::node_state`].
+    pub async fn node_state(&self, sync_threshold: f64) -> Result<NodeState> {
+        self.run(move |client| client.node_state(sync_threshold)).await
+    }
+
+    /// Polls the node until it has finished starting up, see [`BitcoindRpc::wait_for_ready`].
+    ///
+    /// The polling blocks a thread of the blocking thread pool until the node is ready.
+    pub async fn wait_for_ready(
+        &self,
+        sync_threshold: f64,
+        timeout: Duration,
+    ) -> Result<NodeState> {
+        self.run(move |client| client.wait_for_ready(sync_threshold, timeout)).await
+    }
+}
+
+impl<C> Clone for AsyncClientHandle<C> {
+    fn clone(&self) -> Self { Self { client: Arc::clone(&self.client) } }
+}
+
+impl<C> From<C> for AsyncClientHandle<C> {
+    fn from(client: C) -> Self { Self::new(client) }
+}
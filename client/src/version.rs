@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Bitcoin Core release versions.
+//!
+//! Core reports its version as an integer (the `version` field returned by `getnetworkinfo`)
+//! e.g., `170100` for v0.17.1 and `240001` for v24.0.1. [`CoreVersion`] is the parsed form:
+//!
+//! ```
+//! use bitcoind_json_rpc_client::version::CoreVersion;
+//!
+//! let version = CoreVersion::from_server_version(170100);
+//! assert_eq!(version, CoreVersion::new(17, 1, 0));
+//! assert_eq!(version.to_string(), "0.17.1");
+//! assert_eq!(CoreVersion::from_server_version(240001).to_string(), "24.0.1");
+//! ```
+
+use std::fmt;
+
+/// The first major version of Core released without the leading `0.` (v22.0).
+const FIRST_NON_ZERO_MAJOR: u32 = 22;
+
+/// A Bitcoin Core release version.
+///
+/// Before v22 Core versions were of the form `0.<major>.<minor>(.<patch>)`, the leading zero is not
+/// stored i.e., v0.17.1 has a `major` version of 17.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoreVersion {
+    /// The major version e.g., 17 for v0.17.1 and 26 for v26.0.
+    pub major: u32,
+    /// The minor version e.g., 1 for v0.17.1.
+    pub minor: u32,
+    /// The patch (or build) version e.g., 1 for v24.0.1, usually zero.
+    pub patch: u32,
+}
+
+impl CoreVersion {
+    /// Creates a version from its parts.
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        CoreVersion { major, minor, patch }
+    }
+
+    /// Parses the `version` field returned by `getnetworkinfo` e.g., `260000`.
+    pub fn from_server_version(version: usize) -> Self {
+        let version = version as u32;
+        CoreVersion { major: version / 10_000, minor: version / 100 % 100, patch: version % 100 }
+    }
+
+    /// Returns the version in the form of the `version` field returned by `getnetworkinfo`.
+    pub fn to_server_version(self) -> usize {
+        (self.major * 10_000 + self.minor * 100 + self.patch) as usize
+    }
+}
+
+impl fmt::Display for CoreVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.major < FIRST_NON_ZERO_MAJOR {
+            write!(f, "0.")?;
+        }
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.patch != 0 {
+            write!(f, ".{}", self.patch)?;
+        }
+        Ok(())
+    }
+}
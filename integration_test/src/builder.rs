@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing tests of clients created with a `ClientBuilder`.
+
+/// Requires `$version` to be the name of a module in `client::client_sync` e.g., `v17`.
+#[macro_export]
+macro_rules! impl_test_client_builder {
+    ($version:ident) => {
+        #[test]
+        fn client_builder_timeouts_and_retry() {
+            use std::time::Duration;
+
+            use client::client_sync::Auth;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let auth = Auth::CookieFile(bitcoind.params.cookie_file.clone());
+            let client = client::client_sync::$version::Client::builder(&bitcoind.rpc_url())
+                .auth(auth)
+                .connect_timeout(Duration::from_secs(5))
+                .read_timeout(Duration::from_secs(30))
+                .retry(2, Duration::from_millis(100))
+                .build()
+                .expect("build");
+
+            let json = client.get_blockchain_info().expect("getblockchaininfo");
+            assert_eq!(json.into_model().unwrap().chain, client::json::model::Chain::Regtest);
+
+            // RPC errors are returned as such, and are not retried.
+            let err = client
+                .call_raw("getblockhash", &[1_000.into()])
+                .expect_err("block height out of range");
+            assert_eq!(err.rpc_code(), Some(-8));
+        }
+
+        #[test]
+        fn client_builder_socks5_proxy() {
+            use std::io::{Read as _, Write as _};
+            use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+            use std::thread;
+
+            use client::client_sync::Auth;
+
+            // A minimal SOCKS5 proxy that forwards a single connection.
+            let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+            let proxy = listener.local_addr().expect("local_addr");
+            let handle = thread::spawn(move || {
+                let (mut stream, _) = listener.accept().expect("accept");
+                let mut greeting = [0u8; 3];
+                stream.read_exact(&mut greeting).expect("read greeting");
+                assert_eq!(greeting, [5, 1, 0]);
+                stream.write_all(&[5, 0]).expect("write method");
+
+                let mut request = [0u8; 10];
+                stream.read_exact(&mut request).expect("read connect request");
+                assert_eq!(request[..4], [5, 1, 0, 1]);
+                let ip = Ipv4Addr::new(request[4], request[5], request[6], request[7]);
+                let port = u16::from_be_bytes([request[8], request[9]]);
+                let mut node = TcpStream::connect(SocketAddr::from((ip, port))).expect("connect");
+                stream.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0]).expect("write reply");
+
+                let mut from_client = stream.try_clone().expect("try_clone");
+                let mut to_node = node.try_clone().expect("try_clone");
+                let forward = thread::spawn(move || std::io::copy(&mut from_client, &mut to_node));
+                let _ = std::io::copy(&mut node, &mut stream);
+                let _ = forward.join();
+            });
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let auth = Auth::CookieFile(bitcoind.params.cookie_file.clone());
+            let client = client::client_sync::$version::Client::builder(&bitcoind.rpc_url())
+                .auth(auth)
+                .socks5_proxy(proxy)
+                .build()
+                .expect("build");
+
+            let json = client.get_blockchain_info().expect("getblockchaininfo via proxy");
+            assert_eq!(json.into_model().unwrap().chain, client::json::model::Chain::Regtest);
+            handle.join().expect("proxy thread");
+        }
+    };
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing tests of the async JSON-RPC client.
+//!
+//! The async client has the same methods as the sync client, these tests check that requests,
+//! responses and errors make it across the `reqwest` transport rather than retesting every method.
+
+/// Runs `future` to completion on a single threaded `tokio` runtime.
+pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to build tokio runtime")
+        .block_on(future)
+}
+
+/// Requires `$version` to be the name of a module in `client::client_async` e.g., `v17`.
+#[macro_export]
+macro_rules! impl_test_client_async {
+    ($version:ident) => {
+        fn async_client(bitcoind: &bitcoind::BitcoinD) -> client::client_async::$version::Client {
+            use client::client_async::{$version::Client, Auth};
+
+            let url = format!("{}/wallet/default", bitcoind.rpc_url());
+            let auth = Auth::CookieFile(bitcoind.params.cookie_file.clone());
+            Client::new_with_auth(&url, auth)
+                .expect("failed to create async client")
+        }
+
+        #[test]
+        fn async_client_calls() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let client = async_client(&bitcoind);
+
+            $crate::client_async::block_on(async {
+                client.check_expected_server_version().await.expect("unexpected version");
+
+                let address = client.new_address().await.expect("getnewaddress");
+                let json = client.generate_to_address(101, &address).await.expect("generate");
+                assert_eq!(json.0.len(), 101);
+
+                let json = client.get_blockchain_info().await.expect("getblockchaininfo");
+                let model = json.into_model().unwrap();
+                assert_eq!(model.blocks, 101);
+
+                let balance = client.get_balance().await.expect("getbalance");
+                assert_eq!(balance.balance().unwrap(), bitcoin::Amount::from_btc(50.0).unwrap());
+            });
+        }
+
+        #[test]
+        fn async_client_allowed_methods() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let mut client = async_client(&bitcoind);
+            client.set_allowed_methods(["getblockchaininfo"]);
+
+            $crate::client_async::block_on(async {
+                let json = client.get_blockchain_info().await.expect("getblockchaininfo");
+                assert_eq!(json.into_model().unwrap().blocks, 0);
+
+                let err = client.get_balance().await.expect_err("getbalance is not allowed");
+                match err {
+                    client::client_async::Error::MethodNotAllowed(ref method) =>
+                        assert_eq!(method, "getbalance"),
+                    _ => panic!("expected `MethodNotAllowed`, got: {:?}", err),
+                }
+            });
+        }
+
+        #[test]
+        fn async_client_rpc_error() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let client = async_client(&bitcoind);
+
+            let txid = <bitcoin::Txid as bitcoin::hashes::Hash>::all_zeros();
+            let err = $crate::client_async::block_on(client.get_transaction(txid))
+                .expect_err("gettransaction of an unknown transaction");
+            match err {
+                client::client_async::Error::JsonRpc(jsonrpc::error::Error::Rpc(ref e)) =>
+                    assert_eq!(e.code, -5), // RPC_INVALID_ADDRESS_OR_KEY
+                _ => panic!("expected an RPC error, got: {:?}", err),
+            }
+        }
+    };
+}
@@ -0,0 +1,84 @@
+//! Field-by-field comparison of model types.
+//!
+//! The model types are version in-specific but not every field is returned by every version of
+//! Core. Comparing model values with `PartialEq` fails on these version-specific extras and gives
+//! an unreadable error for large types. The functions here compare the serialized form of two
+//! values, skipping fields that are only present (or non-null) on one side, and report every
+//! field that differs along with its path.
+//!
+//! Since the comparison is done on model values, formatting differences in the raw JSON (e.g.
+//! trailing zeros in amounts) do not show up as differences.
+
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// A field that has a different value in the two compared values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difference {
+    /// Path to the field e.g., `details[0].amount`.
+    pub path: String,
+    /// The expected value.
+    pub expected: Value,
+    /// The value we got.
+    pub got: Value,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: expected {} got {}", self.path, self.expected, self.got)
+    }
+}
+
+/// Returns all the fields present in both `expected` and `got` that differ.
+///
+/// Fields that are missing, or `null`, on either side are considered version-specific and skipped.
+///
+/// # Panics
+///
+/// If either value fails to serialize.
+pub fn model_diff<T: Serialize>(expected: &T, got: &T) -> Vec<Difference> {
+    let expected = serde_json::to_value(expected).expect("failed to serialize expected value");
+    let got = serde_json::to_value(got).expect("failed to serialize value");
+
+    let mut diffs = vec![];
+    diff_values(String::new(), &expected, &got, &mut diffs);
+    diffs
+}
+
+/// Asserts that `expected` and `got` are equal, ignoring version-specific fields.
+///
+/// # Panics
+///
+/// With a list of all the differing fields if the values are not equal.
+#[track_caller]
+pub fn assert_model_eq<T: Serialize>(expected: &T, got: &T) {
+    let diffs = model_diff(expected, got);
+    if !diffs.is_empty() {
+        let diffs = diffs.iter().map(|d| format!("  {}", d)).collect::<Vec<_>>().join("\n");
+        panic!("model values differ:\n{}", diffs);
+    }
+}
+
+fn diff_values(path: String, expected: &Value, got: &Value, diffs: &mut Vec<Difference>) {
+    match (expected, got) {
+        (Value::Null, _) | (_, Value::Null) => {}
+        (Value::Object(expected), Value::Object(got)) =>
+            for (key, expected) in expected {
+                if let Some(got) = got.get(key) {
+                    let path =
+                        if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                    diff_values(path, expected, got, diffs);
+                }
+            },
+        (Value::Array(e), Value::Array(g)) if e.len() == g.len() =>
+            for (i, (expected, got)) in e.iter().zip(g).enumerate() {
+                diff_values(format!("{}[{}]", path, i), expected, got, diffs);
+            },
+        _ =>
+            if expected != got {
+                diffs.push(Difference { path, expected: expected.clone(), got: got.clone() });
+            },
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing tests of exporting wallet transactions.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_transactions`
+/// - `get_transaction`
+#[macro_export]
+macro_rules! impl_test_export {
+    () => {
+        #[test]
+        fn export_wallet_transactions() {
+            use client::json::model::export::{self, ExportRow, COLUMNS};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let txid = $crate::send_replaceable(&bitcoind);
+
+            let json = bitcoind.client.list_transactions().expect("listtransactions");
+            let rows = ExportRow::from_list_transactions(&json.into_model().unwrap());
+            assert!(!rows.is_empty());
+
+            let mut csv = Vec::new();
+            export::write_csv(&mut csv, &rows).unwrap();
+            let csv = String::from_utf8(csv).unwrap();
+            let mut lines = csv.lines();
+            assert_eq!(lines.next(), Some(COLUMNS.join(",").as_str()));
+            assert_eq!(lines.count(), rows.len());
+
+            let json = bitcoind.client.get_transaction(txid).expect("gettransaction");
+            let rows = ExportRow::from_get_transaction(&json.into_model().unwrap());
+            let send = rows.iter().find(|row| row.amount.is_negative()).expect("send row");
+            assert!(send.fee.is_some());
+
+            let mut jsonl = Vec::new();
+            export::write_json_lines(&mut jsonl, &rows).unwrap();
+            let jsonl = String::from_utf8(jsonl).unwrap();
+            assert_eq!(jsonl.lines().count(), rows.len());
+            for line in jsonl.lines() {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                assert_eq!(value["txid"], txid.to_string());
+                assert_eq!(value["confirmations"], 0);
+            }
+        }
+    };
+}
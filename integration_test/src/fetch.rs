@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing tests of the parallel block fetcher.
+//!
+//! The fetcher only uses `getblockhash` and `getblock`, which are the same for all versions, so
+//! the tests use the client of the version under test without needing its module name.
+
+/// Requires `bitcoind::Client` to implement `BlockSource` and `BitcoindRpc`.
+#[macro_export]
+macro_rules! impl_test_block_fetcher {
+    () => {
+        fn node_client(bitcoind: &bitcoind::BitcoinD) -> bitcoind::Client {
+            let auth = client::client_sync::Auth::CookieFile(bitcoind.params.cookie_file.clone());
+            bitcoind::Client::new_with_auth(&bitcoind.rpc_url(), auth)
+                .expect("failed to create client")
+        }
+
+        #[test]
+        fn block_fetcher() {
+            use std::sync::atomic::{AtomicBool, Ordering};
+            use std::sync::{Arc, Mutex};
+
+            use bitcoin::Address;
+            use client::client_sync::fetch::{
+                BlockDecoded, BlockFetched, BlockFetcher, FetchHooks, FetchPath, ReorgDetected,
+            };
+
+            // Records the hook calls, and replaces the chain from height 3 once the block at
+            // height 3 has been fetched if `reorg` is set.
+            #[derive(Default)]
+            struct Hooks {
+                fetched: Mutex<Vec<BlockFetched>>,
+                decoded: Mutex<Vec<u64>>,
+                reorgs: Mutex<Vec<ReorgDetected>>,
+                reorg: Option<(bitcoind::Client, Address)>,
+                reorged: AtomicBool,
+            }
+
+            impl FetchHooks for Hooks {
+                fn on_block_fetched(&self, ctx: &BlockFetched) {
+                    self.fetched.lock().unwrap().push(ctx.clone());
+                    if let Some((ref client, ref address)) = self.reorg {
+                        if ctx.height == 3 && !self.reorged.swap(true, Ordering::SeqCst) {
+                            let hash = ctx.hash.to_string().into();
+                            let _: () =
+                                client.call("invalidateblock", &[hash]).expect("invalidate");
+                            let _ = client.generate_to_address(3, address).expect("generate");
+                        }
+                    }
+                }
+
+                fn on_block_decoded(&self, ctx: &BlockDecoded<'_>) {
+                    assert_eq!(ctx.block.block_hash(), ctx.hash);
+                    self.decoded.lock().unwrap().push(ctx.height);
+                }
+
+                fn on_reorg_detected(&self, ctx: &ReorgDetected) {
+                    self.reorgs.lock().unwrap().push(ctx.clone());
+                }
+            }
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(5, &address).expect("generatetoaddress");
+
+            let hooks = Arc::new(Hooks::default());
+            let fetcher = BlockFetcher::new(node_client(&bitcoind))
+                .with_threads(3)
+                .with_hooks(Arc::clone(&hooks));
+            let blocks = fetcher.fetch(0..=5).expect("fetch");
+            assert_eq!(blocks.len(), 6);
+            for (height, fetched) in blocks.iter().enumerate() {
+                assert_eq!(fetched.height, height as u64);
+                let block = bitcoind.client.get_block(&fetched.hash).expect("getblock");
+                assert_eq!(fetched.block, block);
+                assert_eq!(fetched.path, FetchPath::Raw);
+            }
+            let fetched = hooks.fetched.lock().unwrap();
+            assert_eq!(fetched.len(), 6);
+            for ctx in fetched.iter() {
+                assert_eq!(ctx.size, blocks[ctx.height as usize].block.total_size());
+            }
+            assert_eq!(hooks.decoded.lock().unwrap().len(), 6);
+            assert!(hooks.reorgs.lock().unwrap().is_empty());
+
+            // Use a single thread so the reorg happens between fetching heights 3 and 4.
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let hooks = Arc::new(Hooks {
+                reorg: Some((node_client(&bitcoind), address)),
+                ..Hooks::default()
+            });
+            let fetcher = BlockFetcher::new(node_client(&bitcoind))
+                .with_threads(1)
+                .with_hooks(Arc::clone(&hooks));
+            let blocks = fetcher.fetch(1..=5).expect("fetch");
+            assert_eq!(blocks.iter().map(|b| b.height).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+            let reorgs = hooks.reorgs.lock().unwrap();
+            assert_eq!(reorgs.len(), 1);
+            assert_eq!(reorgs[0].height, 4);
+            assert_eq!(reorgs[0].expected_prev, blocks[2].hash);
+            assert_ne!(reorgs[0].actual_prev, blocks[2].hash);
+        }
+
+        #[test]
+        fn block_fetcher_verbosity_downgrade() {
+            use bitcoin::Amount;
+            use client::client_sync::fetch::{BlockFetcher, FetchPath};
+            use client::client_sync::BitcoindRpc;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            // Fill the block at height 102 so it is larger than all the blocks before it.
+            let amount = Amount::from_sat(100_000);
+            for _ in 0..20 {
+                let to = bitcoind.client.new_address().expect("failed to get new address");
+                let _ = BitcoindRpc::send_to_address(&bitcoind.client, &to, amount)
+                    .expect("sendtoaddress");
+            }
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+            let raw = BlockFetcher::new(node_client(&bitcoind)).fetch(0..=102).expect("fetch");
+            let threshold = raw[..102].iter().map(|b| b.block.total_size()).max().unwrap();
+            let big_size = raw[102].block.total_size();
+            assert!(big_size > threshold);
+
+            // The path only depends on the size of each block, whichever thread fetches it.
+            for _ in 0..3 {
+                let fetcher = BlockFetcher::new(node_client(&bitcoind))
+                    .with_threads(4)
+                    .with_verbosity_downgrade(threshold);
+                let blocks = fetcher.fetch(0..=102).expect("fetch");
+                assert_eq!(blocks.len(), 103);
+                for (fetched, raw) in blocks.iter().zip(&raw) {
+                    let expected = match fetched.height {
+                        102 => FetchPath::Raw,
+                        _ => FetchPath::Verbose,
+                    };
+                    assert_eq!(fetched.path, expected);
+                    assert_eq!(fetched.block, raw.block);
+                }
+
+                let metrics = fetcher.metrics();
+                assert_eq!(metrics.verbose_blocks, 102);
+                assert_eq!(metrics.raw_blocks, 1);
+                assert_eq!(metrics.raw_bytes, big_size as u64);
+            }
+
+            // Every block is larger than the threshold.
+            let fetcher = BlockFetcher::new(node_client(&bitcoind))
+                .with_threads(4)
+                .with_verbosity_downgrade(1);
+            assert_eq!(fetcher.fetch(0..=102).expect("fetch"), raw);
+            assert_eq!(fetcher.metrics().verbose_blocks, 0);
+
+            let fetcher = BlockFetcher::new(node_client(&bitcoind)).with_verbose_blocks();
+            let blocks = fetcher.fetch(0..=102).expect("fetch");
+            assert!(blocks.iter().all(|b| b.path == FetchPath::Verbose));
+            assert!(blocks.iter().zip(&raw).all(|(verbose, raw)| verbose.block == raw.block));
+            assert_eq!(fetcher.metrics().raw_blocks, 0);
+        }
+    };
+}
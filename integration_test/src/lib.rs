@@ -1,8 +1,23 @@
 //! Provides a macro that implements the tests.
 
+pub mod builder;
+pub mod client_async;
+pub mod compare;
+pub mod fetch;
+pub mod export;
+pub mod profile;
+pub mod tokio_bridge;
 pub mod v17;
+pub mod v18;
 pub mod v19;
+pub mod v20;
+pub mod v21;
 pub mod v22;
+pub mod v23;
+pub mod v24;
+pub mod v25;
+pub mod v26;
+pub mod v28;
 
 /// Requires `RPC_PORT` to be in scope.
 use bitcoind::BitcoinD;
@@ -22,6 +37,32 @@ pub fn bitcoind_with_default_wallet() -> BitcoinD {
     BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
 }
 
+/// Returns a handle to a `bitcoind` instance with "default" wallet loaded and `args` appended to
+/// the default arguments.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn bitcoind_with_args(args: &[&str]) -> BitcoinD {
+    init_logger();
+
+    let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+
+    let mut conf = bitcoind::Conf::default();
+    conf.args.extend_from_slice(args);
+    BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
+}
+
+/// Returns a handle to a `bitcoind` instance with "default" wallet loaded and the `deprecated`
+/// RPC behaviors re-enabled (see `-deprecatedrpc`).
+#[allow(dead_code)] // Not all tests use this function.
+pub fn bitcoind_with_deprecated_rpc(deprecated: &[&str]) -> BitcoinD {
+    init_logger();
+
+    let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+
+    let mut conf = bitcoind::Conf::default();
+    conf.deprecated_rpc.extend_from_slice(deprecated);
+    BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
+}
+
 /// Returns a handle to a `bitcoind` instance without any wallets.
 #[allow(dead_code)] // Not all tests use this function.
 pub fn bitcoind_with_wallet(wallet: String) -> BitcoinD {
@@ -32,6 +73,19 @@ pub fn bitcoind_with_wallet(wallet: String) -> BitcoinD {
     BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
 }
 
+/// Returns a handle to a `bitcoind` instance on a private signet with "default" wallet loaded.
+///
+/// The signet's block challenge is `OP_TRUE`, see `bitcoind::SIGNET_OP_TRUE_CHALLENGE_ARG`.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn bitcoind_signet() -> BitcoinD {
+    init_logger();
+
+    let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+
+    let conf = bitcoind::Conf::signet();
+    BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
+}
+
 /// Returns a handle to a `bitcoind` instance without any wallet loaded.
 #[allow(dead_code)] // Not all tests use this function.
 pub fn bitcoind_no_wallet() -> BitcoinD {
@@ -41,3 +95,139 @@ pub fn bitcoind_no_wallet() -> BitcoinD {
     conf.wallet = None;
     BitcoinD::with_conf(exe, &conf).expect("failed to create BitcoinD")
 }
+
+/// Returns handles to two connected `bitcoind` instances, the second connects to the first.
+///
+/// The first node is started with the `deprecated` RPC behaviors re-enabled (see
+/// `-deprecatedrpc`). Waits until the first node reports the connection.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn bitcoind_with_peer(deprecated: &[&str]) -> (BitcoinD, BitcoinD) {
+    init_logger();
+
+    let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+
+    let mut conf = bitcoind::Conf::default();
+    conf.p2p = bitcoind::P2P::Yes;
+    conf.deprecated_rpc.extend_from_slice(deprecated);
+    let node = BitcoinD::with_conf(&exe, &conf).expect("failed to create BitcoinD");
+
+    let mut other_conf = bitcoind::Conf::default();
+    other_conf.p2p = node.p2p_connect(false).expect("node has a p2p port");
+    let other = BitcoinD::with_conf(&exe, &other_conf).expect("failed to create BitcoinD");
+
+    for _ in 0..100 {
+        let peers: Vec<serde_json::Value> =
+            node.client.call("getpeerinfo", &[]).expect("getpeerinfo");
+        if !peers.is_empty() {
+            return (node, other);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    panic!("timed out waiting for the nodes to connect");
+}
+
+/// Creates a legacy (non-descriptor) wallet named `name` and returns a client for it.
+///
+/// From v26 creating a legacy wallet requires the node to be started with
+/// `-deprecatedrpc=create_bdb`, see [`bitcoind_with_deprecated_rpc`].
+#[allow(dead_code)] // Not all tests use this function.
+pub fn legacy_wallet(bitcoind: &BitcoinD, name: &str) -> bitcoind::Client {
+    let version = bitcoind.client.server_version().expect("server_version");
+    let mut args = vec![name.into()];
+    if version >= 210000 {
+        // disable_private_keys, blank, passphrase, avoid_reuse, descriptors
+        args.extend(vec![false.into(), false.into(), "".into(), false.into(), false.into()]);
+    }
+    let _: serde_json::Value = bitcoind.client.call("createwallet", &args).expect("createwallet");
+    wallet_client(bitcoind, name)
+}
+
+/// Returns a client for the already loaded wallet `name`.
+///
+/// Required to call wallet methods once more than one wallet is loaded.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn wallet_client(bitcoind: &BitcoinD, name: &str) -> bitcoind::Client {
+    use client::client_sync::Auth;
+
+    let url = format!("{}/wallet/{}", bitcoind.rpc_url(), name);
+    let auth = Auth::CookieFile(bitcoind.params.cookie_file.clone());
+    bitcoind::Client::new_with_auth(&url, auth).expect("failed to create wallet client")
+}
+
+/// Funds the wallet and sends a BIP-125 replaceable transaction paying to a new wallet address.
+///
+/// Returns the txid of the replaceable transaction.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn send_replaceable(bitcoind: &BitcoinD) -> bitcoin::Txid {
+    let address = bitcoind.client.new_address().expect("failed to create new address");
+    let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+    let recipient = bitcoind.client.new_address().expect("failed to create new address");
+    // sendtoaddress "address" amount "comment" "comment_to" subtractfeefromamount replaceable
+    let txid: String = bitcoind
+        .client
+        .call(
+            "sendtoaddress",
+            &[
+                recipient.to_string().into(),
+                1.0.into(),
+                "".into(),
+                "".into(),
+                false.into(),
+                true.into(),
+            ],
+        )
+        .expect("sendtoaddress");
+    txid.parse().expect("valid txid")
+}
+
+/// Funds the wallet and creates an unsigned PSBT paying 1 BTC to a new wallet address.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn create_funded_psbt(bitcoind: &BitcoinD) -> bitcoin::Psbt {
+    use client::client_sync::v17::CreateRawTransactionOutput;
+
+    let address = bitcoind.client.new_address().expect("failed to create new address");
+    let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+    let recipient = bitcoind.client.new_address().expect("failed to create new address");
+    let output = CreateRawTransactionOutput::Address(recipient, bitcoin::Amount::ONE_BTC);
+    let json = bitcoind
+        .client
+        .wallet_create_funded_psbt(&[], &[output])
+        .expect("walletcreatefundedpsbt");
+    json.into_model().unwrap().psbt
+}
+
+/// Funds the wallet and uses `createrawtransaction` to create an unsigned transaction spending a
+/// mature coinbase output to a new wallet address.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn create_unsigned_transaction(bitcoind: &BitcoinD) -> bitcoin::Transaction {
+    use client::client_sync::v17::{CreateRawTransactionInput, CreateRawTransactionOutput};
+
+    let address = bitcoind.client.new_address().expect("failed to create new address");
+    let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+    let json = bitcoind.client.list_unspent().expect("listunspent");
+    let utxo = json.into_model().unwrap().0.remove(0);
+    let input = CreateRawTransactionInput::from(utxo.outpoint());
+    let to = bitcoind.client.new_address().expect("failed to create new address");
+    let amount = utxo.amount - bitcoin::Amount::from_sat(10_000);
+    let output = CreateRawTransactionOutput::Address(to, amount);
+
+    let json = bitcoind
+        .client
+        .create_raw_transaction(&[input], &[output])
+        .expect("createrawtransaction");
+    json.transaction().unwrap()
+}
+
+/// Funds the wallet and creates a signed, unbroadcast transaction paying 1 BTC to a new wallet
+/// address.
+#[allow(dead_code)] // Not all tests use this function.
+pub fn create_signed_transaction(bitcoind: &BitcoinD) -> bitcoin::Transaction {
+    let psbt = create_funded_psbt(bitcoind);
+    let json = bitcoind.client.wallet_process_psbt(&psbt).expect("walletprocesspsbt");
+    let processed = json.into_model().unwrap().psbt;
+    let json = bitcoind.client.finalize_psbt(&processed).expect("finalizepsbt");
+    json.into_model().unwrap().tx.expect("complete transaction")
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing tests of creating clients from connection profiles.
+
+/// Requires `$version` to be the name of a module in `client::client_sync` e.g., `v17`.
+#[macro_export]
+macro_rules! impl_test_client_profile {
+    ($version:ident) => {
+        #[test]
+        fn client_from_profile_file() {
+            use std::io::Write as _;
+
+            use client::client_sync::profile::ClientProfile;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let toml = format!(
+                "default = \"regtest\"\n\n\
+                 [profiles.regtest]\n\
+                 url = \"{}\"\n\
+                 cookie_file = '{}'\n\
+                 wallet = \"default\"\n\
+                 network = \"regtest\"\n\
+                 timeout_secs = 30\n",
+                bitcoind.rpc_url(),
+                bitcoind.params.cookie_file.display(),
+            );
+            let mut file = bitcoind::tempfile::NamedTempFile::new().expect("failed to create file");
+            file.write_all(toml.as_bytes()).expect("failed to write profile file");
+
+            let profile = ClientProfile::from_file(file.path(), "regtest").expect("from_file");
+            assert_eq!(profile.network, Some(bitcoin::Network::Regtest));
+
+            let client = client::client_sync::$version::Client::from_profile(&profile)
+                .expect("from_profile");
+            let json = client.get_blockchain_info().expect("getblockchaininfo");
+            assert_eq!(json.into_model().unwrap().chain, client::json::model::Chain::Regtest);
+            // The profile's wallet is used for wallet RPCs.
+            let _ = client.new_address().expect("getnewaddress");
+
+            let async_client = client::client_async::$version::Client::from_profile(&profile)
+                .expect("async from_profile");
+            let balance = $crate::client_async::block_on(async_client.get_balance())
+                .expect("async getbalance");
+            assert_eq!(balance.balance().unwrap(), bitcoin::Amount::ZERO);
+        }
+    };
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing tests of the `tokio` bridge around the sync JSON-RPC client.
+//!
+//! The bridge only moves calls onto `tokio`'s blocking thread pool, these tests check that results,
+//! panics and cancellation make it back to the caller rather than retesting every method.
+
+/// Requires `$version` to be the name of a module in `client::client_sync` e.g., `v17`.
+#[macro_export]
+macro_rules! impl_test_tokio_bridge {
+    ($version:ident) => {
+        fn bridged_client(
+            bitcoind: &bitcoind::BitcoinD,
+        ) -> client::tokio_bridge::AsyncClientHandle<client::client_sync::$version::Client> {
+            use client::client_sync::{$version::Client, Auth};
+
+            let auth = Auth::CookieFile(bitcoind.params.cookie_file.clone());
+            let client = Client::new_with_auth(&bitcoind.rpc_url(), auth)
+                .expect("failed to create client");
+            client::tokio_bridge::AsyncClientHandle::new(client)
+        }
+
+        #[tokio::test]
+        async fn tokio_bridge_calls() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let handle = bridged_client(&bitcoind);
+
+            let model = handle.get_blockchain_info().await.expect("getblockchaininfo");
+            assert_eq!(model.blocks, 0);
+
+            // Version specific methods are called using `run`, clones share the client.
+            let best = handle.get_best_block_hash().await.expect("getbestblockhash").0;
+            let block = handle.clone().run(move |c| c.get_block(&best)).await.expect("getblock");
+            assert_eq!(block.block_hash(), best);
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "panic in blocking task")]
+        async fn tokio_bridge_run_panics() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let handle = bridged_client(&bitcoind);
+
+            // The panic is resumed on the calling task.
+            let _ = handle
+                .run(|_| -> client::client_sync::Result<()> { panic!("panic in blocking task") })
+                .await;
+        }
+
+        #[test]
+        fn tokio_bridge_run_cancelled() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let handle = bridged_client(&bitcoind);
+
+            // Blocking tasks spawned once the runtime is shutting down are cancelled, not run.
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .build()
+                .expect("failed to build tokio runtime");
+            let rt = runtime.handle().clone();
+            runtime.shutdown_background();
+
+            let res = rt.block_on(handle.run(|c| c.get_best_block_hash()));
+            let err = res.expect_err("call cancelled by runtime shutdown");
+            match err {
+                client::client_sync::Error::Io(ref e) =>
+                    assert_eq!(e.kind(), std::io::ErrorKind::Interrupted),
+                _ => panic!("expected an `Io` error, got: {:?}", err),
+            }
+        }
+    };
+}
@@ -11,9 +11,26 @@ macro_rules! impl_test_v17__getblockchaininfo {
     () => {
         #[test]
         fn get_blockchain_info() {
+            use client::json::model;
+
             let bitcoind = $crate::bitcoind_no_wallet();
             let json = bitcoind.client.get_blockchain_info().expect("getblockchaininfo");
-            assert!(json.into_model().is_ok());
+            let model = json.into_model().unwrap();
+            assert!(model.to_string().starts_with("chain: regtest\n"));
+            assert_eq!(model.chain.network(), Some(bitcoin::Network::Regtest));
+
+            // A fresh regtest node only has the genesis block and is still in IBD.
+            let ibd = model.ibd_state();
+            assert_eq!(ibd.blocks, 0);
+            assert!(ibd.initial_block_download);
+            assert!(!ibd.is_synced(0.0));
+
+            // v0.17 and v0.18 report buried softforks in a list and BIP-9 ones in a map.
+            let bip34 = model.softforks.get("bip34").expect("bip34");
+            assert_eq!(bip34.type_, model::SoftforkType::Buried);
+            let segwit = model.softforks.get("segwit").expect("segwit");
+            assert_eq!(segwit.type_, model::SoftforkType::Bip9);
+            assert!(segwit.bip9.is_some());
         }
     };
 }
@@ -63,10 +80,168 @@ macro_rules! impl_test_v17__getblock_verbosity_1 {
             let json = bitcoind.client.get_block_verbosity_one(&block_hash).expect("getblock 1");
             json.into_model().unwrap();
         }
+
+        #[test]
+        fn get_block_verbosity_1_header() {
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let block_hash = best_block_hash();
+
+            let json = bitcoind.client.get_block_verbosity_one(&block_hash).expect("getblock 1");
+            let model = json.into_model().unwrap();
+
+            let header = model::GetBlockHeaderVerbose::from(&model);
+            assert_eq!(header.hash, block_hash);
+            assert_eq!(header.n_tx, model.n_tx);
+            let header = bitcoin::block::Header::try_from(&header).expect("header");
+            assert_eq!(header.block_hash(), block_hash);
+
+            let sizes = model.size_breakdown();
+            assert_eq!(sizes.size, sizes.stripped_size + sizes.witness_size);
+            assert_eq!(sizes.weight.to_wu() as usize, sizes.stripped_size * 3 + sizes.size);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_block_header`
+/// - `get_block_header_verbose`
+#[macro_export]
+macro_rules! impl_test_v17__getblockheader {
+    () => {
+        #[test]
+        fn get_block_header() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let block_hash = best_block_hash();
+
+            let json = bitcoind.client.get_block_header(&block_hash).expect("getblockheader");
+            let header = json.block_header().unwrap();
+            assert_eq!(header.block_hash(), block_hash);
+
+            let json = bitcoind
+                .client
+                .get_block_header_verbose(&block_hash)
+                .expect("getblockheader verbose");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.hash, block_hash);
+            assert_eq!(model.confirmations, 1);
+            assert_eq!(model.bits, header.bits);
+            assert!(model.median_time.is_some());
+            assert!(model.next_block_hash.is_none());
+            assert_eq!(bitcoin::block::Header::try_from(&model).expect("header"), header);
+        }
     };
 }
 
-/// Requires `Client` to be in scope and to implement `get_block 2`.
+/// Requires `Client` to be in scope and to implement:
+/// - `get_block_hash`
+/// - `get_chain_tips`
+/// - `detect_reorg`
+/// - `invalidate_block`
+/// - `reconsider_block`
+#[macro_export]
+macro_rules! impl_test_v17__getchaintips {
+    () => {
+        #[test]
+        fn get_chain_tips() {
+            use client::json::model::ChainTipsStatus;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let json = bitcoind.client.generate_to_address(3, &address).expect("generatetoaddress");
+            let stale = json.into_model().unwrap().0;
+            assert!(bitcoind.client.detect_reorg(10).expect("detect_reorg").is_none());
+
+            // Replace the last two blocks, then make the stale branch valid again.
+            bitcoind.client.invalidate_block(&stale[1]).expect("invalidateblock");
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(3, &address).expect("generatetoaddress");
+            bitcoind.client.reconsider_block(&stale[1]).expect("reconsiderblock");
+
+            let json = bitcoind.client.get_chain_tips().expect("getchaintips");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 2);
+            assert_eq!(model.active().expect("active tip").height, 4);
+            let tip = model.0.iter().find(|tip| tip.hash == stale[2]).expect("stale tip");
+            assert_eq!(tip.status, ChainTipsStatus::ValidFork);
+            assert_eq!(tip.branch_length, 2);
+
+            let fork = bitcoind.client.detect_reorg(3).expect("detect_reorg").expect("reorg");
+            let json = bitcoind.client.get_block_hash(1).expect("getblockhash");
+            assert_eq!(fork.fork_point, json.block_hash().unwrap());
+            assert_eq!(fork.fork_height, 1);
+            assert_eq!(fork.stale_tip, stale[2]);
+            assert_eq!(fork.stale_blocks, 2);
+            // The fork is deeper than the last two blocks.
+            assert!(bitcoind.client.detect_reorg(2).expect("detect_reorg").is_none());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `best_block_hash`
+/// - `invalidate_block`
+/// - `reconsider_block`
+#[macro_export]
+macro_rules! impl_test_v17__invalidateblock {
+    () => {
+        #[test]
+        fn invalidate_block() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let parent = bitcoind.client.best_block_hash().expect("getbestblockhash");
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let json = bitcoind.client.generate_to_address(2, &address).expect("generatetoaddress");
+            let mined = json.into_model().unwrap().0;
+
+            // Invalidating a block disconnects it and all its descendants.
+            bitcoind.client.invalidate_block(&mined[0]).expect("invalidateblock");
+            assert_eq!(bitcoind.client.best_block_hash().expect("getbestblockhash"), parent);
+
+            bitcoind.client.reconsider_block(&mined[0]).expect("reconsiderblock");
+            assert_eq!(bitcoind.client.best_block_hash().expect("getbestblockhash"), mined[1]);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `best_block_hash`
+/// - `invalidate_block`
+/// - `reconsider_block`
+/// - `precious_block`
+#[macro_export]
+macro_rules! impl_test_v17__preciousblock {
+    () => {
+        #[test]
+        fn precious_block() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+
+            // Create two competing tips with the same work.
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let json = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let a = json.into_model().unwrap().0[0];
+            bitcoind.client.invalidate_block(&a).expect("invalidateblock");
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let json = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let b = json.into_model().unwrap().0[0];
+            assert_ne!(a, b);
+            bitcoind.client.reconsider_block(&a).expect("reconsiderblock");
+
+            // Either tip may be active, switch to the other one and back again.
+            let active = bitcoind.client.best_block_hash().expect("getbestblockhash");
+            let other = if active == a { b } else { a };
+            bitcoind.client.precious_block(&other).expect("preciousblock");
+            assert_eq!(bitcoind.client.best_block_hash().expect("getbestblockhash"), other);
+            bitcoind.client.precious_block(&active).expect("preciousblock");
+            assert_eq!(bitcoind.client.best_block_hash().expect("getbestblockhash"), active);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_block`
+/// - `get_block_verbosity_two`
+/// - `get_block_verbose`
 #[macro_export]
 macro_rules! impl_test_v17__getblock_verbosity_2 {
     () => {
@@ -74,18 +249,433 @@ macro_rules! impl_test_v17__getblock_verbosity_2 {
         fn get_block_verbosity_2() {
             let bitcoind = $crate::bitcoind_no_wallet();
             let block_hash = best_block_hash();
+            let block = bitcoind.client.get_block(&block_hash).expect("getblock");
 
-            let json = client.get_block_verbosity_two(&block_hash).expect("getblock 2");
-            json.into_model().unwrap();
+            let json = bitcoind.client.get_block_verbosity_two(&block_hash).expect("getblock 2");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.hash, block_hash);
+            assert_eq!(model.tx, block.txdata);
+
+            let model = bitcoind.client.get_block_verbose(&block_hash).expect("getblock 2");
+            assert_eq!(model.n_tx as usize, block.txdata.len());
         }
     };
 }
 
-/// Requires `Client` to be in scope and to implement `get_tx_out`.
+/// Requires `Client` to be in scope and to implement:
+/// - `get_tx_out`
+/// - `try_get_tx_out`
 #[macro_export]
 macro_rules! impl_test_v17__gettxout {
     () => {
         #[test]
-        fn get_tx_out() { todo!() }
+        fn get_tx_out() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let amount = Amount::from_sat(1_000_000);
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, amount)
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+            let tx = bitcoind.client.get_transaction(txid).expect("gettransaction");
+            let tx = tx.into_model().unwrap().tx;
+            let vout = tx
+                .output
+                .iter()
+                .position(|out| out.script_pubkey == address.script_pubkey())
+                .expect("no output to address") as u64;
+
+            let json = bitcoind.client.get_tx_out(txid, vout).expect("gettxout");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.tx_out.value, amount);
+            assert_eq!(model.tx_out.script_pubkey, address.script_pubkey());
+            assert_eq!(model.address.assume_checked(), address);
+            assert_eq!(model.confirmations, 1);
+            assert!(!model.coinbase);
+
+            // The outputs spent by the transaction are no longer in the UTXO set.
+            let spent = tx.input[0].previous_output;
+            let json = bitcoind
+                .client
+                .try_get_tx_out(spent.txid, spent.vout.into(), true)
+                .expect("gettxout");
+            assert!(json.is_none());
+
+            let out_of_range = tx.output.len() as u64;
+            let json = bitcoind.client.try_get_tx_out(txid, out_of_range, true).expect("gettxout");
+            assert!(json.is_none());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_tx_out_proof`
+/// - `get_tx_out_proof_in_block`
+/// - `verify_tx_out_proof`
+#[macro_export]
+macro_rules! impl_test_v17__gettxoutproof {
+    () => {
+        #[test]
+        fn get_tx_out_proof() {
+            use bitcoin::Amount;
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(1_000_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let json = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let block_hash = json.into_model().unwrap().0[0];
+
+            // The transaction has unspent outputs so the proof is found without `-txindex`.
+            let json = bitcoind.client.get_tx_out_proof(&[txid]).expect("gettxoutproof");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.header.block_hash(), block_hash);
+            assert_eq!(model.matched_txids(), Some(vec![txid]));
+
+            let s = serde_json::to_string(&model).unwrap();
+            assert_eq!(serde_json::from_str::<model::GetTxOutProof>(&s).unwrap(), model);
+
+            let json = bitcoind
+                .client
+                .get_tx_out_proof_in_block(&[txid], &block_hash)
+                .expect("gettxoutproof");
+            let proof = json.merkle_block().unwrap();
+            assert_eq!(proof, model.0);
+
+            let json = bitcoind.client.verify_tx_out_proof(&proof).expect("verifytxoutproof");
+            assert_eq!(json.into_model().unwrap().0, vec![txid]);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `tx_fee`.
+#[macro_export]
+macro_rules! impl_test_v17__txfee {
+    () => {
+        #[test]
+        fn tx_fee() {
+            use bitcoin::Amount;
+            use client::client_sync::TxFeeError;
+            use client::client_sync::Error;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let amount = Amount::from_sat(1_000_000);
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, amount)
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let model = bitcoind.client.get_transaction(txid).unwrap().into_model().unwrap();
+            let fee = model.fee.expect("send has a fee").unsigned_abs();
+
+            // The previous outputs are spent in the mempool but are still in the UTXO set.
+            assert_eq!(bitcoind.client.tx_fee(&model.tx).expect("tx_fee"), fee);
+
+            // Once mined the previous outputs are spent, without `-txindex` they are unknown.
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let outpoints =
+                model.tx.input.iter().map(|txin| txin.previous_output).collect::<Vec<_>>();
+            match bitcoind.client.tx_fee(&model.tx) {
+                Err(Error::TxFee(TxFeeError::UnknownPrevOuts(unknown))) =>
+                    assert_eq!(unknown, outpoints),
+                res => panic!("unexpected result: {:?}", res),
+            }
+
+            // Only the unknown previous outputs are reported, a mempool transaction is still found.
+            let mempool_txid = bitcoind
+                .client
+                .send_to_address(&address, amount)
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let found = bitcoin::OutPoint { txid: mempool_txid, vout: 0 };
+            let mixed = bitcoin::Transaction {
+                version: bitcoin::transaction::Version::TWO,
+                lock_time: bitcoin::absolute::LockTime::ZERO,
+                input: [outpoints[0], found]
+                    .iter()
+                    .map(|&previous_output| bitcoin::TxIn { previous_output, ..Default::default() })
+                    .collect(),
+                output: vec![],
+            };
+            match bitcoind.client.tx_fee(&mixed) {
+                Err(Error::TxFee(TxFeeError::UnknownPrevOuts(unknown))) =>
+                    assert_eq!(unknown, vec![outpoints[0]]),
+                res => panic!("unexpected result: {:?}", res),
+            }
+        }
+
+        #[test]
+        fn tx_fee_txindex() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_args(&["-txindex"]);
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(1_000_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let model = bitcoind.client.get_transaction(txid).unwrap().into_model().unwrap();
+            let fee = model.fee.expect("send has a fee").unsigned_abs();
+
+            // Spent previous outputs are found using the transaction index.
+            assert_eq!(bitcoind.client.tx_fee(&model.tx).expect("tx_fee"), fee);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `verify_chain`
+/// - `verify_chain_with_heartbeat`
+#[macro_export]
+macro_rules! impl_test_v17__verifychain {
+    () => {
+        #[test]
+        fn verify_chain() {
+            use std::time::Duration;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            assert!(bitcoind.client.verify_chain().expect("verifychain"));
+
+            let mut heartbeats = vec![];
+            let verified = bitcoind
+                .client
+                .verify_chain_with_heartbeat(
+                    4,
+                    0,
+                    Duration::from_secs(600),
+                    Duration::from_millis(100),
+                    |heartbeat| heartbeats.push(heartbeat.clone()),
+                )
+                .expect("verifychain");
+            assert!(verified);
+            assert!(heartbeats.windows(2).all(|w| w[0].elapsed <= w[1].elapsed));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_block_verbose`
+/// - `get_transaction`
+#[macro_export]
+macro_rules! impl_test_v17__getblock_fee_summary {
+    () => {
+        #[test]
+        fn get_block_fee_summary() {
+            use bitcoin::{Amount, Network};
+            use client::json::model::analytics::BlockFeeSummary;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let _ = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_btc(1.0).unwrap())
+                .expect("sendtoaddress");
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+            let hash = bitcoind.client.best_block_hash().expect("best_block_hash");
+            let block = bitcoind.client.get_block_verbose(&hash).expect("getblock 2");
+
+            let summary = block.fee_summary(Network::Regtest);
+            assert_eq!(summary.height, 102);
+            assert_eq!(summary.subsidy, Amount::from_btc(50.0).unwrap());
+            assert_eq!(summary.tx_count, 1);
+            assert!(summary.total_fee > Amount::ZERO);
+            assert!(summary.fee_rate_percentiles.is_none());
+
+            let prevout = |outpoint: &bitcoin::OutPoint| {
+                let json = bitcoind.client.get_transaction(outpoint.txid).ok()?;
+                let tx = json.into_model().ok()?.tx;
+                tx.output.get(outpoint.vout as usize).cloned()
+            };
+            let with_prevouts = BlockFeeSummary::with_prevouts(&block, Network::Regtest, prevout)
+                .expect("prevouts");
+            assert_eq!(with_prevouts.total_fee, summary.total_fee);
+            let percentiles = with_prevouts.fee_rate_percentiles.expect("percentiles");
+            assert_eq!(percentiles.p10, percentiles.p90);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_tx_out_set_info`.
+#[macro_export]
+macro_rules! impl_test_v17__gettxoutsetinfo {
+    () => {
+        #[test]
+        fn get_tx_out_set_info() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.get_tx_out_set_info().expect("gettxoutsetinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.height, 10);
+            // The genesis block output is not in the UTXO set.
+            assert_eq!(model.total_amount, bitcoin::Amount::from_btc(500.0).unwrap());
+            assert!(model.hash_serialized.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `scan_tx_out_set`
+/// - `scan_tx_out_set_status`
+/// - `scan_tx_out_set_abort`
+#[macro_export]
+macro_rules! impl_test_v17__scantxoutset {
+    () => {
+        #[test]
+        fn scan_tx_out_set() {
+            use client::client_sync::v17::ScanObject;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            let scan_object = ScanObject::new(format!("addr({})", address));
+            let json = bitcoind.client.scan_tx_out_set(&[scan_object]).expect("scantxoutset");
+            let model = json.into_model().unwrap();
+            assert!(model.success);
+            assert!(model.txouts >= 10);
+            assert!(model.height.map_or(true, |height| height == 10));
+            assert_eq!(model.unspents.len(), 10);
+            assert!(model.unspents.iter().all(|u| u.script_pubkey == address.script_pubkey()));
+            assert_eq!(model.total_amount, bitcoin::Amount::from_btc(500.0).unwrap());
+
+            assert!(bitcoind.client.scan_tx_out_set_status().expect("scantxoutset").is_none());
+            assert!(!bitcoind.client.scan_tx_out_set_abort().expect("scantxoutset"));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_raw_mempool`
+/// - `get_raw_mempool_verbose`
+/// - `get_mempool_entry`
+#[macro_export]
+macro_rules! impl_test_v17__getrawmempool {
+    () => {
+        #[test]
+        fn get_raw_mempool() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.get_raw_mempool().expect("getrawmempool");
+            assert!(json.into_model().unwrap().0.is_empty());
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let json = bitcoind.client.get_raw_mempool().expect("getrawmempool");
+            assert_eq!(json.into_model().unwrap().0, vec![txid]);
+
+            let json = bitcoind.client.get_raw_mempool_verbose().expect("getrawmempool verbose");
+            let model = json.into_model().unwrap();
+            let entry = model.0.get(&txid).expect("mempool entry");
+
+            let json = bitcoind.client.get_mempool_entry(txid).expect("getmempoolentry");
+            let model = json.into_model().unwrap();
+            assert_eq!(&model.0, entry);
+            assert_eq!(model.0.ancestor_count, 1);
+            assert!(model.0.fees.base > Amount::ZERO);
+            assert!(model.0.fee_rate().is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_mempool_info`.
+#[macro_export]
+macro_rules! impl_test_v17__getmempoolinfo {
+    () => {
+        #[test]
+        fn get_mempool_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_mempool_info().expect("getmempoolinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.size, 0);
+            assert!(model.max_mempool > 0);
+            assert!(model.min_relay_tx_fee > bitcoin::FeeRate::ZERO);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `mempool_candidates`
+/// - `mempool_candidate`
+#[macro_export]
+macro_rules! impl_test_v17__mempool_candidates {
+    () => {
+        #[test]
+        fn mempool_candidates() {
+            use std::time::Duration;
+
+            use bitcoin::FeeRate;
+            use client::client_sync::v17::MempoolCandidateOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let txid = $crate::send_replaceable(&bitcoind);
+
+            // The transaction was accepted, so it pays at least `mempoolminfee`.
+            let options = MempoolCandidateOptions {
+                fee_rate_margin: FeeRate::ZERO,
+                ..MempoolCandidateOptions::new()
+            };
+            let candidate =
+                bitcoind.client.mempool_candidate(txid, &options).expect("mempool_candidate");
+            assert!(!candidate.is_at_risk());
+            assert_eq!(candidate.eviction_rank, None);
+            assert!(candidate.expires_in > Duration::from_secs(13 * 24 * 60 * 60));
+            let candidates = bitcoind.client.mempool_candidates(&options).expect("candidates");
+            assert!(candidates.is_empty());
+
+            let options = MempoolCandidateOptions {
+                fee_rate_margin: FeeRate::from_sat_per_vb_u32(1_000),
+                expiry: Duration::from_secs(60 * 60),
+                expiry_margin: Duration::from_secs(2 * 60 * 60),
+            };
+            let candidates = bitcoind.client.mempool_candidates(&options).expect("candidates");
+            assert_eq!(candidates.len(), 1);
+            let candidate = &candidates[0];
+            assert_eq!(candidate.txid, txid);
+            assert_eq!(candidate.eviction_rank, Some(0));
+            assert!(candidate.near_eviction);
+            assert!(candidate.near_expiry);
+            assert!(candidate.descendant_score >= candidate.mempool_min_fee);
+        }
     };
 }
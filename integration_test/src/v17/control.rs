@@ -5,6 +5,55 @@
 //! Specifically this is methods found under the `== Control ==` section of the
 //! API docs of `bitcoind v0.17.1`.
 
+/// Requires `Client` to be in scope and to implement:
+/// - `get_memory_info`
+/// - `get_memory_info_malloc_info`
+#[macro_export]
+macro_rules! impl_test_v17__getmemoryinfo {
+    () => {
+        #[test]
+        fn get_memory_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+
+            let json = bitcoind.client.get_memory_info().expect("getmemoryinfo");
+            let model = json.into_model();
+            assert!(model.locked.used <= model.locked.total);
+
+            // The release binaries are built with glibc.
+            if cfg!(target_os = "linux") {
+                let json = bitcoind.client.get_memory_info_malloc_info().expect("getmemoryinfo");
+                let model = json.into_model();
+                assert!(model.as_str().starts_with("<malloc"));
+            }
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_logging`
+/// - `logging`
+#[macro_export]
+macro_rules! impl_test_v17__logging {
+    () => {
+        #[test]
+        fn logging() {
+            use client::json::model::LogCategory;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+
+            let model = bitcoind.client.get_logging().expect("logging").into_model();
+            assert!(model.is_active(&LogCategory::Rpc).is_some());
+            assert!(model.0.keys().all(|category| !matches!(category, LogCategory::All)));
+
+            let json = bitcoind.client.logging(&[LogCategory::Mempool], &[]).expect("logging");
+            assert_eq!(json.into_model().is_active(&LogCategory::Mempool), Some(true));
+
+            let json = bitcoind.client.logging(&[], &[LogCategory::Mempool]).expect("logging");
+            assert_eq!(json.into_model().is_active(&LogCategory::Mempool), Some(false));
+        }
+    };
+}
+
 /// Requires `Client` to be in scope and to implement `stop`.
 #[macro_export]
 macro_rules! impl_test_v17__stop {
@@ -17,3 +66,24 @@ macro_rules! impl_test_v17__stop {
         }
     };
 }
+
+/// Requires `Client` to be in scope and to implement `set_mock_time`.
+#[macro_export]
+macro_rules! impl_test_v17__setmocktime {
+    () => {
+        #[test]
+        fn set_mock_time() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+
+            // Well in the future so that it is after the median time past of the genesis block.
+            let time = 2_000_000_000;
+            bitcoind.client.set_mock_time(time).expect("setmocktime");
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+            let hash = bitcoind.client.best_block_hash().expect("best_block_hash");
+            let block = bitcoind.client.get_block(&hash).expect("getblock");
+            assert_eq!(u64::from(block.header.time), time);
+        }
+    };
+}
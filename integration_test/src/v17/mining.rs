@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+
+/// Requires `Client` to be in scope and to implement `get_mining_info`.
+#[macro_export]
+macro_rules! impl_test_v17__getmininginfo {
+    () => {
+        #[test]
+        fn get_mining_info() {
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_mining_info().expect("getmininginfo");
+            let model = json.into_model();
+            assert_eq!(model.chain, model::Chain::Regtest);
+            assert!(model.warnings.iter().all(|w| !w.is_empty()));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_block_template`.
+#[macro_export]
+macro_rules! impl_test_v17__getblocktemplate {
+    () => {
+        #[test]
+        fn get_block_template() {
+            use bitcoin::hashes::Hash;
+            use bitcoin::Amount;
+            use client::client_sync::v17::TemplateRequest;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_btc(1.0).unwrap())
+                .expect("sendtoaddress")
+                .txid()
+                .expect("txid");
+
+            let request = TemplateRequest::default();
+            let json = bitcoind.client.get_block_template(&request).expect("getblocktemplate");
+            let model = json.into_model().expect("GetBlockTemplate into model");
+
+            let best = bitcoind.client.best_block_hash().expect("best_block_hash");
+            assert_eq!(model.previous_block_hash, best);
+            assert_eq!(model.height, 102);
+            assert!(model.rules.iter().any(|rule| rule == "csv"));
+            assert!(model.bits.to_consensus() > 0);
+            assert_eq!(model.transactions.len(), 1);
+
+            let tx = &model.transactions[0];
+            assert_eq!(tx.txid, txid);
+            assert_eq!(tx.data.compute_txid(), txid);
+            assert!(tx.fee > Amount::ZERO);
+            assert!(model.coinbase_value > Amount::from_btc(50.0).unwrap());
+            assert!(model.default_witness_commitment.is_some());
+
+            // Grind a header on top of the template, half of all hashes meet the regtest target.
+            assert!(model.is_consistent());
+            let mut header = bitcoin::block::Header {
+                version: model.version,
+                prev_blockhash: model.previous_block_hash,
+                merkle_root: bitcoin::TxMerkleNode::all_zeros(),
+                time: model.current_time,
+                bits: model.bits,
+                nonce: 0,
+            };
+            while !model.is_met_by(header.block_hash()) {
+                header.nonce += 1;
+            }
+            assert_eq!(model.validate_header(&header), Ok(header.block_hash()));
+
+            header.prev_blockhash = bitcoin::BlockHash::all_zeros();
+            assert!(model.validate_header(&header).is_err());
+        }
+    };
+}
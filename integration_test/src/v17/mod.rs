@@ -5,6 +5,8 @@
 pub mod blockchain;
 pub mod control;
 pub mod generating;
+pub mod mining;
 pub mod network;
 pub mod raw_transactions;
+pub mod util;
 pub mod wallet;
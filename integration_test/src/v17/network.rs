@@ -13,9 +13,56 @@ macro_rules! impl_test_v17__getnetworkinfo {
         fn get_network_info() {
             let bitcoind = $crate::bitcoind_no_wallet();
             let json = bitcoind.client.get_network_info().expect("getnetworkinfo");
-            json.into_model().unwrap();
+            let model = json.into_model().unwrap();
+
+            assert!(model.local_services.has(bitcoin::p2p::ServiceFlags::NETWORK));
+            assert_eq!(model.connections_in + model.connections_out, model.connections);
+            // No proxy is configured.
+            assert!(model.networks.iter().all(|network| network.proxy.is_none()));
+            assert!(model.local_services_names.iter().any(|name| name == "NETWORK"));
+            let client = model.user_agent.client().expect("user agent component");
+            assert_eq!(client.name, "Satoshi");
 
             bitcoind.client.check_expected_server_version().expect("unexpected version");
+            let version = client::version::CoreVersion::from_server_version(model.version);
+            assert!(bitcoind::Client::supported_versions().contains(&version));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_peer_info`.
+#[macro_export]
+macro_rules! impl_test_v17__getpeerinfo {
+    () => {
+        #[test]
+        fn get_peer_info() {
+            let (bitcoind, _peer) = $crate::bitcoind_with_peer(&[]);
+            let json = bitcoind.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+
+            assert_eq!(model.0.len(), 1);
+            let peer = &model.0[0];
+            assert!(peer.inbound);
+            assert!(peer.addr.socket_addr().expect("peer has an IP address").ip().is_loopback());
+            assert_eq!(peer.network, None);
+            assert_eq!(peer.ban_score, Some(0));
+            assert!(peer.whitelisted.is_some());
+            assert_eq!(model.misbehaving(0).count(), 1);
+            assert_eq!(model.misbehaving(1).count(), 0);
+        }
+
+        #[test]
+        fn get_peer_info_ban_score() {
+            // Use the live result as a fixture of a misbehaving peer.
+            let (bitcoind, _peer) = $crate::bitcoind_with_peer(&[]);
+            let mut value: serde_json::Value =
+                bitcoind.client.call("getpeerinfo", &[]).expect("getpeerinfo");
+
+            value[0]["banscore"] = serde_json::json!(50);
+            let json: bitcoind::json::GetPeerInfo = serde_json::from_value(value).unwrap();
+            let model = json.into_model().unwrap();
+            assert_eq!(model.misbehaving(50).count(), 1);
+            assert_eq!(model.misbehaving(100).count(), 0);
         }
     };
 }
@@ -5,15 +5,298 @@
 //! Specifically this is methods found under the `== Rawtransactions ==` section of the
 //! API docs of `bitcoind v0.17.1`.
 
-/// Requires `Client` to be in scope and to implement `get_best_block_hash`.
+/// Requires `Client` to be in scope and to implement:
+/// - `combine_psbt`
+/// - `wallet_process_psbt`
+#[macro_export]
+macro_rules! impl_test_v17__combinepsbt {
+    () => {
+        #[test]
+        fn combine_psbt() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let psbt = $crate::create_funded_psbt(&bitcoind);
+
+            let json = bitcoind.client.wallet_process_psbt(&psbt).expect("walletprocesspsbt");
+            let signed = json.into_model().unwrap().psbt;
+
+            let json = bitcoind.client.combine_psbt(&[psbt.clone(), signed]).expect("combinepsbt");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.unsigned_tx, psbt.unsigned_tx);
+            assert!(model.0.inputs[0].final_script_witness.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `create_raw_transaction`.
+#[macro_export]
+macro_rules! impl_test_v17__createrawtransaction {
+    () => {
+        #[test]
+        fn create_raw_transaction() {
+            use client::client_sync::v17::{CreateRawTransactionInput, CreateRawTransactionOutput};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let txid = <bitcoin::Txid as bitcoin::hashes::Hash>::all_zeros();
+
+            let outpoint = bitcoin::OutPoint { txid, vout: 1 };
+            let inputs = [CreateRawTransactionInput { outpoint, sequence: Some(0xfffffffd) }];
+            let outputs = [
+                CreateRawTransactionOutput::Address(address.clone(), bitcoin::Amount::ONE_BTC),
+                CreateRawTransactionOutput::Data(vec![0xab, 0xcd]),
+            ];
+            let json = bitcoind
+                .client
+                .create_raw_transaction(&inputs, &outputs)
+                .expect("createrawtransaction");
+            let tx = json.into_model().unwrap().0;
+
+            assert_eq!(tx.input.len(), 1);
+            assert_eq!(tx.input[0].previous_output, outpoint);
+            assert_eq!(tx.input[0].sequence, bitcoin::Sequence(0xfffffffd));
+            assert_eq!(tx.output.len(), 2);
+            assert_eq!(tx.output[0].value, bitcoin::Amount::ONE_BTC);
+            assert_eq!(tx.output[0].script_pubkey, address.script_pubkey());
+            assert!(tx.output[1].script_pubkey.is_op_return());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `fund_raw_transaction`
+/// - `fund_raw_transaction_with_options`
+#[macro_export]
+macro_rules! impl_test_v17__fundrawtransaction {
+    () => {
+        #[test]
+        fn fund_raw_transaction() {
+            use bitcoin::{absolute, transaction, Amount, FeeRate, Transaction, TxOut};
+            use client::client_sync::v17::FundRawTransactionOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let tx = Transaction {
+                version: transaction::Version::TWO,
+                lock_time: absolute::LockTime::ZERO,
+                input: vec![],
+                output: vec![TxOut {
+                    value: Amount::ONE_BTC,
+                    script_pubkey: address.script_pubkey(),
+                }],
+            };
+
+            let json = bitcoind.client.fund_raw_transaction(&tx).expect("fundrawtransaction");
+            let model = json.into_model().unwrap();
+            assert!(!model.tx.input.is_empty());
+            assert!(model.fee > Amount::ZERO);
+
+            let change_address =
+                bitcoind.client.new_address().expect("failed to create new address");
+            let options = FundRawTransactionOptions {
+                change_address: Some(change_address.clone()),
+                change_position: Some(0),
+                lock_unspents: Some(true),
+                fee_rate: Some(FeeRate::from_sat_per_vb_u32(10)),
+                subtract_fee_from_outputs: vec![0],
+                ..Default::default()
+            };
+            let json = bitcoind
+                .client
+                .fund_raw_transaction_with_options(&tx, &options)
+                .expect("fundrawtransaction");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.change_position, Some(0));
+            assert_eq!(model.tx.output[0].script_pubkey, change_address.script_pubkey());
+            // The fee is subtracted from the payment, which moved to index 1.
+            assert_eq!(model.tx.output[1].value, Amount::ONE_BTC - model.fee);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `decode_psbt`.
+#[macro_export]
+macro_rules! impl_test_v17__decodepsbt {
+    () => {
+        #[test]
+        fn decode_psbt() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let psbt = $crate::create_funded_psbt(&bitcoind);
+
+            let json = bitcoind.client.decode_psbt(&psbt).expect("decodepsbt");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.psbt.unsigned_tx, psbt.unsigned_tx);
+            assert_eq!(model.psbt.inputs.len(), psbt.inputs.len());
+            for (decoded, input) in model.psbt.inputs.iter().zip(psbt.inputs.iter()) {
+                assert_eq!(decoded.witness_utxo, input.witness_utxo);
+                assert_eq!(decoded.redeem_script, input.redeem_script);
+                assert_eq!(decoded.bip32_derivation, input.bip32_derivation);
+            }
+            assert!(model.fee.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `decode_raw_transaction`.
+#[macro_export]
+macro_rules! impl_test_v17__decoderawtransaction {
+    () => {
+        #[test]
+        fn decode_raw_transaction() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let tx = $crate::create_signed_transaction(&bitcoind);
+
+            let json = bitcoind.client.decode_raw_transaction(&tx).expect("decoderawtransaction");
+            assert_eq!(json.txid, tx.compute_txid().to_string());
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0, tx);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `finalize_psbt`
+/// - `wallet_process_psbt`
+#[macro_export]
+macro_rules! impl_test_v17__finalizepsbt {
+    () => {
+        #[test]
+        fn finalize_psbt() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let psbt = $crate::create_funded_psbt(&bitcoind);
+
+            // Not signed, so neither finalized nor extracted.
+            let json = bitcoind.client.finalize_psbt(&psbt).expect("finalizepsbt");
+            let model = json.into_model().unwrap();
+            assert!(!model.complete);
+            assert!(model.psbt.is_some());
+            assert!(model.tx.is_none());
+
+            let json = bitcoind.client.wallet_process_psbt(&psbt).expect("walletprocesspsbt");
+            let signed = json.into_model().unwrap().psbt;
+
+            let json = bitcoind.client.finalize_psbt(&signed).expect("finalizepsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            let tx = model.tx.expect("extracted transaction");
+            assert_eq!(tx.compute_txid(), psbt.unsigned_tx.compute_txid());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `send_raw_transaction`
+/// - `get_raw_transaction`
+/// - `get_raw_transaction_in_block`
+#[macro_export]
+macro_rules! impl_test_v17__getrawtransaction {
+    () => {
+        #[test]
+        fn get_raw_transaction() {
+            use client::client_sync::Error;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let tx = $crate::create_signed_transaction(&bitcoind);
+            let txid = bitcoind.client.send_raw_transaction(&tx).unwrap().txid().unwrap();
+
+            let json = bitcoind.client.get_raw_transaction(txid).expect("getrawtransaction");
+            assert_eq!(json.transaction().unwrap(), tx);
+
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let json = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let block_hash = json.into_model().unwrap().0[0];
+
+            let json = bitcoind
+                .client
+                .get_raw_transaction_in_block(txid, &block_hash)
+                .expect("getrawtransaction");
+            assert_eq!(json.transaction().unwrap(), tx);
+
+            // The node does not have `-txindex` enabled.
+            match bitcoind.client.get_raw_transaction(txid) {
+                Err(Error::TxNotFound(e)) => assert_eq!(e.txid(), txid),
+                res => panic!("unexpected result {:?}", res),
+            }
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `sign_raw_transaction_with_wallet`
+/// - `send_raw_transaction`
 #[macro_export]
 macro_rules! impl_test_v17__sendrawtransaction {
     () => {
         #[test]
         fn send_raw_transaction() {
-            // let bitcoind = $crate::bitcoind_no_wallet();
-            // // TODO: Get a transaction from somewhere and send it.
-            // let _ = bitcoind.client.get_best_block_hash().expect("getbestblockhash");
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let unsigned = $crate::create_unsigned_transaction(&bitcoind);
+            let json = bitcoind
+                .client
+                .sign_raw_transaction_with_wallet(&unsigned)
+                .expect("signrawtransactionwithwallet");
+            let tx = json.into_model().unwrap().tx;
+
+            let json = bitcoind.client.send_raw_transaction(&tx).expect("sendrawtransaction");
+            assert_eq!(json.txid().unwrap(), tx.compute_txid());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `test_mempool_accept`.
+#[macro_export]
+macro_rules! impl_test_v17__testmempoolaccept {
+    () => {
+        #[test]
+        fn test_mempool_accept() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let tx = $crate::create_signed_transaction(&bitcoind);
+
+            let json = bitcoind.client.test_mempool_accept(&tx).expect("testmempoolaccept");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+            assert_eq!(model.0[0].txid, tx.compute_txid());
+            assert!(model.0[0].allowed);
+            assert!(model.0[0].rejection_kind().is_none());
+        }
+
+        #[test]
+        fn broadcast_checked() {
+            use client::client_sync::v17::BroadcastOutcome;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let tx = $crate::create_signed_transaction(&bitcoind);
+
+            let outcome = bitcoind.client.broadcast_checked(&tx).expect("broadcast_checked");
+            assert_eq!(outcome, BroadcastOutcome::Broadcast(tx.compute_txid()));
+
+            // Already in the mempool is a policy rejection, which can be overridden.
+            let outcome = bitcoind.client.broadcast_checked(&tx).expect("broadcast_checked");
+            let reason = match outcome {
+                BroadcastOutcome::PolicyRejected { reason, .. } => reason,
+                _ => panic!("expected a policy rejection, got: {:?}", outcome),
+            };
+            assert_eq!(reason, "txn-already-in-mempool");
+            let outcome = bitcoind
+                .client
+                .broadcast_checked_overriding(&tx, &[&reason])
+                .expect("broadcast_checked_overriding");
+            assert!(outcome.is_broadcast());
+            assert_eq!(outcome.txid(), tx.compute_txid());
+
+            // Spending more than the inputs is a consensus failure, never overridden.
+            let mut invalid = $crate::create_signed_transaction(&bitcoind);
+            invalid.output[0].value = bitcoin::Amount::from_btc(1_000.0).unwrap();
+            let outcome = bitcoind
+                .client
+                .broadcast_checked_overriding(&invalid, &["bad-txns-in-belowout"])
+                .expect("broadcast_checked_overriding");
+            match outcome {
+                BroadcastOutcome::ConsensusRejected { reason, .. } =>
+                    assert_eq!(reason, "bad-txns-in-belowout"),
+                _ => panic!("expected a consensus rejection, got: {:?}", outcome),
+            }
         }
     };
 }
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.17.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `estimate_smart_fee`
+/// - `estimate_smart_fee_with_mode`
+#[macro_export]
+macro_rules! impl_test_v17__estimatesmartfee {
+    () => {
+        #[test]
+        fn estimate_smart_fee() {
+            use client::client_sync::v17::EstimateMode;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+
+            // A fresh regtest node has not seen enough blocks and transactions to make an estimate.
+            let json = bitcoind.client.estimate_smart_fee(6).expect("estimatesmartfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee_rate.is_none());
+            assert!(!model.errors.is_empty());
+
+            let json = bitcoind
+                .client
+                .estimate_smart_fee_with_mode(6, EstimateMode::Economical)
+                .expect("estimatesmartfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee_rate.is_none());
+        }
+    };
+}
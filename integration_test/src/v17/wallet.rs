@@ -5,6 +5,41 @@
 //! Specifically this is methods found under the `== Wallet ==` section of the
 //! API docs of `bitcoind v0.17.1`.
 
+/// Requires `Client` to be in scope and to implement `add_multisig_address_with_options`.
+#[macro_export]
+macro_rules! impl_test_v17__addmultisigaddress {
+    () => {
+        #[test]
+        fn add_multisig_address() {
+            use bitcoin::PublicKey;
+            use client::client_sync::v17::AddressType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            // The public keys of the secret keys 1 and 2.
+            let keys = [
+                "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            ]
+            .iter()
+            .map(|key| key.parse::<PublicKey>().unwrap())
+            .collect::<Vec<_>>();
+
+            let json = bitcoind.client.add_multisig_address(2, &keys).expect("addmultisigaddress");
+            let model = json.into_model().unwrap();
+            assert!(model.descriptor.is_none());
+
+            let address_type = Some(AddressType::Bech32);
+            let json = bitcoind
+                .client
+                .add_multisig_address_with_options(2, &keys, Some("multi"), address_type)
+                .expect("addmultisigaddress");
+            let model = json.into_model().unwrap();
+            let address = model.address.assume_checked();
+            assert_eq!(address.script_pubkey(), model.redeem_script.to_p2wsh());
+        }
+    };
+}
+
 /// Requires `Client` to be in scope and to implement `createwallet`.
 #[macro_export]
 macro_rules! impl_test_v17__createwallet {
@@ -44,6 +79,31 @@ macro_rules! impl_test_v17__unloadwallet {
     };
 }
 
+/// Requires `Client` to be in scope and to implement:
+/// - `create_wallet_with_options`
+/// - `get_wallet_info`
+#[macro_export]
+macro_rules! impl_test_v17__createwallet_with_options {
+    () => {
+        #[test]
+        fn create_wallet_with_options() {
+            use client::client_sync::v17::CreateWalletOptions;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let wallet = format!("wallet-{}", rand::random::<u32>());
+            let options = CreateWalletOptions::new().disable_private_keys(true);
+            let _ = bitcoind
+                .client
+                .create_wallet_with_options(&wallet, &options)
+                .expect("createwallet");
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert!(!model.private_keys_enabled);
+        }
+    };
+}
+
 /// Requires `Client` to be in scope and to implement `get_new_address`.
 #[macro_export]
 macro_rules! impl_test_v17__getnewaddress {
@@ -77,6 +137,91 @@ macro_rules! impl_test_v17__getnewaddress {
     };
 }
 
+/// Requires `Client` to be in scope and to implement:
+/// - `get_raw_change_address`
+/// - `get_raw_change_address_with_type`
+#[macro_export]
+macro_rules! impl_test_v17__getrawchangeaddress {
+    () => {
+        #[test]
+        fn get_raw_change_address() {
+            use bitcoind::AddressType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+
+            let json = bitcoind.client.get_raw_change_address().expect("getrawchangeaddress");
+            assert!(json.into_model().is_ok());
+
+            let json = bitcoind
+                .client
+                .get_raw_change_address_with_type(AddressType::Legacy)
+                .expect("getrawchangeaddress");
+            assert!(json.into_model().is_ok());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_address_info`.
+#[macro_export]
+macro_rules! impl_test_v17__getaddressinfo {
+    () => {
+        #[test]
+        fn get_address_info() {
+            use bitcoind::AddressType;
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+
+            let types = [
+                (AddressType::Legacy, model::AddressType::Legacy),
+                (AddressType::P2shSegwit, model::AddressType::P2shSegwit),
+                (AddressType::Bech32, model::AddressType::Bech32),
+            ];
+            for (ty, want) in types.iter() {
+                let address = bitcoind.client.new_address_with_type(ty.clone()).unwrap();
+                let json = bitcoind.client.get_address_info(&address).expect("getaddressinfo");
+                let model = json.into_model().unwrap();
+                assert!(model.is_mine);
+                assert_eq!(model.script_pubkey, address.script_pubkey());
+                assert_eq!(model.address_type(), Some(*want));
+            }
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_wallet_info`
+/// - `address_type_defaults`
+#[macro_export]
+macro_rules! impl_test_v17__getwalletinfo {
+    () => {
+        #[test]
+        fn get_wallet_info() {
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.wallet_name, "default");
+            assert!(model.private_keys_enabled);
+            // One mature and 100 immature coinbase outputs.
+            assert_eq!(model.balance, bitcoin::Amount::from_int_btc(50));
+            assert_eq!(model.immature_balance, bitcoin::Amount::from_int_btc(5_000));
+            assert_eq!(model.total(), Some(bitcoin::Amount::from_int_btc(5_050)));
+
+            let bitcoind =
+                $crate::bitcoind_with_args(&["-addresstype=legacy", "-changetype=p2sh-segwit"]);
+            let defaults = bitcoind.client.address_type_defaults().expect("address_type_defaults");
+            assert_eq!(defaults.wallet_name, "default");
+            assert_eq!(defaults.address_type, model::AddressType::Legacy);
+            assert_eq!(defaults.change_type, model::AddressType::P2shSegwit);
+        }
+    };
+}
+
 /// Requires `Client` to be in scope and to implement `get_balance`.
 #[macro_export]
 macro_rules! impl_test_v17__getbalance {
@@ -115,6 +260,45 @@ macro_rules! impl_test_v17__sendtoaddress {
     };
 }
 
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address_with`
+/// - `get_transaction`
+#[macro_export]
+macro_rules! impl_test_v17__sendtoaddress_with {
+    () => {
+        #[test]
+        fn send_to_address_with() {
+            use bitcoin::Amount;
+            use client::client_sync::v17::SendToAddressRequest;
+            use client::json::model::Bip125Replaceable;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let amount = Amount::from_sat(100_000);
+            let request = SendToAddressRequest::new(address, amount)
+                .comment("test")
+                .subtract_fee(true)
+                .replaceable(true);
+            let txid = bitcoind
+                .client
+                .send_to_address_with(&request)
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let json = bitcoind.client.get_transaction(txid).expect("gettransaction");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.bip125_replaceable, Bip125Replaceable::Yes);
+
+            let fee = model.fee.expect("sent transactions have a fee").unsigned_abs();
+            assert!(model.tx.output.iter().any(|txout| txout.value == amount - fee));
+        }
+    };
+}
+
 /// Requires `Client` to be in scope and to implement:
 /// - `generate_to_address`
 /// - `send_to_address`
@@ -139,7 +323,926 @@ macro_rules! impl_test_v17__gettransaction {
                 .unwrap();
 
             let json = bitcoind.client.get_transaction(txid).expect("gettransaction");
-            json.into_model().unwrap();
+            let model = json.into_model().unwrap();
+
+            let summary = model.to_string();
+            assert!(summary.starts_with(&format!("txid: {}", txid)));
+            assert!(summary.contains("replaceable: "));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_transactions`
+/// - `list_transactions_page`
+#[macro_export]
+macro_rules! impl_test_v17__listtransactions {
+    () => {
+        #[test]
+        fn list_transactions() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.list_transactions().expect("listtransactions");
+            let model = json.into_model().unwrap();
+            assert!(!model.0.is_empty());
+
+            let page = bitcoind.client.list_transactions_page(5, 2).expect("listtransactions");
+            assert_eq!(page.0.len(), 5);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_since_block`
+#[macro_export]
+macro_rules! impl_test_v17__listsinceblock {
+    () => {
+        #[test]
+        fn list_since_block() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.list_since_block().expect("listsinceblock");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.last_block, bitcoind.client.best_block_hash().unwrap());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_transaction`
+/// - `list_transactions`
+/// - `list_since_block`
+#[macro_export]
+macro_rules! impl_test_v17__reconcile_transaction {
+    () => {
+        #[test]
+        fn reconcile_transaction() {
+            use bitcoin::Amount;
+            use client::json::model::reconcile;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+            let tx = bitcoind.client.get_transaction(txid).unwrap().into_model().unwrap();
+            let list = bitcoind.client.list_transactions().unwrap().into_model().unwrap();
+            let since = bitcoind.client.list_since_block().unwrap().into_model().unwrap();
+
+            let r = reconcile::reconcile_transaction(txid, Some(&tx), Some(&list), Some(&since));
+            assert_eq!(r.sources.len(), 3);
+            assert!(r.is_consistent(), "{:?}", r.mismatches);
+            assert_eq!(r.confirmations, Some(1));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_unspent`
+#[macro_export]
+macro_rules! impl_test_v17__listunspent {
+    () => {
+        #[test]
+        fn list_unspent() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.list_unspent().expect("listunspent");
+            let model = json.into_model().unwrap();
+            assert!(!model.0.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_unspent`
+/// - `lock_unspent`
+/// - `list_lock_unspent`
+#[macro_export]
+macro_rules! impl_test_v17__lockunspent {
+    () => {
+        #[test]
+        fn lock_unspent() {
+            use bitcoin::OutPoint;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let unspent = |bitcoind: &bitcoind::BitcoinD| -> Vec<OutPoint> {
+                let json = bitcoind.client.list_unspent().expect("listunspent");
+                let model = json.into_model().unwrap();
+                model.0.iter().map(|utxo| OutPoint { txid: utxo.txid, vout: utxo.vout }).collect()
+            };
+            let outpoint = unspent(&bitcoind)[0];
+
+            assert!(bitcoind.client.lock_unspent(false, &[outpoint]).expect("lockunspent"));
+            let json = bitcoind.client.list_lock_unspent().expect("listlockunspent");
+            assert_eq!(json.into_model().unwrap().0, vec![outpoint]);
+            assert!(!unspent(&bitcoind).contains(&outpoint));
+
+            assert!(bitcoind.client.lock_unspent(true, &[outpoint]).expect("lockunspent"));
+            let json = bitcoind.client.list_lock_unspent().expect("listlockunspent");
+            assert!(json.into_model().unwrap().0.is_empty());
+            assert!(unspent(&bitcoind).contains(&outpoint));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_unspent`
+/// - `psbt_inputs_for_unspent`
+#[macro_export]
+macro_rules! impl_test_v17__psbtinputsforunspent {
+    () => {
+        #[test]
+        fn psbt_inputs_for_unspent() {
+            use bitcoin::Amount;
+            use bitcoind::AddressType;
+
+            let bitcoind = $crate::bitcoind_with_args(&["-txindex"]);
+            let legacy = bitcoind
+                .client
+                .new_address_with_type(AddressType::Legacy)
+                .expect("failed to create new address");
+            let segwit = bitcoind
+                .client
+                .new_address_with_type(AddressType::Bech32)
+                .expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &legacy).expect("generatetoaddress");
+            let _ = bitcoind
+                .client
+                .send_to_address(&segwit, Amount::from_btc(1.0).unwrap())
+                .expect("sendtoaddress");
+            let _ = bitcoind.client.generate_to_address(1, &legacy).expect("generatetoaddress");
+
+            let utxos = bitcoind.client.list_unspent().expect("listunspent").into_model().unwrap();
+            let legacy_utxo = utxos
+                .0
+                .iter()
+                .find(|utxo| utxo.script_pubkey.is_p2pkh())
+                .expect("legacy utxo")
+                .clone();
+            let segwit_utxo = utxos
+                .0
+                .iter()
+                .find(|utxo| utxo.script_pubkey.is_p2wpkh())
+                .expect("segwit utxo")
+                .clone();
+            assert!(legacy_utxo.needs_prev_tx());
+            assert!(!segwit_utxo.needs_prev_tx());
+
+            let selected = [legacy_utxo.clone(), segwit_utxo.clone()];
+            let inputs =
+                bitcoind.client.psbt_inputs_for_unspent(&selected).expect("psbt inputs");
+            assert_eq!(inputs.len(), 2);
+
+            let prev_tx = inputs[0].non_witness_utxo.as_ref().expect("non_witness_utxo");
+            assert_eq!(prev_tx.compute_txid(), legacy_utxo.txid);
+            assert!(inputs[0].witness_utxo.is_none());
+
+            assert_eq!(inputs[1].witness_utxo, Some(segwit_utxo.txout()));
+            assert!(inputs[1].non_witness_utxo.is_none());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `import_multi`
+/// - `import_multi_with_options`
+/// - `list_received_by_address`
+///
+/// `importmulti` is only supported by legacy (non-descriptor) wallets.
+#[macro_export]
+macro_rules! impl_test_v17__importmulti {
+    () => {
+        #[test]
+        fn import_multi() {
+            use bitcoin::{Address, CompressedPublicKey, Network};
+            use client::client_sync::v17::{ImportMultiOptions, ImportMultiRequest, ImportTimestamp};
+
+            const PUBKEY: &str =
+                "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let pubkey = PUBKEY.parse::<CompressedPublicKey>().unwrap();
+            let address = Address::p2wpkh(&pubkey, Network::Regtest);
+
+            let mut watch = ImportMultiRequest::address(address.clone(), ImportTimestamp::Now);
+            watch.watch_only = Some(true);
+            // Internal addresses can not have a label.
+            let script = address.script_pubkey();
+            let mut invalid = ImportMultiRequest::script(script, ImportTimestamp::Now);
+            invalid.internal = Some(true);
+            invalid.label = Some("change".to_owned());
+
+            let options = ImportMultiOptions { rescan: Some(false) };
+            let json = bitcoind
+                .client
+                .import_multi_with_options(&[watch.clone(), invalid], &options)
+                .expect("importmulti");
+            let model = json.into_model();
+            assert_eq!(model.0.len(), 2);
+            assert!(model.0[0].success);
+            assert!(model.0[0].error.is_none());
+            assert!(!model.0[1].success);
+            assert!(model.0[1].error.is_some());
+
+            // Importing again is fine, the rescan is a no-op on a fresh chain.
+            let json = bitcoind.client.import_multi(&[watch]).expect("importmulti");
+            assert!(json.into_model().0[0].success);
+
+            let json = bitcoind
+                .client
+                .list_received_by_address(0, true, true, Some(&address))
+                .expect("listreceivedbyaddress");
+            assert_eq!(json.into_model().unwrap().0.len(), 1);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `list_received_by_address`.
+#[macro_export]
+macro_rules! impl_test_v17__listreceivedbyaddress {
+    () => {
+        #[test]
+        fn list_received_by_address() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let mining = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &mining).expect("generatetoaddress");
+
+            let funded = bitcoind.client.new_address().expect("failed to create new address");
+            let empty = bitcoind.client.new_address().expect("failed to create new address");
+            let amount = Amount::from_btc(1.0).unwrap();
+            let txid = bitcoind
+                .client
+                .send_to_address(&funded, amount)
+                .expect("sendtoaddress")
+                .txid()
+                .expect("txid");
+            let _ = bitcoind.client.generate_to_address(1, &mining).expect("generatetoaddress");
+
+            let json = bitcoind
+                .client
+                .list_received_by_address(1, false, false, Some(&funded))
+                .expect("listreceivedbyaddress");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+            let item = &model.0[0];
+            assert_eq!(item.address.clone().assume_checked(), funded);
+            assert_eq!(item.amount, amount);
+            assert_eq!(item.confirmations, 1);
+            assert_eq!(item.txids, vec![txid]);
+            assert!(!item.involves_watch_only);
+
+            // Addresses that have not received anything are only listed with `include_empty`.
+            let json = bitcoind
+                .client
+                .list_received_by_address(1, false, false, Some(&empty))
+                .expect("listreceivedbyaddress");
+            assert!(json.into_model().unwrap().0.is_empty());
+
+            let json = bitcoind
+                .client
+                .list_received_by_address(1, true, false, Some(&empty))
+                .expect("listreceivedbyaddress");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+            assert_eq!(model.0[0].amount, Amount::ZERO);
+            assert!(model.0[0].txids.is_empty());
+
+            // Without a filter all addresses that received payments are listed.
+            let json = bitcoind
+                .client
+                .list_received_by_address(1, false, false, None)
+                .expect("listreceivedbyaddress");
+            let model = json.into_model().unwrap();
+            assert!(model.0.iter().any(|item| item.address.clone().assume_checked() == funded));
+            assert!(model.0.iter().all(|item| item.address.clone().assume_checked() != empty));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_addresses_by_label`
+/// - `get_received_by_address`
+/// - `addresses_with_balances_for_label`
+#[macro_export]
+macro_rules! impl_test_v17__getaddressesbylabel {
+    () => {
+        #[test]
+        fn addresses_with_balances_for_label() {
+            use bitcoin::Amount;
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let json: bitcoind::json::GetNewAddress =
+                bitcoind.client.call("getnewaddress", &["savings".into()]).expect("getnewaddress");
+            let labelled = json.address().unwrap().assume_checked();
+
+            let amount = Amount::from_sat(100_000);
+            for _ in 0..2 {
+                let _ = bitcoind.client.send_to_address(&labelled, amount).expect("sendtoaddress");
+            }
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+            let json =
+                bitcoind.client.get_addresses_by_label("savings").expect("getaddressesbylabel");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+
+            let json =
+                bitcoind.client.get_received_by_address(&labelled).expect("getreceivedbyaddress");
+            assert_eq!(json.into_model().unwrap().0, amount * 2);
+
+            let summaries = bitcoind
+                .client
+                .addresses_with_balances_for_label("savings")
+                .expect("addresses_with_balances_for_label");
+            assert_eq!(summaries.len(), 1);
+            let summary = &summaries[0];
+            assert_eq!(summary.address, labelled.into_unchecked());
+            assert_eq!(summary.purpose, model::AddressPurpose::Receive);
+            assert_eq!(summary.received, amount * 2);
+            assert_eq!(summary.unspent, amount * 2);
+            assert_eq!(summary.unspent_count, 2);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_received_by_address`
+/// - `get_received_by_address_with_minconf`
+#[macro_export]
+macro_rules! impl_test_v17__getreceivedbyaddress {
+    () => {
+        #[test]
+        fn get_received_by_address_zero_conf() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let receiver = bitcoind.client.new_address().expect("failed to create new address");
+            let amount = Amount::from_sat(100_000);
+            let _ = bitcoind.client.send_to_address(&receiver, amount).expect("sendtoaddress");
+
+            // Unconfirmed transactions are only included if `minconf` is zero.
+            let json = bitcoind
+                .client
+                .get_received_by_address_with_minconf(&receiver, 0)
+                .expect("getreceivedbyaddress");
+            assert_eq!(json.into_model().unwrap().0, amount);
+            let json =
+                bitcoind.client.get_received_by_address(&receiver).expect("getreceivedbyaddress");
+            assert_eq!(json.into_model().unwrap().0, Amount::ZERO);
+
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let json =
+                bitcoind.client.get_received_by_address(&receiver).expect("getreceivedbyaddress");
+            assert_eq!(json.into_model().unwrap().0, amount);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_received_by_address`.
+///
+/// Before v23 coinbase outputs are never included in the amount received.
+#[macro_export]
+macro_rules! impl_test_v17__getreceivedbyaddress_coinbase {
+    () => {
+        #[test]
+        fn get_received_by_address_excludes_coinbase() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let json =
+                bitcoind.client.get_received_by_address(&address).expect("getreceivedbyaddress");
+            assert_eq!(json.into_model().unwrap().0, Amount::ZERO);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `bump_fee`.
+#[macro_export]
+macro_rules! impl_test_v17__bumpfee {
+    () => {
+        #[test]
+        fn bump_fee() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let txid = $crate::send_replaceable(&bitcoind);
+
+            let json = bitcoind.client.bump_fee(txid).expect("bumpfee");
+            let model = json.into_model().unwrap();
+            assert_ne!(model.txid, txid);
+            assert!(model.fee > model.original_fee);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `wallet_create_funded_psbt`.
+#[macro_export]
+macro_rules! impl_test_v17__walletcreatefundedpsbt {
+    () => {
+        #[test]
+        fn wallet_create_funded_psbt() {
+            use client::client_sync::v17::CreateRawTransactionOutput;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let recipient = bitcoind.client.new_address().expect("failed to create new address");
+            let output =
+                CreateRawTransactionOutput::Address(recipient.clone(), bitcoin::Amount::ONE_BTC);
+            let json = bitcoind
+                .client
+                .wallet_create_funded_psbt(&[], &[output])
+                .expect("walletcreatefundedpsbt");
+            let model = json.into_model().unwrap();
+
+            let tx = &model.psbt.unsigned_tx;
+            assert!(model.fee > bitcoin::Amount::ZERO);
+            let change = model.change_position.expect("change output") as usize;
+            assert_eq!(tx.output.len(), 2);
+            let payment = &tx.output[1 - change];
+            assert_eq!(payment.value, bitcoin::Amount::ONE_BTC);
+            assert_eq!(payment.script_pubkey, recipient.script_pubkey());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `wallet_create_funded_psbt_with_options`.
+///
+/// Uses the v17 options, the fee rate is sent as `feeRate` in BTC/kvB (v0.17 to v0.20).
+#[macro_export]
+macro_rules! impl_test_v17__walletcreatefundedpsbt_options {
+    () => {
+        #[test]
+        fn wallet_create_funded_psbt_with_options() {
+            use bitcoin::{Amount, FeeRate};
+            use client::client_sync::v17::{
+                AddressType, CreateRawTransactionOutput, EstimateMode,
+                WalletCreateFundedPsbtOptions,
+            };
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let recipient = bitcoind.client.new_address().expect("failed to create new address");
+            let output = CreateRawTransactionOutput::Address(recipient.clone(), Amount::ONE_BTC);
+            let options = WalletCreateFundedPsbtOptions {
+                change_position: Some(1),
+                change_type: Some(AddressType::Bech32),
+                lock_unspents: Some(true),
+                fee_rate: Some(FeeRate::from_sat_per_vb_u32(10)),
+                subtract_fee_from_outputs: vec![0],
+                replaceable: Some(true),
+                ..Default::default()
+            };
+            let json = bitcoind
+                .client
+                .wallet_create_funded_psbt_with_options(&[], std::slice::from_ref(&output), &options)
+                .expect("walletcreatefundedpsbt");
+            let model = json.into_model().unwrap();
+            let tx = &model.psbt.unsigned_tx;
+
+            // The fee is subtracted from the payment, change is a P2WPKH output at index 1.
+            assert_eq!(model.change_position, Some(1));
+            assert_eq!(tx.output[0].script_pubkey, recipient.script_pubkey());
+            assert_eq!(tx.output[0].value, Amount::ONE_BTC - model.fee);
+            assert!(tx.output[1].script_pubkey.is_p2wpkh());
+            assert!(tx.input.iter().all(|input| input.sequence.is_rbf()));
+
+            // The fee pays 10 sat/vB for the signed transaction, larger than the unsigned one.
+            let unsigned_vsize = tx.vsize() as u64;
+            let max_vsize = unsigned_vsize + 100 * tx.input.len() as u64;
+            assert!(model.fee.to_sat() >= 10 * unsigned_vsize);
+            assert!(model.fee.to_sat() <= 10 * max_vsize);
+
+            // The selected inputs are locked.
+            let json = bitcoind.client.list_unspent().expect("listunspent");
+            let unspent = json.into_model().unwrap().0;
+            for input in &tx.input {
+                assert!(unspent.iter().all(|utxo| utxo.outpoint() != input.previous_output));
+            }
+
+            // The fee rate is estimated, falling back to `-fallbackfee` on regtest.
+            let change_address =
+                bitcoind.client.new_address().expect("failed to create new address");
+            let options = WalletCreateFundedPsbtOptions {
+                change_address: Some(change_address.clone()),
+                include_watching: Some(false),
+                conf_target: Some(6),
+                estimate_mode: Some(EstimateMode::Economical),
+                ..Default::default()
+            };
+            let json = bitcoind
+                .client
+                .wallet_create_funded_psbt_with_options(&[], &[output], &options)
+                .expect("walletcreatefundedpsbt");
+            let model = json.into_model().unwrap();
+            let change = model.change_position.expect("change output") as usize;
+            let tx = &model.psbt.unsigned_tx;
+            assert_eq!(tx.output[change].script_pubkey, change_address.script_pubkey());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `wallet_process_psbt`
+/// - `wallet_process_psbt_with_options` (without the v24 `finalize` argument)
+#[macro_export]
+macro_rules! impl_test_v17__walletprocesspsbt {
+    () => {
+        #[test]
+        fn wallet_process_psbt() {
+            use client::client_sync::v17::SighashType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let psbt = $crate::create_funded_psbt(&bitcoind);
+
+            let json = bitcoind.client.wallet_process_psbt(&psbt).expect("walletprocesspsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+
+            let json = bitcoind
+                .client
+                .wallet_process_psbt_with_options(
+                    &psbt,
+                    true,
+                    SighashType::AllPlusAnyoneCanPay,
+                    true,
+                )
+                .expect("walletprocesspsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            // The signature is the first witness element and ends with the sighash type byte.
+            let witness = model.psbt.inputs[0].final_script_witness.as_ref().expect("finalized");
+            assert_eq!(witness.nth(0).unwrap().last(), Some(&0x81));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `list_wallets`.
+#[macro_export]
+macro_rules! impl_test_v17__listwallets {
+    () => {
+        #[test]
+        fn list_wallets() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let json = bitcoind.client.list_wallets().expect("listwallets");
+            assert_eq!(json.into_model().0, vec!["default".to_owned()]);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `encrypt_wallet`
+/// - `wallet_passphrase`
+/// - `wallet_lock`
+/// - `wallet_passphrase_change`
+#[macro_export]
+macro_rules! impl_test_v17__encryptwallet {
+    () => {
+        #[test]
+        fn encrypt_wallet() {
+            use std::time::Duration;
+
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            // Encrypting a legacy wallet flushes the keypool, get the address while unlocked.
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let amount = Amount::from_sat(1_000_000);
+
+            let _ = bitcoind.client.encrypt_wallet("passphrase").expect("encryptwallet");
+            assert!(bitcoind.client.send_to_address(&address, amount).is_err());
+
+            let timeout = Duration::from_secs(60);
+            bitcoind.client.wallet_passphrase("passphrase", timeout).expect("walletpassphrase");
+            let _ = bitcoind.client.send_to_address(&address, amount).expect("sendtoaddress");
+
+            bitcoind.client.wallet_lock().expect("walletlock");
+            assert!(bitcoind.client.send_to_address(&address, amount).is_err());
+
+            bitcoind
+                .client
+                .wallet_passphrase_change("passphrase", "new passphrase")
+                .expect("walletpassphrasechange");
+            assert!(bitcoind.client.wallet_passphrase("passphrase", timeout).is_err());
+            bitcoind.client.wallet_passphrase("new passphrase", timeout).expect("walletpassphrase");
+            let _ = bitcoind.client.send_to_address(&address, amount).expect("sendtoaddress");
+            bitcoind.client.wallet_lock().expect("walletlock");
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `sign_raw_transaction_with_wallet`.
+#[macro_export]
+macro_rules! impl_test_v17__signrawtransactionwithwallet {
+    () => {
+        #[test]
+        fn sign_raw_transaction_with_wallet() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let unsigned = $crate::create_unsigned_transaction(&bitcoind);
+
+            let json = bitcoind
+                .client
+                .sign_raw_transaction_with_wallet(&unsigned)
+                .expect("signrawtransactionwithwallet");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(model.errors.is_empty());
+            assert_eq!(model.tx.input[0].previous_output, unsigned.input[0].previous_output);
+            assert_eq!(model.tx.output, unsigned.output);
+            assert!(!model.tx.input[0].witness.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `BitcoindRpc`.
+#[macro_export]
+macro_rules! impl_test_v17__bitcoind_rpc {
+    () => {
+        #[test]
+        fn bitcoind_rpc_generic() {
+            use bitcoin::Amount;
+            use client::client_sync::BitcoindRpc;
+
+            // Generic over the client, only uses model types.
+            fn fund_and_send<C: BitcoindRpc>(client: &C) -> bitcoin::Txid {
+                let address = client.get_new_address().expect("getnewaddress").0.assume_checked();
+                let _ = client.generate_to_address(101, &address).expect("generatetoaddress");
+                assert!(client.get_balance().expect("getbalance").0 > Amount::ZERO);
+                client.send_to_address(&address, Amount::from_sat(10_000)).expect("send").txid
+            }
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let txid = fund_and_send(&bitcoind.client);
+
+            let client: &dyn BitcoindRpc = &bitcoind.client;
+            assert!(client.get_raw_mempool().expect("getrawmempool").0.contains(&txid));
+            assert_eq!(client.get_transaction(txid).expect("gettransaction").txid, txid);
+            assert_eq!(client.get_mempool_info().expect("getmempoolinfo").size, 1);
+            let info = client.get_blockchain_info().expect("getblockchaininfo");
+            let best = client.get_best_block_hash().expect("getbestblockhash");
+            assert_eq!(best.0, info.best_block_hash);
+            let _ = client.get_network_info().expect("getnetworkinfo");
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement the deprecated account methods.
+#[macro_export]
+macro_rules! impl_test_v17__accounts {
+    () => {
+        #[test]
+        #[allow(deprecated)]
+        fn accounts() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_deprecated_rpc(&["accounts"]);
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            bitcoind.client.set_account(&address, "mining").expect("setaccount");
+            assert_eq!(bitcoind.client.get_account(&address).expect("getaccount").0, "mining");
+
+            let addresses = bitcoind
+                .client
+                .get_addresses_by_account("mining")
+                .expect("getaddressesbyaccount")
+                .addresses()
+                .unwrap();
+            assert_eq!(addresses, vec![address.as_unchecked().clone()]);
+
+            // Coinbase outputs are not counted as received, send to the account instead.
+            let recipient = bitcoind.client.new_address().expect("failed to create new address");
+            bitcoind.client.set_account(&recipient, "savings").expect("setaccount");
+            let _ = bitcoind.client.send_to_address(&recipient, Amount::ONE_BTC).expect("send");
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let received = bitcoind.client.get_received_by_account("savings").unwrap();
+            assert_eq!(received.amount().unwrap(), Amount::ONE_BTC);
+
+            let balances = bitcoind.client.list_accounts().expect("listaccounts");
+            assert!(balances.balances().unwrap().contains_key("savings"));
+
+            let json = bitcoind.client.get_account_address("other").expect("getaccountaddress");
+            let _ = json.address().unwrap();
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `BitcoindRpc`.
+#[macro_export]
+macro_rules! impl_test_v17__notify {
+    () => {
+        #[test]
+        #[cfg(unix)]
+        fn notify_resolve() {
+            use std::io::BufReader;
+            use std::time::Duration;
+            use std::{fs, thread};
+
+            use bitcoin::Amount;
+            use client::client_sync::notify::{Event, Notification, Notifications};
+
+            let dir = std::env::temp_dir();
+            let id = rand::random::<u32>();
+            let block_path = dir.join(format!("blocknotify-{}", id));
+            let wallet_path = dir.join(format!("walletnotify-{}", id));
+            let block_arg = format!("-blocknotify=echo block %s >> {}", block_path.display());
+            let wallet_arg = format!("-walletnotify=echo wallet %s >> {}", wallet_path.display());
+
+            let bitcoind = $crate::bitcoind_with_args(&[&block_arg, &wallet_arg]);
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+            let best = bitcoind.client.best_block_hash().expect("best_block_hash");
+
+            // Hooks are run asynchronously by the node.
+            let read = |path: &std::path::Path, want: &Notification| {
+                for _ in 0..100 {
+                    if let Ok(file) = fs::File::open(path) {
+                        let notifications = Notifications::new(BufReader::new(file))
+                            .collect::<Result<Vec<_>, _>>()
+                            .expect("failed to parse notifications");
+                        if notifications.contains(want) {
+                            return;
+                        }
+                    }
+                    thread::sleep(Duration::from_millis(100));
+                }
+                panic!("no notification `{}` in {}", want, path.display());
+            };
+
+            let block = Notification::Block(best);
+            read(&block_path, &block);
+            match block.resolve(&bitcoind.client).expect("resolve block") {
+                Event::Block { hash, header } => {
+                    assert_eq!(hash, best);
+                    assert_eq!(header.block_hash(), best);
+                }
+                event => panic!("unexpected event: {:?}", event),
+            }
+
+            let wallet = Notification::Wallet { txid, wallet: None };
+            read(&wallet_path, &wallet);
+            match wallet.resolve(&bitcoind.client).expect("resolve transaction") {
+                Event::Transaction { transaction, .. } => assert_eq!(transaction.txid, txid),
+                event => panic!("unexpected event: {:?}", event),
+            }
+
+            let _ = fs::remove_file(block_path);
+            let _ = fs::remove_file(wallet_path);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `sign_message`
+/// - `verify_message`
+#[macro_export]
+macro_rules! impl_test_v17__signmessage {
+    () => {
+        #[test]
+        fn sign_message() {
+            use bitcoin::secp256k1::Secp256k1;
+            use bitcoin::sign_message::signed_msg_hash;
+            use bitcoind::AddressType;
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            // Core only signs messages with the keys of P2PKH addresses.
+            let address = bitcoind
+                .client
+                .new_address_with_type(AddressType::Legacy)
+                .expect("failed to create new address");
+            let message = "proof of ownership";
+
+            let json = bitcoind.client.sign_message(&address, message).expect("signmessage");
+            let model = json.into_model().unwrap();
+            let signature = model.0;
+
+            let secp = Secp256k1::verification_only();
+            let msg_hash = signed_msg_hash(message);
+            assert!(signature.is_signed_by_address(&secp, &address, msg_hash).unwrap());
+
+            let verified = bitcoind
+                .client
+                .verify_message(&address, &signature, message)
+                .expect("verifymessage");
+            assert!(verified);
+            let verified = bitcoind
+                .client
+                .verify_message(&address, &signature, "another message")
+                .expect("verifymessage");
+            assert!(!verified);
+
+            // The model serializes the signature as base64, as Core does.
+            let s = serde_json::to_string(&model).unwrap();
+            assert_eq!(s, format!("\"{}\"", signature.to_base64()));
+            assert_eq!(serde_json::from_str::<model::SignMessage>(&s).unwrap(), model);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `import_address`
+/// - `import_address_with_options`
+/// - `rescan_blockchain`
+#[macro_export]
+macro_rules! impl_test_v17__importaddress {
+    () => {
+        #[test]
+        fn import_address() {
+            use bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey};
+            use bitcoin::{Address, Amount, CompressedPublicKey, Network, ScriptBuf};
+            use client::client_sync::v17::{ImportAddressOptions, ImportAddressTarget};
+
+            let bitcoind = $crate::bitcoind_with_deprecated_rpc(&["create_bdb"]);
+            let watcher = $crate::legacy_wallet(&bitcoind, "watcher");
+
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // Addresses and a script not known to either wallet.
+            let secp = Secp256k1::new();
+            let key = |b| {
+                let sk = SecretKey::from_slice(&[b; 32]).unwrap();
+                CompressedPublicKey(PublicKey::from_secret_key(&secp, &sk))
+            };
+            let watched = Address::p2wpkh(&key(1), Network::Regtest);
+            let redeem_script = ScriptBuf::new_p2pkh(&key(2).pubkey_hash());
+            let p2sh = Address::p2sh(&redeem_script, Network::Regtest).unwrap();
+            let rescanned = Address::p2pkh(key(3), Network::Regtest);
+
+            let amount = Amount::from_sat(1_000_000);
+            for address in [&watched, &p2sh, &rescanned] {
+                let _ = bitcoind.client.send_to_address(address, amount).expect("sendtoaddress");
+            }
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+            let received = |address: &Address| {
+                let json = watcher
+                    .list_received_by_address(0, true, true, Some(address))
+                    .expect("listreceivedbyaddress");
+                let model = json.into_model().unwrap();
+                model.0.iter().map(|item| item.amount).sum::<Amount>()
+            };
+
+            watcher.import_address(&watched).expect("importaddress");
+            assert_eq!(received(&watched), amount);
+
+            let target = ImportAddressTarget::Script(redeem_script);
+            let options = ImportAddressOptions { p2sh: true, ..Default::default() };
+            watcher.import_address_with_options(&target, &options).expect("importaddress");
+            assert_eq!(received(&p2sh), amount);
+
+            // Without a rescan the existing transaction is not found until the chain is rescanned.
+            let target = ImportAddressTarget::Address(rescanned.clone());
+            let options = ImportAddressOptions {
+                label: "rescanned".to_owned(),
+                rescan: false,
+                ..Default::default()
+            };
+            watcher.import_address_with_options(&target, &options).expect("importaddress");
+            assert_eq!(received(&rescanned), Amount::ZERO);
+
+            let json = watcher.rescan_blockchain().expect("rescanblockchain");
+            let model = json.into_model();
+            assert_eq!(model.start_height, 0);
+            assert_eq!(model.stop_height, Some(102));
+            assert_eq!(received(&rescanned), amount);
         }
     };
 }
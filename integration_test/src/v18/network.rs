@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement `get_node_addresses`.
+#[macro_export]
+macro_rules! impl_test_v18__getnodeaddresses {
+    () => {
+        #[test]
+        fn get_node_addresses() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_node_addresses().expect("getnodeaddresses");
+            let model = json.into_model();
+            // A fresh regtest node does not know about any other nodes.
+            assert!(model.0.is_empty());
+
+            let json = bitcoind.client.get_node_addresses_with_count(10).expect("getnodeaddresses");
+            assert!(json.into_model().by_network().is_empty());
+        }
+    };
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_descriptor_info`
+/// - `derive_addresses`
+/// - `derive_addresses_with_range`
+#[macro_export]
+macro_rules! impl_test_v18__getdescriptorinfo {
+    () => {
+        #[test]
+        fn get_descriptor_info_and_derive_addresses() {
+            use bitcoin::bip32::{ChildNumber, Xpriv, Xpub};
+            use bitcoin::secp256k1::Secp256k1;
+            use bitcoin::{Address, Network};
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let secp = Secp256k1::new();
+            let xpriv = Xpriv::new_master(Network::Regtest, &[42; 32]).unwrap();
+            let xpub = Xpub::from_priv(&secp, &xpriv);
+            let derive = |index: u32| {
+                let path = [ChildNumber::Normal { index: 0 }, ChildNumber::Normal { index }];
+                let pk = xpub.derive_pub(&secp, &path).unwrap().to_pub();
+                Address::p2wpkh(&pk, Network::Regtest)
+            };
+
+            let json = bitcoind
+                .client
+                .get_descriptor_info(&format!("wpkh({}/0/*)", xpub))
+                .expect("getdescriptorinfo");
+            let ranged = json.into_model().unwrap();
+            assert!(ranged.is_range);
+            assert!(ranged.is_solvable);
+            assert!(!ranged.has_private_keys);
+
+            let json = bitcoind
+                .client
+                .derive_addresses_with_range(&ranged.descriptor, (0, 2))
+                .expect("deriveaddresses");
+            let model = json.into_model().unwrap();
+            let want = (0..=2).map(derive).collect::<Vec<_>>();
+            assert_eq!(model.0.into_iter().map(|a| a.assume_checked()).collect::<Vec<_>>(), want);
+
+            let json = bitcoind
+                .client
+                .get_descriptor_info(&format!("wpkh({}/0/7)", xpub))
+                .expect("getdescriptorinfo");
+            let single = json.into_model().unwrap();
+            assert!(!single.is_range);
+
+            let json =
+                bitcoind.client.derive_addresses(&single.descriptor).expect("deriveaddresses");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.0.len(), 1);
+            assert_eq!(model.0[0].clone().assume_checked(), derive(7));
+        }
+    };
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.18.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_wallet_dir`
+/// - `wallet_inventory`
+#[macro_export]
+macro_rules! impl_test_v18__listwalletdir {
+    () => {
+        #[test]
+        fn list_wallet_dir() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let _ = bitcoind.client.create_wallet("other").expect("createwallet");
+            let _ = bitcoind.client.unload_wallet("other").expect("unloadwallet");
+
+            let json = bitcoind.client.list_wallet_dir().expect("listwalletdir");
+            let mut names = json.into_model().0;
+            names.sort();
+            assert_eq!(names, vec!["default".to_owned(), "other".to_owned()]);
+
+            let inventory = bitcoind.client.wallet_inventory(false).expect("wallet_inventory");
+            assert!(inventory.loaded.contains("default"));
+            assert!(inventory.unloaded.contains("other"));
+            assert!(inventory.orphaned.is_empty());
+
+            // Probing loads and unloads "other" again, leaving it readable and unloaded.
+            let inventory = bitcoind.client.wallet_inventory(true).expect("wallet_inventory");
+            assert!(inventory.unloaded.contains("other"));
+            assert!(inventory.unreadable.is_empty());
+            let json = bitcoind.client.list_wallets().expect("listwallets");
+            assert_eq!(json.into_model().0, vec!["default".to_owned()]);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_received_by_label`
+/// - `get_received_by_label_with_minconf`
+#[macro_export]
+macro_rules! impl_test_v18__getreceivedbylabel {
+    () => {
+        #[test]
+        fn get_received_by_label() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let amount = Amount::from_sat(100_000);
+            for _ in 0..2 {
+                let json: bitcoind::json::GetNewAddress = bitcoind
+                    .client
+                    .call("getnewaddress", &["savings".into()])
+                    .expect("getnewaddress");
+                let labelled = json.address().unwrap().assume_checked();
+                let _ = bitcoind.client.send_to_address(&labelled, amount).expect("sendtoaddress");
+            }
+
+            // Unconfirmed transactions are only included if `minconf` is zero.
+            let json = bitcoind
+                .client
+                .get_received_by_label_with_minconf("savings", 0)
+                .expect("getreceivedbylabel");
+            assert_eq!(json.into_model().unwrap().0, amount * 2);
+            let json =
+                bitcoind.client.get_received_by_label("savings").expect("getreceivedbylabel");
+            assert_eq!(json.into_model().unwrap().0, Amount::ZERO);
+
+            let _ = bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let json =
+                bitcoind.client.get_received_by_label("savings").expect("getreceivedbylabel");
+            assert_eq!(json.into_model().unwrap().0, amount * 2);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_wallet_with_options`
+/// - `get_wallet_info`
+#[macro_export]
+macro_rules! impl_test_v18__createwallet_with_options {
+    () => {
+        #[test]
+        fn create_wallet_with_options() {
+            use client::client_sync::v18::CreateWalletOptions;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let wallet = format!("wallet-{}", rand::random::<u32>());
+            let options = CreateWalletOptions::new().disable_private_keys(true).blank(true);
+            let _ = bitcoind
+                .client
+                .create_wallet_with_options(&wallet, &options)
+                .expect("createwallet");
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert!(!model.private_keys_enabled);
+            assert_eq!(model.keypool_size, 0);
+        }
+    };
+}
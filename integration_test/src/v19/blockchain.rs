@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.19.1`.
+
+/// Requires `Client` to be in scope and to implement `get_blockchain_info`.
+#[macro_export]
+macro_rules! impl_test_v19__getblockchaininfo {
+    () => {
+        #[test]
+        fn get_blockchain_info() {
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_blockchain_info().expect("getblockchaininfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.chain.network(), Some(bitcoin::Network::Regtest));
+            assert!(model.time.is_none());
+
+            // From v0.19 all softforks are reported in a single map.
+            let segwit = model.softforks.get("segwit").expect("segwit");
+            assert_eq!(segwit.type_, model::SoftforkType::Buried);
+            assert!(segwit.active);
+            let testdummy = model.softforks.get("testdummy").expect("testdummy");
+            assert_eq!(testdummy.type_, model::SoftforkType::Bip9);
+            assert!(testdummy.bip9.is_some());
+        }
+    };
+}
@@ -2,4 +2,6 @@
 
 //! Macros for implementing test methods on a JSON-RPC client for `bitcoind v0.19.1`.
 
+pub mod blockchain;
+pub mod raw_transactions;
 pub mod wallet;
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v0.19.1`.
+
+/// Requires `Client` to be in scope and to implement `send_raw_transaction_with_max_fee_rate`.
+#[macro_export]
+macro_rules! impl_test_v19__sendrawtransaction {
+    () => {
+        #[test]
+        fn send_raw_transaction_with_max_fee_rate() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let tx = $crate::create_signed_transaction(&bitcoind);
+
+            // The wallet pays the regtest fallback fee of 10 sat/vB.
+            let low = bitcoin::FeeRate::from_sat_per_vb(1).unwrap();
+            let _ = bitcoind
+                .client
+                .send_raw_transaction_with_max_fee_rate(&tx, low)
+                .expect_err("fee rate above the maximum");
+
+            let high = bitcoin::FeeRate::from_sat_per_vb(1_000).unwrap();
+            let json = bitcoind
+                .client
+                .send_raw_transaction_with_max_fee_rate(&tx, high)
+                .expect("sendrawtransaction");
+            assert_eq!(json.txid().unwrap(), tx.compute_txid());
+        }
+    };
+}
@@ -4,11 +4,142 @@ macro_rules! impl_test_v19__getbalances {
     () => {
         #[test]
         fn get_balances() {
+            use client::json::model;
+
             let bitcoind = $crate::bitcoind_with_default_wallet();
             let address = bitcoind.client.new_address().expect("failed to get new address");
             let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
             let json = bitcoind.client.get_balances().expect("getbalances");
-            json.into_model().unwrap();
+            let model = json.into_model().unwrap();
+
+            let reward = bitcoin::Amount::from_btc(50.0).unwrap();
+            assert_eq!(model.spendable(model::SpendablePolicy::TrustedOnly), Some(reward));
+            assert_eq!(model.total(), Some(reward * 101));
+            assert!(model.to_string().starts_with("mine: trusted 50 BTC, "));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_balances`.
+#[macro_export]
+macro_rules! impl_test_v19__getbalances_avoid_reuse {
+    () => {
+        #[test]
+        fn get_balances_avoid_reuse() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            // Two mature coinbase outputs, both paying to `address`.
+            let _ = bitcoind.client.generate_to_address(102, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.get_balances().expect("getbalances");
+            let model = json.into_model().unwrap();
+            assert!(model.mine.used.is_none());
+
+            let _: serde_json::Value = bitcoind
+                .client
+                .call("setwalletflag", &["avoid_reuse".into(), true.into()])
+                .expect("setwalletflag");
+
+            let json = bitcoind.client.get_balances().expect("getbalances");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.mine.used, Some(Amount::ZERO));
+
+            // Spending one of the coinbase outputs marks `address` as used.
+            let other = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind
+                .client
+                .send_to_address(&other, Amount::from_btc(1.0).unwrap())
+                .expect("sendtoaddress");
+
+            let json = bitcoind.client.get_balances().expect("getbalances");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.mine.used, Some(Amount::from_btc(50.0).unwrap()));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_wallet_info`.
+#[macro_export]
+macro_rules! impl_test_v19__getwalletinfo {
+    () => {
+        #[test]
+        fn get_wallet_info_avoid_reuse() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.avoid_reuse, Some(false));
+            assert_eq!(model.scanning, Some(false));
+
+            let _: serde_json::Value = bitcoind
+                .client
+                .call("setwalletflag", &["avoid_reuse".into(), true.into()])
+                .expect("setwalletflag");
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.avoid_reuse, Some(true));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_transaction`
+/// - `get_transaction_verbose`
+#[macro_export]
+macro_rules! impl_test_v19__gettransaction_verbose {
+    () => {
+        #[test]
+        fn get_transaction_verbose() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let json = bitcoind.client.get_transaction_verbose(txid).expect("gettransaction");
+            let decoded = json.decoded.clone().expect("verbose result is decoded");
+            assert_eq!(decoded.transaction().unwrap().compute_txid(), txid);
+            let model = json.into_model().unwrap();
+
+            // Apart from `decoded` the result is the same as the non-verbose call.
+            let json = bitcoind.client.get_transaction(txid).expect("gettransaction");
+            $crate::compare::assert_model_eq(&json.into_model().unwrap(), &model);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_wallet_with_options`
+/// - `get_wallet_info`
+#[macro_export]
+macro_rules! impl_test_v19__createwallet_with_options {
+    () => {
+        #[test]
+        fn create_wallet_with_options() {
+            use client::client_sync::v19::CreateWalletOptions;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let wallet = format!("wallet-{}", rand::random::<u32>());
+            let options = CreateWalletOptions::new().passphrase("passphrase").avoid_reuse(true);
+            let _ = bitcoind
+                .client
+                .create_wallet_with_options(&wallet, &options)
+                .expect("createwallet");
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            // An encrypted wallet is locked once created.
+            assert_eq!(model.unlocked_until, Some(0));
+            assert_eq!(model.avoid_reuse, Some(true));
         }
     };
 }
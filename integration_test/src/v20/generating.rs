@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Generating ==` section of the
+//! API docs of `bitcoind v0.20.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_descriptor`
+/// - `get_block`
+#[macro_export]
+macro_rules! impl_test_v20__generatetodescriptor {
+    () => {
+        #[test]
+        fn generate_to_descriptor() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let descriptor = format!("addr({})", address);
+
+            let json = bitcoind
+                .client
+                .generate_to_descriptor(2, &descriptor)
+                .expect("generatetodescriptor");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.len(), 2);
+
+            let block = bitcoind.client.get_block(&model.0[0]).expect("getblock");
+            let coinbase = &block.txdata[0];
+            assert_eq!(coinbase.output[0].script_pubkey, address.script_pubkey());
+        }
+    };
+}
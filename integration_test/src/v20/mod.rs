@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for `bitcoind v0.20.2`.
+
+pub mod generating;
+pub mod wallet;
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.20.2`.
+
+/// Requires `Client` to be in scope and to implement `add_multisig_address_with_options`.
+#[macro_export]
+macro_rules! impl_test_v20__addmultisigaddress {
+    () => {
+        #[test]
+        fn add_multisig_address() {
+            use bitcoin::PublicKey;
+            use client::client_sync::v17::AddressType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            // The public keys of the secret keys 1 and 2.
+            let keys = [
+                "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            ]
+            .iter()
+            .map(|key| key.parse::<PublicKey>().unwrap())
+            .collect::<Vec<_>>();
+
+            let address_type = Some(AddressType::Bech32);
+            let json = bitcoind
+                .client
+                .add_multisig_address_with_options(2, &keys, Some("multi"), address_type)
+                .expect("addmultisigaddress");
+            let model = json.into_model().unwrap();
+
+            let address = model.address.assume_checked();
+            assert_eq!(address.script_pubkey(), model.redeem_script.to_p2wsh());
+            let descriptor = model.descriptor.expect("descriptor is returned by v0.20 and later");
+            assert!(descriptor.starts_with("wsh(multi(2,"));
+        }
+    };
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_index_info`
+/// - `get_index_info_for`
+#[macro_export]
+macro_rules! impl_test_v21__getindexinfo {
+    () => {
+        #[test]
+        fn get_index_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_index_info().expect("getindexinfo");
+            assert!(json.into_model().0.is_empty());
+
+            let bitcoind = $crate::bitcoind_with_args(&["-txindex"]);
+            let json = bitcoind.client.get_index_info().expect("getindexinfo");
+            assert!(json.into_model().0.contains_key("txindex"));
+
+            let json = bitcoind.client.get_index_info_for("coinstatsindex").expect("getindexinfo");
+            assert!(json.into_model().0.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_blockchain_info`
+#[macro_export]
+macro_rules! impl_test_v21__getblockchaininfo_signet {
+    () => {
+        #[test]
+        fn get_blockchain_info_signet() {
+            use client::json::model::Chain;
+
+            let bitcoind = $crate::bitcoind_signet();
+            let json = bitcoind.client.get_blockchain_info().expect("getblockchaininfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.chain, Chain::Signet);
+            assert_eq!(model.chain.network(), Some(bitcoin::Network::Signet));
+            // Only returned by newer versions of Core.
+            if let Some(challenge) = model.signet_challenge {
+                assert_eq!(challenge.as_bytes(), [0x51]);
+            }
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_tx_out_set_info_with_hash_type`.
+#[macro_export]
+macro_rules! impl_test_v21__gettxoutsetinfo_muhash {
+    () => {
+        #[test]
+        fn get_tx_out_set_info_muhash() {
+            use client::client_sync::v21::TxOutSetHashType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            let json = bitcoind
+                .client
+                .get_tx_out_set_info_with_hash_type(TxOutSetHashType::MuHash)
+                .expect("gettxoutsetinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.height, 10);
+            assert!(model.muhash.is_some());
+            assert!(model.hash_serialized.is_none());
+
+            let json = bitcoind
+                .client
+                .get_tx_out_set_info_with_hash_type(TxOutSetHashType::None)
+                .expect("gettxoutsetinfo");
+            let model = json.into_model().unwrap();
+            assert!(model.muhash.is_none());
+            assert!(model.hash_serialized.is_none());
+        }
+    };
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Generating ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_block`
+/// - `generate_block_to_descriptor`
+/// - `get_block`
+#[macro_export]
+macro_rules! impl_test_v21__generateblock {
+    () => {
+        #[test]
+        fn generate_block() {
+            use bitcoin::Amount;
+            use client::client_sync::v21::GenerateBlockTransaction;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // One transaction referenced by txid and one included as a raw transaction.
+            let send = || {
+                bitcoind
+                    .client
+                    .send_to_address(&address, Amount::from_sat(10_000))
+                    .expect("sendtoaddress")
+                    .txid()
+                    .unwrap()
+            };
+            let txid = send();
+            let raw = bitcoind.client.get_transaction(send()).expect("gettransaction");
+            let raw = raw.into_model().unwrap().tx;
+            let transactions =
+                [GenerateBlockTransaction::Txid(txid), GenerateBlockTransaction::Raw(raw.clone())];
+
+            let json =
+                bitcoind.client.generate_block(&address, &transactions).expect("generateblock");
+            let model = json.into_model().unwrap();
+            let block = bitcoind.client.get_block(&model.hash).expect("getblock");
+            let txids = block.txdata.iter().map(|tx| tx.compute_txid()).collect::<Vec<_>>();
+            assert_eq!(txids[1..], [txid, raw.compute_txid()]);
+
+            let descriptor = format!("addr({})", address);
+            let json = bitcoind
+                .client
+                .generate_block_to_descriptor(&descriptor, &[])
+                .expect("generateblock");
+            let model = json.into_model().unwrap();
+            let block = bitcoind.client.get_block(&model.hash).expect("getblock");
+            assert_eq!(block.txdata.len(), 1);
+        }
+    };
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for `bitcoind v0.21.2`.
+
+pub mod blockchain;
+pub mod generating;
+pub mod network;
+pub mod wallet;
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+
+/// Requires `Client` to be in scope and to implement `get_peer_info`.
+#[macro_export]
+macro_rules! impl_test_v21__getpeerinfo {
+    () => {
+        #[test]
+        fn get_peer_info() {
+            use client::json::model::NetworkType;
+
+            let (bitcoind, _peer) = $crate::bitcoind_with_peer(&[]);
+            let json = bitcoind.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+
+            let peer = &model.0[0];
+            assert_eq!(peer.connection_type.as_deref(), Some("inbound"));
+            assert!(peer.addr.socket_addr().expect("peer has an IP address").ip().is_loopback());
+            assert_eq!(peer.network, Some(NetworkType::Other("not_publicly_routable".to_owned())));
+            assert_eq!(peer.ban_score, None);
+            assert_eq!(model.misbehaving(0).count(), 0);
+        }
+
+        #[test]
+        fn get_peer_info_deprecated_ban_score() {
+            let (bitcoind, _peer) = $crate::bitcoind_with_peer(&["banscore"]);
+            let json = bitcoind.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+
+            assert_eq!(model.0[0].ban_score, Some(0));
+            assert_eq!(model.misbehaving(0).count(), 1);
+        }
+    };
+}
@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v0.21.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `generate_to_address`
+/// - `send_to_address_with`
+/// - `get_transaction`
+#[macro_export]
+macro_rules! impl_test_v21__sendtoaddress_with {
+    () => {
+        #[test]
+        fn send_to_address_with() {
+            use bitcoin::{Amount, FeeRate};
+            use client::client_sync::v21::SendToAddressRequest;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let fee_rate = FeeRate::from_sat_per_vb(5).unwrap();
+            let request = SendToAddressRequest::new(address, Amount::from_sat(100_000))
+                .comment("test")
+                .fee_rate(fee_rate);
+            let txid = bitcoind
+                .client
+                .send_to_address_with(&request)
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            let json = bitcoind.client.get_transaction(txid).expect("gettransaction");
+            let model = json.into_model().unwrap();
+            let fee = model.fee.expect("sent transactions have a fee").unsigned_abs();
+            assert_eq!(fee.to_sat() / model.tx.vsize() as u64, 5);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `wallet_create_funded_psbt_with_options`.
+///
+/// Uses the v21 options, the fee rate is sent as `fee_rate` in sat/vB.
+#[macro_export]
+macro_rules! impl_test_v21__walletcreatefundedpsbt_options {
+    () => {
+        #[test]
+        fn wallet_create_funded_psbt_with_options_sat_per_vb() {
+            use bitcoin::{Amount, FeeRate};
+            use client::client_sync::v21::{
+                AddressType, CreateRawTransactionOutput, WalletCreateFundedPsbtOptions,
+            };
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let recipient = bitcoind.client.new_address().expect("failed to create new address");
+            let output = CreateRawTransactionOutput::Address(recipient.clone(), Amount::ONE_BTC);
+            let options = WalletCreateFundedPsbtOptions {
+                change_position: Some(0),
+                change_type: Some(AddressType::Bech32),
+                lock_unspents: Some(true),
+                fee_rate: Some(FeeRate::from_sat_per_vb_u32(25)),
+                subtract_fee_from_outputs: vec![0],
+                ..Default::default()
+            };
+            let json = bitcoind
+                .client
+                .wallet_create_funded_psbt_with_options(&[], &[output], &options)
+                .expect("walletcreatefundedpsbt");
+            let model = json.into_model().unwrap();
+            let tx = &model.psbt.unsigned_tx;
+
+            // The payment moved to index 1, after the change output.
+            assert_eq!(model.change_position, Some(0));
+            assert!(tx.output[0].script_pubkey.is_p2wpkh());
+            assert_eq!(tx.output[1].script_pubkey, recipient.script_pubkey());
+            assert_eq!(tx.output[1].value, Amount::ONE_BTC - model.fee);
+
+            // Sending the rate in the wrong unit would be off by orders of magnitude.
+            let unsigned_vsize = tx.vsize() as u64;
+            let max_vsize = unsigned_vsize + 100 * tx.input.len() as u64;
+            assert!(model.fee.to_sat() >= 25 * unsigned_vsize);
+            assert!(model.fee.to_sat() <= 25 * max_vsize);
+
+            let json = bitcoind.client.list_unspent().expect("listunspent");
+            let unspent = json.into_model().unwrap().0;
+            for input in &tx.input {
+                assert!(unspent.iter().all(|utxo| utxo.outpoint() != input.previous_output));
+            }
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `bump_fee_with_options`.
+#[macro_export]
+macro_rules! impl_test_v21__bumpfee {
+    () => {
+        #[test]
+        fn bump_fee_with_options() {
+            use bitcoin::FeeRate;
+            use client::client_sync::v21::BumpFeeOptions;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let txid = $crate::send_replaceable(&bitcoind);
+
+            let options = BumpFeeOptions {
+                fee_rate: Some(FeeRate::from_sat_per_vb(25).unwrap()),
+                replaceable: Some(true),
+                ..Default::default()
+            };
+            let json = bitcoind.client.bump_fee_with_options(txid, &options).expect("bumpfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee > model.original_fee);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `psbt_bump_fee`.
+#[macro_export]
+macro_rules! impl_test_v21__psbtbumpfee {
+    () => {
+        #[test]
+        fn psbt_bump_fee() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let txid = $crate::send_replaceable(&bitcoind);
+
+            let json = bitcoind.client.psbt_bump_fee(txid).expect("psbtbumpfee");
+            let model = json.into_model().unwrap();
+            assert!(model.fee > model.original_fee);
+            assert!(!model.psbt.unsigned_tx.input.is_empty());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `import_descriptors`
+/// - `import_descriptors_chunked`
+#[macro_export]
+macro_rules! impl_test_v21__importdescriptors {
+    () => {
+        #[test]
+        fn import_descriptors_chunked() {
+            use std::num::NonZeroUsize;
+
+            use client::client_sync::v21::{ImportDescriptorsRequest, ImportTimestamp};
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            // createwallet "name" disable_private_keys blank "passphrase" avoid_reuse descriptors
+            let args =
+                ["watch".into(), true.into(), true.into(), "".into(), false.into(), true.into()];
+            let _: serde_json::Value =
+                bitcoind.client.call("createwallet", &args).expect("createwallet");
+
+            let mut requests = (1..=5_u8)
+                .map(|i| {
+                    let desc = format!("raw(0014{})", hex_repeat(i));
+                    let info: serde_json::Value = bitcoind
+                        .client
+                        .call("getdescriptorinfo", &[desc.into()])
+                        .expect("getdescriptorinfo");
+                    let desc = info["descriptor"].as_str().unwrap().to_owned();
+                    ImportDescriptorsRequest::new(desc, ImportTimestamp::Now)
+                })
+                .collect::<Vec<_>>();
+            // Invalid checksum.
+            let bad = format!("raw(0014{})#00000000", hex_repeat(6));
+            requests.insert(2, ImportDescriptorsRequest::new(bad.clone(), ImportTimestamp::Now));
+
+            let json =
+                bitcoind.client.import_descriptors(&requests[..1]).expect("importdescriptors");
+            assert!(json.into_model().0[0].success);
+
+            let chunk_size = NonZeroUsize::new(2).unwrap();
+            let mut progress = vec![];
+            let report = bitcoind
+                .client
+                .import_descriptors_chunked(&requests, chunk_size, 1, |p| progress.push(p.clone()))
+                .expect("importdescriptors");
+
+            assert_eq!(report.imported, 5);
+            assert!(!report.is_complete());
+            assert_eq!(report.failed.len(), 1);
+            assert_eq!(report.failed[0].index, 2);
+            assert_eq!(report.failed[0].descriptor, bad);
+            assert_eq!(report.failed[0].attempts, 2);
+            // Three chunks plus one retry of the invalid descriptor.
+            assert_eq!(progress.len(), 4);
+            assert_eq!(progress[2].retrying, 1);
+            let last = progress.last().unwrap();
+            assert_eq!((last.total, last.imported, last.failed, last.retrying), (6, 5, 1, 0));
+
+            // A failed call is a failed attempt for each descriptor in the chunk.
+            let _: serde_json::Value =
+                bitcoind.client.call("unloadwallet", &["watch".into()]).expect("unloadwallet");
+            let report = bitcoind
+                .client
+                .import_descriptors_chunked(&requests[..3], chunk_size, 1, |_| {})
+                .expect("importdescriptors");
+            assert_eq!(report.imported, 0);
+            assert_eq!(report.failed.len(), 3);
+            assert!(report.failed.iter().all(|f| f.attempts == 2));
+            // RPC_WALLET_NOT_FOUND
+            assert!(report.failed.iter().all(|f| f.error.code == -18));
+
+            fn hex_repeat(byte: u8) -> String { format!("{:02x}", byte).repeat(20) }
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `send`.
+#[macro_export]
+macro_rules! impl_test_v21__send {
+    () => {
+        #[test]
+        fn send() {
+            use bitcoin::{Amount, FeeRate};
+            use client::client_sync::v21::{SendOptions, SendParams};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let amount = Amount::from_sat(100_000);
+            let params = SendParams::new()
+                .add_address(address.clone(), amount)
+                .add_data(b"hello".to_vec())
+                .fee_rate(FeeRate::from_sat_per_vb_u32(10));
+            let json = bitcoind.client.send(&params).expect("send");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            let txid = model.txid.expect("txid");
+            let tx = bitcoind.client.get_transaction(txid).expect("gettransaction");
+            let tx = tx.into_model().unwrap().tx;
+            assert_eq!(tx.output.iter().filter(|out| out.script_pubkey.is_op_return()).count(), 1);
+            assert!(tx.output.iter().any(|out| out.value == amount));
+
+            // Without adding it to the wallet the signed transaction is returned.
+            let options = SendOptions { add_to_wallet: Some(false), ..Default::default() };
+            let params =
+                SendParams::new().add_address(address, amount).conf_target(6).options(options);
+            let json = bitcoind.client.send(&params).expect("send");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            assert!(model.txid.is_none());
+            assert!(model.tx.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_wallet_with_options`
+/// - `get_wallet_info`
+#[macro_export]
+macro_rules! impl_test_v21__createwallet_with_options {
+    () => {
+        #[test]
+        fn create_wallet_with_options() {
+            use client::client_sync::v21::CreateWalletOptions;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let wallet = format!("wallet-{}", rand::random::<u32>());
+            let options =
+                CreateWalletOptions::new().descriptors(true).blank(true).load_on_startup(false);
+            let _ = bitcoind
+                .client
+                .create_wallet_with_options(&wallet, &options)
+                .expect("createwallet");
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert!(model.descriptors);
+            assert_eq!(model.keypool_size, 0);
+        }
+    };
+}
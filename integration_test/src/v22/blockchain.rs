@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v22.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_tx_out_set_info_at`
+/// - `get_index_info_for`
+#[macro_export]
+macro_rules! impl_test_v22__gettxoutsetinfo {
+    () => {
+        #[test]
+        fn get_tx_out_set_info_at_requires_index() {
+            use client::client_sync::v22::HashOrHeight;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            match bitcoind.client.get_tx_out_set_info_at(HashOrHeight::Height(0)) {
+                Err(client::client_sync::Error::IndexNotAvailable(index)) =>
+                    assert_eq!(index, "coinstatsindex"),
+                res => panic!("expected index not available error, got: {:?}", res),
+            }
+        }
+
+        #[test]
+        fn get_tx_out_set_info_at() {
+            use bitcoin::Amount;
+            use client::client_sync::v22::HashOrHeight;
+
+            let bitcoind = $crate::bitcoind_with_args(&["-coinstatsindex"]);
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let hashes = bitcoind.client.generate_to_address(20, &address).expect("generate");
+            let hash = hashes.into_model().unwrap().0[9];
+
+            // The index is built in the background.
+            for _ in 0..100 {
+                let json = bitcoind.client.get_index_info_for("coinstatsindex").unwrap();
+                let index = json.into_model().0["coinstatsindex"].clone();
+                if index.synced && index.best_block_height == 20 {
+                    break;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+
+            let json = bitcoind
+                .client
+                .get_tx_out_set_info_at(HashOrHeight::Height(10))
+                .expect("gettxoutsetinfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.height, 10);
+            assert_eq!(model.best_block, hash);
+            assert!(model.muhash.is_some());
+            assert_eq!(model.total_amount, Amount::from_btc(500.0).unwrap());
+            // The genesis block subsidy is unspendable.
+            assert_eq!(model.total_unspendable_amount, Some(Amount::from_btc(50.0).unwrap()));
+            let block_info = model.block_info.expect("block_info with coinstatsindex");
+            assert_eq!(block_info.coinbase, Amount::from_btc(50.0).unwrap());
+            assert_eq!(block_info.unspendables.scripts, Amount::ZERO);
+
+            let json = bitcoind
+                .client
+                .get_tx_out_set_info_at(HashOrHeight::Hash(hash))
+                .expect("gettxoutsetinfo");
+            assert_eq!(json.into_model().unwrap().height, 10);
+        }
+    };
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v22.1`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_node_addresses_with_count`
+/// - `get_node_addresses_on_network`
+#[macro_export]
+macro_rules! impl_test_v22__getnodeaddresses {
+    () => {
+        #[test]
+        fn get_node_addresses_on_network() {
+            use client::json::model::NetworkType;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            // `addpeeraddress` is a hidden, test only, method.
+            let _: serde_json::Value = bitcoind
+                .client
+                .call("addpeeraddress", &["1.2.3.4".into(), 8333.into()])
+                .expect("addpeeraddress");
+            let _: serde_json::Value = bitcoind
+                .client
+                .call("addpeeraddress", &["2a00:1450:4001::1".into(), 8333.into()])
+                .expect("addpeeraddress");
+
+            let json = bitcoind.client.get_node_addresses_with_count(0).expect("getnodeaddresses");
+            let model = json.into_model();
+            let grouped = model.by_network();
+            assert_eq!(grouped[&NetworkType::Ipv4].len(), 1);
+            assert_eq!(grouped[&NetworkType::Ipv6].len(), 1);
+
+            let json = bitcoind
+                .client
+                .get_node_addresses_on_network(0, &NetworkType::Ipv4)
+                .expect("getnodeaddresses");
+            let model = json.into_model();
+            assert_eq!(model.0.len(), 1);
+            assert_eq!(model.0[0].address, "1.2.3.4");
+            assert_eq!(model.0[0].port, 8333);
+            assert_eq!(model.on_network(&NetworkType::Ipv4).count(), 1);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_peer_info`.
+#[macro_export]
+macro_rules! impl_test_v22__getpeerinfo {
+    () => {
+        #[test]
+        fn get_peer_info() {
+            let (bitcoind, _peer) = $crate::bitcoind_with_peer(&[]);
+            let json = bitcoind.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+
+            assert_eq!(model.0.len(), 1);
+            let peer = &model.0[0];
+            assert_eq!(peer.connection_type.as_deref(), Some("inbound"));
+            assert!(peer.addr.socket_addr().expect("peer has an IP address").ip().is_loopback());
+            assert!(peer.bip152_hb_to.is_some());
+            assert!(peer.bip152_hb_from.is_some());
+            // Ban scores were removed in v22.
+            assert_eq!(peer.ban_score, None);
+            assert_eq!(peer.whitelisted, None);
+        }
+    };
+}
@@ -18,3 +18,89 @@ macro_rules! impl_test_v22__unloadwallet {
         }
     };
 }
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_unspent`
+#[macro_export]
+macro_rules! impl_test_v22__listunspent {
+    () => {
+        #[test]
+        fn list_unspent() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // Create an unconfirmed UTXO so the ancestor fields are populated.
+            let txid = bitcoind
+                .client
+                .send_to_address(&address, Amount::from_sat(10_000))
+                .expect("sendtoaddress")
+                .txid()
+                .unwrap();
+
+            // Use `minconf=0` to include the unconfirmed UTXO.
+            let json: bitcoind::json::ListUnspent =
+                bitcoind.client.call("listunspent", &[0.into()]).expect("listunspent");
+            let model = json.into_model().unwrap();
+            assert!(model.0.iter().all(|utxo| utxo.descriptor.is_some()));
+
+            let unconfirmed = model.0.iter().find(|utxo| utxo.txid == txid).expect("unconfirmed");
+            assert_eq!(unconfirmed.ancestor_count, Some(1));
+            assert!(unconfirmed.ancestor_fees.is_some());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_descriptors`
+#[macro_export]
+macro_rules! impl_test_v22__listdescriptors {
+    () => {
+        #[test]
+        fn list_descriptors() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            // createwallet "name" disable_private_keys blank "passphrase" avoid_reuse descriptors
+            let args =
+                ["desc".into(), false.into(), false.into(), "".into(), false.into(), true.into()];
+            let _: serde_json::Value =
+                bitcoind.client.call("createwallet", &args).expect("createwallet");
+
+            let json = bitcoind.client.list_descriptors().expect("listdescriptors");
+            let model = json.into_model();
+            assert_eq!(model.wallet_name, "desc");
+            assert!(!model.descriptors.is_empty());
+            assert!(model.descriptors.iter().any(|d| d.internal == Some(true)));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_wallet_with_options`
+/// - `get_wallet_info`
+#[macro_export]
+macro_rules! impl_test_v22__createwallet_with_options {
+    () => {
+        #[test]
+        fn create_wallet_with_options() {
+            use client::client_sync::v22::CreateWalletOptions;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let wallet = format!("wallet-{}", rand::random::<u32>());
+            let options = CreateWalletOptions::new()
+                .descriptors(true)
+                .disable_private_keys(true)
+                .external_signer(false);
+            let _ = bitcoind
+                .client
+                .create_wallet_with_options(&wallet, &options)
+                .expect("createwallet");
+
+            let json = bitcoind.client.get_wallet_info().expect("getwalletinfo");
+            let model = json.into_model().unwrap();
+            assert!(model.descriptors);
+            assert!(!model.private_keys_enabled);
+        }
+    };
+}
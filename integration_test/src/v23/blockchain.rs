@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v23.2`.
+
+/// Requires `Client` to be in scope and to implement `get_blockchain_info`.
+#[macro_export]
+macro_rules! impl_test_v23__getblockchaininfo {
+    () => {
+        #[test]
+        fn get_blockchain_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_blockchain_info().expect("getblockchaininfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.chain.network(), Some(bitcoin::Network::Regtest));
+            assert!(model.time.is_some());
+            // From v23 softforks are only reported by `getdeploymentinfo`.
+            assert!(model.softforks.is_empty());
+        }
+    };
+}
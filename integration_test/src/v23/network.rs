@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v23.2`.
+
+/// Requires `Client` to be in scope and to implement `get_peer_info`.
+#[macro_export]
+macro_rules! impl_test_v23__getpeerinfo {
+    () => {
+        #[test]
+        fn get_peer_info() {
+            let (bitcoind, _peer) = $crate::bitcoind_with_peer(&[]);
+            let json = bitcoind.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+
+            assert_eq!(model.0.len(), 1);
+            let peer = &model.0[0];
+            assert_eq!(peer.connection_type.as_deref(), Some("inbound"));
+            assert!(peer.addr.socket_addr().expect("peer has an IP address").ip().is_loopback());
+            assert!(peer.addr_relay_enabled.is_some());
+            assert_eq!(peer.transport_protocol_type, None);
+        }
+    };
+}
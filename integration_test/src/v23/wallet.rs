@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v23.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `list_descriptors_private`
+#[macro_export]
+macro_rules! impl_test_v23__listdescriptors_private {
+    () => {
+        #[test]
+        fn list_descriptors_private() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            // createwallet "name" disable_private_keys blank "passphrase" avoid_reuse descriptors
+            let args =
+                ["desc".into(), false.into(), false.into(), "".into(), false.into(), true.into()];
+            let _: serde_json::Value =
+                bitcoind.client.call("createwallet", &args).expect("createwallet");
+
+            let json = bitcoind.client.list_descriptors_private().expect("listdescriptors true");
+            let model = json.into_model();
+            assert!(!model.descriptors.is_empty());
+
+            let item = &model.descriptors[0];
+            assert!(!format!("{:?}", item).contains("prv"));
+            assert!(item.descriptor.expose_secret().contains("tprv"));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_received_by_address`
+/// - `get_received_by_address_with_options`
+/// - `get_received_by_label`
+/// - `get_received_by_label_with_options`
+///
+/// From v23 mature coinbase outputs are included in the amount received, immature ones only if
+/// `include_immature_coinbase` is set.
+#[macro_export]
+macro_rules! impl_test_v23__getreceivedbyaddress_coinbase {
+    () => {
+        #[test]
+        fn get_received_by_address_includes_coinbase() {
+            use bitcoin::Amount;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let json: bitcoind::json::GetNewAddress =
+                bitcoind.client.call("getnewaddress", &["mining".into()]).expect("getnewaddress");
+            let address = json.address().unwrap().assume_checked();
+            // Only the coinbase of the first block has reached maturity (100 confirmations).
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let subsidy = Amount::from_int_btc(50);
+
+            let json =
+                bitcoind.client.get_received_by_address(&address).expect("getreceivedbyaddress");
+            assert_eq!(json.into_model().unwrap().0, subsidy);
+            let json = bitcoind
+                .client
+                .get_received_by_address_with_options(&address, 1, true)
+                .expect("getreceivedbyaddress");
+            assert_eq!(json.into_model().unwrap().0, subsidy * 101);
+
+            let json = bitcoind.client.get_received_by_label("mining").expect("getreceivedbylabel");
+            assert_eq!(json.into_model().unwrap().0, subsidy);
+            let json = bitcoind
+                .client
+                .get_received_by_label_with_options("mining", 1, true)
+                .expect("getreceivedbylabel");
+            assert_eq!(json.into_model().unwrap().0, subsidy * 101);
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_wallet`
+/// - `create_wallet_with_options`
+/// - `list_descriptors`
+/// - `import_descriptors`
+/// - `wallet_create_funded_psbt`
+/// - `wallet_process_psbt`
+/// - `finalize_psbt`
+/// - `send_raw_transaction`
+///
+/// Spends from a watch-only wallet, signing with a separate wallet that holds the private keys.
+/// From v23 `createwallet` creates descriptor wallets by default.
+#[macro_export]
+macro_rules! impl_test_v23__watchonly_scenario {
+    () => {
+        #[test]
+        fn watch_only_wallet_scenario() {
+            use bitcoin::Amount;
+            use client::client_sync::v21::{ImportDescriptorsRequest, ImportTimestamp};
+            use client::client_sync::v17::CreateRawTransactionOutput;
+            use client::client_sync::v22::CreateWalletOptions;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            bitcoind.client.create_wallet("signer").expect("createwallet signer");
+            let options = CreateWalletOptions::new().disable_private_keys(true);
+            let json = bitcoind
+                .client
+                .create_wallet_with_options("watchonly", &options)
+                .expect("createwallet watchonly");
+            assert_eq!(json.name, "watchonly");
+
+            let signer = $crate::wallet_client(&bitcoind, "signer");
+            let watch_only = $crate::wallet_client(&bitcoind, "watchonly");
+
+            // Import the signer's (xpub) descriptors into the watch-only wallet.
+            let json = signer.list_descriptors().expect("listdescriptors");
+            let requests = json
+                .into_model()
+                .descriptors
+                .into_iter()
+                .filter(|d| d.active)
+                .map(|d| {
+                    assert!(d.descriptor.contains("tpub"));
+                    let mut request =
+                        ImportDescriptorsRequest::new(d.descriptor, ImportTimestamp::Now);
+                    request.active = Some(true);
+                    request.internal = d.internal;
+                    request.range = d.range.map(|(start, end)| (start as u32, end as u32));
+                    request
+                })
+                .collect::<Vec<_>>();
+            let json = watch_only.import_descriptors(&requests).expect("importdescriptors");
+            assert!(json.into_model().0.iter().all(|result| result.success));
+
+            // Fund an address of the watch-only wallet, the wallet detects the coins.
+            let address = watch_only.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+            let json = watch_only.list_unspent().expect("listunspent");
+            let utxos = json.into_model().unwrap().0;
+            assert_eq!(utxos.len(), 1);
+            assert!(utxos[0].solvable);
+            assert!(!utxos[0].spendable);
+
+            // Create the spend in the watch-only wallet, sign it in the signing wallet.
+            let recipient = signer.new_address().expect("failed to create new address");
+            let output = CreateRawTransactionOutput::Address(recipient, Amount::ONE_BTC);
+            let json = watch_only
+                .wallet_create_funded_psbt(&[], &[output])
+                .expect("walletcreatefundedpsbt");
+            let psbt = json.into_model().unwrap().psbt;
+
+            let json = watch_only.wallet_process_psbt(&psbt).expect("walletprocesspsbt");
+            assert!(!json.into_model().unwrap().complete);
+
+            let json = signer.wallet_process_psbt(&psbt).expect("walletprocesspsbt");
+            let signed = json.into_model().unwrap();
+            assert!(signed.complete);
+
+            let json = bitcoind.client.finalize_psbt(&signed.psbt).expect("finalizepsbt");
+            let tx = json.into_model().unwrap().tx.expect("complete transaction");
+            let json = bitcoind.client.send_raw_transaction(&tx).expect("sendrawtransaction");
+            let txid = json.txid().unwrap();
+
+            let json = watch_only.get_transaction(txid).expect("gettransaction");
+            assert_eq!(json.into_model().unwrap().txid, txid);
+        }
+    };
+}
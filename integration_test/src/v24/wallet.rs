@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v24.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `bump_fee_with_options`
+/// - `get_transaction`
+#[macro_export]
+macro_rules! impl_test_v24__bumpfee_outputs {
+    () => {
+        #[test]
+        fn bump_fee_replace_outputs() {
+            use bitcoin::Amount;
+            use client::client_sync::v21::{BumpFeeOptions, BumpFeeOutput};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let txid = $crate::send_replaceable(&bitcoind);
+
+            // Replace the payment (and change) with a single, smaller, payment to a new address.
+            let recipient = bitcoind.client.new_address().expect("failed to create new address");
+            let amount = Amount::from_btc(0.5).unwrap();
+            let options = BumpFeeOptions {
+                outputs: vec![BumpFeeOutput::Address(recipient.clone(), amount)],
+                ..Default::default()
+            };
+            let json = bitcoind.client.bump_fee_with_options(txid, &options).expect("bumpfee");
+            let model = json.into_model().unwrap();
+
+            let json = bitcoind.client.get_transaction(model.txid).expect("gettransaction");
+            let tx = json.into_model().unwrap().tx;
+            assert!(tx
+                .output
+                .iter()
+                .any(|out| out.script_pubkey == recipient.script_pubkey() && out.value == amount));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `wallet_process_psbt_with_options`.
+#[macro_export]
+macro_rules! impl_test_v24__walletprocesspsbt {
+    () => {
+        #[test]
+        fn wallet_process_psbt_no_finalize() {
+            use bitcoin::sighash::EcdsaSighashType;
+            use client::client_sync::v17::SighashType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let psbt = $crate::create_funded_psbt(&bitcoind);
+
+            let json = bitcoind
+                .client
+                .wallet_process_psbt_with_options(
+                    &psbt,
+                    true,
+                    SighashType::NonePlusAnyoneCanPay,
+                    true,
+                    false,
+                )
+                .expect("walletprocesspsbt");
+            let model = json.into_model().unwrap();
+            assert!(!model.complete);
+            assert!(model.hex.is_none());
+
+            let input = &model.psbt.inputs[0];
+            assert!(input.final_script_witness.is_none());
+            let sig = input.partial_sigs.values().next().expect("partial signature");
+            assert_eq!(sig.sighash_type, EcdsaSighashType::NonePlusAnyoneCanPay);
+        }
+    };
+}
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v25`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `scan_blocks`
+/// - `scan_blocks_abort`
+/// - `list_descriptors`
+#[macro_export]
+macro_rules! impl_test_v25__scanblocks {
+    () => {
+        #[test]
+        fn scan_blocks() {
+            use std::ops::RangeInclusive;
+
+            use client::client_sync::v17::{ScanObject, ScanRangeError};
+
+            let bitcoind = $crate::bitcoind_with_args(&["-blockfilterindex"]);
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let json =
+                bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+            let relevant = json.into_model().unwrap().0;
+            let other = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(5, &other).expect("generatetoaddress");
+
+            let scan_object = ScanObject::new(format!("addr({})", address));
+            let json = bitcoind.client.scan_blocks(&[scan_object], None, None).expect("scanblocks");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.from_height, 0);
+            assert_eq!(model.to_height, 15);
+            assert!(relevant.iter().all(|hash| model.relevant_blocks.contains(hash)));
+            assert_ne!(model.completed, Some(false));
+
+            // The first address is the first child of the wallet's active receive descriptor.
+            let descriptors = bitcoind.client.list_descriptors().expect("listdescriptors");
+            let receive = descriptors
+                .descriptors
+                .into_iter()
+                .find(|d| d.active && d.internal == Some(false) && d.desc.starts_with("wpkh("))
+                .expect("no active wpkh receive descriptor");
+
+            let scan_object = ScanObject::with_range(receive.desc.clone(), 0..=9).unwrap();
+            let json =
+                bitcoind.client.scan_blocks(&[scan_object], Some(5), None).expect("scanblocks");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.from_height, 5);
+            assert!(relevant[4..].iter().all(|hash| model.relevant_blocks.contains(hash)));
+
+            assert_eq!(
+                ScanObject::with_range(receive.desc.clone(), RangeInclusive::new(9, 0)),
+                Err(ScanRangeError::StartAfterEnd { start: 9, end: 0 })
+            );
+            assert_eq!(
+                ScanObject::with_end(receive.desc, 1 << 31),
+                Err(ScanRangeError::EndTooHigh(1 << 31))
+            );
+
+            assert!(!bitcoind.client.scan_blocks_abort().expect("scanblocks"));
+        }
+    };
+}
@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for `bitcoind v25`.
+
+pub mod blockchain;
+pub mod raw_transactions;
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of `bitcoind v25`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `create_raw_transaction`
+/// - `sign_raw_transaction_with_wallet`
+/// - `send_raw_transaction_with_limits`
+#[macro_export]
+macro_rules! impl_test_v25__sendrawtransaction {
+    () => {
+        #[test]
+        fn send_raw_transaction_with_limits() {
+            use bitcoin::{Amount, FeeRate};
+            use client::client_sync::v17::{CreateRawTransactionInput, CreateRawTransactionOutput};
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            // Spend a mature coinbase output, burning 1 BTC to an `OP_RETURN` output.
+            let json = bitcoind.client.list_unspent().expect("listunspent");
+            let utxo = json.into_model().unwrap().0.remove(0);
+            let input = CreateRawTransactionInput::from(utxo.outpoint());
+            let change = bitcoind.client.new_address().expect("failed to create new address");
+            let change_amount = utxo.amount - Amount::from_sat(100_010_000);
+            let outputs = [
+                CreateRawTransactionOutput::Data(vec![0x00]),
+                CreateRawTransactionOutput::Address(change, change_amount),
+            ];
+            let json = bitcoind
+                .client
+                .create_raw_transaction(&[input], &outputs)
+                .expect("createrawtransaction");
+            let mut unsigned = json.transaction().unwrap();
+            unsigned.output[0].value = Amount::ONE_BTC;
+            assert!(unsigned.output[0].script_pubkey.is_op_return());
+
+            let json = bitcoind
+                .client
+                .sign_raw_transaction_with_wallet(&unsigned)
+                .expect("signrawtransactionwithwallet");
+            let tx = json.into_model().unwrap().tx;
+
+            let max_fee_rate = FeeRate::from_sat_per_vb(1_000).unwrap();
+            let _ = bitcoind
+                .client
+                .send_raw_transaction_with_limits(&tx, max_fee_rate, Amount::ZERO)
+                .expect_err("burn amount above the maximum");
+
+            let json = bitcoind
+                .client
+                .send_raw_transaction_with_limits(&tx, max_fee_rate, Amount::ONE_BTC)
+                .expect("sendrawtransaction");
+            assert_eq!(json.txid().unwrap(), tx.compute_txid());
+        }
+    };
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement `get_blockchain_info`.
+#[macro_export]
+macro_rules! impl_test_v26__getblockchaininfo {
+    () => {
+        #[test]
+        fn get_blockchain_info() {
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_blockchain_info().expect("getblockchaininfo");
+            let model = json.into_model().unwrap();
+            assert!(model.time.is_some());
+            assert!(model.softforks.is_empty());
+            assert!(model.warnings.iter().all(|w| !w.is_empty()));
+        }
+
+        #[test]
+        fn get_blockchain_info_warnings_array() {
+            // Later versions of Core return `warnings` as an array, use the live result as a fixture.
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let mut value: serde_json::Value =
+                bitcoind.client.call("getblockchaininfo", &[]).expect("getblockchaininfo");
+
+            value["warnings"] = serde_json::json!("");
+            let json: bitcoind::json::GetBlockchainInfo = serde_json::from_value(value.clone()).unwrap();
+            assert!(json.into_model().unwrap().warnings.is_empty());
+
+            value["warnings"] = serde_json::json!(["first warning", "second warning"]);
+            let json: bitcoind::json::GetBlockchainInfo = serde_json::from_value(value).unwrap();
+            let model = json.into_model().unwrap();
+            assert_eq!(model.warnings, vec!["first warning", "second warning"]);
+        }
+
+        #[test]
+        fn get_blockchain_info_versioned() {
+            use client::json::model::{self, schema};
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_blockchain_info().expect("getblockchaininfo");
+            let model = json.into_model().unwrap();
+
+            let s = schema::to_versioned_string(&model).unwrap();
+            let envelope: serde_json::Value = serde_json::from_str(&s).unwrap();
+            assert_eq!(envelope["schema_version"], model::MODEL_SCHEMA_VERSION);
+            let read: model::GetBlockchainInfo = schema::from_versioned_str(&s).unwrap();
+            assert_eq!(read, model);
+
+            // Schema version 1 used `bitcoin::Network` names and a single warnings string.
+            let mut v1 = serde_json::to_value(&model).unwrap();
+            v1["chain"] = serde_json::json!("bitcoin");
+            v1["warnings"] = serde_json::json!("a warning");
+            v1.as_object_mut().unwrap().remove("time");
+            let envelope = serde_json::json!({ "schema_version": 1, "payload": v1 });
+            let read: model::GetBlockchainInfo =
+                schema::from_versioned_str(&envelope.to_string()).unwrap();
+            assert_eq!(read.chain, model::Chain::Main);
+            assert_eq!(read.warnings, vec!["a warning"]);
+            assert_eq!(read.time, None);
+
+            let envelope = serde_json::json!({ "schema_version": 99, "payload": {} });
+            assert!(schema::from_versioned_str::<model::GetBlockchainInfo>(&envelope.to_string())
+                .is_err());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `dump_tx_out_set`.
+#[macro_export]
+macro_rules! impl_test_v26__dumptxoutset {
+    () => {
+        #[test]
+        fn dump_tx_out_set() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let path = bitcoind.workdir().join("utxo.dat");
+            let json =
+                bitcoind.client.dump_tx_out_set(path.to_str().unwrap()).expect("dumptxoutset");
+            let model = json.into_model().unwrap();
+
+            assert_eq!(model.base_height, 101);
+            assert_eq!(model.base_hash, bitcoind.client.best_block_hash().unwrap());
+            // One spendable output per coinbase, genesis is not part of the UTXO set.
+            assert_eq!(model.coins_written, 101);
+            assert_eq!(model.n_chain_tx, 102);
+            assert!(path.exists());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_chain_states`.
+#[macro_export]
+macro_rules! impl_test_v26__getchainstates {
+    () => {
+        #[test]
+        fn get_chain_states() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(10, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.get_chain_states().expect("getchainstates");
+            let model = json.into_model().unwrap();
+
+            assert_eq!(model.headers, 10);
+            assert!(model.is_fully_validated());
+            assert!(model.snapshot().is_none());
+            let active = model.active().unwrap();
+            assert_eq!(active.blocks, 10);
+            assert_eq!(active.best_block_hash, bitcoind.client.best_block_hash().unwrap());
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `load_tx_out_set`.
+///
+/// Runs the assumeutxo workflow up to loading the snapshot. Core only accepts snapshots whose
+/// base block is in its chain parameters, on regtest that is the chain created by Core's
+/// functional tests so loading a snapshot of the chain mined here is rejected. Loading a snapshot
+/// successfully is not tested.
+#[macro_export]
+macro_rules! impl_test_v26__assumeutxo {
+    () => {
+        #[test]
+        fn assumeutxo_rejects_unknown_snapshot() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to create new address");
+            let _ = bitcoind.client.generate_to_address(110, &address).expect("generatetoaddress");
+
+            let path = bitcoind.workdir().join("utxo.dat");
+            let snapshot = bitcoind.dump_tx_out_set(&path).expect("dumptxoutset");
+
+            let node = $crate::bitcoind_no_wallet();
+            node.submit_headers_from(&bitcoind, snapshot.base_height).expect("submitheader");
+            let states = node.chain_states().expect("getchainstates");
+            assert_eq!(states.headers, snapshot.base_height);
+            assert_eq!(states.active().unwrap().blocks, 0);
+
+            let err = node.client.load_tx_out_set(path.to_str().unwrap()).unwrap_err();
+            assert!(err.to_string().contains("not recognized"), "{}", err);
+            assert!(node.chain_states().unwrap().snapshot().is_none());
+        }
+    };
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement `get_mining_info`.
+#[macro_export]
+macro_rules! impl_test_v26__getmininginfo {
+    () => {
+        #[test]
+        fn get_mining_info() {
+            use client::json::model;
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_mining_info().expect("getmininginfo");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.chain, model::Chain::Regtest);
+            assert!(model.warnings.iter().all(|w| !w.is_empty()));
+            if let Some(target) = model.current_target() {
+                assert_eq!(target, bitcoin::Target::MAX_ATTAINABLE_REGTEST);
+            }
+        }
+    };
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for `bitcoind v26.2`.
+
+pub mod blockchain;
+pub mod mining;
+pub mod network;
+pub mod wallet;
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_peer_info`
+/// - `get_network_info`
+#[macro_export]
+macro_rules! impl_test_v26__getpeerinfo {
+    () => {
+        #[test]
+        fn get_peer_info() {
+            let (bitcoind, _peer) = $crate::bitcoind_with_peer(&[]);
+            let json = bitcoind.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+
+            assert_eq!(model.0.len(), 1);
+            let peer = &model.0[0];
+            assert_eq!(peer.connection_type.as_deref(), Some("inbound"));
+            assert!(peer.addr.socket_addr().expect("peer has an IP address").ip().is_loopback());
+            assert!(peer.addr_relay_enabled.is_some());
+            // BIP-324 transport is not enabled by default in v26.
+            assert_eq!(peer.transport_protocol_type.as_deref(), Some("v1"));
+        }
+
+        #[test]
+        fn get_peer_info_versioned() {
+            use client::json::model::{self, schema};
+
+            let (bitcoind, _peer) = $crate::bitcoind_with_peer(&[]);
+            let json = bitcoind.client.get_peer_info().expect("getpeerinfo");
+            let model = json.into_model().unwrap();
+
+            let s = schema::to_versioned_string(&model).unwrap();
+            let read: model::GetPeerInfo = schema::from_versioned_str(&s).unwrap();
+            assert_eq!(read, model);
+
+            // Schema version 3 used a string address and did not have the newer fields.
+            let mut v3 = serde_json::to_value(&model).unwrap();
+            let peer = v3[0].as_object_mut().unwrap();
+            peer.insert("addr".to_owned(), model.0[0].addr.to_string().into());
+            let added = ["relay_txes", "bytes_sent", "bytes_recv", "starting_height", "permissions"];
+            for field in added {
+                peer.remove(field);
+            }
+            let envelope = serde_json::json!({ "schema_version": 3, "payload": v3 });
+            let read: model::GetPeerInfo =
+                schema::from_versioned_str(&envelope.to_string()).unwrap();
+            assert_eq!(read.0[0].addr, model.0[0].addr);
+            assert!(read.0[0].permissions.is_empty());
+        }
+
+        #[test]
+        fn get_network_info_versioned() {
+            use client::json::model::{self, schema};
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            let json = bitcoind.client.get_network_info().expect("getnetworkinfo");
+            let model = json.into_model().unwrap();
+
+            // Schema version 3 used a string proxy, empty if there is none.
+            let mut v3 = serde_json::to_value(&model).unwrap();
+            for network in v3["networks"].as_array_mut().unwrap() {
+                network["proxy"] = "".into();
+            }
+            v3["networks"][0]["proxy"] = "127.0.0.1:9050".into();
+            let envelope = serde_json::json!({ "schema_version": 3, "payload": v3 });
+            let read: model::GetNetworkInfo =
+                schema::from_versioned_str(&envelope.to_string()).unwrap();
+            let proxy = read.networks[0].proxy.as_ref().expect("proxy");
+            assert_eq!(proxy.to_string(), "127.0.0.1:9050");
+            assert!(read.networks[1..].iter().all(|network| network.proxy.is_none()));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `get_raw_addr_man`.
+#[macro_export]
+macro_rules! impl_test_v26__getrawaddrman {
+    () => {
+        #[test]
+        fn get_raw_addr_man() {
+            use client::json::model::{AddrManPosition, NetworkType};
+
+            let bitcoind = $crate::bitcoind_no_wallet();
+            // `addpeeraddress` is a hidden, test only, method.
+            let _: serde_json::Value = bitcoind
+                .client
+                .call("addpeeraddress", &["1.2.3.4".into(), 8333.into()])
+                .expect("addpeeraddress");
+            let _: serde_json::Value = bitcoind
+                .client
+                .call("addpeeraddress", &["2a00:1450:4001::1".into(), 8333.into(), true.into()])
+                .expect("addpeeraddress");
+
+            let json = bitcoind.client.get_raw_addr_man().expect("getrawaddrman");
+            let model = json.into_model().unwrap();
+            assert_eq!(model.new.len(), 1);
+            assert_eq!(model.tried.len(), 1);
+
+            let (position, entry) = model.new.iter().next().unwrap();
+            let parsed: AddrManPosition = position.to_string().parse().unwrap();
+            assert_eq!(parsed, *position);
+            assert_eq!(entry.address, "1.2.3.4");
+            assert_eq!(entry.port, 8333);
+            assert_eq!(entry.network, NetworkType::Ipv4);
+
+            let entry = model.tried.values().next().unwrap();
+            assert_eq!(entry.network, NetworkType::Ipv6);
+        }
+    };
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of `bitcoind v26.2`.
+
+/// Requires `Client` to be in scope and to implement:
+/// - `get_balances`
+/// - `best_block_hash`
+#[macro_export]
+macro_rules! impl_test_v26__getbalances {
+    () => {
+        #[test]
+        fn get_balances_last_processed_block() {
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let _ = bitcoind.client.generate_to_address(101, &address).expect("generatetoaddress");
+
+            let json = bitcoind.client.get_balances().expect("getbalances");
+            let model = json.into_model().unwrap();
+            let block = model.last_processed_block.expect("lastprocessedblock");
+            assert_eq!(block.height, 101);
+            assert_eq!(block.hash, bitcoind.client.best_block_hash().expect("best_block_hash"));
+        }
+    };
+}
+
+/// Requires `Client` to be in scope and to implement `wallet_process_psbt_with_options`.
+#[macro_export]
+macro_rules! impl_test_v26__walletprocesspsbt {
+    () => {
+        #[test]
+        fn wallet_process_psbt_hex() {
+            use client::client_sync::v17::SighashType;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let psbt = $crate::create_funded_psbt(&bitcoind);
+
+            let json = bitcoind
+                .client
+                .wallet_process_psbt_with_options(&psbt, true, SighashType::Default, true, true)
+                .expect("walletprocesspsbt");
+            let model = json.into_model().unwrap();
+            assert!(model.complete);
+            // The network transaction is only returned if complete.
+            let tx = model.hex.expect("complete psbt includes hex");
+            assert_eq!(tx.compute_txid(), psbt.unsigned_tx.compute_txid());
+        }
+    };
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of the forthcoming `bitcoind v28`.
+
+/// Requires `Client` to be in scope and to implement `get_descriptor_activity`.
+#[macro_export]
+macro_rules! impl_test_v28__getdescriptoractivity {
+    () => {
+        #[test]
+        fn get_descriptor_activity() {
+            use client::json::v28::ActivityEntry;
+
+            let bitcoind = $crate::bitcoind_with_default_wallet();
+            let address = bitcoind.client.new_address().expect("failed to get new address");
+            let hashes =
+                bitcoind.client.generate_to_address(1, &address).expect("generatetoaddress");
+            let block_hash = hashes.into_model().unwrap().0[0];
+
+            let descriptor = format!("addr({})", address);
+            let json = bitcoind
+                .client
+                .get_descriptor_activity(&[block_hash], &[&descriptor])
+                .expect("getdescriptoractivity");
+
+            // The coinbase output is the only activity.
+            assert_eq!(json.activity.len(), 1);
+            match json.activity[0] {
+                ActivityEntry::Receive(ref receive) => {
+                    assert_eq!(receive.block_hash, Some(block_hash.to_string()));
+                    assert_eq!(receive.amount, 50.0);
+                }
+                ActivityEntry::Spend(_) => panic!("expected a receive entry"),
+            }
+        }
+    };
+}
@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing test methods on a JSON-RPC client for the forthcoming `bitcoind v28`.
+
+pub mod blockchain;
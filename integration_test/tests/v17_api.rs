@@ -12,13 +12,32 @@ mod blockchain {
     impl_test_v17__getbestblockhash!();
     impl_test_v17__getblock_verbosity_0!();
     impl_test_v17__getblock_verbosity_1!();
+    impl_test_v17__getblock_verbosity_2!();
+    impl_test_v17__getblockheader!();
+    impl_test_v17__getchaintips!();
+    impl_test_v17__invalidateblock!();
+    impl_test_v17__preciousblock!();
+    impl_test_v17__getblock_fee_summary!();
+    impl_test_v17__gettxout!();
+    impl_test_v17__gettxoutproof!();
+    impl_test_v17__txfee!();
+    impl_test_v17__verifychain!();
+    impl_test_v17__gettxoutsetinfo!();
+    impl_test_v17__scantxoutset!();
+    impl_test_v17__getrawmempool!();
+    impl_test_v17__getmempoolinfo!();
+    impl_test_v17__mempool_candidates!();
 }
 
 // == Control ==
 mod control {
     use super::*;
 
+    impl_test_v17__getmemoryinfo!();
+
+    impl_test_v17__logging!();
     impl_test_v17__stop!();
+    impl_test_v17__setmocktime!();
 }
 
 // == Generating ==
@@ -28,31 +47,126 @@ mod generating {
     impl_test_v17__generatetoaddress!();
 }
 
+// == Mining ==
+mod mining {
+    use super::*;
+
+    impl_test_v17__getmininginfo!();
+
+    impl_test_v17__getblocktemplate!();
+}
+
 // == Network ==
 mod network {
     use super::*;
 
     impl_test_v17__getnetworkinfo!();
+    impl_test_v17__getpeerinfo!();
 }
 
 // == Rawtransactions ==
 mod raw_transactions {
     use super::*;
 
+    impl_test_v17__combinepsbt!();
+    impl_test_v17__createrawtransaction!();
+    impl_test_v17__fundrawtransaction!();
+    impl_test_v17__getrawtransaction!();
+    impl_test_v17__decodepsbt!();
+    impl_test_v17__decoderawtransaction!();
+    impl_test_v17__finalizepsbt!();
     impl_test_v17__sendrawtransaction!();
+    impl_test_v17__testmempoolaccept!();
+}
+
+// == Util ==
+mod util {
+    use super::*;
+
+    impl_test_v17__estimatesmartfee!();
 }
 
 // == Wallet ==
 mod wallet {
     use super::*;
 
+    impl_test_v17__addmultisigaddress!();
     impl_test_v17__createwallet!();
+    impl_test_v17__createwallet_with_options!();
     impl_test_v17__loadwallet!();
     // impl_test_v17__unloadwallet!();
 
     impl_test_v17__getnewaddress!();
+    impl_test_v17__getrawchangeaddress!();
+    impl_test_v17__getaddressinfo!();
+    impl_test_v17__getwalletinfo!();
     impl_test_v17__getbalance!();
     impl_test_v17__sendtoaddress!();
+    impl_test_v17__notify!();
+    impl_test_v17__sendtoaddress_with!();
+    impl_test_v17__bitcoind_rpc!();
+    impl_test_v17__accounts!();
     impl_test_v17__gettransaction!();
+    impl_test_v17__listtransactions!();
+    impl_test_v17__listsinceblock!();
+    impl_test_v17__importmulti!();
+    impl_test_v17__listreceivedbyaddress!();
+    impl_test_v17__listunspent!();
+    impl_test_v17__lockunspent!();
+    impl_test_v17__psbtinputsforunspent!();
+    impl_test_v17__reconcile_transaction!();
+    impl_test_v17__getaddressesbylabel!();
+    impl_test_v17__getreceivedbyaddress!();
+    impl_test_v17__getreceivedbyaddress_coinbase!();
+    impl_test_v17__bumpfee!();
+    impl_test_v17__walletcreatefundedpsbt!();
+    impl_test_v17__walletcreatefundedpsbt_options!();
+    impl_test_v17__walletprocesspsbt!();
+    impl_test_v17__listwallets!();
+    impl_test_v17__encryptwallet!();
+    impl_test_v17__signrawtransactionwithwallet!();
+    impl_test_v17__signmessage!();
+    impl_test_v17__importaddress!();
+}
+
+// == Block fetcher ==
+mod block_fetcher {
+    use super::*;
+
+    impl_test_block_fetcher!();
 }
 
+// == Export ==
+mod export {
+    use super::*;
+
+    impl_test_export!();
+}
+
+// == Async client ==
+mod client_async {
+    use super::*;
+
+    impl_test_client_async!(v17);
+}
+
+// == Tokio bridge ==
+mod tokio_bridge {
+    use super::*;
+
+    impl_test_tokio_bridge!(v17);
+}
+
+// == Connection profiles ==
+mod profile {
+    use super::*;
+
+    impl_test_client_profile!(v17);
+}
+
+// == Client builder ==
+mod builder {
+    use super::*;
+
+    impl_test_client_builder!(v17);
+}
@@ -8,17 +8,40 @@ use integration_test::*;
 mod blockchain {
     use super::*;
 
-    impl_test_v17__getblockchaininfo!();
+    impl_test_v19__getblockchaininfo!();
+    impl_test_v21__getblockchaininfo_signet!();
     impl_test_v17__getbestblockhash!();
     impl_test_v17__getblock_verbosity_0!();
     impl_test_v17__getblock_verbosity_1!();
+    impl_test_v17__getblock_verbosity_2!();
+    impl_test_v17__getblockheader!();
+    impl_test_v17__getchaintips!();
+    impl_test_v17__invalidateblock!();
+    impl_test_v17__preciousblock!();
+    impl_test_v17__getblock_fee_summary!();
+    impl_test_v17__gettxout!();
+    impl_test_v17__gettxoutproof!();
+    impl_test_v17__txfee!();
+    impl_test_v17__verifychain!();
+    impl_test_v17__gettxoutsetinfo!();
+    impl_test_v17__scantxoutset!();
+    impl_test_v21__gettxoutsetinfo_muhash!();
+    impl_test_v17__getrawmempool!();
+    impl_test_v17__getmempoolinfo!();
+    impl_test_v17__mempool_candidates!();
+    impl_test_v21__getindexinfo!();
+    impl_test_v22__gettxoutsetinfo!();
 }
 
 // == Control ==
 mod control {
     use super::*;
 
+    impl_test_v17__getmemoryinfo!();
+
+    impl_test_v17__logging!();
     impl_test_v17__stop!();
+    impl_test_v17__setmocktime!();
 }
 
 // == Generating ==
@@ -26,6 +49,17 @@ mod generating {
     use super::*;
 
     impl_test_v17__generatetoaddress!();
+    impl_test_v20__generatetodescriptor!();
+    impl_test_v21__generateblock!();
+}
+
+// == Mining ==
+mod mining {
+    use super::*;
+
+    impl_test_v17__getmininginfo!();
+
+    impl_test_v17__getblocktemplate!();
 }
 
 // == Network ==
@@ -33,25 +67,125 @@ mod network {
     use super::*;
 
     impl_test_v17__getnetworkinfo!();
+    impl_test_v22__getpeerinfo!();
+    impl_test_v18__getnodeaddresses!();
+    impl_test_v22__getnodeaddresses!();
 }
 
 // == Rawtransactions ==
 mod raw_transactions {
     use super::*;
 
+    impl_test_v17__combinepsbt!();
+    impl_test_v17__createrawtransaction!();
+    impl_test_v17__fundrawtransaction!();
+    impl_test_v17__getrawtransaction!();
+    impl_test_v17__decodepsbt!();
+    impl_test_v17__decoderawtransaction!();
+    impl_test_v17__finalizepsbt!();
     impl_test_v17__sendrawtransaction!();
+    impl_test_v17__testmempoolaccept!();
+    impl_test_v19__sendrawtransaction!();
+}
+
+// == Util ==
+mod util {
+    use super::*;
+
+    impl_test_v17__estimatesmartfee!();
+    impl_test_v18__getdescriptorinfo!();
 }
 
 // == Wallet ==
 mod wallet {
     use super::*;
 
+    impl_test_v20__addmultisigaddress!();
     impl_test_v17__createwallet!();
+    impl_test_v22__createwallet_with_options!();
     impl_test_v17__loadwallet!();
 
     impl_test_v17__getnewaddress!();
+    impl_test_v17__getrawchangeaddress!();
+    impl_test_v17__getaddressinfo!();
+    impl_test_v17__getwalletinfo!();
     impl_test_v17__getbalance!();
     impl_test_v19__getbalances!();
+    impl_test_v19__getbalances_avoid_reuse!();
+    impl_test_v19__getwalletinfo!();
     impl_test_v17__sendtoaddress!();
+    impl_test_v17__notify!();
+    impl_test_v21__sendtoaddress_with!();
+    impl_test_v17__bitcoind_rpc!();
     impl_test_v17__gettransaction!();
+    impl_test_v19__gettransaction_verbose!();
+    impl_test_v17__listtransactions!();
+    impl_test_v17__listsinceblock!();
+    impl_test_v17__importmulti!();
+    impl_test_v17__listreceivedbyaddress!();
+    impl_test_v22__listunspent!();
+    impl_test_v17__lockunspent!();
+    impl_test_v17__psbtinputsforunspent!();
+    impl_test_v17__reconcile_transaction!();
+    impl_test_v17__getaddressesbylabel!();
+    impl_test_v17__getreceivedbyaddress!();
+    impl_test_v17__getreceivedbyaddress_coinbase!();
+    impl_test_v18__getreceivedbylabel!();
+    impl_test_v17__bumpfee!();
+    impl_test_v21__bumpfee!();
+    impl_test_v21__psbtbumpfee!();
+    impl_test_v17__walletcreatefundedpsbt!();
+    impl_test_v21__walletcreatefundedpsbt_options!();
+    impl_test_v17__walletprocesspsbt!();
+    impl_test_v17__listwallets!();
+    impl_test_v17__encryptwallet!();
+    impl_test_v18__listwalletdir!();
+    impl_test_v21__importdescriptors!();
+    impl_test_v21__send!();
+    impl_test_v22__listdescriptors!();
+    impl_test_v17__signrawtransactionwithwallet!();
+    impl_test_v17__signmessage!();
+    impl_test_v17__importaddress!();
+}
+
+// == Block fetcher ==
+mod block_fetcher {
+    use super::*;
+
+    impl_test_block_fetcher!();
+}
+
+// == Export ==
+mod export {
+    use super::*;
+
+    impl_test_export!();
+}
+
+// == Async client ==
+mod client_async {
+    use super::*;
+
+    impl_test_client_async!(v22);
+}
+
+// == Tokio bridge ==
+mod tokio_bridge {
+    use super::*;
+
+    impl_test_tokio_bridge!(v22);
+}
+
+// == Connection profiles ==
+mod profile {
+    use super::*;
+
+    impl_test_client_profile!(v22);
+}
+
+// == Client builder ==
+mod builder {
+    use super::*;
+
+    impl_test_client_builder!(v22);
 }
@@ -18,6 +18,132 @@ pub mod v23;
 pub mod v24;
 pub mod v25;
 pub mod v26;
+#[cfg(feature = "unstable-v28")]
+pub mod v28;
 
 // JSON types that model _all_ `bitcoind` versions.
 pub mod model;
+pub mod serde_outpoint;
+
+use core::fmt;
+use core::str::FromStr;
+
+use bitcoin::{amount, Amount, FeeRate};
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+/// The `warnings` field returned by a number of JSON-RPC methods.
+///
+/// Earlier versions of Bitcoin Core return a single string (empty if there are no warnings), later
+/// versions return an array of strings. This type can be deserialized from either form.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Warnings {
+    /// A single warning string, empty if there are no warnings.
+    Single(String),
+    /// A list of warnings.
+    Multiple(Vec<String>),
+}
+
+impl Warnings {
+    /// Converts the warnings into a list, an empty single warning becomes an empty list.
+    pub fn into_model(self) -> Vec<String> {
+        match self {
+            Warnings::Single(s) if s.is_empty() => vec![],
+            Warnings::Single(s) => vec![s],
+            Warnings::Multiple(v) => v,
+        }
+    }
+}
+
+impl From<String> for Warnings {
+    fn from(s: String) -> Self { Warnings::Single(s) }
+}
+
+/// A number that Core returns as a JSON number or as a string, depending on the version or
+/// configuration (e.g. output indexes and fees).
+///
+/// Versioned types keep the numeric field type and absorb the difference during deserialization
+/// using [`deserialize_number_or_string`] (or [`deserialize_optional_number_or_string`]):
+///
+/// ```
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Utxo {
+///     #[serde(deserialize_with = "bitcoind_json_rpc_types::deserialize_number_or_string")]
+///     vout: u32,
+/// }
+///
+/// let a: Utxo = serde_json::from_str(r#"{ "vout": 1 }"#).unwrap();
+/// let b: Utxo = serde_json::from_str(r#"{ "vout": "1" }"#).unwrap();
+/// assert_eq!(a.vout, b.vout);
+/// assert!(serde_json::from_str::<Utxo>(r#"{ "vout": "one" }"#).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum NumberOrString<T> {
+    /// The value as a JSON number.
+    Number(T),
+    /// The value as a string e.g., `"1"`.
+    String(String),
+}
+
+impl<T: FromStr> NumberOrString<T> {
+    /// Returns the number, parsing the string form.
+    pub fn into_number(self) -> Result<T, T::Err> {
+        match self {
+            NumberOrString::Number(n) => Ok(n),
+            NumberOrString::String(s) => s.trim().parse(),
+        }
+    }
+}
+
+impl<T> From<T> for NumberOrString<T> {
+    fn from(n: T) -> Self { NumberOrString::Number(n) }
+}
+
+/// Deserializes a number sent as a JSON number or as a string, see [`NumberOrString`].
+pub fn deserialize_number_or_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: fmt::Display,
+{
+    NumberOrString::<T>::deserialize(deserializer)?.into_number().map_err(de::Error::custom)
+}
+
+/// Deserializes an optional number sent as a JSON number or as a string, see [`NumberOrString`].
+///
+/// The field must also be annotated with `#[serde(default)]` if it may be missing.
+pub fn deserialize_optional_number_or_string<'de, D, T>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: fmt::Display,
+{
+    match Option::<NumberOrString<T>>::deserialize(deserializer)? {
+        Some(n) => n.into_number().map(Some).map_err(de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+/// Converts a fee rate in BTC per 1000 virtual bytes (BTC/kvB, as returned by Core) to a `FeeRate`.
+///
+/// Core uses BTC/kvB for all fee rates (e.g. `estimatesmartfee`, `getmempoolinfo`), sub-satoshi
+/// per weight unit precision is lost.
+///
+/// ```
+/// # use bitcoind_json_rpc_types::fee_rate_from_btc_per_kvb;
+/// use bitcoind_json_rpc_types::bitcoin::FeeRate;
+///
+/// // 0.00001 BTC/kvB is Core's default minimum relay fee rate.
+/// let rate = fee_rate_from_btc_per_kvb(0.00001).unwrap();
+/// assert_eq!(rate, FeeRate::from_sat_per_vb(1).unwrap());
+/// assert_eq!(fee_rate_from_btc_per_kvb(0.0002).unwrap().to_sat_per_vb_floor(), 20);
+/// assert!(fee_rate_from_btc_per_kvb(-1.0).is_err());
+/// ```
+pub fn fee_rate_from_btc_per_kvb(btc_kvb: f64) -> Result<FeeRate, amount::ParseAmountError> {
+    let sat_kvb = Amount::from_btc(btc_kvb)?.to_sat();
+    Ok(FeeRate::from_sat_per_kwu(sat_kvb / 4))
+}
@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Fee and subsidy analytics of blocks returned by `getblock` with verbosity set to 2.
+//!
+//! Core does not include the fee of each transaction in the verbose `getblock` result of all
+//! versions. The total fees of a block can always be derived from the coinbase transaction and the
+//! block subsidy, per transaction fees (and hence fee rate percentiles) need the previous outputs
+//! spent by the block, see [`BlockFeeSummary::with_prevouts`].
+
+use core::fmt;
+
+use bitcoin::{Amount, FeeRate, Network, OutPoint, Transaction, TxOut, Weight};
+use serde::{Deserialize, Serialize};
+
+use crate::model::GetBlockVerbosityTwo;
+
+/// The initial block subsidy, 50 BTC.
+const INITIAL_SUBSIDY: Amount = Amount::from_sat(50 * 100_000_000);
+
+/// The number of blocks between subsidy halvings on regtest.
+const REGTEST_HALVING_INTERVAL: u64 = 150;
+
+/// Returns the block subsidy (excluding fees) of a block at `height` on `network`.
+pub fn block_subsidy(height: u64, network: Network) -> Amount {
+    let interval = match network {
+        Network::Regtest => REGTEST_HALVING_INTERVAL,
+        _ => u64::from(bitcoin::constants::SUBSIDY_HALVING_INTERVAL),
+    };
+    let halvings = height / interval;
+    // The subsidy is zero after 64 halvings (right shifting by 64 or more is undefined).
+    if halvings >= 64 {
+        return Amount::ZERO;
+    }
+    Amount::from_sat(INITIAL_SUBSIDY.to_sat() >> halvings)
+}
+
+/// Fee and subsidy summary of a block.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BlockFeeSummary {
+    /// The block height.
+    pub height: u64,
+    /// The block subsidy (excluding fees).
+    pub subsidy: Amount,
+    /// The total value of the coinbase outputs.
+    pub coinbase_value: Amount,
+    /// The total fees paid by the transactions in the block.
+    ///
+    /// Without previous outputs this is derived as `coinbase_value - subsidy`, which is a lower
+    /// bound if the miner did not claim all fees.
+    pub total_fee: Amount,
+    /// The number of transactions in the block, excluding the coinbase transaction.
+    pub tx_count: usize,
+    /// The number of transactions (excluding the coinbase transaction) that have witness data.
+    pub segwit_tx_count: usize,
+    /// The total weight of the transactions that have witness data.
+    pub segwit_weight: Weight,
+    /// The total weight of all transactions, excluding the coinbase transaction.
+    pub total_weight: Weight,
+    /// Weighted fee rate percentiles, only available if the previous outputs were provided.
+    pub fee_rate_percentiles: Option<FeeRatePercentiles>,
+}
+
+/// Fee rate percentiles of the transactions in a block, weighted by transaction weight.
+///
+/// These are the same percentiles as reported by `getblockstats`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FeeRatePercentiles {
+    /// The 10th percentile.
+    pub p10: FeeRate,
+    /// The 25th percentile.
+    pub p25: FeeRate,
+    /// The 50th percentile (median).
+    pub p50: FeeRate,
+    /// The 75th percentile.
+    pub p75: FeeRate,
+    /// The 90th percentile.
+    pub p90: FeeRate,
+}
+
+impl BlockFeeSummary {
+    /// Summarizes `block` without previous outputs, fee rate percentiles are not available.
+    pub fn new(block: &GetBlockVerbosityTwo, network: Network) -> Self {
+        let mut summary = Self::base(block, network);
+        summary.total_fee =
+            summary.coinbase_value.checked_sub(summary.subsidy).unwrap_or(Amount::ZERO);
+        summary
+    }
+
+    /// Summarizes `block` using `prevout` to look up the outputs spent by its transactions.
+    ///
+    /// The total fees and fee rate percentiles are computed from the fee of each transaction.
+    pub fn with_prevouts<F>(
+        block: &GetBlockVerbosityTwo,
+        network: Network,
+        mut prevout: F,
+    ) -> Result<Self, BlockFeeSummaryError>
+    where
+        F: FnMut(&OutPoint) -> Option<TxOut>,
+    {
+        let mut summary = Self::base(block, network);
+
+        let mut rates = Vec::with_capacity(summary.tx_count);
+        let mut total_fee = Amount::ZERO;
+        for tx in block.tx.iter().filter(|tx| !tx.is_coinbase()) {
+            let fee = tx_fee(tx, &mut prevout)?;
+            total_fee = total_fee.checked_add(fee).ok_or(BlockFeeSummaryError::Overflow)?;
+            rates.push((fee_rate(fee, tx.weight()), tx.weight()));
+        }
+        summary.total_fee = total_fee;
+        summary.fee_rate_percentiles = FeeRatePercentiles::from_rates(rates);
+
+        Ok(summary)
+    }
+
+    /// Returns the fraction of transactions (excluding the coinbase) that have witness data.
+    ///
+    /// Returns `None` if the block only contains the coinbase transaction.
+    pub fn segwit_tx_ratio(&self) -> Option<f64> {
+        if self.tx_count == 0 {
+            return None;
+        }
+        Some(self.segwit_tx_count as f64 / self.tx_count as f64)
+    }
+
+    /// Returns the fraction of the transaction weight (excluding the coinbase) used by segwit
+    /// transactions.
+    ///
+    /// Returns `None` if the block only contains the coinbase transaction.
+    pub fn segwit_weight_ratio(&self) -> Option<f64> {
+        if self.total_weight == Weight::ZERO {
+            return None;
+        }
+        Some(self.segwit_weight.to_wu() as f64 / self.total_weight.to_wu() as f64)
+    }
+
+    /// Returns the summary without any fee information.
+    fn base(block: &GetBlockVerbosityTwo, network: Network) -> Self {
+        let height = block.height as u64;
+        let coinbase_value = block
+            .tx
+            .iter()
+            .find(|tx| tx.is_coinbase())
+            .map(|tx| tx.output.iter().map(|txout| txout.value).sum())
+            .unwrap_or(Amount::ZERO);
+
+        let mut tx_count = 0;
+        let mut segwit_tx_count = 0;
+        let mut segwit_weight = Weight::ZERO;
+        let mut total_weight = Weight::ZERO;
+        for tx in block.tx.iter().filter(|tx| !tx.is_coinbase()) {
+            tx_count += 1;
+            total_weight += tx.weight();
+            if tx.input.iter().any(|txin| !txin.witness.is_empty()) {
+                segwit_tx_count += 1;
+                segwit_weight += tx.weight();
+            }
+        }
+
+        BlockFeeSummary {
+            height,
+            subsidy: block_subsidy(height, network),
+            coinbase_value,
+            total_fee: Amount::ZERO,
+            tx_count,
+            segwit_tx_count,
+            segwit_weight,
+            total_weight,
+            fee_rate_percentiles: None,
+        }
+    }
+}
+
+impl FeeRatePercentiles {
+    /// Computes the weighted percentiles the same way as Core's `getblockstats`.
+    ///
+    /// Returns `None` if `rates` is empty.
+    fn from_rates(mut rates: Vec<(FeeRate, Weight)>) -> Option<Self> {
+        if rates.is_empty() {
+            return None;
+        }
+        rates.sort();
+
+        let total_weight = rates.iter().map(|(_, weight)| weight.to_wu()).sum::<u64>() as f64;
+        let thresholds = [0.10, 0.25, 0.50, 0.75, 0.90].map(|p| total_weight * p);
+
+        let mut result = [FeeRate::ZERO; 5];
+        let mut next = 0;
+        let mut cumulative = 0;
+        for (rate, weight) in rates.iter() {
+            cumulative += weight.to_wu();
+            while next < thresholds.len() && cumulative as f64 >= thresholds[next] {
+                result[next] = *rate;
+                next += 1;
+            }
+        }
+        // Only reachable due to rounding, use the highest fee rate.
+        let highest = rates[rates.len() - 1].0;
+        for rate in result.iter_mut().skip(next) {
+            *rate = highest;
+        }
+
+        Some(FeeRatePercentiles {
+            p10: result[0],
+            p25: result[1],
+            p50: result[2],
+            p75: result[3],
+            p90: result[4],
+        })
+    }
+}
+
+/// Returns the fee paid by `tx`, looking up the spent outputs using `prevout`.
+fn tx_fee<F>(tx: &Transaction, prevout: &mut F) -> Result<Amount, BlockFeeSummaryError>
+where
+    F: FnMut(&OutPoint) -> Option<TxOut>,
+{
+    use BlockFeeSummaryError as E;
+
+    let mut input_value = Amount::ZERO;
+    for txin in tx.input.iter() {
+        let spent =
+            prevout(&txin.previous_output).ok_or(E::MissingPrevout(txin.previous_output))?;
+        input_value = input_value.checked_add(spent.value).ok_or(E::Overflow)?;
+    }
+    let output_value = tx.output.iter().map(|txout| txout.value).sum();
+
+    input_value.checked_sub(output_value).ok_or(E::NegativeFee(tx.compute_txid()))
+}
+
+/// Returns the fee rate of a transaction that pays `fee` and has `weight`.
+fn fee_rate(fee: Amount, weight: Weight) -> FeeRate {
+    match weight.to_wu() {
+        0 => FeeRate::ZERO,
+        wu => FeeRate::from_sat_per_kwu(fee.to_sat().saturating_mul(1000) / wu),
+    }
+}
+
+/// Error when computing a [`BlockFeeSummary`] from previous outputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockFeeSummaryError {
+    /// The previous output spent by an input was not provided.
+    MissingPrevout(OutPoint),
+    /// The outputs of a transaction are worth more than its inputs.
+    NegativeFee(bitcoin::Txid),
+    /// Summing amounts overflowed.
+    Overflow,
+}
+
+impl fmt::Display for BlockFeeSummaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use BlockFeeSummaryError::*;
+
+        match *self {
+            MissingPrevout(ref outpoint) => write!(f, "missing previous output: {}", outpoint),
+            NegativeFee(ref txid) =>
+                write!(f, "outputs of transaction {} are worth more than its inputs", txid),
+            Overflow => write!(f, "amount overflow"),
+        }
+    }
+}
+
+impl std::error::Error for BlockFeeSummaryError {}
+
+impl GetBlockVerbosityTwo {
+    /// Returns the fee and subsidy summary of this block, see [`BlockFeeSummary::new`].
+    pub fn fee_summary(&self, network: Network) -> BlockFeeSummary {
+        BlockFeeSummary::new(self, network)
+    }
+}
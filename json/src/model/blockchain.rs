@@ -6,11 +6,15 @@
 //! and are not specific to a specific version of Bitcoin Core.
 
 use std::collections::BTreeMap;
+use std::fmt;
 
 use bitcoin::address::NetworkUnchecked;
+use bitcoin::hashes::Hash;
 use bitcoin::{
-    block, Address, Block, BlockHash, CompactTarget, Network, TxOut, Txid, Weight, Work,
+    block, hex, Address, Amount, Block, BlockHash, CompactTarget, FeeRate, MerkleBlock, Network,
+    OutPoint, ScriptBuf, Transaction, TxMerkleNode, TxOut, Txid, Weight, Work, Wtxid,
 };
+use internals::write_err;
 use serde::{Deserialize, Serialize};
 
 /// Models the result of JSON-RPC method `getbestblockhash`.
@@ -21,7 +25,7 @@ pub struct GetBestBlockHash(pub BlockHash);
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBlockchainInfo {
     /// Current network name as defined in BIP70 (main, test, signet, regtest).
-    pub chain: Network,
+    pub chain: Chain,
     /// The current number of blocks processed in the server.
     pub blocks: u64,
     /// The current number of headers we have validated.
@@ -30,6 +34,9 @@ pub struct GetBlockchainInfo {
     pub best_block_hash: BlockHash,
     /// The current difficulty.
     pub difficulty: f64,
+    /// The block time of the current best block in seconds since epoch (v23 and later, `None`
+    /// for earlier versions).
+    pub time: Option<u64>,
     /// Median time for the current best block.
     pub median_time: u64,
     /// Estimate of verification progress (between 0 and 1).
@@ -50,8 +57,69 @@ pub struct GetBlockchainInfo {
     pub prune_target_size: Option<u64>,
     /// Status of softforks in progress, maps softfork name -> [`Softfork`].
     pub softforks: BTreeMap<String, Softfork>,
+    /// The block challenge (aka. block script), only present on signet and not returned by all
+    /// versions.
+    pub signet_challenge: Option<ScriptBuf>,
     /// Any network and blockchain warnings.
-    pub warnings: String,
+    pub warnings: Vec<String>,
+}
+
+/// The initial block download (IBD) state of a node, see [`GetBlockchainInfo::ibd_state`].
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct IbdState {
+    /// Estimate of whether the node is in initial block download mode.
+    pub initial_block_download: bool,
+    /// Estimate of verification progress.
+    pub verification_progress: VerificationProgress,
+    /// The current number of blocks processed.
+    pub blocks: u64,
+    /// The current number of headers validated.
+    pub headers: u64,
+    /// The estimated size of the block and undo files on disk.
+    pub size_on_disk: u64,
+}
+
+impl IbdState {
+    /// Returns true if the node is out of IBD and verification progress is at least `threshold`.
+    ///
+    /// Progress is an estimate based on the transaction count, it rarely reaches exactly 1.0 so a
+    /// threshold such as 0.9999 is typical.
+    pub fn is_synced(&self, threshold: f64) -> bool {
+        !self.initial_block_download && self.verification_progress.as_f64() >= threshold
+    }
+}
+
+/// An estimate of verification progress, a ratio between 0 and 1 (inclusive).
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(from = "f64", into = "f64")]
+pub struct VerificationProgress(f64);
+
+impl VerificationProgress {
+    /// Creates a new progress ratio, `progress` is clamped to between 0 and 1 (NaN is 0).
+    pub fn new(progress: f64) -> Self {
+        if progress.is_nan() {
+            return VerificationProgress(0.0);
+        }
+        VerificationProgress(progress.clamp(0.0, 1.0))
+    }
+
+    /// Returns the progress ratio.
+    pub fn as_f64(self) -> f64 { self.0 }
+
+    /// Returns the progress as a percentage.
+    pub fn to_percent(self) -> f64 { self.0 * 100.0 }
+}
+
+impl From<f64> for VerificationProgress {
+    fn from(progress: f64) -> Self { VerificationProgress::new(progress) }
+}
+
+impl From<VerificationProgress> for f64 {
+    fn from(progress: VerificationProgress) -> Self { progress.0 }
+}
+
+impl fmt::Display for VerificationProgress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{:.2}%", self.to_percent()) }
 }
 
 /// Status of softfork.
@@ -80,6 +148,8 @@ pub enum SoftforkType {
     ///
     /// [BIP-9] <https://github.com/bitcoin/bips/blob/master/bip-0009.mediawiki>
     Bip9,
+    /// A softfork type not known to this library.
+    Unknown,
 }
 
 /// Status of BIP-9 softforks.
@@ -97,6 +167,8 @@ pub struct Bip9SoftforkInfo {
     pub since: u32,
     /// Numeric statistics about BIP-9 signalling for a softfork (only for "started" status).
     pub statistics: Option<Bip9SoftforkStatistics>,
+    /// Minimum height of blocks for which the rules may be enforced (Core v0.21 onwards).
+    pub min_activation_height: Option<u32>,
 }
 
 /// BIP-9 softfork status: one of "defined", "started", "locked_in", "active", "failed".
@@ -112,6 +184,8 @@ pub enum Bip9SoftforkStatus {
     Active,
     /// BIP-9 softfork status "failed".
     Failed,
+    /// A status not known to this library.
+    Unknown,
 }
 
 /// Statistics for a BIP-9 softfork.
@@ -176,6 +250,263 @@ pub struct GetBlockVerbosityOne {
     pub next_block_hash: Option<BlockHash>,
 }
 
+/// Models the result of JSON-RPC method `getblock` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockVerbosityTwo {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: BlockHash,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i32,
+    /// The block size.
+    pub size: usize,
+    /// The block size excluding witness data.
+    pub stripped_size: Option<usize>,
+    /// The block weight as defined in BIP-141.
+    pub weight: Weight,
+    /// The block height or index.
+    pub height: usize,
+    /// The block version.
+    pub version: block::Version,
+    /// The block version formatted in hexadecimal.
+    pub version_hex: String,
+    /// The merkle root.
+    pub merkle_root: String,
+    /// The transactions.
+    pub tx: Vec<Transaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: usize,
+    /// The median block time expressed in UNIX epoch time.
+    pub median_time: Option<usize>,
+    /// The nonce.
+    pub nonce: u32,
+    /// The bits.
+    pub bits: CompactTarget,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    pub chain_work: Work,
+    /// The number of transactions in the block.
+    pub n_tx: u32,
+    /// The hash of the previous block (if available).
+    pub previous_block_hash: Option<BlockHash>,
+    /// The hash of the next block (if available).
+    pub next_block_hash: Option<BlockHash>,
+}
+
+/// Models the result of JSON-RPC method `getblockhash`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetBlockHash(pub BlockHash);
+
+/// Models the result of JSON-RPC method `getblockheader` with verbose set to `false`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetBlockHeader(pub block::Header);
+
+/// Header level data of a block, as returned by JSON-RPC method `getblockheader` with verbose set.
+///
+/// Can be extracted from the verbose `getblock` models without another round trip.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockHeaderVerbose {
+    /// The block hash.
+    pub hash: BlockHash,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i32,
+    /// The block height or index.
+    pub height: usize,
+    /// The block version.
+    pub version: block::Version,
+    /// The block version formatted in hexadecimal.
+    pub version_hex: String,
+    /// The merkle root.
+    pub merkle_root: String,
+    /// The block time expressed in UNIX epoch time.
+    pub time: usize,
+    /// The median block time expressed in UNIX epoch time.
+    pub median_time: Option<usize>,
+    /// The nonce.
+    pub nonce: u32,
+    /// The bits.
+    pub bits: CompactTarget,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    pub chain_work: Work,
+    /// The number of transactions in the block.
+    pub n_tx: u32,
+    /// The hash of the previous block (if available).
+    pub previous_block_hash: Option<BlockHash>,
+    /// The hash of the next block (if available).
+    pub next_block_hash: Option<BlockHash>,
+}
+
+/// Size and weight breakdown of a block, computed from the verbose `getblock` fields.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BlockSizeBreakdown {
+    /// The block size in bytes, including witness data.
+    pub size: usize,
+    /// The block size in bytes, excluding witness data.
+    pub stripped_size: usize,
+    /// The number of bytes of witness data (`size - stripped_size`).
+    pub witness_size: usize,
+    /// The block weight as defined in BIP-141.
+    pub weight: Weight,
+}
+
+/// Models the result of JSON-RPC method `getrawmempool` with verbose set to false.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawMempool(pub Vec<Txid>);
+
+/// Models the result of JSON-RPC method `getrawmempool` with verbose set to true.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawMempoolVerbose(pub BTreeMap<Txid, MempoolEntry>);
+
+/// Models the result of JSON-RPC method `getmempoolentry`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetMempoolEntry(pub MempoolEntry);
+
+/// A transaction in the mempool.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MempoolEntry {
+    /// Virtual transaction size as defined in BIP 141.
+    pub vsize: u64,
+    /// Transaction weight as defined in BIP 141 (v0.19 onwards).
+    pub weight: Option<Weight>,
+    /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
+    pub time: u64,
+    /// Block height when transaction entered pool.
+    pub height: u64,
+    /// Number of in-mempool descendant transactions (including this one).
+    pub descendant_count: u64,
+    /// Virtual transaction size of in-mempool descendants (including this one).
+    pub descendant_size: u64,
+    /// Number of in-mempool ancestor transactions (including this one).
+    pub ancestor_count: u64,
+    /// Virtual transaction size of in-mempool ancestors (including this one).
+    pub ancestor_size: u64,
+    /// Hash of serialized transaction, including witness data.
+    pub wtxid: Wtxid,
+    /// The base, modified, ancestor and descendant fees.
+    pub fees: MempoolEntryFees,
+    /// Unconfirmed transactions used as inputs for this transaction.
+    pub depends: Vec<Txid>,
+    /// Unconfirmed transactions spending outputs from this transaction.
+    pub spent_by: Vec<Txid>,
+    /// Whether this transaction could be replaced due to BIP-125 (replace-by-fee).
+    pub bip125_replaceable: Option<bool>,
+    /// Whether this transaction is currently unbroadcast (v0.21 onwards).
+    pub unbroadcast: Option<bool>,
+}
+
+impl MempoolEntry {
+    /// Returns the fee rate of this transaction, computed from the base fee and virtual size.
+    ///
+    /// Returns `None` if the size is zero.
+    pub fn fee_rate(&self) -> Option<FeeRate> {
+        let weight = self.weight.unwrap_or_else(|| Weight::from_vb_unchecked(self.vsize));
+        let sat_kwu = self.fees.base.to_sat().checked_mul(1000)?.checked_div(weight.to_wu())?;
+        Some(FeeRate::from_sat_per_kwu(sat_kwu))
+    }
+
+    /// Returns the fee rate Core uses to order transactions for eviction from a full mempool.
+    ///
+    /// This is the greater of the modified fee rate of the transaction and the modified fee rate of
+    /// the transaction together with its in-mempool descendants. Returns `None` if a size is zero.
+    pub fn descendant_score(&self) -> Option<FeeRate> {
+        fn fee_rate(fee: Amount, vsize: u64) -> Option<FeeRate> {
+            let sat_kwu = fee.to_sat().checked_mul(250)?.checked_div(vsize)?;
+            Some(FeeRate::from_sat_per_kwu(sat_kwu))
+        }
+
+        let modified = fee_rate(self.fees.modified, self.vsize)?;
+        let descendants = fee_rate(self.fees.descendant, self.descendant_size)?;
+        Some(modified.max(descendants))
+    }
+}
+
+/// The fees of a transaction in the mempool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MempoolEntryFees {
+    /// Transaction fee.
+    pub base: Amount,
+    /// Transaction fee with fee deltas used for mining priority.
+    pub modified: Amount,
+    /// Modified fees (see above) of in-mempool ancestors (including this one).
+    pub ancestor: Amount,
+    /// Modified fees (see above) of in-mempool descendants (including this one).
+    pub descendant: Amount,
+}
+
+/// Models the result of JSON-RPC method `getmempoolinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetMempoolInfo {
+    /// True if the mempool is fully loaded (v0.19 onwards).
+    pub loaded: Option<bool>,
+    /// Current transaction count.
+    pub size: u64,
+    /// Sum of all virtual transaction sizes as defined in BIP-141.
+    pub bytes: u64,
+    /// Total memory usage for the mempool.
+    pub usage: u64,
+    /// Total fees for the mempool, ignoring modified fees (v24 onwards).
+    pub total_fee: Option<Amount>,
+    /// Maximum memory usage for the mempool.
+    pub max_mempool: u64,
+    /// Minimum fee rate for a transaction to be accepted.
+    pub mempool_min_fee: FeeRate,
+    /// Current minimum relay fee for transactions.
+    pub min_relay_tx_fee: FeeRate,
+    /// Minimum fee rate increment for mempool limiting or replacement (v24 onwards).
+    pub incremental_relay_fee: Option<FeeRate>,
+    /// Current number of transactions that haven't passed initial broadcast yet (v0.21 onwards).
+    pub unbroadcast_count: Option<u64>,
+    /// True if the mempool accepts RBF without replaceability signaling inspection (v24 onwards).
+    pub full_rbf: Option<bool>,
+}
+
+/// Models the result of JSON-RPC method `getchaintips`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetChainTips(pub Vec<ChainTips>);
+
+impl GetChainTips {
+    /// Returns the tip of the active chain.
+    pub fn active(&self) -> Option<&ChainTips> {
+        self.0.iter().find(|tip| tip.status == ChainTipsStatus::Active)
+    }
+}
+
+/// A chain tip, part of `getchaintips`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ChainTips {
+    /// Height of the chain tip.
+    pub height: u64,
+    /// Block hash of the tip.
+    pub hash: BlockHash,
+    /// Zero for the active chain, otherwise the length of the branch connecting the tip to the
+    /// active chain.
+    pub branch_length: u64,
+    /// Status of the chain.
+    pub status: ChainTipsStatus,
+}
+
+impl ChainTips {
+    /// Returns the height of the last block this tip has in common with the active chain.
+    pub fn fork_height(&self) -> u64 { self.height.saturating_sub(self.branch_length) }
+}
+
+/// The status of a chain tip, part of `getchaintips`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum ChainTipsStatus {
+    /// This branch contains at least one invalid block.
+    Invalid,
+    /// Not all blocks for this branch are available, but the headers are valid.
+    HeadersOnly,
+    /// All blocks are available for this branch, but they were never fully validated.
+    ValidHeaders,
+    /// This branch is not part of the active chain, but is fully validated.
+    ValidFork,
+    /// This is the tip of the active main chain.
+    Active,
+}
+
 /// Models the result of JSON-RPC method `gettxout`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GetTxOut {
@@ -190,3 +521,458 @@ pub struct GetTxOut {
     /// Coinbase or not.
     pub coinbase: bool,
 }
+
+/// Models the result of JSON-RPC method `gettxoutproof`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetTxOutProof(#[serde(with = "serde_merkle_block")] pub MerkleBlock);
+
+impl GetTxOutProof {
+    /// Returns the transactions the proof commits to, `None` if the proof is invalid.
+    ///
+    /// Only checks the proof is internally consistent i.e., the merkle root matches the header,
+    /// this does not check the block is in the best chain (use `verifytxoutproof` for that).
+    pub fn matched_txids(&self) -> Option<Vec<Txid>> {
+        let mut matches = vec![];
+        let mut indexes = vec![];
+        self.0.extract_matches(&mut matches, &mut indexes).ok()?;
+        Some(matches)
+    }
+}
+
+/// Serializes a merkle block as consensus encoded hex, the same encoding Core uses.
+mod serde_merkle_block {
+    use bitcoin::consensus::encode;
+    use bitcoin::MerkleBlock;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(block: &MerkleBlock, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&encode::serialize_hex(block))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<MerkleBlock, D::Error> {
+        let s = String::deserialize(d)?;
+        encode::deserialize_hex(&s).map_err(D::Error::custom)
+    }
+}
+
+/// Models the result of JSON-RPC method `verifytxoutproof`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VerifyTxOutProof(pub Vec<Txid>);
+
+/// Models the result of JSON-RPC method `gettxoutsetinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The block height (index) of the returned statistics.
+    pub height: u64,
+    /// The hash of the block at which these statistics are calculated.
+    pub best_block: BlockHash,
+    /// The number of transactions with unspent outputs (not available when coinstatsindex is
+    /// used).
+    pub transactions: Option<u64>,
+    /// The number of unspent transaction outputs.
+    pub tx_outs: u64,
+    /// Database-independent, meaningless metric indicating the UTXO set size.
+    pub bogo_size: u64,
+    /// The legacy serialized hash (`hash_serialized_2` before v26, `hash_serialized_3` after).
+    pub hash_serialized: Option<String>,
+    /// The MuHash of the UTXO set (only present if the 'muhash' hash type is chosen).
+    pub muhash: Option<String>,
+    /// The estimated size of the chainstate on disk (not available when coinstatsindex is used).
+    pub disk_size: Option<u64>,
+    /// The total amount of coins in the UTXO set.
+    pub total_amount: Amount,
+    /// The total amount of coins permanently excluded from the UTXO set (only available if
+    /// coinstatsindex is used).
+    pub total_unspendable_amount: Option<Amount>,
+    /// Info on amounts in the block at this block height (only available if coinstatsindex is
+    /// used).
+    pub block_info: Option<TxOutSetBlockInfo>,
+}
+
+/// Amounts in the block at the height of `GetTxOutSetInfo`, only available with coinstatsindex.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TxOutSetBlockInfo {
+    /// Total amount of all prevouts spent in this block.
+    pub prevout_spent: Amount,
+    /// Coinbase subsidy amount of this block.
+    pub coinbase: Amount,
+    /// Total amount of new outputs created by this block.
+    pub new_outputs_ex_coinbase: Amount,
+    /// Total amount of unspendable outputs created in this block.
+    pub unspendable: Amount,
+    /// Detailed view of the unspendable categories.
+    pub unspendables: TxOutSetUnspendables,
+}
+
+/// The unspendable amounts of a `TxOutSetBlockInfo`, by category.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TxOutSetUnspendables {
+    /// The unspendable amount of the Genesis block subsidy.
+    pub genesis_block: Amount,
+    /// Transactions overridden by duplicates (no longer possible with BIP30).
+    pub bip30: Amount,
+    /// Amounts sent to scripts that are unspendable (for example `OP_RETURN` outputs).
+    pub scripts: Amount,
+    /// Fee rewards that miners did not claim in their coinbase transaction.
+    pub unclaimed_rewards: Amount,
+}
+
+/// Models the result of JSON-RPC method `getindexinfo`, maps index name to its status.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetIndexInfo(pub BTreeMap<String, IndexInfo>);
+
+/// The status of an index, part of `GetIndexInfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IndexInfo {
+    /// Whether the index is synced or not.
+    pub synced: bool,
+    /// The block height to which the index is synced.
+    pub best_block_height: u64,
+}
+
+/// Models the result of JSON-RPC method `scanblocks` with action "start".
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanBlocksStart {
+    /// The height we started the scan from.
+    pub from_height: u64,
+    /// The height we ended the scan at.
+    pub to_height: u64,
+    /// Blocks that may have matched a scanobject.
+    pub relevant_blocks: Vec<BlockHash>,
+    /// True if the scan process was not aborted (v26 and later).
+    pub completed: Option<bool>,
+}
+
+/// Models the result of JSON-RPC method `dumptxoutset`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DumpTxOutSet {
+    /// The number of coins written in the snapshot.
+    pub coins_written: u64,
+    /// The hash of the base of the snapshot.
+    pub base_hash: BlockHash,
+    /// The height of the base of the snapshot.
+    pub base_height: u32,
+    /// The absolute path that the snapshot was written to.
+    pub path: String,
+    /// The hash of the UTXO set contents (`hash_serialized_3`).
+    pub tx_out_set_hash: String,
+    /// The number of transactions in the chain up to and including the base block.
+    pub n_chain_tx: u64,
+}
+
+/// Models the result of JSON-RPC method `loadtxoutset`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LoadTxOutSet {
+    /// The number of coins loaded from the snapshot.
+    pub coins_loaded: u64,
+    /// The hash of the base of the snapshot.
+    pub tip_hash: BlockHash,
+    /// The height of the base of the snapshot.
+    pub base_height: u32,
+    /// The absolute path that the snapshot was loaded from.
+    pub path: String,
+}
+
+/// Models the result of JSON-RPC method `getchainstates`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetChainStates {
+    /// The number of headers seen so far.
+    pub headers: u32,
+    /// The chainstates ordered by work, the active chainstate is last.
+    pub chain_states: Vec<ChainState>,
+}
+
+impl GetChainStates {
+    /// Returns the active chainstate.
+    pub fn active(&self) -> Option<&ChainState> { self.chain_states.last() }
+
+    /// Returns the chainstate based on a snapshot, if a snapshot has been loaded and the
+    /// background validation of it has not yet completed.
+    pub fn snapshot(&self) -> Option<&ChainState> {
+        self.chain_states.iter().find(|c| c.snapshot_block_hash.is_some() && !c.validated)
+    }
+
+    /// Returns true if there is a single, fully validated, chainstate.
+    ///
+    /// After loading a snapshot this becomes true once the background chainstate has validated
+    /// the snapshot and been removed.
+    pub fn is_fully_validated(&self) -> bool {
+        self.chain_states.len() == 1 && self.chain_states[0].validated
+    }
+}
+
+/// Models a chainstate, part of the result of JSON-RPC method `getchainstates`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ChainState {
+    /// Number of blocks in this chainstate.
+    pub blocks: u32,
+    /// Blockhash of the tip.
+    pub best_block_hash: BlockHash,
+    /// Difficulty of the tip.
+    pub difficulty: f64,
+    /// Progress towards the network tip.
+    pub verification_progress: f64,
+    /// The base block of the snapshot this chainstate is based on, if any.
+    pub snapshot_block_hash: Option<BlockHash>,
+    /// Size of the coinsdb cache.
+    pub coins_db_cache_bytes: u64,
+    /// Size of the coinstip cache.
+    pub coins_tip_cache_bytes: u64,
+    /// Whether all blocks in the chainstate were validated, false for an unvalidated snapshot.
+    pub validated: bool,
+}
+
+/// Models the result of JSON-RPC method `scantxoutset` with action "start".
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStart {
+    /// Whether the scan completed (false if it was aborted).
+    pub success: bool,
+    /// The number of unspent transaction outputs scanned.
+    pub txouts: u64,
+    /// The block height at which the scan was done (v21 and later).
+    pub height: Option<u64>,
+    /// The hash of the block at the tip of the chain (v21 and later).
+    pub best_block: Option<BlockHash>,
+    /// The unspent transaction outputs that matched a scan object.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs.
+    pub total_amount: Amount,
+}
+
+/// An unspent transaction output, part of `ScanTxOutSetStart`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanTxOutSetUnspent {
+    /// The outpoint of the unspent transaction output.
+    pub outpoint: OutPoint,
+    /// The script pubkey.
+    pub script_pubkey: ScriptBuf,
+    /// A specialized descriptor for the matched script pubkey (v18 and later).
+    pub descriptor: Option<String>,
+    /// The amount of the output.
+    pub amount: Amount,
+    /// Whether this is a coinbase output (v25 and later).
+    pub coinbase: Option<bool>,
+    /// Height of the unspent transaction output.
+    pub height: u64,
+}
+
+/// The chain a node is running on, as reported in the `chain` field of various RPC methods.
+///
+/// Unlike `bitcoin::Network` this type can represent any chain name Core returns, unknown names
+/// (e.g. a chain added in a future release) are kept in the `Other` variant.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum Chain {
+    /// Bitcoin mainnet ("main").
+    Main,
+    /// Bitcoin testnet3 ("test").
+    Test,
+    /// Bitcoin testnet4 ("testnet4", Core v28 onwards).
+    Testnet4,
+    /// Bitcoin signet ("signet").
+    Signet,
+    /// Bitcoin regtest ("regtest").
+    Regtest,
+    /// A chain name not known to this library.
+    Other(String),
+}
+
+impl Chain {
+    /// Parses the chain name as returned by Core, never fails.
+    pub fn from_core_arg(s: &str) -> Self {
+        match s {
+            "main" => Chain::Main,
+            "test" => Chain::Test,
+            "testnet4" => Chain::Testnet4,
+            "signet" => Chain::Signet,
+            "regtest" => Chain::Regtest,
+            other => Chain::Other(other.to_owned()),
+        }
+    }
+
+    /// Returns the chain name as used by Core.
+    pub fn to_core_arg(&self) -> &str {
+        match *self {
+            Chain::Main => "main",
+            Chain::Test => "test",
+            Chain::Testnet4 => "testnet4",
+            Chain::Signet => "signet",
+            Chain::Regtest => "regtest",
+            Chain::Other(ref s) => s,
+        }
+    }
+
+    /// Returns the `bitcoin::Network` for this chain, if `rust-bitcoin` knows about it.
+    pub fn network(&self) -> Option<Network> { Network::from_core_arg(self.to_core_arg()).ok() }
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.to_core_arg()) }
+}
+
+impl From<Network> for Chain {
+    fn from(network: Network) -> Self { Chain::from_core_arg(network.to_core_arg()) }
+}
+
+impl From<String> for Chain {
+    fn from(s: String) -> Self { Chain::from_core_arg(&s) }
+}
+
+impl From<Chain> for String {
+    fn from(chain: Chain) -> Self { chain.to_core_arg().to_owned() }
+}
+
+impl GetBlockchainInfo {
+    /// Returns the initial block download state of the node.
+    pub fn ibd_state(&self) -> IbdState {
+        IbdState {
+            initial_block_download: self.initial_block_download,
+            verification_progress: VerificationProgress::new(self.verification_progress),
+            blocks: self.blocks,
+            headers: self.headers,
+            size_on_disk: self.size_on_disk,
+        }
+    }
+}
+
+impl fmt::Display for GetBlockchainInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "chain: {}", self.chain)?;
+        writeln!(f, "blocks: {} (headers {})", self.blocks, self.headers)?;
+        writeln!(f, "best block: {}", self.best_block_hash)?;
+        writeln!(f, "difficulty: {}", self.difficulty)?;
+        writeln!(f, "verification progress: {:.2}%", self.verification_progress * 100.0)?;
+        writeln!(f, "initial block download: {}", self.initial_block_download)?;
+        write!(f, "size on disk: {} bytes", self.size_on_disk)?;
+        if self.pruned {
+            match self.prune_height {
+                Some(height) => write!(f, " (pruned to height {})", height)?,
+                None => f.write_str(" (pruned)")?,
+            }
+        }
+        if let Some(ref challenge) = self.signet_challenge {
+            write!(f, "\nsignet challenge: {:x}", challenge)?;
+        }
+        if self.warnings.is_empty() {
+            f.write_str("\nwarnings: none")
+        } else {
+            write!(f, "\nwarnings: {}", self.warnings.join("; "))
+        }
+    }
+}
+
+macro_rules! impl_block_header_conversions {
+    ($ty:ident) => {
+        impl $ty {
+            /// Returns the header level data of this block.
+            pub fn header_verbose(&self) -> GetBlockHeaderVerbose {
+                GetBlockHeaderVerbose::from(self)
+            }
+
+            /// Returns the size and weight breakdown of this block.
+            ///
+            /// If `strippedsize` was not returned it is derived from the weight, since
+            /// `weight = stripped_size * 3 + size` (BIP-141).
+            pub fn size_breakdown(&self) -> BlockSizeBreakdown {
+                let stripped_size = self.stripped_size.unwrap_or_else(|| {
+                    (self.weight.to_wu() as usize).saturating_sub(self.size) / 3
+                });
+                BlockSizeBreakdown {
+                    size: self.size,
+                    stripped_size,
+                    witness_size: self.size.saturating_sub(stripped_size),
+                    weight: self.weight,
+                }
+            }
+        }
+
+        impl From<&$ty> for GetBlockHeaderVerbose {
+            fn from(block: &$ty) -> Self {
+                GetBlockHeaderVerbose {
+                    hash: block.hash,
+                    confirmations: block.confirmations,
+                    height: block.height,
+                    version: block.version,
+                    version_hex: block.version_hex.clone(),
+                    merkle_root: block.merkle_root.clone(),
+                    time: block.time,
+                    median_time: block.median_time,
+                    nonce: block.nonce,
+                    bits: block.bits,
+                    difficulty: block.difficulty,
+                    chain_work: block.chain_work,
+                    n_tx: block.n_tx,
+                    previous_block_hash: block.previous_block_hash,
+                    next_block_hash: block.next_block_hash,
+                }
+            }
+        }
+
+        impl From<$ty> for GetBlockHeaderVerbose {
+            fn from(block: $ty) -> Self { GetBlockHeaderVerbose::from(&block) }
+        }
+    };
+}
+impl_block_header_conversions!(GetBlockVerbosityOne);
+impl_block_header_conversions!(GetBlockVerbosityTwo);
+
+impl TryFrom<&GetBlockHeaderVerbose> for block::Header {
+    type Error = BlockHeaderError;
+
+    fn try_from(header: &GetBlockHeaderVerbose) -> Result<Self, Self::Error> {
+        use BlockHeaderError as E;
+
+        let merkle_root = header.merkle_root.parse::<TxMerkleNode>().map_err(E::MerkleRoot)?;
+        let time = u32::try_from(header.time).map_err(|_| E::Time(header.time))?;
+
+        Ok(block::Header {
+            version: header.version,
+            // The genesis block has no previous block.
+            prev_blockhash: header.previous_block_hash.unwrap_or_else(BlockHash::all_zeros),
+            merkle_root,
+            time,
+            bits: header.bits,
+            nonce: header.nonce,
+        })
+    }
+}
+
+impl TryFrom<GetBlockHeaderVerbose> for block::Header {
+    type Error = BlockHeaderError;
+
+    fn try_from(header: GetBlockHeaderVerbose) -> Result<Self, Self::Error> {
+        block::Header::try_from(&header)
+    }
+}
+
+/// Error when converting a [`GetBlockHeaderVerbose`] into a `block::Header`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockHeaderError {
+    /// Conversion of the `merkle_root` field failed.
+    MerkleRoot(hex::HexToArrayError),
+    /// The `time` field does not fit in a `u32`.
+    Time(usize),
+}
+
+impl fmt::Display for BlockHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use BlockHeaderError::*;
+
+        match *self {
+            MerkleRoot(ref e) => write_err!(f, "conversion of the `merkle_root` field failed"; e),
+            Time(time) => write!(f, "block time {} does not fit in a u32", time),
+        }
+    }
+}
+
+impl std::error::Error for BlockHeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use BlockHeaderError::*;
+
+        match *self {
+            MerkleRoot(ref e) => Some(e),
+            Time(_) => None,
+        }
+    }
+}
@@ -4,3 +4,216 @@
 //!
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
+
+use core::fmt;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Models the result of JSON-RPC method `getmemoryinfo` with mode "stats".
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetMemoryInfoStats {
+    /// Information about locked memory manager.
+    pub locked: Locked,
+}
+
+/// Information about locked memory manager, part of [`GetMemoryInfoStats`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Locked {
+    /// Number of bytes used.
+    pub used: u64,
+    /// Number of bytes available in current arenas.
+    pub free: u64,
+    /// Total number of bytes managed.
+    pub total: u64,
+    /// Amount of bytes that succeeded locking.
+    pub locked: u64,
+    /// Number allocated chunks.
+    pub chunks_used: u64,
+    /// Number unused chunks.
+    pub chunks_free: u64,
+}
+
+/// Models the result of JSON-RPC method `getmemoryinfo` with mode "mallocinfo".
+///
+/// The XML document produced by glibc's `malloc_info`, unmodified. `Display` writes the document
+/// as is so it can be written straight to a file or piped to other tools.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetMemoryInfoMallocInfo(pub String);
+
+impl GetMemoryInfoMallocInfo {
+    /// Returns the XML document.
+    pub fn as_str(&self) -> &str { &self.0 }
+
+    /// Returns the XML document, consuming `self`.
+    pub fn into_string(self) -> String { self.0 }
+}
+
+impl fmt::Display for GetMemoryInfoMallocInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(&self.0) }
+}
+
+/// Models the result of JSON-RPC method `logging`.
+///
+/// Maps each logging category known to the node to whether debug logging is active for it.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Logging(pub BTreeMap<LogCategory, bool>);
+
+impl Logging {
+    /// Returns whether debug logging is active for `category`, `None` if the node does not know it.
+    pub fn is_active(&self, category: &LogCategory) -> Option<bool> {
+        self.0.get(category).copied()
+    }
+
+    /// Returns an iterator over the categories for which debug logging is active.
+    pub fn active(&self) -> impl Iterator<Item = &LogCategory> {
+        self.0.iter().filter(|(_, active)| **active).map(|(category, _)| category)
+    }
+}
+
+/// A debug logging category, used by the JSON-RPC method `logging`.
+///
+/// The set of categories differs between Core versions, categories not known to this crate are
+/// represented by `Unknown`. `All` is only valid as an argument, it selects every category.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogCategory {
+    /// Every category (argument only).
+    All,
+    Net,
+    Tor,
+    Mempool,
+    Http,
+    Bench,
+    Zmq,
+    /// Wallet database, renamed to `walletdb` in v0.20.
+    Db,
+    /// Wallet database, v0.20 onwards.
+    WalletDb,
+    Rpc,
+    EstimateFee,
+    Addrman,
+    SelectCoins,
+    Reindex,
+    CmpctBlock,
+    Rand,
+    Prune,
+    Proxy,
+    MempoolRej,
+    Libevent,
+    CoinDb,
+    Qt,
+    LevelDb,
+    Validation,
+    I2p,
+    Ipc,
+    Lock,
+    Util,
+    BlockStorage,
+    TxReconciliation,
+    Scan,
+    TxPackages,
+    /// A category not known to this crate.
+    Unknown(String),
+}
+
+impl LogCategory {
+    /// Returns the name of the category as used by Core.
+    pub fn as_str(&self) -> &str {
+        use LogCategory::*;
+
+        match *self {
+            All => "all",
+            Net => "net",
+            Tor => "tor",
+            Mempool => "mempool",
+            Http => "http",
+            Bench => "bench",
+            Zmq => "zmq",
+            Db => "db",
+            WalletDb => "walletdb",
+            Rpc => "rpc",
+            EstimateFee => "estimatefee",
+            Addrman => "addrman",
+            SelectCoins => "selectcoins",
+            Reindex => "reindex",
+            CmpctBlock => "cmpctblock",
+            Rand => "rand",
+            Prune => "prune",
+            Proxy => "proxy",
+            MempoolRej => "mempoolrej",
+            Libevent => "libevent",
+            CoinDb => "coindb",
+            Qt => "qt",
+            LevelDb => "leveldb",
+            Validation => "validation",
+            I2p => "i2p",
+            Ipc => "ipc",
+            Lock => "lock",
+            Util => "util",
+            BlockStorage => "blockstorage",
+            TxReconciliation => "txreconciliation",
+            Scan => "scan",
+            TxPackages => "txpackages",
+            Unknown(ref s) => s,
+        }
+    }
+}
+
+impl From<&str> for LogCategory {
+    fn from(s: &str) -> Self {
+        use LogCategory::*;
+
+        match s {
+            "all" | "1" => All,
+            "net" => Net,
+            "tor" => Tor,
+            "mempool" => Mempool,
+            "http" => Http,
+            "bench" => Bench,
+            "zmq" => Zmq,
+            "db" => Db,
+            "walletdb" => WalletDb,
+            "rpc" => Rpc,
+            "estimatefee" => EstimateFee,
+            "addrman" => Addrman,
+            "selectcoins" => SelectCoins,
+            "reindex" => Reindex,
+            "cmpctblock" => CmpctBlock,
+            "rand" => Rand,
+            "prune" => Prune,
+            "proxy" => Proxy,
+            "mempoolrej" => MempoolRej,
+            "libevent" => Libevent,
+            "coindb" => CoinDb,
+            "qt" => Qt,
+            "leveldb" => LevelDb,
+            "validation" => Validation,
+            "i2p" => I2p,
+            "ipc" => Ipc,
+            "lock" => Lock,
+            "util" => Util,
+            "blockstorage" => BlockStorage,
+            "txreconciliation" => TxReconciliation,
+            "scan" => Scan,
+            "txpackages" => TxPackages,
+            other => Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for LogCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.as_str()) }
+}
+
+impl Serialize for LogCategory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LogCategory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(LogCategory::from(s.as_str()))
+    }
+}
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Export of wallet transactions to CSV and JSON lines.
+//!
+//! Both formats use the same stable set of columns, in this order:
+//!
+//! `txid`, `time`, `category`, `amount_btc`, `amount_sat`, `fee_btc`, `fee_sat`, `confirmations`
+//!
+//! There is one row per wallet transaction entry, i.e. per output involving the wallet, as
+//! returned by `listtransactions`. BTC amounts are written as decimal strings with eight decimal
+//! places (never as floats), `fee_*` are empty (CSV) or `null` (JSON) if Core did not report a fee.
+//!
+//! ```
+//! # use bitcoind_json_rpc_types::model::export::{self, ExportRow};
+//! # use bitcoind_json_rpc_types::model::ListTransactions;
+//! # fn f(list: ListTransactions) -> std::io::Result<()> {
+//! let rows = ExportRow::from_list_transactions(&list);
+//! export::write_csv(std::io::stdout(), &rows)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io;
+
+use bitcoin::{SignedAmount, Txid};
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    GetTransaction, GetTransactionDetailCategory, ListTransactions, TransactionItem,
+};
+
+/// The column names, in order.
+pub const COLUMNS: [&str; 8] =
+    ["txid", "time", "category", "amount_btc", "amount_sat", "fee_btc", "fee_sat", "confirmations"];
+
+/// A single exported wallet transaction entry.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ExportRow {
+    /// The transaction id.
+    pub txid: Txid,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    pub time: u64,
+    /// The category of the entry.
+    pub category: GetTransactionDetailCategory,
+    /// The amount, negative for the `send` category.
+    #[serde(with = "bitcoin::amount::serde::as_sat")]
+    pub amount: SignedAmount,
+    /// The fee, negative and only reported for the `send` category.
+    #[serde(default, with = "bitcoin::amount::serde::as_sat::opt")]
+    pub fee: Option<SignedAmount>,
+    /// The number of confirmations, negative if the transaction is conflicted.
+    pub confirmations: i64,
+}
+
+impl ExportRow {
+    /// Returns the rows for the entries of a `listtransactions` result, in the same order.
+    pub fn from_list_transactions(list: &ListTransactions) -> Vec<ExportRow> {
+        list.0.iter().map(ExportRow::from_transaction_item).collect()
+    }
+
+    /// Returns the row for a single `listtransactions` (or `listsinceblock`) entry.
+    pub fn from_transaction_item(item: &TransactionItem) -> ExportRow {
+        ExportRow {
+            txid: item.txid,
+            time: item.time,
+            category: item.category,
+            amount: item.amount,
+            fee: item.fee,
+            confirmations: item.confirmations,
+        }
+    }
+
+    /// Returns the rows for a `gettransaction` result, one per entry in `details`.
+    pub fn from_get_transaction(tx: &GetTransaction) -> Vec<ExportRow> {
+        tx.details
+            .iter()
+            .map(|detail| ExportRow {
+                txid: tx.txid,
+                time: tx.time,
+                category: detail.category,
+                amount: detail.amount,
+                fee: detail.fee,
+                confirmations: i64::from(tx.confirmations),
+            })
+            .collect()
+    }
+
+    /// Returns the fields of this row formatted as strings, in [`COLUMNS`] order.
+    ///
+    /// A missing fee is formatted as an empty string.
+    pub fn to_record(&self) -> [String; 8] {
+        [
+            self.txid.to_string(),
+            self.time.to_string(),
+            category_name(self.category).to_owned(),
+            format_btc(self.amount),
+            self.amount.to_sat().to_string(),
+            self.fee.map(format_btc).unwrap_or_default(),
+            self.fee.map(|fee| fee.to_sat().to_string()).unwrap_or_default(),
+            self.confirmations.to_string(),
+        ]
+    }
+}
+
+/// Writes `rows` as CSV, with a header line.
+pub fn write_csv<'a, W, I>(mut w: W, rows: I) -> io::Result<()>
+where
+    W: io::Write,
+    I: IntoIterator<Item = &'a ExportRow>,
+{
+    writeln!(w, "{}", COLUMNS.join(","))?;
+    for row in rows {
+        // None of the fields can contain a comma, quote or newline so none need quoting.
+        writeln!(w, "{}", row.to_record().join(","))?;
+    }
+    Ok(())
+}
+
+/// Writes `rows` as JSON lines, one JSON object per line.
+pub fn write_json_lines<'a, W, I>(mut w: W, rows: I) -> io::Result<()>
+where
+    W: io::Write,
+    I: IntoIterator<Item = &'a ExportRow>,
+{
+    for row in rows {
+        let line = JsonRow {
+            txid: row.txid,
+            time: row.time,
+            category: category_name(row.category),
+            amount_btc: format_btc(row.amount),
+            amount_sat: row.amount.to_sat(),
+            fee_btc: row.fee.map(format_btc),
+            fee_sat: row.fee.map(|fee| fee.to_sat()),
+            confirmations: row.confirmations,
+        };
+        serde_json::to_writer(&mut w, &line)?;
+        w.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// A row as written to JSON lines, fields are in [`COLUMNS`] order.
+#[derive(Serialize)]
+struct JsonRow {
+    txid: Txid,
+    time: u64,
+    category: &'static str,
+    amount_btc: String,
+    amount_sat: i64,
+    fee_btc: Option<String>,
+    fee_sat: Option<i64>,
+    confirmations: i64,
+}
+
+/// Returns the category name as used by Core.
+fn category_name(category: GetTransactionDetailCategory) -> &'static str {
+    use GetTransactionDetailCategory::*;
+
+    match category {
+        Send => "send",
+        Receive => "receive",
+        Generate => "generate",
+        Immature => "immature",
+        Orphan => "orphan",
+        Unknown => "unknown",
+    }
+}
+
+/// Formats `amount` in BTC with exactly eight decimal places e.g., "-0.00010000".
+fn format_btc(amount: SignedAmount) -> String {
+    let sat = amount.to_sat();
+    let sign = if sat < 0 { "-" } else { "" };
+    let abs = sat.unsigned_abs();
+    format!("{}{}.{:08}", sign, abs / 100_000_000, abs % 100_000_000)
+}
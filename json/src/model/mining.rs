@@ -4,3 +4,179 @@
 //!
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
+
+use core::fmt;
+use std::collections::BTreeMap;
+
+use bitcoin::{
+    block, Amount, BlockHash, CompactTarget, ScriptBuf, Target, Transaction, Txid, Weight, Wtxid,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::model::Chain;
+
+/// Models the result of JSON-RPC method `getmininginfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMiningInfo {
+    /// The current block.
+    pub blocks: u64,
+    /// The block weight of the last assembled block (only present if a block was ever assembled).
+    pub current_block_weight: Option<Weight>,
+    /// The number of block transactions of the last assembled block (only present if a block was
+    /// ever assembled).
+    pub current_block_tx: Option<u64>,
+    /// The current difficulty.
+    pub difficulty: f64,
+    /// The network hashes per second.
+    pub network_hash_ps: f64,
+    /// The size of the mempool.
+    pub pooled_tx: u64,
+    /// Current network name as defined in BIP70 (main, test, signet, regtest).
+    pub chain: Chain,
+    /// The current target (only returned by later versions of Core).
+    pub target: Option<Target>,
+    /// The current compact target (only returned by later versions of Core).
+    pub bits: Option<CompactTarget>,
+    /// The block challenge (aka. block script), only returned on signet by later versions of Core.
+    pub signet_challenge: Option<ScriptBuf>,
+    /// Any network and blockchain warnings.
+    pub warnings: Vec<String>,
+}
+
+impl GetMiningInfo {
+    /// Returns the current target, from `target` or else derived from `bits`.
+    ///
+    /// Returns `None` if the node did not return either field.
+    pub fn current_target(&self) -> Option<Target> {
+        self.target.or_else(|| self.bits.map(Target::from_compact))
+    }
+
+    /// Returns true if `hash` meets the current target, `None` if the target is not known.
+    pub fn is_met_by(&self, hash: BlockHash) -> Option<bool> {
+        self.current_target().map(|target| target.is_met_by(hash))
+    }
+}
+
+/// Models the result of JSON-RPC method `getblocktemplate`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetBlockTemplate {
+    /// Specific client side supported features.
+    pub capabilities: Vec<String>,
+    /// The preferred block version.
+    pub version: block::Version,
+    /// Specific block rules that are to be enforced.
+    pub rules: Vec<String>,
+    /// Set of pending, supported versionbit (BIP-9) softfork deployments, rule name to bit number.
+    pub version_bits_available: BTreeMap<String, u32>,
+    /// Bit mask of versionbits the server requires set in submissions.
+    pub version_bits_required: u32,
+    /// The hash of current highest block.
+    pub previous_block_hash: BlockHash,
+    /// Non-coinbase transactions that should be included in the next block.
+    pub transactions: Vec<BlockTemplateTransaction>,
+    /// Data that should be included in the coinbase's scriptSig content.
+    pub coinbase_aux: BTreeMap<String, String>,
+    /// Maximum allowable input to coinbase transaction, including the generation award and
+    /// transaction fees.
+    pub coinbase_value: Amount,
+    /// An id to include with a request to longpoll on an update to this template.
+    pub long_poll_id: String,
+    /// The hash target.
+    pub target: Target,
+    /// The minimum timestamp appropriate for next block time in seconds since epoch.
+    pub min_time: u32,
+    /// List of ways the block template may be changed.
+    pub mutable: Vec<String>,
+    /// A range of valid nonces.
+    pub nonce_range: String,
+    /// Limit of sigops in blocks.
+    pub sigop_limit: u32,
+    /// Limit of block size.
+    pub size_limit: u32,
+    /// Limit of block weight.
+    pub weight_limit: Weight,
+    /// Current timestamp in seconds since epoch.
+    pub current_time: u32,
+    /// Compressed target of next block.
+    pub bits: CompactTarget,
+    /// The height of the next block.
+    pub height: u32,
+    /// The block challenge (aka. block script), only present on signet.
+    pub signet_challenge: Option<ScriptBuf>,
+    /// A valid witness commitment for the unmodified block template.
+    pub default_witness_commitment: Option<ScriptBuf>,
+}
+
+impl GetBlockTemplate {
+    /// Returns true if the `bits` and `target` fields describe the same target.
+    pub fn is_consistent(&self) -> bool { Target::from_compact(self.bits) == self.target }
+
+    /// Returns true if `hash` meets the template's target i.e., is valid proof of work.
+    ///
+    /// To validate shares against an easier share target use `Target::is_met_by` directly.
+    pub fn is_met_by(&self, hash: BlockHash) -> bool { self.target.is_met_by(hash) }
+
+    /// Validates a candidate header built from this template.
+    ///
+    /// Checks the header builds on `previous_block_hash`, commits to `bits`, and that its hash
+    /// meets the target. Returns the block hash if the header is valid.
+    pub fn validate_header(
+        &self,
+        header: &block::Header,
+    ) -> Result<BlockHash, TemplateHeaderError> {
+        use TemplateHeaderError as E;
+
+        if header.prev_blockhash != self.previous_block_hash {
+            return Err(E::PrevBlockHash(header.prev_blockhash));
+        }
+        if header.bits != self.bits {
+            return Err(E::Bits(header.bits));
+        }
+        header.validate_pow(self.target).map_err(|_| E::ProofOfWork(header.block_hash()))
+    }
+}
+
+/// Error when validating a header against a [`GetBlockTemplate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateHeaderError {
+    /// The header does not build on the template's previous block.
+    PrevBlockHash(BlockHash),
+    /// The header's compact target is not the template's.
+    Bits(CompactTarget),
+    /// The header's hash does not meet the template's target.
+    ProofOfWork(BlockHash),
+}
+
+impl fmt::Display for TemplateHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TemplateHeaderError::*;
+
+        match *self {
+            PrevBlockHash(ref hash) => write!(f, "header builds on unexpected block {}", hash),
+            Bits(ref bits) => write!(f, "header has unexpected bits {:#010x}", bits.to_consensus()),
+            ProofOfWork(ref hash) => write!(f, "block hash {} does not meet the target", hash),
+        }
+    }
+}
+
+impl std::error::Error for TemplateHeaderError {}
+
+/// A transaction in the result of JSON-RPC method `getblocktemplate`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BlockTemplateTransaction {
+    /// The transaction.
+    pub data: Transaction,
+    /// The transaction id.
+    pub txid: Txid,
+    /// The witness transaction id.
+    pub wtxid: Wtxid,
+    /// Transactions before this one (by 1-based index in `transactions` list) that must be
+    /// present in the final block if this one is.
+    pub depends: Vec<u32>,
+    /// Difference in value between transaction inputs and outputs.
+    pub fee: Amount,
+    /// Total SigOps cost, as counted for purposes of block limits.
+    pub sigops: u32,
+    /// Total transaction weight, as counted for purposes of block limits.
+    pub weight: Weight,
+}
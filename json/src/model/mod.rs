@@ -18,24 +18,60 @@ mod util;
 mod wallet;
 mod zmq;
 
+pub mod analytics;
+#[cfg(feature = "export")]
+pub mod export;
+pub mod reconcile;
+pub mod schema;
+
 // TODO/QUESTIONS
 //
 // - Should all types here be non_exhaustive (otherwise evertime Core changes them we will need a new major release)?
 // - Should we provide an inherent convertion method because try_from's lack of type inference is annoying to use.
 
+#[cfg(feature = "unstable-rpc")]
+#[doc(inline)]
+pub use self::network::{AddrManEntry, AddrManPosition, GetRawAddrMan, ParseAddrManPositionError};
 #[doc(inline)]
 pub use self::{
     blockchain::{
-        Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBestBlockHash,
-        GetBlockVerbosityOne, GetBlockVerbosityZero, GetBlockchainInfo, GetTxOut, Softfork,
-        SoftforkType,
+        Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, BlockHeaderError,
+        BlockSizeBreakdown, Chain, ChainState, ChainTips, ChainTipsStatus, DumpTxOutSet,
+        GetBestBlockHash, GetBlockHash, GetBlockHeader, GetBlockHeaderVerbose,
+        GetBlockVerbosityOne, GetBlockVerbosityTwo, GetBlockVerbosityZero, GetBlockchainInfo,
+        GetChainStates, GetChainTips, GetIndexInfo, GetMempoolEntry, GetMempoolInfo, GetRawMempool,
+        GetRawMempoolVerbose, GetTxOut, GetTxOutProof, GetTxOutSetInfo, IbdState, IndexInfo,
+        LoadTxOutSet, MempoolEntry, MempoolEntryFees, ScanBlocksStart, ScanTxOutSetStart,
+        ScanTxOutSetUnspent, Softfork, SoftforkType, TxOutSetBlockInfo, TxOutSetUnspendables,
+        VerificationProgress, VerifyTxOutProof,
+    },
+    control::{GetMemoryInfoMallocInfo, GetMemoryInfoStats, Locked, LogCategory, Logging},
+    generating::{GenerateBlock, GenerateToAddress, GenerateToDescriptor},
+    mining::{BlockTemplateTransaction, GetBlockTemplate, GetMiningInfo, TemplateHeaderError},
+    network::{
+        check_service_flags_names, service_flags_from_names, service_flags_names, GetNetworkInfo,
+        GetNetworkInfoAddress, GetNetworkInfoNetwork, GetNodeAddresses, GetPeerInfo, NetworkType,
+        NodeAddress, PeerAddress, PeerInfo, ServiceNamesError, UserAgent, UserAgentComponent,
+    },
+    raw_transactions::{
+        CombinePsbt, CreateRawTransaction, DecodePsbt, DecodeRawTransaction, FinalizePsbt,
+        FundRawTransaction, GetRawTransaction, MempoolAcceptance, RejectionKind,
+        SendRawTransaction, SignFailure, SignRawTransaction, TestMempoolAccept,
     },
-    generating::GenerateToAddress,
-    network::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork},
-    raw_transactions::SendRawTransaction,
+    schema::MODEL_SCHEMA_VERSION,
+    util::{DeriveAddresses, EstimateSmartFee, GetDescriptorInfo},
     wallet::{
-        CreateWallet, GetBalance, GetBalances, GetBalancesMine, GetBalancesWatchOnly,
-        GetNewAddress, GetTransaction, GetTransactionDetail, GetTransactionDetailCategory,
-        LoadWallet, SendToAddress, UnloadWallet,
+        AddMultisigAddress, AddressInformation, AddressPurpose, AddressType, AddressTypeDefaults,
+        Bip125Replaceable, BumpFee, CreateWallet, GetAddressInfo, GetAddressesByLabel, GetBalance,
+        GetBalances, GetBalancesMine, GetBalancesWatchOnly, GetNewAddress, GetRawChangeAddress,
+        GetReceivedByAddress, GetReceivedByLabel, GetTransaction, GetTransactionDetail,
+        GetTransactionDetailCategory, GetWalletInfo, ImportDescriptors, ImportDescriptorsResult,
+        ImportMulti, ImportMultiResult, JsonRpcError, LabelAddressBalance, LastProcessedBlock,
+        ListDescriptors, ListDescriptorsItem, ListDescriptorsPrivate, ListDescriptorsPrivateItem,
+        ListLockUnspent, ListReceivedByAddress, ListReceivedByAddressItem, ListSinceBlock,
+        ListTransactions, ListUnspent, ListUnspentItem, ListUnspentItemPsbtError, ListWalletDir,
+        ListWallets, LoadWallet, PrivateDescriptor, PsbtBumpFee, RescanBlockchain, Send,
+        SendToAddress, SignMessage, SpendablePolicy, TransactionItem, UnloadWallet,
+        WalletCreateFundedPsbt, WalletInventory, WalletProcessPsbt,
     },
 };
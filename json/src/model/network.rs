@@ -5,6 +5,11 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
+use core::fmt;
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+
+use bitcoin::p2p::ServiceFlags;
 use bitcoin::FeeRate;
 use serde::{Deserialize, Serialize};
 
@@ -15,11 +20,16 @@ pub struct GetNetworkInfo {
     pub version: usize,
     /// The server subversion string.
     pub subversion: String,
+    /// The server subversion string, parsed.
+    pub user_agent: UserAgent,
     /// The protocol version.
     pub protocol_version: usize,
-    /// The services we offer to the network (hex string).
-    pub local_services: String,
+    /// The services we offer to the network.
+    #[serde(with = "serde_service_flags")]
+    pub local_services: ServiceFlags,
     /// The services we offer to the network, in human-readable form.
+    ///
+    /// Uses Core's names, computed from `local_services` if the node did not return them.
     pub local_services_names: Vec<String>,
     /// `true` if transaction relay is requested from peers.
     pub local_relay: bool,
@@ -54,8 +64,8 @@ pub struct GetNetworkInfoNetwork {
     pub limited: bool,
     /// Is the network reachable?
     pub reachable: bool,
-    /// ("host:port"): The proxy that is used for this network, or empty if none.
-    pub proxy: String,
+    /// The proxy that is used for this network, if any.
+    pub proxy: Option<PeerAddress>,
     /// Whether randomized credentials are used.
     pub proxy_randomize_credentials: bool,
 }
@@ -70,3 +80,515 @@ pub struct GetNetworkInfoAddress {
     /// Relative score
     pub score: u32,
 }
+
+impl GetNetworkInfoAddress {
+    /// Returns the address as a socket address, `None` if it is not an IP address (e.g. Tor).
+    pub fn socket_addr(&self) -> Option<SocketAddr> {
+        self.address.parse::<IpAddr>().ok().map(|ip| SocketAddr::new(ip, self.port))
+    }
+}
+
+/// Models the result of JSON-RPC method `getnodeaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetNodeAddresses(pub Vec<NodeAddress>);
+
+impl GetNodeAddresses {
+    /// Returns the addresses grouped by the network they are reachable on.
+    pub fn by_network(&self) -> BTreeMap<NetworkType, Vec<&NodeAddress>> {
+        let mut map = BTreeMap::<NetworkType, Vec<&NodeAddress>>::new();
+        for addr in &self.0 {
+            map.entry(addr.network.clone()).or_default().push(addr);
+        }
+        map
+    }
+
+    /// Returns an iterator over the addresses on `network`.
+    pub fn on_network<'a>(
+        &'a self,
+        network: &'a NetworkType,
+    ) -> impl Iterator<Item = &'a NodeAddress> + 'a {
+        self.0.iter().filter(move |addr| addr.network == *network)
+    }
+}
+
+/// An item from the list returned by the JSON-RPC method `getnodeaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NodeAddress {
+    /// Timestamp in seconds since epoch (Jan 1 1970 GMT) when the node was last seen.
+    pub time: u64,
+    /// The services offered by the node.
+    #[serde(with = "serde_service_flags")]
+    pub services: ServiceFlags,
+    /// The address of the node.
+    pub address: String,
+    /// The port number of the node.
+    pub port: u16,
+    /// The network the node is reachable on (inferred from `address` before Core v22).
+    pub network: NetworkType,
+}
+
+/// Models the result of JSON-RPC method `getrawaddrman`.
+#[cfg(feature = "unstable-rpc")]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawAddrMan {
+    /// The entries of the "new" table, addresses we have not yet connected to.
+    pub new: BTreeMap<AddrManPosition, AddrManEntry>,
+    /// The entries of the "tried" table, addresses we have successfully connected to.
+    pub tried: BTreeMap<AddrManPosition, AddrManEntry>,
+}
+
+/// The location of an entry in an address manager table.
+///
+/// Formatted as "<bucket>/<position>", as used by Core.
+#[cfg(feature = "unstable-rpc")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AddrManPosition {
+    /// The bucket index.
+    pub bucket: u32,
+    /// The position within the bucket.
+    pub position: u32,
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl fmt::Display for AddrManPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.bucket, self.position)
+    }
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl core::str::FromStr for AddrManPosition {
+    type Err = ParseAddrManPositionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseAddrManPositionError(s.to_owned());
+        let (bucket, position) = s.split_once('/').ok_or_else(err)?;
+        Ok(AddrManPosition {
+            bucket: bucket.parse().map_err(|_| err())?,
+            position: position.parse().map_err(|_| err())?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl TryFrom<String> for AddrManPosition {
+    type Error = ParseAddrManPositionError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> { s.parse() }
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl From<AddrManPosition> for String {
+    fn from(position: AddrManPosition) -> Self { position.to_string() }
+}
+
+/// Error parsing an [`AddrManPosition`], the string is not of the form "<bucket>/<position>".
+#[cfg(feature = "unstable-rpc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseAddrManPositionError(pub String);
+
+#[cfg(feature = "unstable-rpc")]
+impl fmt::Display for ParseAddrManPositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid address manager position (expected <bucket>/<position>): {}", self.0)
+    }
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl std::error::Error for ParseAddrManPositionError {}
+
+/// An address manager entry, part of `GetRawAddrMan`.
+#[cfg(feature = "unstable-rpc")]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddrManEntry {
+    /// The address of the node.
+    pub address: String,
+    /// The port number of the node.
+    pub port: u16,
+    /// The network the address is on.
+    pub network: NetworkType,
+    /// The services offered by the node.
+    #[serde(with = "serde_service_flags")]
+    pub services: ServiceFlags,
+    /// Timestamp in seconds since epoch (Jan 1 1970 GMT) when the node was last seen.
+    pub time: u64,
+    /// The address that relayed the address to us.
+    pub source: String,
+    /// The network the source address is on.
+    pub source_network: NetworkType,
+}
+
+/// Models the result of JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+impl GetPeerInfo {
+    /// Returns the peers with a ban score of at least `threshold`.
+    ///
+    /// Core v22 removed ban scores (v21 only reports them with `-deprecatedrpc=banscore`), peers
+    /// without a ban score are never returned.
+    pub fn misbehaving(&self, threshold: i64) -> impl Iterator<Item = &PeerInfo> {
+        self.0.iter().filter(move |peer| peer.ban_score.map_or(false, |score| score >= threshold))
+    }
+}
+
+/// An item from the list returned by the JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u64,
+    /// The address and port of the peer.
+    pub addr: PeerAddress,
+    /// Bind address of the connection to the peer.
+    pub addr_bind: Option<PeerAddress>,
+    /// Local address as reported by the peer.
+    pub addr_local: Option<PeerAddress>,
+    /// The network the peer connected through (Core v21 onwards).
+    pub network: Option<NetworkType>,
+    /// The services offered by the peer.
+    #[serde(with = "serde_service_flags")]
+    pub services: ServiceFlags,
+    /// Whether the peer has asked us to relay transactions to it.
+    pub relay_txes: bool,
+    /// The total bytes sent.
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    pub bytes_recv: u64,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// The UNIX epoch time of the connection.
+    pub connection_time: u64,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The peer's user agent string.
+    pub subversion: String,
+    /// The starting height (block) of the peer.
+    pub starting_height: i64,
+    /// The last header we have in common with this peer, -1 if unknown.
+    pub synced_headers: i64,
+    /// The last block we have in common with this peer, -1 if unknown.
+    pub synced_blocks: i64,
+    /// The type of the connection (Core v21 onwards).
+    pub connection_type: Option<String>,
+    /// Any special permissions that have been granted to this peer (Core v21 onwards).
+    pub permissions: Vec<String>,
+    /// Whether we selected the peer as a compact blocks high-bandwidth peer (Core v22 onwards).
+    pub bip152_hb_to: Option<bool>,
+    /// Whether the peer selected us as a compact blocks high-bandwidth peer (Core v22 onwards).
+    pub bip152_hb_from: Option<bool>,
+    /// Whether we participate in address relay with this peer (Core v23 onwards).
+    pub addr_relay_enabled: Option<bool>,
+    /// The transport protocol, "v1", "v2" or "detecting" (Core v26 onwards).
+    pub transport_protocol_type: Option<String>,
+    /// The ban score (Core v17 to v20, and v21 with `-deprecatedrpc=banscore`).
+    pub ban_score: Option<i64>,
+    /// Whether the peer is whitelisted (Core v17 to v20).
+    pub whitelisted: Option<bool>,
+    /// Whether the connection was made using `addnode` (Core v17 to v20).
+    pub addnode: Option<bool>,
+}
+
+/// The address of a node.
+///
+/// Addresses on networks other than IPv4 and IPv6 (e.g. Tor, I2P) are kept in the `Other` variant,
+/// as formatted by Core.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum PeerAddress {
+    /// An IPv4 or IPv6 socket address.
+    Ip(SocketAddr),
+    /// Any other address ("host:port").
+    Other(String),
+}
+
+impl PeerAddress {
+    /// Parses an address as returned by Core, never fails.
+    pub fn parse(s: &str) -> Self {
+        match s.parse::<SocketAddr>() {
+            Ok(addr) => PeerAddress::Ip(addr),
+            Err(_) => PeerAddress::Other(s.to_owned()),
+        }
+    }
+
+    /// Parses an optional address, Core reports missing addresses as an empty string.
+    pub fn parse_optional(s: &str) -> Option<Self> {
+        if s.is_empty() {
+            None
+        } else {
+            Some(Self::parse(s))
+        }
+    }
+
+    /// Returns the socket address, `None` if this is not an IP address.
+    pub fn socket_addr(&self) -> Option<SocketAddr> {
+        match *self {
+            PeerAddress::Ip(addr) => Some(addr),
+            PeerAddress::Other(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for PeerAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PeerAddress::Ip(ref addr) => fmt::Display::fmt(addr, f),
+            PeerAddress::Other(ref s) => f.write_str(s),
+        }
+    }
+}
+
+/// A BIP-14 user agent, e.g. the `subversion` field of `getnetworkinfo` or `subver` of a peer.
+///
+/// User agents are of the form `/Name:Version(comment; comment)/Name:Version/`. Parsing never
+/// fails, anything that does not follow BIP-14 is kept in `raw` with no components.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UserAgent {
+    /// The user agent string as returned by Core.
+    pub raw: String,
+    /// The components in the order they appear, the first is usually the client software.
+    pub components: Vec<UserAgentComponent>,
+}
+
+/// A single `Name:Version(comments)` component of a [`UserAgent`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UserAgentComponent {
+    /// The name of the software, e.g. "Satoshi".
+    pub name: String,
+    /// The version, if present, e.g. "26.0.0".
+    pub version: Option<String>,
+    /// The comments, if any.
+    pub comments: Vec<String>,
+}
+
+impl UserAgent {
+    /// Parses a BIP-14 user agent, never fails.
+    pub fn parse(s: &str) -> Self {
+        UserAgent { raw: s.to_owned(), components: Self::components(s).unwrap_or_default() }
+    }
+
+    /// Returns the first component, usually the client software.
+    pub fn client(&self) -> Option<&UserAgentComponent> { self.components.first() }
+
+    fn components(s: &str) -> Option<Vec<UserAgentComponent>> {
+        let inner = s.strip_prefix('/')?.strip_suffix('/')?;
+        let mut components = vec![];
+        for part in inner.split('/') {
+            let (head, comments) = match part.find('(') {
+                Some(i) => {
+                    let comments = part[i + 1..].strip_suffix(')')?;
+                    (&part[..i], comments.split(';').map(|c| c.trim().to_owned()).collect())
+                }
+                None => (part, vec![]),
+            };
+            let (name, version) = match head.split_once(':') {
+                Some((name, version)) => (name, Some(version.to_owned())),
+                None => (head, None),
+            };
+            if name.is_empty() {
+                return None;
+            }
+            components.push(UserAgentComponent { name: name.to_owned(), version, comments });
+        }
+        Some(components)
+    }
+}
+
+impl fmt::Display for UserAgent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(&self.raw) }
+}
+
+/// Core's names for the service flags it knows about, used by `servicesnames` fields.
+const SERVICE_NAMES: [(ServiceFlags, &str); 7] = [
+    (ServiceFlags::NETWORK, "NETWORK"),
+    (ServiceFlags::GETUTXO, "GETUTXO"),
+    (ServiceFlags::BLOOM, "BLOOM"),
+    (ServiceFlags::WITNESS, "WITNESS"),
+    (ServiceFlags::COMPACT_FILTERS, "COMPACT_FILTERS"),
+    (ServiceFlags::NETWORK_LIMITED, "NETWORK_LIMITED"),
+    (ServiceFlags::P2P_V2, "P2P_V2"),
+];
+
+/// Returns the names Core uses for each bit set in `flags`, lowest bit first.
+///
+/// Unknown bits are named `UNKNOWN[2^n]`, as Core does.
+pub fn service_flags_names(flags: ServiceFlags) -> Vec<String> {
+    let bits = flags.to_u64();
+    (0..64)
+        .filter(|bit| bits & (1 << bit) != 0)
+        .map(|bit| {
+            let flag = ServiceFlags::from(1_u64 << bit);
+            match SERVICE_NAMES.iter().find(|(f, _)| *f == flag) {
+                Some((_, name)) => (*name).to_owned(),
+                None => format!("UNKNOWN[2^{}]", bit),
+            }
+        })
+        .collect()
+}
+
+/// Converts a list of service names, as returned by Core, into service flags.
+///
+/// Accepts the `UNKNOWN[2^n]` form for bits that Core does not have a name for.
+pub fn service_flags_from_names<S: AsRef<str>>(
+    names: &[S],
+) -> Result<ServiceFlags, ServiceNamesError> {
+    let mut flags = ServiceFlags::NONE;
+    for name in names {
+        let name = name.as_ref();
+        let flag = match SERVICE_NAMES.iter().find(|(_, n)| *n == name) {
+            Some((flag, _)) => *flag,
+            None => name
+                .strip_prefix("UNKNOWN[2^")
+                .and_then(|s| s.strip_suffix(']'))
+                .and_then(|bit| bit.parse::<u32>().ok())
+                .and_then(|bit| 1_u64.checked_shl(bit))
+                .map(ServiceFlags::from)
+                .ok_or_else(|| ServiceNamesError::UnknownName(name.to_owned()))?,
+        };
+        flags |= flag;
+    }
+    Ok(flags)
+}
+
+/// Checks that `names` (e.g. `localservicesnames`) describes the same services as `flags`.
+pub fn check_service_flags_names<S: AsRef<str>>(
+    flags: ServiceFlags,
+    names: &[S],
+) -> Result<(), ServiceNamesError> {
+    let from_names = service_flags_from_names(names)?;
+    if from_names != flags {
+        return Err(ServiceNamesError::Mismatch { flags, names: from_names });
+    }
+    Ok(())
+}
+
+/// Error when service names do not match the service flags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceNamesError {
+    /// A service name that is not known and not of the form `UNKNOWN[2^n]`.
+    UnknownName(String),
+    /// The names describe different services to the flags.
+    Mismatch {
+        /// The service flags.
+        flags: ServiceFlags,
+        /// The service flags described by the names.
+        names: ServiceFlags,
+    },
+}
+
+impl fmt::Display for ServiceNamesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ServiceNamesError::*;
+
+        match *self {
+            UnknownName(ref name) => write!(f, "unknown service name: {}", name),
+            Mismatch { flags, names } =>
+                write!(f, "service names ({}) do not match the service flags ({})", names, flags),
+        }
+    }
+}
+
+impl std::error::Error for ServiceNamesError {}
+
+/// Serializes `ServiceFlags` as its integer representation.
+mod serde_service_flags {
+    use bitcoin::p2p::ServiceFlags;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(flags: &ServiceFlags, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u64(flags.to_u64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<ServiceFlags, D::Error> {
+        Ok(ServiceFlags::from(u64::deserialize(d)?))
+    }
+}
+
+/// A network a peer can be reached on, as used by the network related RPC methods.
+///
+/// Unknown names (e.g. a network added in a future release) are kept in the `Other` variant.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum NetworkType {
+    /// IPv4 ("ipv4").
+    Ipv4,
+    /// IPv6 ("ipv6").
+    Ipv6,
+    /// Tor ("onion").
+    Onion,
+    /// I2P ("i2p", Core v22 onwards).
+    I2p,
+    /// CJDNS ("cjdns", Core v23 onwards).
+    Cjdns,
+    /// A network name not known to this library.
+    Other(String),
+}
+
+impl NetworkType {
+    /// Parses the network name as returned by Core, never fails.
+    pub fn from_core_arg(s: &str) -> Self {
+        match s {
+            "ipv4" => NetworkType::Ipv4,
+            "ipv6" => NetworkType::Ipv6,
+            "onion" => NetworkType::Onion,
+            "i2p" => NetworkType::I2p,
+            "cjdns" => NetworkType::Cjdns,
+            other => NetworkType::Other(other.to_owned()),
+        }
+    }
+
+    /// Returns the network name as used by Core.
+    pub fn to_core_arg(&self) -> &str {
+        match *self {
+            NetworkType::Ipv4 => "ipv4",
+            NetworkType::Ipv6 => "ipv6",
+            NetworkType::Onion => "onion",
+            NetworkType::I2p => "i2p",
+            NetworkType::Cjdns => "cjdns",
+            NetworkType::Other(ref s) => s,
+        }
+    }
+
+    /// Infers the network from an address string, for Core versions that do not report it.
+    ///
+    /// CJDNS addresses are indistinguishable from IPv6 addresses so are reported as `Ipv6`,
+    /// addresses that cannot be classified are reported as `Other("unknown")`.
+    pub fn from_address(address: &str) -> Self {
+        if address.ends_with(".onion") {
+            return NetworkType::Onion;
+        }
+        if address.ends_with(".i2p") {
+            return NetworkType::I2p;
+        }
+        match address.parse::<IpAddr>() {
+            Ok(IpAddr::V4(_)) => NetworkType::Ipv4,
+            Ok(IpAddr::V6(_)) => NetworkType::Ipv6,
+            Err(_) => NetworkType::Other("unknown".to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for NetworkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.to_core_arg()) }
+}
+
+impl From<String> for NetworkType {
+    fn from(s: String) -> Self { NetworkType::from_core_arg(&s) }
+}
+
+impl From<NetworkType> for String {
+    fn from(network: NetworkType) -> Self { network.to_core_arg().to_owned() }
+}
+
+impl fmt::Display for GetNetworkInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "version: {} {}", self.version, self.subversion)?;
+        writeln!(f, "protocol version: {}", self.protocol_version)?;
+        write!(f, "connections: {}", self.connections)?;
+        if self.connections_in != 0 || self.connections_out != 0 {
+            write!(f, " ({} in, {} out)", self.connections_in, self.connections_out)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "network active: {}", self.network_active)?;
+        write!(f, "warnings: {}", if self.warnings.is_empty() { "none" } else { &self.warnings })
+    }
+}
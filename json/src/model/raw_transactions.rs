@@ -5,9 +5,148 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
-use bitcoin::Txid;
+use core::fmt;
+
+use bitcoin::{Amount, Psbt, ScriptBuf, Sequence, Transaction, Txid};
 use serde::{Deserialize, Serialize};
 
+/// Models the result of JSON-RPC method `combinepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CombinePsbt(pub Psbt);
+
+/// Models the result of JSON-RPC method `createrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateRawTransaction(pub Transaction);
+
+/// Models the result of JSON-RPC method `decoderawtransaction`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeRawTransaction(pub Transaction);
+
+/// Models the result of JSON-RPC method `fundrawtransaction`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FundRawTransaction {
+    /// The funded transaction, inputs are not signed.
+    pub tx: Transaction,
+    /// The fee the resulting transaction pays.
+    pub fee: Amount,
+    /// The position of the added change output, `None` if no change output was added.
+    pub change_position: Option<u32>,
+}
+
+/// Models the result of JSON-RPC method `getrawtransaction` with verbose set to `false`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawTransaction(pub Transaction);
+
+/// Models the result of JSON-RPC methods `signrawtransactionwithwallet` and
+/// `signrawtransactionwithkey`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignRawTransaction {
+    /// The transaction with signature(s).
+    pub tx: Transaction,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// Script verification errors, empty if there are none.
+    pub errors: Vec<SignFailure>,
+}
+
+/// A script verification error, part of [`SignRawTransaction`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignFailure {
+    /// The id of the transaction containing the output spent by the input.
+    pub txid: Txid,
+    /// The index of the output spent by the input.
+    pub vout: u32,
+    /// The signature script.
+    pub script_sig: ScriptBuf,
+    /// The input's sequence number.
+    pub sequence: Sequence,
+    /// Verification or signing error related to the input.
+    pub error: String,
+}
+
+/// Models the result of JSON-RPC method `decodepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DecodePsbt {
+    /// The PSBT, excluding unknown fields and fields not returned by all versions of Core.
+    pub psbt: Psbt,
+    /// The transaction fee paid, if all the UTXOs are known.
+    pub fee: Option<Amount>,
+}
+
+/// Models the result of JSON-RPC method `finalizepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FinalizePsbt {
+    /// The partially signed transaction, if not extracted.
+    pub psbt: Option<Psbt>,
+    /// The finalized transaction, if extracted.
+    pub tx: Option<Transaction>,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+}
+
 /// Models the result of JSON-RPC method `sendrawtransaction`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct SendRawTransaction(pub Txid);
+
+/// Models the result of JSON-RPC method `testmempoolaccept`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TestMempoolAccept(pub Vec<MempoolAcceptance>);
+
+/// The mempool acceptance of a single transaction, part of [`TestMempoolAccept`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MempoolAcceptance {
+    /// The transaction id.
+    pub txid: Txid,
+    /// If the mempool allows this transaction to be inserted.
+    pub allowed: bool,
+    /// Rejection string, without the reject code older versions of Core prefix it with (only
+    /// present when `allowed` is false).
+    pub reject_reason: Option<String>,
+}
+
+impl MempoolAcceptance {
+    /// Returns the kind of rule the transaction was rejected by, `None` if it was allowed.
+    pub fn rejection_kind(&self) -> Option<RejectionKind> {
+        match self.reject_reason {
+            Some(ref reason) if !self.allowed => Some(RejectionKind::from_reason(reason)),
+            None if !self.allowed => Some(RejectionKind::Policy),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of rule a transaction was rejected by when testing for mempool acceptance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum RejectionKind {
+    /// The transaction is invalid, it can never be mined as is.
+    Consensus,
+    /// The transaction is valid but this node's policy (standardness, fees, mempool state)
+    /// rejects it, it may be accepted by other nodes or later on.
+    Policy,
+}
+
+impl RejectionKind {
+    /// Classifies a Core reject reason, e.g. "bad-txns-inputs-missingorspent".
+    ///
+    /// Reasons Core reports for consensus failures are the `bad-txns-*`, `bad-cb-*` and
+    /// `mandatory-script-verify-flag-failed` families, everything else is policy.
+    pub fn from_reason(reason: &str) -> Self {
+        const CONSENSUS_PREFIXES: [&str; 3] =
+            ["bad-txns-", "bad-cb-", "mandatory-script-verify-flag-failed"];
+
+        if CONSENSUS_PREFIXES.iter().any(|prefix| reason.starts_with(prefix)) {
+            RejectionKind::Consensus
+        } else {
+            RejectionKind::Policy
+        }
+    }
+}
+
+impl fmt::Display for RejectionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RejectionKind::Consensus => f.write_str("consensus"),
+            RejectionKind::Policy => f.write_str("policy"),
+        }
+    }
+}
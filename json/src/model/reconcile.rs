@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Reconciliation of wallet transaction data returned by different JSON-RPC methods.
+//!
+//! Core reports information about a wallet transaction via `gettransaction`, `listtransactions`
+//! and `listsinceblock`. Accounting tools often cross-check these, this module merges the views
+//! of a single transaction and records any fields that disagree.
+//!
+//! Note that if the RPC results were fetched at different times (e.g. either side of a block being
+//! mined) some fields, in particular `confirmations`, are expected to differ.
+
+use core::fmt;
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::{Address, BlockHash, SignedAmount, Txid};
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    GetTransaction, GetTransactionDetailCategory, ListSinceBlock, ListTransactions, TransactionItem,
+};
+
+/// Records the mismatch, if any, from checking a field.
+macro_rules! check {
+    ($r:ident.$field:ident, $vout:expr, $value:expr, $source:expr) => {
+        if let Some(m) = $r.$field.check(stringify!($field), $vout, $value, $source) {
+            $r.mismatches.push(m);
+        }
+    };
+}
+
+/// The JSON-RPC method a piece of transaction data came from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Source {
+    /// The `gettransaction` method.
+    GetTransaction,
+    /// The `listtransactions` method.
+    ListTransactions,
+    /// The `listsinceblock` method.
+    ListSinceBlock,
+}
+
+/// A field that has different values depending on the source it was read from.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Mismatch {
+    /// The name of the field (as in the model types).
+    pub field: String,
+    /// The output index, for per-output fields.
+    pub vout: Option<u32>,
+    /// The source the field was first read from, and its value.
+    pub expected: (Source, String),
+    /// The source that disagrees, and its value.
+    pub got: (Source, String),
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "field `{}`", self.field)?;
+        if let Some(vout) = self.vout {
+            write!(f, " (vout {})", vout)?;
+        }
+        write!(
+            f,
+            " mismatch: {:?} reported {} but {:?} reported {}",
+            self.expected.0, self.expected.1, self.got.0, self.got.1
+        )
+    }
+}
+
+/// A single wallet-relevant output of a reconciled transaction.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ReconciledEntry {
+    /// The vout value.
+    pub vout: u32,
+    /// The transaction category.
+    pub category: GetTransactionDetailCategory,
+    /// The amount, negative for the 'send' category.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub amount: SignedAmount,
+    /// The address, if any source reported one.
+    pub address: Option<Address<NetworkUnchecked>>,
+    /// The label, if any source reported one.
+    pub label: Option<String>,
+}
+
+/// A reconciled view of a single wallet transaction.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ReconciledTransaction {
+    /// The transaction id.
+    pub txid: Txid,
+    /// The sources that had data about this transaction.
+    pub sources: Vec<Source>,
+    /// The number of confirmations, negative if conflicted.
+    pub confirmations: Option<i64>,
+    /// The block hash containing the transaction.
+    pub block_hash: Option<BlockHash>,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    pub time: Option<u64>,
+    /// The time received in seconds since epoch (Jan 1 1970 GMT).
+    pub time_received: Option<u64>,
+    /// The fee, negative and only available if the wallet sent the transaction.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc::opt")]
+    pub fee: Option<SignedAmount>,
+    /// The wallet-relevant outputs, one per `(vout, category)` pair.
+    pub entries: Vec<ReconciledEntry>,
+    /// All the fields where sources disagree.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl ReconciledTransaction {
+    /// Returns true if all sources agree.
+    pub fn is_consistent(&self) -> bool { self.mismatches.is_empty() }
+}
+
+/// Reconciles the data about `txid` found in any of the given RPC results.
+///
+/// The first source to report a field is used as the reference value, later sources that
+/// disagree are recorded in [`ReconciledTransaction::mismatches`]. Sources are considered in
+/// the order `gettransaction`, `listtransactions`, `listsinceblock`. Removed transactions in
+/// the `listsinceblock` result are ignored.
+pub fn reconcile_transaction(
+    txid: Txid,
+    get_transaction: Option<&GetTransaction>,
+    list_transactions: Option<&ListTransactions>,
+    list_since_block: Option<&ListSinceBlock>,
+) -> ReconciledTransaction {
+    let mut r = Reconciler::new(txid);
+
+    if let Some(tx) = get_transaction.filter(|tx| tx.txid == txid) {
+        let source = Source::GetTransaction;
+        r.sources.push(source);
+        check!(r.confirmations, None, i64::from(tx.confirmations), source);
+        check!(r.time, None, tx.time, source);
+        check!(r.time_received, None, tx.time_received, source);
+        if let Some(fee) = tx.fee {
+            check!(r.fee, None, fee, source);
+        }
+        for detail in &tx.details {
+            r.entry(
+                detail.vout,
+                detail.category,
+                detail.amount,
+                Some(&detail.address),
+                detail.label.as_ref(),
+                source,
+            );
+        }
+    }
+
+    if let Some(list) = list_transactions {
+        r.items(&list.0, Source::ListTransactions);
+    }
+    if let Some(list) = list_since_block {
+        r.items(&list.transactions, Source::ListSinceBlock);
+    }
+
+    r.finish()
+}
+
+/// A value read from one of the sources, along with the source.
+struct Field<T> {
+    value: Option<(T, Source)>,
+}
+
+impl<T: PartialEq + fmt::Debug> Field<T> {
+    fn new() -> Self { Field { value: None } }
+
+    /// Sets the value if not yet set, otherwise returns a mismatch if `value` differs.
+    fn check(
+        &mut self,
+        field: &'static str,
+        vout: Option<u32>,
+        value: T,
+        source: Source,
+    ) -> Option<Mismatch> {
+        match self.value {
+            None => {
+                self.value = Some((value, source));
+                None
+            }
+            Some((ref expected, expected_source)) if *expected != value => Some(Mismatch {
+                field: field.to_owned(),
+                vout,
+                expected: (expected_source, format!("{:?}", expected)),
+                got: (source, format!("{:?}", value)),
+            }),
+            Some(_) => None,
+        }
+    }
+
+    fn into_value(self) -> Option<T> { self.value.map(|(value, _)| value) }
+}
+
+struct Entry {
+    vout: u32,
+    category: GetTransactionDetailCategory,
+    amount: Field<SignedAmount>,
+    address: Option<Address<NetworkUnchecked>>,
+    label: Option<String>,
+}
+
+struct Reconciler {
+    txid: Txid,
+    sources: Vec<Source>,
+    confirmations: Field<i64>,
+    block_hash: Field<BlockHash>,
+    time: Field<u64>,
+    time_received: Field<u64>,
+    fee: Field<SignedAmount>,
+    entries: Vec<Entry>,
+    mismatches: Vec<Mismatch>,
+}
+
+impl Reconciler {
+    fn new(txid: Txid) -> Self {
+        Reconciler {
+            txid,
+            sources: vec![],
+            confirmations: Field::new(),
+            block_hash: Field::new(),
+            time: Field::new(),
+            time_received: Field::new(),
+            fee: Field::new(),
+            entries: vec![],
+            mismatches: vec![],
+        }
+    }
+
+    fn items(&mut self, items: &[TransactionItem], source: Source) {
+        let txid = self.txid;
+        let mut found = false;
+        for item in items.iter().filter(|item| item.txid == txid) {
+            found = true;
+            let r = &mut *self;
+            check!(r.confirmations, None, item.confirmations, source);
+            if let Some(hash) = item.block_hash {
+                check!(r.block_hash, None, hash, source);
+            }
+            check!(r.time, None, item.time, source);
+            check!(r.time_received, None, item.time_received, source);
+            if let Some(fee) = item.fee {
+                check!(r.fee, None, fee, source);
+            }
+            self.entry(
+                item.vout,
+                item.category,
+                item.amount,
+                item.address.as_ref(),
+                item.label.as_ref(),
+                source,
+            );
+        }
+        if found {
+            self.sources.push(source);
+        }
+    }
+
+    fn entry(
+        &mut self,
+        vout: u32,
+        category: GetTransactionDetailCategory,
+        amount: SignedAmount,
+        address: Option<&Address<NetworkUnchecked>>,
+        label: Option<&String>,
+        source: Source,
+    ) {
+        let index = match self.entries.iter().position(|e| e.vout == vout && e.category == category)
+        {
+            Some(index) => index,
+            None => {
+                self.entries.push(Entry {
+                    vout,
+                    category,
+                    amount: Field::new(),
+                    address: None,
+                    label: None,
+                });
+                self.entries.len() - 1
+            }
+        };
+        let entry = &mut self.entries[index];
+
+        if let Some(m) = entry.amount.check("amount", Some(vout), amount, source) {
+            self.mismatches.push(m);
+        }
+        if entry.address.is_none() {
+            entry.address = address.cloned();
+        }
+        if entry.label.is_none() {
+            entry.label = label.cloned();
+        }
+    }
+
+    fn finish(self) -> ReconciledTransaction {
+        let entries = self
+            .entries
+            .into_iter()
+            .map(|e| ReconciledEntry {
+                vout: e.vout,
+                category: e.category,
+                amount: e.amount.into_value().expect("entries are only created with an amount"),
+                address: e.address,
+                label: e.label,
+            })
+            .collect();
+
+        ReconciledTransaction {
+            txid: self.txid,
+            sources: self.sources,
+            confirmations: self.confirmations.into_value(),
+            block_hash: self.block_hash.into_value(),
+            time: self.time.into_value(),
+            time_received: self.time_received.into_value(),
+            fee: self.fee.into_value(),
+            entries,
+            mismatches: self.mismatches,
+        }
+    }
+}
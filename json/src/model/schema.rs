@@ -0,0 +1,327 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Versioning of the serialized form of the model types.
+//!
+//! The model types implement `Serialize` and `Deserialize` so that applications can store them,
+//! however the types change as we improve them, so data written by one release of this crate may
+//! not deserialize with a later one. To help with this, values can be wrapped in a [`Versioned`]
+//! envelope that records the [`MODEL_SCHEMA_VERSION`] they were written with. When reading the
+//! envelope back the payload is upgraded to the current schema using [`Migrate`].
+//!
+//! Schema versions:
+//!
+//! - 1: The initial release.
+//! - 2: `GetBlockchainInfo`: `chain` is a [`Chain`] (Core chain names instead of `bitcoin::Network`
+//!   names), `warnings` is a list, and the `time` field was added.
+//! - 3: `GetNetworkInfo`: `local_services` is the integer service flags instead of a hex string,
+//!   `local_services_names` is always populated, and the `user_agent` field was added.
+//! - 4: `GetNetworkInfo`: the network `proxy` is an optional [`PeerAddress`] instead of a string.
+//!   `GetPeerInfo`: `addr` is a [`PeerAddress`], and the `addr_bind`, `addr_local`, `network`,
+//!   `relay_txes`, `bytes_sent`, `bytes_recv`, `starting_height`, `permissions`, `bip152_hb_to`,
+//!   `bip152_hb_from`, `addr_relay_enabled` and `transport_protocol_type` fields were added.
+//! - 5: `GetWalletInfo`: the `balance`, `unconfirmed_balance` and `immature_balance` fields were
+//!   added, earlier payloads can not be read.
+//!
+//! [`Chain`]: crate::model::Chain
+//! [`PeerAddress`]: crate::model::PeerAddress
+
+use core::fmt;
+
+use bitcoin::p2p::ServiceFlags;
+use internals::write_err;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::model;
+
+/// The current version of the serialized form of the model types.
+pub const MODEL_SCHEMA_VERSION: u32 = 5;
+
+/// A self-describing envelope around a model value.
+///
+/// Serializes as `{ "schema_version": <u32>, "payload": <value> }`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Versioned<T> {
+    /// The schema version `payload` was serialized with.
+    pub schema_version: u32,
+    /// The model value.
+    pub payload: T,
+}
+
+impl<T> Versioned<T> {
+    /// Wraps `payload` in an envelope tagged with the current [`MODEL_SCHEMA_VERSION`].
+    pub fn new(payload: T) -> Self { Versioned { schema_version: MODEL_SCHEMA_VERSION, payload } }
+}
+
+impl Versioned<Value> {
+    /// Upgrades the payload to the current schema and deserializes it.
+    pub fn upgrade<T: Migrate>(self) -> Result<T, SchemaError> {
+        T::migrate(self.schema_version, self.payload)
+    }
+}
+
+/// Deserializes a [`Versioned`] envelope from `s`, upgrading the payload to the current schema.
+pub fn from_versioned_str<T: Migrate>(s: &str) -> Result<T, SchemaError> {
+    let envelope: Versioned<Value> = serde_json::from_str(s).map_err(SchemaError::Json)?;
+    envelope.upgrade()
+}
+
+/// Serializes `value` wrapped in a [`Versioned`] envelope.
+pub fn to_versioned_string<T: Serialize>(value: &T) -> Result<String, SchemaError> {
+    serde_json::to_string(&Versioned::new(value)).map_err(SchemaError::Json)
+}
+
+/// A model type that can be deserialized from any supported schema version.
+pub trait Migrate: DeserializeOwned {
+    /// The oldest schema version this type can be read from.
+    const MIN_SCHEMA_VERSION: u32 = 1;
+
+    /// Upgrades `payload`, serialized with schema `version`, to the current schema in place.
+    ///
+    /// Only called for supported versions older than [`MODEL_SCHEMA_VERSION`]. The default
+    /// implementation does nothing, which is correct for types that have not changed.
+    fn upgrade_payload(_version: u32, _payload: &mut Value) -> Result<(), SchemaError> { Ok(()) }
+
+    /// Deserializes `payload`, which was serialized with schema `version`.
+    fn migrate(version: u32, mut payload: Value) -> Result<Self, SchemaError> {
+        if version < Self::MIN_SCHEMA_VERSION || version > MODEL_SCHEMA_VERSION {
+            return Err(SchemaError::UnsupportedVersion(version));
+        }
+        if version < MODEL_SCHEMA_VERSION {
+            Self::upgrade_payload(version, &mut payload)?;
+        }
+        serde_json::from_value(payload).map_err(SchemaError::Json)
+    }
+}
+
+impl Migrate for model::GetBlockchainInfo {
+    fn upgrade_payload(version: u32, payload: &mut Value) -> Result<(), SchemaError> {
+        if version == 1 {
+            // `bitcoin::Network` serialized as the `rust-bitcoin` network name.
+            if let Some(chain) = payload.get_mut("chain") {
+                let name = match chain.as_str() {
+                    Some("bitcoin") => "main",
+                    Some("testnet") => "test",
+                    Some(other) => other,
+                    None => return Err(SchemaError::InvalidPayload("chain")),
+                };
+                *chain = Value::from(name);
+            }
+            // A single, possibly empty, string.
+            if let Some(warnings) = payload.get_mut("warnings") {
+                let list = match warnings.as_str() {
+                    Some("") => vec![],
+                    Some(s) => vec![Value::from(s)],
+                    None => return Err(SchemaError::InvalidPayload("warnings")),
+                };
+                *warnings = Value::Array(list);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Migrate for model::GetNetworkInfo {
+    fn upgrade_payload(version: u32, payload: &mut Value) -> Result<(), SchemaError> {
+        if version < 3 {
+            let object = payload.as_object_mut().ok_or(SchemaError::InvalidPayload("payload"))?;
+
+            let flags = object
+                .get("local_services")
+                .and_then(Value::as_str)
+                .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+                .ok_or(SchemaError::InvalidPayload("local_services"))?;
+            object.insert("local_services".to_owned(), Value::from(flags));
+
+            // Earlier releases always serialized an empty list.
+            let names = model::service_flags_names(ServiceFlags::from(flags));
+            object.insert("local_services_names".to_owned(), Value::from(names));
+
+            let subversion = object
+                .get("subversion")
+                .and_then(Value::as_str)
+                .ok_or(SchemaError::InvalidPayload("subversion"))?;
+            let user_agent = serde_json::to_value(model::UserAgent::parse(subversion))
+                .map_err(SchemaError::Json)?;
+            object.insert("user_agent".to_owned(), user_agent);
+        }
+        if version < 4 {
+            let networks = payload
+                .get_mut("networks")
+                .and_then(Value::as_array_mut)
+                .ok_or(SchemaError::InvalidPayload("networks"))?;
+            for network in networks {
+                let proxy = network.get_mut("proxy").ok_or(SchemaError::InvalidPayload("proxy"))?;
+                // An empty string if there is no proxy.
+                let addr = proxy
+                    .as_str()
+                    .map(model::PeerAddress::parse_optional)
+                    .ok_or(SchemaError::InvalidPayload("proxy"))?;
+                *proxy = serde_json::to_value(addr).map_err(SchemaError::Json)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Migrate for model::GetPeerInfo {
+    fn upgrade_payload(version: u32, payload: &mut Value) -> Result<(), SchemaError> {
+        if version < 4 {
+            let peers = payload.as_array_mut().ok_or(SchemaError::InvalidPayload("payload"))?;
+            for peer in peers {
+                let object = peer.as_object_mut().ok_or(SchemaError::InvalidPayload("payload"))?;
+
+                let addr = object
+                    .get("addr")
+                    .and_then(Value::as_str)
+                    .map(model::PeerAddress::parse)
+                    .ok_or(SchemaError::InvalidPayload("addr"))?;
+                let addr = serde_json::to_value(addr).map_err(SchemaError::Json)?;
+                object.insert("addr".to_owned(), addr);
+
+                // Not recorded by earlier releases, use the values of a freshly connected peer.
+                object.entry("relay_txes").or_insert(Value::from(true));
+                object.entry("bytes_sent").or_insert(Value::from(0));
+                object.entry("bytes_recv").or_insert(Value::from(0));
+                object.entry("starting_height").or_insert(Value::from(-1));
+                object.entry("permissions").or_insert(Value::Array(vec![]));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Migrate for model::GetWalletInfo {
+    // The balances were not recorded by earlier schemas.
+    const MIN_SCHEMA_VERSION: u32 = 5;
+}
+
+/// Implements [`Migrate`] for types whose serialized form has not changed since schema 1.
+macro_rules! impl_migrate_unchanged {
+    ($($ty:ident),* $(,)?) => {
+        $(impl Migrate for model::$ty {})*
+    };
+}
+impl_migrate_unchanged!(
+    AddMultisigAddress,
+    AddressInformation,
+    AddressTypeDefaults,
+    BumpFee,
+    CombinePsbt,
+    CreateRawTransaction,
+    CreateWallet,
+    DecodePsbt,
+    DeriveAddresses,
+    EstimateSmartFee,
+    FinalizePsbt,
+    FundRawTransaction,
+    GenerateBlock,
+    GenerateToAddress,
+    GenerateToDescriptor,
+    GetAddressInfo,
+    GetAddressesByLabel,
+    GetBalance,
+    GetBalances,
+    GetBestBlockHash,
+    GetBlockHash,
+    GetBlockHeader,
+    GetBlockHeaderVerbose,
+    GetBlockTemplate,
+    GetBlockVerbosityOne,
+    GetBlockVerbosityTwo,
+    GetBlockVerbosityZero,
+    GetChainTips,
+    GetDescriptorInfo,
+    GetMiningInfo,
+    GetNewAddress,
+    GetRawChangeAddress,
+    GetRawTransaction,
+    GetNodeAddresses,
+    GetReceivedByAddress,
+    GetReceivedByLabel,
+    GetTransaction,
+    GetIndexInfo,
+    GetMemoryInfoMallocInfo,
+    GetMemoryInfoStats,
+    GetMempoolEntry,
+    GetMempoolInfo,
+    GetRawMempool,
+    GetRawMempoolVerbose,
+    GetTxOut,
+    GetTxOutProof,
+    GetTxOutSetInfo,
+    IbdState,
+    ScanBlocksStart,
+    DumpTxOutSet,
+    LoadTxOutSet,
+    GetChainStates,
+    ChainState,
+    ScanTxOutSetStart,
+    ScanTxOutSetUnspent,
+    ImportDescriptors,
+    ImportMulti,
+    LabelAddressBalance,
+    ListDescriptors,
+    ListDescriptorsPrivate,
+    ListLockUnspent,
+    ListReceivedByAddress,
+    ListSinceBlock,
+    ListTransactions,
+    ListUnspent,
+    ListWalletDir,
+    ListWallets,
+    LoadWallet,
+    Logging,
+    PsbtBumpFee,
+    RescanBlockchain,
+    SendRawTransaction,
+    Send,
+    SendToAddress,
+    SignMessage,
+    SignRawTransaction,
+    TestMempoolAccept,
+    UnloadWallet,
+    VerifyTxOutProof,
+    WalletCreateFundedPsbt,
+    WalletInventory,
+    WalletProcessPsbt,
+);
+
+/// Error when reading a [`Versioned`] model value.
+#[derive(Debug)]
+pub enum SchemaError {
+    /// The schema version is newer than this crate or older than the type supports.
+    UnsupportedVersion(u32),
+    /// A field of an older schema payload did not have the expected form.
+    InvalidPayload(&'static str),
+    /// Serialization or deserialization failed.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SchemaError::*;
+
+        match *self {
+            UnsupportedVersion(v) => write!(
+                f,
+                "unsupported model schema version {} (current version {})",
+                v, MODEL_SCHEMA_VERSION
+            ),
+            InvalidPayload(field) => write!(f, "invalid `{}` field in versioned payload", field),
+            Json(ref e) => write_err!(f, "versioned payload serde failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SchemaError::*;
+
+        match *self {
+            UnsupportedVersion(_) | InvalidPayload(_) => None,
+            Json(ref e) => Some(e),
+        }
+    }
+}
@@ -4,3 +4,37 @@
 //!
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
+
+use bitcoin::address::{Address, NetworkUnchecked};
+use bitcoin::FeeRate;
+use serde::{Deserialize, Serialize};
+
+/// Models the result of JSON-RPC method `deriveaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeriveAddresses(pub Vec<Address<NetworkUnchecked>>);
+
+/// Models the result of JSON-RPC method `estimatesmartfee`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EstimateSmartFee {
+    /// The estimated fee rate, `None` if no estimate could be made.
+    pub fee_rate: Option<FeeRate>,
+    /// Errors encountered during processing (e.g. "Insufficient data or no feerate found").
+    pub errors: Vec<String>,
+    /// Block number where estimate was found.
+    pub blocks: u32,
+}
+
+/// Models the result of JSON-RPC method `getdescriptorinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetDescriptorInfo {
+    /// The descriptor in canonical form, without private keys.
+    pub descriptor: String,
+    /// The checksum for the input descriptor.
+    pub checksum: String,
+    /// Whether the descriptor is ranged.
+    pub is_range: bool,
+    /// Whether the descriptor is solvable.
+    pub is_solvable: bool,
+    /// Whether the input descriptor contained at least one private key.
+    pub has_private_keys: bool,
+}
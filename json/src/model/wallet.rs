@@ -5,10 +5,29 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
 use bitcoin::address::{Address, NetworkUnchecked};
-use bitcoin::{Amount, SignedAmount, Transaction, Txid};
+use bitcoin::hashes::hash160;
+use bitcoin::sign_message::MessageSignature;
+use bitcoin::{
+    psbt, Amount, BlockHash, FeeRate, OutPoint, Psbt, ScriptBuf, SignedAmount, Transaction, TxOut,
+    Txid,
+};
 use serde::{Deserialize, Serialize};
 
+/// Models the result of JSON-RPC method `addmultisigaddress`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddMultisigAddress {
+    /// The new multisig address.
+    pub address: Address<NetworkUnchecked>,
+    /// The redemption script.
+    pub redeem_script: ScriptBuf,
+    /// The descriptor for this multisig, v0.20 and later only.
+    pub descriptor: Option<String>,
+}
+
 /// Models the result of JSON-RPC method  `createwallet`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct CreateWallet {
@@ -45,7 +64,11 @@ pub struct GetBalance(pub Amount);
 pub struct GetBalances {
     /// Balances from outputs that the wallet can sign.
     pub mine: GetBalancesMine,
+    /// Balances from watch-only addresses, only present for legacy wallets with watch-only
+    /// addresses.
     pub watch_only: Option<GetBalancesWatchOnly>,
+    /// The block the balances were calculated at (v26 onwards).
+    pub last_processed_block: Option<LastProcessedBlock>,
 }
 
 /// Balances from outputs that the wallet can sign.
@@ -63,7 +86,7 @@ pub struct GetBalancesMine {
     pub used: Option<Amount>,
 }
 
-/// Hash and height of the block this information was generated on.
+/// Balances from watch-only addresses.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBalancesWatchOnly {
     /// Trusted balance (outputs created by the wallet or confirmed outputs).
@@ -74,10 +97,326 @@ pub struct GetBalancesWatchOnly {
     pub immature: Amount,
 }
 
+/// Hash and height of the block a wallet result was generated on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LastProcessedBlock {
+    /// The hash of the block.
+    pub hash: BlockHash,
+    /// The height of the block.
+    pub height: u64,
+}
+
+impl GetBalances {
+    /// Returns the balance available to spend from outputs the wallet can sign.
+    ///
+    /// Returns `None` on overflow.
+    pub fn spendable(&self, policy: SpendablePolicy) -> Option<Amount> {
+        self.mine.spendable(policy)
+    }
+
+    /// Returns the sum of all balances, including watch-only balances if present.
+    ///
+    /// Returns `None` on overflow.
+    pub fn total(&self) -> Option<Amount> {
+        let watch_only = match self.watch_only {
+            Some(ref watch_only) => watch_only.total()?,
+            None => Amount::ZERO,
+        };
+        self.mine.total()?.checked_add(watch_only)
+    }
+}
+
+impl GetBalancesMine {
+    /// Returns the balance available to spend, as determined by `policy`.
+    ///
+    /// Returns `None` on overflow.
+    pub fn spendable(&self, policy: SpendablePolicy) -> Option<Amount> {
+        match policy {
+            SpendablePolicy::TrustedOnly => Some(self.trusted),
+            SpendablePolicy::IncludeUntrustedPending =>
+                self.trusted.checked_add(self.untrusted_pending),
+        }
+    }
+
+    /// Returns the sum of the trusted, untrusted pending, immature and used balances.
+    ///
+    /// Returns `None` on overflow.
+    pub fn total(&self) -> Option<Amount> {
+        self.trusted
+            .checked_add(self.untrusted_pending)?
+            .checked_add(self.immature)?
+            .checked_add(self.used.unwrap_or(Amount::ZERO))
+    }
+}
+
+impl GetBalancesWatchOnly {
+    /// Returns the sum of the trusted, untrusted pending and immature balances.
+    ///
+    /// Returns `None` on overflow.
+    pub fn total(&self) -> Option<Amount> {
+        self.trusted.checked_add(self.untrusted_pending)?.checked_add(self.immature)
+    }
+}
+
+/// Which balances to consider spendable.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SpendablePolicy {
+    /// Only trusted outputs (outputs created by the wallet or confirmed outputs).
+    TrustedOnly,
+    /// Trusted outputs as well as untrusted outputs in the mempool (created by others).
+    IncludeUntrustedPending,
+}
+
+/// Models the result of JSON-RPC method `bumpfee`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BumpFee {
+    /// The id of the new transaction.
+    pub txid: Txid,
+    /// Fee of the replaced transaction.
+    pub original_fee: Amount,
+    /// Fee of the new transaction.
+    pub fee: Amount,
+    /// Errors encountered during processing (may be empty).
+    pub errors: Vec<String>,
+}
+
+/// Models the result of JSON-RPC method `psbtbumpfee`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PsbtBumpFee {
+    /// The unsigned PSBT of the new transaction.
+    pub psbt: Psbt,
+    /// Fee of the replaced transaction.
+    pub original_fee: Amount,
+    /// Fee of the new transaction.
+    pub fee: Amount,
+    /// Errors encountered during processing (may be empty).
+    pub errors: Vec<String>,
+}
+
+/// Models the result of JSON-RPC method `getaddressesbylabel`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetAddressesByLabel(pub BTreeMap<Address<NetworkUnchecked>, AddressInformation>);
+
+/// Information about an address, part of the result of `getaddressesbylabel`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddressInformation {
+    /// Purpose of address.
+    pub purpose: AddressPurpose,
+}
+
+/// The purpose of an address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AddressPurpose {
+    /// A send-to address.
+    Send,
+    /// A receiving address.
+    Receive,
+    /// A purpose not known to this version of the library.
+    Unknown,
+}
+
+/// Models the result of JSON-RPC method `getreceivedbyaddress`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetReceivedByAddress(pub Amount);
+
+/// Models the result of JSON-RPC method `getreceivedbylabel`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetReceivedByLabel(pub Amount);
+
+/// Summary of a single address assigned a label, combining the results of several RPC methods.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LabelAddressBalance {
+    /// The address.
+    pub address: Address<NetworkUnchecked>,
+    /// Purpose of address.
+    pub purpose: AddressPurpose,
+    /// Total amount received by the address in confirmed transactions (`getreceivedbyaddress`).
+    pub received: Amount,
+    /// Total value of the wallet's unspent outputs paying to the address (`listunspent`).
+    pub unspent: Amount,
+    /// The number of unspent outputs paying to the address.
+    pub unspent_count: usize,
+}
+
 /// Models the result of JSON-RPC method `getnewaddress`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetNewAddress(pub Address<NetworkUnchecked>);
 
+/// Models the result of JSON-RPC method `getrawchangeaddress`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawChangeAddress(pub Address<NetworkUnchecked>);
+
+/// Models the result of JSON-RPC method `getaddressinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressInfo {
+    /// The address validated.
+    pub address: Address<NetworkUnchecked>,
+    /// The scriptPubKey generated by the address.
+    pub script_pubkey: ScriptBuf,
+    /// If the address is yours or not.
+    pub is_mine: bool,
+    /// If the address is watch-only.
+    pub is_watch_only: bool,
+    /// If the key is a script.
+    pub is_script: bool,
+    /// If the address is a witness address.
+    pub is_witness: bool,
+    /// The version number of the witness program.
+    pub witness_version: Option<u8>,
+    /// The witness program.
+    pub witness_program: Option<Vec<u8>>,
+    /// The output script type of the redeem script, if the address is a script and it is known.
+    pub script: Option<String>,
+    /// Information about the address embedded in P2SH or P2WSH, if relevant and known.
+    pub embedded: Option<Box<GetAddressInfo>>,
+    /// If the address was used for change output.
+    pub is_change: Option<bool>,
+    /// The creation time of the key, if available, in seconds since epoch.
+    pub timestamp: Option<u32>,
+    /// The HD keypath, if the key is HD and available.
+    pub hd_key_path: Option<String>,
+}
+
+impl GetAddressInfo {
+    /// Returns the type of the address, as used by Core's `-addresstype` option.
+    ///
+    /// Returns `None` if the address is not of a type the wallet generates e.g., a P2SH multisig.
+    pub fn address_type(&self) -> Option<AddressType> {
+        let spk = &self.script_pubkey;
+        if spk.is_p2pkh() {
+            Some(AddressType::Legacy)
+        } else if spk.is_p2wpkh() {
+            Some(AddressType::Bech32)
+        } else if spk.is_p2tr() {
+            Some(AddressType::Bech32m)
+        } else if spk.is_p2sh() && self.wraps_p2wpkh() {
+            Some(AddressType::P2shSegwit)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if this is a P2SH address known to wrap a P2WPKH script.
+    fn wraps_p2wpkh(&self) -> bool {
+        match self.embedded {
+            Some(ref embedded) => embedded.script_pubkey.is_p2wpkh(),
+            None => self.script.as_deref() == Some("witness_v0_keyhash"),
+        }
+    }
+}
+
+/// Models the result of JSON-RPC method `getwalletinfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    pub wallet_name: String,
+    /// The wallet version.
+    pub wallet_version: u32,
+    /// The total confirmed balance of the wallet.
+    pub balance: Amount,
+    /// The total unconfirmed balance of the wallet.
+    pub unconfirmed_balance: Amount,
+    /// The total immature balance of the wallet.
+    pub immature_balance: Amount,
+    /// The total number of transactions in the wallet
+    pub tx_count: u32,
+    /// The timestamp (seconds since Unix epoch) of the oldest pre-generated key in the key pool.
+    pub keypool_oldest: Option<u32>,
+    /// How many new keys are pre-generated (only counts external keys).
+    pub keypool_size: u32,
+    /// How many new keys are pre-generated for internal use, if the wallet is using this feature.
+    pub keypool_size_hd_internal: Option<u32>,
+    /// The timestamp (seconds since Unix epoch) that the wallet is unlocked until, or 0 if the
+    /// wallet is locked. `None` if the wallet is not encrypted.
+    pub unlocked_until: Option<u32>,
+    /// The transaction fee configuration.
+    pub pay_tx_fee: FeeRate,
+    /// The Hash160 of the HD seed, only present when HD is enabled.
+    pub hd_seed_id: Option<hash160::Hash>,
+    /// False if private keys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet uses descriptors for scriptPubKey management.
+    pub descriptors: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse (v19 onwards).
+    pub avoid_reuse: Option<bool>,
+    /// Whether a rescan is in progress (v19 onwards).
+    ///
+    /// The scan duration and progress are only available from the version specific type.
+    pub scanning: Option<bool>,
+    /// Whether this wallet is configured to use an external signer e.g., a hardware wallet (v23
+    /// onwards).
+    pub external_signer: Option<bool>,
+}
+
+impl GetWalletInfo {
+    /// Returns the sum of the confirmed, unconfirmed and immature balances.
+    ///
+    /// Returns `None` on overflow.
+    pub fn total(&self) -> Option<Amount> {
+        self.balance.checked_add(self.unconfirmed_balance)?.checked_add(self.immature_balance)
+    }
+}
+
+/// An address type, as used by Core's `-addresstype` and `-changetype` options.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AddressType {
+    /// P2PKH.
+    Legacy,
+    /// P2WPKH nested in P2SH.
+    P2shSegwit,
+    /// Native segwit v0 i.e., P2WPKH.
+    Bech32,
+    /// Native segwit v1 i.e., P2TR (Core v23 onwards).
+    Bech32m,
+}
+
+impl AddressType {
+    /// Returns the string Core uses for this address type.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            AddressType::Legacy => "legacy",
+            AddressType::P2shSegwit => "p2sh-segwit",
+            AddressType::Bech32 => "bech32",
+            AddressType::Bech32m => "bech32m",
+        }
+    }
+}
+
+impl fmt::Display for AddressType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.as_str()) }
+}
+
+/// The address types a wallet generates by default, as configured on the node.
+///
+/// Core does not report `-addresstype` and `-changetype` directly, these are found by inspecting
+/// addresses generated by the wallet.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddressTypeDefaults {
+    /// The wallet used.
+    pub wallet_name: String,
+    /// Whether the wallet is a descriptor wallet.
+    pub descriptors: bool,
+    /// The type of receive addresses (`-addresstype`).
+    pub address_type: AddressType,
+    /// The type of change addresses (`-changetype`).
+    pub change_type: AddressType,
+}
+
+/// Models the result of JSON-RPC method `send`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Send {
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The transaction id, only if the transaction is complete and was added to the wallet.
+    pub txid: Option<Txid>,
+    /// The signed transaction, only if it was not added to the wallet.
+    pub tx: Option<Transaction>,
+    /// The (partially) signed transaction, if more signatures are needed or if it was not added to
+    /// the wallet.
+    pub psbt: Option<Psbt>,
+}
+
 /// Models the result of JSON-RPC method `sendtoaddress`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct SendToAddress {
@@ -87,6 +426,26 @@ pub struct SendToAddress {
     pub fee_reason: String,
 }
 
+/// Models the result of JSON-RPC method `signmessage`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignMessage(#[serde(with = "serde_message_signature")] pub MessageSignature);
+
+/// Serializes a message signature as base64, the same encoding Core uses.
+mod serde_message_signature {
+    use bitcoin::sign_message::MessageSignature;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(sig: &MessageSignature, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&sig.to_base64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<MessageSignature, D::Error> {
+        let s = String::deserialize(d)?;
+        MessageSignature::from_base64(&s).map_err(D::Error::custom)
+    }
+}
+
 /// Models the result of JSON-RPC method `gettransaction`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetTransaction {
@@ -98,7 +457,7 @@ pub struct GetTransaction {
     pub txid: Txid,
     pub time: u64,
     pub time_received: u64,
-    pub bip125_replaceable: String,
+    pub bip125_replaceable: Bip125Replaceable,
     pub details: Vec<GetTransactionDetail>,
     pub tx: Transaction,
 }
@@ -125,4 +484,559 @@ pub enum GetTransactionDetailCategory {
     Generate,
     Immature,
     Orphan,
+    /// A category not known to this library.
+    Unknown,
+}
+
+/// Whether a transaction could be replaced due to BIP-125 (replace-by-fee).
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum Bip125Replaceable {
+    /// Transaction is replaceable.
+    Yes,
+    /// Transaction is not replaceable.
+    No,
+    /// Replaceability is unknown, or Core returned a value not known to this library.
+    Unknown,
+}
+
+/// Models the result of JSON-RPC method `listtransactions`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListTransactions(pub Vec<TransactionItem>);
+
+/// Models the result of JSON-RPC method `listsinceblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListSinceBlock {
+    /// All the transactions.
+    pub transactions: Vec<TransactionItem>,
+    /// Transactions removed due to a reorg.
+    pub removed: Vec<TransactionItem>,
+    /// The hash of the block (target_confirmations-1) from the best block on the main chain.
+    pub last_block: BlockHash,
+}
+
+/// A wallet transaction entry, part of `listtransactions` and `listsinceblock`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TransactionItem {
+    /// True if imported addresses were involved in transaction.
+    pub involves_watch_only: bool,
+    /// The bitcoin address of the transaction.
+    pub address: Option<Address<NetworkUnchecked>>,
+    /// The transaction category.
+    pub category: GetTransactionDetailCategory,
+    /// The amount, negative for the 'send' category.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub amount: SignedAmount,
+    /// A comment for the address/transaction, if any.
+    pub label: Option<String>,
+    /// The vout value.
+    pub vout: u32,
+    /// The amount of the fee, negative and only available for the 'send' category.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc::opt")]
+    pub fee: Option<SignedAmount>,
+    /// The number of confirmations for the transaction, negative if conflicted.
+    pub confirmations: i64,
+    /// True if transaction only input is a coinbase one.
+    pub generated: bool,
+    /// Whether we consider the transaction to be trusted and so safe to spend from.
+    pub trusted: Option<bool>,
+    /// The block hash containing the transaction.
+    pub block_hash: Option<BlockHash>,
+    /// The index of the transaction in the block that includes it.
+    pub block_index: Option<u32>,
+    /// The block time in seconds since epoch (1 Jan 1970 GMT).
+    pub block_time: Option<u64>,
+    /// The transaction id.
+    pub txid: Txid,
+    /// Conflicting transaction ids.
+    pub wallet_conflicts: Vec<Txid>,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    pub time: u64,
+    /// The time received in seconds since epoch (Jan 1 1970 GMT).
+    pub time_received: u64,
+    /// If a comment is associated with the transaction.
+    pub comment: Option<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee).
+    pub bip125_replaceable: Bip125Replaceable,
+    /// True if the transaction has been abandoned (inputs are respendable).
+    pub abandoned: Option<bool>,
+}
+
+/// Models the result of JSON-RPC method `importmulti`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportMulti(pub Vec<ImportMultiResult>);
+
+/// The result of a single import request, part of `importmulti`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportMultiResult {
+    /// Whether the import succeeded.
+    pub success: bool,
+    /// Warnings encountered while importing (always empty before v0.18).
+    pub warnings: Vec<String>,
+    /// The error, if the import failed.
+    pub error: Option<JsonRpcError>,
+}
+
+/// Models the result of JSON-RPC method `listreceivedbyaddress`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListReceivedByAddress(pub Vec<ListReceivedByAddressItem>);
+
+/// An address and the payments it received, part of `listreceivedbyaddress`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListReceivedByAddressItem {
+    /// Whether imported (watch-only) addresses were involved in the transactions.
+    pub involves_watch_only: bool,
+    /// The receiving address.
+    pub address: Address<NetworkUnchecked>,
+    /// The total amount received by the address.
+    pub amount: Amount,
+    /// The number of confirmations of the most recent transaction included.
+    pub confirmations: u32,
+    /// The label of the receiving address. The default label is "".
+    pub label: String,
+    /// The ids of transactions received with the address.
+    pub txids: Vec<Txid>,
+}
+
+/// Models the result of JSON-RPC method `listdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptors {
+    /// Name of the wallet this operation was performed on.
+    pub wallet_name: String,
+    /// The descriptors of the wallet.
+    pub descriptors: Vec<ListDescriptorsItem>,
+}
+
+/// A descriptor, part of `listdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptorsItem {
+    /// The descriptor string, including its checksum.
+    pub descriptor: String,
+    /// The creation time of the descriptor in seconds since epoch.
+    pub timestamp: u64,
+    /// Whether this descriptor is currently used to generate new addresses.
+    pub active: bool,
+    /// Whether this is an internal or external descriptor, `None` if the descriptor is not active.
+    pub internal: Option<bool>,
+    /// The start and end indices (inclusive) of a ranged descriptor.
+    pub range: Option<(u64, u64)>,
+    /// The next index to generate addresses from, for ranged descriptors.
+    pub next: Option<u64>,
+}
+
+/// Models the result of JSON-RPC method `listdescriptors` with `private` set to true.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptorsPrivate {
+    /// Name of the wallet this operation was performed on.
+    pub wallet_name: String,
+    /// The descriptors of the wallet, including private keys.
+    pub descriptors: Vec<ListDescriptorsPrivateItem>,
+}
+
+/// A descriptor including private keys, part of `listdescriptors` with `private` set to true.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptorsPrivateItem {
+    /// The descriptor, including private keys and its checksum.
+    pub descriptor: PrivateDescriptor,
+    /// The creation time of the descriptor in seconds since epoch.
+    pub timestamp: u64,
+    /// Whether this descriptor is currently used to generate new addresses.
+    pub active: bool,
+    /// Whether this is an internal or external descriptor, `None` if the descriptor is not active.
+    pub internal: Option<bool>,
+    /// The start and end indices (inclusive) of a ranged descriptor.
+    pub range: Option<(u64, u64)>,
+    /// The next index to generate addresses from, for ranged descriptors.
+    pub next: Option<u64>,
+}
+
+/// A descriptor string that contains private keys.
+///
+/// The `Debug` output is redacted and the string is zeroed when dropped, use
+/// [`PrivateDescriptor::expose_secret`] to access it. Serializing writes the descriptor in the
+/// clear, this is only done when explicitly asked to (e.g., writing a wallet backup).
+///
+/// Zeroing only covers this value, copies made while receiving and parsing the JSON-RPC response
+/// are outside of our control.
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct PrivateDescriptor(String);
+
+impl PrivateDescriptor {
+    /// Wraps a descriptor string containing private keys.
+    pub fn new(descriptor: String) -> Self { PrivateDescriptor(descriptor) }
+
+    /// Returns the descriptor, including private keys.
+    pub fn expose_secret(&self) -> &str { &self.0 }
+}
+
+impl fmt::Debug for PrivateDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str("PrivateDescriptor(..)") }
+}
+
+impl Drop for PrivateDescriptor {
+    fn drop(&mut self) {
+        let mut bytes = core::mem::take(&mut self.0).into_bytes();
+        for byte in bytes.iter_mut() {
+            // SAFETY: `byte` is a valid, aligned, exclusive reference. A volatile write is used so
+            // the compiler does not optimize away writes to memory that is about to be freed.
+            unsafe { core::ptr::write_volatile(byte, 0) };
+        }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// Models the result of JSON-RPC method `listlockunspent`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListLockUnspent(pub Vec<OutPoint>);
+
+/// Models the result of JSON-RPC method `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspent(pub Vec<ListUnspentItem>);
+
+/// An unspent transaction output, part of `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspentItem {
+    /// The transaction id.
+    pub txid: Txid,
+    /// The vout value.
+    pub vout: u32,
+    /// The bitcoin address of the transaction.
+    pub address: Option<Address<NetworkUnchecked>>,
+    /// The associated label, or "" for the default label.
+    pub label: Option<String>,
+    /// The script key.
+    pub script_pubkey: ScriptBuf,
+    /// The transaction output amount.
+    #[serde(default, with = "bitcoin::amount::serde::as_btc")]
+    pub amount: Amount,
+    /// The number of confirmations.
+    pub confirmations: u32,
+    /// The redeem script if `script_pubkey` is P2SH.
+    pub redeem_script: Option<ScriptBuf>,
+    /// The witness script if `script_pubkey` is P2WSH or P2SH-P2WSH.
+    pub witness_script: Option<ScriptBuf>,
+    /// Whether we have the private keys to spend this output.
+    pub spendable: bool,
+    /// Whether we know how to spend this output, ignoring the lack of keys.
+    pub solvable: bool,
+    /// Whether this output is reused/dirty (only present if `avoid_reuse` is set).
+    pub reused: Option<bool>,
+    /// A descriptor for spending this output (only when solvable).
+    pub descriptor: Option<String>,
+    /// The descriptors that this output belongs to (v24 onwards, descriptor wallets only).
+    pub parent_descriptors: Option<Vec<String>>,
+    /// Whether this output is considered safe to spend.
+    pub safe: bool,
+    /// The number of in-mempool ancestor transactions, including this one (unconfirmed only).
+    pub ancestor_count: Option<u32>,
+    /// The virtual transaction size of in-mempool ancestors, including this one (unconfirmed only).
+    pub ancestor_size: Option<u64>,
+    /// The total fees of in-mempool ancestors, including this one (unconfirmed only).
+    #[serde(default, with = "bitcoin::amount::serde::as_sat::opt")]
+    pub ancestor_fees: Option<Amount>,
+}
+
+impl ListUnspentItem {
+    /// Returns the outpoint of this output.
+    pub fn outpoint(&self) -> OutPoint { OutPoint { txid: self.txid, vout: self.vout } }
+
+    /// Returns this output as a `TxOut`.
+    pub fn txout(&self) -> TxOut {
+        TxOut { value: self.amount, script_pubkey: self.script_pubkey.clone() }
+    }
+
+    /// Returns true if this is a segwit output, native or nested in P2SH.
+    ///
+    /// Nested segwit outputs are only recognised if the node returned the redeem script.
+    pub fn is_segwit(&self) -> bool {
+        self.script_pubkey.is_witness_program()
+            || (self.script_pubkey.is_p2sh()
+                && self.redeem_script.as_ref().map_or(false, |s| s.is_witness_program()))
+    }
+
+    /// Returns true if [`ListUnspentItem::to_psbt_input`] requires the previous transaction.
+    pub fn needs_prev_tx(&self) -> bool { !self.is_segwit() }
+
+    /// Converts this output into a PSBT input spending it.
+    ///
+    /// `prev_tx` is the transaction that created this output, it is required for non-segwit
+    /// outputs (see [`ListUnspentItem::needs_prev_tx`]). Segwit outputs get a `witness_utxo` and,
+    /// if `prev_tx` is provided, also a `non_witness_utxo` (some signers require both). The redeem
+    /// and witness scripts are set if the node returned them.
+    pub fn to_psbt_input(
+        &self,
+        prev_tx: Option<Transaction>,
+    ) -> Result<psbt::Input, ListUnspentItemPsbtError> {
+        use ListUnspentItemPsbtError as E;
+
+        if let Some(ref tx) = prev_tx {
+            let txid = tx.compute_txid();
+            if txid != self.txid {
+                return Err(E::PrevTxMismatch { expected: self.txid, got: txid });
+            }
+            match tx.output.get(self.vout as usize) {
+                Some(txout) if *txout == self.txout() => {}
+                _ => return Err(E::PrevTxOutput(self.outpoint())),
+            }
+        } else if self.needs_prev_tx() {
+            return Err(E::MissingPrevTx(self.outpoint()));
+        }
+
+        Ok(psbt::Input {
+            witness_utxo: if self.is_segwit() { Some(self.txout()) } else { None },
+            non_witness_utxo: prev_tx,
+            redeem_script: self.redeem_script.clone(),
+            witness_script: self.witness_script.clone(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Error when converting a [`ListUnspentItem`] into a PSBT input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListUnspentItemPsbtError {
+    /// The previous transaction is required to spend a non-segwit output.
+    MissingPrevTx(OutPoint),
+    /// The previous transaction provided is not the one that created the output.
+    PrevTxMismatch {
+        /// The txid of the output.
+        expected: Txid,
+        /// The txid of the transaction provided.
+        got: Txid,
+    },
+    /// The output of the previous transaction does not match the unspent output.
+    PrevTxOutput(OutPoint),
+}
+
+impl fmt::Display for ListUnspentItemPsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ListUnspentItemPsbtError::*;
+
+        match *self {
+            MissingPrevTx(ref outpoint) =>
+                write!(f, "previous transaction required to spend non-segwit output {}", outpoint),
+            PrevTxMismatch { ref expected, ref got } =>
+                write!(f, "previous transaction mismatch, expected {} got {}", expected, got),
+            PrevTxOutput(ref outpoint) =>
+                write!(f, "previous transaction output does not match unspent output {}", outpoint),
+        }
+    }
+}
+
+impl std::error::Error for ListUnspentItemPsbtError {}
+
+/// Models the result of JSON-RPC method `walletprocesspsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WalletProcessPsbt {
+    /// The partially signed transaction.
+    pub psbt: Psbt,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The finalized transaction, only returned (v26 onwards) if `complete` is true.
+    pub hex: Option<Transaction>,
+}
+
+/// Models the result of JSON-RPC method `walletcreatefundedpsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WalletCreateFundedPsbt {
+    /// The funded, unsigned PSBT.
+    pub psbt: Psbt,
+    /// The fee the resulting transaction pays.
+    pub fee: Amount,
+    /// The position of the added change output, `None` if no change output was added.
+    pub change_position: Option<u32>,
+}
+
+/// Models the result of JSON-RPC method `rescanblockchain`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RescanBlockchain {
+    /// The block height where the rescan started.
+    pub start_height: u32,
+    /// The height of the last rescanned block, `None` if no blocks were scanned.
+    pub stop_height: Option<u32>,
+}
+
+/// Models the result of JSON-RPC method `listwallets`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWallets(pub Vec<String>);
+
+/// Models the result of JSON-RPC method `listwalletdir`, the wallet names.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWalletDir(pub Vec<String>);
+
+/// Loaded wallets (`listwallets`) reconciled with the wallets on disk (`listwalletdir`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WalletInventory {
+    /// Wallets that are loaded and in the wallet directory.
+    pub loaded: BTreeSet<String>,
+    /// Wallets in the wallet directory that are not loaded.
+    pub unloaded: BTreeSet<String>,
+    /// Wallets that are loaded but not in the wallet directory.
+    ///
+    /// These were loaded from an external path or their files were removed while loaded.
+    pub orphaned: BTreeSet<String>,
+    /// Wallets in the wallet directory that failed to load, maps wallet name -> error message.
+    ///
+    /// Only populated if the unloaded wallets were probed, probed wallets that loaded correctly
+    /// stay in `unloaded`.
+    pub unreadable: BTreeMap<String, String>,
+}
+
+impl WalletInventory {
+    /// Reconciles the loaded wallets with the wallets found in the wallet directory.
+    pub fn new(loaded: &ListWallets, on_disk: &ListWalletDir) -> Self {
+        let loaded_set = loaded.0.iter().cloned().collect::<BTreeSet<_>>();
+        let disk_set = on_disk.0.iter().cloned().collect::<BTreeSet<_>>();
+
+        WalletInventory {
+            loaded: loaded_set.intersection(&disk_set).cloned().collect(),
+            unloaded: disk_set.difference(&loaded_set).cloned().collect(),
+            orphaned: loaded_set.difference(&disk_set).cloned().collect(),
+            unreadable: BTreeMap::new(),
+        }
+    }
+
+    /// Moves `name` from `unloaded` to `unreadable`.
+    pub fn mark_unreadable(&mut self, name: &str, error: String) {
+        self.unloaded.remove(name);
+        self.unreadable.insert(name.to_owned(), error);
+    }
+}
+
+/// Models the result of JSON-RPC method `importdescriptors`, one result per request.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptors(pub Vec<ImportDescriptorsResult>);
+
+/// The result of importing a single descriptor.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptorsResult {
+    /// Whether the descriptor was imported.
+    pub success: bool,
+    /// Warnings encountered while importing (may be empty).
+    pub warnings: Vec<String>,
+    /// The error, if the descriptor was not imported.
+    pub error: Option<JsonRpcError>,
+}
+
+/// An error object, as returned by Core for JSON-RPC errors (e.g. per-item errors of batch
+/// methods).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JsonRpcError {
+    /// The error code.
+    pub code: i32,
+    /// The error message.
+    pub message: String,
+}
+
+impl fmt::Display for JsonRpcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (code {})", self.message, self.code)
+    }
+}
+
+impl std::error::Error for JsonRpcError {}
+
+impl fmt::Display for GetBalance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(&self.0, f) }
+}
+
+impl fmt::Display for GetBalances {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "mine: {}", self.mine)?;
+        if let Some(ref watch_only) = self.watch_only {
+            write!(f, "\nwatch-only: {}", watch_only)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GetBalancesMine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "trusted {}, untrusted pending {}, immature {}",
+            self.trusted, self.untrusted_pending, self.immature
+        )?;
+        if let Some(used) = self.used {
+            write!(f, ", used {}", used)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GetBalancesWatchOnly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "trusted {}, untrusted pending {}, immature {}",
+            self.trusted, self.untrusted_pending, self.immature
+        )
+    }
+}
+
+impl fmt::Display for GetTransaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "txid: {}", self.txid)?;
+        write!(f, "amount: {}", self.amount)?;
+        if let Some(fee) = self.fee {
+            write!(f, " (fee {})", fee)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "confirmations: {}", self.confirmations)?;
+        writeln!(f, "time: {} (received {})", self.time, self.time_received)?;
+        write!(f, "replaceable: {}", self.bip125_replaceable)?;
+        for detail in &self.details {
+            write!(f, "\n  {}", detail)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GetTransactionDetail {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} (vout {})",
+            self.category,
+            self.amount,
+            self.address.clone().assume_checked(),
+            self.vout
+        )?;
+        if let Some(ref label) = self.label {
+            if !label.is_empty() {
+                write!(f, " label {:?}", label)?;
+            }
+        }
+        if self.abandoned == Some(true) {
+            f.write_str(" abandoned")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GetTransactionDetailCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetTransactionDetailCategory as C;
+
+        let s = match *self {
+            C::Send => "send",
+            C::Receive => "receive",
+            C::Generate => "generate",
+            C::Immature => "immature",
+            C::Orphan => "orphan",
+            C::Unknown => "unknown",
+        };
+        f.write_str(s)
+    }
+}
+
+impl fmt::Display for Bip125Replaceable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Bip125Replaceable::Yes => "yes",
+            Bip125Replaceable::No => "no",
+            Bip125Replaceable::Unknown => "unknown",
+        };
+        f.write_str(s)
+    }
 }
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! (De)serializes a [`bitcoin::OutPoint`] as the `{ "txid": ..., "vout": ... }` object Core uses.
+//!
+//! `rust-bitcoin` serializes an outpoint as a `txid:vout` string. Use this module with
+//! `#[serde(with = "...")]`, flattened if the object has other fields (e.g. `sequence`), and
+//! [`vec`] for a list of outpoints:
+//!
+//! ```
+//! # use bitcoind_json_rpc_types::bitcoin::{OutPoint, Txid};
+//! # use bitcoind_json_rpc_types::serde_outpoint;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Deserialize, Serialize)]
+//! struct Input {
+//!     #[serde(flatten, with = "serde_outpoint")]
+//!     outpoint: OutPoint,
+//!     sequence: u32,
+//! }
+//!
+//! let txid = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+//! let json = format!(r#"{{"txid":"{}","vout":1,"sequence":0}}"#, txid);
+//! let input: Input = serde_json::from_str(&json).unwrap();
+//! assert_eq!(input.outpoint, OutPoint { txid: txid.parse::<Txid>().unwrap(), vout: 1 });
+//! assert_eq!(serde_json::to_string(&input).unwrap(), json);
+//! ```
+
+use bitcoin::{OutPoint, Txid};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes `outpoint` as a `{ "txid": ..., "vout": ... }` object.
+pub fn serialize<S: Serializer>(outpoint: &OutPoint, s: S) -> Result<S::Ok, S::Error> {
+    Object::from(*outpoint).serialize(s)
+}
+
+/// Deserializes an outpoint from a `{ "txid": ..., "vout": ... }` object.
+pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<OutPoint, D::Error> {
+    Object::deserialize(d).map(OutPoint::from)
+}
+
+/// (De)serializes a list of outpoints as an array of `{ "txid": ..., "vout": ... }` objects.
+pub mod vec {
+    use bitcoin::OutPoint;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Object;
+
+    /// Serializes `outpoints` as an array of `{ "txid": ..., "vout": ... }` objects.
+    pub fn serialize<S: Serializer>(outpoints: &[OutPoint], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(outpoints.iter().map(|outpoint| Object::from(*outpoint)))
+    }
+
+    /// Deserializes a list of outpoints from an array of `{ "txid": ..., "vout": ... }` objects.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<OutPoint>, D::Error> {
+        let objects = Vec::<Object>::deserialize(d)?;
+        Ok(objects.into_iter().map(OutPoint::from).collect())
+    }
+}
+
+/// An outpoint as Core (de)serializes it.
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "OutPoint")]
+struct Object {
+    txid: Txid,
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    vout: u32,
+}
+
+impl From<OutPoint> for Object {
+    fn from(outpoint: OutPoint) -> Self { Object { txid: outpoint.txid, vout: outpoint.vout } }
+}
+
+impl From<Object> for OutPoint {
+    fn from(object: Object) -> Self { OutPoint { txid: object.txid, vout: object.vout } }
+}
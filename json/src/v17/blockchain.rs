@@ -11,13 +11,14 @@ use std::str::FromStr;
 use bitcoin::consensus::encode;
 use bitcoin::error::UnprefixedHexError;
 use bitcoin::{
-    address, amount, block, hex, network, Address, Amount, Block, BlockHash, CompactTarget,
-    Network, ScriptBuf, TxOut, Txid, Weight, Work,
+    address, amount, block, hex, Address, Amount, Block, BlockHash, CompactTarget, MerkleBlock,
+    OutPoint, ScriptBuf, TxOut, Txid, Weight, Work, Wtxid,
 };
 use internals::write_err;
 use serde::{Deserialize, Serialize};
 
-use crate::model;
+use crate::v17::{DecodeRawTransaction, DecodeRawTransactionError};
+use crate::{model, Warnings};
 
 /// Result of JSON-RPC method `getbestblockhash`.
 ///
@@ -91,18 +92,18 @@ pub struct GetBlockchainInfo {
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Softfork {
     /// Name of softfork.
-    id: String,
+    pub id: String,
     /// Block version.
-    version: usize,
+    pub version: usize,
     /// Progress toward rejecting pre-softfork blocks.
-    reject: SoftforkReject,
+    pub reject: SoftforkReject,
 }
 
 /// Progress toward rejecting pre-softfork blocks.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct SoftforkReject {
     /// `true` if threshold reached.
-    status: bool,
+    pub status: bool,
 }
 
 /// Status of BIP-9 softforksin progress.
@@ -135,6 +136,9 @@ pub enum Bip9SoftforkStatus {
     Active,
     /// BIP-9 softfork status "failed".
     Failed,
+    /// A status not known to this library (deserialization catch-all).
+    #[serde(other)]
+    Unknown,
 }
 
 impl GetBlockchainInfo {
@@ -142,13 +146,18 @@ impl GetBlockchainInfo {
     pub fn into_model(self) -> Result<model::GetBlockchainInfo, GetBlockchainInfoError> {
         use GetBlockchainInfoError as E;
 
-        let chain = Network::from_core_arg(&self.chain).map_err(E::Chain)?;
+        let chain = model::Chain::from_core_arg(&self.chain);
         let best_block_hash =
             self.best_block_hash.parse::<BlockHash>().map_err(E::BestBlockHash)?;
         // FIXME: Is unprefixed correct?
         let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
 
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let mut softforks = self
+            .softforks
+            .into_iter()
+            .map(|softfork| (softfork.id, softfork.reject.into_model()))
+            .collect::<BTreeMap<_, _>>();
+        softforks.extend(self.bip9_softforks.into_iter().map(|(name, s)| (name, s.into_model())));
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -156,6 +165,7 @@ impl GetBlockchainInfo {
             headers: self.headers,
             best_block_hash,
             difficulty: self.difficulty,
+            time: None,
             median_time: self.median_time,
             verification_progress: self.verification_progress,
             initial_block_download: self.initial_block_download,
@@ -166,12 +176,44 @@ impl GetBlockchainInfo {
             automatic_pruning: self.automatic_pruning,
             prune_target_size: self.prune_target_size,
             softforks,
-            warnings: self.warnings,
+            // Signet was added in Core v0.21.
+            signet_challenge: None,
+            warnings: Warnings::from(self.warnings).into_model(),
         })
     }
 }
 
-// FIXME: Me mightn't need this.
+impl SoftforkReject {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    ///
+    /// Pre-BIP-9 softforks were activated by block version, they are modelled as buried.
+    pub fn into_model(self) -> model::Softfork {
+        model::Softfork {
+            type_: model::SoftforkType::Buried,
+            bip9: None,
+            height: None,
+            active: self.status,
+        }
+    }
+}
+
+impl Bip9Softfork {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Softfork {
+        let active = self.status == Bip9SoftforkStatus::Active;
+        let bip9 = model::Bip9SoftforkInfo {
+            status: self.status.into_model(),
+            bit: self.bit,
+            start_time: self.start_time,
+            timeout: self.timeout,
+            since: self.since,
+            statistics: None,
+            min_activation_height: None,
+        };
+        model::Softfork { type_: model::SoftforkType::Bip9, bip9: Some(bip9), height: None, active }
+    }
+}
+
 impl Bip9SoftforkStatus {
     /// Converts version specific type to a version in-specific, more strongly typed type.
     pub fn into_model(self) -> model::Bip9SoftforkStatus {
@@ -183,6 +225,7 @@ impl Bip9SoftforkStatus {
             Self::LockedIn => LockedIn,
             Self::Active => Active,
             Self::Failed => Failed,
+            Self::Unknown => Unknown,
         }
     }
 }
@@ -190,7 +233,6 @@ impl Bip9SoftforkStatus {
 /// Error when converting a `GetBlockchainInfo` type into the model type.
 #[derive(Debug)]
 pub enum GetBlockchainInfoError {
-    Chain(network::ParseNetworkError),
     BestBlockHash(hex::HexToArrayError),
     ChainWork(UnprefixedHexError),
 }
@@ -200,7 +242,6 @@ impl fmt::Display for GetBlockchainInfoError {
         use GetBlockchainInfoError::*;
 
         match *self {
-            Chain(ref e) => write_err!(f, "conversion of the `chain` field failed"; e),
             BestBlockHash(ref e) =>
                 write_err!(f, "conversion of the `best_block_hash` field failed"; e),
             ChainWork(ref e) => write_err!(f, "conversion of the `chain_work` field failed"; e),
@@ -213,7 +254,6 @@ impl std::error::Error for GetBlockchainInfoError {
         use GetBlockchainInfoError::*;
 
         match *self {
-            Chain(ref e) => Some(e),
             BestBlockHash(ref e) => Some(e),
             ChainWork(ref e) => Some(e),
         }
@@ -392,111 +432,1189 @@ impl std::error::Error for GetBlockVerbosityOneError {
     }
 }
 
-/// Result of JSON-RPC method `gettxout`.
+/// Result of JSON-RPC method `getblock` with verbosity set to 2.
 ///
-/// > gettxout "txid" n ( include_mempool )
-/// >
-/// > Returns details about an unspent transaction output.
-/// >
-/// > Arguments:
-/// > 1. txid               (string, required) The transaction id
-/// > 2. n                  (numeric, required) vout number
-/// > 3. include_mempool    (boolean, optional, default=true) Whether to include the mempool. Note that an unspent output that is spent in the mempool won't appear.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-pub struct GetTxOut {
-    /// The hash of the block at the tip of the chain.
-    #[serde(rename = "bestblock")]
-    pub best_block: String,
-    /// The number of confirmations.
-    pub confirmations: u32,
-    /// The transaction value in BTC.
-    pub value: f64,
-    /// The script pubkey.
-    #[serde(rename = "scriptPubkey")]
-    pub script_pubkey: ScriptPubkey,
-    /// Coinbase or not.
-    pub coinbase: bool,
-}
-
-/// A script pubkey.
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
-pub struct ScriptPubkey {
-    /// Script assembly.
-    pub asm: String,
-    /// Script hex.
-    pub hex: String,
-    // TODO: Add support for deprecatedrpc=addresses
-    // #[serde(rename = "reqSigs")]
-    // pub req_sigs: u64,
-    /// The type, eg pubkeyhash
-    #[serde(rename = "type")]
-    pub type_: String,
-    /// bitcoin address
-    pub address: String,
-    // TODO: Add support for deprecatedrpc=addresses
-    // pub addressess: Vec<String>,
+/// The same as verbosity 1 except that each transaction is decoded.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockVerbosityTwo {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: String,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i32,
+    /// The block size.
+    pub size: usize,
+    /// The block size excluding witness data.
+    #[serde(rename = "strippedsize")]
+    pub stripped_size: Option<usize>,
+    /// The block weight as defined in BIP-141.
+    pub weight: u64,
+    /// The block height or index.
+    pub height: usize,
+    /// The block version.
+    pub version: i32,
+    /// The block version formatted in hexadecimal.
+    #[serde(rename = "versionHex")]
+    pub version_hex: String,
+    /// The merkle root
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// The decoded transactions.
+    pub tx: Vec<DecodeRawTransaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: usize,
+    /// The median block time expressed in UNIX epoch time.
+    #[serde(rename = "mediantime")]
+    pub median_time: Option<usize>,
+    /// The nonce
+    pub nonce: u32,
+    /// The bits.
+    pub bits: String,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// The number of transactions in the block.
+    #[serde(rename = "nTx")]
+    pub n_tx: u32,
+    /// The hash of the previous block (if available).
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: Option<String>,
+    /// The hash of the next block (if available).
+    #[serde(rename = "nextblockhash")]
+    pub next_block_hash: Option<String>,
 }
 
-impl GetTxOut {
+impl GetBlockVerbosityTwo {
     /// Converts version specific type to a version in-specific, more strongly typed type.
-    pub fn into_model(self) -> Result<model::GetTxOut, GetTxOutError> {
-        use GetTxOutError as E;
-
-        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
-
-        let tx_out = TxOut {
-            value: Amount::from_btc(self.value).map_err(E::Value)?,
-            script_pubkey: ScriptBuf::from_hex(&self.script_pubkey.hex).map_err(E::ScriptPubkey)?,
-        };
+    pub fn into_model(self) -> Result<model::GetBlockVerbosityTwo, GetBlockVerbosityTwoError> {
+        use GetBlockVerbosityTwoError as E;
 
-        let address = Address::from_str(&self.script_pubkey.address).map_err(E::Address)?;
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let weight = Weight::from_wu(self.weight);
+        let version = block::Version::from_consensus(self.version);
+        let tx = self
+            .tx
+            .into_iter()
+            .map(|tx| tx.transaction())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Tx)?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let previous_block_hash = self
+            .previous_block_hash
+            .map(|hash| hash.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::PreviousBlockHash)?;
+        let next_block_hash = self
+            .next_block_hash
+            .map(|hash| hash.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::NextBlockHash)?;
 
-        Ok(model::GetTxOut {
-            best_block,
+        Ok(model::GetBlockVerbosityTwo {
+            hash,
             confirmations: self.confirmations,
-            tx_out,
-            address,
-            coinbase: self.coinbase,
+            size: self.size,
+            stripped_size: self.stripped_size,
+            weight,
+            height: self.height,
+            version,
+            version_hex: self.version_hex,
+            merkle_root: self.merkle_root,
+            tx,
+            time: self.time,
+            median_time: self.median_time,
+            nonce: self.nonce,
+            bits,
+            difficulty: self.difficulty,
+            chain_work,
+            n_tx: self.n_tx,
+            previous_block_hash,
+            next_block_hash,
         })
     }
 }
 
-/// Error when converting a `GetTxOut` type into the model type.
+/// Error when converting a `GetBlockVerbosityTwo` type into the model type.
 #[derive(Debug)]
-pub enum GetTxOutError {
-    /// Conversion of the transaction `best_block` field failed.
-    BestBlock(hex::HexToArrayError),
-    /// Conversion of the transaction `value` field failed.
-    Value(amount::ParseAmountError),
-    /// Conversion of the transaction `script_pubkey` field failed.
-    ScriptPubkey(hex::HexToBytesError),
-    /// Conversion of the transaction `address` field failed.
-    Address(address::ParseError),
+pub enum GetBlockVerbosityTwoError {
+    /// Conversion of the `hash` field failed.
+    Hash(hex::HexToArrayError),
+    /// Conversion of one of the transactions in the `tx` field failed.
+    Tx(DecodeRawTransactionError),
+    /// Conversion of the `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the `chain_work` field failed.
+    ChainWork(UnprefixedHexError),
+    /// Conversion of the `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the `next_block_hash` field failed.
+    NextBlockHash(hex::HexToArrayError),
 }
 
-impl fmt::Display for GetTxOutError {
+impl fmt::Display for GetBlockVerbosityTwoError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use GetTxOutError::*;
+        use GetBlockVerbosityTwoError::*;
 
         match *self {
-            BestBlock(ref e) => write_err!(f, "conversion of the `best_block` field failed"; e),
-            Value(ref e) => write_err!(f, "conversion of the `value` field failed"; e),
-            ScriptPubkey(ref e) =>
-                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
-            Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+            Tx(ref e) => write_err!(f, "conversion of the `tx` field failed"; e),
+            Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            ChainWork(ref e) => write_err!(f, "conversion of the `chain_work` field failed"; e),
+            PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            NextBlockHash(ref e) =>
+                write_err!(f, "conversion of the `next_block_hash` field failed"; e),
         }
     }
 }
 
-impl std::error::Error for GetTxOutError {
+impl std::error::Error for GetBlockVerbosityTwoError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        use GetTxOutError::*;
+        use GetBlockVerbosityTwoError::*;
 
         match *self {
-            BestBlock(ref e) => Some(e),
-            Value(ref e) => Some(e),
-            ScriptPubkey(ref e) => Some(e),
-            Address(ref e) => Some(e),
+            Hash(ref e) => Some(e),
+            Tx(ref e) => Some(e),
+            Bits(ref e) => Some(e),
+            ChainWork(ref e) => Some(e),
+            PreviousBlockHash(ref e) => Some(e),
+            NextBlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `getblockheader` with verbose set to `false`.
+///
+/// A string that is serialized, hex-encoded data for block 'hash'.
+///
+/// Method call: `getblockheader "hash" ( verbose )`
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockHeader(pub String);
+
+impl GetBlockHeader {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockHeader, encode::FromHexError> {
+        let header = encode::deserialize_hex(&self.0)?;
+        Ok(model::GetBlockHeader(header))
+    }
+
+    /// Converts json straight to a `bitcoin::block::Header`.
+    pub fn block_header(self) -> Result<block::Header, encode::FromHexError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// Result of JSON-RPC method `getblockheader` with verbose set to `true`.
+///
+/// Method call: `getblockheader "hash" ( verbose )`
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetBlockHeaderVerbose {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: String,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i32,
+    /// The block height or index.
+    pub height: usize,
+    /// The block version.
+    pub version: i32,
+    /// The block version formatted in hexadecimal.
+    #[serde(rename = "versionHex")]
+    pub version_hex: String,
+    /// The merkle root
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// The block time expressed in UNIX epoch time.
+    pub time: usize,
+    /// The median block time expressed in UNIX epoch time.
+    #[serde(rename = "mediantime")]
+    pub median_time: Option<usize>,
+    /// The nonce
+    pub nonce: u32,
+    /// The bits.
+    pub bits: String,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// The number of transactions in the block.
+    #[serde(rename = "nTx")]
+    pub n_tx: u32,
+    /// The hash of the previous block (if available).
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: Option<String>,
+    /// The hash of the next block (if available).
+    #[serde(rename = "nextblockhash")]
+    pub next_block_hash: Option<String>,
+}
+
+impl GetBlockHeaderVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockHeaderVerbose, GetBlockHeaderVerboseError> {
+        use GetBlockHeaderVerboseError as E;
+
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let version = block::Version::from_consensus(self.version);
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let previous_block_hash = self
+            .previous_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::PreviousBlockHash)?;
+        let next_block_hash = self
+            .next_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::NextBlockHash)?;
+
+        Ok(model::GetBlockHeaderVerbose {
+            hash,
+            confirmations: self.confirmations,
+            height: self.height,
+            version,
+            version_hex: self.version_hex,
+            merkle_root: self.merkle_root,
+            time: self.time,
+            median_time: self.median_time,
+            nonce: self.nonce,
+            bits,
+            difficulty: self.difficulty,
+            chain_work,
+            n_tx: self.n_tx,
+            previous_block_hash,
+            next_block_hash,
+        })
+    }
+
+    /// Converts json straight to a `bitcoin::block::Header`.
+    pub fn block_header(self) -> Result<block::Header, GetBlockHeaderVerboseError> {
+        let model = self.into_model()?;
+        block::Header::try_from(&model).map_err(GetBlockHeaderVerboseError::Header)
+    }
+}
+
+/// Error when converting a `GetBlockHeaderVerbose` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockHeaderVerboseError {
+    /// Conversion of the `hash` field failed.
+    Hash(hex::HexToArrayError),
+    /// Conversion of the `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the `chain_work` field failed.
+    ChainWork(UnprefixedHexError),
+    /// Conversion of the `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the `next_block_hash` field failed.
+    NextBlockHash(hex::HexToArrayError),
+    /// Conversion of the model into a `block::Header` failed.
+    Header(model::BlockHeaderError),
+}
+
+impl fmt::Display for GetBlockHeaderVerboseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetBlockHeaderVerboseError::*;
+
+        match *self {
+            Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+            Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            ChainWork(ref e) => write_err!(f, "conversion of the `chain_work` field failed"; e),
+            PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            NextBlockHash(ref e) =>
+                write_err!(f, "conversion of the `next_block_hash` field failed"; e),
+            Header(ref e) => write_err!(f, "conversion into a block header failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetBlockHeaderVerboseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetBlockHeaderVerboseError::*;
+
+        match *self {
+            Hash(ref e) => Some(e),
+            Bits(ref e) => Some(e),
+            ChainWork(ref e) => Some(e),
+            PreviousBlockHash(ref e) => Some(e),
+            NextBlockHash(ref e) => Some(e),
+            Header(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `getblockhash`.
+///
+/// > getblockhash height
+/// >
+/// > Returns hash of block in best-block-chain at height provided.
+/// >
+/// > Arguments:
+/// > 1. height         (numeric, required) The height index
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockHash(pub String);
+
+impl GetBlockHash {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockHash, hex::HexToArrayError> {
+        let hash = self.0.parse::<BlockHash>()?;
+        Ok(model::GetBlockHash(hash))
+    }
+
+    /// Converts json straight to a `bitcoin::BlockHash`.
+    pub fn block_hash(self) -> Result<BlockHash, hex::HexToArrayError> { Ok(self.into_model()?.0) }
+}
+
+/// Result of JSON-RPC method `getchaintips`.
+///
+/// > getchaintips
+/// >
+/// > Return information about all known tips in the block tree, including the main chain as well
+/// > as orphaned branches.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetChainTips(pub Vec<ChainTips>);
+
+/// A chain tip, part of `getchaintips`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ChainTips {
+    /// Height of the chain tip.
+    pub height: u64,
+    /// Block hash of the tip.
+    pub hash: String,
+    /// Zero for main chain, otherwise length of branch connecting the tip to the main chain.
+    #[serde(rename = "branchlen")]
+    pub branch_length: u64,
+    /// Status of the chain.
+    pub status: ChainTipsStatus,
+}
+
+/// The status of a chain tip, part of `getchaintips`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChainTipsStatus {
+    /// This branch contains at least one invalid block.
+    Invalid,
+    /// Not all blocks for this branch are available, but the headers are valid.
+    HeadersOnly,
+    /// All blocks are available for this branch, but they were never fully validated.
+    ValidHeaders,
+    /// This branch is not part of the active chain, but is fully validated.
+    ValidFork,
+    /// This is the tip of the active main chain, which is certainly valid.
+    Active,
+}
+
+impl GetChainTips {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetChainTips, hex::HexToArrayError> {
+        let tips = self.0.into_iter().map(|tip| tip.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::GetChainTips(tips))
+    }
+}
+
+impl ChainTips {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ChainTips, hex::HexToArrayError> {
+        Ok(model::ChainTips {
+            height: self.height,
+            hash: self.hash.parse::<BlockHash>()?,
+            branch_length: self.branch_length,
+            status: self.status.into_model(),
+        })
+    }
+}
+
+impl ChainTipsStatus {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ChainTipsStatus {
+        use model::ChainTipsStatus::*;
+
+        match self {
+            Self::Invalid => Invalid,
+            Self::HeadersOnly => HeadersOnly,
+            Self::ValidHeaders => ValidHeaders,
+            Self::ValidFork => ValidFork,
+            Self::Active => Active,
+        }
+    }
+}
+
+/// Result of JSON-RPC method `gettxout`.
+///
+/// > gettxout "txid" n ( include_mempool )
+/// >
+/// > Returns details about an unspent transaction output.
+/// >
+/// > Arguments:
+/// > 1. txid               (string, required) The transaction id
+/// > 2. n                  (numeric, required) vout number
+/// > 3. include_mempool    (boolean, optional, default=true) Whether to include the mempool. Note that an unspent output that is spent in the mempool won't appear.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOut {
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of confirmations.
+    pub confirmations: u32,
+    /// The transaction value in BTC.
+    pub value: f64,
+    /// The script pubkey.
+    #[serde(rename = "scriptPubkey")]
+    pub script_pubkey: ScriptPubkey,
+    /// Coinbase or not.
+    pub coinbase: bool,
+}
+
+/// A script pubkey.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScriptPubkey {
+    /// Script assembly.
+    pub asm: String,
+    /// Script hex.
+    pub hex: String,
+    /// Number of required signatures (removed in v22 unless `-deprecatedrpc=addresses`).
+    #[serde(rename = "reqSigs")]
+    pub req_sigs: Option<u64>,
+    /// The type, eg pubkeyhash
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Bitcoin address (v22 onwards, not present if the script has no address).
+    pub address: Option<String>,
+    /// Array of bitcoin addresses (removed in v22 unless `-deprecatedrpc=addresses`).
+    pub addresses: Option<Vec<String>>,
+}
+
+impl GetTxOut {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOut, GetTxOutError> {
+        use GetTxOutError as E;
+
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+
+        let tx_out = TxOut {
+            value: Amount::from_btc(self.value).map_err(E::Value)?,
+            script_pubkey: ScriptBuf::from_hex(&self.script_pubkey.hex).map_err(E::ScriptPubkey)?,
+        };
+
+        // Core before v22 only returns the `addresses` list.
+        let address = match (&self.script_pubkey.address, self.script_pubkey.addresses.as_deref()) {
+            (Some(address), _) | (None, Some([address])) =>
+                Address::from_str(address).map_err(E::Address)?,
+            _ => return Err(E::MissingAddress),
+        };
+
+        Ok(model::GetTxOut {
+            best_block,
+            confirmations: self.confirmations,
+            tx_out,
+            address,
+            coinbase: self.coinbase,
+        })
+    }
+}
+
+/// Error when converting a `GetTxOut` type into the model type.
+#[derive(Debug)]
+pub enum GetTxOutError {
+    /// Conversion of the transaction `best_block` field failed.
+    BestBlock(hex::HexToArrayError),
+    /// Conversion of the transaction `value` field failed.
+    Value(amount::ParseAmountError),
+    /// Conversion of the transaction `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the transaction `address` field failed.
+    Address(address::ParseError),
+    /// The script has no address (or more than one).
+    MissingAddress,
+}
+
+impl fmt::Display for GetTxOutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetTxOutError::*;
+
+        match *self {
+            BestBlock(ref e) => write_err!(f, "conversion of the `best_block` field failed"; e),
+            Value(ref e) => write_err!(f, "conversion of the `value` field failed"; e),
+            ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+            Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            MissingAddress => write!(f, "the script has no address"),
+        }
+    }
+}
+
+impl std::error::Error for GetTxOutError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetTxOutError::*;
+
+        match *self {
+            BestBlock(ref e) => Some(e),
+            Value(ref e) => Some(e),
+            ScriptPubkey(ref e) => Some(e),
+            Address(ref e) => Some(e),
+            MissingAddress => None,
+        }
+    }
+}
+
+/// Result of JSON-RPC method `gettxoutproof`.
+///
+/// > gettxoutproof ["txid",...] ( blockhash )
+/// >
+/// > Returns a hex-encoded proof that "txid" was included in a block.
+/// >
+/// > NOTE: By default this function only works sometimes. This is when there is an
+/// > unspent output in the utxo for this transaction. To make it always work,
+/// > you need to maintain a transaction index, using the -txindex command line option or
+/// > specify the block in which the transaction is included manually (by blockhash).
+/// >
+/// > Arguments:
+/// > 1. "txids"       (string) A json array of txids to filter
+/// > 2. "blockhash"   (string, optional) If specified, looks for txid in the block with this hash
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetTxOutProof(pub String);
+
+impl GetTxOutProof {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutProof, encode::FromHexError> {
+        let merkle_block = encode::deserialize_hex(&self.0)?;
+        Ok(model::GetTxOutProof(merkle_block))
+    }
+
+    /// Converts json straight to a `bitcoin::MerkleBlock`.
+    pub fn merkle_block(self) -> Result<MerkleBlock, encode::FromHexError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// Result of JSON-RPC method `verifytxoutproof`.
+///
+/// > verifytxoutproof "proof"
+/// >
+/// > Verifies that a proof points to a transaction in a block, returning the transaction it commits to
+/// > and throwing an RPC error if the block is not in our best chain
+/// >
+/// > Arguments:
+/// > 1. "proof"    (string, required) The hex-encoded proof generated by gettxoutproof
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VerifyTxOutProof(pub Vec<String>);
+
+impl VerifyTxOutProof {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::VerifyTxOutProof, hex::HexToArrayError> {
+        let txids = self.0.iter().map(|txid| txid.parse::<Txid>()).collect::<Result<_, _>>()?;
+        Ok(model::VerifyTxOutProof(txids))
+    }
+}
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The current block height (index).
+    pub height: u64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of transactions with unspent outputs.
+    pub transactions: u64,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// A meaningless metric for UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: u64,
+    /// The serialized hash.
+    pub hash_serialized_2: String,
+    /// The estimated size of the chainstate on disk.
+    pub disk_size: u64,
+    /// The total amount.
+    pub total_amount: f64,
+}
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height: self.height,
+            best_block,
+            transactions: Some(self.transactions),
+            tx_outs: self.tx_outs,
+            bogo_size: self.bogo_size,
+            hash_serialized: Some(self.hash_serialized_2),
+            muhash: None,
+            disk_size: Some(self.disk_size),
+            total_amount,
+            total_unspendable_amount: None,
+            block_info: None,
+        })
+    }
+}
+
+/// Error when converting a `GetTxOutSetInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetTxOutSetInfoError {
+    /// Conversion of the `best_block` field failed.
+    BestBlock(hex::HexToArrayError),
+    /// Conversion of the `total_amount` field failed.
+    TotalAmount(amount::ParseAmountError),
+    /// Conversion of the `total_unspendable_amount` field failed.
+    TotalUnspendableAmount(amount::ParseAmountError),
+    /// Conversion of one of the `block_info` amounts failed.
+    BlockInfo(amount::ParseAmountError),
+}
+
+impl fmt::Display for GetTxOutSetInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetTxOutSetInfoError as E;
+
+        match *self {
+            E::BestBlock(ref e) => write_err!(f, "conversion of the `best_block` field failed"; e),
+            E::TotalAmount(ref e) =>
+                write_err!(f, "conversion of the `total_amount` field failed"; e),
+            E::TotalUnspendableAmount(ref e) =>
+                write_err!(f, "conversion of the `total_unspendable_amount` field failed"; e),
+            E::BlockInfo(ref e) => write_err!(f, "conversion of the `block_info` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetTxOutSetInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetTxOutSetInfoError as E;
+
+        match *self {
+            E::BestBlock(ref e) => Some(e),
+            E::TotalAmount(ref e) => Some(e),
+            E::TotalUnspendableAmount(ref e) => Some(e),
+            E::BlockInfo(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `scantxoutset` with action "start".
+///
+/// > scantxoutset <action> ( <scanobjects> )
+/// >
+/// > EXPERIMENTAL warning: this call may be removed or changed in future releases.
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. "action"                       (string, required) The action to execute
+/// >                                       "start" for starting a scan
+/// >                                       "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                       "status" for progress report (in %) of the current scan
+/// > 2. "scanobjects"                  (array, required) Array of scan objects
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStart {
+    /// Whether the scan completed (false if it was aborted).
+    pub success: bool,
+    /// The number of unspent transaction outputs scanned.
+    pub searched_items: u64,
+    /// The unspent transaction outputs that matched a scan object.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs in BTC.
+    pub total_amount: f64,
+}
+
+/// An unspent transaction output, part of `ScanTxOutSetStart`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetUnspent {
+    /// The transaction id.
+    pub txid: String,
+    /// The output index.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub vout: u32,
+    /// The script pubkey (hex).
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// A specialized descriptor for the matched script pubkey (v18 onwards).
+    pub desc: Option<String>,
+    /// The amount in BTC.
+    pub amount: f64,
+    /// Whether this is a coinbase output (v25 onwards).
+    pub coinbase: Option<bool>,
+    /// Height of the unspent transaction output.
+    pub height: u64,
+}
+
+impl ScanTxOutSetStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetStart, ScanTxOutSetStartError> {
+        use ScanTxOutSetStartError as E;
+
+        let unspents =
+            self.unspents.into_iter().map(|u| u.into_model()).collect::<Result<Vec<_>, _>>()?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::ScanTxOutSetStart {
+            success: self.success,
+            txouts: self.searched_items,
+            height: None,
+            best_block: None,
+            unspents,
+            total_amount,
+        })
+    }
+}
+
+impl ScanTxOutSetUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetUnspent, ScanTxOutSetStartError> {
+        use ScanTxOutSetStartError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+
+        Ok(model::ScanTxOutSetUnspent {
+            outpoint: OutPoint { txid, vout: self.vout },
+            script_pubkey,
+            descriptor: self.desc,
+            amount,
+            coinbase: self.coinbase,
+            height: self.height,
+        })
+    }
+}
+
+/// Error when converting a `ScanTxOutSetStart` type into the model type.
+#[derive(Debug)]
+pub enum ScanTxOutSetStartError {
+    /// Conversion of the `best_block` field failed.
+    BestBlock(hex::HexToArrayError),
+    /// Conversion of the `total_amount` field failed.
+    TotalAmount(amount::ParseAmountError),
+    /// Conversion of an unspent's `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of an unspent's `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of an unspent's `amount` field failed.
+    Amount(amount::ParseAmountError),
+}
+
+impl fmt::Display for ScanTxOutSetStartError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ScanTxOutSetStartError as E;
+
+        match *self {
+            E::BestBlock(ref e) => write_err!(f, "conversion of the `best_block` field failed"; e),
+            E::TotalAmount(ref e) =>
+                write_err!(f, "conversion of the `total_amount` field failed"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of an unspent's `txid` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of an unspent's `script_pubkey` field failed"; e),
+            E::Amount(ref e) =>
+                write_err!(f, "conversion of an unspent's `amount` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ScanTxOutSetStartError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ScanTxOutSetStartError as E;
+
+        match *self {
+            E::BestBlock(ref e) => Some(e),
+            E::TotalAmount(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::Amount(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `scantxoutset` with action "status".
+///
+/// Core returns `null` if there is no scan in progress.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStatus {
+    /// The approximate progress of the scan in percent.
+    pub progress: f64,
+}
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to `false`.
+///
+/// > getrawmempool ( verbose )
+/// >
+/// > Returns all transaction ids in memory pool as a json array of string transaction ids.
+/// >
+/// > Hint: use getmempoolentry to fetch a specific transaction from the mempool.
+/// >
+/// > Arguments:
+/// > 1. verbose (boolean, optional, default=false) True for a json object, false for array of transaction ids
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawMempool(pub Vec<String>);
+
+impl GetRawMempool {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempool, hex::HexToArrayError> {
+        let v = self.0.iter().map(|t| t.parse::<Txid>()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::GetRawMempool(v))
+    }
+}
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to `true`.
+///
+/// Map of txid to [`MempoolEntry`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawMempoolVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetRawMempoolVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempoolVerbose, MapMempoolEntryError> {
+        use MapMempoolEntryError as E;
+
+        let mut map = BTreeMap::new();
+        for (k, v) in self.0.into_iter() {
+            let txid = k.parse::<Txid>().map_err(E::Txid)?;
+            let entry = v.into_model().map_err(E::MempoolEntry)?;
+            map.insert(txid, entry);
+        }
+        Ok(model::GetRawMempoolVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolentry`.
+///
+/// > getmempoolentry txid
+/// >
+/// > Returns mempool data for given transaction
+/// >
+/// > Arguments:
+/// > 1. "txid"                 (string, required) The transaction id (must be in mempool)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolEntry(pub MempoolEntry);
+
+impl GetMempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolEntry, MempoolEntryError> {
+        Ok(model::GetMempoolEntry(self.0.into_model()?))
+    }
+}
+
+/// A transaction in the mempool.
+///
+/// Used by `getmempoolentry` and `getrawmempool` with verbose set to `true`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntry {
+    /// Virtual transaction size as defined in BIP 141.
+    ///
+    /// This is different from actual serialized size for witness transactions as witness data is
+    /// discounted.
+    pub size: u64,
+    /// Transaction fee in BTC (DEPRECATED).
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub fee: f64,
+    /// Transaction fee with fee deltas used for mining priority (DEPRECATED).
+    #[serde(rename = "modifiedfee")]
+    pub modified_fee: f64,
+    /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
+    pub time: u64,
+    /// Block height when transaction entered pool.
+    pub height: u64,
+    /// Number of in-mempool descendant transactions (including this one).
+    #[serde(rename = "descendantcount")]
+    pub descendant_count: u64,
+    /// Virtual transaction size of in-mempool descendants (including this one).
+    #[serde(rename = "descendantsize")]
+    pub descendant_size: u64,
+    /// Modified fees (see above) of in-mempool descendants (including this one) (DEPRECATED).
+    #[serde(rename = "descendantfees")]
+    pub descendant_fees: u64,
+    /// Number of in-mempool ancestor transactions (including this one).
+    #[serde(rename = "ancestorcount")]
+    pub ancestor_count: u64,
+    /// Virtual transaction size of in-mempool ancestors (including this one).
+    #[serde(rename = "ancestorsize")]
+    pub ancestor_size: u64,
+    /// Modified fees (see above) of in-mempool ancestors (including this one) (DEPRECATED).
+    #[serde(rename = "ancestorfees")]
+    pub ancestor_fees: u64,
+    /// Hash of serialized transaction, including witness data.
+    pub wtxid: String,
+    /// Fee object which contains the base fee, modified fee (with fee deltas), and ancestor/descendant fee totals all in BTC.
+    pub fees: MempoolEntryFees,
+    /// Unconfirmed transactions used as inputs for this transaction (parent transaction id).
+    pub depends: Vec<String>,
+    /// Used by unconfirmed transactions spending outputs from this transaction (child transaction id).
+    #[serde(rename = "spentby")]
+    pub spent_by: Vec<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee)
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: bool,
+}
+
+impl MempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolEntry, MempoolEntryError> {
+        use MempoolEntryError as E;
+
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let fees = self.fees.into_model().map_err(E::Fees)?;
+        let depends = self
+            .depends
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Depends)?;
+        let spent_by = self
+            .spent_by
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::SpentBy)?;
+
+        Ok(model::MempoolEntry {
+            vsize: self.size,
+            weight: None,
+            time: self.time,
+            height: self.height,
+            descendant_count: self.descendant_count,
+            descendant_size: self.descendant_size,
+            ancestor_count: self.ancestor_count,
+            ancestor_size: self.ancestor_size,
+            wtxid,
+            fees,
+            depends,
+            spent_by,
+            bip125_replaceable: Some(self.bip125_replaceable),
+            unbroadcast: None,
+        })
+    }
+}
+
+/// The `fees` field from the result of JSON-RPC method `getmempoolentry`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntryFees {
+    /// Transaction fee in BTC.
+    pub base: f64,
+    /// Transaction fee with fee deltas used for mining priority in BTC.
+    pub modified: f64,
+    /// Modified fees (see above) of in-mempool ancestors (including this one) in BTC
+    pub ancestor: f64,
+    /// Modified fees (see above) of in-mempool descendants (including this one) in BTC.
+    pub descendant: f64,
+}
+
+impl MempoolEntryFees {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolEntryFees, MempoolEntryFeesError> {
+        use MempoolEntryFeesError as E;
+
+        Ok(model::MempoolEntryFees {
+            base: Amount::from_btc(self.base).map_err(E::Base)?,
+            modified: Amount::from_btc(self.modified).map_err(E::Modified)?,
+            ancestor: Amount::from_btc(self.ancestor).map_err(E::Ancestor)?,
+            descendant: Amount::from_btc(self.descendant).map_err(E::Descendant)?,
+        })
+    }
+}
+
+/// Error when converting a `MempoolEntryFees` type into the model type.
+#[derive(Debug)]
+pub enum MempoolEntryFeesError {
+    /// Conversion of the `base` field failed.
+    Base(amount::ParseAmountError),
+    /// Conversion of the `modified` field failed.
+    Modified(amount::ParseAmountError),
+    /// Conversion of the `ancestor` field failed.
+    Ancestor(amount::ParseAmountError),
+    /// Conversion of the `descendant` field failed.
+    Descendant(amount::ParseAmountError),
+}
+
+impl fmt::Display for MempoolEntryFeesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use MempoolEntryFeesError::*;
+
+        match *self {
+            Base(ref e) => write_err!(f, "conversion of the `base` field failed"; e),
+            Modified(ref e) => write_err!(f, "conversion of the `modified` field failed"; e),
+            Ancestor(ref e) => write_err!(f, "conversion of the `ancestor` field failed"; e),
+            Descendant(ref e) => write_err!(f, "conversion of the `descendant` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for MempoolEntryFeesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use MempoolEntryFeesError::*;
+
+        match *self {
+            Base(ref e) => Some(e),
+            Modified(ref e) => Some(e),
+            Ancestor(ref e) => Some(e),
+            Descendant(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `MempoolEntry` type into the model type.
+#[derive(Debug)]
+pub enum MempoolEntryError {
+    /// Conversion of the `wtxid` field failed.
+    Wtxid(hex::HexToArrayError),
+    /// Conversion of the `fees` field failed.
+    Fees(MempoolEntryFeesError),
+    /// Conversion of the `depends` field failed.
+    Depends(hex::HexToArrayError),
+    /// Conversion of the `spent_by` field failed.
+    SpentBy(hex::HexToArrayError),
+}
+
+impl fmt::Display for MempoolEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use MempoolEntryError::*;
+
+        match *self {
+            Wtxid(ref e) => write_err!(f, "conversion of the `wtxid` field failed"; e),
+            Fees(ref e) => write_err!(f, "conversion of the `fees` field failed"; e),
+            Depends(ref e) => write_err!(f, "conversion of the `depends` field failed"; e),
+            SpentBy(ref e) => write_err!(f, "conversion of the `spent_by` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for MempoolEntryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use MempoolEntryError::*;
+
+        match *self {
+            Wtxid(ref e) => Some(e),
+            Fees(ref e) => Some(e),
+            Depends(ref e) => Some(e),
+            SpentBy(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `Map<String, MempoolEntry>` into the model type.
+#[derive(Debug)]
+pub enum MapMempoolEntryError {
+    /// Conversion of a `txid` failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of a `MempoolEntry` failed.
+    MempoolEntry(MempoolEntryError),
+}
+
+impl fmt::Display for MapMempoolEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use MapMempoolEntryError as E;
+
+        match *self {
+            E::Txid(ref e) => write_err!(f, "conversion of a `txid` failed"; e),
+            E::MempoolEntry(ref e) => write_err!(f, "conversion of a `MempoolEntry` failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for MapMempoolEntryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use MapMempoolEntryError as E;
+
+        match *self {
+            E::Txid(ref e) => Some(e),
+            E::MempoolEntry(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolinfo`.
+///
+/// > getmempoolinfo
+/// >
+/// > Returns details on the active state of the TX memory pool.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolInfo {
+    /// Current tx count.
+    pub size: u64,
+    /// Sum of all virtual transaction sizes as defined in BIP 141.
+    ///
+    /// Differs from actual serialized size because witness data is discounted.
+    pub bytes: u64,
+    /// Total memory usage for the mempool.
+    pub usage: u64,
+    /// Maximum memory usage for the mempool.
+    #[serde(rename = "maxmempool")]
+    pub max_mempool: u64,
+    /// Minimum fee rate in BTC/kB for a transaction to be accepted.
+    ///
+    /// This is the maximum of `minrelaytxfee` and the minimum mempool fee.
+    #[serde(rename = "mempoolminfee")]
+    pub mempool_min_fee: f64,
+    /// Current minimum relay fee for transactions.
+    #[serde(rename = "minrelaytxfee")]
+    pub min_relay_tx_fee: f64,
+}
+
+impl GetMempoolInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolInfo, GetMempoolInfoError> {
+        use GetMempoolInfoError as E;
+
+        let mempool_min_fee =
+            crate::fee_rate_from_btc_per_kvb(self.mempool_min_fee).map_err(E::MempoolMinFee)?;
+        let min_relay_tx_fee =
+            crate::fee_rate_from_btc_per_kvb(self.min_relay_tx_fee).map_err(E::MinRelayTxFee)?;
+
+        Ok(model::GetMempoolInfo {
+            loaded: None,
+            size: self.size,
+            bytes: self.bytes,
+            usage: self.usage,
+            total_fee: None,
+            max_mempool: self.max_mempool,
+            mempool_min_fee,
+            min_relay_tx_fee,
+            incremental_relay_fee: None,
+            unbroadcast_count: None,
+            full_rbf: None,
+        })
+    }
+}
+
+/// Error when converting a `GetMempoolInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetMempoolInfoError {
+    /// Conversion of the `mempool_min_fee` field failed.
+    MempoolMinFee(amount::ParseAmountError),
+    /// Conversion of the `min_relay_tx_fee` field failed.
+    MinRelayTxFee(amount::ParseAmountError),
+}
+
+impl fmt::Display for GetMempoolInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetMempoolInfoError::*;
+
+        match *self {
+            MempoolMinFee(ref e) =>
+                write_err!(f, "conversion of the `mempool_min_fee` field failed"; e),
+            MinRelayTxFee(ref e) =>
+                write_err!(f, "conversion of the `min_relay_tx_fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetMempoolInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetMempoolInfoError::*;
+
+        match *self {
+            MempoolMinFee(ref e) => Some(e),
+            MinRelayTxFee(ref e) => Some(e),
         }
     }
 }
@@ -3,3 +3,101 @@
 //! The JSON-RPC API for Bitcoin Core v0.17.1 - control.
 //!
 //! Types for methods found under the `== Control ==` section of the API docs.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `getmemoryinfo` with mode "stats" (the default).
+///
+/// > getmemoryinfo ("mode")
+/// >
+/// > Returns an object containing information about memory usage.
+/// >
+/// > Arguments:
+/// > 1. "mode" determines what kind of information is returned. This argument is optional, the default mode is "stats".
+/// >   - "stats" returns general statistics about memory usage in the daemon.
+/// >   - "mallocinfo" returns an XML string describing low-level heap state (only available if compiled with glibc 2.10+).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetMemoryInfoStats {
+    /// Information about locked memory manager.
+    pub locked: Locked,
+}
+
+/// Information about locked memory manager, part of [`GetMemoryInfoStats`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Locked {
+    /// Number of bytes used.
+    pub used: u64,
+    /// Number of bytes available in current arenas.
+    pub free: u64,
+    /// Total number of bytes managed.
+    pub total: u64,
+    /// Amount of bytes that succeeded locking. If this number is smaller than total, locking
+    /// pages failed at some point and key data could be swapped to disk.
+    pub locked: u64,
+    /// Number allocated chunks.
+    pub chunks_used: u64,
+    /// Number unused chunks.
+    pub chunks_free: u64,
+}
+
+impl GetMemoryInfoStats {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetMemoryInfoStats {
+        let locked = self.locked;
+        model::GetMemoryInfoStats {
+            locked: model::Locked {
+                used: locked.used,
+                free: locked.free,
+                total: locked.total,
+                locked: locked.locked,
+                chunks_used: locked.chunks_used,
+                chunks_free: locked.chunks_free,
+            },
+        }
+    }
+}
+
+/// Result of JSON-RPC method `getmemoryinfo` with mode "mallocinfo".
+///
+/// An XML string describing low-level heap state, only available if Core is compiled with glibc.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetMemoryInfoMallocInfo(pub String);
+
+impl GetMemoryInfoMallocInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetMemoryInfoMallocInfo {
+        model::GetMemoryInfoMallocInfo(self.0)
+    }
+}
+
+/// Result of JSON-RPC method `logging`.
+///
+/// > logging ( <include> <exclude> )
+/// >
+/// > Gets and sets the logging configuration.
+/// > When called without an argument, returns the list of categories with status that are currently being debug logged or not.
+/// > When called with arguments, adds or removes categories from debug logging and return the lists above.
+/// > The arguments are evaluated in order "include", "exclude".
+/// > If an item is both included and excluded, it will thus end up being excluded.
+/// >
+/// > Arguments:
+/// > 1. "include"        (array of strings, optional) A json array of categories to add debug logging
+/// > 2. "exclude"        (array of strings, optional) A json array of categories to remove debug logging
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Logging(pub BTreeMap<String, bool>);
+
+impl Logging {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Logging {
+        let categories = self
+            .0
+            .into_iter()
+            .map(|(category, active)| (model::LogCategory::from(category.as_str()), active))
+            .collect();
+        model::Logging(categories)
+    }
+}
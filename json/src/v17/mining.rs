@@ -3,3 +3,339 @@
 //! The JSON-RPC API for Bitcoin Core v0.17.1 - mining.
 //!
 //! Types for methods found under the `== Mining ==` section of the API docs.
+
+use core::fmt;
+use std::collections::BTreeMap;
+
+use bitcoin::consensus::encode;
+use bitcoin::error::UnprefixedHexError;
+use bitcoin::{
+    block, hex, Amount, BlockHash, CompactTarget, ScriptBuf, Target, Transaction, Txid, Weight,
+    Wtxid,
+};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::{model, Warnings};
+
+/// Result of the JSON-RPC method `getmininginfo`.
+///
+/// > getmininginfo
+/// >
+/// > Returns a json object containing mining-related information.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMiningInfo {
+    /// The current block.
+    pub blocks: u64,
+    /// The last block weight.
+    #[serde(rename = "currentblockweight")]
+    pub current_block_weight: Option<u64>,
+    /// The last block transaction.
+    #[serde(rename = "currentblocktx")]
+    pub current_block_tx: Option<u64>,
+    /// The current difficulty.
+    pub difficulty: f64,
+    /// The network hashes per second.
+    #[serde(rename = "networkhashps")]
+    pub network_hash_ps: f64,
+    /// The size of the mempool.
+    #[serde(rename = "pooledtx")]
+    pub pooled_tx: u64,
+    /// Current network name as defined in BIP70 (main, test, regtest).
+    pub chain: String,
+    /// Any network and blockchain warnings.
+    pub warnings: String,
+}
+
+impl GetMiningInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetMiningInfo {
+        model::GetMiningInfo {
+            blocks: self.blocks,
+            current_block_weight: self.current_block_weight.map(Weight::from_wu),
+            current_block_tx: self.current_block_tx,
+            difficulty: self.difficulty,
+            network_hash_ps: self.network_hash_ps,
+            pooled_tx: self.pooled_tx,
+            chain: model::Chain::from_core_arg(&self.chain),
+            target: None,
+            bits: None,
+            signet_challenge: None,
+            warnings: Warnings::from(self.warnings).into_model(),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `getblocktemplate`.
+///
+/// > getblocktemplate ( TemplateRequest )
+/// >
+/// > If the request parameters include a 'mode' key, that is used to explicitly select between the default 'template' request or a 'proposal'.
+/// > It returns data needed to construct a block to work on.
+/// > For full specification, see BIPs 22, 23, 9, and 145:
+/// >     <https://github.com/bitcoin/bips/blob/master/bip-0022.mediawiki>
+/// >     <https://github.com/bitcoin/bips/blob/master/bip-0023.mediawiki>
+/// >     <https://github.com/bitcoin/bips/blob/master/bip-0009.mediawiki#getblocktemplate_changes>
+/// >     <https://github.com/bitcoin/bips/blob/master/bip-0145.mediawiki>
+/// >
+/// > Arguments:
+/// > 1. template_request         (json object, optional) A json object in the following spec
+/// >      {
+/// >        "mode": "str",       (string, optional) This must be set to "template", "proposal" (see BIP 23), or omitted
+/// >        "capabilities": [    (json array, optional) A list of strings
+/// >          "support",         (string) client side supported feature, 'longpoll', 'coinbasetxn', 'coinbasevalue', 'proposal', 'serverlist', 'workid'
+/// >          ...
+/// >        ],
+/// >        "rules": [           (json array, required) A list of strings
+/// >          "support",         (string) client side supported softfork deployment
+/// >          ...
+/// >        ],
+/// >      }
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockTemplate {
+    /// Specific client side supported features.
+    pub capabilities: Vec<String>,
+    /// The preferred block version.
+    pub version: i32,
+    /// Specific block rules that are to be enforced.
+    pub rules: Vec<String>,
+    /// Set of pending, supported versionbit (BIP 9) softfork deployments.
+    ///
+    /// Map of rule name to the bit number identifying the rule.
+    #[serde(rename = "vbavailable")]
+    pub version_bits_available: BTreeMap<String, u32>,
+    /// Bit mask of versionbits the server requires set in submissions.
+    #[serde(rename = "vbrequired")]
+    pub version_bits_required: u32,
+    /// The hash of current highest block.
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: String,
+    /// Contents of non-coinbase transactions that should be included in the next block.
+    pub transactions: Vec<BlockTemplateTransaction>,
+    /// Data that should be included in the coinbase's scriptSig content.
+    ///
+    /// Map of key name to hex-encoded data (v0.17 includes `flags`, later versions are empty).
+    #[serde(rename = "coinbaseaux")]
+    pub coinbase_aux: BTreeMap<String, String>,
+    /// Maximum allowable input to coinbase transaction, including the generation award and
+    /// transaction fees (in satoshis).
+    #[serde(rename = "coinbasevalue")]
+    pub coinbase_value: u64,
+    /// An id to include with a request to longpoll on an update to this template.
+    #[serde(rename = "longpollid")]
+    pub long_poll_id: String,
+    /// The hash target, in hex.
+    pub target: String,
+    /// The minimum timestamp appropriate for next block time in seconds since epoch.
+    #[serde(rename = "mintime")]
+    pub min_time: u32,
+    /// List of ways the block template may be changed.
+    pub mutable: Vec<String>,
+    /// A range of valid nonces.
+    #[serde(rename = "noncerange")]
+    pub nonce_range: String,
+    /// Limit of sigops in blocks.
+    #[serde(rename = "sigoplimit")]
+    pub sigop_limit: u32,
+    /// Limit of block size.
+    #[serde(rename = "sizelimit")]
+    pub size_limit: u32,
+    /// Limit of block weight.
+    #[serde(rename = "weightlimit")]
+    pub weight_limit: u64,
+    /// Current timestamp in seconds since epoch.
+    #[serde(rename = "curtime")]
+    pub current_time: u32,
+    /// Compressed target of next block, in hex.
+    pub bits: String,
+    /// The height of the next block.
+    pub height: u32,
+    /// The block challenge (aka. block script) in hex (only present on signet, v0.21 onwards).
+    pub signet_challenge: Option<String>,
+    /// A valid witness commitment for the unmodified block template, in hex (only present if the
+    /// template includes witness transactions).
+    pub default_witness_commitment: Option<String>,
+}
+
+/// A transaction in the result of JSON-RPC method `getblocktemplate`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BlockTemplateTransaction {
+    /// Transaction data encoded in hexadecimal (byte-for-byte).
+    pub data: String,
+    /// Transaction id encoded in little-endian hexadecimal.
+    pub txid: String,
+    /// Hash encoded in little-endian hexadecimal (including witness data).
+    pub hash: String,
+    /// Transactions before this one (by 1-based index in `transactions` list) that must be
+    /// present in the final block if this one is.
+    pub depends: Vec<u32>,
+    /// Difference in value between transaction inputs and outputs (in satoshis).
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub fee: u64,
+    /// Total SigOps cost, as counted for purposes of block limits.
+    pub sigops: u32,
+    /// Total transaction weight, as counted for purposes of block limits.
+    pub weight: u64,
+}
+
+impl GetBlockTemplate {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockTemplate, GetBlockTemplateError> {
+        use GetBlockTemplateError as E;
+
+        let previous_block_hash =
+            self.previous_block_hash.parse::<BlockHash>().map_err(E::PreviousBlockHash)?;
+        let transactions = self
+            .transactions
+            .into_iter()
+            .map(|t| t.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Transactions)?;
+        let target = Target::from_unprefixed_hex(&self.target).map_err(E::Target)?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let signet_challenge = self
+            .signet_challenge
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::SignetChallenge)?;
+        let default_witness_commitment = self
+            .default_witness_commitment
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::DefaultWitnessCommitment)?;
+
+        Ok(model::GetBlockTemplate {
+            capabilities: self.capabilities,
+            version: block::Version::from_consensus(self.version),
+            rules: self.rules,
+            version_bits_available: self.version_bits_available,
+            version_bits_required: self.version_bits_required,
+            previous_block_hash,
+            transactions,
+            coinbase_aux: self.coinbase_aux,
+            coinbase_value: Amount::from_sat(self.coinbase_value),
+            long_poll_id: self.long_poll_id,
+            target,
+            min_time: self.min_time,
+            mutable: self.mutable,
+            nonce_range: self.nonce_range,
+            sigop_limit: self.sigop_limit,
+            size_limit: self.size_limit,
+            weight_limit: Weight::from_wu(self.weight_limit),
+            current_time: self.current_time,
+            bits,
+            height: self.height,
+            signet_challenge,
+            default_witness_commitment,
+        })
+    }
+}
+
+impl BlockTemplateTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::BlockTemplateTransaction, BlockTemplateTransactionError> {
+        use BlockTemplateTransactionError as E;
+
+        let data = encode::deserialize_hex::<Transaction>(&self.data).map_err(E::Data)?;
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let wtxid = self.hash.parse::<Wtxid>().map_err(E::Hash)?;
+
+        Ok(model::BlockTemplateTransaction {
+            data,
+            txid,
+            wtxid,
+            depends: self.depends,
+            fee: Amount::from_sat(self.fee),
+            sigops: self.sigops,
+            weight: Weight::from_wu(self.weight),
+        })
+    }
+}
+
+/// Error when converting a `GetBlockTemplate` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockTemplateError {
+    /// Conversion of the `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the `transactions` field failed.
+    Transactions(BlockTemplateTransactionError),
+    /// Conversion of the `target` field failed.
+    Target(UnprefixedHexError),
+    /// Conversion of the `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the `signet_challenge` field failed.
+    SignetChallenge(hex::HexToBytesError),
+    /// Conversion of the `default_witness_commitment` field failed.
+    DefaultWitnessCommitment(hex::HexToBytesError),
+}
+
+impl fmt::Display for GetBlockTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetBlockTemplateError::*;
+
+        match *self {
+            PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            Transactions(ref e) =>
+                write_err!(f, "conversion of the `transactions` field failed"; e),
+            Target(ref e) => write_err!(f, "conversion of the `target` field failed"; e),
+            Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            SignetChallenge(ref e) =>
+                write_err!(f, "conversion of the `signet_challenge` field failed"; e),
+            DefaultWitnessCommitment(ref e) =>
+                write_err!(f, "conversion of the `default_witness_commitment` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetBlockTemplateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetBlockTemplateError::*;
+
+        match *self {
+            PreviousBlockHash(ref e) => Some(e),
+            Transactions(ref e) => Some(e),
+            Target(ref e) => Some(e),
+            Bits(ref e) => Some(e),
+            SignetChallenge(ref e) => Some(e),
+            DefaultWitnessCommitment(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `BlockTemplateTransaction` type into the model type.
+#[derive(Debug)]
+pub enum BlockTemplateTransactionError {
+    /// Conversion of the `data` field failed.
+    Data(encode::FromHexError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `hash` field failed.
+    Hash(hex::HexToArrayError),
+}
+
+impl fmt::Display for BlockTemplateTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use BlockTemplateTransactionError::*;
+
+        match *self {
+            Data(ref e) => write_err!(f, "conversion of the `data` field failed"; e),
+            Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for BlockTemplateTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use BlockTemplateTransactionError::*;
+
+        match *self {
+            Data(ref e) => Some(e),
+            Txid(ref e) => Some(e),
+            Hash(ref e) => Some(e),
+        }
+    }
+}
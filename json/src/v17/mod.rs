@@ -12,31 +12,31 @@
 //! - [x] `getblock "blockhash" ( verbosity ) `
 //! - [x] `getblockchaininfo`
 //! - [ ] `getblockcount`
-//! - [ ] `getblockhash height`
-//! - [ ] `getblockheader "hash" ( verbose )`
+//! - [x] `getblockhash height`
+//! - [x] `getblockheader "hash" ( verbose )`
 //! - [ ] `getblockstats hash_or_height ( stats )`
-//! - [ ] `getchaintips`
+//! - [x] `getchaintips`
 //! - [ ] `getchaintxstats ( nblocks blockhash )`
 //! - [ ] `getdifficulty`
 //! - [ ] `getmempoolancestors txid (verbose)`
 //! - [ ] `getmempooldescendants txid (verbose)`
-//! - [ ] `getmempoolentry txid`
-//! - [ ] `getmempoolinfo`
-//! - [ ] `getrawmempool ( verbose )`
-//! - [ ] `gettxout "txid" n ( include_mempool )`
-//! - [ ] `gettxoutproof ["txid",...] ( blockhash )`
-//! - [ ] `gettxoutsetinfo`
-//! - [ ] `preciousblock "blockhash"`
+//! - [x] `getmempoolentry txid`
+//! - [x] `getmempoolinfo`
+//! - [x] `getrawmempool ( verbose )`
+//! - [x] `gettxout "txid" n ( include_mempool )`
+//! - [x] `gettxoutproof ["txid",...] ( blockhash )`
+//! - [x] `gettxoutsetinfo`
+//! - [x] `preciousblock "blockhash"`
 //! - [ ] `pruneblockchain`
 //! - [ ] `savemempool`
-//! - [ ] `scantxoutset <action> ( <scanobjects> )`
-//! - [ ] `verifychain ( checklevel nblocks )`
-//! - [ ] `verifytxoutproof "proof"`
+//! - [x] `scantxoutset <action> ( <scanobjects> )`
+//! - [x] `verifychain ( checklevel nblocks )`
+//! - [x] `verifytxoutproof "proof"`
 //!
 //! **== Control ==**
-//! - [ ] `getmemoryinfo ("mode")`
+//! - [x] `getmemoryinfo ("mode")`
 //! - [ ] `help ( "command" )`
-//! - [ ] `logging ( <include> <exclude> )`
+//! - [x] `logging ( <include> <exclude> )`
 //! - [x] `stop`
 //! - [ ] `uptime`
 //!
@@ -45,8 +45,8 @@
 //! - [x] `generatetoaddress nblocks address (maxtries)`
 //!
 //! **== Mining ==**
-//! - [ ] `getblocktemplate ( TemplateRequest )`
-//! - [ ] `getmininginfo`
+//! - [x] `getblocktemplate ( TemplateRequest )`
+//! - [x] `getmininginfo`
 //! - [ ] `getnetworkhashps ( nblocks height )`
 //! - [ ] `prioritisetransaction <txid> <dummy value> <fee delta>`
 //! - [ ] `submitblock "hexdata"  ( "dummy" )`
@@ -59,95 +59,95 @@
 //! - [ ] `getconnectioncount`
 //! - [ ] `getnettotals`
 //! - [x] `getnetworkinfo`
-//! - [ ] `getpeerinfo`
+//! - [x] `getpeerinfo`
 //! - [ ] `listbanned`
 //! - [ ] `ping`
 //! - [ ] `setban "subnet" "add|remove" (bantime) (absolute)`
 //! - [ ] `setnetworkactive true|false`
 //!
 //! **== Rawtransactions ==**
-//! - [ ] `combinepsbt ["psbt",...]`
+//! - [x] `combinepsbt ["psbt",...]`
 //! - [ ] `combinerawtransaction ["hexstring",...]`
 //! - [ ] `converttopsbt "hexstring" ( permitsigdata iswitness )`
 //! - [ ] `createpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )`
-//! - [ ] `createrawtransaction [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )`
-//! - [ ] `decodepsbt "psbt"`
-//! - [ ] `decoderawtransaction "hexstring" ( iswitness )`
+//! - [x] `createrawtransaction [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )`
+//! - [x] `decodepsbt "psbt"`
+//! - [x] `decoderawtransaction "hexstring" ( iswitness )`
 //! - [ ] `decodescript "hexstring"`
-//! - [ ] `finalizepsbt "psbt" ( extract )`
-//! - [ ] `fundrawtransaction "hexstring" ( options iswitness )`
-//! - [ ] `getrawtransaction "txid" ( verbose "blockhash" )`
-//! - [ ] `sendrawtransaction "hexstring" ( allowhighfees )`
+//! - [x] `finalizepsbt "psbt" ( extract )`
+//! - [x] `fundrawtransaction "hexstring" ( options iswitness )`
+//! - [x] `getrawtransaction "txid" ( verbose "blockhash" )`
+//! - [x] `sendrawtransaction "hexstring" ( allowhighfees )`
 //! - [ ] `signrawtransaction "hexstring" ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] ["privatekey1",...] sighashtype )`
 //! - [ ] `signrawtransactionwithkey "hexstring" ["privatekey1",...] ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )`
-//! - [ ] `testmempoolaccept ["rawtxs"] ( allowhighfees )`
+//! - [x] `testmempoolaccept ["rawtxs"] ( allowhighfees )`
 //!
 //! **== Util ==**
 //! - [ ] `createmultisig nrequired ["key",...] ( "address_type" )`
-//! - [ ] `estimatesmartfee conf_target ("estimate_mode")`
+//! - [x] `estimatesmartfee conf_target ("estimate_mode")`
 //! - [ ] `signmessagewithprivkey "privkey" "message"`
 //! - [ ] `validateaddress "address"`
-//! - [ ] `verifymessage "address" "signature" "message"`
+//! - [x] `verifymessage "address" "signature" "message"`
 //!
 //! **== Wallet ==**
 //! - [ ] `abandontransaction "txid"`
 //! - [ ] `abortrescan`
-//! - [ ] `addmultisigaddress nrequired ["key",...] ( "label" "address_type" )`
+//! - [x] `addmultisigaddress nrequired ["key",...] ( "label" "address_type" )`
 //! - [ ] `backupwallet "destination"`
-//! - [ ] `bumpfee "txid" ( options ) `
+//! - [x] `bumpfee "txid" ( options ) `
 //! - [x] `createwallet "wallet_name" ( disable_private_keys )`
 //! - [ ] `dumpprivkey "address"`
 //! - [ ] `dumpwallet "filename"`
-//! - [ ] `encryptwallet "passphrase"`
-//! - [ ] `getaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
-//! - [ ] `getaccountaddress (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
-//! - [ ] `getaddressbyaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
-//! - [ ] `getaddressesbylabel "label"`
-//! - [ ] `getaddressinfo "address"`
+//! - [x] `encryptwallet "passphrase"`
+//! - [x] `getaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
+//! - [x] `getaccountaddress (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
+//! - [x] `getaddressbyaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
+//! - [x] `getaddressesbylabel "label"`
+//! - [x] `getaddressinfo "address"`
 //! - [x] `getbalance ( "(dummy)" minconf include_watchonly )`
 //! - [x] `getnewaddress ( "label" "address_type" )`
-//! - [ ] `getrawchangeaddress ( "address_type" )`
-//! - [ ] `getreceivedbyaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
-//! - [ ] `getreceivedbyaddress "address" ( minconf )`
+//! - [x] `getrawchangeaddress ( "address_type" )`
+//! - [x] `getreceivedbyaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
+//! - [x] `getreceivedbyaddress "address" ( minconf )`
 //! - [x] `gettransaction "txid" ( include_watchonly )`
 //! - [ ] `getunconfirmedbalance`
-//! - [ ] `getwalletinfo`
-//! - [ ] `importaddress "address" ( "label" rescan p2sh )`
-//! - [ ] `importmulti "requests" ( "options" )`
+//! - [x] `getwalletinfo`
+//! - [x] `importaddress "address" ( "label" rescan p2sh )`
+//! - [x] `importmulti "requests" ( "options" )`
 //! - [ ] `importprivkey "privkey" ( "label" ) ( rescan )`
 //! - [ ] `importprunedfunds`
 //! - [ ] `importpubkey "pubkey" ( "label" rescan )`
 //! - [ ] `importwallet "filename"`
 //! - [ ] `keypoolrefill ( newsize )`
-//! - [ ] `listaccounts (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
+//! - [x] `listaccounts (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `listaddressgroupings`
 //! - [ ] `listlabels ( "purpose" )`
-//! - [ ] `listlockunspent`
+//! - [x] `listlockunspent`
 //! - [ ] `listreceivedbyaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
-//! - [ ] `listreceivedbyaddress ( minconf include_empty include_watchonly address_filter )`
-//! - [ ] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
-//! - [ ] `listtransactions (label count skip include_watchonly)`
-//! - [ ] `listunspent ( minconf maxconf  ["addresses",...] [include_unsafe] [query_options])`
-//! - [ ] `listwallets`
+//! - [x] `listreceivedbyaddress ( minconf include_empty include_watchonly address_filter )`
+//! - [x] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
+//! - [x] `listtransactions (label count skip include_watchonly)`
+//! - [x] `listunspent ( minconf maxconf  ["addresses",...] [include_unsafe] [query_options])`
+//! - [x] `listwallets`
 //! - [x] `loadwallet "filename"`
-//! - [ ] `lockunspent unlock ([{"txid":"txid","vout":n},...])`
+//! - [x] `lockunspent unlock ([{"txid":"txid","vout":n},...])`
 //! - [ ] `move (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `removeprunedfunds "txid"`
-//! - [ ] `rescanblockchain ("start_height") ("stop_height")`
+//! - [x] `rescanblockchain ("start_height") ("stop_height")`
 //! - [ ] `sendfrom (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `sendmany "" {"address":amount,...} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode")`
 //! - [x] `sendtoaddress "address" amount ( "comment" "comment_to" subtractfeefromamount replaceable conf_target "estimate_mode")`
-//! - [ ] `setaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
+//! - [x] `setaccount (Deprecated, will be removed in V0.18. To use this command, start bitcoind with -deprecatedrpc=accounts)`
 //! - [ ] `sethdseed ( "newkeypool" "seed" )`
 //! - [ ] `settxfee amount`
-//! - [ ] `signmessage "address" "message"`
-//! - [ ] `signrawtransactionwithwallet "hexstring" ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )`
+//! - [x] `signmessage "address" "message"`
+//! - [x] `signrawtransactionwithwallet "hexstring" ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )`
 //! - [ ] `unloadwallet ( "wallet_name" )`
-//! - [ ] `walletcreatefundedpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable ) ( options bip32derivs )`
-//! - [ ] `walletlock`
-//! - [ ] `walletpassphrase "passphrase" timeout`
-//! - [ ] `walletpassphrasechange "oldpassphrase" "newpassphrase"`
-//! - [ ] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )`
+//! - [x] `walletcreatefundedpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable ) ( options bip32derivs )`
+//! - [x] `walletlock`
+//! - [x] `walletpassphrase "passphrase" timeout`
+//! - [x] `walletpassphrasechange "oldpassphrase" "newpassphrase"`
+//! - [x] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )`
 //!
 //! **== Zmq ==**
 //! - [ ] `getzmqnotifications`
@@ -166,14 +166,48 @@ mod zmq;
 #[doc(inline)]
 pub use self::{
     blockchain::{
-        Bip9Softfork, Bip9SoftforkStatus, GetBestBlockHash, GetBlockVerbosityOne,
-        GetBlockVerbosityZero, GetBlockchainInfo, GetTxOut, ScriptPubkey, Softfork, SoftforkReject,
+        Bip9Softfork, Bip9SoftforkStatus, ChainTips, ChainTipsStatus, GetBestBlockHash,
+        GetBlockHash, GetBlockHeader, GetBlockHeaderVerbose, GetBlockHeaderVerboseError,
+        GetBlockVerbosityOne, GetBlockVerbosityOneError, GetBlockVerbosityTwo,
+        GetBlockVerbosityTwoError, GetBlockVerbosityZero, GetBlockchainInfo, GetChainTips,
+        GetMempoolEntry, GetMempoolInfo, GetMempoolInfoError, GetRawMempool, GetRawMempoolVerbose,
+        GetTxOut, GetTxOutProof, GetTxOutSetInfo, GetTxOutSetInfoError, MapMempoolEntryError,
+        MempoolEntry, MempoolEntryError, MempoolEntryFees, MempoolEntryFeesError,
+        ScanTxOutSetStart, ScanTxOutSetStartError, ScanTxOutSetStatus, ScanTxOutSetUnspent,
+        ScriptPubkey, Softfork, SoftforkReject, VerifyTxOutProof,
     },
+    control::{GetMemoryInfoMallocInfo, GetMemoryInfoStats, Locked, Logging},
     generating::GenerateToAddress,
-    network::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork},
-    raw_transactions::SendRawTransaction,
+    mining::{
+        BlockTemplateTransaction, BlockTemplateTransactionError, GetBlockTemplate,
+        GetBlockTemplateError, GetMiningInfo,
+    },
+    network::{
+        GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, GetPeerInfo, PeerInfo,
+    },
+    raw_transactions::{
+        Bip32Deriv, Bip32DerivOrigin, Bip32Derivs, Bip32DerivsError, CombinePsbt,
+        CreateRawTransaction, DecodePsbt, DecodePsbtError, DecodeRawTransaction,
+        DecodeRawTransactionError, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
+        FundRawTransactionError, GetRawTransaction, MempoolAcceptance, PsbtInput, PsbtInputError,
+        PsbtOutput, PsbtOutputError, PsbtScript, PsbtWitnessUtxo, RawTransactionInput,
+        RawTransactionInputError, RawTransactionOutput, RawTransactionOutputError, ScriptSig,
+        SendRawTransaction, SignFailure, SignRawTransaction, SignRawTransactionError,
+        TestMempoolAccept,
+    },
+    util::EstimateSmartFee,
     wallet::{
-        CreateWallet, GetBalance, GetNewAddress, GetTransaction, GetTransactionDetail,
-        GetTransactionDetailCategory, LoadWallet, SendToAddress,
+        AddMultisigAddress, AddMultisigAddressError, AddressInformation, AddressPurpose,
+        Bip125Replaceable, BumpFee, BumpFeeError, CreateWallet, EncryptWallet, GetAccount,
+        GetAccountAddress, GetAddressInfo, GetAddressInfoError, GetAddressesByAccount,
+        GetAddressesByLabel, GetBalance, GetNewAddress, GetRawChangeAddress, GetReceivedByAccount,
+        GetReceivedByAddress, GetTransaction, GetTransactionDetail, GetTransactionDetailCategory,
+        GetTransactionError, GetWalletInfo, GetWalletInfoError, ImportMulti, ImportMultiResult,
+        JsonRpcError, ListAccounts, ListLockUnspent, ListLockUnspentItem, ListReceivedByAddress,
+        ListReceivedByAddressItem, ListReceivedByAddressItemError, ListSinceBlock,
+        ListSinceBlockError, ListTransactions, ListUnspent, ListUnspentItem, ListUnspentItemError,
+        ListWallets, LoadWallet, RescanBlockchain, SendToAddress, SignMessage, TransactionItem,
+        TransactionItemError, WalletCreateFundedPsbt, WalletCreateFundedPsbtError,
+        WalletProcessPsbt,
     },
 };
@@ -5,8 +5,10 @@
 //! Types for methods found under the `== Network ==` section of the API docs.
 
 use core::fmt;
+use core::num::ParseIntError;
 
-use bitcoin::{amount, Amount, FeeRate};
+use bitcoin::amount;
+use bitcoin::p2p::ServiceFlags;
 use internals::write_err;
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +31,9 @@ pub struct GetNetworkInfo {
     /// The services we offer to the network (hex string).
     #[serde(rename = "localservices")]
     pub local_services: String,
+    /// The services we offer to the network, in human-readable form (v19 onwards).
+    #[serde(rename = "localservicesnames")]
+    pub local_services_names: Option<Vec<String>>,
     /// `true` if transaction relay is requested from peers.
     #[serde(rename = "localrelay")]
     pub local_relay: bool,
@@ -37,6 +42,10 @@ pub struct GetNetworkInfo {
     pub time_offset: isize,
     /// The total number of connections.
     pub connections: usize,
+    /// The number of inbound connections (v21 onwards).
+    pub connections_in: Option<usize>,
+    /// The number of outbound connections (v21 onwards).
+    pub connections_out: Option<usize>,
     #[serde(rename = "networkactive")]
     /// Whether p2p networking is enabled.
     pub network_active: bool,
@@ -86,21 +95,34 @@ impl GetNetworkInfo {
     pub fn into_model(self) -> Result<model::GetNetworkInfo, GetNetworkInfoError> {
         use GetNetworkInfoError as E;
 
-        let relay_fee = fee_rate_from_btc_per_kb(self.relay_fee).map_err(E::RelayFee)?;
+        let relay_fee = crate::fee_rate_from_btc_per_kvb(self.relay_fee).map_err(E::RelayFee)?;
         let incremental_fee =
-            fee_rate_from_btc_per_kb(self.incremental_fee).map_err(E::IncrementalFee)?;
+            crate::fee_rate_from_btc_per_kvb(self.incremental_fee).map_err(E::IncrementalFee)?;
+        let local_services = u64::from_str_radix(&self.local_services, 16)
+            .map(ServiceFlags::from)
+            .map_err(E::LocalServices)?;
+        let local_services_names = match self.local_services_names {
+            Some(names) => {
+                model::check_service_flags_names(local_services, &names)
+                    .map_err(E::LocalServicesNames)?;
+                names
+            }
+            None => model::service_flags_names(local_services),
+        };
+        let user_agent = model::UserAgent::parse(&self.subversion);
 
         Ok(model::GetNetworkInfo {
             version: self.version,
             subversion: self.subversion,
+            user_agent,
             protocol_version: self.protocol_version,
-            local_services: self.local_services,
-            local_services_names: vec![], // TODO: Manually create names?
+            local_services,
+            local_services_names,
             local_relay: self.local_relay,
             time_offset: self.time_offset,
             connections: self.connections,
-            connections_in: 0,  // FIXME: Can we do better than this?
-            connections_out: 0, // FIXME: Can we do better than this?
+            connections_in: self.connections_in.unwrap_or(0),
+            connections_out: self.connections_out.unwrap_or(0),
             network_active: self.network_active,
             networks: self.networks.into_iter().map(|j| j.into_model()).collect(),
             relay_fee,
@@ -111,15 +133,6 @@ impl GetNetworkInfo {
     }
 }
 
-// TODO: Upstream to `rust-bitcoin`.
-/// Constructs a `bitcoin::FeeRate` from bitcoin per 1000 bytes.
-fn fee_rate_from_btc_per_kb(btc_kb: f64) -> Result<FeeRate, amount::ParseAmountError> {
-    let amount = Amount::from_btc(btc_kb)?;
-    let sat_kb = amount.to_sat();
-    // There were no virtual bytes in v0.17.1
-    Ok(FeeRate::from_sat_per_kwu(sat_kb))
-}
-
 impl GetNetworkInfoNetwork {
     /// Converts version specific type to a version in-specific, more strongly typed type.
     pub fn into_model(self) -> model::GetNetworkInfoNetwork {
@@ -127,7 +140,7 @@ impl GetNetworkInfoNetwork {
             name: self.name,
             limited: self.limited,
             reachable: self.reachable,
-            proxy: self.proxy,
+            proxy: model::PeerAddress::parse_optional(&self.proxy),
             proxy_randomize_credentials: self.proxy_randomize_credentials,
         }
     }
@@ -147,6 +160,10 @@ pub enum GetNetworkInfoError {
     RelayFee(amount::ParseAmountError),
     /// Conversion of the `incremental_fee` field failed.
     IncrementalFee(amount::ParseAmountError),
+    /// Conversion of the `local_services` field failed.
+    LocalServices(ParseIntError),
+    /// The `local_services_names` field does not match the `local_services` field.
+    LocalServicesNames(model::ServiceNamesError),
 }
 
 impl fmt::Display for GetNetworkInfoError {
@@ -157,6 +174,10 @@ impl fmt::Display for GetNetworkInfoError {
             RelayFee(ref e) => write_err!(f, "conversion of the `relay_fee` field failed"; e),
             IncrementalFee(ref e) =>
                 write_err!(f, "conversion of the `incremental_fee` field failed"; e),
+            LocalServices(ref e) =>
+                write_err!(f, "conversion of the `local_services` field failed"; e),
+            LocalServicesNames(ref e) =>
+                write_err!(f, "conversion of the `local_services_names` field failed"; e),
         }
     }
 }
@@ -168,6 +189,131 @@ impl std::error::Error for GetNetworkInfoError {
         match *self {
             RelayFee(ref e) => Some(e),
             IncrementalFee(ref e) => Some(e),
+            LocalServices(ref e) => Some(e),
+            LocalServicesNames(ref e) => Some(e),
         }
     }
 }
+
+/// Result of JSON-RPC method `getpeerinfo`.
+///
+/// > getpeerinfo
+/// >
+/// > Returns data about each connected network node as a json array of objects.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+/// An item from the list returned by the JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u64,
+    /// The IP address and port of the peer ("host:port").
+    pub addr: String,
+    /// Bind address of the connection to the peer ("ip:port").
+    #[serde(rename = "addrbind")]
+    pub addr_bind: Option<String>,
+    /// Local address as reported by the peer.
+    #[serde(rename = "addrlocal")]
+    pub addr_local: Option<String>,
+    /// The services offered (hex string).
+    pub services: String,
+    /// Whether peer has asked us to relay transactions to it.
+    #[serde(rename = "relaytxes")]
+    pub relay_txes: bool,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last send.
+    #[serde(rename = "lastsend")]
+    pub last_send: u64,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last receive.
+    #[serde(rename = "lastrecv")]
+    pub last_recv: u64,
+    /// The total bytes sent.
+    #[serde(rename = "bytessent")]
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    #[serde(rename = "bytesrecv")]
+    pub bytes_recv: u64,
+    /// The connection time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "conntime")]
+    pub connection_time: u64,
+    /// The time offset in seconds.
+    #[serde(rename = "timeoffset")]
+    pub time_offset: i64,
+    /// Ping time (if available).
+    #[serde(rename = "pingtime")]
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time (if any at all).
+    #[serde(rename = "minping")]
+    pub minimum_ping: Option<f64>,
+    /// Ping wait (if non-zero).
+    #[serde(rename = "pingwait")]
+    pub ping_wait: Option<f64>,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The string version.
+    #[serde(rename = "subver")]
+    pub subversion: String,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// Whether connection was due to `addnode`/`-connect` or if it was an automatic/inbound
+    /// connection.
+    #[serde(rename = "addnode")]
+    pub add_node: bool,
+    /// The starting height (block) of the peer.
+    #[serde(rename = "startingheight")]
+    pub starting_height: i64,
+    /// The ban score.
+    #[serde(rename = "banscore")]
+    pub ban_score: i64,
+    /// The last header we have in common with this peer.
+    pub synced_headers: i64,
+    /// The last block we have in common with this peer.
+    pub synced_blocks: i64,
+    /// The heights of blocks we're currently asking from this peer.
+    pub inflight: Vec<u64>,
+    /// Whether the peer is whitelisted.
+    pub whitelisted: bool,
+}
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, ParseIntError> {
+        let peers = self.0.into_iter().map(|peer| peer.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::GetPeerInfo(peers))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, ParseIntError> {
+        let services = u64::from_str_radix(&self.services, 16).map(ServiceFlags::from)?;
+
+        Ok(model::PeerInfo {
+            id: self.id,
+            addr: model::PeerAddress::parse(&self.addr),
+            addr_bind: self.addr_bind.as_deref().and_then(model::PeerAddress::parse_optional),
+            addr_local: self.addr_local.as_deref().and_then(model::PeerAddress::parse_optional),
+            network: None,
+            services,
+            relay_txes: self.relay_txes,
+            bytes_sent: self.bytes_sent,
+            bytes_recv: self.bytes_recv,
+            inbound: self.inbound,
+            connection_time: self.connection_time,
+            version: self.version,
+            subversion: self.subversion,
+            starting_height: self.starting_height,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            connection_type: None,
+            permissions: vec![],
+            bip152_hb_to: None,
+            bip152_hb_from: None,
+            addr_relay_enabled: None,
+            transport_protocol_type: None,
+            ban_score: Some(self.ban_score),
+            whitelisted: Some(self.whitelisted),
+            addnode: Some(self.add_node),
+        })
+    }
+}
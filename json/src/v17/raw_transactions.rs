@@ -4,10 +4,265 @@
 //!
 //! Types for methods found under the `== Rawtransactions ==` section of the API docs.
 
-use bitcoin::{hex, Txid};
+use core::fmt;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use bitcoin::bip32::{self, DerivationPath, Fingerprint, KeySource};
+use bitcoin::consensus::encode;
+use bitcoin::psbt::{self, PsbtParseError, PsbtSighashType};
+use bitcoin::sighash::SighashTypeParseError;
+use bitcoin::{
+    absolute, amount, ecdsa, hex, key, secp256k1, transaction, Amount, OutPoint, Psbt, PublicKey,
+    ScriptBuf, Sequence, Transaction, TxIn, TxOut, Txid, Witness,
+};
+use internals::write_err;
 use serde::{Deserialize, Serialize};
 
 use crate::model;
+use crate::v17::ScriptPubkey;
+
+/// Result of JSON-RPC method `createrawtransaction`.
+///
+/// > createrawtransaction [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable )
+/// >
+/// > Create a transaction spending the given inputs and creating new outputs.
+/// > Outputs can be addresses or data.
+/// > Returns hex-encoded raw transaction.
+/// > Note that the transaction's inputs are not signed, and
+/// > it is not stored in the wallet or transmitted to the network.
+/// >
+/// > Arguments:
+/// > 1. "inputs"                (array, required) A json array of json objects
+/// > 2. "outputs"               (array, required) a json array with outputs (key-value pairs)
+/// > 3. locktime                  (numeric, optional, default=0) Raw locktime. Non-0 value also locktime-activates inputs
+/// > 4. replaceable               (boolean, optional, default=false) Marks this transaction as BIP125 replaceable.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CreateRawTransaction(pub String); // The hex encoded transaction.
+
+impl CreateRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CreateRawTransaction, encode::FromHexError> {
+        let tx = encode::deserialize_hex::<Transaction>(&self.0)?;
+        Ok(model::CreateRawTransaction(tx))
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, encode::FromHexError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// Result of JSON-RPC method `fundrawtransaction`.
+///
+/// > fundrawtransaction "hexstring" ( options iswitness )
+/// >
+/// > Add inputs to a transaction until it has enough in value to meet its out value.
+/// > This will not modify existing inputs, and will add at most one change output to the outputs.
+/// > No existing outputs will be modified unless "subtractFeeFromOutputs" is specified.
+/// > Note that inputs which were signed may need to be resigned after completion since in/outputs have been added.
+/// > The inputs added will not be signed, use signrawtransaction for that.
+/// >
+/// > Arguments:
+/// > 1. "hexstring"           (string, required) The hex string of the raw transaction
+/// > 2. options                 (object, optional)
+/// > 3. iswitness               (boolean, optional) Whether the transaction hex is a serialized witness transaction
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FundRawTransaction {
+    /// The resulting raw transaction (hex-encoded string).
+    pub hex: String,
+    /// Fee in BTC the resulting transaction pays.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub fee: f64,
+    /// The position of the added change output, or -1.
+    #[serde(rename = "changepos")]
+    pub change_position: i64,
+}
+
+impl FundRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::FundRawTransaction, FundRawTransactionError> {
+        use FundRawTransactionError as E;
+
+        let tx = encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Transaction)?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+        let change_position = u32::try_from(self.change_position).ok();
+
+        Ok(model::FundRawTransaction { tx, fee, change_position })
+    }
+}
+
+/// Error when converting a `FundRawTransaction` type into the model type.
+#[derive(Debug)]
+pub enum FundRawTransactionError {
+    /// Conversion of the transaction `hex` field failed.
+    Transaction(encode::FromHexError),
+    /// Conversion of the `fee` field failed.
+    Fee(amount::ParseAmountError),
+}
+
+impl fmt::Display for FundRawTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use FundRawTransactionError as E;
+
+        match *self {
+            E::Transaction(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for FundRawTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use FundRawTransactionError as E;
+
+        match *self {
+            E::Transaction(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `getrawtransaction` with verbose set to `false`.
+///
+/// > getrawtransaction "txid" ( verbose "blockhash" )
+/// >
+/// > NOTE: By default this function only works for mempool transactions. If the -txindex option is
+/// > enabled, it also works for blockchain transactions. If the block which contains the transaction
+/// > is known, its hash can be provided even for nodes without -txindex.
+/// >
+/// > Return the raw transaction data.
+/// >
+/// > Arguments:
+/// > 1. "txid"      (string, required) The transaction id
+/// > 2. verbose     (bool, optional, default=false) If false, return a string, otherwise return a json object
+/// > 3. "blockhash" (string, optional) The block in which to look for the transaction
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawTransaction(pub String); // The hex encoded transaction.
+
+impl GetRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawTransaction, encode::FromHexError> {
+        let tx = encode::deserialize_hex::<Transaction>(&self.0)?;
+        Ok(model::GetRawTransaction(tx))
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, encode::FromHexError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
+/// Result of JSON-RPC methods `signrawtransactionwithwallet` and `signrawtransactionwithkey`.
+///
+/// > signrawtransactionwithwallet "hexstring" ( [{"txid":"id","vout":n,"scriptPubKey":"hex","redeemScript":"hex"},...] sighashtype )
+/// >
+/// > Sign inputs for raw transaction (serialized, hex-encoded).
+/// > The second optional argument (may be null) is an array of previous transaction outputs that
+/// > this transaction depends on but may not yet be in the block chain.
+/// >
+/// > Arguments:
+/// > 1. "hexstring"                      (string, required) The transaction hex string
+/// > 2. "prevtxs"                        (string, optional) An json array of previous dependent transaction outputs
+/// > 3. "sighashtype"                    (string, optional, default=ALL) The signature hash type.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignRawTransaction {
+    /// The hex-encoded raw transaction with signature(s).
+    pub hex: String,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// Script verification errors (if there are any).
+    pub errors: Option<Vec<SignFailure>>,
+}
+
+/// A script verification error, part of [`SignRawTransaction`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignFailure {
+    /// The hash of the referenced, previous transaction.
+    pub txid: String,
+    /// The index of the output to spent and used as input.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub vout: u32,
+    /// The hex-encoded signature script.
+    #[serde(rename = "scriptSig")]
+    pub script_sig: String,
+    /// Script sequence number.
+    pub sequence: u32,
+    /// Verification or signing error related to the input.
+    pub error: String,
+}
+
+impl SignRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SignRawTransaction, SignRawTransactionError> {
+        use SignRawTransactionError as E;
+
+        let tx = encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Tx)?;
+        let errors = self
+            .errors
+            .unwrap_or_default()
+            .into_iter()
+            .map(|e| e.into_model())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(model::SignRawTransaction { tx, complete: self.complete, errors })
+    }
+}
+
+impl SignFailure {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SignFailure, SignRawTransactionError> {
+        use SignRawTransactionError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::FailureTxid)?;
+        let script_sig = ScriptBuf::from_hex(&self.script_sig).map_err(E::FailureScriptSig)?;
+
+        Ok(model::SignFailure {
+            txid,
+            vout: self.vout,
+            script_sig,
+            sequence: Sequence(self.sequence),
+            error: self.error,
+        })
+    }
+}
+
+/// Error when converting a `SignRawTransaction` type into the model type.
+#[derive(Debug)]
+pub enum SignRawTransactionError {
+    /// Conversion of the transaction `hex` field failed.
+    Tx(encode::FromHexError),
+    /// Conversion of the `txid` field of one of the errors failed.
+    FailureTxid(hex::HexToArrayError),
+    /// Conversion of the `script_sig` field of one of the errors failed.
+    FailureScriptSig(hex::HexToBytesError),
+}
+
+impl fmt::Display for SignRawTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SignRawTransactionError::*;
+
+        match *self {
+            Tx(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            FailureTxid(ref e) =>
+                write_err!(f, "conversion of the `txid` field of an error failed"; e),
+            FailureScriptSig(ref e) =>
+                write_err!(f, "conversion of the `script_sig` field of an error failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for SignRawTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SignRawTransactionError::*;
+
+        match *self {
+            Tx(ref e) => Some(e),
+            FailureTxid(ref e) => Some(e),
+            FailureScriptSig(ref e) => Some(e),
+        }
+    }
+}
 
 /// Result of JSON-RPC method `sendrawtransaction`.
 ///
@@ -36,3 +291,886 @@ impl SendRawTransaction {
         Ok(model.0)
     }
 }
+
+/// Result of JSON-RPC method `testmempoolaccept`.
+///
+/// > testmempoolaccept ["rawtxs"] ( allowhighfees )
+/// >
+/// > Returns if raw transaction (serialized, hex-encoded) would be accepted by mempool.
+/// >
+/// > This checks if the transaction violates the consensus or policy rules.
+/// >
+/// > Arguments:
+/// > 1. ["rawtxs"]       (array, required) An array of hex strings of raw transactions.
+/// >                                        Length must be one for now.
+/// > 2. allowhighfees    (boolean, optional, default=false) Allow high fees
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TestMempoolAccept(pub Vec<MempoolAcceptance>);
+
+/// The mempool acceptance of a single transaction, part of [`TestMempoolAccept`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MempoolAcceptance {
+    /// The transaction hash in hex.
+    pub txid: String,
+    /// If the mempool allows this tx to be inserted.
+    pub allowed: bool,
+    /// Rejection string (only present when 'allowed' is false).
+    ///
+    /// Prefixed with the BIP-61 reject code before v0.20, e.g. "18: txn-already-in-mempool".
+    #[serde(rename = "reject-reason")]
+    pub reject_reason: Option<String>,
+}
+
+impl TestMempoolAccept {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TestMempoolAccept, hex::HexToArrayError> {
+        let results = self.0.into_iter().map(|r| r.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::TestMempoolAccept(results))
+    }
+}
+
+impl MempoolAcceptance {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolAcceptance, hex::HexToArrayError> {
+        let txid = self.txid.parse::<Txid>()?;
+        Ok(model::MempoolAcceptance {
+            txid,
+            allowed: self.allowed,
+            reject_reason: self.reject_reason.map(|reason| strip_reject_code(&reason).to_owned()),
+        })
+    }
+}
+
+/// Strips the BIP-61 reject code prefix from a reject reason, if present.
+fn strip_reject_code(reason: &str) -> &str {
+    match reason.split_once(": ") {
+        Some((code, rest)) if code.chars().all(|c| c.is_ascii_digit()) => rest,
+        _ => reason,
+    }
+}
+
+/// Result of JSON-RPC method `decoderawtransaction`.
+///
+/// > decoderawtransaction "hexstring" ( iswitness )
+/// >
+/// > Return a JSON object representing the serialized, hex-encoded transaction.
+/// >
+/// > Arguments:
+/// > 1. "hexstring"      (string, required) The transaction hex string
+/// > 2. iswitness          (boolean, optional) Whether the transaction hex is a serialized witness transaction
+///
+/// This is also the shape of the `decoded` field returned by `gettransaction` when `verbose` is
+/// set (v0.19 onwards).
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeRawTransaction {
+    /// The transaction id.
+    pub txid: String,
+    /// The transaction hash (differs from txid for witness transactions).
+    pub hash: String,
+    /// The transaction size.
+    pub size: u64,
+    /// The virtual transaction size (differs from size for witness transactions).
+    pub vsize: u64,
+    /// The transaction's weight (between vsize*4 - 3 and vsize*4).
+    pub weight: u64,
+    /// The version.
+    pub version: i32,
+    /// The lock time.
+    #[serde(rename = "locktime")]
+    pub lock_time: u32,
+    /// The transaction inputs.
+    pub vin: Vec<RawTransactionInput>,
+    /// The transaction outputs.
+    pub vout: Vec<RawTransactionOutput>,
+}
+
+/// A decoded transaction input, part of [`DecodeRawTransaction`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RawTransactionInput {
+    /// The transaction id (not present for coinbase inputs).
+    pub txid: Option<String>,
+    /// The output number (not present for coinbase inputs).
+    #[serde(default, deserialize_with = "crate::deserialize_optional_number_or_string")]
+    pub vout: Option<u32>,
+    /// The script (not present for coinbase inputs).
+    #[serde(rename = "scriptSig")]
+    pub script_sig: Option<ScriptSig>,
+    /// The coinbase script, in hex (only present for coinbase inputs).
+    pub coinbase: Option<String>,
+    /// Hex-encoded witness data (if any).
+    #[serde(rename = "txinwitness")]
+    pub witness: Option<Vec<String>>,
+    /// The script sequence number.
+    pub sequence: u32,
+}
+
+/// A script signature, part of [`RawTransactionInput`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScriptSig {
+    /// Script assembly.
+    pub asm: String,
+    /// Script hex.
+    pub hex: String,
+}
+
+/// A decoded transaction output, part of [`DecodeRawTransaction`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RawTransactionOutput {
+    /// The value in BTC.
+    pub value: f64,
+    /// Index.
+    pub n: u32,
+    /// The script pubkey.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: ScriptPubkey,
+}
+
+impl DecodeRawTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeRawTransaction, DecodeRawTransactionError> {
+        Ok(model::DecodeRawTransaction(self.transaction()?))
+    }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, DecodeRawTransactionError> {
+        use DecodeRawTransactionError as E;
+
+        let input = self
+            .vin
+            .into_iter()
+            .map(|input| input.into_tx_in())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Inputs)?;
+        let output = self
+            .vout
+            .into_iter()
+            .map(|output| output.into_tx_out())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Outputs)?;
+
+        Ok(Transaction {
+            version: transaction::Version(self.version),
+            lock_time: absolute::LockTime::from_consensus(self.lock_time),
+            input,
+            output,
+        })
+    }
+}
+
+impl RawTransactionInput {
+    /// Converts the decoded input into a `bitcoin::TxIn`.
+    pub fn into_tx_in(self) -> Result<TxIn, RawTransactionInputError> {
+        use RawTransactionInputError as E;
+
+        let (previous_output, script_sig) = match self.coinbase {
+            Some(coinbase) =>
+                (OutPoint::null(), ScriptBuf::from_hex(&coinbase).map_err(E::Coinbase)?),
+            None => {
+                let txid = self.txid.ok_or(E::MissingTxid)?.parse::<Txid>().map_err(E::Txid)?;
+                let vout = self.vout.ok_or(E::MissingVout)?;
+                let script_sig = match self.script_sig {
+                    Some(script_sig) =>
+                        ScriptBuf::from_hex(&script_sig.hex).map_err(E::ScriptSig)?,
+                    None => ScriptBuf::new(),
+                };
+                (OutPoint { txid, vout }, script_sig)
+            }
+        };
+
+        let witness = match self.witness {
+            Some(witness) => {
+                let elements = witness
+                    .iter()
+                    .map(|element| hex::FromHex::from_hex(element))
+                    .collect::<Result<Vec<Vec<u8>>, _>>()
+                    .map_err(E::Witness)?;
+                Witness::from_slice(&elements)
+            }
+            None => Witness::new(),
+        };
+
+        Ok(TxIn { previous_output, script_sig, sequence: Sequence(self.sequence), witness })
+    }
+}
+
+impl RawTransactionOutput {
+    /// Converts the decoded output into a `bitcoin::TxOut`.
+    pub fn into_tx_out(self) -> Result<TxOut, RawTransactionOutputError> {
+        use RawTransactionOutputError as E;
+
+        let value = Amount::from_btc(self.value).map_err(E::Value)?;
+        let script_pubkey =
+            ScriptBuf::from_hex(&self.script_pubkey.hex).map_err(E::ScriptPubkey)?;
+
+        Ok(TxOut { value, script_pubkey })
+    }
+}
+
+/// Error when converting a `DecodeRawTransaction` type into the model type.
+#[derive(Debug)]
+pub enum DecodeRawTransactionError {
+    /// Conversion of one of the transaction inputs failed.
+    Inputs(RawTransactionInputError),
+    /// Conversion of one of the transaction outputs failed.
+    Outputs(RawTransactionOutputError),
+}
+
+impl fmt::Display for DecodeRawTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DecodeRawTransactionError::*;
+
+        match *self {
+            Inputs(ref e) => write_err!(f, "conversion of one of the transaction inputs failed"; e),
+            Outputs(ref e) =>
+                write_err!(f, "conversion of one of the transaction outputs failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for DecodeRawTransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DecodeRawTransactionError::*;
+
+        match *self {
+            Inputs(ref e) => Some(e),
+            Outputs(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `RawTransactionInput` type into a `TxIn`.
+#[derive(Debug)]
+pub enum RawTransactionInputError {
+    /// Input is not a coinbase input and is missing the `txid` field.
+    MissingTxid,
+    /// Input is not a coinbase input and is missing the `vout` field.
+    MissingVout,
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `script_sig` field failed.
+    ScriptSig(hex::HexToBytesError),
+    /// Conversion of the `coinbase` field failed.
+    Coinbase(hex::HexToBytesError),
+    /// Conversion of one of the `witness` elements failed.
+    Witness(hex::HexToBytesError),
+}
+
+impl fmt::Display for RawTransactionInputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use RawTransactionInputError::*;
+
+        match *self {
+            MissingTxid => write!(f, "non-coinbase input is missing the `txid` field"),
+            MissingVout => write!(f, "non-coinbase input is missing the `vout` field"),
+            Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            ScriptSig(ref e) => write_err!(f, "conversion of the `script_sig` field failed"; e),
+            Coinbase(ref e) => write_err!(f, "conversion of the `coinbase` field failed"; e),
+            Witness(ref e) => write_err!(f, "conversion of the `witness` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for RawTransactionInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use RawTransactionInputError::*;
+
+        match *self {
+            Txid(ref e) => Some(e),
+            ScriptSig(ref e) => Some(e),
+            Coinbase(ref e) => Some(e),
+            Witness(ref e) => Some(e),
+            MissingTxid | MissingVout => None,
+        }
+    }
+}
+
+/// Error when converting a `RawTransactionOutput` type into a `TxOut`.
+#[derive(Debug)]
+pub enum RawTransactionOutputError {
+    /// Conversion of the `value` field failed.
+    Value(amount::ParseAmountError),
+    /// Conversion of the `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+}
+
+impl fmt::Display for RawTransactionOutputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use RawTransactionOutputError::*;
+
+        match *self {
+            Value(ref e) => write_err!(f, "conversion of the `value` field failed"; e),
+            ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for RawTransactionOutputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use RawTransactionOutputError::*;
+
+        match *self {
+            Value(ref e) => Some(e),
+            ScriptPubkey(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `combinepsbt`.
+///
+/// > combinepsbt ["psbt",...]
+/// >
+/// > Combine multiple partially signed Bitcoin transactions into one transaction.
+/// > Implements the Combiner role.
+/// >
+/// > Arguments:
+/// > 1. "txs"                   (string) A json array of base64 strings of partially signed transactions
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CombinePsbt(pub String); // The base64-encoded PSBT.
+
+impl CombinePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::CombinePsbt, PsbtParseError> {
+        let psbt = self.0.parse::<Psbt>()?;
+        Ok(model::CombinePsbt(psbt))
+    }
+}
+
+/// Result of JSON-RPC method `finalizepsbt`.
+///
+/// > finalizepsbt "psbt" ( extract )
+/// >
+/// > Finalize the inputs of a PSBT. If the transaction is fully signed, it will produce a
+/// > network serialized transaction which can be broadcast with sendrawtransaction. Otherwise a PSBT will be
+/// > created which has the final_scriptSig and final_scriptWitness fields filled for inputs that are complete.
+/// > Implements the Finalizer and Extractor roles.
+/// >
+/// > Arguments:
+/// > 1. "psbt"                 (string) A base64 string of a PSBT
+/// > 2. "extract"              (boolean, optional, default=true) If true and the transaction is complete,
+/// >                              extract and return the complete transaction in normal network serialization instead of the PSBT.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FinalizePsbt {
+    /// The base64-encoded partially signed transaction if not extracted.
+    pub psbt: Option<String>,
+    /// The hex-encoded network transaction if extracted.
+    pub hex: Option<String>,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+}
+
+impl FinalizePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::FinalizePsbt, FinalizePsbtError> {
+        use FinalizePsbtError as E;
+
+        let psbt = self.psbt.map(|psbt| psbt.parse::<Psbt>()).transpose().map_err(E::Psbt)?;
+        let tx = self
+            .hex
+            .map(|hex| encode::deserialize_hex::<Transaction>(&hex))
+            .transpose()
+            .map_err(E::Hex)?;
+
+        Ok(model::FinalizePsbt { psbt, tx, complete: self.complete })
+    }
+}
+
+/// Error when converting a `FinalizePsbt` type into the model type.
+#[derive(Debug)]
+pub enum FinalizePsbtError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the `hex` field failed.
+    Hex(encode::FromHexError),
+}
+
+impl fmt::Display for FinalizePsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use FinalizePsbtError::*;
+
+        match *self {
+            Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for FinalizePsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use FinalizePsbtError::*;
+
+        match *self {
+            Psbt(ref e) => Some(e),
+            Hex(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of JSON-RPC method `decodepsbt`.
+///
+/// > decodepsbt "psbt"
+/// >
+/// > Return a JSON object representing the serialized, base64-encoded partially signed Bitcoin transaction.
+/// >
+/// > Arguments:
+/// > 1. "psbt"            (string, required) The PSBT base64 string
+///
+/// Only the fields common to all supported versions of Core are typed, fields added later (e.g.
+/// taproot fields) are ignored.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodePsbt {
+    /// The decoded network-serialized unsigned transaction.
+    pub tx: DecodeRawTransaction,
+    /// The unknown global fields, hex key to hex value.
+    pub unknown: BTreeMap<String, String>,
+    /// The PSBT inputs.
+    pub inputs: Vec<PsbtInput>,
+    /// The PSBT outputs.
+    pub outputs: Vec<PsbtOutput>,
+    /// The transaction fee paid if all UTXOs slots in the PSBT have been filled.
+    #[serde(default, deserialize_with = "crate::deserialize_optional_number_or_string")]
+    pub fee: Option<f64>,
+}
+
+/// An input of a PSBT, part of [`DecodePsbt`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtInput {
+    /// Decoded network transaction for non-witness UTXOs.
+    pub non_witness_utxo: Option<DecodeRawTransaction>,
+    /// Transaction output for witness UTXOs.
+    pub witness_utxo: Option<PsbtWitnessUtxo>,
+    /// The partial signatures, hex public key to hex signature.
+    pub partial_signatures: Option<BTreeMap<String, String>>,
+    /// The sighash type to be used e.g., "ALL".
+    pub sighash: Option<String>,
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The BIP-32 derivation paths of the public keys.
+    pub bip32_derivs: Option<Bip32Derivs>,
+    /// The final script signature.
+    #[serde(rename = "final_scriptSig")]
+    pub final_script_sig: Option<ScriptSig>,
+    /// The final script witness, hex-encoded stack items.
+    #[serde(rename = "final_scriptwitness")]
+    pub final_script_witness: Option<Vec<String>>,
+    /// The unknown input fields, hex key to hex value.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+/// An output of a PSBT, part of [`DecodePsbt`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtOutput {
+    /// The redeem script.
+    pub redeem_script: Option<PsbtScript>,
+    /// The witness script.
+    pub witness_script: Option<PsbtScript>,
+    /// The BIP-32 derivation paths of the public keys.
+    pub bip32_derivs: Option<Bip32Derivs>,
+    /// The unknown output fields, hex key to hex value.
+    pub unknown: Option<BTreeMap<String, String>>,
+}
+
+/// A witness UTXO, part of [`PsbtInput`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtWitnessUtxo {
+    /// The value in BTC.
+    pub amount: f64,
+    /// The script pubkey.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: ScriptPubkey,
+}
+
+/// A script, part of [`PsbtInput`] and [`PsbtOutput`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PsbtScript {
+    /// Script assembly.
+    pub asm: String,
+    /// Script hex.
+    pub hex: String,
+    /// The type, eg pubkeyhash.
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+}
+
+/// The BIP-32 derivation paths of a PSBT input or output.
+///
+/// Core v0.17 returns an object keyed by public key, later versions return an array.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Bip32Derivs {
+    /// Derivation paths keyed by hex public key (v0.17).
+    Map(BTreeMap<String, Bip32DerivOrigin>),
+    /// Derivation paths including the public key (v0.18 onwards).
+    List(Vec<Bip32Deriv>),
+}
+
+/// A BIP-32 derivation path, including the public key, part of [`Bip32Derivs`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Bip32Deriv {
+    /// The public key with the derivation path as the value.
+    pub pubkey: String,
+    /// The fingerprint of the master key.
+    pub master_fingerprint: String,
+    /// The path.
+    pub path: String,
+}
+
+/// A BIP-32 key origin, part of [`Bip32Derivs`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Bip32DerivOrigin {
+    /// The fingerprint of the master key.
+    pub master_fingerprint: String,
+    /// The path.
+    pub path: String,
+}
+
+impl DecodePsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    ///
+    /// Unknown fields are not included in the model's `psbt`.
+    pub fn into_model(self) -> Result<model::DecodePsbt, DecodePsbtError> {
+        use DecodePsbtError as E;
+
+        let tx = self.tx.transaction().map_err(E::Tx)?;
+        let mut psbt = Psbt::from_unsigned_tx(tx).map_err(E::Psbt)?;
+        if psbt.inputs.len() != self.inputs.len() || psbt.outputs.len() != self.outputs.len() {
+            return Err(E::Length);
+        }
+
+        for (input, json) in psbt.inputs.iter_mut().zip(self.inputs) {
+            *input = json.into_input().map_err(E::Inputs)?;
+        }
+        for (output, json) in psbt.outputs.iter_mut().zip(self.outputs) {
+            *output = json.into_output().map_err(E::Outputs)?;
+        }
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        Ok(model::DecodePsbt { psbt, fee })
+    }
+}
+
+impl PsbtInput {
+    /// Converts the decoded input into a `bitcoin::psbt::Input`.
+    pub fn into_input(self) -> Result<psbt::Input, PsbtInputError> {
+        use PsbtInputError as E;
+
+        let non_witness_utxo = self
+            .non_witness_utxo
+            .map(|tx| tx.transaction())
+            .transpose()
+            .map_err(E::NonWitnessUtxo)?;
+        let witness_utxo = match self.witness_utxo {
+            Some(utxo) => {
+                let value = Amount::from_btc(utxo.amount).map_err(E::WitnessUtxoAmount)?;
+                let script_pubkey =
+                    ScriptBuf::from_hex(&utxo.script_pubkey.hex).map_err(E::WitnessUtxoScript)?;
+                Some(TxOut { value, script_pubkey })
+            }
+            None => None,
+        };
+        let partial_sigs = self
+            .partial_signatures
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pubkey, sig)| {
+                let pubkey = pubkey.parse::<PublicKey>().map_err(E::PartialSignatureKey)?;
+                let sig = sig.parse::<ecdsa::Signature>().map_err(E::PartialSignature)?;
+                Ok((pubkey, sig))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+        let sighash_type =
+            self.sighash.map(|s| parse_sighash(&s)).transpose().map_err(E::Sighash)?;
+        let redeem_script =
+            self.redeem_script.map(|s| s.script()).transpose().map_err(E::RedeemScript)?;
+        let witness_script =
+            self.witness_script.map(|s| s.script()).transpose().map_err(E::WitnessScript)?;
+        let bip32_derivation = match self.bip32_derivs {
+            Some(derivs) => derivs.into_map().map_err(E::Bip32Derivs)?,
+            None => BTreeMap::new(),
+        };
+        let final_script_sig = self
+            .final_script_sig
+            .map(|s| ScriptBuf::from_hex(&s.hex))
+            .transpose()
+            .map_err(E::FinalScriptSig)?;
+        let final_script_witness = match self.final_script_witness {
+            Some(witness) => {
+                let elements = witness
+                    .iter()
+                    .map(|element| hex::FromHex::from_hex(element))
+                    .collect::<Result<Vec<Vec<u8>>, _>>()
+                    .map_err(E::FinalScriptWitness)?;
+                Some(Witness::from_slice(&elements))
+            }
+            None => None,
+        };
+
+        Ok(psbt::Input {
+            non_witness_utxo,
+            witness_utxo,
+            partial_sigs,
+            sighash_type,
+            redeem_script,
+            witness_script,
+            bip32_derivation,
+            final_script_sig,
+            final_script_witness,
+            ..Default::default()
+        })
+    }
+}
+
+impl PsbtOutput {
+    /// Converts the decoded output into a `bitcoin::psbt::Output`.
+    pub fn into_output(self) -> Result<psbt::Output, PsbtOutputError> {
+        use PsbtOutputError as E;
+
+        let redeem_script =
+            self.redeem_script.map(|s| s.script()).transpose().map_err(E::RedeemScript)?;
+        let witness_script =
+            self.witness_script.map(|s| s.script()).transpose().map_err(E::WitnessScript)?;
+        let bip32_derivation = match self.bip32_derivs {
+            Some(derivs) => derivs.into_map().map_err(E::Bip32Derivs)?,
+            None => BTreeMap::new(),
+        };
+
+        Ok(psbt::Output { redeem_script, witness_script, bip32_derivation, ..Default::default() })
+    }
+}
+
+impl PsbtScript {
+    /// Converts the script hex into a `bitcoin::ScriptBuf`.
+    pub fn script(&self) -> Result<ScriptBuf, hex::HexToBytesError> {
+        ScriptBuf::from_hex(&self.hex)
+    }
+}
+
+impl Bip32Derivs {
+    /// Converts the derivation paths into a map as used by `bitcoin::psbt`.
+    pub fn into_map(self) -> Result<BTreeMap<secp256k1::PublicKey, KeySource>, Bip32DerivsError> {
+        let derivs = match self {
+            Bip32Derivs::Map(map) => map
+                .into_iter()
+                .map(|(pubkey, origin)| (pubkey, origin.master_fingerprint, origin.path))
+                .collect::<Vec<_>>(),
+            Bip32Derivs::List(list) =>
+                list.into_iter().map(|d| (d.pubkey, d.master_fingerprint, d.path)).collect(),
+        };
+
+        derivs
+            .into_iter()
+            .map(|(pubkey, fingerprint, path)| {
+                use Bip32DerivsError as E;
+
+                let pubkey = pubkey.parse::<secp256k1::PublicKey>().map_err(E::Pubkey)?;
+                let fingerprint = fingerprint.parse::<Fingerprint>().map_err(E::Fingerprint)?;
+                let path = path.parse::<DerivationPath>().map_err(E::Path)?;
+                Ok((pubkey, (fingerprint, path)))
+            })
+            .collect()
+    }
+}
+
+/// Parses a sighash type as formatted by Core e.g., "ALL|ANYONECANPAY".
+fn parse_sighash(s: &str) -> Result<PsbtSighashType, SighashTypeParseError> {
+    // Core omits the `SIGHASH_` prefix `rust-bitcoin` expects.
+    let prefixed = s.split('|').map(|part| format!("SIGHASH_{}", part)).collect::<Vec<_>>();
+    PsbtSighashType::from_str(&prefixed.join("|"))
+}
+
+/// Error when converting a `DecodePsbt` type into the model type.
+#[derive(Debug)]
+pub enum DecodePsbtError {
+    /// Conversion of the transaction `tx` field failed.
+    Tx(DecodeRawTransactionError),
+    /// The unsigned transaction is not valid for a PSBT.
+    Psbt(psbt::Error),
+    /// The number of inputs or outputs does not match the transaction.
+    Length,
+    /// Conversion of one of the PSBT inputs failed.
+    Inputs(PsbtInputError),
+    /// Conversion of one of the PSBT outputs failed.
+    Outputs(PsbtOutputError),
+    /// Conversion of the `fee` field failed.
+    Fee(amount::ParseAmountError),
+}
+
+impl fmt::Display for DecodePsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DecodePsbtError::*;
+
+        match *self {
+            Tx(ref e) => write_err!(f, "conversion of the `tx` field failed"; e),
+            Psbt(ref e) => write_err!(f, "the unsigned transaction is not valid for a PSBT"; e),
+            Length => write!(f, "the number of inputs or outputs does not match the transaction"),
+            Inputs(ref e) => write_err!(f, "conversion of one of the PSBT inputs failed"; e),
+            Outputs(ref e) => write_err!(f, "conversion of one of the PSBT outputs failed"; e),
+            Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for DecodePsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DecodePsbtError::*;
+
+        match *self {
+            Tx(ref e) => Some(e),
+            Psbt(ref e) => Some(e),
+            Length => None,
+            Inputs(ref e) => Some(e),
+            Outputs(ref e) => Some(e),
+            Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `PsbtInput` type into a `bitcoin::psbt::Input`.
+#[derive(Debug)]
+pub enum PsbtInputError {
+    /// Conversion of the `non_witness_utxo` field failed.
+    NonWitnessUtxo(DecodeRawTransactionError),
+    /// Conversion of the `witness_utxo` amount failed.
+    WitnessUtxoAmount(amount::ParseAmountError),
+    /// Conversion of the `witness_utxo` script pubkey failed.
+    WitnessUtxoScript(hex::HexToBytesError),
+    /// Conversion of one of the `partial_signatures` public keys failed.
+    PartialSignatureKey(key::ParsePublicKeyError),
+    /// Conversion of one of the `partial_signatures` signatures failed.
+    PartialSignature(ecdsa::Error),
+    /// Conversion of the `sighash` field failed.
+    Sighash(SighashTypeParseError),
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+    /// Conversion of the `bip32_derivs` field failed.
+    Bip32Derivs(Bip32DerivsError),
+    /// Conversion of the `final_script_sig` field failed.
+    FinalScriptSig(hex::HexToBytesError),
+    /// Conversion of the `final_script_witness` field failed.
+    FinalScriptWitness(hex::HexToBytesError),
+}
+
+impl fmt::Display for PsbtInputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtInputError::*;
+
+        match *self {
+            NonWitnessUtxo(ref e) =>
+                write_err!(f, "conversion of the `non_witness_utxo` field failed"; e),
+            WitnessUtxoAmount(ref e) =>
+                write_err!(f, "conversion of the `witness_utxo` amount failed"; e),
+            WitnessUtxoScript(ref e) =>
+                write_err!(f, "conversion of the `witness_utxo` script pubkey failed"; e),
+            PartialSignatureKey(ref e) =>
+                write_err!(f, "conversion of a `partial_signatures` public key failed"; e),
+            PartialSignature(ref e) =>
+                write_err!(f, "conversion of a `partial_signatures` signature failed"; e),
+            Sighash(ref e) => write_err!(f, "conversion of the `sighash` field failed"; e),
+            RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+            Bip32Derivs(ref e) => write_err!(f, "conversion of the `bip32_derivs` field failed"; e),
+            FinalScriptSig(ref e) =>
+                write_err!(f, "conversion of the `final_script_sig` field failed"; e),
+            FinalScriptWitness(ref e) =>
+                write_err!(f, "conversion of the `final_script_witness` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtInputError::*;
+
+        match *self {
+            NonWitnessUtxo(ref e) => Some(e),
+            WitnessUtxoAmount(ref e) => Some(e),
+            WitnessUtxoScript(ref e) => Some(e),
+            PartialSignatureKey(ref e) => Some(e),
+            PartialSignature(ref e) => Some(e),
+            Sighash(ref e) => Some(e),
+            RedeemScript(ref e) => Some(e),
+            WitnessScript(ref e) => Some(e),
+            Bip32Derivs(ref e) => Some(e),
+            FinalScriptSig(ref e) => Some(e),
+            FinalScriptWitness(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `PsbtOutput` type into a `bitcoin::psbt::Output`.
+#[derive(Debug)]
+pub enum PsbtOutputError {
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+    /// Conversion of the `bip32_derivs` field failed.
+    Bip32Derivs(Bip32DerivsError),
+}
+
+impl fmt::Display for PsbtOutputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtOutputError::*;
+
+        match *self {
+            RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+            Bip32Derivs(ref e) => write_err!(f, "conversion of the `bip32_derivs` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtOutputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtOutputError::*;
+
+        match *self {
+            RedeemScript(ref e) => Some(e),
+            WitnessScript(ref e) => Some(e),
+            Bip32Derivs(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `Bip32Derivs` type into a map of key sources.
+#[derive(Debug)]
+pub enum Bip32DerivsError {
+    /// Conversion of a public key failed.
+    Pubkey(secp256k1::Error),
+    /// Conversion of a master key fingerprint failed.
+    Fingerprint(hex::HexToArrayError),
+    /// Conversion of a derivation path failed.
+    Path(bip32::Error),
+}
+
+impl fmt::Display for Bip32DerivsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Bip32DerivsError::*;
+
+        match *self {
+            Pubkey(ref e) => write_err!(f, "conversion of a public key failed"; e),
+            Fingerprint(ref e) => write_err!(f, "conversion of a master key fingerprint failed"; e),
+            Path(ref e) => write_err!(f, "conversion of a derivation path failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for Bip32DerivsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use Bip32DerivsError::*;
+
+        match *self {
+            Pubkey(ref e) => Some(e),
+            Fingerprint(ref e) => Some(e),
+            Path(ref e) => Some(e),
+        }
+    }
+}
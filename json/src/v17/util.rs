@@ -3,3 +3,47 @@
 //! The JSON-RPC API for Bitcoin Core v0.17.1 - util.
 //!
 //! Types for methods found under the `== Util ==` section of the API docs.
+
+use bitcoin::amount;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `estimatesmartfee`.
+///
+/// > estimatesmartfee conf_target ("estimate_mode")
+/// >
+/// > Estimates the approximate fee per kilobyte needed for a transaction to begin
+/// > confirmation within conf_target blocks if possible and return the number of blocks
+/// > for which the estimate is valid. Uses virtual transaction size as defined
+/// > in BIP 141 (witness data is discounted).
+/// >
+/// > Arguments:
+/// > 1. conf_target     (numeric) Confirmation target in blocks (1 - 1008)
+/// > 2. "estimate_mode" (string, optional, default=CONSERVATIVE) The fee estimate mode.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EstimateSmartFee {
+    /// Estimate fee rate in BTC/kvB, not present if no estimate could be made.
+    #[serde(rename = "feerate")]
+    pub fee_rate: Option<f64>,
+    /// Errors encountered during processing.
+    pub errors: Option<Vec<String>>,
+    /// Block number where estimate was found.
+    ///
+    /// The request target will be clamped between 2 and the highest target fee estimation is able
+    /// to return based on how long it has been running. An error is returned if not enough
+    /// transactions and blocks have been observed to make an estimate for any number of blocks.
+    pub blocks: u32,
+}
+
+impl EstimateSmartFee {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::EstimateSmartFee, amount::ParseAmountError> {
+        let fee_rate = self.fee_rate.map(crate::fee_rate_from_btc_per_kvb).transpose()?;
+        Ok(model::EstimateSmartFee {
+            fee_rate,
+            errors: self.errors.unwrap_or_default(),
+            blocks: self.blocks,
+        })
+    }
+}
@@ -4,18 +4,177 @@
 //!
 //! Types for methods found under the `== Wallet ==` section of the API docs.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 
 use bitcoin::address::NetworkUnchecked;
 use bitcoin::amount::ParseAmountError;
 use bitcoin::consensus::encode;
-use bitcoin::{address, hex, Address, Amount, SignedAmount, Transaction, Txid};
+use bitcoin::hashes::hash160;
+use bitcoin::hex::FromHex;
+use bitcoin::psbt::PsbtParseError;
+use bitcoin::sign_message::{MessageSignature, MessageSignatureError};
+use bitcoin::witness_version::{self, WitnessVersion};
+use bitcoin::{
+    address, hex, Address, Amount, BlockHash, OutPoint, Psbt, ScriptBuf, SignedAmount, Transaction,
+    Txid,
+};
 use internals::write_err;
 use serde::{Deserialize, Serialize};
 
 use crate::model;
 
+/// Result of the JSON-RPC method `addmultisigaddress`.
+///
+/// > addmultisigaddress nrequired ["key",...] ( "label" "address_type" )
+/// >
+/// > Add a nrequired-to-sign multisignature address to the wallet. Requires a new wallet backup.
+/// > Each key is a Bitcoin address or hex-encoded public key.
+/// > This functionality is only intended for use with non-watchonly addresses.
+/// > See `importaddress` for watchonly p2sh address support.
+/// > If 'label' is specified, assign address to that label.
+/// >
+/// > Arguments:
+/// > 1. nrequired                      (numeric, required) The number of required signatures out of the n keys or addresses.
+/// > 2. "keys"                         (string, required) A json array of bitcoin addresses or hex-encoded public keys
+/// > 3. "label"                        (string, optional) A label to assign the addresses to.
+/// > 4. "address_type"                 (string, optional) The address type to use. Options are "legacy", "p2sh-segwit", and "bech32". Default is set by -addresstype.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddMultisigAddress {
+    /// The value of the new multisig address.
+    pub address: String,
+    /// The string value of the hex-encoded redemption script.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: String,
+}
+
+impl AddMultisigAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::AddMultisigAddress, AddMultisigAddressError> {
+        use AddMultisigAddressError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let redeem_script = ScriptBuf::from_hex(&self.redeem_script).map_err(E::RedeemScript)?;
+
+        Ok(model::AddMultisigAddress { address, redeem_script, descriptor: None })
+    }
+}
+
+/// Error when converting a `AddMultisigAddress` type into the model type.
+#[derive(Debug)]
+pub enum AddMultisigAddressError {
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+}
+
+impl fmt::Display for AddMultisigAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use AddMultisigAddressError as E;
+
+        match *self {
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for AddMultisigAddressError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use AddMultisigAddressError as E;
+
+        match *self {
+            E::Address(ref e) => Some(e),
+            E::RedeemScript(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `bumpfee`.
+///
+/// > bumpfee "txid" ( options )
+/// >
+/// > Bumps the fee of an opt-in-RBF transaction T, replacing it with a new transaction B.
+/// > An opt-in RBF transaction with the given txid must be in the wallet.
+/// > The command will pay the additional fee by decreasing (or perhaps removing) its change output.
+/// > If the change output is not big enough to cover the increased fee, the command will currently fail
+/// > instead of adding new inputs to compensate. (A future implementation could improve this.)
+/// > The command will fail if the wallet or mempool contains a transaction that spends one of T's outputs.
+/// > By default, the new fee will be calculated automatically using estimatesmartfee.
+/// > The user can specify a confirmation target for estimatesmartfee.
+/// > Alternatively, the user can specify totalFee, or use RPC settxfee to set a higher fee rate.
+/// > At a minimum, the new fee rate must be high enough to pay an additional new relay fee (incrementalfee
+/// > returned by getnetworkinfo) to enter the node's mempool.
+/// >
+/// > Arguments:
+/// > 1. txid                           (string, required) The txid to be bumped
+/// > 2. options                        (json object, optional)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct BumpFee {
+    /// The id of the new transaction.
+    pub txid: String,
+    /// Fee of the replaced transaction.
+    #[serde(rename = "origfee")]
+    pub original_fee: f64,
+    /// Fee of the new transaction.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub fee: f64,
+    /// Errors encountered during processing (may be empty).
+    pub errors: Vec<String>,
+}
+
+impl BumpFee {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::BumpFee, BumpFeeError> {
+        use BumpFeeError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let original_fee = Amount::from_btc(self.original_fee).map_err(E::OriginalFee)?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+
+        Ok(model::BumpFee { txid, original_fee, fee, errors: self.errors })
+    }
+}
+
+/// Error when converting a `BumpFee` type into the model type.
+#[derive(Debug)]
+pub enum BumpFeeError {
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `original_fee` field failed.
+    OriginalFee(ParseAmountError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+}
+
+impl fmt::Display for BumpFeeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use BumpFeeError as E;
+
+        match *self {
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::OriginalFee(ref e) =>
+                write_err!(f, "conversion of the `original_fee` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for BumpFeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use BumpFeeError as E;
+
+        match *self {
+            E::Txid(ref e) => Some(e),
+            E::OriginalFee(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
 /// Result of the JSON-RPC method `createwallet`.
 ///
 /// > createwallet "wallet_name" ( disable_private_keys )
@@ -73,6 +232,231 @@ impl LoadWallet {
     pub fn name(self) -> String { self.into_model().name }
 }
 
+/// Result of the JSON-RPC method `getaddressinfo`.
+///
+/// > getaddressinfo "address"
+/// >
+/// > Return information about the given bitcoin address. Some information requires the address
+/// > to be in the wallet.
+/// >
+/// > Arguments:
+/// > 1. "address"                    (string, required) The bitcoin address to get the information of.
+///
+/// Only the fields returned by all supported versions of Core are included, some of them are only
+/// returned if the address is in the wallet.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetAddressInfo {
+    /// The bitcoin address validated.
+    pub address: String,
+    /// The hex encoded scriptPubKey generated by the address.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// If the address is yours or not.
+    #[serde(rename = "ismine")]
+    pub is_mine: bool,
+    /// If the address is watchonly.
+    #[serde(rename = "iswatchonly")]
+    pub is_watch_only: bool,
+    /// If the key is a script.
+    #[serde(rename = "isscript")]
+    pub is_script: bool,
+    /// If the address is a witness address.
+    #[serde(rename = "iswitness")]
+    pub is_witness: bool,
+    /// The version number of the witness program.
+    pub witness_version: Option<u8>,
+    /// The hex value of the witness program.
+    pub witness_program: Option<String>,
+    /// The output script type.
+    ///
+    /// Only if "isscript" is true and the redeemscript is known. Possible types: nonstandard,
+    /// pubkey, pubkeyhash, scripthash, multisig, nulldata, witness_v0_keyhash,
+    /// witness_v0_scripthash, witness_unknown.
+    pub script: Option<String>,
+    /// Information about the address embedded in P2SH or P2WSH, if relevant and known.
+    pub embedded: Option<Box<GetAddressInfo>>,
+    /// If the address was used for change output.
+    #[serde(rename = "ischange")]
+    pub is_change: Option<bool>,
+    /// The creation time of the key if available in seconds since epoch (Jan 1 1970 GMT).
+    pub timestamp: Option<u32>,
+    /// The HD keypath if the key is HD and available.
+    #[serde(rename = "hdkeypath")]
+    pub hd_key_path: Option<String>,
+}
+
+impl GetAddressInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetAddressInfo, GetAddressInfoError> {
+        use GetAddressInfoError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let witness_version = self
+            .witness_version
+            .map(|v| {
+                WitnessVersion::try_from(v).map(WitnessVersion::to_num).map_err(E::WitnessVersion)
+            })
+            .transpose()?;
+        let witness_program = self
+            .witness_program
+            .map(|p| Vec::<u8>::from_hex(&p).map_err(E::WitnessProgram))
+            .transpose()?;
+        let embedded = self
+            .embedded
+            .map(|embedded| {
+                embedded.into_model().map(Box::new).map_err(|e| E::Embedded(Box::new(e)))
+            })
+            .transpose()?;
+
+        Ok(model::GetAddressInfo {
+            address,
+            script_pubkey,
+            is_mine: self.is_mine,
+            is_watch_only: self.is_watch_only,
+            is_script: self.is_script,
+            is_witness: self.is_witness,
+            witness_version,
+            witness_program,
+            script: self.script,
+            embedded,
+            is_change: self.is_change,
+            timestamp: self.timestamp,
+            hd_key_path: self.hd_key_path,
+        })
+    }
+}
+
+/// Error when converting a `GetAddressInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetAddressInfoError {
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `witness_version` field failed.
+    WitnessVersion(witness_version::TryFromError),
+    /// Conversion of the `witness_program` field failed.
+    WitnessProgram(hex::HexToBytesError),
+    /// Conversion of the `embedded` field failed.
+    Embedded(Box<GetAddressInfoError>),
+}
+
+impl fmt::Display for GetAddressInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetAddressInfoError as E;
+
+        match *self {
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+            E::WitnessVersion(ref e) =>
+                write_err!(f, "conversion of the `witness_version` field failed"; e),
+            E::WitnessProgram(ref e) =>
+                write_err!(f, "conversion of the `witness_program` field failed"; e),
+            E::Embedded(ref e) => write_err!(f, "conversion of the `embedded` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetAddressInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetAddressInfoError as E;
+
+        match *self {
+            E::Address(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::WitnessVersion(ref e) => Some(e),
+            E::WitnessProgram(ref e) => Some(e),
+            E::Embedded(ref e) => Some(&**e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `getaddressesbylabel`.
+///
+/// > getaddressesbylabel "label"
+/// >
+/// > Returns the list of addresses assigned the specified label.
+/// >
+/// > Arguments:
+/// > 1. "label"  (string, required) The label.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetAddressesByLabel(pub BTreeMap<String, AddressInformation>);
+
+impl GetAddressesByLabel {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetAddressesByLabel, address::ParseError> {
+        let map = self
+            .0
+            .into_iter()
+            .map(|(address, info)| {
+                let address = Address::from_str(&address)?;
+                Ok((address, info.into_model()))
+            })
+            .collect::<Result<_, address::ParseError>>()?;
+        Ok(model::GetAddressesByLabel(map))
+    }
+}
+
+/// Information about an address, part of the result of `getaddressesbylabel`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddressInformation {
+    /// Purpose of address.
+    pub purpose: AddressPurpose,
+}
+
+impl AddressInformation {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::AddressInformation {
+        model::AddressInformation { purpose: self.purpose.into_model() }
+    }
+}
+
+/// The purpose of an address, part of the result of `getaddressesbylabel`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressPurpose {
+    /// A send-to address.
+    Send,
+    /// A receiving address.
+    Receive,
+    /// A purpose not known to this version of the library.
+    #[serde(other)]
+    Unknown,
+}
+
+impl AddressPurpose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::AddressPurpose {
+        match self {
+            AddressPurpose::Send => model::AddressPurpose::Send,
+            AddressPurpose::Receive => model::AddressPurpose::Receive,
+            AddressPurpose::Unknown => model::AddressPurpose::Unknown,
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `getreceivedbyaddress`.
+///
+/// > getreceivedbyaddress "address" ( minconf )
+/// >
+/// > Returns the total amount received by the given address in transactions with at least minconf confirmations.
+/// >
+/// > Arguments:
+/// > 1. "address"         (string, required) The bitcoin address for transactions.
+/// > 2. minconf             (numeric, optional, default=1) Only include transactions confirmed at least this many times.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetReceivedByAddress(pub f64);
+
+impl GetReceivedByAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetReceivedByAddress, ParseAmountError> {
+        let amount = Amount::from_btc(self.0)?;
+        Ok(model::GetReceivedByAddress(amount))
+    }
+}
+
 /// Result of the JSON-RPC method `getnewaddress`.
 ///
 /// > getnewaddress ( "label" "address_type" )
@@ -101,6 +485,149 @@ impl GetNewAddress {
     }
 }
 
+/// Result of the JSON-RPC method `getrawchangeaddress`.
+///
+/// > getrawchangeaddress ( "address_type" )
+/// >
+/// > Returns a new Bitcoin address, for receiving change.
+/// > This is for use with raw transactions, NOT normal use.
+/// >
+/// > Arguments:
+/// > 1. "address_type"           (string, optional) The address type to use. Options are "legacy", "p2sh-segwit", and "bech32". Default is set by -changetype.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawChangeAddress(pub String);
+
+impl GetRawChangeAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawChangeAddress, address::ParseError> {
+        let address = Address::from_str(&self.0)?;
+        Ok(model::GetRawChangeAddress(address))
+    }
+}
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+///
+/// Only the fields returned by all supported versions of Core are included, the version specific
+/// types of later versions include the fields added since.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: u32,
+    /// The total confirmed balance of the wallet in BTC.
+    pub balance: f64,
+    /// The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: f64,
+    /// The total immature balance of the wallet in BTC.
+    pub immature_balance: f64,
+    /// The total number of transactions in the wallet
+    #[serde(rename = "txcount")]
+    pub tx_count: u32,
+    /// The timestamp (seconds since Unix epoch) of the oldest pre-generated key in the key pool.
+    ///
+    /// Not returned by descriptor wallets.
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: Option<u32>,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: u32,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    #[serde(rename = "keypoolsize_hd_internal")]
+    pub keypool_size_hd_internal: Option<u32>,
+    /// The timestamp in seconds since epoch (midnight Jan 1 1970 GMT) that the wallet is unlocked
+    /// for transfers, or 0 if the wallet is locked.
+    pub unlocked_until: Option<u32>,
+    /// The transaction fee configuration, set in BTC/kB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet uses descriptors for scriptPubKey management.
+    ///
+    /// Only returned by v21 onwards.
+    pub descriptors: Option<bool>,
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let balance = Amount::from_btc(self.balance).map_err(E::Balance)?;
+        let unconfirmed_balance = Amount::from_btc(self.unconfirmed_balance).map_err(E::Balance)?;
+        let immature_balance = Amount::from_btc(self.immature_balance).map_err(E::Balance)?;
+        let pay_tx_fee = crate::fee_rate_from_btc_per_kvb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version: self.wallet_version,
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count: self.tx_count,
+            keypool_oldest: self.keypool_oldest,
+            keypool_size: self.keypool_size,
+            keypool_size_hd_internal: self.keypool_size_hd_internal,
+            unlocked_until: self.unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            descriptors: self.descriptors.unwrap_or(false),
+            avoid_reuse: None,
+            scanning: None,
+            external_signer: None,
+        })
+    }
+}
+
+/// Error when converting a `GetWalletInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetWalletInfoError {
+    /// Conversion of one of the balance fields failed.
+    Balance(ParseAmountError),
+    /// Conversion of the `pay_tx_fee` field failed.
+    PayTxFee(ParseAmountError),
+    /// Conversion of the `hd_seed_id` field failed.
+    HdSeedId(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetWalletInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetWalletInfoError as E;
+
+        match *self {
+            E::Balance(ref e) => write_err!(f, "conversion of a balance field failed"; e),
+            E::PayTxFee(ref e) => write_err!(f, "conversion of the `pay_tx_fee` field failed"; e),
+            E::HdSeedId(ref e) => write_err!(f, "conversion of the `hd_seed_id` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetWalletInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetWalletInfoError as E;
+
+        match *self {
+            E::Balance(ref e) => Some(e),
+            E::PayTxFee(ref e) => Some(e),
+            E::HdSeedId(ref e) => Some(e),
+        }
+    }
+}
+
 /// Result of the JSON-RPC method `getbalance`.
 ///
 /// > getbalance ( "(dummy)" minconf include_watchonly )
@@ -157,6 +684,31 @@ impl SendToAddress {
     pub fn txid(self) -> Result<Txid, hex::HexToArrayError> { Ok(self.into_model()?.txid) }
 }
 
+/// Result of the JSON-RPC method `signmessage`.
+///
+/// > signmessage "address" "message"
+/// >
+/// > Sign a message with the private key of an address
+/// >
+/// > Arguments:
+/// > 1. "address"         (string, required) The bitcoin address to use for the private key.
+/// > 2. "message"         (string, required) The message to create a signature of.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SignMessage(pub String);
+
+impl SignMessage {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::SignMessage, MessageSignatureError> {
+        let signature = MessageSignature::from_base64(&self.0)?;
+        Ok(model::SignMessage(signature))
+    }
+
+    /// Converts json straight to a `bitcoin::sign_message::MessageSignature`.
+    pub fn signature(self) -> Result<MessageSignature, MessageSignatureError> {
+        Ok(self.into_model()?.0)
+    }
+}
+
 /// Result of the JSON-RPC method `gettransaction`.
 ///
 /// > gettransaction "txid" ( include_watchonly )
@@ -169,6 +721,7 @@ impl SendToAddress {
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetTransaction {
     pub amount: f64,
+    #[serde(default, deserialize_with = "crate::deserialize_optional_number_or_string")]
     pub fee: Option<f64>,
     pub confirmations: u32,
     // FIXME: The docs say these two fields should be here but it is not returned.
@@ -183,7 +736,7 @@ pub struct GetTransaction {
     #[serde(rename = "timereceived")]
     pub time_received: u64,
     #[serde(rename = "bip125-replaceable")]
-    pub bip125_replaceable: String,
+    pub bip125_replaceable: Bip125Replaceable,
     pub details: Vec<GetTransactionDetail>,
     pub hex: String,
 }
@@ -194,6 +747,7 @@ pub struct GetTransactionDetail {
     pub category: GetTransactionDetailCategory,
     pub amount: f64,
     pub label: Option<String>,
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
     pub vout: u32,
     pub fee: Option<f64>,
     pub abandoned: Option<bool>,
@@ -208,6 +762,25 @@ pub enum GetTransactionDetailCategory {
     Generate,
     Immature,
     Orphan,
+    /// A category not known to this library (deserialization catch-all).
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether a transaction could be replaced due to BIP-125 (replace-by-fee).
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bip125Replaceable {
+    /// Transaction is replaceable.
+    Yes,
+    /// Transaction is not replaceable.
+    No,
+    /// Replaceability is unknown (e.g. an unconfirmed ancestor is not in the mempool).
+    ///
+    /// Also used for any value not known to this library so that a single odd field does not
+    /// cause deserialization of the whole response to fail.
+    #[serde(other)]
+    Unknown,
 }
 
 impl GetTransaction {
@@ -237,7 +810,7 @@ impl GetTransaction {
             txid,
             time: self.time,
             time_received: self.time_received,
-            bip125_replaceable: self.bip125_replaceable,
+            bip125_replaceable: self.bip125_replaceable.into_model(),
             details,
             tx,
         })
@@ -347,6 +920,19 @@ impl std::error::Error for GetTransactionDetailError {
     }
 }
 
+impl Bip125Replaceable {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Bip125Replaceable {
+        use Bip125Replaceable::*;
+
+        match self {
+            Yes => model::Bip125Replaceable::Yes,
+            No => model::Bip125Replaceable::No,
+            Unknown => model::Bip125Replaceable::Unknown,
+        }
+    }
+}
+
 impl GetTransactionDetailCategory {
     /// Converts version specific type to a version in-specific, more strongly typed type.
     pub fn into_model(self) -> model::GetTransactionDetailCategory {
@@ -358,6 +944,848 @@ impl GetTransactionDetailCategory {
             Generate => model::GetTransactionDetailCategory::Generate,
             Immature => model::GetTransactionDetailCategory::Immature,
             Orphan => model::GetTransactionDetailCategory::Orphan,
+            Unknown => model::GetTransactionDetailCategory::Unknown,
         }
     }
 }
+
+/// Result of the JSON-RPC method `listtransactions`.
+///
+/// > listtransactions (label count skip include_watchonly)
+/// >
+/// > If a label name is provided, this will return only incoming transactions paying to addresses with the specified label.
+/// >
+/// > Returns up to 'count' most recent transactions skipping the first 'from' transactions.
+/// >
+/// > Arguments:
+/// > 1. label              (string, optional) If set, should be a valid label name to return only incoming transactions with the specified label, or "*" to disable filtering and return all transactions.
+/// > 2. count              (numeric, optional, default=10) The number of transactions to return
+/// > 3. skip               (numeric, optional, default=0) The number of transactions to skip
+/// > 4. include_watchonly  (bool, optional, default=false) Include transactions to watch-only addresses (see 'importaddress')
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListTransactions(pub Vec<TransactionItem>);
+
+/// Result of the JSON-RPC method `listsinceblock`.
+///
+/// > listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )
+/// >
+/// > Get all transactions in blocks since block [blockhash], or all transactions if omitted.
+/// > If "blockhash" is no longer a part of the main chain, transactions from the fork point onward are included.
+/// > Additionally, if include_removed is set, transactions affecting the wallet which were removed are returned in the "removed" array.
+/// >
+/// > Arguments:
+/// > 1. "blockhash"            (string, optional) The block hash to list transactions since
+/// > 2. target_confirmations:    (numeric, optional, default=1) Return the nth block hash from the main chain. e.g. 1 would mean the best block hash. Note: this is not used as a filter, but only affects [lastblock] in the return value
+/// > 3. include_watchonly:       (bool, optional, default=false) Include transactions to watch-only addresses (see 'importaddress')
+/// > 4. include_removed:         (bool, optional, default=true) Show transactions that were removed due to a reorg in the "removed" array
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListSinceBlock {
+    /// All the transactions.
+    pub transactions: Vec<TransactionItem>,
+    /// Transactions removed due to a reorg (only present if `include_removed` is set).
+    #[serde(default)]
+    pub removed: Vec<TransactionItem>,
+    /// The hash of the block (target_confirmations-1) from the best block on the main chain.
+    #[serde(rename = "lastblock")]
+    pub last_block: String,
+}
+
+/// A wallet transaction entry, part of `listtransactions` and `listsinceblock`.
+///
+/// One entry is returned per wallet-relevant output, so a single transaction may appear more
+/// than once.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TransactionItem {
+    /// Only returned if imported addresses were involved in transaction.
+    #[serde(rename = "involvesWatchonly")]
+    pub involves_watch_only: Option<bool>,
+    /// The bitcoin address of the transaction (not present for some non-standard outputs).
+    pub address: Option<String>,
+    /// The transaction category.
+    pub category: GetTransactionDetailCategory,
+    /// The amount in BTC, negative for the 'send' category.
+    pub amount: f64,
+    /// A comment for the address/transaction, if any.
+    pub label: Option<String>,
+    /// The vout value.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub vout: u32,
+    /// The amount of the fee in BTC, negative and only available for the 'send' category.
+    pub fee: Option<f64>,
+    /// The number of confirmations for the transaction, negative if conflicted.
+    pub confirmations: i64,
+    /// Only present if transaction only input is a coinbase one.
+    pub generated: Option<bool>,
+    /// Only present if we consider transaction to be trusted and so safe to spend from.
+    pub trusted: Option<bool>,
+    /// The block hash containing the transaction.
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The index of the transaction in the block that includes it.
+    #[serde(rename = "blockindex")]
+    pub block_index: Option<u32>,
+    /// The block time in seconds since epoch (1 Jan 1970 GMT).
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<u64>,
+    /// The transaction id.
+    pub txid: String,
+    /// Conflicting transaction ids.
+    #[serde(rename = "walletconflicts")]
+    pub wallet_conflicts: Vec<String>,
+    /// The transaction time in seconds since epoch (Jan 1 1970 GMT).
+    pub time: u64,
+    /// The time received in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "timereceived")]
+    pub time_received: u64,
+    /// If a comment is associated with the transaction.
+    pub comment: Option<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee).
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: Bip125Replaceable,
+    /// 'true' if the transaction has been abandoned (inputs are respendable).
+    pub abandoned: Option<bool>,
+}
+
+impl ListTransactions {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListTransactions, TransactionItemError> {
+        let transactions =
+            self.0.into_iter().map(|tx| tx.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListTransactions(transactions))
+    }
+}
+
+impl ListSinceBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListSinceBlock, ListSinceBlockError> {
+        use ListSinceBlockError as E;
+
+        let transactions = self
+            .transactions
+            .into_iter()
+            .map(|tx| tx.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Transactions)?;
+        let removed = self
+            .removed
+            .into_iter()
+            .map(|tx| tx.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Removed)?;
+        let last_block = self.last_block.parse::<BlockHash>().map_err(E::LastBlock)?;
+
+        Ok(model::ListSinceBlock { transactions, removed, last_block })
+    }
+}
+
+impl TransactionItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TransactionItem, TransactionItemError> {
+        use TransactionItemError as E;
+
+        let address =
+            self.address.map(|a| Address::from_str(&a)).transpose().map_err(E::Address)?;
+        let amount = SignedAmount::from_btc(self.amount).map_err(E::Amount)?;
+        let fee = self.fee.map(SignedAmount::from_btc).transpose().map_err(E::Fee)?;
+        let block_hash =
+            self.block_hash.map(|h| h.parse::<BlockHash>()).transpose().map_err(E::BlockHash)?;
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let wallet_conflicts = self
+            .wallet_conflicts
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::WalletConflicts)?;
+
+        Ok(model::TransactionItem {
+            involves_watch_only: self.involves_watch_only.unwrap_or(false),
+            address,
+            category: self.category.into_model(),
+            amount,
+            label: self.label,
+            vout: self.vout,
+            fee,
+            confirmations: self.confirmations,
+            generated: self.generated.unwrap_or(false),
+            trusted: self.trusted,
+            block_hash,
+            block_index: self.block_index,
+            block_time: self.block_time,
+            txid,
+            wallet_conflicts,
+            time: self.time,
+            time_received: self.time_received,
+            comment: self.comment,
+            bip125_replaceable: self.bip125_replaceable.into_model(),
+            abandoned: self.abandoned,
+        })
+    }
+}
+
+/// Error when converting a `ListSinceBlock` type into the model type.
+#[derive(Debug)]
+pub enum ListSinceBlockError {
+    /// Conversion of one of the `transactions` failed.
+    Transactions(TransactionItemError),
+    /// Conversion of one of the `removed` transactions failed.
+    Removed(TransactionItemError),
+    /// Conversion of the `last_block` field failed.
+    LastBlock(hex::HexToArrayError),
+}
+
+impl fmt::Display for ListSinceBlockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ListSinceBlockError as E;
+
+        match *self {
+            E::Transactions(ref e) =>
+                write_err!(f, "conversion of the `transactions` field failed"; e),
+            E::Removed(ref e) => write_err!(f, "conversion of the `removed` field failed"; e),
+            E::LastBlock(ref e) => write_err!(f, "conversion of the `last_block` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ListSinceBlockError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ListSinceBlockError as E;
+
+        match *self {
+            E::Transactions(ref e) => Some(e),
+            E::Removed(ref e) => Some(e),
+            E::LastBlock(ref e) => Some(e),
+        }
+    }
+}
+
+/// Error when converting a `TransactionItem` type into the model type.
+#[derive(Debug)]
+pub enum TransactionItemError {
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `amount` field failed.
+    Amount(ParseAmountError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+    /// Conversion of the `block_hash` field failed.
+    BlockHash(hex::HexToArrayError),
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `wallet_conflicts` field failed.
+    WalletConflicts(hex::HexToArrayError),
+}
+
+impl fmt::Display for TransactionItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use TransactionItemError as E;
+
+        match *self {
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::Amount(ref e) => write_err!(f, "conversion of the `amount` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+            E::BlockHash(ref e) => write_err!(f, "conversion of the `block_hash` field failed"; e),
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::WalletConflicts(ref e) =>
+                write_err!(f, "conversion of the `wallet_conflicts` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for TransactionItemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use TransactionItemError as E;
+
+        match *self {
+            E::Address(ref e) => Some(e),
+            E::Amount(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+            E::BlockHash(ref e) => Some(e),
+            E::Txid(ref e) => Some(e),
+            E::WalletConflicts(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `importmulti`.
+///
+/// > importmulti "requests" ( "options" )
+/// >
+/// > Import addresses/scripts (with private or public keys, redeem script (P2SH)), rescanning all addresses in one-shot-only (rescan can be disabled via options). Requires a new wallet backup.
+/// >
+/// > Arguments:
+/// > 1. requests     (array, required) Data to be imported
+/// > 2. options      (json, optional)
+/// >   {
+/// >     "rescan": <false>,         (boolean, optional, default: true) Stating if should rescan the blockchain after all imports
+/// >   }
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportMulti(pub Vec<ImportMultiResult>);
+
+/// The result of a single import request, part of `ImportMulti`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportMultiResult {
+    /// Whether the import succeeded.
+    pub success: bool,
+    /// Warnings encountered while importing (v0.18 onwards).
+    pub warnings: Option<Vec<String>>,
+    /// The error, if the import failed.
+    pub error: Option<JsonRpcError>,
+}
+
+/// An error object, as returned by Core for JSON-RPC errors.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JsonRpcError {
+    /// The error code.
+    pub code: i32,
+    /// The error message.
+    pub message: String,
+}
+
+impl ImportMulti {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportMulti {
+        model::ImportMulti(self.0.into_iter().map(|r| r.into_model()).collect())
+    }
+}
+
+impl ImportMultiResult {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportMultiResult {
+        model::ImportMultiResult {
+            success: self.success,
+            warnings: self.warnings.unwrap_or_default(),
+            error: self.error.map(|e| e.into_model()),
+        }
+    }
+}
+
+impl JsonRpcError {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::JsonRpcError {
+        model::JsonRpcError { code: self.code, message: self.message }
+    }
+}
+
+/// Result of the JSON-RPC method `listreceivedbyaddress`.
+///
+/// > listreceivedbyaddress ( minconf include_empty include_watchonly address_filter )
+/// >
+/// > List balances by receiving address.
+/// >
+/// > Arguments:
+/// > 1. minconf           (numeric, optional, default=1) The minimum number of confirmations before payments are included.
+/// > 2. include_empty     (bool, optional, default=false) Whether to include addresses that haven't received any payments.
+/// > 3. include_watchonly (bool, optional, default=false) Whether to include watch-only addresses (see 'importaddress').
+/// > 4. address_filter    (string, optional) If present, only return information on this address.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListReceivedByAddress(pub Vec<ListReceivedByAddressItem>);
+
+/// An address and the payments it received, part of `listreceivedbyaddress`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListReceivedByAddressItem {
+    /// Only returned if imported addresses were involved in transaction.
+    #[serde(rename = "involvesWatchonly")]
+    pub involves_watch_only: Option<bool>,
+    /// The receiving address.
+    pub address: String,
+    /// DEPRECATED. Backwards compatible alias for label.
+    ///
+    /// Only returned by v0.17 if started with `-deprecatedrpc=accounts`, removed in v0.18.
+    pub account: Option<String>,
+    /// The total amount in BTC received by the address.
+    pub amount: f64,
+    /// The number of confirmations of the most recent transaction included.
+    pub confirmations: u32,
+    /// The label of the receiving address. The default label is "".
+    pub label: String,
+    /// The ids of transactions received with the address.
+    pub txids: Vec<String>,
+}
+
+impl ListReceivedByAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::ListReceivedByAddress, ListReceivedByAddressItemError> {
+        let items =
+            self.0.into_iter().map(|item| item.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListReceivedByAddress(items))
+    }
+}
+
+impl ListReceivedByAddressItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::ListReceivedByAddressItem, ListReceivedByAddressItemError> {
+        use ListReceivedByAddressItemError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let txids = self
+            .txids
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Txids)?;
+
+        Ok(model::ListReceivedByAddressItem {
+            involves_watch_only: self.involves_watch_only.unwrap_or(false),
+            address,
+            amount,
+            confirmations: self.confirmations,
+            label: self.label,
+            txids,
+        })
+    }
+}
+
+/// Error when converting a `ListReceivedByAddressItem` type into the model type.
+#[derive(Debug)]
+pub enum ListReceivedByAddressItemError {
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `amount` field failed.
+    Amount(ParseAmountError),
+    /// Conversion of the `txids` field failed.
+    Txids(hex::HexToArrayError),
+}
+
+impl fmt::Display for ListReceivedByAddressItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ListReceivedByAddressItemError as E;
+
+        match *self {
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::Amount(ref e) => write_err!(f, "conversion of the `amount` field failed"; e),
+            E::Txids(ref e) => write_err!(f, "conversion of the `txids` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ListReceivedByAddressItemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ListReceivedByAddressItemError as E;
+
+        match *self {
+            E::Address(ref e) => Some(e),
+            E::Amount(ref e) => Some(e),
+            E::Txids(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `listunspent`.
+///
+/// > listunspent ( minconf maxconf  ["addresses",...] [include_unsafe] [query_options])
+/// >
+/// > Returns array of unspent transaction outputs
+/// > with between minconf and maxconf (inclusive) confirmations.
+/// > Optionally filter to only include txouts paid to specified addresses.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspent(pub Vec<ListUnspentItem>);
+
+/// An unspent transaction output, part of `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspentItem {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub vout: u32,
+    /// The bitcoin address of the transaction.
+    pub address: Option<String>,
+    /// The associated label, or "" for the default label.
+    pub label: Option<String>,
+    /// The script key.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// The transaction output amount in BTC.
+    pub amount: f64,
+    /// The number of confirmations.
+    pub confirmations: u32,
+    /// The redeemScript if scriptPubKey is P2SH.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: Option<String>,
+    /// Whether we have the private keys to spend this output.
+    pub spendable: bool,
+    /// Whether we know how to spend this output, ignoring the lack of keys.
+    pub solvable: bool,
+    /// Whether this output is considered safe to spend. Unconfirmed transactions
+    /// from outside keys and unconfirmed replacement transactions are considered unsafe
+    /// and are not eligible for spending by fundrawtransaction and sendtoaddress.
+    pub safe: bool,
+}
+
+impl ListUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspent, ListUnspentItemError> {
+        let utxos =
+            self.0.into_iter().map(|utxo| utxo.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListUnspent(utxos))
+    }
+}
+
+impl ListUnspentItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspentItem, ListUnspentItemError> {
+        use ListUnspentItemError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let address =
+            self.address.map(|a| Address::from_str(&a)).transpose().map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let redeem_script = self
+            .redeem_script
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+
+        Ok(model::ListUnspentItem {
+            txid,
+            vout: self.vout,
+            address,
+            label: self.label,
+            script_pubkey,
+            amount,
+            confirmations: self.confirmations,
+            redeem_script,
+            witness_script: None,
+            spendable: self.spendable,
+            solvable: self.solvable,
+            reused: None,
+            descriptor: None,
+            parent_descriptors: None,
+            safe: self.safe,
+            ancestor_count: None,
+            ancestor_size: None,
+            ancestor_fees: None,
+        })
+    }
+}
+
+/// Error when converting a `ListUnspentItem` type into the model type.
+#[derive(Debug)]
+pub enum ListUnspentItemError {
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the `script_pubkey` field failed.
+    ScriptPubkey(hex::HexToBytesError),
+    /// Conversion of the `amount` field failed.
+    Amount(ParseAmountError),
+    /// Conversion of the `redeem_script` field failed.
+    RedeemScript(hex::HexToBytesError),
+    /// Conversion of the `witness_script` field failed.
+    WitnessScript(hex::HexToBytesError),
+}
+
+impl fmt::Display for ListUnspentItemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ListUnspentItemError as E;
+
+        match *self {
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            E::ScriptPubkey(ref e) =>
+                write_err!(f, "conversion of the `script_pubkey` field failed"; e),
+            E::Amount(ref e) => write_err!(f, "conversion of the `amount` field failed"; e),
+            E::RedeemScript(ref e) =>
+                write_err!(f, "conversion of the `redeem_script` field failed"; e),
+            E::WitnessScript(ref e) =>
+                write_err!(f, "conversion of the `witness_script` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for ListUnspentItemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use ListUnspentItemError as E;
+
+        match *self {
+            E::Txid(ref e) => Some(e),
+            E::Address(ref e) => Some(e),
+            E::ScriptPubkey(ref e) => Some(e),
+            E::Amount(ref e) => Some(e),
+            E::RedeemScript(ref e) => Some(e),
+            E::WitnessScript(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `listlockunspent`.
+///
+/// > listlockunspent
+/// >
+/// > Returns list of temporarily unspendable outputs.
+/// > See the lockunspent call to lock and unlock transactions for spending.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListLockUnspent(pub Vec<ListLockUnspentItem>);
+
+/// A locked output, part of `listlockunspent`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListLockUnspentItem {
+    /// The transaction id locked.
+    pub txid: String,
+    /// The vout value.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub vout: u32,
+}
+
+impl ListLockUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListLockUnspent, hex::HexToArrayError> {
+        let outpoints =
+            self.0.into_iter().map(|item| item.outpoint()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListLockUnspent(outpoints))
+    }
+}
+
+impl ListLockUnspentItem {
+    /// Converts the locked output to an `OutPoint`.
+    pub fn outpoint(&self) -> Result<OutPoint, hex::HexToArrayError> {
+        Ok(OutPoint { txid: self.txid.parse()?, vout: self.vout })
+    }
+}
+
+/// Result of the JSON-RPC method `walletprocesspsbt`.
+///
+/// > walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )
+/// >
+/// > Update a PSBT with input information from our wallet and then sign inputs
+/// > that we can sign for.
+/// >
+/// > Arguments:
+/// > 1. "psbt"                      (string, required) The transaction base64 string
+/// > 2. sign                          (boolean, optional, default=true) Also sign the transaction when updating
+/// > 3. "sighashtype"            (string, optional, default=ALL) The signature hash type to sign with if not specified by the PSBT.
+/// > 4. bip32derivs                    (boolean, optional, default=false) If true, includes the BIP 32 derivation paths for public keys if we know them
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletProcessPsbt {
+    /// The base64-encoded partially signed transaction.
+    pub psbt: String,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+}
+
+impl WalletProcessPsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WalletProcessPsbt, PsbtParseError> {
+        let psbt = self.psbt.parse::<Psbt>()?;
+        Ok(model::WalletProcessPsbt { psbt, complete: self.complete, hex: None })
+    }
+}
+
+/// Result of the JSON-RPC method `walletcreatefundedpsbt`.
+///
+/// > walletcreatefundedpsbt [{"txid":"id","vout":n},...] [{"address":amount},{"data":"hex"},...] ( locktime ) ( replaceable ) ( options bip32derivs )
+/// >
+/// > Creates and funds a transaction in the Partially Signed Transaction format. Inputs will be added if supplied inputs are not enough
+/// > Implements the Creator and Updater roles.
+/// >
+/// > Arguments:
+/// > 1. "inputs"                (array, required) A json array of json objects
+/// > 2. "outputs"               (array, required) a json array with outputs (key-value pairs)
+/// > 3. locktime                  (numeric, optional, default=0) Raw locktime. Non-0 value also locktime-activates inputs
+/// > 4. options                 (object, optional)
+/// > 5. bip32derivs                    (boolean, optional, default=false) If true, includes the BIP 32 derivation paths for public keys if we know them
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletCreateFundedPsbt {
+    /// The resulting raw transaction (base64-encoded string).
+    pub psbt: String,
+    /// Fee in BTC the resulting transaction pays.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub fee: f64,
+    /// The position of the added change output, or -1.
+    #[serde(rename = "changepos")]
+    pub change_position: i64,
+}
+
+impl WalletCreateFundedPsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WalletCreateFundedPsbt, WalletCreateFundedPsbtError> {
+        use WalletCreateFundedPsbtError as E;
+
+        let psbt = self.psbt.parse::<Psbt>().map_err(E::Psbt)?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+        let change_position = u32::try_from(self.change_position).ok();
+
+        Ok(model::WalletCreateFundedPsbt { psbt, fee, change_position })
+    }
+}
+
+/// Error when converting a `WalletCreateFundedPsbt` type into the model type.
+#[derive(Debug)]
+pub enum WalletCreateFundedPsbtError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+}
+
+impl fmt::Display for WalletCreateFundedPsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use WalletCreateFundedPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for WalletCreateFundedPsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use WalletCreateFundedPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `rescanblockchain`.
+///
+/// > rescanblockchain ("start_height") ("stop_height")
+/// >
+/// > Rescan the local blockchain for wallet related transactions.
+/// >
+/// > Arguments:
+/// > 1. "start_height"    (numeric, optional) block height where the rescan should start
+/// > 2. "stop_height"     (numeric, optional) the last block height that should be scanned
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RescanBlockchain {
+    /// The block height where the rescan has started.
+    pub start_height: u32,
+    /// The height of the last rescanned block, `None` if no blocks were scanned.
+    pub stop_height: Option<u32>,
+}
+
+impl RescanBlockchain {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::RescanBlockchain {
+        model::RescanBlockchain { start_height: self.start_height, stop_height: self.stop_height }
+    }
+}
+
+/// Result of the JSON-RPC method `listwallets`.
+///
+/// > listwallets
+/// > Returns a list of currently loaded wallets.
+/// > For full information on the wallet, use "getwalletinfo"
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWallets(pub Vec<String>);
+
+impl ListWallets {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListWallets { model::ListWallets(self.0) }
+}
+
+/// Result of the JSON-RPC method `encryptwallet`.
+///
+/// > encryptwallet "passphrase"
+/// >
+/// > Encrypts the wallet with 'passphrase'. This is for first time encryption.
+/// > After this, any calls that interact with private keys such as sending or signing
+/// > will require the passphrase to be set prior the making these calls.
+/// > Use the walletpassphrase call for this, and then walletlock call.
+/// > If the wallet is already encrypted, use the walletpassphrasechange call.
+/// >
+/// > Arguments:
+/// > 1. passphrase    (string, required) The pass phrase to encrypt the wallet with. It must be at least 1 character, but should be long.
+///
+/// The result is a human readable message, the wallet is encrypted if the call succeeds.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EncryptWallet(pub String);
+
+// The deprecated account methods, only available if `bitcoind` is started with
+// `-deprecatedrpc=accounts` and removed in v0.18. Accounts do not exist in later versions so these
+// types are not modelled, use labels instead.
+
+/// Result of the deprecated JSON-RPC method `getaccount`.
+///
+/// > getaccount "address"
+/// >
+/// > DEPRECATED. Returns the account associated with the given address.
+/// >
+/// > Arguments:
+/// > 1. "address"         (string, required) The bitcoin address for account lookup.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAccount(pub String);
+
+/// Result of the deprecated JSON-RPC method `getaccountaddress`.
+///
+/// > getaccountaddress "account"
+/// >
+/// > DEPRECATED. Returns the current Bitcoin address for receiving payments to this account.
+/// >
+/// > Arguments:
+/// > 1. "account"       (string, required) The account for the address. It can also be set to the empty string "" to represent the default account. The account does not need to exist, it will be created and a new address created  if there is no account by the given name.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAccountAddress(pub String);
+
+impl GetAccountAddress {
+    /// Converts json straight to a `bitcoin::Address`.
+    pub fn address(self) -> Result<Address<NetworkUnchecked>, address::ParseError> {
+        Address::from_str(&self.0)
+    }
+}
+
+/// Result of the deprecated JSON-RPC method `getaddressesbyaccount`.
+///
+/// > getaddressesbyaccount "account"
+/// >
+/// > DEPRECATED. Returns the list of addresses for the given account.
+/// >
+/// > Arguments:
+/// > 1. "account"        (string, required) The account name.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetAddressesByAccount(pub Vec<String>);
+
+impl GetAddressesByAccount {
+    /// Converts json straight to a list of `bitcoin::Address`.
+    pub fn addresses(self) -> Result<Vec<Address<NetworkUnchecked>>, address::ParseError> {
+        self.0.iter().map(|s| Address::from_str(s)).collect()
+    }
+}
+
+/// Result of the deprecated JSON-RPC method `getreceivedbyaccount`.
+///
+/// > getreceivedbyaccount "account" ( minconf )
+/// >
+/// > DEPRECATED. Returns the total amount received by addresses with <account> in transactions with at least [minconf] confirmations.
+/// >
+/// > Arguments:
+/// > 1. "account"      (string, required) The selected account, may be the default account using "".
+/// > 2. minconf          (numeric, optional, default=1) Only include transactions confirmed at least this many times.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetReceivedByAccount(pub f64);
+
+impl GetReceivedByAccount {
+    /// Converts json straight to a `bitcoin::Amount`.
+    pub fn amount(self) -> Result<Amount, ParseAmountError> { Amount::from_btc(self.0) }
+}
+
+/// Result of the deprecated JSON-RPC method `listaccounts`.
+///
+/// > listaccounts ( minconf include_watchonly)
+/// >
+/// > DEPRECATED. Returns Object that has account names as keys, account balances as values.
+/// >
+/// > Arguments:
+/// > 1. minconf             (numeric, optional, default=1) Only include transactions with at least this many confirmations
+/// > 2. include_watchonly   (bool, optional, default=false) Include balances in watch-only addresses (see 'importaddress')
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListAccounts(pub BTreeMap<String, f64>);
+
+impl ListAccounts {
+    /// Converts json straight to a map of account name to balance.
+    ///
+    /// Balances can be negative, e.g. after using the deprecated `move` method.
+    pub fn balances(self) -> Result<BTreeMap<String, SignedAmount>, ParseAmountError> {
+        self.0.into_iter().map(|(k, v)| Ok((k, SignedAmount::from_btc(v)?))).collect()
+    }
+}
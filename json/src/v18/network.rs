@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.18.1 - network.
+//!
+//! Types for methods found under the `== Network ==` section of the API docs.
+
+use bitcoin::p2p::ServiceFlags;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `getnodeaddresses`.
+///
+/// > getnodeaddresses ( count )
+/// >
+/// > Return known addresses which can potentially be used to find new nodes in the network
+/// >
+/// > Arguments:
+/// > 1. "count"    (numeric, optional) How many addresses to return. Limited to the smaller of 2500
+/// >                                   or 23% of all known addresses. (default = 1)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetNodeAddresses(pub Vec<NodeAddress>);
+
+/// An item from the list returned by the JSON-RPC method `getnodeaddresses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NodeAddress {
+    /// Timestamp in seconds since epoch (Jan 1 1970 GMT) keeping track of when the node was last seen.
+    pub time: u64,
+    /// The services offered.
+    pub services: u64,
+    /// The address of the node.
+    pub address: String,
+    /// The port of the node.
+    pub port: u16,
+}
+
+impl GetNodeAddresses {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetNodeAddresses {
+        model::GetNodeAddresses(self.0.into_iter().map(|a| a.into_model()).collect())
+    }
+}
+
+impl NodeAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    ///
+    /// This version of Core does not report the network so it is inferred from the address.
+    pub fn into_model(self) -> model::NodeAddress {
+        let network = model::NetworkType::from_address(&self.address);
+        model::NodeAddress {
+            time: self.time,
+            services: ServiceFlags::from(self.services),
+            address: self.address,
+            port: self.port,
+            network,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.18.1 - util.
+//!
+//! Types for methods found under the `== Util ==` section of the API docs.
+
+use core::fmt;
+
+use bitcoin::address::{self, Address};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `deriveaddresses`.
+///
+/// > deriveaddresses "descriptor" ( range )
+/// >
+/// > Derives one or more addresses corresponding to an output descriptor.
+/// >
+/// > Arguments:
+/// > 1. "descriptor"    (string, required) The descriptor.
+/// > 2. range           (numeric or array, optional) If a ranged descriptor is used, this specifies the end or the range (as [begin,end]) to derive.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeriveAddresses(pub Vec<String>);
+
+impl DeriveAddresses {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DeriveAddresses, address::ParseError> {
+        let addresses =
+            self.0.iter().map(|s| s.parse::<Address<_>>()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::DeriveAddresses(addresses))
+    }
+}
+
+/// Result of JSON-RPC method `getdescriptorinfo`.
+///
+/// > getdescriptorinfo "descriptor"
+/// >
+/// > Analyses a descriptor.
+/// >
+/// > Arguments:
+/// > 1. "descriptor"    (string, required) The descriptor.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetDescriptorInfo {
+    /// The descriptor in canonical form, without private keys.
+    pub descriptor: String,
+    /// The checksum for the input descriptor.
+    ///
+    /// Only returned by v0.19 onwards.
+    pub checksum: Option<String>,
+    /// Whether the descriptor is ranged.
+    #[serde(rename = "isrange")]
+    pub is_range: bool,
+    /// Whether the descriptor is solvable.
+    #[serde(rename = "issolvable")]
+    pub is_solvable: bool,
+    /// Whether the input descriptor contained at least one private key.
+    #[serde(rename = "hasprivatekeys")]
+    pub has_private_keys: bool,
+}
+
+impl GetDescriptorInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    ///
+    /// v0.18 does not return the checksum of the input descriptor, the checksum of the canonical
+    /// descriptor is used instead (they only differ if the input contained private keys or was not
+    /// in canonical form).
+    pub fn into_model(self) -> Result<model::GetDescriptorInfo, GetDescriptorInfoError> {
+        let checksum = match self.checksum {
+            Some(checksum) => checksum,
+            None => match self.descriptor.rsplit_once('#') {
+                Some((_, checksum)) => checksum.to_owned(),
+                None => return Err(GetDescriptorInfoError::MissingChecksum),
+            },
+        };
+
+        Ok(model::GetDescriptorInfo {
+            descriptor: self.descriptor,
+            checksum,
+            is_range: self.is_range,
+            is_solvable: self.is_solvable,
+            has_private_keys: self.has_private_keys,
+        })
+    }
+}
+
+/// Error when converting a `GetDescriptorInfo` type into the model type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GetDescriptorInfoError {
+    /// Neither the `checksum` field nor the `descriptor` field included a checksum.
+    MissingChecksum,
+}
+
+impl fmt::Display for GetDescriptorInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetDescriptorInfoError as E;
+
+        match *self {
+            E::MissingChecksum => write!(f, "the descriptor checksum is missing"),
+        }
+    }
+}
+
+impl std::error::Error for GetDescriptorInfoError {}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.18.1 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use bitcoin::amount::ParseAmountError;
+use bitcoin::Amount;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of the JSON-RPC method `getreceivedbylabel`.
+///
+/// > getreceivedbylabel "label" ( minconf )
+/// >
+/// > Returns the total amount received by addresses with <label> in transactions with at least [minconf] confirmations.
+/// >
+/// > Arguments:
+/// > 1. label      (string, required) The selected label, may be the default label using "".
+/// > 2. minconf    (numeric, optional, default=1) Only include transactions confirmed at least this many times.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetReceivedByLabel(pub f64);
+
+impl GetReceivedByLabel {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetReceivedByLabel, ParseAmountError> {
+        let amount = Amount::from_btc(self.0)?;
+        Ok(model::GetReceivedByLabel(amount))
+    }
+}
+
+/// Result of the JSON-RPC method `listwalletdir`.
+///
+/// > listwalletdir
+/// > Returns a list of wallets in the wallet directory.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWalletDir {
+    /// The wallets in the wallet directory.
+    pub wallets: Vec<ListWalletDirWallet>,
+}
+
+/// A wallet entry in the result of the JSON-RPC method `listwalletdir`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListWalletDirWallet {
+    /// The wallet name.
+    pub name: String,
+}
+
+impl ListWalletDir {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListWalletDir {
+        model::ListWalletDir(self.wallets.into_iter().map(|w| w.name).collect())
+    }
+}
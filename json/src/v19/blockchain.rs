@@ -8,13 +8,12 @@ use core::fmt;
 use std::collections::BTreeMap;
 
 use bitcoin::error::UnprefixedHexError;
-use bitcoin::{hex, network, BlockHash, Network, Work};
+use bitcoin::{hex, BlockHash, ScriptBuf, Txid, Weight, Work, Wtxid};
 use internals::write_err;
 use serde::{Deserialize, Serialize};
 
-use crate::model;
-
-#[rustfmt::skip]                // Keep public re-exports separate.
+use crate::v17::{GetMempoolInfoError, MapMempoolEntryError, MempoolEntryError, MempoolEntryFees};
+use crate::{model, Warnings};
 
 /// Result of JSON-RPC method `getblockchaininfo`.
 ///
@@ -60,6 +59,9 @@ pub struct GetBlockchainInfo {
     /// Status of softforks in progress, maps softfork name -> [`Softfork`].
     #[serde(default)]
     pub softforks: BTreeMap<String, Softfork>,
+    /// The block challenge (aka. block script) in hex (only present on signet, not returned by
+    /// all versions).
+    pub signet_challenge: Option<String>,
     /// Any network and blockchain warnings.
     pub warnings: String,
 }
@@ -90,6 +92,9 @@ pub enum SoftforkType {
     ///
     /// [BIP-9] <https://github.com/bitcoin/bips/blob/master/bip-0009.mediawiki>
     Bip9,
+    /// A softfork type not known to this library (deserialization catch-all).
+    #[serde(other)]
+    Unknown,
 }
 
 /// Status of BIP-9 softforks.
@@ -107,6 +112,8 @@ pub struct Bip9SoftforkInfo {
     pub since: u32,
     /// Numeric statistics about BIP-9 signalling for a softfork (only for "started" status).
     pub statistics: Option<Bip9SoftforkStatistics>,
+    /// Minimum height of blocks for which the rules may be enforced (Core v0.21 onwards).
+    pub min_activation_height: Option<u32>,
 }
 
 /// BIP-9 softfork status: one of "defined", "started", "locked_in", "active", "failed".
@@ -123,6 +130,9 @@ pub enum Bip9SoftforkStatus {
     Active,
     /// BIP-9 softfork status "failed".
     Failed,
+    /// A status not known to this library (deserialization catch-all).
+    #[serde(other)]
+    Unknown,
 }
 
 /// Statistics for a BIP-9 softfork.
@@ -145,13 +155,21 @@ impl GetBlockchainInfo {
     pub fn into_model(self) -> Result<model::GetBlockchainInfo, GetBlockchainInfoError> {
         use GetBlockchainInfoError as E;
 
-        let chain = Network::from_core_arg(&self.chain).map_err(E::Chain)?;
+        let chain = model::Chain::from_core_arg(&self.chain);
         let best_block_hash =
             self.best_block_hash.parse::<BlockHash>().map_err(E::BestBlockHash)?;
         // FIXME: Is unprefixed correct?
         let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let signet_challenge = self
+            .signet_challenge
+            .map(|hex| ScriptBuf::from_hex(&hex).map_err(E::SignetChallenge))
+            .transpose()?;
 
-        let softforks = BTreeMap::new(); // TODO: Handle softforks stuff.
+        let softforks = self
+            .softforks
+            .into_iter()
+            .map(|(name, softfork)| (name, softfork.into_model()))
+            .collect();
 
         Ok(model::GetBlockchainInfo {
             chain,
@@ -159,6 +177,7 @@ impl GetBlockchainInfo {
             headers: self.headers,
             best_block_hash,
             difficulty: self.difficulty,
+            time: None,
             median_time: self.median_time,
             verification_progress: self.verification_progress,
             initial_block_download: self.initial_block_download,
@@ -169,17 +188,87 @@ impl GetBlockchainInfo {
             automatic_pruning: self.automatic_pruning,
             prune_target_size: self.prune_target_size,
             softforks,
-            warnings: self.warnings,
+            signet_challenge,
+            warnings: Warnings::from(self.warnings).into_model(),
         })
     }
 }
 
+impl Softfork {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Softfork {
+        model::Softfork {
+            type_: self.type_.into_model(),
+            bip9: self.bip9.map(Bip9SoftforkInfo::into_model),
+            height: self.height,
+            active: self.active,
+        }
+    }
+}
+
+impl SoftforkType {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::SoftforkType {
+        use model::SoftforkType::*;
+
+        match self {
+            Self::Buried => Buried,
+            Self::Bip9 => Bip9,
+            Self::Unknown => Unknown,
+        }
+    }
+}
+
+impl Bip9SoftforkInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Bip9SoftforkInfo {
+        model::Bip9SoftforkInfo {
+            status: self.status.into_model(),
+            bit: self.bit,
+            start_time: self.start_time,
+            timeout: self.timeout,
+            since: self.since,
+            statistics: self.statistics.map(Bip9SoftforkStatistics::into_model),
+            min_activation_height: self.min_activation_height,
+        }
+    }
+}
+
+impl Bip9SoftforkStatus {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Bip9SoftforkStatus {
+        use model::Bip9SoftforkStatus::*;
+
+        match self {
+            Self::Defined => Defined,
+            Self::Started => Started,
+            Self::LockedIn => LockedIn,
+            Self::Active => Active,
+            Self::Failed => Failed,
+            Self::Unknown => Unknown,
+        }
+    }
+}
+
+impl Bip9SoftforkStatistics {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::Bip9SoftforkStatistics {
+        model::Bip9SoftforkStatistics {
+            period: self.period,
+            threshold: self.threshold,
+            elapsed: self.elapsed,
+            count: self.count,
+            possible: self.possible,
+        }
+    }
+}
+
 /// Error when converting a `GetBlockchainInfo` type into the model type.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GetBlockchainInfoError {
-    Chain(network::ParseNetworkError),
     BestBlockHash(hex::HexToArrayError),
     ChainWork(UnprefixedHexError),
+    SignetChallenge(hex::HexToBytesError),
 }
 
 impl fmt::Display for GetBlockchainInfoError {
@@ -187,11 +276,12 @@ impl fmt::Display for GetBlockchainInfoError {
         use GetBlockchainInfoError::*;
 
         match *self {
-            Chain(ref e) => write_err!(f, "conversion of the `chain` field failed"; e),
             BestBlockHash(ref e) => {
                 write_err!(f, "conversion of the `best_block_hash` field failed"; e)
             }
             ChainWork(ref e) => write_err!(f, "conversion of the `chain_work` field failed"; e),
+            SignetChallenge(ref e) =>
+                write_err!(f, "conversion of the `signet_challenge` field failed"; e),
         }
     }
 }
@@ -201,9 +291,199 @@ impl std::error::Error for GetBlockchainInfoError {
         use GetBlockchainInfoError::*;
 
         match *self {
-            Chain(ref e) => Some(e),
             BestBlockHash(ref e) => Some(e),
             ChainWork(ref e) => Some(e),
+            SignetChallenge(ref e) => Some(e),
         }
     }
 }
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to `true`.
+///
+/// Map of txid to [`MempoolEntry`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawMempoolVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetRawMempoolVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempoolVerbose, MapMempoolEntryError> {
+        use MapMempoolEntryError as E;
+
+        let mut map = BTreeMap::new();
+        for (k, v) in self.0.into_iter() {
+            let txid = k.parse::<Txid>().map_err(E::Txid)?;
+            let entry = v.into_model().map_err(E::MempoolEntry)?;
+            map.insert(txid, entry);
+        }
+        Ok(model::GetRawMempoolVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolentry`.
+///
+/// > getmempoolentry txid
+/// >
+/// > Returns mempool data for given transaction
+/// >
+/// > Arguments:
+/// > 1. txid    (string, required) The transaction id (must be in mempool)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolEntry(pub MempoolEntry);
+
+impl GetMempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolEntry, MempoolEntryError> {
+        Ok(model::GetMempoolEntry(self.0.into_model()?))
+    }
+}
+
+/// A transaction in the mempool.
+///
+/// Used by `getmempoolentry` and `getrawmempool` with verbose set to `true`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntry {
+    /// Virtual transaction size as defined in BIP 141.
+    ///
+    /// This is different from actual serialized size for witness transactions as witness data is
+    /// discounted.
+    pub vsize: u64,
+    /// Transaction weight as defined in BIP 141.
+    pub weight: u64,
+    /// Virtual transaction size (DEPRECATED).
+    pub size: Option<u64>,
+    /// Transaction fee in BTC (DEPRECATED).
+    #[serde(default, deserialize_with = "crate::deserialize_optional_number_or_string")]
+    pub fee: Option<f64>,
+    /// Transaction fee with fee deltas used for mining priority (DEPRECATED).
+    #[serde(rename = "modifiedfee")]
+    pub modified_fee: Option<f64>,
+    /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
+    pub time: u64,
+    /// Block height when transaction entered pool.
+    pub height: u64,
+    /// Number of in-mempool descendant transactions (including this one).
+    #[serde(rename = "descendantcount")]
+    pub descendant_count: u64,
+    /// Virtual transaction size of in-mempool descendants (including this one).
+    #[serde(rename = "descendantsize")]
+    pub descendant_size: u64,
+    /// Modified fees (see above) of in-mempool descendants (including this one) (DEPRECATED).
+    #[serde(rename = "descendantfees")]
+    pub descendant_fees: Option<u64>,
+    /// Number of in-mempool ancestor transactions (including this one).
+    #[serde(rename = "ancestorcount")]
+    pub ancestor_count: u64,
+    /// Virtual transaction size of in-mempool ancestors (including this one).
+    #[serde(rename = "ancestorsize")]
+    pub ancestor_size: u64,
+    /// Modified fees (see above) of in-mempool ancestors (including this one) (DEPRECATED).
+    #[serde(rename = "ancestorfees")]
+    pub ancestor_fees: Option<u64>,
+    /// Hash of serialized transaction, including witness data.
+    pub wtxid: String,
+    /// Fee object which contains the base fee, modified fee (with fee deltas), and ancestor/descendant fee totals all in BTC.
+    pub fees: MempoolEntryFees,
+    /// Unconfirmed transactions used as inputs for this transaction (parent transaction id).
+    pub depends: Vec<String>,
+    /// Used by unconfirmed transactions spending outputs from this transaction (child transaction id).
+    #[serde(rename = "spentby")]
+    pub spent_by: Vec<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee)
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: bool,
+}
+
+impl MempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolEntry, MempoolEntryError> {
+        use MempoolEntryError as E;
+
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let fees = self.fees.into_model().map_err(E::Fees)?;
+        let depends = self
+            .depends
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Depends)?;
+        let spent_by = self
+            .spent_by
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::SpentBy)?;
+
+        Ok(model::MempoolEntry {
+            vsize: self.vsize,
+            weight: Some(Weight::from_wu(self.weight)),
+            time: self.time,
+            height: self.height,
+            descendant_count: self.descendant_count,
+            descendant_size: self.descendant_size,
+            ancestor_count: self.ancestor_count,
+            ancestor_size: self.ancestor_size,
+            wtxid,
+            fees,
+            depends,
+            spent_by,
+            bip125_replaceable: Some(self.bip125_replaceable),
+            unbroadcast: None,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolinfo`.
+///
+/// > getmempoolinfo
+/// >
+/// > Returns details on the active state of the TX memory pool.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolInfo {
+    /// True if the mempool is fully loaded.
+    pub loaded: bool,
+    /// Current tx count.
+    pub size: u64,
+    /// Sum of all virtual transaction sizes as defined in BIP 141.
+    ///
+    /// Differs from actual serialized size because witness data is discounted.
+    pub bytes: u64,
+    /// Total memory usage for the mempool.
+    pub usage: u64,
+    /// Maximum memory usage for the mempool.
+    #[serde(rename = "maxmempool")]
+    pub max_mempool: u64,
+    /// Minimum fee rate in BTC/kB for a transaction to be accepted.
+    ///
+    /// This is the maximum of `minrelaytxfee` and the minimum mempool fee.
+    #[serde(rename = "mempoolminfee")]
+    pub mempool_min_fee: f64,
+    /// Current minimum relay fee for transactions.
+    #[serde(rename = "minrelaytxfee")]
+    pub min_relay_tx_fee: f64,
+}
+
+impl GetMempoolInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolInfo, GetMempoolInfoError> {
+        use GetMempoolInfoError as E;
+
+        let mempool_min_fee =
+            crate::fee_rate_from_btc_per_kvb(self.mempool_min_fee).map_err(E::MempoolMinFee)?;
+        let min_relay_tx_fee =
+            crate::fee_rate_from_btc_per_kvb(self.min_relay_tx_fee).map_err(E::MinRelayTxFee)?;
+
+        Ok(model::GetMempoolInfo {
+            loaded: Some(self.loaded),
+            size: self.size,
+            bytes: self.bytes,
+            usage: self.usage,
+            total_fee: None,
+            max_mempool: self.max_mempool,
+            mempool_min_fee,
+            min_relay_tx_fee,
+            incremental_relay_fee: None,
+            unbroadcast_count: None,
+            full_rbf: None,
+        })
+    }
+}
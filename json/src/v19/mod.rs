@@ -11,32 +11,32 @@
 //! - [x] `getblockchaininfo`
 //! - [ ] `getblockcount`
 //! - [ ] `getblockfilter "blockhash" ( "filtertype" )`
-//! - [ ] `getblockhash height`
-//! - [ ] `getblockheader "blockhash" ( verbose )`
+//! - [x] `getblockhash height`
+//! - [x] `getblockheader "blockhash" ( verbose )`
 //! - [ ] `getblockstats hash_or_height ( stats )`
-//! - [ ] `getchaintips`
+//! - [x] `getchaintips`
 //! - [ ] `getchaintxstats ( nblocks "blockhash" )`
 //! - [ ] `getdifficulty`
 //! - [ ] `getmempoolancestors "txid" ( verbose )`
 //! - [ ] `getmempooldescendants "txid" ( verbose )`
-//! - [ ] `getmempoolentry "txid"`
-//! - [ ] `getmempoolinfo`
-//! - [ ] `getrawmempool ( verbose )`
-//! - [ ] `gettxout "txid" n ( include_mempool )`
-//! - [ ] `gettxoutproof ["txid",...] ( "blockhash" )`
-//! - [ ] `gettxoutsetinfo`
-//! - [ ] `preciousblock "blockhash"`
+//! - [x] `getmempoolentry "txid"`
+//! - [x] `getmempoolinfo`
+//! - [x] `getrawmempool ( verbose )`
+//! - [x] `gettxout "txid" n ( include_mempool )`
+//! - [x] `gettxoutproof ["txid",...] ( "blockhash" )`
+//! - [x] `gettxoutsetinfo`
+//! - [x] `preciousblock "blockhash"`
 //! - [ ] `pruneblockchain height`
 //! - [ ] `savemempool`
-//! - [ ] `scantxoutset "action" ( [scanobjects,...] )`
-//! - [ ] `verifychain ( checklevel nblocks )`
-//! - [ ] `verifytxoutproof "proof"`
+//! - [x] `scantxoutset "action" ( [scanobjects,...] )`
+//! - [x] `verifychain ( checklevel nblocks )`
+//! - [x] `verifytxoutproof "proof"`
 //!
 //! **== Control ==**
-//! - [ ] `getmemoryinfo ( "mode" )`
+//! - [x] `getmemoryinfo ( "mode" )`
 //! - [ ] `getrpcinfo`
 //! - [ ] `help ( "command" )`
-//! - [ ] `logging ( ["include_category",...] ["exclude_category",...] )`
+//! - [x] `logging ( ["include_category",...] ["exclude_category",...] )`
 //! - [x] `stop`
 //! - [ ] `uptime`
 //!
@@ -44,8 +44,8 @@
 //! - [x] `generatetoaddress nblocks "address" ( maxtries )`
 //!
 //! **== Mining ==**
-//! - [ ] `getblocktemplate ( "template_request" )`
-//! - [ ] `getmininginfo`
+//! - [x] `getblocktemplate ( "template_request" )`
+//! - [x] `getmininginfo`
 //! - [ ] `getnetworkhashps ( nblocks height )`
 //! - [ ] `prioritisetransaction "txid" ( dummy ) fee_delta`
 //! - [ ] `submitblock "hexdata" ( "dummy" )`
@@ -59,8 +59,8 @@
 //! - [ ] `getconnectioncount`
 //! - [ ] `getnettotals`
 //! - [x] `getnetworkinfo`
-//! - [ ] `getnodeaddresses ( count )`
-//! - [ ] `getpeerinfo`
+//! - [x] `getnodeaddresses ( count )`
+//! - [x] `getpeerinfo`
 //! - [ ] `listbanned`
 //! - [ ] `ping`
 //! - [ ] `setban "subnet" "command" ( bantime absolute )`
@@ -68,55 +68,55 @@
 //!
 //! **== Rawtransactions ==**
 //! - [ ] `analyzepsbt "psbt"`
-//! - [ ] `combinepsbt ["psbt",...]`
+//! - [x] `combinepsbt ["psbt",...]`
 //! - [ ] `combinerawtransaction ["hexstring",...]`
 //! - [ ] `converttopsbt "hexstring" ( permitsigdata iswitness )`
 //! - [ ] `createpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime replaceable )`
-//! - [ ] `createrawtransaction [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime replaceable )`
-//! - [ ] `decodepsbt "psbt"`
-//! - [ ] `decoderawtransaction "hexstring" ( iswitness )`
+//! - [x] `createrawtransaction [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime replaceable )`
+//! - [x] `decodepsbt "psbt"`
+//! - [x] `decoderawtransaction "hexstring" ( iswitness )`
 //! - [ ] `decodescript "hexstring"`
-//! - [ ] `finalizepsbt "psbt" ( extract )`
-//! - [ ] `fundrawtransaction "hexstring" ( options iswitness )`
-//! - [ ] `getrawtransaction "txid" ( verbose "blockhash" )`
+//! - [x] `finalizepsbt "psbt" ( extract )`
+//! - [x] `fundrawtransaction "hexstring" ( options iswitness )`
+//! - [x] `getrawtransaction "txid" ( verbose "blockhash" )`
 //! - [ ] `joinpsbts ["psbt",...]`
-//! - [ ] `sendrawtransaction "hexstring" ( maxfeerate )`
+//! - [x] `sendrawtransaction "hexstring" ( maxfeerate )`
 //! - [ ] `signrawtransactionwithkey "hexstring" ["privatekey",...] ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
-//! - [ ] `testmempoolaccept ["rawtx",...] ( maxfeerate )`
+//! - [x] `testmempoolaccept ["rawtx",...] ( maxfeerate )`
 //! - [ ] `utxoupdatepsbt "psbt" ( ["",{"desc":"str","range":n or [n,n]},...] )`
 //!
 //! **== Util ==**
 //! - [ ] `createmultisig nrequired ["key",...] ( "address_type" )`
-//! - [ ] `deriveaddresses "descriptor" ( range )`
-//! - [ ] `estimatesmartfee conf_target ( "estimate_mode" )`
-//! - [ ] `getdescriptorinfo "descriptor"`
+//! - [x] `deriveaddresses "descriptor" ( range )`
+//! - [x] `estimatesmartfee conf_target ( "estimate_mode" )`
+//! - [x] `getdescriptorinfo "descriptor"`
 //! - [ ] `signmessagewithprivkey "privkey" "message"`
 //! - [ ] `validateaddress "address"`
-//! - [ ] `verifymessage "address" "signature" "message"`
+//! - [x] `verifymessage "address" "signature" "message"`
 //!
 //! **== Wallet ==**
 //! - [ ] `abandontransaction "txid"`
 //! - [ ] `abortrescan`
-//! - [ ] `addmultisigaddress nrequired ["key",...] ( "label" "address_type" )`
+//! - [x] `addmultisigaddress nrequired ["key",...] ( "label" "address_type" )`
 //! - [ ] `backupwallet "destination"`
-//! - [ ] `bumpfee "txid" ( options )`
+//! - [x] `bumpfee "txid" ( options )`
 //! - [x] `createwallet "wallet_name" ( disable_private_keys blank "passphrase" avoid_reuse )`
 //! - [ ] `dumpprivkey "address"`
 //! - [ ] `dumpwallet "filename"`
-//! - [ ] `encryptwallet "passphrase"`
-//! - [ ] `getaddressesbylabel "label"`
-//! - [ ] `getaddressinfo "address"`
+//! - [x] `encryptwallet "passphrase"`
+//! - [x] `getaddressesbylabel "label"`
+//! - [x] `getaddressinfo "address"`
 //! - [x] `getbalance ( "dummy" minconf include_watchonly avoid_reuse )`
 //! - [ ] `getbalances`
 //! - [x] `getnewaddress ( "label" "address_type" )`
-//! - [ ] `getrawchangeaddress ( "address_type" )`
-//! - [ ] `getreceivedbyaddress "address" ( minconf )`
-//! - [ ] `getreceivedbylabel "label" ( minconf )`
+//! - [x] `getrawchangeaddress ( "address_type" )`
+//! - [x] `getreceivedbyaddress "address" ( minconf )`
+//! - [x] `getreceivedbylabel "label" ( minconf )`
 //! - [x] `gettransaction "txid" ( include_watchonly verbose )`
 //! - [ ] `getunconfirmedbalance`
-//! - [ ] `getwalletinfo`
-//! - [ ] `importaddress "address" ( "label" rescan p2sh )`
-//! - [ ] `importmulti "requests" ( "options" )`
+//! - [x] `getwalletinfo`
+//! - [x] `importaddress "address" ( "label" rescan p2sh )`
+//! - [x] `importmulti "requests" ( "options" )`
 //! - [ ] `importprivkey "privkey" ( "label" rescan )`
 //! - [ ] `importprunedfunds "rawtransaction" "txoutproof"`
 //! - [ ] `importpubkey "pubkey" ( "label" rescan )`
@@ -124,32 +124,32 @@
 //! - [ ] `keypoolrefill ( newsize )`
 //! - [ ] `listaddressgroupings`
 //! - [ ] `listlabels ( "purpose" )`
-//! - [ ] `listlockunspent`
-//! - [ ] `listreceivedbyaddress ( minconf include_empty include_watchonly "address_filter" )`
+//! - [x] `listlockunspent`
+//! - [x] `listreceivedbyaddress ( minconf include_empty include_watchonly "address_filter" )`
 //! - [ ] `listreceivedbylabel ( minconf include_empty include_watchonly )`
-//! - [ ] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
-//! - [ ] `listtransactions ( "label" count skip include_watchonly )`
-//! - [ ] `listunspent ( minconf maxconf ["address",...] include_unsafe query_options )`
-//! - [ ] `listwalletdir`
-//! - [ ] `listwallets`
+//! - [x] `listsinceblock ( "blockhash" target_confirmations include_watchonly include_removed )`
+//! - [x] `listtransactions ( "label" count skip include_watchonly )`
+//! - [x] `listunspent ( minconf maxconf ["address",...] include_unsafe query_options )`
+//! - [x] `listwalletdir`
+//! - [x] `listwallets`
 //! - [x] `loadwallet "filename"`
-//! - [ ] `lockunspent unlock ( [{"txid":"hex","vout":n},...] )`
+//! - [x] `lockunspent unlock ( [{"txid":"hex","vout":n},...] )`
 //! - [ ] `removeprunedfunds "txid"`
-//! - [ ] `rescanblockchain ( start_height stop_height )`
+//! - [x] `rescanblockchain ( start_height stop_height )`
 //! - [ ] `sendmany "" {"address":amount} ( minconf "comment" ["address",...] replaceable conf_target "estimate_mode" )`
 //! - [x] `sendtoaddress "address" amount ( "comment" "comment_to" subtractfeefromamount replaceable conf_target "estimate_mode" avoid_reuse )`
 //! - [ ] `sethdseed ( newkeypool "seed" )`
 //! - [ ] `setlabel "address" "label"`
 //! - [ ] `settxfee amount`
 //! - [ ] `setwalletflag "flag" ( value )`
-//! - [ ] `signmessage "address" "message"`
-//! - [ ] `signrawtransactionwithwallet "hexstring" ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
+//! - [x] `signmessage "address" "message"`
+//! - [x] `signrawtransactionwithwallet "hexstring" ( [{"txid":"hex","vout":n,"scriptPubKey":"hex","redeemScript":"hex","witnessScript":"hex","amount":amount},...] "sighashtype" )`
 //! - [ ] `unloadwallet ( "wallet_name" )`
-//! - [ ] `walletcreatefundedpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime options bip32derivs )`
-//! - [ ] `walletlock`
-//! - [ ] `walletpassphrase "passphrase" timeout`
-//! - [ ] `walletpassphrasechange "oldpassphrase" "newpassphrase"`
-//! - [ ] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )`
+//! - [x] `walletcreatefundedpsbt [{"txid":"hex","vout":n,"sequence":n},...] [{"address":amount},{"data":"hex"},...] ( locktime options bip32derivs )`
+//! - [x] `walletlock`
+//! - [x] `walletpassphrase "passphrase" timeout`
+//! - [x] `walletpassphrasechange "oldpassphrase" "newpassphrase"`
+//! - [x] `walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs )`
 //!
 //! **== Zmq ==**
 //! - [ ] `getzmqnotifications`
@@ -160,15 +160,43 @@ mod wallet;
 #[doc(inline)]
 pub use self::{
     blockchain::{
-        Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBlockchainInfo, Softfork,
-        SoftforkType,
+        Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBlockchainInfo,
+        GetBlockchainInfoError, GetMempoolEntry, GetMempoolInfo, GetRawMempoolVerbose,
+        MempoolEntry, Softfork, SoftforkType,
+    },
+    wallet::{
+        GetBalances, GetBalancesMine, GetBalancesWatchOnly, GetTransaction, GetWalletInfo,
+        GetWalletInfoScanning,
     },
-    wallet::{GetBalances, GetBalancesMine, GetBalancesWatchOnly},
 };
 #[doc(inline)]
-pub use crate::v17::{
-    CreateWallet, GenerateToAddress, GetBalance, GetBestBlockHash, GetBlockVerbosityOne,
-    GetBlockVerbosityZero, GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork,
-    GetNewAddress, GetTransaction, GetTransactionDetail, GetTransactionDetailCategory, GetTxOut,
-    LoadWallet, SendRawTransaction, SendToAddress,
+pub use crate::{
+    v17::{
+        AddMultisigAddress, AddMultisigAddressError, AddressInformation, AddressPurpose,
+        Bip125Replaceable, BlockTemplateTransaction, BlockTemplateTransactionError, BumpFee,
+        BumpFeeError, ChainTips, ChainTipsStatus, CombinePsbt, CreateRawTransaction, CreateWallet,
+        DecodePsbt, DecodeRawTransaction, EncryptWallet, EstimateSmartFee, FinalizePsbt,
+        FundRawTransaction, FundRawTransactionError, GenerateToAddress, GetAddressInfo,
+        GetAddressInfoError, GetAddressesByLabel, GetBalance, GetBestBlockHash, GetBlockHash,
+        GetBlockHeader, GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockTemplate,
+        GetBlockTemplateError, GetBlockVerbosityOne, GetBlockVerbosityTwo, GetBlockVerbosityZero,
+        GetChainTips, GetMemoryInfoMallocInfo, GetMemoryInfoStats, GetMempoolInfoError,
+        GetMiningInfo, GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, GetNewAddress,
+        GetPeerInfo, GetRawChangeAddress, GetRawMempool, GetRawTransaction, GetReceivedByAddress,
+        GetTransactionDetail, GetTransactionDetailCategory, GetTransactionError, GetTxOut,
+        GetTxOutProof, GetTxOutSetInfo, GetTxOutSetInfoError, GetWalletInfoError, ImportMulti,
+        ImportMultiResult, JsonRpcError, ListLockUnspent, ListLockUnspentItem,
+        ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByAddressItemError,
+        ListSinceBlock, ListSinceBlockError, ListTransactions, ListUnspent, ListUnspentItem,
+        ListUnspentItemError, ListWallets, LoadWallet, Logging, MapMempoolEntryError,
+        MempoolAcceptance, MempoolEntryError, MempoolEntryFees, MempoolEntryFeesError, PeerInfo,
+        RescanBlockchain, ScanTxOutSetStart, ScanTxOutSetStartError, ScanTxOutSetStatus,
+        ScanTxOutSetUnspent, SendRawTransaction, SendToAddress, SignMessage, SignRawTransaction,
+        TestMempoolAccept, TransactionItem, TransactionItemError, VerifyTxOutProof,
+        WalletCreateFundedPsbt, WalletCreateFundedPsbtError, WalletProcessPsbt,
+    },
+    v18::{
+        DeriveAddresses, GetDescriptorInfo, GetDescriptorInfoError, GetNodeAddresses,
+        GetReceivedByLabel, ListWalletDir, ListWalletDirWallet, NodeAddress,
+    },
 };
@@ -5,10 +5,16 @@
 //! Types for methods found under the `== Wallet ==` section of the API docs.
 
 use bitcoin::amount::ParseAmountError;
-use bitcoin::Amount;
+use bitcoin::consensus::encode;
+use bitcoin::hashes::hash160;
+use bitcoin::{Amount, SignedAmount, Transaction, Txid};
 use serde::{Deserialize, Serialize};
 
 use crate::model;
+use crate::v17::{
+    Bip125Replaceable, DecodeRawTransaction, GetTransactionDetail, GetTransactionError,
+    GetWalletInfoError,
+};
 
 /// Result of the JSON-RPC method `getbalances`.
 ///
@@ -19,6 +25,8 @@ use crate::model;
 pub struct GetBalances {
     /// Balances from outputs that the wallet can sign.
     pub mine: GetBalancesMine,
+    /// Balances from watch-only addresses, only present for legacy wallets with watch-only
+    /// addresses.
     #[serde(rename = "watchonly")]
     pub watch_only: Option<GetBalancesWatchOnly>,
 }
@@ -38,7 +46,7 @@ pub struct GetBalancesMine {
     pub used: Option<f64>,
 }
 
-/// Hash and height of the block this information was generated on.
+/// Balances from watch-only addresses.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBalancesWatchOnly {
     /// Trusted balance (outputs created by the wallet or confirmed outputs).
@@ -59,7 +67,7 @@ impl GetBalances {
             None => None,
         };
 
-        Ok(model::GetBalances { mine, watch_only })
+        Ok(model::GetBalances { mine, watch_only, last_processed_block: None })
     }
 }
 
@@ -89,3 +97,172 @@ impl GetBalancesWatchOnly {
         Ok(model::GetBalancesWatchOnly { trusted, untrusted_pending, immature })
     }
 }
+
+/// Result of the JSON-RPC method `gettransaction`.
+///
+/// > gettransaction "txid" ( include_watchonly verbose )
+/// >
+/// > Get detailed information about in-wallet transaction `<txid>`
+/// >
+/// > Arguments:
+/// > 1. txid                 (string, required) The transaction id
+/// > 2. include_watchonly    (boolean, optional, default=true for watch-only wallets, otherwise false) Whether to include watch-only addresses in balance calculation and details[]
+/// > 3. verbose              (boolean, optional, default=false) Whether to include a `decoded` field containing the decoded transaction (equivalent to RPC decoderawtransaction)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTransaction {
+    pub amount: f64,
+    #[serde(default, deserialize_with = "crate::deserialize_optional_number_or_string")]
+    pub fee: Option<f64>,
+    pub confirmations: u32,
+    pub txid: String,
+    pub time: u64,
+    #[serde(rename = "timereceived")]
+    pub time_received: u64,
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: Bip125Replaceable,
+    pub details: Vec<GetTransactionDetail>,
+    pub hex: String,
+    /// The decoded transaction, only present if `verbose` is set.
+    ///
+    /// Not included in the model, it is the same transaction as `hex`.
+    pub decoded: Option<DecodeRawTransaction>,
+}
+
+impl GetTransaction {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTransaction, GetTransactionError> {
+        use GetTransactionError as E;
+
+        let amount = SignedAmount::from_btc(self.amount).map_err(E::Amount)?;
+        let fee = self.fee.map(SignedAmount::from_btc).transpose().map_err(E::Fee)?;
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+
+        let tx = encode::deserialize_hex::<Transaction>(&self.hex).map_err(E::Tx)?;
+        let details = self
+            .details
+            .into_iter()
+            .map(|detail| detail.into_model())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Details)?;
+
+        Ok(model::GetTransaction {
+            amount,
+            fee,
+            confirmations: self.confirmations,
+            txid,
+            time: self.time,
+            time_received: self.time_received,
+            bip125_replaceable: self.bip125_replaceable.into_model(),
+            details,
+            tx,
+        })
+    }
+}
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: u32,
+    /// The total confirmed balance of the wallet in BTC.
+    pub balance: f64,
+    /// The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: f64,
+    /// The total immature balance of the wallet in BTC.
+    pub immature_balance: f64,
+    /// The total number of transactions in the wallet
+    #[serde(rename = "txcount")]
+    pub tx_count: u32,
+    /// The timestamp (seconds since Unix epoch) of the oldest pre-generated key in the key pool.
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: Option<u32>,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: u32,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    #[serde(rename = "keypoolsize_hd_internal")]
+    pub keypool_size_hd_internal: Option<u32>,
+    /// The timestamp in seconds since epoch (midnight Jan 1 1970 GMT) that the wallet is unlocked
+    /// for transfers, or 0 if the wallet is locked.
+    pub unlocked_until: Option<u32>,
+    /// The transaction fee configuration, set in BTC/kB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse.
+    pub avoid_reuse: bool,
+    /// Current scanning details, or false if no scan is in progress.
+    pub scanning: GetWalletInfoScanning,
+}
+
+/// The `scanning` field of the JSON-RPC method `getwalletinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum GetWalletInfoScanning {
+    /// A scan is in progress.
+    Details {
+        /// Elapsed seconds since scan start.
+        duration: u64,
+        /// Scanning progress percentage [0.0, 1.0].
+        progress: f64,
+    },
+    /// No scan is in progress (always `false`).
+    NotScanning(bool),
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let balance = Amount::from_btc(self.balance).map_err(E::Balance)?;
+        let unconfirmed_balance = Amount::from_btc(self.unconfirmed_balance).map_err(E::Balance)?;
+        let immature_balance = Amount::from_btc(self.immature_balance).map_err(E::Balance)?;
+        let pay_tx_fee = crate::fee_rate_from_btc_per_kvb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version: self.wallet_version,
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count: self.tx_count,
+            keypool_oldest: self.keypool_oldest,
+            keypool_size: self.keypool_size,
+            keypool_size_hd_internal: self.keypool_size_hd_internal,
+            unlocked_until: self.unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            descriptors: false,
+            avoid_reuse: Some(self.avoid_reuse),
+            scanning: Some(self.scanning.is_scanning()),
+            external_signer: None,
+        })
+    }
+}
+
+impl GetWalletInfoScanning {
+    /// Returns true if a scan is in progress.
+    pub fn is_scanning(&self) -> bool {
+        match *self {
+            GetWalletInfoScanning::Details { .. } => true,
+            GetWalletInfoScanning::NotScanning(scanning) => scanning,
+        }
+    }
+}
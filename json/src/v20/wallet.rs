@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.20.2 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use bitcoin::{Address, ScriptBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::AddMultisigAddressError;
+
+/// Result of the JSON-RPC method `addmultisigaddress`.
+///
+/// > addmultisigaddress nrequired ["key",...] ( "label" "address_type" )
+/// >
+/// > Add a nrequired-to-sign multisignature address to the wallet. Requires a new wallet backup.
+/// > Each key is a Bitcoin address or hex-encoded public key.
+/// > This functionality is only intended for use with non-watchonly addresses.
+/// > See `importaddress` for watchonly p2sh address support.
+/// > If 'label' is specified, assign address to that label.
+/// >
+/// > Arguments:
+/// > 1. nrequired        (numeric, required) The number of required signatures out of the n keys or addresses.
+/// > 2. keys             (json array, required) The bitcoin addresses or hex-encoded public keys
+/// > 3. label            (string, optional) A label to assign the addresses to.
+/// > 4. address_type     (string, optional, default=set by -addresstype) The address type to use. Options are "legacy", "p2sh-segwit", and "bech32".
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddMultisigAddress {
+    /// The value of the new multisig address.
+    pub address: String,
+    /// The string value of the hex-encoded redemption script.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: String,
+    /// The descriptor for this multisig.
+    pub descriptor: String,
+}
+
+impl AddMultisigAddress {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::AddMultisigAddress, AddMultisigAddressError> {
+        use AddMultisigAddressError as E;
+
+        let address = self.address.parse::<Address<_>>().map_err(E::Address)?;
+        let redeem_script = ScriptBuf::from_hex(&self.redeem_script).map_err(E::RedeemScript)?;
+
+        Ok(model::AddMultisigAddress { address, redeem_script, descriptor: Some(self.descriptor) })
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21.2 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use std::collections::BTreeMap;
+
+use bitcoin::{Amount, BlockHash, Txid, Weight, Wtxid};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::{
+    GetMempoolInfoError, GetTxOutSetInfoError, MapMempoolEntryError, MempoolEntryError,
+    MempoolEntryFees, ScanTxOutSetStartError, ScanTxOutSetUnspent,
+};
+
+/// Result of JSON-RPC method `getindexinfo`.
+///
+/// > getindexinfo ( "index_name" )
+/// >
+/// > Returns the status of one or all available indices currently running in the node.
+/// >
+/// > Arguments:
+/// > 1. index_name    (string, optional) Filter results for an index with a specific name.
+///
+/// Maps index name (e.g. "txindex", "coinstatsindex") to its status, only contains indices that
+/// are enabled.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetIndexInfo(pub BTreeMap<String, IndexInfo>);
+
+/// The status of an index, part of `GetIndexInfo`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IndexInfo {
+    /// Whether the index is synced or not.
+    pub synced: bool,
+    /// The block height to which the index is synced.
+    pub best_block_height: u64,
+}
+
+impl GetIndexInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::GetIndexInfo {
+        let map = self.0.into_iter().map(|(name, info)| (name, info.into_model())).collect();
+        model::GetIndexInfo(map)
+    }
+}
+
+impl IndexInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::IndexInfo {
+        model::IndexInfo { synced: self.synced, best_block_height: self.best_block_height }
+    }
+}
+
+/// Result of JSON-RPC method `getrawmempool` with verbose set to `true`.
+///
+/// Map of txid to [`MempoolEntry`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawMempoolVerbose(pub BTreeMap<String, MempoolEntry>);
+
+impl GetRawMempoolVerbose {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawMempoolVerbose, MapMempoolEntryError> {
+        use MapMempoolEntryError as E;
+
+        let mut map = BTreeMap::new();
+        for (k, v) in self.0.into_iter() {
+            let txid = k.parse::<Txid>().map_err(E::Txid)?;
+            let entry = v.into_model().map_err(E::MempoolEntry)?;
+            map.insert(txid, entry);
+        }
+        Ok(model::GetRawMempoolVerbose(map))
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolentry`.
+///
+/// > getmempoolentry txid
+/// >
+/// > Returns mempool data for given transaction
+/// >
+/// > Arguments:
+/// > 1. txid    (string, required) The transaction id (must be in mempool)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolEntry(pub MempoolEntry);
+
+impl GetMempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolEntry, MempoolEntryError> {
+        Ok(model::GetMempoolEntry(self.0.into_model()?))
+    }
+}
+
+/// A transaction in the mempool.
+///
+/// Used by `getmempoolentry` and `getrawmempool` with verbose set to `true`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MempoolEntry {
+    /// Virtual transaction size as defined in BIP 141.
+    ///
+    /// This is different from actual serialized size for witness transactions as witness data is
+    /// discounted.
+    pub vsize: u64,
+    /// Transaction weight as defined in BIP 141.
+    pub weight: u64,
+    /// Virtual transaction size (DEPRECATED).
+    pub size: Option<u64>,
+    /// Transaction fee in BTC (DEPRECATED).
+    #[serde(default, deserialize_with = "crate::deserialize_optional_number_or_string")]
+    pub fee: Option<f64>,
+    /// Transaction fee with fee deltas used for mining priority (DEPRECATED).
+    #[serde(rename = "modifiedfee")]
+    pub modified_fee: Option<f64>,
+    /// Local time transaction entered pool in seconds since 1 Jan 1970 GMT.
+    pub time: u64,
+    /// Block height when transaction entered pool.
+    pub height: u64,
+    /// Number of in-mempool descendant transactions (including this one).
+    #[serde(rename = "descendantcount")]
+    pub descendant_count: u64,
+    /// Virtual transaction size of in-mempool descendants (including this one).
+    #[serde(rename = "descendantsize")]
+    pub descendant_size: u64,
+    /// Modified fees (see above) of in-mempool descendants (including this one) (DEPRECATED).
+    #[serde(rename = "descendantfees")]
+    pub descendant_fees: Option<u64>,
+    /// Number of in-mempool ancestor transactions (including this one).
+    #[serde(rename = "ancestorcount")]
+    pub ancestor_count: u64,
+    /// Virtual transaction size of in-mempool ancestors (including this one).
+    #[serde(rename = "ancestorsize")]
+    pub ancestor_size: u64,
+    /// Modified fees (see above) of in-mempool ancestors (including this one) (DEPRECATED).
+    #[serde(rename = "ancestorfees")]
+    pub ancestor_fees: Option<u64>,
+    /// Hash of serialized transaction, including witness data.
+    pub wtxid: String,
+    /// Fee object which contains the base fee, modified fee (with fee deltas), and ancestor/descendant fee totals all in BTC.
+    pub fees: MempoolEntryFees,
+    /// Unconfirmed transactions used as inputs for this transaction (parent transaction id).
+    pub depends: Vec<String>,
+    /// Used by unconfirmed transactions spending outputs from this transaction (child transaction id).
+    #[serde(rename = "spentby")]
+    pub spent_by: Vec<String>,
+    /// Whether this transaction could be replaced due to BIP125 (replace-by-fee)
+    #[serde(rename = "bip125-replaceable")]
+    pub bip125_replaceable: bool,
+    /// Whether this transaction is currently unbroadcast (initial broadcast not yet acknowledged by
+    /// any peers).
+    pub unbroadcast: bool,
+}
+
+impl MempoolEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::MempoolEntry, MempoolEntryError> {
+        use MempoolEntryError as E;
+
+        let wtxid = self.wtxid.parse::<Wtxid>().map_err(E::Wtxid)?;
+        let fees = self.fees.into_model().map_err(E::Fees)?;
+        let depends = self
+            .depends
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::Depends)?;
+        let spent_by = self
+            .spent_by
+            .iter()
+            .map(|txid| txid.parse::<Txid>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(E::SpentBy)?;
+
+        Ok(model::MempoolEntry {
+            vsize: self.vsize,
+            weight: Some(Weight::from_wu(self.weight)),
+            time: self.time,
+            height: self.height,
+            descendant_count: self.descendant_count,
+            descendant_size: self.descendant_size,
+            ancestor_count: self.ancestor_count,
+            ancestor_size: self.ancestor_size,
+            wtxid,
+            fees,
+            depends,
+            spent_by,
+            bip125_replaceable: Some(self.bip125_replaceable),
+            unbroadcast: Some(self.unbroadcast),
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getmempoolinfo`.
+///
+/// > getmempoolinfo
+/// >
+/// > Returns details on the active state of the TX memory pool.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolInfo {
+    /// True if the mempool is fully loaded.
+    pub loaded: bool,
+    /// Current tx count.
+    pub size: u64,
+    /// Sum of all virtual transaction sizes as defined in BIP 141.
+    ///
+    /// Differs from actual serialized size because witness data is discounted.
+    pub bytes: u64,
+    /// Total memory usage for the mempool.
+    pub usage: u64,
+    /// Maximum memory usage for the mempool.
+    #[serde(rename = "maxmempool")]
+    pub max_mempool: u64,
+    /// Minimum fee rate in BTC/kB for a transaction to be accepted.
+    ///
+    /// This is the maximum of `minrelaytxfee` and the minimum mempool fee.
+    #[serde(rename = "mempoolminfee")]
+    pub mempool_min_fee: f64,
+    /// Current minimum relay fee for transactions.
+    #[serde(rename = "minrelaytxfee")]
+    pub min_relay_tx_fee: f64,
+    /// Current number of transactions that haven't passed initial broadcast yet.
+    #[serde(rename = "unbroadcastcount")]
+    pub unbroadcast_count: u64,
+}
+
+impl GetMempoolInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolInfo, GetMempoolInfoError> {
+        use GetMempoolInfoError as E;
+
+        let mempool_min_fee =
+            crate::fee_rate_from_btc_per_kvb(self.mempool_min_fee).map_err(E::MempoolMinFee)?;
+        let min_relay_tx_fee =
+            crate::fee_rate_from_btc_per_kvb(self.min_relay_tx_fee).map_err(E::MinRelayTxFee)?;
+
+        Ok(model::GetMempoolInfo {
+            loaded: Some(self.loaded),
+            size: self.size,
+            bytes: self.bytes,
+            usage: self.usage,
+            total_fee: None,
+            max_mempool: self.max_mempool,
+            mempool_min_fee,
+            min_relay_tx_fee,
+            incremental_relay_fee: None,
+            unbroadcast_count: Some(self.unbroadcast_count),
+            full_rbf: None,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo ( "hash_type" )
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time.
+/// >
+/// > Arguments:
+/// > 1. hash_type    (string, optional, default=hash_serialized_2) Which UTXO set hash should be calculated. Options: 'hash_serialized_2' (the legacy algorithm), 'muhash', 'none'.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The current block height (index).
+    pub height: u64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of transactions with unspent outputs.
+    pub transactions: u64,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// A meaningless metric for UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: u64,
+    /// The serialized hash (only present if 'hash_serialized_2' hash_type is chosen).
+    pub hash_serialized_2: Option<String>,
+    /// The serialized hash (only present if 'muhash' hash_type is chosen).
+    pub muhash: Option<String>,
+    /// The estimated size of the chainstate on disk.
+    pub disk_size: u64,
+    /// The total amount.
+    pub total_amount: f64,
+}
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height: self.height,
+            best_block,
+            transactions: Some(self.transactions),
+            tx_outs: self.tx_outs,
+            bogo_size: self.bogo_size,
+            hash_serialized: self.hash_serialized_2,
+            muhash: self.muhash,
+            disk_size: Some(self.disk_size),
+            total_amount,
+            total_unspendable_amount: None,
+            block_info: None,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `scantxoutset` with action "start".
+///
+/// > scantxoutset "action" ( [scanobjects,...] )
+/// >
+/// > EXPERIMENTAL warning: this call may be removed or changed in future releases.
+/// >
+/// > Scans the unspent transaction output set for entries that match certain output descriptors.
+/// >
+/// > Arguments:
+/// > 1. action                        (string, required) The action to execute
+/// >                                  "start" for starting a scan
+/// >                                  "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                  "status" for progress report (in %) of the current scan
+/// > 2. scanobjects                   (json array) Array of scan objects. Required for "start" action
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScanTxOutSetStart {
+    /// Whether the scan completed (false if it was aborted).
+    pub success: bool,
+    /// The number of unspent transaction outputs scanned.
+    pub txouts: u64,
+    /// The current block height (index).
+    pub height: u64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The unspent transaction outputs that matched a scan object.
+    pub unspents: Vec<ScanTxOutSetUnspent>,
+    /// The total amount of all found unspent outputs in BTC.
+    pub total_amount: f64,
+}
+
+impl ScanTxOutSetStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanTxOutSetStart, ScanTxOutSetStartError> {
+        use ScanTxOutSetStartError as E;
+
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let unspents =
+            self.unspents.into_iter().map(|u| u.into_model()).collect::<Result<Vec<_>, _>>()?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::ScanTxOutSetStart {
+            success: self.success,
+            txouts: self.txouts,
+            height: Some(self.height),
+            best_block: Some(best_block),
+            unspents,
+            total_amount,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21.2 - generating.
+//!
+//! Types for methods found under the `== Generating ==` section of the API docs.
+
+use bitcoin::{hex, BlockHash};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `generateblock`.
+///
+/// > generateblock "output" ["rawtx/txid",...]
+/// >
+/// > Mine a block with a set of ordered transactions immediately to a specified address or descriptor (before the RPC call returns)
+/// >
+/// > Arguments:
+/// > 1. output               (string, required) The address or descriptor to send the newly generated bitcoin to.
+/// > 2. transactions         (json array, required) An array of hex strings which are either txids or raw transactions.
+/// >                         Txids must reference transactions currently in the mempool.
+/// >                         All transactions must be valid and in valid order, otherwise the block will be rejected.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GenerateBlock {
+    /// Hash of generated block.
+    pub hash: String,
+}
+
+impl GenerateBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GenerateBlock, hex::HexToArrayError> {
+        let hash = self.hash.parse::<BlockHash>()?;
+        Ok(model::GenerateBlock { hash })
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21.2 - network.
+//!
+//! Types for methods found under the `== Network ==` section of the API docs.
+
+use core::num::ParseIntError;
+
+use bitcoin::p2p::ServiceFlags;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `getpeerinfo`.
+///
+/// > getpeerinfo
+/// >
+/// > Returns data about each connected network node as a json array of objects.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+/// An item from the list returned by the JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u64,
+    /// The IP address and port of the peer ("host:port").
+    pub addr: String,
+    /// Bind address of the connection to the peer ("ip:port").
+    #[serde(rename = "addrbind")]
+    pub addr_bind: Option<String>,
+    /// Local address as reported by the peer.
+    #[serde(rename = "addrlocal")]
+    pub addr_local: Option<String>,
+    /// Network (ipv4, ipv6, or onion) the peer connected through.
+    pub network: String,
+    /// The services offered (hex string).
+    pub services: String,
+    /// Whether peer has asked us to relay transactions to it.
+    #[serde(rename = "relaytxes")]
+    pub relay_txes: bool,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last send.
+    #[serde(rename = "lastsend")]
+    pub last_send: u64,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last receive.
+    #[serde(rename = "lastrecv")]
+    pub last_recv: u64,
+    /// The total bytes sent.
+    #[serde(rename = "bytessent")]
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    #[serde(rename = "bytesrecv")]
+    pub bytes_recv: u64,
+    /// The connection time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "conntime")]
+    pub connection_time: u64,
+    /// The time offset in seconds.
+    #[serde(rename = "timeoffset")]
+    pub time_offset: i64,
+    /// Ping time (if available).
+    #[serde(rename = "pingtime")]
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time (if any at all).
+    #[serde(rename = "minping")]
+    pub minimum_ping: Option<f64>,
+    /// Ping wait (if non-zero).
+    #[serde(rename = "pingwait")]
+    pub ping_wait: Option<f64>,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The string version.
+    #[serde(rename = "subver")]
+    pub subversion: String,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// Whether connection was due to `addnode`/`-connect` or if it was an automatic/inbound
+    /// connection.
+    ///
+    /// DEPRECATED, only returned if `bitcoind` is started with `-deprecatedrpc=getpeerinfo_addnode`.
+    #[serde(rename = "addnode")]
+    pub add_node: Option<bool>,
+    /// Type of connection.
+    pub connection_type: String,
+    /// The starting height (block) of the peer.
+    #[serde(rename = "startingheight")]
+    pub starting_height: i64,
+    /// The ban score.
+    ///
+    /// DEPRECATED, only returned if `bitcoind` is started with `-deprecatedrpc=banscore`.
+    #[serde(rename = "banscore")]
+    pub ban_score: Option<i64>,
+    /// The last header we have in common with this peer.
+    pub synced_headers: i64,
+    /// The last block we have in common with this peer.
+    pub synced_blocks: i64,
+    /// The heights of blocks we're currently asking from this peer.
+    pub inflight: Vec<u64>,
+    /// Any special permissions that have been granted to this peer.
+    pub permissions: Vec<String>,
+    /// Whether the peer is whitelisted.
+    ///
+    /// DEPRECATED, only returned if `bitcoind` is started with
+    /// `-deprecatedrpc=getpeerinfo_whitelisted`.
+    pub whitelisted: Option<bool>,
+}
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, ParseIntError> {
+        let peers = self.0.into_iter().map(|peer| peer.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::GetPeerInfo(peers))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, ParseIntError> {
+        let services = u64::from_str_radix(&self.services, 16).map(ServiceFlags::from)?;
+
+        Ok(model::PeerInfo {
+            id: self.id,
+            addr: model::PeerAddress::parse(&self.addr),
+            addr_bind: self.addr_bind.as_deref().and_then(model::PeerAddress::parse_optional),
+            addr_local: self.addr_local.as_deref().and_then(model::PeerAddress::parse_optional),
+            network: Some(model::NetworkType::from_core_arg(&self.network)),
+            services,
+            relay_txes: self.relay_txes,
+            bytes_sent: self.bytes_sent,
+            bytes_recv: self.bytes_recv,
+            inbound: self.inbound,
+            connection_time: self.connection_time,
+            version: self.version,
+            subversion: self.subversion,
+            starting_height: self.starting_height,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            connection_type: Some(self.connection_type),
+            permissions: self.permissions,
+            bip152_hb_to: None,
+            bip152_hb_from: None,
+            addr_relay_enabled: None,
+            transport_protocol_type: None,
+            ban_score: self.ban_score,
+            whitelisted: self.whitelisted,
+            addnode: self.add_node,
+        })
+    }
+}
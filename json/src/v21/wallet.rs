@@ -0,0 +1,310 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v0.21.2 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use std::fmt;
+
+use bitcoin::amount::ParseAmountError;
+use bitcoin::consensus::encode;
+use bitcoin::hashes::hash160;
+use bitcoin::psbt::PsbtParseError;
+use bitcoin::{hex, Amount, Psbt, Transaction, Txid};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::{GetWalletInfoError, JsonRpcError};
+use crate::v19::GetWalletInfoScanning;
+
+/// Result of the JSON-RPC method `psbtbumpfee`.
+///
+/// > psbtbumpfee "txid" ( options )
+/// >
+/// > Bumps the fee of an opt-in-RBF transaction T, replacing it with a new transaction B.
+/// > Returns a PSBT instead of creating and signing a new transaction.
+/// > An opt-in RBF transaction with the given txid must be in the wallet.
+/// > The command will pay the additional fee by reducing change outputs or adding inputs when necessary.
+/// > It may add a new change output if one does not already exist.
+/// > All inputs in the original transaction will be included in the replacement transaction.
+/// > The command will fail if the wallet or mempool contains a transaction that spends one of T's outputs.
+/// > By default, the new fee will be calculated automatically using the estimatesmartfee RPC.
+/// > The user can specify a confirmation target for estimatesmartfee.
+/// > Alternatively, the user can specify a fee rate in sat/vB for the new transaction.
+/// > At a minimum, the new fee rate must be high enough to pay an additional new relay fee (incrementalfee
+/// > returned by getnetworkinfo) to enter the node's mempool.
+/// >
+/// > Arguments:
+/// > 1. txid                           (string, required) The txid to be bumped
+/// > 2. options                        (json object, optional)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PsbtBumpFee {
+    /// The base64-encoded unsigned PSBT of the new transaction.
+    pub psbt: String,
+    /// Fee of the replaced transaction.
+    #[serde(rename = "origfee")]
+    pub original_fee: f64,
+    /// Fee of the new transaction.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub fee: f64,
+    /// Errors encountered during processing (may be empty).
+    pub errors: Vec<String>,
+}
+
+impl PsbtBumpFee {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PsbtBumpFee, PsbtBumpFeeError> {
+        use PsbtBumpFeeError as E;
+
+        let psbt = self.psbt.parse::<Psbt>().map_err(E::Psbt)?;
+        let original_fee = Amount::from_btc(self.original_fee).map_err(E::OriginalFee)?;
+        let fee = Amount::from_btc(self.fee).map_err(E::Fee)?;
+
+        Ok(model::PsbtBumpFee { psbt, original_fee, fee, errors: self.errors })
+    }
+}
+
+/// Error when converting a `PsbtBumpFee` type into the model type.
+#[derive(Debug)]
+pub enum PsbtBumpFeeError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the `original_fee` field failed.
+    OriginalFee(ParseAmountError),
+    /// Conversion of the `fee` field failed.
+    Fee(ParseAmountError),
+}
+
+impl fmt::Display for PsbtBumpFeeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PsbtBumpFeeError as E;
+
+        match *self {
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            E::OriginalFee(ref e) =>
+                write_err!(f, "conversion of the `original_fee` field failed"; e),
+            E::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for PsbtBumpFeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use PsbtBumpFeeError as E;
+
+        match *self {
+            E::Psbt(ref e) => Some(e),
+            E::OriginalFee(ref e) => Some(e),
+            E::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `importdescriptors`.
+///
+/// > importdescriptors "requests"
+/// >
+/// > Import descriptors. This will trigger a rescan of the blockchain based on the earliest timestamp of all descriptors being imported. Requires a new wallet backup.
+/// >
+/// > Note: This call can take over an hour to complete if using an early timestamp; during that time, other rpc calls
+/// > may report that the imported keys, addresses or scripts exist but related transactions are still missing.
+/// >
+/// > Arguments:
+/// > 1. requests                                 (json array, required) Data to be imported
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptors(pub Vec<ImportDescriptorsResult>);
+
+/// The result of importing a single descriptor, part of `ImportDescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptorsResult {
+    /// Whether the descriptor was imported.
+    pub success: bool,
+    /// Warnings encountered while importing.
+    pub warnings: Option<Vec<String>>,
+    /// The error, if the descriptor was not imported.
+    pub error: Option<JsonRpcError>,
+}
+
+impl ImportDescriptors {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportDescriptors {
+        model::ImportDescriptors(self.0.into_iter().map(|r| r.into_model()).collect())
+    }
+}
+
+impl ImportDescriptorsResult {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportDescriptorsResult {
+        model::ImportDescriptorsResult {
+            success: self.success,
+            warnings: self.warnings.unwrap_or_default(),
+            error: self.error.map(|e| e.into_model()),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `send`.
+///
+/// > send [{"address":amount},{"data":"hex"},...] ( conf_target "estimate_mode" fee_rate options )
+/// >
+/// > EXPERIMENTAL warning: this call may be changed in future releases.
+/// >
+/// > Send a transaction.
+/// >
+/// > Arguments:
+/// > 1. outputs        (json array, required) A list of outputs, each a {"address":amount} or {"data":"hex"} object
+/// > 2. conf_target    (numeric, optional, default=wallet -txconfirmtarget) Confirmation target in blocks
+/// > 3. estimate_mode  (string, optional, default=unset) The fee estimate mode, must be one of (case insensitive): "unset" "economical" "conservative"
+/// > 4. fee_rate       (numeric or string, optional, default=not set, falls back to wallet fee estimation) Specify a fee rate in sat/vB.
+/// > 5. options        (json object, optional)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Send {
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The transaction id for the send, only if the transaction is complete and added to the
+    /// wallet.
+    pub txid: Option<String>,
+    /// If `add_to_wallet` is false, the hex-encoded raw transaction with signature(s).
+    pub hex: Option<String>,
+    /// If more signatures are needed, or if `add_to_wallet` is false, the base64-encoded
+    /// (partially) signed transaction.
+    pub psbt: Option<String>,
+}
+
+impl Send {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::Send, SendError> {
+        use SendError as E;
+
+        let txid = self.txid.map(|s| s.parse::<Txid>()).transpose().map_err(E::Txid)?;
+        let tx = self
+            .hex
+            .map(|s| encode::deserialize_hex::<Transaction>(&s))
+            .transpose()
+            .map_err(E::Hex)?;
+        let psbt = self.psbt.map(|s| s.parse::<Psbt>()).transpose().map_err(E::Psbt)?;
+
+        Ok(model::Send { complete: self.complete, txid, tx, psbt })
+    }
+}
+
+/// Error when converting a `Send` type into the model type.
+#[derive(Debug)]
+pub enum SendError {
+    /// Conversion of the `txid` field failed.
+    Txid(hex::HexToArrayError),
+    /// Conversion of the `hex` field failed.
+    Hex(encode::FromHexError),
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SendError as E;
+
+        match *self {
+            E::Txid(ref e) => write_err!(f, "conversion of the `txid` field failed"; e),
+            E::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for SendError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SendError as E;
+
+        match *self {
+            E::Txid(ref e) => Some(e),
+            E::Hex(ref e) => Some(e),
+            E::Psbt(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: u32,
+    /// The total confirmed balance of the wallet in BTC.
+    pub balance: f64,
+    /// The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: f64,
+    /// The total immature balance of the wallet in BTC.
+    pub immature_balance: f64,
+    /// The total number of transactions in the wallet
+    #[serde(rename = "txcount")]
+    pub tx_count: u32,
+    /// The timestamp (seconds since Unix epoch) of the oldest pre-generated key in the key pool.
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: Option<u32>,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: u32,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    #[serde(rename = "keypoolsize_hd_internal")]
+    pub keypool_size_hd_internal: Option<u32>,
+    /// The timestamp in seconds since epoch (midnight Jan 1 1970 GMT) that the wallet is unlocked
+    /// for transfers, or 0 if the wallet is locked.
+    pub unlocked_until: Option<u32>,
+    /// The transaction fee configuration, set in BTC/kB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse.
+    pub avoid_reuse: bool,
+    /// Current scanning details, or false if no scan is in progress.
+    pub scanning: GetWalletInfoScanning,
+    /// Whether this wallet uses descriptors for scriptPubKey management.
+    pub descriptors: bool,
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let balance = Amount::from_btc(self.balance).map_err(E::Balance)?;
+        let unconfirmed_balance = Amount::from_btc(self.unconfirmed_balance).map_err(E::Balance)?;
+        let immature_balance = Amount::from_btc(self.immature_balance).map_err(E::Balance)?;
+        let pay_tx_fee = crate::fee_rate_from_btc_per_kvb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version: self.wallet_version,
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count: self.tx_count,
+            keypool_oldest: self.keypool_oldest,
+            keypool_size: self.keypool_size,
+            keypool_size_hd_internal: self.keypool_size_hd_internal,
+            unlocked_until: self.unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            descriptors: self.descriptors,
+            avoid_reuse: Some(self.avoid_reuse),
+            scanning: Some(self.scanning.is_scanning()),
+            external_signer: None,
+        })
+    }
+}
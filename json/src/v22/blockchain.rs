@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v22.1 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use bitcoin::{Amount, BlockHash};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::GetTxOutSetInfoError;
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo ( "hash_type" hash_or_height use_index )
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time if you are not using coinstatsindex.
+/// >
+/// > Arguments:
+/// > 1. hash_type         (string, optional, default=hash_serialized_2) Which UTXO set hash should be calculated. Options: 'hash_serialized_2' (the legacy algorithm), 'muhash', 'none'.
+/// > 2. hash_or_height    (string or numeric, optional) The block hash or height of the target height (only available with coinstatsindex).
+/// > 3. use_index         (boolean, optional, default=true) Use coinstatsindex, if available.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The block height (index) of the returned statistics.
+    pub height: u64,
+    /// The hash of the block at which these statistics are calculated.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// Database-independent, meaningless metric indicating the UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: u64,
+    /// The serialized hash (only present if 'hash_serialized_2' hash_type is chosen).
+    pub hash_serialized_2: Option<String>,
+    /// The serialized hash (only present if 'muhash' hash_type is chosen).
+    pub muhash: Option<String>,
+    /// The number of transactions with unspent outputs (not available when coinstatsindex is
+    /// used).
+    pub transactions: Option<u64>,
+    /// The estimated size of the chainstate on disk (not available when coinstatsindex is used).
+    pub disk_size: Option<u64>,
+    /// The total amount of coins in the UTXO set.
+    pub total_amount: f64,
+    /// The total amount of coins permanently excluded from the UTXO set (only available if
+    /// coinstatsindex is used).
+    pub total_unspendable_amount: Option<f64>,
+    /// Info on amounts in the block at this block height (only available if coinstatsindex is
+    /// used).
+    pub block_info: Option<TxOutSetBlockInfo>,
+}
+
+/// Amounts in the block at the height of `GetTxOutSetInfo`, only available with coinstatsindex.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TxOutSetBlockInfo {
+    /// Total amount of all prevouts spent in this block.
+    pub prevout_spent: f64,
+    /// Coinbase subsidy amount of this block.
+    pub coinbase: f64,
+    /// Total amount of new outputs created by this block.
+    pub new_outputs_ex_coinbase: f64,
+    /// Total amount of unspendable outputs created in this block.
+    pub unspendable: f64,
+    /// Detailed view of the unspendable categories.
+    pub unspendables: TxOutSetUnspendables,
+}
+
+/// The unspendable amounts of a `TxOutSetBlockInfo`, by category.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TxOutSetUnspendables {
+    /// The unspendable amount of the Genesis block subsidy.
+    pub genesis_block: f64,
+    /// Transactions overridden by duplicates (no longer possible with BIP30).
+    pub bip30: f64,
+    /// Amounts sent to scripts that are unspendable (for example OP_RETURN outputs).
+    pub scripts: f64,
+    /// Fee rewards that miners did not claim in their coinbase transaction.
+    pub unclaimed_rewards: f64,
+}
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+        let total_unspendable_amount = self
+            .total_unspendable_amount
+            .map(Amount::from_btc)
+            .transpose()
+            .map_err(E::TotalUnspendableAmount)?;
+        let block_info =
+            self.block_info.map(|info| info.into_model()).transpose().map_err(E::BlockInfo)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height: self.height,
+            best_block,
+            transactions: self.transactions,
+            tx_outs: self.tx_outs,
+            bogo_size: self.bogo_size,
+            hash_serialized: self.hash_serialized_2,
+            muhash: self.muhash,
+            disk_size: self.disk_size,
+            total_amount,
+            total_unspendable_amount,
+            block_info,
+        })
+    }
+}
+
+impl TxOutSetBlockInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::TxOutSetBlockInfo, bitcoin::amount::ParseAmountError> {
+        let u = self.unspendables;
+        Ok(model::TxOutSetBlockInfo {
+            prevout_spent: Amount::from_btc(self.prevout_spent)?,
+            coinbase: Amount::from_btc(self.coinbase)?,
+            new_outputs_ex_coinbase: Amount::from_btc(self.new_outputs_ex_coinbase)?,
+            unspendable: Amount::from_btc(self.unspendable)?,
+            unspendables: model::TxOutSetUnspendables {
+                genesis_block: Amount::from_btc(u.genesis_block)?,
+                bip30: Amount::from_btc(u.bip30)?,
+                scripts: Amount::from_btc(u.scripts)?,
+                unclaimed_rewards: Amount::from_btc(u.unclaimed_rewards)?,
+            },
+        })
+    }
+}
@@ -4,9 +4,13 @@
 //!
 //! Types for methods found under the `== Wallet ==` section of the API docs.
 
+use std::str::FromStr;
+
+use bitcoin::{Address, Amount, ScriptBuf, Txid};
 use serde::{Deserialize, Serialize};
 
 use crate::model;
+use crate::v17::ListUnspentItemError;
 
 /// Result of the JSON-RPC method `unloadwallet`.
 ///
@@ -30,3 +34,170 @@ impl UnloadWallet {
         model::UnloadWallet { warnings: vec![self.warning] }
     }
 }
+
+/// Result of the JSON-RPC method `listunspent`.
+///
+/// > listunspent ( minconf maxconf ["address",...] include_unsafe query_options )
+/// >
+/// > Returns array of unspent transaction outputs
+/// > with between minconf and maxconf (inclusive) confirmations.
+/// > Optionally filter to only include txouts paid to specified addresses.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspent(pub Vec<ListUnspentItem>);
+
+/// An unspent transaction output, part of `listunspent`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListUnspentItem {
+    /// The transaction id.
+    pub txid: String,
+    /// The vout value.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub vout: u32,
+    /// The bitcoin address of the transaction.
+    pub address: Option<String>,
+    /// The associated label, or "" for the default label.
+    pub label: Option<String>,
+    /// The script key.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: String,
+    /// The transaction output amount in BTC.
+    pub amount: f64,
+    /// The number of confirmations.
+    pub confirmations: u32,
+    /// The number of in-mempool ancestor transactions, including this one (if transaction is in the mempool).
+    #[serde(rename = "ancestorcount")]
+    pub ancestor_count: Option<u32>,
+    /// The virtual transaction size of in-mempool ancestors, including this one (if transaction is in the mempool).
+    #[serde(rename = "ancestorsize")]
+    pub ancestor_size: Option<u64>,
+    /// The total fees of in-mempool ancestors (including this one) with fee deltas used for mining priority in sat (if transaction is in the mempool).
+    #[serde(rename = "ancestorfees")]
+    pub ancestor_fees: Option<u64>,
+    /// The redeemScript if scriptPubKey is P2SH.
+    #[serde(rename = "redeemScript")]
+    pub redeem_script: Option<String>,
+    /// witnessScript if the scriptPubKey is P2WSH or P2SH-P2WSH.
+    #[serde(rename = "witnessScript")]
+    pub witness_script: Option<String>,
+    /// Whether we have the private keys to spend this output.
+    pub spendable: bool,
+    /// Whether we know how to spend this output, ignoring the lack of keys.
+    pub solvable: bool,
+    /// (only present if avoid_reuse is set) Whether this output is reused/dirty (sent to an address that was previously spent from).
+    pub reused: Option<bool>,
+    /// (only when solvable) A descriptor for spending this output.
+    pub desc: Option<String>,
+    /// List of parent descriptors for the scriptPubKey of this coin (v24 onwards).
+    pub parent_descs: Option<Vec<String>>,
+    /// Whether this output is considered safe to spend. Unconfirmed transactions
+    /// from outside keys and unconfirmed replacement transactions are considered unsafe
+    /// and are not eligible for spending by fundrawtransaction and sendtoaddress.
+    pub safe: bool,
+}
+
+impl ListUnspent {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspent, ListUnspentItemError> {
+        let utxos =
+            self.0.into_iter().map(|utxo| utxo.into_model()).collect::<Result<Vec<_>, _>>()?;
+        Ok(model::ListUnspent(utxos))
+    }
+}
+
+impl ListUnspentItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ListUnspentItem, ListUnspentItemError> {
+        use ListUnspentItemError as E;
+
+        let txid = self.txid.parse::<Txid>().map_err(E::Txid)?;
+        let address =
+            self.address.map(|a| Address::from_str(&a)).transpose().map_err(E::Address)?;
+        let script_pubkey = ScriptBuf::from_hex(&self.script_pubkey).map_err(E::ScriptPubkey)?;
+        let amount = Amount::from_btc(self.amount).map_err(E::Amount)?;
+        let redeem_script = self
+            .redeem_script
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::RedeemScript)?;
+        let witness_script = self
+            .witness_script
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::WitnessScript)?;
+
+        Ok(model::ListUnspentItem {
+            txid,
+            vout: self.vout,
+            address,
+            label: self.label,
+            script_pubkey,
+            amount,
+            confirmations: self.confirmations,
+            redeem_script,
+            witness_script,
+            spendable: self.spendable,
+            solvable: self.solvable,
+            reused: self.reused,
+            descriptor: self.desc,
+            parent_descriptors: self.parent_descs,
+            safe: self.safe,
+            ancestor_count: self.ancestor_count,
+            ancestor_size: self.ancestor_size,
+            ancestor_fees: self.ancestor_fees.map(Amount::from_sat),
+        })
+    }
+}
+
+/// Result of the JSON-RPC method `listdescriptors`.
+///
+/// > listdescriptors
+/// >
+/// > List descriptors imported into a descriptor-enabled wallet.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptors {
+    /// Name of wallet this operation was performed on.
+    pub wallet_name: String,
+    /// Array of descriptor objects.
+    pub descriptors: Vec<ListDescriptorsItem>,
+}
+
+/// A descriptor object, part of `listdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptorsItem {
+    /// Descriptor string representation.
+    pub desc: String,
+    /// The creation time of the descriptor.
+    pub timestamp: u64,
+    /// Activeness flag.
+    pub active: bool,
+    /// Whether this is an internal or external descriptor; defined only for active descriptors.
+    pub internal: Option<bool>,
+    /// Defined only for ranged descriptors, the range start and end (inclusive).
+    pub range: Option<[u64; 2]>,
+    /// The next index to generate addresses from; defined only for ranged descriptors.
+    pub next: Option<u64>,
+}
+
+impl ListDescriptors {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListDescriptors {
+        model::ListDescriptors {
+            wallet_name: self.wallet_name,
+            descriptors: self.descriptors.into_iter().map(|d| d.into_model()).collect(),
+        }
+    }
+}
+
+impl ListDescriptorsItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListDescriptorsItem {
+        model::ListDescriptorsItem {
+            descriptor: self.desc,
+            timestamp: self.timestamp,
+            active: self.active,
+            internal: self.internal,
+            range: self.range.map(|[start, end]| (start, end)),
+            next: self.next,
+        }
+    }
+}
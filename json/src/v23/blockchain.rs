@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v23 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use std::collections::BTreeMap;
+
+use bitcoin::{BlockHash, ScriptBuf, Work};
+use serde::{Deserialize, Serialize};
+
+use crate::v19::GetBlockchainInfoError;
+use crate::{model, Warnings};
+
+/// Result of JSON-RPC method `getblockchaininfo`.
+///
+/// Method call: `getblockchaininfo`
+///
+/// > Returns an object containing various state info regarding blockchain processing.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBlockchainInfo {
+    /// Current network name as defined in BIP70 (main, test, signet, regtest).
+    pub chain: String,
+    /// The current number of blocks processed in the server.
+    pub blocks: u64,
+    /// The current number of headers we have validated.
+    pub headers: u64,
+    /// The hash of the currently best block.
+    #[serde(rename = "bestblockhash")]
+    pub best_block_hash: String,
+    /// The current difficulty.
+    pub difficulty: f64,
+    /// The block time expressed in UNIX epoch time.
+    pub time: u64,
+    /// Median time for the current best block.
+    #[serde(rename = "mediantime")]
+    pub median_time: u64,
+    /// Estimate of verification progress (between 0 and 1).
+    #[serde(rename = "verificationprogress")]
+    pub verification_progress: f64,
+    /// Estimate of whether this node is in Initial Block Download (IBD) mode.
+    #[serde(rename = "initialblockdownload")]
+    pub initial_block_download: bool,
+    /// Total amount of work in active chain, in hexadecimal.
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// The estimated size of the block and undo files on disk.
+    pub size_on_disk: u64,
+    /// If the blocks are subject to pruning.
+    pub pruned: bool,
+    /// Lowest-height complete block stored (only present if pruning is enabled).
+    #[serde(rename = "pruneheight")]
+    pub prune_height: Option<u64>,
+    /// Whether automatic pruning is enabled (only present if pruning is enabled).
+    pub automatic_pruning: Option<bool>,
+    /// The target size used by pruning (only present if automatic pruning is enabled).
+    pub prune_target_size: Option<u64>,
+    /// The block challenge (aka. block script) in hex (only present on signet, not returned by
+    /// all versions).
+    pub signet_challenge: Option<String>,
+    /// Any network and blockchain warnings, a string or an array of strings depending on version.
+    pub warnings: Warnings,
+}
+
+impl GetBlockchainInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockchainInfo, GetBlockchainInfoError> {
+        use GetBlockchainInfoError as E;
+
+        let chain = model::Chain::from_core_arg(&self.chain);
+        let best_block_hash =
+            self.best_block_hash.parse::<BlockHash>().map_err(E::BestBlockHash)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let signet_challenge = self
+            .signet_challenge
+            .map(|hex| ScriptBuf::from_hex(&hex).map_err(E::SignetChallenge))
+            .transpose()?;
+
+        Ok(model::GetBlockchainInfo {
+            chain,
+            blocks: self.blocks,
+            headers: self.headers,
+            best_block_hash,
+            difficulty: self.difficulty,
+            time: Some(self.time),
+            median_time: self.median_time,
+            verification_progress: self.verification_progress,
+            initial_block_download: self.initial_block_download,
+            chain_work,
+            size_on_disk: self.size_on_disk,
+            pruned: self.pruned,
+            prune_height: self.prune_height,
+            automatic_pruning: self.automatic_pruning,
+            prune_target_size: self.prune_target_size,
+            // Softforks are reported by `getdeploymentinfo` since v23.
+            softforks: BTreeMap::new(),
+            signet_challenge,
+            warnings: self.warnings.into_model(),
+        })
+    }
+}
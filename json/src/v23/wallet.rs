@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v23 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use bitcoin::hashes::hash160;
+use bitcoin::Amount;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::GetWalletInfoError;
+use crate::v19::GetWalletInfoScanning;
+
+/// Result of the JSON-RPC method `listdescriptors` with `private` set to true.
+///
+/// > listdescriptors ( private )
+/// >
+/// > List descriptors imported into a descriptor-enabled wallet.
+/// >
+/// > Arguments:
+/// > 1. private    (boolean, optional, default=false) Show private descriptors.
+///
+/// Use `ListDescriptors` when `private` is false.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptorsPrivate {
+    /// Name of wallet this operation was performed on.
+    pub wallet_name: String,
+    /// Array of descriptor objects.
+    pub descriptors: Vec<ListDescriptorsPrivateItem>,
+}
+
+/// A descriptor object including private keys, part of `listdescriptors`.
+///
+/// The descriptor is wrapped as soon as it is deserialized so that it is redacted from `Debug`
+/// output and zeroed on drop.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListDescriptorsPrivateItem {
+    /// Descriptor string representation, including private keys.
+    pub desc: model::PrivateDescriptor,
+    /// The creation time of the descriptor.
+    pub timestamp: u64,
+    /// Activeness flag.
+    pub active: bool,
+    /// Whether this is an internal or external descriptor; defined only for active descriptors.
+    pub internal: Option<bool>,
+    /// Defined only for ranged descriptors, the range start and end (inclusive).
+    pub range: Option<[u64; 2]>,
+    /// The next index to generate addresses from; defined only for ranged descriptors.
+    pub next: Option<u64>,
+}
+
+impl ListDescriptorsPrivate {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListDescriptorsPrivate {
+        model::ListDescriptorsPrivate {
+            wallet_name: self.wallet_name,
+            descriptors: self.descriptors.into_iter().map(|d| d.into_model()).collect(),
+        }
+    }
+}
+
+impl ListDescriptorsPrivateItem {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::ListDescriptorsPrivateItem {
+        model::ListDescriptorsPrivateItem {
+            descriptor: self.desc,
+            timestamp: self.timestamp,
+            active: self.active,
+            internal: self.internal,
+            range: self.range.map(|[start, end]| (start, end)),
+            next: self.next,
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `getwalletinfo`.
+///
+/// > getwalletinfo
+/// >
+/// > Returns an object containing various wallet state info.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetWalletInfo {
+    /// The wallet name.
+    #[serde(rename = "walletname")]
+    pub wallet_name: String,
+    /// The wallet version.
+    #[serde(rename = "walletversion")]
+    pub wallet_version: u32,
+    /// The total confirmed balance of the wallet in BTC.
+    pub balance: f64,
+    /// The total unconfirmed balance of the wallet in BTC.
+    pub unconfirmed_balance: f64,
+    /// The total immature balance of the wallet in BTC.
+    pub immature_balance: f64,
+    /// The total number of transactions in the wallet
+    #[serde(rename = "txcount")]
+    pub tx_count: u32,
+    /// The timestamp (seconds since Unix epoch) of the oldest pre-generated key in the key pool.
+    #[serde(rename = "keypoololdest")]
+    pub keypool_oldest: Option<u32>,
+    /// How many new keys are pre-generated (only counts external keys).
+    #[serde(rename = "keypoolsize")]
+    pub keypool_size: u32,
+    /// How many new keys are pre-generated for internal use (used for change outputs, only
+    /// appears if the wallet is using this feature, otherwise external keys are used).
+    #[serde(rename = "keypoolsize_hd_internal")]
+    pub keypool_size_hd_internal: Option<u32>,
+    /// The timestamp in seconds since epoch (midnight Jan 1 1970 GMT) that the wallet is unlocked
+    /// for transfers, or 0 if the wallet is locked.
+    pub unlocked_until: Option<u32>,
+    /// The transaction fee configuration, set in BTC/kB.
+    #[serde(rename = "paytxfee")]
+    pub pay_tx_fee: f64,
+    /// The Hash160 of the HD seed (only present when HD is enabled).
+    #[serde(rename = "hdseedid")]
+    pub hd_seed_id: Option<String>,
+    /// False if privatekeys are disabled for this wallet (enforced watch-only wallet).
+    pub private_keys_enabled: bool,
+    /// Whether this wallet tracks clean/dirty coins in terms of reuse.
+    pub avoid_reuse: bool,
+    /// Current scanning details, or false if no scan is in progress.
+    pub scanning: GetWalletInfoScanning,
+    /// Whether this wallet uses descriptors for scriptPubKey management.
+    pub descriptors: bool,
+    /// Whether this wallet is configured to use an external signer such as a hardware wallet.
+    pub external_signer: bool,
+}
+
+impl GetWalletInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetWalletInfo, GetWalletInfoError> {
+        use GetWalletInfoError as E;
+
+        let balance = Amount::from_btc(self.balance).map_err(E::Balance)?;
+        let unconfirmed_balance = Amount::from_btc(self.unconfirmed_balance).map_err(E::Balance)?;
+        let immature_balance = Amount::from_btc(self.immature_balance).map_err(E::Balance)?;
+        let pay_tx_fee = crate::fee_rate_from_btc_per_kvb(self.pay_tx_fee).map_err(E::PayTxFee)?;
+        let hd_seed_id =
+            self.hd_seed_id.map(|s| s.parse::<hash160::Hash>()).transpose().map_err(E::HdSeedId)?;
+
+        Ok(model::GetWalletInfo {
+            wallet_name: self.wallet_name,
+            wallet_version: self.wallet_version,
+            balance,
+            unconfirmed_balance,
+            immature_balance,
+            tx_count: self.tx_count,
+            keypool_oldest: self.keypool_oldest,
+            keypool_size: self.keypool_size,
+            keypool_size_hd_internal: self.keypool_size_hd_internal,
+            unlocked_until: self.unlocked_until,
+            pay_tx_fee,
+            hd_seed_id,
+            private_keys_enabled: self.private_keys_enabled,
+            descriptors: self.descriptors,
+            avoid_reuse: Some(self.avoid_reuse),
+            scanning: Some(self.scanning.is_scanning()),
+            external_signer: Some(self.external_signer),
+        })
+    }
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v24 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use core::fmt;
+
+use bitcoin::{amount, Amount};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `getmempoolinfo`.
+///
+/// > getmempoolinfo
+/// >
+/// > Returns details on the active state of the TX memory pool.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMempoolInfo {
+    /// True if the mempool is fully loaded.
+    pub loaded: bool,
+    /// Current tx count.
+    pub size: u64,
+    /// Sum of all virtual transaction sizes as defined in BIP 141.
+    ///
+    /// Differs from actual serialized size because witness data is discounted.
+    pub bytes: u64,
+    /// Total memory usage for the mempool.
+    pub usage: u64,
+    /// Total fees for the mempool in BTC, ignoring modified fees through prioritisetransaction.
+    pub total_fee: f64,
+    /// Maximum memory usage for the mempool.
+    #[serde(rename = "maxmempool")]
+    pub max_mempool: u64,
+    /// Minimum fee rate in BTC/kvB for tx to be accepted.
+    ///
+    /// Is the maximum of minrelaytxfee and minimum mempool fee.
+    #[serde(rename = "mempoolminfee")]
+    pub mempool_min_fee: f64,
+    /// Current minimum relay fee for transactions.
+    #[serde(rename = "minrelaytxfee")]
+    pub min_relay_tx_fee: f64,
+    /// Minimum fee rate increment for mempool limiting or replacement in BTC/kvB.
+    #[serde(rename = "incrementalrelayfee")]
+    pub incremental_relay_fee: f64,
+    /// Current number of transactions that haven't passed initial broadcast yet.
+    #[serde(rename = "unbroadcastcount")]
+    pub unbroadcast_count: u64,
+    /// True if the mempool accepts RBF without replaceability signaling inspection.
+    #[serde(rename = "fullrbf")]
+    pub full_rbf: bool,
+}
+
+impl GetMempoolInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMempoolInfo, GetMempoolInfoError> {
+        use GetMempoolInfoError as E;
+
+        let total_fee = Amount::from_btc(self.total_fee).map_err(E::TotalFee)?;
+        let mempool_min_fee =
+            crate::fee_rate_from_btc_per_kvb(self.mempool_min_fee).map_err(E::MempoolMinFee)?;
+        let min_relay_tx_fee =
+            crate::fee_rate_from_btc_per_kvb(self.min_relay_tx_fee).map_err(E::MinRelayTxFee)?;
+        let incremental_relay_fee = crate::fee_rate_from_btc_per_kvb(self.incremental_relay_fee)
+            .map_err(E::IncrementalRelayFee)?;
+
+        Ok(model::GetMempoolInfo {
+            loaded: Some(self.loaded),
+            size: self.size,
+            bytes: self.bytes,
+            usage: self.usage,
+            total_fee: Some(total_fee),
+            max_mempool: self.max_mempool,
+            mempool_min_fee,
+            min_relay_tx_fee,
+            incremental_relay_fee: Some(incremental_relay_fee),
+            unbroadcast_count: Some(self.unbroadcast_count),
+            full_rbf: Some(self.full_rbf),
+        })
+    }
+}
+
+/// Error when converting a `GetMempoolInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetMempoolInfoError {
+    /// Conversion of the `total_fee` field failed.
+    TotalFee(amount::ParseAmountError),
+    /// Conversion of the `mempool_min_fee` field failed.
+    MempoolMinFee(amount::ParseAmountError),
+    /// Conversion of the `min_relay_tx_fee` field failed.
+    MinRelayTxFee(amount::ParseAmountError),
+    /// Conversion of the `incremental_relay_fee` field failed.
+    IncrementalRelayFee(amount::ParseAmountError),
+}
+
+impl fmt::Display for GetMempoolInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetMempoolInfoError::*;
+
+        match *self {
+            TotalFee(ref e) => write_err!(f, "conversion of the `total_fee` field failed"; e),
+            MempoolMinFee(ref e) =>
+                write_err!(f, "conversion of the `mempool_min_fee` field failed"; e),
+            MinRelayTxFee(ref e) =>
+                write_err!(f, "conversion of the `min_relay_tx_fee` field failed"; e),
+            IncrementalRelayFee(ref e) =>
+                write_err!(f, "conversion of the `incremental_relay_fee` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetMempoolInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetMempoolInfoError::*;
+
+        match *self {
+            TotalFee(ref e) => Some(e),
+            MempoolMinFee(ref e) => Some(e),
+            MinRelayTxFee(ref e) => Some(e),
+            IncrementalRelayFee(ref e) => Some(e),
+        }
+    }
+}
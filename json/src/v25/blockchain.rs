@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v25 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use bitcoin::{hex, BlockHash};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `scanblocks` with action "start".
+///
+/// > scanblocks "action" ( [scanobjects,...] start_height stop_height "filtertype" "options" )
+/// >
+/// > Return relevant blockhashes for given descriptors (requires blockfilterindex).
+/// > This call may take several minutes. Make sure to use no RPC timeout (bitcoin-cli -rpcclienttimeout=0)
+/// >
+/// > Arguments:
+/// > 1. action                                (string, required) The action to execute
+/// >                                          "start" for starting a scan
+/// >                                          "abort" for aborting the current scan (returns true when abort was successful)
+/// >                                          "status" for progress report (in %) of the current scan
+/// > 2. scanobjects                           (json array, optional) Array of scan objects. Required for "start" action
+/// > 3. start_height                          (numeric, optional, default=0) Height to start to scan from
+/// > 4. stop_height                           (numeric, optional, default=chain tip) Height to stop to scan
+/// > 5. filtertype                            (string, optional, default=basic) The type name of the filter
+/// > 6. options                               (json object, optional)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanBlocksStart {
+    /// The height we started the scan from.
+    pub from_height: u64,
+    /// The height we ended the scan at.
+    pub to_height: u64,
+    /// Blocks that may have matched a scanobject.
+    pub relevant_blocks: Vec<String>,
+}
+
+impl ScanBlocksStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanBlocksStart, hex::HexToArrayError> {
+        let relevant_blocks = self
+            .relevant_blocks
+            .iter()
+            .map(|s| s.parse::<BlockHash>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(model::ScanBlocksStart {
+            from_height: self.from_height,
+            to_height: self.to_height,
+            relevant_blocks,
+            completed: None,
+        })
+    }
+}
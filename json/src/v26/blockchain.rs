@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v26 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use bitcoin::{hex, Amount, BlockHash};
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v17::GetTxOutSetInfoError;
+use crate::v22::TxOutSetBlockInfo;
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo ( "hash_type" hash_or_height use_index )
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time if you are not using coinstatsindex.
+/// >
+/// > Arguments:
+/// > 1. hash_type         (string, optional, default="hash_serialized_3") Which UTXO set hash should be calculated. Options: 'hash_serialized_3' (the legacy algorithm), 'muhash', 'none'.
+/// > 2. hash_or_height    (string or numeric, optional, default=the current best block) The block hash or height of the target height (only available with coinstatsindex).
+/// > 3. use_index         (boolean, optional, default=true) Use coinstatsindex, if available.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetTxOutSetInfo {
+    /// The block height (index) of the returned statistics.
+    pub height: u64,
+    /// The hash of the block at which these statistics are calculated.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: u64,
+    /// Database-independent, meaningless metric indicating the UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: u64,
+    /// The serialized hash (only present if 'hash_serialized_3' hash_type is chosen).
+    pub hash_serialized_3: Option<String>,
+    /// The serialized hash (only present if 'muhash' hash_type is chosen).
+    pub muhash: Option<String>,
+    /// The number of transactions with unspent outputs (not available when coinstatsindex is
+    /// used).
+    pub transactions: Option<u64>,
+    /// The estimated size of the chainstate on disk (not available when coinstatsindex is used).
+    pub disk_size: Option<u64>,
+    /// The total amount of coins in the UTXO set.
+    pub total_amount: f64,
+    /// The total amount of coins permanently excluded from the UTXO set (only available if
+    /// coinstatsindex is used).
+    pub total_unspendable_amount: Option<f64>,
+    /// Info on amounts in the block at this block height (only available if coinstatsindex is
+    /// used).
+    pub block_info: Option<TxOutSetBlockInfo>,
+}
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+        let total_unspendable_amount = self
+            .total_unspendable_amount
+            .map(Amount::from_btc)
+            .transpose()
+            .map_err(E::TotalUnspendableAmount)?;
+        let block_info =
+            self.block_info.map(|info| info.into_model()).transpose().map_err(E::BlockInfo)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height: self.height,
+            best_block,
+            transactions: self.transactions,
+            tx_outs: self.tx_outs,
+            bogo_size: self.bogo_size,
+            hash_serialized: self.hash_serialized_3,
+            muhash: self.muhash,
+            disk_size: self.disk_size,
+            total_amount,
+            total_unspendable_amount,
+            block_info,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `scanblocks` with action "start".
+///
+/// > scanblocks "action" ( [scanobjects,...] start_height stop_height "filtertype" options )
+/// >
+/// > Return relevant blockhashes for given descriptors (requires blockfilterindex).
+/// > This call may take several minutes. Make sure to use no RPC timeout (bitcoin-cli -rpcclienttimeout=0)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScanBlocksStart {
+    /// The height we started the scan from.
+    pub from_height: u64,
+    /// The height we ended the scan at.
+    pub to_height: u64,
+    /// Blocks that may have matched a scanobject.
+    pub relevant_blocks: Vec<String>,
+    /// True if the scan process was not aborted.
+    pub completed: bool,
+}
+
+impl ScanBlocksStart {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ScanBlocksStart, hex::HexToArrayError> {
+        let relevant_blocks = self
+            .relevant_blocks
+            .iter()
+            .map(|s| s.parse::<BlockHash>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(model::ScanBlocksStart {
+            from_height: self.from_height,
+            to_height: self.to_height,
+            relevant_blocks,
+            completed: Some(self.completed),
+        })
+    }
+}
+
+/// Result of JSON-RPC method `dumptxoutset`.
+///
+/// > dumptxoutset "path"
+/// >
+/// > Write the serialized UTXO set to disk.
+/// >
+/// > Arguments:
+/// > 1. path    (string, required) Path to the output file. If relative, will be prefixed by datadir.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DumpTxOutSet {
+    /// The number of coins written in the snapshot.
+    pub coins_written: u64,
+    /// The hash of the base of the snapshot.
+    pub base_hash: String,
+    /// The height of the base of the snapshot.
+    pub base_height: u32,
+    /// The absolute path that the snapshot was written to.
+    pub path: String,
+    /// The hash of the UTXO set contents.
+    pub txoutset_hash: String,
+    /// The number of transactions in the chain up to and including the base block.
+    #[serde(rename = "nchaintx")]
+    pub n_chain_tx: u64,
+}
+
+impl DumpTxOutSet {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DumpTxOutSet, hex::HexToArrayError> {
+        let base_hash = self.base_hash.parse::<BlockHash>()?;
+
+        Ok(model::DumpTxOutSet {
+            coins_written: self.coins_written,
+            base_hash,
+            base_height: self.base_height,
+            path: self.path,
+            tx_out_set_hash: self.txoutset_hash,
+            n_chain_tx: self.n_chain_tx,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `loadtxoutset`.
+///
+/// > loadtxoutset "path"
+/// >
+/// > Load the serialized UTXO set from disk.
+/// > Once this snapshot is loaded, its contents will be deserialized into a second chainstate data
+/// > structure, which is then used to sync to the network's tip. Meanwhile, the original chainstate
+/// > will complete the initial block download process in the background, eventually validating up
+/// > to the block that the snapshot is based upon.
+/// >
+/// > Arguments:
+/// > 1. path    (string, required) path to the snapshot file. If relative, will be prefixed by datadir.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LoadTxOutSet {
+    /// The number of coins loaded from the snapshot.
+    pub coins_loaded: u64,
+    /// The hash of the base of the snapshot.
+    pub tip_hash: String,
+    /// The height of the base of the snapshot.
+    pub base_height: u32,
+    /// The absolute path that the snapshot was loaded from.
+    pub path: String,
+}
+
+impl LoadTxOutSet {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::LoadTxOutSet, hex::HexToArrayError> {
+        let tip_hash = self.tip_hash.parse::<BlockHash>()?;
+
+        Ok(model::LoadTxOutSet {
+            coins_loaded: self.coins_loaded,
+            tip_hash,
+            base_height: self.base_height,
+            path: self.path,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getchainstates`.
+///
+/// > getchainstates
+/// >
+/// > Return information about chainstates.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetChainStates {
+    /// The number of headers seen so far.
+    pub headers: u32,
+    /// List of the chainstates ordered by work, with the most-work (active) chainstate last.
+    #[serde(rename = "chainstates")]
+    pub chain_states: Vec<ChainState>,
+}
+
+impl GetChainStates {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetChainStates, hex::HexToArrayError> {
+        let chain_states =
+            self.chain_states.into_iter().map(|c| c.into_model()).collect::<Result<_, _>>()?;
+
+        Ok(model::GetChainStates { headers: self.headers, chain_states })
+    }
+}
+
+/// A chainstate, part of the result of JSON-RPC method `getchainstates`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ChainState {
+    /// Number of blocks in this chainstate.
+    pub blocks: u32,
+    /// Blockhash of the tip.
+    #[serde(rename = "bestblockhash")]
+    pub best_block_hash: String,
+    /// Difficulty of the tip.
+    pub difficulty: f64,
+    /// Progress towards the network tip.
+    #[serde(rename = "verificationprogress")]
+    pub verification_progress: f64,
+    /// The base block of the snapshot this chainstate is based on, if any.
+    #[serde(rename = "snapshot_blockhash")]
+    pub snapshot_block_hash: Option<String>,
+    /// Size of the coinsdb cache.
+    pub coins_db_cache_bytes: u64,
+    /// Size of the coinstip cache.
+    pub coins_tip_cache_bytes: u64,
+    /// Whether the chainstate is fully validated. True if all blocks in the chainstate were
+    /// validated, false if the chain is based on a snapshot and the snapshot has not yet been
+    /// validated.
+    pub validated: bool,
+}
+
+impl ChainState {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::ChainState, hex::HexToArrayError> {
+        let best_block_hash = self.best_block_hash.parse::<BlockHash>()?;
+        let snapshot_block_hash =
+            self.snapshot_block_hash.map(|s| s.parse::<BlockHash>()).transpose()?;
+
+        Ok(model::ChainState {
+            blocks: self.blocks,
+            best_block_hash,
+            difficulty: self.difficulty,
+            verification_progress: self.verification_progress,
+            snapshot_block_hash,
+            coins_db_cache_bytes: self.coins_db_cache_bytes,
+            coins_tip_cache_bytes: self.coins_tip_cache_bytes,
+            validated: self.validated,
+        })
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v26 - mining.
+//!
+//! Types for methods found under the `== Mining ==` section of the API docs.
+
+use core::fmt;
+
+use bitcoin::error::UnprefixedHexError;
+use bitcoin::{hex, CompactTarget, ScriptBuf, Target, Weight};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::{model, Warnings};
+
+/// Result of the JSON-RPC method `getmininginfo`.
+///
+/// > getmininginfo
+/// >
+/// > Returns a json object containing mining-related information.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetMiningInfo {
+    /// The current block.
+    pub blocks: u64,
+    /// The block weight of the last assembled block (only present if a block was ever assembled).
+    #[serde(rename = "currentblockweight")]
+    pub current_block_weight: Option<u64>,
+    /// The number of block transactions of the last assembled block (only present if a block was
+    /// ever assembled).
+    #[serde(rename = "currentblocktx")]
+    pub current_block_tx: Option<u64>,
+    /// The current difficulty.
+    pub difficulty: f64,
+    /// The network hashes per second.
+    #[serde(rename = "networkhashps")]
+    pub network_hash_ps: f64,
+    /// The size of the mempool.
+    #[serde(rename = "pooledtx")]
+    pub pooled_tx: u64,
+    /// Current network name (main, test, signet, regtest).
+    pub chain: String,
+    /// The current target, in hexadecimal (not returned by all versions).
+    pub target: Option<String>,
+    /// The current compact target, in hexadecimal (not returned by all versions).
+    pub bits: Option<String>,
+    /// The block challenge (aka. block script) in hexadecimal (only returned on signet, not
+    /// returned by all versions).
+    pub signet_challenge: Option<String>,
+    /// Any network and blockchain warnings, a string or an array of strings depending on version.
+    pub warnings: Warnings,
+}
+
+impl GetMiningInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetMiningInfo, GetMiningInfoError> {
+        use GetMiningInfoError as E;
+
+        let chain = model::Chain::from_core_arg(&self.chain);
+        let target = match self.target {
+            Some(t) => Some(Target::from_unprefixed_hex(&t).map_err(E::Target)?),
+            None => None,
+        };
+        let bits = match self.bits {
+            Some(b) => Some(CompactTarget::from_unprefixed_hex(&b).map_err(E::Bits)?),
+            None => None,
+        };
+        let signet_challenge = self
+            .signet_challenge
+            .map(|s| ScriptBuf::from_hex(&s))
+            .transpose()
+            .map_err(E::SignetChallenge)?;
+
+        Ok(model::GetMiningInfo {
+            blocks: self.blocks,
+            current_block_weight: self.current_block_weight.map(Weight::from_wu),
+            current_block_tx: self.current_block_tx,
+            difficulty: self.difficulty,
+            network_hash_ps: self.network_hash_ps,
+            pooled_tx: self.pooled_tx,
+            chain,
+            target,
+            bits,
+            signet_challenge,
+            warnings: self.warnings.into_model(),
+        })
+    }
+}
+
+/// Error when converting a `GetMiningInfo` type into the model type.
+#[derive(Debug)]
+pub enum GetMiningInfoError {
+    /// Conversion of the `target` field failed.
+    Target(UnprefixedHexError),
+    /// Conversion of the `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the `signet_challenge` field failed.
+    SignetChallenge(hex::HexToBytesError),
+}
+
+impl fmt::Display for GetMiningInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetMiningInfoError::*;
+
+        match *self {
+            Target(ref e) => write_err!(f, "conversion of the `target` field failed"; e),
+            Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            SignetChallenge(ref e) =>
+                write_err!(f, "conversion of the `signet_challenge` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetMiningInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetMiningInfoError::*;
+
+        match *self {
+            Target(ref e) => Some(e),
+            Bits(ref e) => Some(e),
+            SignetChallenge(ref e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v26.0 - network.
+//!
+//! Types for methods found under the `== Network ==` section of the API docs.
+
+#[cfg(feature = "unstable-rpc")]
+use core::fmt;
+use core::num::ParseIntError;
+#[cfg(feature = "unstable-rpc")]
+use std::collections::BTreeMap;
+
+use bitcoin::p2p::ServiceFlags;
+#[cfg(feature = "unstable-rpc")]
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+
+/// Result of JSON-RPC method `getpeerinfo`.
+///
+/// > getpeerinfo
+/// >
+/// > Returns data about each connected network node as a json array of objects.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+/// An item from the list returned by the JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u64,
+    /// The IP address and port of the peer ("host:port").
+    pub addr: String,
+    /// Bind address of the connection to the peer ("ip:port").
+    #[serde(rename = "addrbind")]
+    pub addr_bind: Option<String>,
+    /// Local address as reported by the peer.
+    #[serde(rename = "addrlocal")]
+    pub addr_local: Option<String>,
+    /// Network (ipv4, ipv6, onion, i2p, not_publicly_routable) the peer connected through.
+    pub network: String,
+    /// The services offered (hex string).
+    pub services: String,
+    /// Whether peer has asked us to relay transactions to it.
+    #[serde(rename = "relaytxes")]
+    pub relay_txes: bool,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last send.
+    #[serde(rename = "lastsend")]
+    pub last_send: u64,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last receive.
+    #[serde(rename = "lastrecv")]
+    pub last_recv: u64,
+    /// The total bytes sent.
+    #[serde(rename = "bytessent")]
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    #[serde(rename = "bytesrecv")]
+    pub bytes_recv: u64,
+    /// The connection time in seconds since epoch (Jan 1 1970 GMT).
+    #[serde(rename = "conntime")]
+    pub connection_time: u64,
+    /// The time offset in seconds.
+    #[serde(rename = "timeoffset")]
+    pub time_offset: i64,
+    /// Ping time (if available).
+    #[serde(rename = "pingtime")]
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time (if any at all).
+    #[serde(rename = "minping")]
+    pub minimum_ping: Option<f64>,
+    /// Ping wait (if non-zero).
+    #[serde(rename = "pingwait")]
+    pub ping_wait: Option<f64>,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The string version.
+    #[serde(rename = "subver")]
+    pub subversion: String,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// Whether we selected peer as (compact blocks) high-bandwidth peer.
+    pub bip152_hb_to: bool,
+    /// Whether peer selected us as (compact blocks) high-bandwidth peer.
+    pub bip152_hb_from: bool,
+    /// Type of connection.
+    pub connection_type: String,
+    /// The starting height (block) of the peer.
+    #[serde(rename = "startingheight")]
+    pub starting_height: i64,
+    /// The last header we have in common with this peer.
+    pub synced_headers: i64,
+    /// The last block we have in common with this peer.
+    pub synced_blocks: i64,
+    /// The heights of blocks we're currently asking from this peer.
+    pub inflight: Vec<u64>,
+    /// Whether we participate in address relay with this peer.
+    pub addr_relay_enabled: bool,
+    /// The total number of addresses processed, excluding those dropped due to rate limiting.
+    pub addr_processed: u64,
+    /// The total number of addresses dropped due to rate limiting.
+    pub addr_rate_limited: u64,
+    /// Any special permissions that have been granted to this peer.
+    pub permissions: Vec<String>,
+    /// Type of transport protocol: "detecting", "v1" or "v2".
+    pub transport_protocol_type: String,
+    /// The session ID for this connection, or "" if there is none ("v2" transport protocol only).
+    pub session_id: String,
+}
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetPeerInfo, ParseIntError> {
+        let peers = self.0.into_iter().map(|peer| peer.into_model()).collect::<Result<_, _>>()?;
+        Ok(model::GetPeerInfo(peers))
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::PeerInfo, ParseIntError> {
+        let services = u64::from_str_radix(&self.services, 16).map(ServiceFlags::from)?;
+
+        Ok(model::PeerInfo {
+            id: self.id,
+            addr: model::PeerAddress::parse(&self.addr),
+            addr_bind: self.addr_bind.as_deref().and_then(model::PeerAddress::parse_optional),
+            addr_local: self.addr_local.as_deref().and_then(model::PeerAddress::parse_optional),
+            network: Some(model::NetworkType::from_core_arg(&self.network)),
+            services,
+            relay_txes: self.relay_txes,
+            bytes_sent: self.bytes_sent,
+            bytes_recv: self.bytes_recv,
+            inbound: self.inbound,
+            connection_time: self.connection_time,
+            version: self.version,
+            subversion: self.subversion,
+            starting_height: self.starting_height,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            connection_type: Some(self.connection_type),
+            permissions: self.permissions,
+            bip152_hb_to: Some(self.bip152_hb_to),
+            bip152_hb_from: Some(self.bip152_hb_from),
+            addr_relay_enabled: Some(self.addr_relay_enabled),
+            transport_protocol_type: Some(self.transport_protocol_type),
+            ban_score: None,
+            whitelisted: None,
+            addnode: None,
+        })
+    }
+}
+
+/// Result of JSON-RPC method `getrawaddrman`.
+///
+/// > getrawaddrman
+/// >
+/// > EXPERIMENTAL warning: this call may be changed in future releases.
+/// >
+/// > Returns information on all address manager entries for the new and tried tables.
+///
+/// This is a hidden RPC method. Maps table name ("new" or "tried") to the table's entries, keyed by
+/// "<bucket>/<position>".
+#[cfg(feature = "unstable-rpc")]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetRawAddrMan(pub BTreeMap<String, BTreeMap<String, AddrManEntry>>);
+
+/// An address manager entry, part of `GetRawAddrMan`.
+#[cfg(feature = "unstable-rpc")]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AddrManEntry {
+    /// The address of the node.
+    pub address: String,
+    /// The port number of the node.
+    pub port: u16,
+    /// The network (ipv4, ipv6, onion, i2p, cjdns) of the address.
+    pub network: String,
+    /// The services offered by the node.
+    pub services: u64,
+    /// The UNIX epoch time when the node was last seen.
+    pub time: u64,
+    /// The address that relayed the address to us.
+    pub source: String,
+    /// The network (ipv4, ipv6, onion, i2p, cjdns) of the source address.
+    pub source_network: String,
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl GetRawAddrMan {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetRawAddrMan, GetRawAddrManError> {
+        use GetRawAddrManError as E;
+
+        let mut model = model::GetRawAddrMan { new: BTreeMap::new(), tried: BTreeMap::new() };
+        for (name, entries) in self.0 {
+            let table = match name.as_str() {
+                "new" => &mut model.new,
+                "tried" => &mut model.tried,
+                _ => return Err(E::Table(name)),
+            };
+            for (position, entry) in entries {
+                let position = position.parse().map_err(E::Position)?;
+                table.insert(position, entry.into_model());
+            }
+        }
+        Ok(model)
+    }
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl AddrManEntry {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> model::AddrManEntry {
+        model::AddrManEntry {
+            address: self.address,
+            port: self.port,
+            network: model::NetworkType::from_core_arg(&self.network),
+            services: ServiceFlags::from(self.services),
+            time: self.time,
+            source: self.source,
+            source_network: model::NetworkType::from_core_arg(&self.source_network),
+        }
+    }
+}
+
+/// Error when converting a `GetRawAddrMan` type into the model type.
+#[cfg(feature = "unstable-rpc")]
+#[derive(Debug)]
+pub enum GetRawAddrManError {
+    /// The table is not "new" or "tried".
+    Table(String),
+    /// Conversion of an entry's "<bucket>/<position>" key failed.
+    Position(model::ParseAddrManPositionError),
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl fmt::Display for GetRawAddrManError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetRawAddrManError as E;
+
+        match *self {
+            E::Table(ref name) => write!(f, "unknown address manager table: {}", name),
+            E::Position(ref e) => write_err!(f, "conversion of an entry's position failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "unstable-rpc")]
+impl std::error::Error for GetRawAddrManError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetRawAddrManError as E;
+
+        match *self {
+            E::Table(_) => None,
+            E::Position(ref e) => Some(e),
+        }
+    }
+}
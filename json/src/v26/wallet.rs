@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v26.2 - wallet.
+//!
+//! Types for methods found under the `== Wallet ==` section of the API docs.
+
+use std::fmt;
+
+use bitcoin::amount::ParseAmountError;
+use bitcoin::consensus::encode;
+use bitcoin::psbt::PsbtParseError;
+use bitcoin::{hex, BlockHash, Psbt, Transaction};
+use internals::write_err;
+use serde::{Deserialize, Serialize};
+
+use crate::model;
+use crate::v19::{GetBalancesMine, GetBalancesWatchOnly};
+
+/// Result of the JSON-RPC method `getbalances`.
+///
+/// > getbalances
+/// >
+/// > Returns an object with all balances in BTC.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetBalances {
+    /// Balances from outputs that the wallet can sign.
+    pub mine: GetBalancesMine,
+    /// Balances from watch-only addresses, only present for legacy wallets with watch-only
+    /// addresses.
+    #[serde(rename = "watchonly")]
+    pub watch_only: Option<GetBalancesWatchOnly>,
+    /// Hash and height of the block this information was generated on.
+    #[serde(rename = "lastprocessedblock")]
+    pub last_processed_block: LastProcessedBlock,
+}
+
+/// Hash and height of the block a wallet result was generated on.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct LastProcessedBlock {
+    /// Hash of the block this information was generated on.
+    pub hash: String,
+    /// Height of the block this information was generated on.
+    pub height: u64,
+}
+
+impl GetBalances {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBalances, GetBalancesError> {
+        use GetBalancesError as E;
+
+        let mine = self.mine.into_model().map_err(E::Amount)?;
+        let watch_only = self.watch_only.map(|w| w.into_model()).transpose().map_err(E::Amount)?;
+        let last_processed_block =
+            self.last_processed_block.into_model().map_err(E::LastProcessedBlockHash)?;
+
+        Ok(model::GetBalances {
+            mine,
+            watch_only,
+            last_processed_block: Some(last_processed_block),
+        })
+    }
+}
+
+impl LastProcessedBlock {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::LastProcessedBlock, hex::HexToArrayError> {
+        let hash = self.hash.parse::<BlockHash>()?;
+        Ok(model::LastProcessedBlock { hash, height: self.height })
+    }
+}
+
+/// Error when converting a `GetBalances` type into the model type.
+#[derive(Debug)]
+pub enum GetBalancesError {
+    /// Conversion of one of the balance fields failed.
+    Amount(ParseAmountError),
+    /// Conversion of the `last_processed_block` hash failed.
+    LastProcessedBlockHash(hex::HexToArrayError),
+}
+
+impl fmt::Display for GetBalancesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use GetBalancesError as E;
+
+        match *self {
+            E::Amount(ref e) => write_err!(f, "conversion of a balance failed"; e),
+            E::LastProcessedBlockHash(ref e) =>
+                write_err!(f, "conversion of the `last_processed_block` hash failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for GetBalancesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use GetBalancesError as E;
+
+        match *self {
+            E::Amount(ref e) => Some(e),
+            E::LastProcessedBlockHash(ref e) => Some(e),
+        }
+    }
+}
+
+/// Result of the JSON-RPC method `walletprocesspsbt`.
+///
+/// > walletprocesspsbt "psbt" ( sign "sighashtype" bip32derivs finalize )
+/// >
+/// > Update a PSBT with input information from our wallet and then sign inputs
+/// > that we can sign for.
+/// > Requires wallet passphrase to be set with walletpassphrase call if wallet is encrypted.
+/// >
+/// > Arguments:
+/// > 1. psbt           (string, required) The transaction base64 string
+/// > 2. sign           (boolean, optional, default=true) Also sign the transaction when updating (requires wallet to be unlocked)
+/// > 3. sighashtype    (string, optional, default="DEFAULT for Taproot, ALL otherwise") The signature hash type to sign with if not specified by the PSBT. Must be one of
+/// >                   "DEFAULT"
+/// >                   "ALL"
+/// >                   "NONE"
+/// >                   "SINGLE"
+/// >                   "ALL|ANYONECANPAY"
+/// >                   "NONE|ANYONECANPAY"
+/// >                   "SINGLE|ANYONECANPAY"
+/// > 4. bip32derivs    (boolean, optional, default=true) Include BIP 32 derivation paths for public keys if we know them
+/// > 5. finalize       (boolean, optional, default=true) Also finalize inputs if possible
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WalletProcessPsbt {
+    /// The base64-encoded partially signed transaction.
+    pub psbt: String,
+    /// If the transaction has a complete set of signatures.
+    pub complete: bool,
+    /// The hex-encoded network transaction if complete.
+    pub hex: Option<String>,
+}
+
+impl WalletProcessPsbt {
+    /// Converts version specific type to a version in-specific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::WalletProcessPsbt, WalletProcessPsbtError> {
+        use WalletProcessPsbtError as E;
+
+        let psbt = self.psbt.parse::<Psbt>().map_err(E::Psbt)?;
+        let hex = self
+            .hex
+            .map(|hex| encode::deserialize_hex::<Transaction>(&hex))
+            .transpose()
+            .map_err(E::Hex)?;
+
+        Ok(model::WalletProcessPsbt { psbt, complete: self.complete, hex })
+    }
+}
+
+/// Error when converting a `WalletProcessPsbt` type into the model type.
+#[derive(Debug)]
+pub enum WalletProcessPsbtError {
+    /// Conversion of the `psbt` field failed.
+    Psbt(PsbtParseError),
+    /// Conversion of the `hex` field failed.
+    Hex(encode::FromHexError),
+}
+
+impl fmt::Display for WalletProcessPsbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use WalletProcessPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => write_err!(f, "conversion of the `psbt` field failed"; e),
+            E::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+        }
+    }
+}
+
+impl std::error::Error for WalletProcessPsbtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use WalletProcessPsbtError as E;
+
+        match *self {
+            E::Psbt(ref e) => Some(e),
+            E::Hex(ref e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The JSON-RPC API for Bitcoin Core v28 - blockchain.
+//!
+//! Types for methods found under the `== Blockchain ==` section of the API docs.
+
+use serde::{Deserialize, Serialize};
+
+use crate::v17::ScriptPubkey;
+
+/// Result of the JSON-RPC method `getdescriptoractivity`.
+///
+/// > getdescriptoractivity ( ["blockhash",...] [scanobjects,...] include_mempool )
+/// >
+/// > Get spend and receive activity associated with a set of descriptors for a set of blocks.
+/// > This command pairs well with the `relevant_blocks` output of `scanblocks()`.
+/// > This call may take several minutes. If you encounter timeouts, try specifying no RPC timeout (bitcoin-cli -rpcclienttimeout=0)
+/// >
+/// > Arguments:
+/// > 1. blockhashes        (json array, optional) The list of blockhashes to examine for activity. Order doesn't matter. Must be along main chain or an error is thrown.
+/// > 2. scanobjects        (json array, optional) The list of descriptors (scan objects) to examine for activity.
+/// > 3. include_mempool    (boolean, optional, default=true) Whether to include unconfirmed activity
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetDescriptorActivity {
+    /// Events, ordered by block height then by transaction position, mempool activity last.
+    pub activity: Vec<ActivityEntry>,
+}
+
+/// An entry in the `activity` field of the `getdescriptoractivity` result.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ActivityEntry {
+    /// An output matching one of the descriptors was spent.
+    Spend(SpendActivity),
+    /// An output matching one of the descriptors was created.
+    Receive(ReceiveActivity),
+}
+
+/// A `spend` entry of the `getdescriptoractivity` result.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SpendActivity {
+    /// The total amount in BTC of the spent output.
+    pub amount: f64,
+    /// The blockhash this spend appears in (omitted if unconfirmed).
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// Height of the spend (omitted if unconfirmed).
+    pub height: Option<u64>,
+    /// The txid of the spending transaction.
+    pub spend_txid: String,
+    /// The vout of the spend.
+    pub spend_vout: u32,
+    /// The txid of the prevout.
+    pub prevout_txid: String,
+    /// The vout of the prevout.
+    pub prevout_vout: u32,
+    /// The prevout script.
+    pub prevout_spk: ScriptPubkey,
+}
+
+/// A `receive` entry of the `getdescriptoractivity` result.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ReceiveActivity {
+    /// The total amount in BTC of the new output.
+    pub amount: f64,
+    /// The block that this receive is in (omitted if unconfirmed).
+    #[serde(rename = "blockhash")]
+    pub block_hash: Option<String>,
+    /// The height of the receive (omitted if unconfirmed).
+    pub height: Option<u64>,
+    /// The txid of the receiving transaction.
+    pub txid: String,
+    /// The vout of the receiving output.
+    #[serde(deserialize_with = "crate::deserialize_number_or_string")]
+    pub vout: u32,
+    /// The output script.
+    pub output_spk: ScriptPubkey,
+}
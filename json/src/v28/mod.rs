@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Structs with standard types.
+//!
+//! These structs model the types returned by the JSON-RPC API and use stdlib types (or custom
+//! types) and are specific to the forthcoming Bitcoin Core `v28`.
+//!
+//! **Unstable:** This module is only available with the `unstable-v28` feature. Types are
+//! scaffolded from the announced RPCs and may change, without a major version bump, as Core
+//! release candidates change. Only new (or changed) methods are listed here, the types of all other
+//! methods are re-exported from `v26`.
+//!
+//! **== Blockchain ==**
+//! - [x] `getdescriptoractivity ( ["blockhash",...] [scanobjects,...] include_mempool )`
+
+mod blockchain;
+
+#[doc(inline)]
+pub use self::blockchain::{ActivityEntry, GetDescriptorActivity, ReceiveActivity, SpendActivity};
+#[cfg(feature = "unstable-rpc")]
+#[doc(inline)]
+pub use crate::v26::{AddrManEntry, GetRawAddrMan, GetRawAddrManError};
+#[doc(inline)]
+pub use crate::{
+    v17::{
+        AddMultisigAddressError, AddressInformation, AddressPurpose, Bip125Replaceable,
+        BlockTemplateTransaction, BlockTemplateTransactionError, BumpFee, BumpFeeError, ChainTips,
+        ChainTipsStatus, CombinePsbt, CreateRawTransaction, DecodePsbt, DecodeRawTransaction,
+        EncryptWallet, EstimateSmartFee, FinalizePsbt, FundRawTransaction, FundRawTransactionError,
+        GenerateToAddress, GetAddressInfo, GetAddressInfoError, GetAddressesByLabel, GetBalance,
+        GetBestBlockHash, GetBlockHash, GetBlockHeader, GetBlockHeaderVerbose,
+        GetBlockHeaderVerboseError, GetBlockTemplate, GetBlockTemplateError, GetBlockVerbosityOne,
+        GetBlockVerbosityTwo, GetBlockVerbosityZero, GetChainTips, GetMemoryInfoMallocInfo,
+        GetMemoryInfoStats, GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork,
+        GetNewAddress, GetRawChangeAddress, GetRawMempool, GetRawTransaction, GetReceivedByAddress,
+        GetTransactionDetail, GetTransactionDetailCategory, GetTransactionError, GetTxOut,
+        GetTxOutProof, GetTxOutSetInfoError, GetWalletInfoError, ImportMulti, ImportMultiResult,
+        ListLockUnspent, ListLockUnspentItem, ListReceivedByAddress, ListReceivedByAddressItem,
+        ListReceivedByAddressItemError, ListSinceBlock, ListSinceBlockError, ListTransactions,
+        ListUnspentItemError, ListWallets, Logging, MapMempoolEntryError, MempoolAcceptance,
+        MempoolEntryError, MempoolEntryFees, MempoolEntryFeesError, RescanBlockchain,
+        ScanTxOutSetStartError, ScanTxOutSetStatus, ScanTxOutSetUnspent, ScriptPubkey,
+        SendRawTransaction, SignMessage, SignRawTransaction, TestMempoolAccept, TransactionItem,
+        TransactionItemError, VerifyTxOutProof, WalletCreateFundedPsbt,
+        WalletCreateFundedPsbtError,
+    },
+    v18::{
+        DeriveAddresses, GetDescriptorInfo, GetDescriptorInfoError, GetReceivedByLabel,
+        ListWalletDir, ListWalletDirWallet,
+    },
+    v19::{
+        Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalancesMine,
+        GetBalancesWatchOnly, GetTransaction, GetWalletInfoScanning, Softfork, SoftforkType,
+    },
+    v20::{AddMultisigAddress, GenerateToDescriptor},
+    v21::{
+        GenerateBlock, GetIndexInfo, GetMempoolEntry, GetRawMempoolVerbose, ImportDescriptors,
+        ImportDescriptorsResult, IndexInfo, JsonRpcError, MempoolEntry, PsbtBumpFee,
+        ScanTxOutSetStart, Send, SendError,
+    },
+    v22::{
+        GetNodeAddresses, ListDescriptors, ListDescriptorsItem, ListUnspent, ListUnspentItem,
+        NodeAddress, SendToAddress, TxOutSetBlockInfo, TxOutSetUnspendables, UnloadWallet,
+    },
+    v23::{GetBlockchainInfo, GetWalletInfo, ListDescriptorsPrivate, ListDescriptorsPrivateItem},
+    v24::{GetMempoolInfo, GetMempoolInfoError},
+    v25::{CreateWallet, LoadWallet},
+    v26::{
+        ChainState, DumpTxOutSet, GetBalances, GetBalancesError, GetChainStates, GetMiningInfo,
+        GetPeerInfo, GetTxOutSetInfo, LastProcessedBlock, LoadTxOutSet, PeerInfo, ScanBlocksStart,
+        WalletProcessPsbt, WalletProcessPsbtError,
+    },
+};
@@ -5,6 +5,10 @@
 /// unusual in that it expects exactly one version number feature to be selected, docs.rs is not set
 /// up to handle such oddity.
 ///
+#[cfg(all(feature = "28_0", not(feature = "26_0")))]
+#[allow(unused_imports)] // Not all users need the json types.
+pub use bitcoind_json_rpc_client::{client_sync::v28::{Client, AddressType}, json::v28 as json};
+
 #[cfg(feature = "26_0")]
 #[allow(unused_imports)] // Not all users need the json types.
 pub use bitcoind_json_rpc_client::{client_sync::v26::{Client, AddressType}, json::v26 as json};
@@ -74,6 +78,6 @@ pub use bitcoind_json_rpc_client::{client_sync::v18::{Client, AddressType}, json
 pub use bitcoind_json_rpc_client::{client_sync::v17::{Client, AddressType}, json::v17 as json};
 
 // To make --no-default-features work we have to re-export a the types, use most recent version same as we do for all features.
-#[cfg(all(not(feature = "26_0"), not(feature = "25_2"), not(feature = "25_1"), not(feature = "25_0"), not(feature = "24_2"),not(feature = "24_1"), not(feature = "24_0_1"), not(feature = "23_2"), not(feature = "23_1"), not(feature = "23_0"), not(feature = "22_1"), not(feature = "22_0"), not(feature = "0_21_2"), not(feature = "0_20_2"), not(feature = "0_19_1"), not(feature = "0_18_1"), not(feature = "0_17_1")))]
+#[cfg(all(not(feature = "28_0"), not(feature = "26_0"), not(feature = "25_2"), not(feature = "25_1"), not(feature = "25_0"), not(feature = "24_2"),not(feature = "24_1"), not(feature = "24_0_1"), not(feature = "23_2"), not(feature = "23_1"), not(feature = "23_0"), not(feature = "22_1"), not(feature = "22_0"), not(feature = "0_21_2"), not(feature = "0_20_2"), not(feature = "0_19_1"), not(feature = "0_18_1"), not(feature = "0_17_1")))]
 #[allow(unused_imports)] // Not all users need the json types.
 pub use bitcoind_json_rpc_client::{client_sync::v26::{Client, AddressType}, json::v26 as json};
@@ -9,13 +9,20 @@ mod versions;
 
 use std::ffi::OsStr;
 use std::net::{Ipv4Addr, SocketAddrV4, TcpListener};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, ExitStatus, Stdio};
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::{env, fmt, fs, thread};
 
 use anyhow::Context;
+use bitcoind_json_rpc_client::bitcoin::secp256k1::Secp256k1;
+use bitcoind_json_rpc_client::bitcoin::{
+    Address, Amount, BlockHash, CompressedPublicKey, Network, NetworkKind, PrivateKey,
+};
 use bitcoind_json_rpc_client::client_sync::{self, Auth};
+use bitcoind_json_rpc_client::json::{model, v26};
+use bitcoind_json_rpc_client::node_url::NodeUrl;
 use log::{debug, error, warn};
 use tempfile::TempDir;
 pub use {anyhow, tempfile, which};
@@ -39,6 +46,19 @@ pub struct BitcoinD {
 
     /// Contains information to connect to this node
     pub params: ConnectParams,
+    /// The mock time last set using this handle, `0` if mock time is not in use.
+    mock_time: AtomicU64,
+    /// Everything needed to launch the process again on the same data directory.
+    launch: Launch,
+}
+
+/// The executable and arguments used to launch a bitcoind process.
+#[derive(Debug, Clone)]
+struct Launch {
+    exe: PathBuf,
+    args: Vec<String>,
+    view_stdout: bool,
+    wallet: Option<String>,
 }
 
 #[derive(Debug)]
@@ -199,6 +219,7 @@ const INVALID_ARGS: [&str; 2] = ["-rpcuser", "-rpcpassword"];
 /// conf.tmpdir = None;
 /// conf.staticdir = None;
 /// conf.attempts = 3;
+/// conf.deprecated_rpc = vec![];
 /// assert_eq!(conf, bitcoind::Conf::default());
 /// ```
 ///
@@ -250,6 +271,10 @@ pub struct Conf<'a> {
 
     /// Load `wallet` after initialization.
     pub wallet: Option<String>,
+
+    /// Deprecated RPC behaviors to re-enable, each passed as `-deprecatedrpc=<name>` e.g.,
+    /// `accounts` to use the account methods of v0.17.
+    pub deprecated_rpc: Vec<&'a str>,
 }
 
 impl Default for Conf<'_> {
@@ -264,10 +289,43 @@ impl Default for Conf<'_> {
             attempts: 3,
             enable_zmq: false,
             wallet: Some("default".to_string()),
+            deprecated_rpc: vec![],
+        }
+    }
+}
+
+/// The `-signetchallenge` argument for a private signet with an `OP_TRUE` block challenge.
+///
+/// Blocks on such a signet need no signature, only signet's proof of work. Note that at the
+/// minimum difficulty a block takes millions of hashes, more than the default `maxtries` of
+/// `generatetoaddress`.
+pub const SIGNET_OP_TRUE_CHALLENGE_ARG: &str = "-signetchallenge=51";
+
+impl Conf<'_> {
+    /// Returns the configuration for a node on a private signet, see
+    /// [`SIGNET_OP_TRUE_CHALLENGE_ARG`].
+    ///
+    /// Other parameters are the same as [`Conf::default`]. Signet is supported by Core v0.21 and
+    /// later.
+    pub fn signet() -> Self {
+        Conf {
+            args: vec!["-signet", SIGNET_OP_TRUE_CHALLENGE_ARG, "-fallbackfee=0.0001"],
+            network: "signet",
+            ..Default::default()
         }
     }
 }
 
+/// A fixed regtest private key (the secret key `0x0101..01`) for use with
+/// [`BitcoinD::fund_deterministic_wallet`].
+pub const DETERMINISTIC_WIF: &str = "cMceqPhHedrhbcR9eXgzmfWy7kRqLyAxMYwFT6ABDWsiwUp9Nsq9";
+
+/// The number of blocks after which a coinbase output can be spent.
+const COINBASE_MATURITY: usize = 100;
+
+/// The number of blocks between block subsidy halvings on regtest.
+const REGTEST_HALVING_INTERVAL: u64 = 150;
+
 impl BitcoinD {
     /// Launch the bitcoind process from the given `exe` executable with default args.
     ///
@@ -299,7 +357,7 @@ impl BitcoinD {
         let cookie_file = work_dir_path.join(conf.network).join(".cookie");
         let rpc_port = get_available_port()?;
         let rpc_socket = SocketAddrV4::new(LOCAL_IP, rpc_port);
-        let rpc_url = format!("http://{}", rpc_socket);
+        let rpc_url = NodeUrl::from_socket_addr(rpc_socket.into());
         debug!("rpc_url: {}", rpc_url);
 
         let (p2p_args, p2p_socket) = match conf.p2p {
@@ -342,29 +400,27 @@ impl BitcoinD {
             false => (vec![], None, None),
         };
 
-        let stdout = if conf.view_stdout { Stdio::inherit() } else { Stdio::null() };
-
         let datadir_arg = format!("-datadir={}", work_dir_path.display());
         let rpc_arg = format!("-rpcport={}", rpc_port);
         let default_args = [&datadir_arg, &rpc_arg];
         let conf_args = validate_args(conf.args.clone())?;
-
-        debug!(
-            "launching {:?} with args: {:?} {:?} AND custom args: {:?}",
-            exe.as_ref(),
-            default_args,
-            p2p_args,
-            conf_args
-        );
-
-        let mut process = Command::new(exe.as_ref())
-            .args(default_args)
-            .args(&p2p_args)
-            .args(&conf_args)
-            .args(&zmq_args)
-            .stdout(stdout)
-            .spawn()
-            .with_context(|| format!("Error while executing {:?}", exe.as_ref()))?;
+        let deprecated_rpc_args =
+            conf.deprecated_rpc.iter().map(|name| format!("-deprecatedrpc={}", name));
+
+        let launch = Launch {
+            exe: PathBuf::from(exe.as_ref()),
+            args: default_args
+                .iter()
+                .map(|arg| arg.to_string())
+                .chain(p2p_args)
+                .chain(conf_args.iter().map(|arg| arg.to_string()))
+                .chain(deprecated_rpc_args)
+                .chain(zmq_args)
+                .collect(),
+            view_stdout: conf.view_stdout,
+            wallet: conf.wallet.clone(),
+        };
+        let mut process = launch.spawn(&[])?;
 
         debug!("cookie file: {}", cookie_file.display());
 
@@ -383,47 +439,7 @@ impl BitcoinD {
         thread::sleep(Duration::from_millis(1000));
         assert!(process.stderr.is_none());
 
-        let mut i = 0;
-        let auth = Auth::CookieFile(cookie_file.clone());
-        let client_base =
-            Client::new_with_auth(&rpc_url, auth.clone()).expect("failed to create client");
-
-        let client = loop {
-            // Just use serde value because changes to the GetBlockchainInfo type make debugging hard.
-            let client_result: Result<serde_json::Value, _> =
-                client_base.call("getblockchaininfo", &[]);
-
-            if client_result.is_ok() {
-                let url = match &conf.wallet {
-                    Some(wallet) => {
-                        debug!("trying to create/load wallet: {}", wallet);
-                        // Debugging logic here implicitly tests `into_model` for create/load wallet.
-                        match client_base.create_wallet(wallet) {
-                            Ok(json) => {
-                                debug!("created wallet: {}", json.name());
-                            },
-                            Err(e) => {
-                                debug!("initial create_wallet unsuccessful, try loading instead: {:?}", e);
-                                let wallet = client_base.load_wallet(wallet)?.name();
-                                debug!("loaded wallet: {}", wallet);
-                            }
-                        }
-                        format!("{}/wallet/{}", rpc_url, wallet)
-                    }
-                    None => rpc_url,
-                };
-                debug!("creating client with url: {}", url);
-                break Client::new_with_auth(&url, auth)?;
-            }
-
-            thread::sleep(Duration::from_millis(1000));
-
-            i += 1;
-            if i > 10 {
-                error!("failed to get a response from bitcoind");
-                return Err(Error::NoBitcoindInstance.into());
-            }
-        };
+        let client = connect(&rpc_url, &cookie_file, conf.wallet.as_deref())?;
 
         Ok(BitcoinD {
             process,
@@ -436,17 +452,22 @@ impl BitcoinD {
                 zmq_pub_raw_block_socket,
                 zmq_pub_raw_tx_socket,
             },
+            mock_time: AtomicU64::new(0),
+            launch,
         })
     }
 
     /// Returns the rpc URL including the schema eg. http://127.0.0.1:44842
-    pub fn rpc_url(&self) -> String { format!("http://{}", self.params.rpc_socket) }
+    pub fn rpc_url(&self) -> String { self.node_url().to_string() }
+
+    /// Returns the rpc URL, use [`NodeUrl::with_wallet`] to get a wallet endpoint.
+    pub fn node_url(&self) -> NodeUrl { NodeUrl::from_socket_addr(self.params.rpc_socket.into()) }
 
     #[cfg(any(feature = "0_19_1", not(feature = "download")))]
     /// Returns the rpc URL including the schema and the given `wallet_name`
     /// eg. http://127.0.0.1:44842/wallet/my_wallet
     pub fn rpc_url_with_wallet<T: AsRef<str>>(&self, wallet_name: T) -> String {
-        format!("http://{}/wallet/{}", self.params.rpc_socket, wallet_name.as_ref())
+        self.node_url().with_wallet(wallet_name.as_ref()).to_string()
     }
 
     /// Return the current workdir path of the running node
@@ -463,6 +484,159 @@ impl BitcoinD {
         Ok(self.process.wait()?)
     }
 
+    /// Stops the node using the `stop` RPC and waits for the process to exit.
+    ///
+    /// Unlike [`BitcoinD::stop`] this is a no-op if the process has already exited. The data
+    /// directory is kept (even if temporary) so the node can be started again with
+    /// [`BitcoinD::restart`].
+    pub fn stop_gracefully(&mut self) -> anyhow::Result<ExitStatus> {
+        if let Some(status) = self.process.try_wait()? {
+            return Ok(status);
+        }
+        self.stop()
+    }
+
+    /// Stops the node (if running) and starts it again on the same data directory and ports.
+    ///
+    /// `args` are appended to the arguments the node was originally launched with, e.g.
+    /// `&["-persistmempool=0"]`. The configured wallet is loaded again, if it is not already
+    /// loaded on startup. Mock time is not persisted by Core so is reset.
+    pub fn restart(&mut self, args: &[&str]) -> anyhow::Result<()> {
+        self.stop_gracefully()?;
+
+        let args: Vec<String> =
+            validate_args(args.to_vec())?.iter().map(|arg| arg.to_string()).collect();
+        let mut process = self.launch.spawn(&args)?;
+        if let Some(status) = process.try_wait()? {
+            error!("early exit on restart with: {:?}", status);
+            return Err(Error::EarlyExit(status).into());
+        }
+        self.process = process;
+        self.client =
+            connect(&self.node_url(), &self.params.cookie_file, self.launch.wallet.as_deref())?;
+        self.mock_time.store(0, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Sets the node's mock time to `time` (seconds since epoch), `0` disables mock time.
+    pub fn set_mock_time(&self, time: u64) -> anyhow::Result<()> {
+        self.client.set_mock_time(time)?;
+        self.mock_time.store(time, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Returns the mock time last set using this handle, `None` if mock time is not in use.
+    pub fn mock_time(&self) -> Option<u64> {
+        match self.mock_time.load(Ordering::SeqCst) {
+            0 => None,
+            time => Some(time),
+        }
+    }
+
+    /// Advances the node's mock time by `secs` seconds and returns the new mock time.
+    ///
+    /// If mock time is not yet in use it is advanced from the current system time.
+    pub fn advance_mock_time(&self, secs: u64) -> anyhow::Result<u64> {
+        let now = match self.mock_time() {
+            Some(time) => time,
+            None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        };
+        let time = now + secs;
+        self.set_mock_time(time)?;
+        Ok(time)
+    }
+
+    /// Mines `nblocks` blocks to `address`, advancing mock time by `interval` seconds before each.
+    ///
+    /// Gives deterministic block timestamps, note however that Core will not create a block with
+    /// a timestamp less than or equal to the median time past of the previous 11 blocks.
+    pub fn mine_blocks_with_interval(
+        &self,
+        nblocks: usize,
+        interval: u64,
+        address: &Address,
+    ) -> anyhow::Result<Vec<BlockHash>> {
+        let mut hashes = Vec::with_capacity(nblocks);
+        for _ in 0..nblocks {
+            self.advance_mock_time(interval)?;
+            let json = self.client.generate_to_address(1, address)?;
+            hashes.extend(json.into_model()?.0);
+        }
+        Ok(hashes)
+    }
+
+    /// Imports the private key `wif` into the node's wallet and mines to it until at least
+    /// `amount` is spendable.
+    ///
+    /// The key is imported as p2pkh, p2sh-p2wpkh and p2wpkh (p2pkh only for an uncompressed key),
+    /// blocks are mined to each address in turn until the mature coinbase outputs add up to
+    /// `amount`, then [`COINBASE_MATURITY`] more blocks are mined to the first address. Returns the
+    /// addresses that received mature coinbase outputs.
+    ///
+    /// Since coinbase transactions only depend on the block height and the output script, calling
+    /// this on a fresh chain with the same key (e.g. [`DETERMINISTIC_WIF`]) gives the same funding
+    /// txids on every run. Works with both legacy and descriptor wallets.
+    pub fn fund_deterministic_wallet(
+        &self,
+        wif: &str,
+        amount: Amount,
+    ) -> anyhow::Result<Vec<Address>> {
+        let key = PrivateKey::from_wif(wif).context("invalid WIF private key")?;
+        if key.network != NetworkKind::Test {
+            anyhow::bail!("expected a test network private key");
+        }
+        let addresses = deterministic_addresses(&key);
+        self.import_key(&key)?;
+
+        let mut height: u64 = self.client.call("getblockcount", &[])?;
+        let mut funded = Vec::new();
+        let mut total = Amount::ZERO;
+        while total < amount {
+            height += 1;
+            let subsidy = regtest_block_subsidy(height);
+            if subsidy == Amount::ZERO {
+                anyhow::bail!("block subsidy exhausted before mining {}", amount);
+            }
+            let address = &addresses[funded.len() % addresses.len()];
+            let _ = self.client.generate_to_address(1, address)?;
+            funded.push(address.clone());
+            total += subsidy;
+        }
+        let _ = self.client.generate_to_address(COINBASE_MATURITY, &addresses[0])?;
+
+        funded.truncate(addresses.len());
+        Ok(funded)
+    }
+
+    /// Imports `key` into the node's wallet, without rescanning.
+    fn import_key(&self, key: &PrivateKey) -> anyhow::Result<()> {
+        let info: serde_json::Value = self.client.call("getwalletinfo", &[])?;
+        let descriptors = info.get("descriptors").and_then(|d| d.as_bool()).unwrap_or(false);
+
+        if !descriptors {
+            let args = [key.to_wif().into(), "".into(), false.into()];
+            let _: serde_json::Value = self.client.call("importprivkey", &args)?;
+            return Ok(());
+        }
+
+        let mut requests = Vec::new();
+        for desc in deterministic_descriptors(key) {
+            let info: serde_json::Value =
+                self.client.call("getdescriptorinfo", &[desc.clone().into()])?;
+            let checksum = info["checksum"].as_str().context("missing descriptor checksum")?;
+            requests.push(serde_json::json!({
+                "desc": format!("{}#{}", desc, checksum),
+                "timestamp": "now",
+            }));
+        }
+        let results: Vec<serde_json::Value> =
+            self.client.call("importdescriptors", &[requests.into()])?;
+        if results.iter().any(|r| r["success"] != serde_json::Value::Bool(true)) {
+            anyhow::bail!("importdescriptors failed: {:?}", results);
+        }
+        Ok(())
+    }
+
     #[cfg(any(feature = "0_19_1", not(feature = "download")))]
     /// Create a new wallet in the running node, and return an RPC client connected to the just
     /// created wallet
@@ -473,6 +647,125 @@ impl BitcoinD {
             Auth::CookieFile(self.params.cookie_file.clone()),
         )?)
     }
+
+    /// Writes a snapshot of the UTXO set to `path` using `dumptxoutset` (Core v26 and later).
+    ///
+    /// A relative `path` is relative to the node's data directory, the file must not exist.
+    pub fn dump_tx_out_set<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<model::DumpTxOutSet> {
+        let path = path.as_ref().to_str().context("snapshot path is not valid UTF-8")?;
+        let json: v26::DumpTxOutSet = self.client.call("dumptxoutset", &[path.into()])?;
+        Ok(json.into_model()?)
+    }
+
+    /// Loads the UTXO set snapshot at `path` using `loadtxoutset` (Core v26 and later).
+    ///
+    /// The headers up to the base block of the snapshot must be known to the node, see
+    /// [`BitcoinD::submit_headers_from`].
+    pub fn load_tx_out_set<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<model::LoadTxOutSet> {
+        let path = path.as_ref().to_str().context("snapshot path is not valid UTF-8")?;
+        let json: v26::LoadTxOutSet = self.client.call("loadtxoutset", &[path.into()])?;
+        Ok(json.into_model()?)
+    }
+
+    /// Returns the node's chainstates using `getchainstates` (Core v26 and later).
+    pub fn chain_states(&self) -> anyhow::Result<model::GetChainStates> {
+        let json: v26::GetChainStates = self.client.call("getchainstates", &[])?;
+        Ok(json.into_model()?)
+    }
+
+    /// Submits the headers of `other`'s active chain, up to and including `height`, to this node.
+    ///
+    /// Does not require the nodes to be connected, used to make the base block of a snapshot known
+    /// to a node before calling `loadtxoutset`.
+    pub fn submit_headers_from(&self, other: &BitcoinD, height: u32) -> anyhow::Result<()> {
+        for height in 1..=height {
+            let hash: String = other.client.call("getblockhash", &[height.into()])?;
+            let header: String =
+                other.client.call("getblockheader", &[hash.into(), false.into()])?;
+            let _: serde_json::Value = self.client.call("submitheader", &[header.into()])?;
+        }
+        Ok(())
+    }
+
+    /// Starts a new node, configured using `conf`, from a snapshot of this node's UTXO set.
+    ///
+    /// **Not usable on arbitrary regtest chains:** Core only accepts snapshots whose base block is
+    /// listed in its chain parameters. On regtest that is the chain created by Core's functional
+    /// tests (which depends on their keys and mock times), a snapshot of any other chain, e.g. one
+    /// mined with `generatetoaddress`, is rejected by `loadtxoutset`. Only this failure is
+    /// exercised by our tests.
+    ///
+    /// Orchestrates the assumeutxo workflow (Core v26 and later):
+    ///
+    /// 1. Dumps the UTXO set of this node (`dumptxoutset`) into its data directory.
+    /// 2. Launches a new node with a fresh data directory, using the same executable.
+    /// 3. Submits the headers up to the base block of the snapshot to the new node.
+    /// 4. Loads the snapshot on the new node (`loadtxoutset`).
+    /// 5. If this node has P2P enabled, connects the new node to it (`addnode onetry`), the new
+    ///    node then syncs to the tip and validates the snapshot in the background.
+    ///
+    /// Use [`BitcoinD::wait_for_snapshot_validation`] on the returned node to wait for background
+    /// validation. `conf` should not connect the new node to a peer, blocks received before the
+    /// snapshot is loaded cause `loadtxoutset` to fail.
+    ///
+    /// # Errors
+    ///
+    /// If the node is older than v26, or any step fails, including `loadtxoutset` rejecting a
+    /// snapshot that is not in Core's chain parameters.
+    pub fn assumeutxo(&self, conf: &Conf) -> anyhow::Result<AssumeUtxo> {
+        let version = self.client.server_version()?;
+        if version < 260000 {
+            anyhow::bail!("assumeutxo requires Core v26 or later, node is version {}", version);
+        }
+
+        let path = self.workdir().join("assumeutxo.dat");
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+        let snapshot = self.dump_tx_out_set(&path)?;
+
+        let node = BitcoinD::with_conf(&self.launch.exe, conf)?;
+        node.submit_headers_from(self, snapshot.base_height)?;
+        let loaded = node.load_tx_out_set(&path)?;
+
+        if let Some(socket) = self.params.p2p_socket {
+            let args = [socket.to_string().into(), "onetry".into()];
+            let _: serde_json::Value = node.client.call("addnode", &args)?;
+        }
+        Ok(AssumeUtxo { node, snapshot, loaded })
+    }
+
+    /// Polls `getchainstates` until the background validation of a loaded snapshot completes.
+    ///
+    /// Returns the final chainstates, errors if validation has not completed within `timeout`.
+    pub fn wait_for_snapshot_validation(
+        &self,
+        timeout: Duration,
+    ) -> anyhow::Result<model::GetChainStates> {
+        let start = Instant::now();
+        loop {
+            let states = self.chain_states()?;
+            if states.is_fully_validated() {
+                return Ok(states);
+            }
+            if start.elapsed() > timeout {
+                let progress = states.chain_states.first().map(|c| c.verification_progress);
+                anyhow::bail!("snapshot not validated in {:?}, progress: {:?}", timeout, progress);
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+}
+
+/// A node started from a UTXO set snapshot, see [`BitcoinD::assumeutxo`].
+#[derive(Debug)]
+pub struct AssumeUtxo {
+    /// The node the snapshot was loaded into.
+    pub node: BitcoinD,
+    /// The result of dumping the snapshot from the original node.
+    pub snapshot: model::DumpTxOutSet,
+    /// The result of loading the snapshot into the new node.
+    pub loaded: model::LoadTxOutSet,
 }
 
 #[cfg(feature = "download")]
@@ -495,6 +788,89 @@ impl Drop for BitcoinD {
     }
 }
 
+impl Launch {
+    /// Spawns the bitcoind process with `extra_args` appended to the launch arguments.
+    fn spawn(&self, extra_args: &[String]) -> anyhow::Result<Child> {
+        let stdout = if self.view_stdout { Stdio::inherit() } else { Stdio::null() };
+
+        debug!("launching {:?} with args: {:?} {:?}", self.exe, self.args, extra_args);
+
+        Command::new(&self.exe)
+            .args(&self.args)
+            .args(extra_args)
+            .stdout(stdout)
+            .spawn()
+            .with_context(|| format!("Error while executing {:?}", self.exe))
+    }
+}
+
+/// Waits for the node at `rpc_url` to respond then creates (or loads) `wallet`.
+///
+/// Returns a client connected to the wallet endpoint if `wallet` is set.
+fn connect(
+    rpc_url: &NodeUrl,
+    cookie_file: &std::path::Path,
+    wallet: Option<&str>,
+) -> anyhow::Result<Client> {
+    let mut i = 0;
+    let auth = Auth::CookieFile(cookie_file.to_path_buf());
+
+    loop {
+        // The node writes the cookie file once it has started, the client reads it on creation.
+        if cookie_file.exists() {
+            let client_base = Client::from_node_url(rpc_url, auth.clone())?;
+
+            // Just use serde value, changes to the GetBlockchainInfo type make debugging hard.
+            let client_result: Result<serde_json::Value, _> =
+                client_base.call("getblockchaininfo", &[]);
+
+            if client_result.is_ok() {
+                let url = match wallet {
+                    Some(wallet) => {
+                        debug!("trying to create/load wallet: {}", wallet);
+                        // Debugging logic here implicitly tests `into_model` for create/load.
+                        match client_base.create_wallet(wallet) {
+                            Ok(json) => {
+                                debug!("created wallet: {}", json.name());
+                            }
+                            Err(e) => {
+                                debug!(
+                                    "initial create_wallet unsuccessful, try loading instead: {:?}",
+                                    e
+                                );
+                                match client_base.load_wallet(wallet) {
+                                    Ok(json) => debug!("loaded wallet: {}", json.name()),
+                                    Err(e) => {
+                                        // The wallet may have been loaded on startup.
+                                        let loaded: Vec<String> =
+                                            client_base.call("listwallets", &[])?;
+                                        if !loaded.iter().any(|w| w == wallet) {
+                                            return Err(e.into());
+                                        }
+                                        debug!("wallet already loaded: {}", wallet);
+                                    }
+                                }
+                            }
+                        }
+                        rpc_url.clone().with_wallet(wallet)
+                    }
+                    None => rpc_url.clone(),
+                };
+                debug!("creating client with url: {}", url);
+                return Ok(Client::from_node_url(&url, auth)?);
+            }
+        }
+
+        thread::sleep(Duration::from_millis(1000));
+
+        i += 1;
+        if i > 10 {
+            error!("failed to get a response from bitcoind");
+            return Err(Error::NoBitcoindInstance.into());
+        }
+    }
+}
+
 /// Returns a non-used local port if available.
 ///
 /// Note there is a race condition during the time the method check availability and the caller
@@ -537,6 +913,39 @@ pub fn downloaded_exe_path() -> anyhow::Result<String> {
     Ok(format!("{}", path.display()))
 }
 
+/// Returns the regtest addresses for `key`, p2pkh first.
+fn deterministic_addresses(key: &PrivateKey) -> Vec<Address> {
+    let secp = Secp256k1::signing_only();
+    let pk = key.public_key(&secp);
+
+    let mut addresses = vec![Address::p2pkh(pk, Network::Regtest)];
+    if let Ok(compressed) = CompressedPublicKey::try_from(pk) {
+        addresses.push(Address::p2shwpkh(&compressed, Network::Regtest));
+        addresses.push(Address::p2wpkh(&compressed, Network::Regtest));
+    }
+    addresses
+}
+
+/// Returns the descriptors, without checksum, matching [`deterministic_addresses`].
+fn deterministic_descriptors(key: &PrivateKey) -> Vec<String> {
+    let wif = key.to_wif();
+    let mut descriptors = vec![format!("pkh({})", wif)];
+    if key.compressed {
+        descriptors.push(format!("sh(wpkh({}))", wif));
+        descriptors.push(format!("wpkh({})", wif));
+    }
+    descriptors
+}
+
+/// Returns the block subsidy at `height` on regtest.
+fn regtest_block_subsidy(height: u64) -> Amount {
+    let halvings = height / REGTEST_HALVING_INTERVAL;
+    if halvings >= 64 {
+        return Amount::ZERO;
+    }
+    Amount::from_sat(Amount::from_int_btc(50).to_sat() >> halvings)
+}
+
 /// Returns the daemon `bitcoind` executable with the following precedence:
 ///
 /// 1) If it's specified in the `BITCOIND_EXE` env var
@@ -605,6 +1014,352 @@ mod test {
         assert_eq!(1, info.blocks);
     }
 
+    #[test]
+    fn test_client_shared_across_threads() {
+        let exe = init();
+        let bitcoind = BitcoinD::new(exe).unwrap();
+
+        let shared = std::sync::Arc::new(bitcoind.client.clone());
+        let handles = (0..4)
+            .map(|i| {
+                // Use both a shared `Arc<Client>` and a clone per thread.
+                let client = if i % 2 == 0 { (*shared).clone() } else { bitcoind.client.clone() };
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    let _ = client.get_blockchain_info().unwrap();
+                    shared.best_block_hash().unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let best = bitcoind.client.best_block_hash().unwrap();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), best);
+        }
+    }
+
+    #[test]
+    fn test_client_from_transport() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::Arc;
+
+        use client_sync::transport::{Request, Response};
+        use client_sync::{MinreqTransport, Transport};
+
+        struct Counting(MinreqTransport, Arc<AtomicUsize>);
+
+        impl Transport for Counting {
+            fn send_request(&self, request: &Request) -> client_sync::Result<Response> {
+                self.1.fetch_add(1, Ordering::SeqCst);
+                self.0.send_request(request)
+            }
+
+            fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { self.0.fmt_target(f) }
+        }
+
+        let exe = init();
+        let bitcoind = BitcoinD::new(exe).unwrap();
+        let (user, pass) =
+            Auth::CookieFile(bitcoind.params.cookie_file.clone()).get_user_pass().unwrap();
+        let transport =
+            MinreqTransport::new(&bitcoind.rpc_url(), Some((user.unwrap(), pass)), None);
+        let count = Arc::new(AtomicUsize::new(0));
+        let client = Client::from_transport(Counting(transport, count.clone()));
+
+        assert_eq!(client.best_block_hash().unwrap(), bitcoind.client.best_block_hash().unwrap());
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_retry_missing_cookie_file() {
+        let exe = init();
+        let bitcoind = BitcoinD::new(exe).unwrap();
+        let dir = TempDir::new().unwrap();
+        let cookie_file = dir.path().join(".cookie");
+
+        fs::copy(&bitcoind.params.cookie_file, &cookie_file).unwrap();
+        let client = Client::builder(&bitcoind.rpc_url())
+            .auth(Auth::CookieFile(cookie_file.clone()))
+            .retry(5, Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        // The cookie file is missing while the node restarts.
+        fs::remove_file(&cookie_file).unwrap();
+        let original = bitcoind.params.cookie_file.clone();
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            fs::copy(original, cookie_file).unwrap();
+        });
+        assert!(client.get_blockchain_info().is_ok());
+        writer.join().unwrap();
+    }
+
+    #[test]
+    fn test_retry_across_restart() {
+        use std::sync::atomic::AtomicBool;
+        use std::sync::Arc;
+
+        let exe = init();
+        let mut bitcoind = BitcoinD::new(exe).unwrap();
+        let best_block = bitcoind.client.best_block_hash().unwrap();
+        let client = Client::builder(&bitcoind.rpc_url())
+            .auth(Auth::CookieFile(bitcoind.params.cookie_file.clone()))
+            .retry(10, Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let done = Arc::new(AtomicBool::new(false));
+        let calls = {
+            let done = done.clone();
+            thread::spawn(move || {
+                let mut succeeded = 0;
+                while !done.load(Ordering::SeqCst) {
+                    match client.best_block_hash() {
+                        Ok(hash) => {
+                            assert_eq!(hash, best_block);
+                            succeeded += 1;
+                        }
+                        // RPC_IN_WARMUP, the node is up but still loading.
+                        Err(ref e) if e.rpc_code() == Some(-28) => {}
+                        Err(e) => panic!("call failed during restart: {}", e),
+                    }
+                    thread::sleep(Duration::from_millis(20));
+                }
+                (client, succeeded)
+            })
+        };
+
+        thread::sleep(Duration::from_millis(100));
+        bitcoind.restart(&[]).unwrap();
+        done.store(true, Ordering::SeqCst);
+
+        let (client, succeeded) = calls.join().unwrap();
+        assert!(succeeded > 0);
+        // The client authenticates using the cookie written by the restarted node.
+        assert_eq!(client.best_block_hash().unwrap(), best_block);
+    }
+
+    #[test]
+    fn test_cookie_file_is_reread() {
+        let exe = init();
+        let bitcoind = BitcoinD::new(exe).unwrap();
+        let dir = TempDir::new().unwrap();
+        let cookie_file = dir.path().join(".cookie");
+
+        fs::write(&cookie_file, "__cookie__:wrong").unwrap();
+        let auth = Auth::CookieFile(cookie_file.clone());
+        let client = Client::new_with_auth(&bitcoind.rpc_url(), auth).unwrap();
+        assert!(client.get_blockchain_info().is_err());
+
+        // Make sure the modification time changes on file systems with a coarse granularity.
+        thread::sleep(Duration::from_millis(1100));
+        fs::copy(&bitcoind.params.cookie_file, &cookie_file).unwrap();
+        assert!(client.get_blockchain_info().is_ok());
+    }
+
+    #[test]
+    fn test_call_raw_with_rpc_hook() {
+        use std::sync::{Arc, Mutex};
+
+        use client_sync::mock::MockTransport;
+        use client_sync::RpcEvent;
+        use serde_json::json;
+
+        let mock = MockTransport::new();
+        mock.push_result("getblockcount", 42);
+        mock.push_rpc_error("getblockcount", -28, "Loading block index...");
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut client = Client::from_transport(mock.clone());
+        let recorded = events.clone();
+        client.set_rpc_hook(move |event| {
+            let entry = match *event {
+                RpcEvent::Request { method, args } => format!("request {} {:?}", method, args),
+                RpcEvent::Response { method, result: Ok(resp), .. } =>
+                    format!("response {} {:?}", method, resp.error.as_ref().map(|e| e.code)),
+                RpcEvent::Response { method, result: Err(_), .. } => format!("error {}", method),
+            };
+            recorded.lock().unwrap().push(entry);
+        });
+
+        assert_eq!(client.call_raw("getblockcount", &[]).unwrap(), json!(42));
+        assert!(client.call_raw("getblockcount", &[]).is_err());
+        assert!(client.call_raw("getblockhash", &[json!(1)]).is_err());
+
+        let events = events.lock().unwrap().clone();
+        assert_eq!(
+            events,
+            vec![
+                "request getblockcount []",
+                "response getblockcount None",
+                "request getblockcount []",
+                "response getblockcount Some(-28)",
+                "request getblockhash [Number(1)]",
+                "response getblockhash Some(-32601)",
+            ]
+        );
+
+        client.clear_rpc_hook();
+        mock.push_result("getblockcount", 43);
+        assert_eq!(client.call_raw("getblockcount", &[]).unwrap(), json!(43));
+    }
+
+    #[test]
+    fn test_get_raw_transaction_not_found() {
+        use bitcoind_json_rpc_client::bitcoin::Txid;
+        use client_sync::mock::MockTransport;
+        use client_sync::{Error, TxNotFoundError};
+        use serde_json::json;
+
+        let txid: Txid =
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b".parse().unwrap();
+        let not_found = |mock: &MockTransport| {
+            mock.push_rpc_error("getrawtransaction", -5, "No such mempool transaction.");
+        };
+
+        let mock = MockTransport::new();
+        let client = Client::from_transport(mock.clone());
+
+        not_found(&mock);
+        mock.push_result("getindexinfo", json!({}));
+        match client.get_raw_transaction(txid) {
+            Err(Error::TxNotFound(e)) => assert_eq!(e, TxNotFoundError::TxIndexDisabled(txid)),
+            res => panic!("unexpected result {:?}", res),
+        }
+
+        not_found(&mock);
+        let index = json!({ "txindex": { "synced": false, "best_block_height": 10 } });
+        mock.push_result("getindexinfo", index);
+        match client.get_raw_transaction(txid) {
+            Err(Error::TxNotFound(e)) => assert_eq!(e, TxNotFoundError::TxIndexSyncing(txid)),
+            res => panic!("unexpected result {:?}", res),
+        }
+
+        // Nodes before v0.21 do not have `getindexinfo`.
+        not_found(&mock);
+        mock.push_rpc_error("getindexinfo", -32601, "Method not found");
+        match client.get_raw_transaction(txid) {
+            Err(Error::TxNotFound(e)) => assert_eq!(e, TxNotFoundError::NotFound(txid)),
+            res => panic!("unexpected result {:?}", res),
+        }
+
+        // Other errors are returned as is.
+        mock.push_rpc_error("getrawtransaction", -8, "parameter 1 must be hexadecimal");
+        assert_eq!(client.get_raw_transaction(txid).unwrap_err().rpc_code(), Some(-8));
+        assert_eq!(mock.pending(), 0);
+    }
+
+    #[test]
+    fn test_wait_for_ready() {
+        use client_sync::mock::MockTransport;
+        use client_sync::{BitcoindRpc, NodeState};
+
+        let exe = init();
+        let bitcoind = BitcoinD::new(exe).unwrap();
+        let address = bitcoind.client.new_address().unwrap();
+        let _ = bitcoind.client.generate_to_address(1, &address).unwrap();
+        let info: serde_json::Value = bitcoind.client.call("getblockchaininfo", &[]).unwrap();
+
+        let mock = MockTransport::new();
+        mock.push_timeout("getblockchaininfo");
+        mock.push_rpc_error("getblockchaininfo", -28, "Loading block index...");
+        mock.push_result("getblockchaininfo", info.clone());
+        let client = Client::from_transport(mock.clone());
+
+        let state = client.wait_for_ready(0.9, Duration::from_secs(10)).unwrap();
+        assert!(state.is_synced());
+        assert_eq!(state.ibd_state().unwrap().blocks, 1);
+        assert_eq!(mock.pending(), 0);
+
+        mock.push_rpc_error("getblockchaininfo", -28, "Loading wallet...");
+        let state = client.node_state(0.9).unwrap();
+        assert_eq!(state, NodeState::Warmup("Loading wallet...".to_owned()));
+
+        // Verification progress never exceeds 1.0.
+        mock.push_result("getblockchaininfo", info);
+        match client.node_state(1.1).unwrap() {
+            NodeState::InitialBlockDownload(state) => assert!(!state.initial_block_download),
+            state => panic!("unexpected state {:?}", state),
+        }
+    }
+
+    #[test]
+    fn test_node_url() {
+        use bitcoind_json_rpc_client::bitcoin::Network;
+        use bitcoind_json_rpc_client::node_url::NodeUrlError;
+
+        let url = NodeUrl::new(Network::Signet);
+        assert_eq!(url.to_string(), "http://127.0.0.1:38332");
+        let url = url.with_wallet("my wallet/1");
+        assert_eq!(url.to_string(), "http://127.0.0.1:38332/wallet/my%20wallet%2F1");
+        assert_eq!(url.to_string().parse::<NodeUrl>().unwrap(), url);
+
+        let url = NodeUrl::parse("localhost", Network::Regtest).unwrap();
+        assert_eq!(url.to_string(), "http://localhost:18443");
+        let url: NodeUrl = "http://[::1]:1234/wallet/w".parse().unwrap();
+        assert_eq!((url.host(), url.port(), url.wallet()), ("[::1]", 1234, Some("w")));
+
+        assert!(matches!("https://localhost".parse::<NodeUrl>(), Err(NodeUrlError::Scheme(_))));
+        assert!(matches!("http://:8332".parse::<NodeUrl>(), Err(NodeUrlError::Host(_))));
+        assert!(matches!("http://a b".parse::<NodeUrl>(), Err(NodeUrlError::Host(_))));
+        assert!(matches!("localhost:99999".parse::<NodeUrl>(), Err(NodeUrlError::Port(_))));
+        assert!(matches!("localhost/rest".parse::<NodeUrl>(), Err(NodeUrlError::Path(_))));
+        assert!(NodeUrl::new(Network::Bitcoin).with_host("a/b").is_err());
+    }
+
+    #[test]
+    fn test_mine_blocks_with_interval() {
+        let exe = init();
+        let bitcoind = BitcoinD::new(exe).unwrap();
+        let address = bitcoind.client.new_address().unwrap();
+
+        let start = 2_000_000_000;
+        bitcoind.set_mock_time(start).unwrap();
+        let hashes = bitcoind.mine_blocks_with_interval(3, 600, &address).unwrap();
+        assert_eq!(hashes.len(), 3);
+        assert_eq!(bitcoind.mock_time(), Some(start + 3 * 600));
+
+        let block = bitcoind.client.get_block(&hashes[2]).unwrap();
+        assert_eq!(u64::from(block.header.time), start + 3 * 600);
+    }
+
+    #[test]
+    fn test_fund_deterministic_wallet() {
+        let exe = init();
+        let amount = Amount::from_int_btc(120);
+
+        let mut txids = Vec::new();
+        for _ in 0..2 {
+            let bitcoind = BitcoinD::new(&exe).unwrap();
+            let addresses = bitcoind.fund_deterministic_wallet(DETERMINISTIC_WIF, amount).unwrap();
+            assert_eq!(addresses.len(), 3);
+
+            let unspent = bitcoind.client.list_unspent().unwrap().into_model().unwrap().0;
+            let total = unspent.iter().map(|utxo| utxo.amount).sum::<Amount>();
+            assert_eq!(total, Amount::from_int_btc(150));
+
+            let mut run = unspent.iter().map(|utxo| utxo.txid).collect::<Vec<_>>();
+            run.sort();
+            txids.push(run);
+        }
+        assert_eq!(txids[0], txids[1]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "26_0", feature = "28_0"))]
+    fn test_assumeutxo_rejects_unknown_snapshot() {
+        let exe = init();
+        let bitcoind = BitcoinD::new(exe).unwrap();
+        let address = bitcoind.client.new_address().unwrap();
+        let _ = bitcoind.client.generate_to_address(110, &address).unwrap();
+
+        // Regtest only accepts a snapshot of the chain created by Core's functional tests.
+        let err = bitcoind.assumeutxo(&Conf::default()).unwrap_err();
+        assert!(format!("{:?}", err).contains("not recognized"), "{:?}", err);
+        assert!(bitcoind.workdir().join("assumeutxo.dat").exists());
+    }
+
     #[test]
     #[cfg(feature = "0_21_2")]
     fn test_getindexinfo() {
@@ -630,7 +1385,8 @@ mod test {
 
         let bitcoind = BitcoinD::with_conf(&exe, &conf).unwrap();
         assert_eq!(peers_connected(&bitcoind.client), 0);
-        let mut other_conf = Conf::<'_> { p2p: bitcoind.p2p_connect(false).unwrap(), ..Default::default() };
+        let mut other_conf =
+            Conf::<'_> { p2p: bitcoind.p2p_connect(false).unwrap(), ..Default::default() };
         other_conf.p2p = bitcoind.p2p_connect(false).unwrap();
 
         let other_bitcoind = BitcoinD::with_conf(&exe, &other_conf).unwrap();
@@ -670,6 +1426,25 @@ mod test {
         assert_eq!(wallet_balance_1, wallet_balance_2);
     }
 
+    #[test]
+    fn test_restart() {
+        let exe = init();
+        let mut bitcoind = BitcoinD::new(exe).unwrap();
+        let address = bitcoind.client.new_address().unwrap();
+        bitcoind.client.generate_to_address(101, &address).unwrap();
+        let balance = bitcoind.client.get_balance().unwrap();
+        let best_block = bitcoind.client.get_best_block_hash().unwrap();
+
+        assert!(bitcoind.stop_gracefully().unwrap().success());
+        // Stopping an already stopped node is a no-op.
+        assert!(bitcoind.stop_gracefully().unwrap().success());
+
+        bitcoind.restart(&["-persistmempool=0"]).unwrap();
+
+        assert_eq!(bitcoind.client.get_best_block_hash().unwrap(), best_block);
+        assert_eq!(bitcoind.client.get_balance().unwrap(), balance);
+    }
+
     #[test]
     fn test_multi_p2p() {
         let _ = env_logger::try_init();
@@ -681,7 +1456,8 @@ mod test {
         let node2 = BitcoinD::with_conf(exe_path().unwrap(), &conf_node2).unwrap();
 
         // Create Node 3 Connected To Node
-        let conf_node3 = Conf::<'_> { p2p: node2.p2p_connect(false).unwrap(), ..Default::default() };
+        let conf_node3 =
+            Conf::<'_> { p2p: node2.p2p_connect(false).unwrap(), ..Default::default() };
         let node3 = BitcoinD::with_conf(exe_path().unwrap(), &conf_node3).unwrap();
 
         // Get each nodes Peers
@@ -798,6 +1574,38 @@ mod test {
         assert_eq!(1, info.blocks);
     }
 
+    #[test]
+    #[cfg(feature = "0_20_2")]
+    fn test_client_allowed_methods() {
+        let exe = init();
+
+        let mut conf = Conf::default();
+        // Same rpcauth as `test_bitcoind_rpcauth`, the cookie user is not restricted.
+        conf.args.push("-rpcauth=bitcoind:cccd5d7fd36e55c1b8576b8077dc1b83$60b5676a09f8518dcb4574838fb86f37700cd690d99bd2fdc2ea2bf2ab80ead6");
+        conf.args.push("-rpcwhitelist=bitcoind:getblockchaininfo,getbestblockhash");
+        conf.args.push("-rpcwhitelistdefault=0");
+        let bitcoind = BitcoinD::with_conf(exe, &conf).unwrap();
+
+        let auth = Auth::UserPass("bitcoind".to_string(), "bitcoind".to_string());
+        let mut client = Client::new_with_auth(&bitcoind.rpc_url(), auth).unwrap();
+        client.set_allowed_methods(["getblockchaininfo", "getbestblockhash"]);
+
+        assert_eq!(0, client.get_blockchain_info().unwrap().blocks);
+        assert_eq!(client.best_block_hash().unwrap(), bitcoind.client.best_block_hash().unwrap());
+
+        // Fails before the request is sent instead of with HTTP 403 from the node.
+        match client.call::<serde_json::Value>("getnetworkinfo", &[]) {
+            Err(client_sync::Error::MethodNotAllowed(method)) =>
+                assert_eq!(method, "getnetworkinfo"),
+            res => panic!("expected `MethodNotAllowed`, got: {:?}", res),
+        }
+        let clone = client.clone();
+        assert!(clone.allowed_methods().unwrap().contains("getbestblockhash"));
+
+        client.clear_allowed_methods();
+        assert!(client.call::<serde_json::Value>("getnetworkinfo", &[]).is_err());
+    }
+
     #[test]
     fn test_get_cookie_user_and_pass() {
         let exe = init();
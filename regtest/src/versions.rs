@@ -1,3 +1,7 @@
+#[cfg(all(feature = "28_0", not(feature = "26_0")))]
+#[allow(dead_code)]         // v28 is not downloaded
+pub const VERSION: &str = "28.0";
+
 #[cfg(feature = "26_0")]
 pub const VERSION: &str = "26.0";
 
@@ -50,6 +54,6 @@ pub const VERSION: &str = "0.18.1";
 pub const VERSION: &str = "0.17.1";
 
 // To make --no-default-features work we have to enable some feature, use most recent version same as for default.
-#[cfg(all(not(feature = "26_0"), not(feature = "25_2"), not(feature = "25_1"), not(feature = "25_0"), not(feature = "24_2"),not(feature = "24_1"), not(feature = "24_0_1"), not(feature = "23_2"), not(feature = "23_1"), not(feature = "23_0"), not(feature = "22_1"), not(feature = "22_0"), not(feature = "0_21_2"), not(feature = "0_20_2"), not(feature = "0_19_1"), not(feature = "0_18_1"), not(feature = "0_17_1")))]
+#[cfg(all(not(feature = "28_0"), not(feature = "26_0"), not(feature = "25_2"), not(feature = "25_1"), not(feature = "25_0"), not(feature = "24_2"),not(feature = "24_1"), not(feature = "24_0_1"), not(feature = "23_2"), not(feature = "23_1"), not(feature = "23_0"), not(feature = "22_1"), not(feature = "22_0"), not(feature = "0_21_2"), not(feature = "0_20_2"), not(feature = "0_19_1"), not(feature = "0_18_1"), not(feature = "0_17_1")))]
 #[allow(dead_code)]         // for --no-default-features
 pub const VERSION: &str = "26.0";